@@ -0,0 +1,22 @@
+use na::{Isometry2, Point2, Vector2};
+use parry2d::bounding_volume::details::aabb_of_shapes;
+use parry2d::bounding_volume::AABB;
+use parry2d::shape::{Ball, Cuboid, Shape};
+
+#[test]
+fn aabb_of_ball_and_cuboid() {
+    let shapes: Vec<(Isometry2<f32>, Box<dyn Shape>)> = vec![
+        (Isometry2::translation(-3.0, 1.0), Box::new(Ball::new(1.0))),
+        (
+            Isometry2::new(Vector2::new(2.0, -1.0), std::f32::consts::FRAC_PI_2),
+            Box::new(Cuboid::new(Vector2::new(1.0, 0.5))),
+        ),
+    ];
+
+    let aabb = aabb_of_shapes(&shapes).unwrap();
+    let expected = AABB::new(Point2::new(-4.0, -2.0), Point2::new(2.5, 2.0));
+    assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-5);
+
+    assert!(aabb_of_shapes(&[]).is_none());
+}
@@ -0,0 +1,47 @@
+use na::{Point2, Vector2};
+use parry2d::bounding_volume::AABB;
+
+fn test_aabb() -> AABB {
+    AABB::new(Point2::new(1.0, -2.0), Point2::new(3.0, 4.0))
+}
+
+#[test]
+fn aabb_scaled_uniform_and_non_uniform() {
+    let aabb = test_aabb();
+
+    let scaled = aabb.scaled(&Vector2::repeat(2.0));
+    assert_eq!(scaled.mins, Point2::new(2.0, -4.0));
+    assert_eq!(scaled.maxs, Point2::new(6.0, 8.0));
+
+    let scaled = aabb.scaled(&Vector2::new(0.5, 3.0));
+    assert_eq!(scaled.mins, Point2::new(0.5, -6.0));
+    assert_eq!(scaled.maxs, Point2::new(1.5, 12.0));
+}
+
+#[test]
+fn aabb_scaled_wrt_center_uniform_and_non_uniform() {
+    let aabb = test_aabb();
+
+    let scaled = aabb.scaled_wrt_center(&Vector2::repeat(2.0));
+    assert_eq!(scaled.center(), aabb.center());
+    assert_eq!(scaled.mins, Point2::new(0.0, -5.0));
+    assert_eq!(scaled.maxs, Point2::new(4.0, 7.0));
+
+    let scaled = aabb.scaled_wrt_center(&Vector2::new(0.5, 3.0));
+    assert_eq!(scaled.center(), aabb.center());
+    assert_eq!(scaled.mins, Point2::new(1.5, -8.0));
+    assert_eq!(scaled.maxs, Point2::new(2.5, 10.0));
+}
+
+#[test]
+fn aabb_scaled_negative_factor() {
+    let aabb = test_aabb();
+
+    let scaled = aabb.scaled(&Vector2::new(-1.0, 2.0));
+    assert_eq!(scaled.mins, Point2::new(-3.0, -4.0));
+    assert_eq!(scaled.maxs, Point2::new(-1.0, 8.0));
+
+    let scaled = aabb.scaled_wrt_center(&Vector2::new(-2.0, 1.0));
+    assert_eq!(scaled.mins, Point2::new(0.0, -2.0));
+    assert_eq!(scaled.maxs, Point2::new(4.0, 4.0));
+}
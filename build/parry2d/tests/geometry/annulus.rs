@@ -0,0 +1,89 @@
+use na::{Point2, Vector2};
+use parry2d::query::{PointQuery, Ray, RayCast};
+use parry2d::shape::{Annulus, Shape};
+use parry2d::transformation;
+
+#[test]
+fn annulus_point_queries() {
+    let annulus = Annulus::new(1.0, 2.0);
+
+    assert!(annulus.contains_local_point(&Point2::new(0.0, 1.5)));
+    assert!(!annulus.contains_local_point(&Point2::new(0.5, 0.0)));
+    assert!(!annulus.contains_local_point(&Point2::new(2.5, 0.0)));
+
+    // In the hole.
+    let proj = annulus.project_local_point(&Point2::new(0.0, -0.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(0.0, -1.0));
+    assert_relative_eq!(
+        annulus.distance_to_local_point(&Point2::new(0.0, -0.5), true),
+        0.5
+    );
+
+    // Outside.
+    let proj = annulus.project_local_point(&Point2::new(3.0, 0.0), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(2.0, 0.0));
+
+    // In the ring, closer to the inner circle.
+    let pt = Point2::new(-1.2, 0.0);
+    assert_eq!(annulus.project_local_point(&pt, true).point, pt);
+    let proj = annulus.project_local_point(&pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(-1.0, 0.0));
+    assert_relative_eq!(annulus.distance_to_local_point(&pt, false), -0.2);
+}
+
+#[test]
+fn annulus_ray_cast() {
+    let annulus = Annulus::new(1.0, 2.0);
+
+    // Through both circles.
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+    let hit = annulus
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.0);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+
+    // From the hole.
+    let ray = Ray::new(Point2::origin(), Vector2::y() * 2.0);
+    let hit = annulus
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, -Vector2::y());
+
+    // From the ring, toward the hole.
+    let ray = Ray::new(Point2::new(1.5, 0.0), -Vector2::x());
+    assert_eq!(annulus.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+    let hit = annulus
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, Vector2::x());
+
+    // Stopped by `max_toi`, and missing the ring.
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+    assert!(annulus.cast_local_ray(&ray, 2.9, true).is_none());
+    let ray = Ray::new(Point2::new(-5.0, 2.5), Vector2::x());
+    assert!(annulus.cast_local_ray(&ray, f32::MAX, true).is_none());
+}
+
+#[test]
+fn annulus_mass_properties() {
+    let annulus = Annulus::new(0.5, 1.0);
+    let mprops = annulus.mass_properties(2.0);
+    let voxelized = transformation::mass_properties_by_voxelization(&annulus, 2.0, 0.005);
+
+    assert_relative_eq!(
+        1.0 / mprops.inv_mass,
+        1.0 / voxelized.inv_mass,
+        max_relative = 1.0e-2
+    );
+    assert_relative_eq!(
+        mprops.inv_principal_inertia_sqrt,
+        voxelized.inv_principal_inertia_sqrt,
+        max_relative = 1.0e-2
+    );
+}
@@ -0,0 +1,70 @@
+use na::{DVector, Isometry2, Point2, Vector2};
+use parry2d::shape::{Ball, Compound, Cuboid, HeightField, Shape, SharedShape, TriMesh, Triangle};
+
+#[test]
+fn ccd_thickness_of_triangles() {
+    // The smallest height is the one orthogonal to the hypotenuse.
+    let triangle = Triangle::new(
+        Point2::origin(),
+        Point2::new(4.0, 0.0),
+        Point2::new(0.0, 3.0),
+    );
+    assert_relative_eq!(triangle.ccd_thickness(), 12.0 / 5.0, epsilon = 1.0e-6);
+
+    // A degenerate triangle has no thickness.
+    let flat = Triangle::new(
+        Point2::origin(),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+    );
+    assert_eq!(flat.ccd_thickness(), 0.0);
+
+    // The thinnest triangle of the mesh gives its thickness.
+    let trimesh = TriMesh::new(
+        vec![
+            Point2::origin(),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 3.0),
+            Point2::new(4.0, 0.5),
+        ],
+        vec![[0, 1, 2], [1, 3, 2]],
+    );
+    let thinnest = Triangle::new(
+        Point2::new(4.0, 0.0),
+        Point2::new(4.0, 0.5),
+        Point2::new(0.0, 3.0),
+    );
+    assert_relative_eq!(trimesh.ccd_thickness(), thinnest.ccd_thickness());
+    assert!(trimesh.ccd_thickness() < triangle.ccd_thickness());
+
+    let heightfield =
+        HeightField::new(DVector::from_vec(vec![0.0, 1.0, 0.5]), Vector2::repeat(2.0));
+    assert_eq!(heightfield.ccd_thickness(), 0.0);
+    let heightfield = heightfield.with_ccd_thickness(0.5);
+    assert_eq!(heightfield.ccd_thickness(), 0.5);
+}
+
+#[test]
+fn ccd_angular_thickness() {
+    assert_eq!(Ball::new(1.0).ccd_angular_thickness(), f32::MAX);
+    assert_relative_eq!(
+        Cuboid::new(Vector2::repeat(1.0)).ccd_angular_thickness(),
+        std::f32::consts::FRAC_PI_2
+    );
+
+    let compound = Compound::new(vec![
+        (Isometry2::identity(), SharedShape::ball(1.0)),
+        (
+            Isometry2::translation(3.0, 0.0),
+            SharedShape::triangle(
+                Point2::origin(),
+                Point2::new(1.0, 0.0),
+                Point2::new(0.0, 1.0),
+            ),
+        ),
+    ]);
+    assert_relative_eq!(
+        compound.ccd_angular_thickness(),
+        std::f32::consts::FRAC_PI_4
+    );
+}
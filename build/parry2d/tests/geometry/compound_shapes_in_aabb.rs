@@ -0,0 +1,29 @@
+use na::{Isometry2, Point2, Vector2};
+use parry2d::bounding_volume::AABB;
+use parry2d::shape::{Compound, SharedShape};
+
+#[test]
+fn compound_shapes_in_aabb() {
+    let shapes = (0..100)
+        .map(|i| {
+            let pos = Isometry2::translation((i % 10) as f32 * 4.0, (i / 10) as f32 * 4.0);
+            (pos, SharedShape::ball(0.5))
+        })
+        .collect();
+    let compound = Compound::new(shapes);
+    let position = Isometry2::new(Vector2::new(-10.0, 5.0), 0.0);
+
+    // Covers the balls at the grid coordinates (2..=4, 1..=2).
+    let query = AABB::new(Point2::new(-3.0, 8.5), Point2::new(6.0, 14.0));
+    let mut found: Vec<_> = compound
+        .shapes_in_aabb(&position, &query)
+        .map(|(i, pos, shape)| {
+            assert_eq!(*pos, compound.shapes()[i].0);
+            assert!(shape.as_ball().is_some());
+            i
+        })
+        .collect();
+    found.sort();
+
+    assert_eq!(found, vec![12, 13, 14, 22, 23, 24]);
+}
@@ -0,0 +1,31 @@
+use na::Point2;
+use parry2d::mass_properties::MassProperties;
+
+#[test]
+fn unit_square_mass_properties_with_geometry() {
+    let square = [
+        Point2::new(1.0, 2.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(2.0, 3.0),
+        Point2::new(1.0, 3.0),
+    ];
+    let density = 2.0;
+
+    let (props, centroid, area) =
+        MassProperties::from_convex_polygon_with_geometry(density, &square);
+    assert_relative_eq!(centroid, Point2::new(1.5, 2.5), epsilon = 1.0e-6);
+    assert_relative_eq!(area, 1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(props.local_com, centroid);
+    assert_relative_eq!(props.inv_mass, 1.0 / density, epsilon = 1.0e-6);
+
+    // The inertia of a square of mass `m` and side `s` is `m * s^2 / 6`.
+    let inertia = density / 6.0;
+    assert_relative_eq!(
+        props.inv_principal_inertia_sqrt,
+        1.0 / inertia.sqrt(),
+        epsilon = 1.0e-5
+    );
+
+    let props2 = MassProperties::from_convex_polygon(density, &square);
+    assert_eq!(props, props2);
+}
@@ -0,0 +1,68 @@
+use na::Point2;
+use parry2d::shape::ConvexPolygon;
+
+fn square() -> Vec<Point2<f32>> {
+    vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(0.0, 2.0),
+    ]
+}
+
+#[test]
+fn try_from_convex_polyline_validates_input() {
+    let polygon = ConvexPolygon::try_from_convex_polyline(square()).unwrap();
+    assert_eq!(polygon.points().len(), 4);
+
+    // Clockwise.
+    let mut cw = square();
+    cw.reverse();
+    assert!(ConvexPolygon::try_from_convex_polyline(cw).is_none());
+
+    // Concave.
+    let mut concave = square();
+    concave.insert(4, Point2::new(1.0, 1.0));
+    assert!(ConvexPolygon::try_from_convex_polyline(concave).is_none());
+
+    // Flat.
+    let flat = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+    ];
+    assert!(ConvexPolygon::try_from_convex_polyline(flat).is_none());
+
+    // Duplicate points.
+    let mut duplicate = square();
+    duplicate.push(Point2::new(0.0, 2.0));
+    assert!(ConvexPolygon::try_from_convex_polyline(duplicate).is_none());
+}
+
+#[test]
+fn normalize_fixes_winding_and_reports_concavity() {
+    let mut cw = square();
+    cw.reverse();
+    let mut polygon = ConvexPolygon::from_convex_polyline(cw).unwrap();
+    assert!(!polygon.is_convex_with_tolerance(1.0e-5));
+
+    assert!(polygon.normalize());
+    assert_eq!(polygon.points().len(), 4);
+    assert!(polygon.is_convex_with_tolerance(1.0e-5));
+
+    // The normals point outward after normalization.
+    for (pt, n) in polygon.points().iter().zip(polygon.normals()) {
+        assert!(n.dot(&(Point2::new(1.0, 1.0) - pt)) < 0.0);
+    }
+
+    // Normalizing an already valid polygon does nothing.
+    let before = polygon.points().to_vec();
+    assert!(polygon.normalize());
+    assert_eq!(polygon.points(), &before[..]);
+
+    let mut concave = square();
+    concave.insert(4, Point2::new(1.0, 1.0));
+    let mut polygon = ConvexPolygon::from_convex_polyline(concave).unwrap();
+    assert!(!polygon.normalize());
+}
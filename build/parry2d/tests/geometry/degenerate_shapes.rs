@@ -0,0 +1,94 @@
+use approx::assert_relative_eq;
+use na::{Point2, Vector2};
+use parry2d::query::{PointQuery, Ray, RayCast};
+use parry2d::shape::{ConvexPolygon, Degeneracy, Segment, Triangle};
+
+#[test]
+fn convex_polyline_canonicalization() {
+    let points = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0e-4),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(1.0, 2.0001),
+        Point2::new(0.0, 2.0),
+        Point2::new(0.0, 0.0),
+    ];
+
+    // The duplicate vertex makes the polygon invalid without tolerance.
+    assert!(ConvexPolygon::from_convex_polyline(points.clone()).is_none());
+
+    let polygon = ConvexPolygon::from_convex_polyline_with_tolerance(points, 1.0e-3).unwrap();
+    let expected = [
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(0.0, 2.0),
+    ];
+    assert_eq!(polygon.points().len(), 4);
+    assert!(expected.iter().all(|pt| polygon.points().contains(pt)));
+    assert_eq!(polygon.degeneracy(1.0e-3), None);
+
+    let flat = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 1.0e-4),
+        Point2::new(2.0, 0.0),
+        Point2::new(1.0, -1.0e-4),
+    ];
+    assert!(ConvexPolygon::from_convex_polyline_with_tolerance(flat.clone(), 1.0e-3).is_none());
+
+    let polygon = ConvexPolygon::from_convex_polyline(flat).unwrap();
+    match polygon.degeneracy(1.0e-3) {
+        Some(Degeneracy::Segment(seg)) => assert_relative_eq!(seg.length(), 2.0),
+        d => panic!("Unexpected degeneracy: {:?}", d),
+    }
+}
+
+#[test]
+fn triangle_queries_robustness() {
+    let tri = Triangle::new(
+        Point2::origin(),
+        Point2::new(4.0, 0.0),
+        Point2::new(0.0, 4.0),
+    );
+
+    // Points inside are projected on the closest edge for non-solid projections.
+    let proj = tri.project_local_point(&Point2::new(1.0, 0.5), false);
+    assert_eq!(proj.point, Point2::new(1.0, 0.0));
+    let proj = tri.project_local_point(&Point2::new(0.5, 1.5), false);
+    assert_eq!(proj.point, Point2::new(0.0, 1.5));
+    let proj = tri.project_local_point(&Point2::new(1.8, 1.8), false);
+    assert_relative_eq!(proj.point, Point2::new(2.0, 2.0), epsilon = 1.0e-5);
+
+    // Solid ray casts only hit at the origin of rays starting inside.
+    let inside = Ray::new(Point2::new(1.0, 1.0), Vector2::new(0.0, -1.0));
+    assert_eq!(tri.cast_local_ray(&inside, 10.0, true), Some(0.0));
+    assert_eq!(tri.cast_local_ray(&inside, 10.0, false), Some(1.0));
+    let outside = Ray::new(Point2::new(1.0, -1.0), Vector2::new(0.0, 1.0));
+    assert_eq!(tri.cast_local_ray(&outside, 10.0, true), Some(1.0));
+
+    // Degenerate triangles behave like segments.
+    let degenerate = Triangle::new(Point2::origin(), Point2::origin(), Point2::new(1.0, 0.0));
+    let proj = degenerate.project_local_point(&Point2::new(0.5, 0.3), true);
+    assert_eq!(proj.point, Point2::new(0.5, 0.0));
+    assert!(!proj.is_inside);
+
+    let ray = Ray::new(Point2::new(0.5, 1.0), Vector2::new(0.0, -1.0));
+    let hit = degenerate
+        .cast_local_ray_and_get_normal(&ray, 10.0, true)
+        .unwrap();
+    assert_eq!(hit.toi, 1.0);
+    assert!(hit.normal.norm() > 0.0);
+
+    // A degenerate segment is only hit by the rays passing through it.
+    let point = Segment::new(Point2::new(1.0, 1.0), Point2::new(1.0, 1.0));
+    let ray = Ray::new(Point2::new(0.0, 1.0), Vector2::new(1.0, 0.0));
+    let hit = point
+        .cast_local_ray_and_get_normal(&ray, 10.0, true)
+        .unwrap();
+    assert_eq!(hit.toi, 1.0);
+    assert_eq!(hit.normal, Vector2::new(-1.0, 0.0));
+    let ray = Ray::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    assert!(point.cast_local_ray(&ray, 10.0, true).is_none());
+}
@@ -0,0 +1,46 @@
+use na::{Isometry2, Point2, Vector2};
+use parry2d::query::{self, PointQuery, Ray, RayCast};
+use parry2d::shape::{Ball, Polyline, Shape, TriMesh};
+
+#[test]
+fn empty_polyline_queries() {
+    for polyline in &[
+        Polyline::new(Vec::new(), None),
+        Polyline::new(Vec::new(), Some(Vec::new())),
+        Polyline::new(vec![Point2::origin()], None),
+    ] {
+        let pos = Isometry2::translation(1.0, 2.0);
+        assert!(polyline.is_empty());
+
+        let aabb = polyline.compute_aabb(&pos);
+        assert!(aabb.mins > aabb.maxs);
+
+        let ray = Ray::new(Point2::origin(), Vector2::x());
+        assert!(polyline.cast_ray(&pos, &ray, f32::MAX, true).is_none());
+        assert!(!polyline.intersects_ray(&pos, &ray, f32::MAX));
+
+        let pt = Point2::new(1.0, 2.0);
+        assert!(!polyline.contains_point(&pos, &pt));
+        assert!(!polyline.project_point(&pos, &pt, true).is_inside);
+
+        let ball = Ball::new(1.0);
+        assert!(!query::intersection_test(&pos, polyline, &pos, &ball).unwrap());
+    }
+}
+
+#[test]
+fn empty_trimesh_queries() {
+    let mesh = TriMesh::new(Vec::new(), Vec::new());
+    let pos = Isometry2::translation(1.0, 2.0);
+    assert!(mesh.is_empty());
+
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    assert!(mesh.cast_ray(&pos, &ray, f32::MAX, true).is_none());
+    assert!(!mesh.contains_point(&pos, &Point2::new(1.0, 2.0)));
+    assert!(
+        !mesh
+            .project_point(&pos, &Point2::new(1.0, 2.0), true)
+            .is_inside
+    );
+    assert_eq!(mesh.mass_properties(1.0).inv_mass, 0.0);
+}
@@ -50,3 +50,24 @@ fn cuboids_large_size_ratio_issue_181() {
         }
     }
 }
+
+#[test]
+fn cuboid_cuboid_penetration_depth() {
+    use parry2d::query::epa::{self, EPA};
+
+    let c = Cuboid::new(Vector2::new(2.0, 1.0));
+    let pos12 = Isometry2::translation(3.5, 0.1);
+
+    let res = epa::penetration_depth(&pos12, &c, &c).unwrap();
+    assert_relative_eq!(res.depth, 0.5, epsilon = 1.0e-5);
+    assert_relative_eq!(res.normal1.into_inner(), Vector2::x(), epsilon = 1.0e-5);
+    assert_relative_eq!(res.witness1.x, 2.0, epsilon = 1.0e-5);
+    assert_relative_eq!(res.witness2.x, -2.0, epsilon = 1.0e-5);
+
+    let mut epa = EPA::with_budget(1, 4);
+    let res = epa::penetration_depth_with_epa(&pos12, &c, &c, &mut epa).unwrap();
+    assert!(res.depth <= 0.5 + 1.0e-5);
+
+    let pos12 = Isometry2::translation(4.5, 0.0);
+    assert!(epa::penetration_depth(&pos12, &c, &c).is_none());
+}
@@ -0,0 +1,61 @@
+use na::{Point2, Vector2};
+use parry2d::math::Real;
+use parry2d::shape::{ConvexPolygon, Cuboid, FeatureId, Shape, Triangle};
+
+// Checks that projecting a point located outside of each feature returns this feature, and
+// that the sides are counterclockwise.
+fn assert_features_match_projections(shape: &dyn Shape, center: Point2<Real>) -> usize {
+    let mut num_features = 0;
+
+    shape.for_each_feature(&mut |id, vertices| {
+        num_features += 1;
+        let query = match id {
+            FeatureId::Vertex(_) => vertices[0] + (vertices[0] - center).normalize() * 0.5,
+            FeatureId::Face(_) => {
+                let dir = vertices[1] - vertices[0];
+                let normal = Vector2::new(dir.y, -dir.x).normalize();
+                assert!((vertices[0] - center).dot(&normal) > 0.0);
+                na::center(&vertices[0], &vertices[1]) + normal * 0.5
+            }
+            FeatureId::Unknown => panic!("Unknown feature."),
+        };
+        let (_, projected) = shape.project_local_point_and_get_feature(&query);
+        assert_eq!(projected, id, "{:?}", vertices);
+    });
+
+    num_features
+}
+
+#[test]
+fn polygonal_shapes_features() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 2.0));
+    assert_eq!(
+        assert_features_match_projections(&cuboid, Point2::origin()),
+        8
+    );
+
+    let points = [
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(0.0, 1.0),
+    ];
+
+    // The sides are counterclockwise for both windings of the triangle.
+    let ccw = Triangle::new(points[0], points[1], points[2]);
+    let cw = Triangle::new(points[0], points[2], points[1]);
+    assert_eq!(assert_features_match_projections(&ccw, ccw.center()), 6);
+    assert_eq!(assert_features_match_projections(&cw, cw.center()), 6);
+
+    let polygon = ConvexPolygon::from_convex_hull(&[
+        Point2::new(-1.0, 0.0),
+        Point2::new(0.0, -1.5),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.5, 1.0),
+        Point2::new(-0.5, 1.0),
+    ])
+    .unwrap();
+    assert_eq!(
+        assert_features_match_projections(&polygon, Point2::new(0.0, 0.0)),
+        10
+    );
+}
@@ -0,0 +1,22 @@
+use na::{DVector, Point2, Vector2};
+use parry2d::query::{PointQuery, Ray, RayCast};
+use parry2d::shape::{FilteredShape, HeightField};
+
+#[test]
+fn filtered_heightfield_ray_cast_and_projection() {
+    // Four segments of width 1, spanning x in [-2, 2].
+    let heightfield = HeightField::new(DVector::from_element(5, 0.0), Vector2::new(4.0, 1.0));
+    let ray = Ray::new(Point2::new(-0.5, 3.0), -Vector2::y());
+
+    let toi = heightfield.cast_local_ray(&ray, 100.0, true).unwrap();
+    assert_relative_eq!(toi, 3.0, epsilon = 1.0e-5);
+
+    // The ray crosses the segment 1, which is ignored.
+    let filtered = FilteredShape::new(&heightfield, |i: u32| i != 1);
+    assert!(filtered.cast_local_ray(&ray, 100.0, true).is_none());
+
+    let pt = Point2::new(-0.5, 1.0);
+    let proj = filtered.project_local_point(&pt, false);
+    assert_relative_eq!(proj.point.y, 0.0, epsilon = 1.0e-5);
+    assert_relative_eq!((proj.point.x + 0.5).abs(), 0.5, epsilon = 1.0e-5);
+}
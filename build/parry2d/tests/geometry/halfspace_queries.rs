@@ -0,0 +1,35 @@
+use na::{DVector, Isometry2, Vector2};
+use parry2d::query;
+use parry2d::shape::{HalfSpace, HeightField};
+
+#[test]
+fn halfspace_heightfield_queries() {
+    let heights = DVector::from_vec(vec![1.0, 0.25, 1.0, 1.5]);
+    let heightfield = HeightField::new(heights, Vector2::new(3.0, 1.0));
+    let ground = HalfSpace::new(Vector2::y_axis());
+    let identity = Isometry2::identity();
+    let pos = Isometry2::translation(0.0, 0.5);
+
+    let dist = query::distance(&identity, &ground, &pos, &heightfield).unwrap();
+    assert_relative_eq!(dist, 0.75, epsilon = 1.0e-5);
+
+    let contact = query::contact(&pos, &heightfield, &identity, &ground, 1.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, 0.75, epsilon = 1.0e-5);
+    assert_relative_eq!(contact.point1.y, 0.75, epsilon = 1.0e-5);
+
+    let toi = query::time_of_impact(
+        &identity,
+        &Vector2::zeros(),
+        &ground,
+        &pos,
+        &Vector2::new(0.0, -1.0),
+        &heightfield,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+    assert_relative_eq!(toi.toi, 0.75, epsilon = 1.0e-5);
+}
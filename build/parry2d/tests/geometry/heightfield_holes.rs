@@ -0,0 +1,54 @@
+use na::{DVector, Isometry2, Point2, Vector2};
+use parry2d::math::Real;
+use parry2d::query::{
+    ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray, RayCast,
+};
+use parry2d::shape::{Ball, HeightField, Shape};
+
+// A flat heightfield with 4 unit segments and a hole at the segment spanning x in [0, 1].
+fn heightfield_with_hole() -> HeightField {
+    let mut heightfield = HeightField::new(DVector::zeros(5), Vector2::new(4.0, 1.0));
+    heightfield.set_segment_removed(2, true);
+    heightfield
+}
+
+#[test]
+fn heightfield_queries_skip_holes() {
+    let heightfield = heightfield_with_hole();
+    assert!(heightfield.segment_at(2).is_none());
+    assert_eq!(heightfield.segments().count(), 3);
+
+    let ray = Ray::new(Point2::new(0.5, 2.0), -Vector2::y());
+    assert!(heightfield.cast_local_ray(&ray, Real::MAX, true).is_none());
+    let ray = Ray::new(Point2::new(-0.5, 2.0), -Vector2::y());
+    assert_eq!(heightfield.cast_local_ray(&ray, Real::MAX, true), Some(2.0));
+
+    let proj = heightfield.project_local_point(&Point2::new(0.6, 1.0), false);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 0.0), epsilon = 1.0e-5);
+}
+
+#[test]
+fn heightfield_contacts_skip_holes() {
+    let heightfield = heightfield_with_hole();
+    let ball = Ball::new(0.2);
+    let contacts_at = |x: Real| {
+        let pos12 = Isometry2::translation(x, 0.1).inv_mul(&Isometry2::identity());
+        let mut manifolds: Vec<ContactManifold<(), ()>> = vec![];
+        DefaultQueryDispatcher
+            .contact_manifolds(&pos12, &ball, &heightfield, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        manifolds.iter().map(|m| m.points.len()).sum::<usize>()
+    };
+
+    assert!(contacts_at(-0.5) > 0);
+    assert_eq!(contacts_at(0.5), 0);
+}
+
+#[test]
+fn scaled_heightfield_keeps_its_holes() {
+    let heightfield = heightfield_with_hole();
+    let scaled = heightfield.scaled(&Vector2::new(2.0, 1.0), 10).unwrap();
+    let scaled = scaled.as_heightfield().unwrap();
+    assert!(scaled.is_segment_removed(2));
+    assert!(!scaled.is_segment_removed(1));
+}
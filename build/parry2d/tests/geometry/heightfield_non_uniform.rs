@@ -0,0 +1,73 @@
+use na::{DVector, Point2, Vector2};
+use parry2d::bounding_volume::AABB;
+use parry2d::math::Real;
+use parry2d::query::{PointQuery, Ray, RayCast};
+use parry2d::shape::{HeightField, Shape};
+
+// A heightfield with irregularly spaced cell endpoints, following the line `y = x`.
+fn non_uniform_heightfield() -> HeightField {
+    let xs = vec![0.0, 1.0, 3.0, 6.0];
+    let heights = DVector::from_column_slice(&xs);
+    HeightField::with_coordinates(heights, xs, Vector2::repeat(1.0))
+}
+
+#[test]
+fn non_uniform_heightfield_geometry() {
+    let heightfield = non_uniform_heightfield();
+    assert_eq!(
+        *heightfield.root_aabb(),
+        AABB::new(Point2::new(0.0, 0.0), Point2::new(6.0, 6.0))
+    );
+    assert_eq!(heightfield.start_x(), 0.0);
+    assert_eq!(heightfield.x_at(2), 3.0);
+    assert_eq!(heightfield.cell_at_point(&Point2::new(2.0, 0.0)), Some(1));
+    assert_eq!(heightfield.cell_at_point(&Point2::new(6.0, 0.0)), Some(2));
+    assert_eq!(heightfield.cell_at_point(&Point2::new(-1.0, 0.0)), None);
+
+    let seg = heightfield.segment_at(2).unwrap();
+    assert_eq!(seg.a, Point2::new(3.0, 3.0));
+    assert_eq!(seg.b, Point2::new(6.0, 6.0));
+
+    let mut segments = vec![];
+    let aabb = AABB::new(Point2::new(4.0, 4.0), Point2::new(5.0, 5.0));
+    heightfield.map_elements_in_local_aabb(&aabb, &mut |i, _| segments.push(i));
+    assert_eq!(segments, vec![2]);
+}
+
+#[test]
+fn non_uniform_heightfield_queries() {
+    let heightfield = non_uniform_heightfield();
+
+    let ray = Ray::new(Point2::new(4.5, 5.0), -Vector2::y());
+    assert_relative_eq!(
+        heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap(),
+        0.5,
+        epsilon = 1.0e-5
+    );
+
+    // Horizontal rays crossing several cells of different widths.
+    let ray = Ray::new(Point2::new(-1.0, 2.0), Vector2::x());
+    assert_relative_eq!(
+        heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap(),
+        3.0,
+        epsilon = 1.0e-5
+    );
+    let ray = Ray::new(Point2::new(10.0, 0.5), -Vector2::x());
+    assert_relative_eq!(
+        heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap(),
+        9.5,
+        epsilon = 1.0e-5
+    );
+
+    let proj = heightfield.project_local_point(&Point2::new(0.0, 2.0), false);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 1.0), epsilon = 1.0e-5);
+}
+
+#[test]
+fn scaled_non_uniform_heightfield_keeps_its_coordinates() {
+    let heightfield = non_uniform_heightfield();
+    let scaled = heightfield.scaled(&Vector2::new(2.0, 1.0), 10).unwrap();
+    let scaled = scaled.as_heightfield().unwrap();
+    assert_eq!(scaled.x_coordinates(), heightfield.x_coordinates());
+    assert_eq!(scaled.x_at(3), 12.0);
+}
@@ -0,0 +1,44 @@
+use na::{DVector, Point2, Vector2};
+use parry2d::math::Real;
+use parry2d::query::{Ray, RayCast};
+use parry2d::shape::HeightField;
+
+#[test]
+fn heightfield_height_and_normal_sampling() {
+    // The cell endpoints are at x = -1.5, -0.5, 0.5, 1.5 with the heights 0, 2, 2, 6.
+    let heights = DVector::from_column_slice(&[0.0, 1.0, 1.0, 3.0]);
+    let mut heightfield = HeightField::new(heights, Vector2::new(3.0, 2.0));
+
+    assert_relative_eq!(heightfield.height_at(-1.0).unwrap(), 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(heightfield.height_at(0.0).unwrap(), 2.0, epsilon = 1.0e-5);
+    assert_relative_eq!(heightfield.height_at(1.0).unwrap(), 4.0, epsilon = 1.0e-5);
+    assert_relative_eq!(heightfield.height_at(1.5).unwrap(), 6.0, epsilon = 1.0e-5);
+    assert_eq!(heightfield.height_at(1.6), None);
+    assert_eq!(heightfield.height_at(-1.6), None);
+
+    assert_relative_eq!(
+        *heightfield.normal_at(-1.0).unwrap(),
+        Vector2::new(-2.0, 1.0).normalize(),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        *heightfield.normal_at(0.0).unwrap(),
+        Vector2::y(),
+        epsilon = 1.0e-5
+    );
+
+    // The heights match the hits of vertical rays.
+    for x in [-1.4, -0.7, 0.3, 1.2].iter() {
+        let ray = Ray::new(Point2::new(*x, 10.0), -Vector2::y());
+        let toi = heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        assert_relative_eq!(
+            10.0 - toi,
+            heightfield.height_at(*x).unwrap(),
+            epsilon = 1.0e-4
+        );
+    }
+
+    heightfield.set_segment_removed(1, true);
+    assert_eq!(heightfield.height_at(0.0), None);
+    assert_eq!(heightfield.normal_at(0.0), None);
+}
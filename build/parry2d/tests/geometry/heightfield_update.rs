@@ -0,0 +1,38 @@
+use na::{DVector, Point2, Vector2};
+use parry2d::math::Real;
+use parry2d::query::{Ray, RayCast};
+use parry2d::shape::HeightField;
+
+#[test]
+fn heightfield_set_height_refits_aabb() {
+    let mut heightfield = HeightField::new(DVector::zeros(5), Vector2::new(4.0, 2.0));
+
+    // Dig a crater in the middle of the heightfield.
+    heightfield.set_height(2, -1.0);
+    assert_eq!(heightfield.heights()[2], -1.0);
+    assert_eq!(heightfield.root_aabb().mins.y, -2.0);
+    assert_eq!(heightfield.root_aabb().maxs.y, 0.0);
+
+    let ray = Ray::new(Point2::new(0.0, 5.0), -Vector2::y());
+    assert_eq!(heightfield.cast_local_ray(&ray, Real::MAX, true), Some(7.0));
+
+    // Fill it back: the AABB shrinks again.
+    heightfield.set_height(2, 0.0);
+    assert_eq!(heightfield.root_aabb().mins.y, 0.0);
+    assert_eq!(heightfield.cast_local_ray(&ray, Real::MAX, true), Some(5.0));
+}
+
+#[test]
+fn heightfield_set_heights_in_range_refits_aabb() {
+    let mut heightfield = HeightField::new(DVector::zeros(5), Vector2::new(4.0, 1.0));
+    heightfield.set_height(0, 3.0);
+
+    heightfield.set_heights_in_range(1, &DVector::from_element(2, 1.5));
+    assert_eq!(heightfield.heights().as_slice(), &[3.0, 1.5, 1.5, 0.0, 0.0]);
+    assert_eq!(heightfield.root_aabb().maxs.y, 3.0);
+
+    // Replace the highest endpoint.
+    heightfield.set_heights_in_range(0, &DVector::from_element(1, -0.5));
+    assert_eq!(heightfield.root_aabb().mins.y, -0.5);
+    assert_eq!(heightfield.root_aabb().maxs.y, 1.5);
+}
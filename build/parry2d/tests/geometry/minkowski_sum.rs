@@ -0,0 +1,41 @@
+use na::{Point2, Vector2};
+use parry2d::math::Real;
+use parry2d::query::{PointQuery, Ray, RayCast};
+use parry2d::shape::{Ball, Capsule, Cuboid, MinkowskiSum, Segment, Shape};
+
+#[test]
+fn segment_ball_minkowski_sum_is_a_capsule() {
+    let a = Point2::new(-1.0, 0.5);
+    let b = Point2::new(2.0, -0.5);
+    let sum = MinkowskiSum::new(Segment::new(a, b), Ball::new(0.3));
+    let capsule = Capsule::new(a, b, 0.3);
+
+    let ray = Ray::new(Point2::new(0.0, 5.0), Vector2::new(0.2, -1.0));
+    let hit = sum
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    let expected = capsule
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, expected.toi, epsilon = 1.0e-4);
+    assert_relative_eq!(hit.normal, expected.normal, epsilon = 1.0e-3);
+
+    let pt = Point2::new(3.0, 1.0);
+    assert_relative_eq!(
+        sum.project_local_point(&pt, true).point,
+        capsule.project_local_point(&pt, true).point,
+        epsilon = 1.0e-2
+    );
+    assert!(sum.contains_local_point(&Point2::new(0.5, 0.1)));
+}
+
+#[test]
+fn cuboid_cuboid_minkowski_sum_mass_properties() {
+    let sum = MinkowskiSum::new(
+        Cuboid::new(Vector2::new(1.0, 0.5)),
+        Cuboid::new(Vector2::new(0.5, 1.0)),
+    );
+    let mprops = sum.mass_properties(2.0);
+    let expected = Cuboid::new(Vector2::new(1.5, 1.5)).mass_properties(2.0);
+    assert_relative_eq!(mprops, expected, epsilon = 1.0e-4);
+}
@@ -1,5 +1,37 @@
+mod aabb_of_shapes;
+mod aabb_scale;
+mod annulus;
 mod ball_ball_toi;
 mod ball_cuboid_contact;
+mod ccd_thickness;
+mod compound_shapes_in_aabb;
+mod convex_polygon_mass_properties;
+mod convex_polygon_validation;
+mod degenerate_shapes;
+mod empty_polyline;
 mod epa2;
+mod feature_enumeration;
+mod filtered_shape;
+mod halfspace_queries;
+mod heightfield_holes;
+mod heightfield_non_uniform;
+mod heightfield_sampling;
+mod heightfield_update;
+mod minkowski_sum;
+mod num_features;
+mod point_projection_features;
+mod polygon;
+mod polyline_builders;
+mod polyline_loops;
 mod ray_cast;
+mod round_polyline;
+mod sampling;
+mod scaled_shape;
+mod sector;
+mod shape_discretization;
+mod shape_scale;
+mod tapered_capsule_mass_properties;
 mod time_of_impact2;
+mod typed_shape;
+mod volume_surface_area;
+mod voxels;
@@ -0,0 +1,8 @@
+use na::Vector2;
+use parry2d::shape::{Cuboid, Shape};
+
+#[test]
+fn cuboid_num_features() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 2.0));
+    assert_eq!(cuboid.num_features(), (4, 4, 1));
+}
@@ -0,0 +1,19 @@
+use na::{DVector, Point2, Vector2};
+use parry2d::query::PointQuery;
+use parry2d::shape::{FeatureId, HeightField};
+
+#[test]
+fn heightfield_projection_features() {
+    // Three segments spanning `x ∈ [-1.5, 1.5]`.
+    let heightfield = HeightField::new(DVector::from_element(4, 0.0), Vector2::new(3.0, 1.0));
+    let feature_at = |x, y| {
+        heightfield
+            .project_local_point_and_get_feature(&Point2::new(x, y))
+            .1
+    };
+
+    assert_eq!(feature_at(0.2, 1.0), FeatureId::Face(1));
+    assert_eq!(feature_at(0.2, -1.0), FeatureId::Face(4));
+    assert_eq!(feature_at(-2.0, 0.5), FeatureId::Vertex(0));
+    assert_eq!(feature_at(2.0, 0.5), FeatureId::Vertex(3));
+}
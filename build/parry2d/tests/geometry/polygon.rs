@@ -0,0 +1,166 @@
+use approx::assert_relative_eq;
+use na::{Isometry2, Point2, Vector2};
+use parry2d::query::{self, PointQuery, Ray, RayCast};
+use parry2d::shape::{Ball, Polygon, Shape};
+
+fn square(half_extent: f32) -> Vec<Point2<f32>> {
+    vec![
+        Point2::new(-half_extent, -half_extent),
+        Point2::new(half_extent, -half_extent),
+        Point2::new(half_extent, half_extent),
+        Point2::new(-half_extent, half_extent),
+    ]
+}
+
+#[test]
+fn polygon_with_hole() {
+    // The hole is given counterclockwise and gets reoriented.
+    let polygon = Polygon::new(square(2.0), vec![square(1.0)]).unwrap();
+    assert_eq!(polygon.num_holes(), 1);
+    assert_eq!(polygon.triangulation().num_triangles(), 8);
+    assert_relative_eq!(polygon.area(), 12.0);
+    assert_relative_eq!(polygon.surface_area(), 24.0);
+
+    let mprops = polygon.mass_properties(2.0);
+    assert_relative_eq!(1.0 / mprops.inv_mass, 24.0, epsilon = 1.0e-5);
+    assert_relative_eq!(mprops.local_com, Point2::origin(), epsilon = 1.0e-5);
+
+    assert!(polygon.contains_local_point(&Point2::new(1.5, 0.3)));
+    assert!(!polygon.contains_local_point(&Point2::new(0.5, 0.3)));
+    assert!(!polygon.contains_local_point(&Point2::new(2.5, 0.3)));
+
+    // A point inside of the hole is projected on the hole boundary.
+    let proj = polygon.project_local_point(&Point2::new(0.5, 0.3), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 0.3));
+
+    // A ray from the hole hits its boundary, with a normal pointing toward the hole.
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    let hit = polygon
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.0);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+
+    // From inside of the polygon, a solid ray hits immediately.
+    let ray = Ray::new(Point2::new(1.5, 0.0), Vector2::x());
+    assert_eq!(polygon.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+    let hit = polygon
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+}
+
+#[test]
+fn concave_polygon_queries() {
+    // A U-shaped polygon, given clockwise.
+    let mut outer = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(3.0, 3.0),
+        Point2::new(2.0, 3.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 3.0),
+        Point2::new(0.0, 3.0),
+    ];
+    outer.reverse();
+    let polygon = Polygon::new(outer, vec![]).unwrap();
+    assert_eq!(polygon.num_holes(), 0);
+    assert_eq!(polygon.triangulation().num_triangles(), 6);
+    assert_relative_eq!(polygon.area(), 7.0);
+
+    // The notch isn't part of the polygon.
+    assert!(polygon.contains_local_point(&Point2::new(0.5, 2.5)));
+    assert!(!polygon.contains_local_point(&Point2::new(1.5, 2.5)));
+    assert_relative_eq!(
+        polygon.distance_to_local_point(&Point2::new(1.5, 2.5), true),
+        0.5
+    );
+
+    // A ray crossing the notch hits its far side.
+    let ray = Ray::new(Point2::new(0.5, 2.5), Vector2::x());
+    let hit = polygon
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+
+    let ray = Ray::new(Point2::new(1.5, 2.5), Vector2::x());
+    let hit = polygon
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+    assert!(polygon.cast_local_ray(&ray, 0.4, true).is_none());
+
+    // Pairwise queries run on the triangulation.
+    let ball = Ball::new(0.4);
+    let pos = Isometry2::translation(1.5, 2.5);
+    assert!(!query::intersection_test(&Isometry2::identity(), &polygon, &pos, &ball).unwrap());
+    let pos = Isometry2::translation(1.5, 1.2);
+    assert!(query::intersection_test(&Isometry2::identity(), &polygon, &pos, &ball).unwrap());
+}
+
+#[test]
+fn polygon_with_several_holes() {
+    let outer = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(10.0, 0.0),
+        Point2::new(10.0, 4.0),
+        Point2::new(5.0, 2.0),
+        Point2::new(0.0, 4.0),
+    ];
+    let hole1 = vec![
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(2.0, 2.0),
+    ];
+    let hole2 = vec![
+        Point2::new(8.0, 1.0),
+        Point2::new(9.0, 1.0),
+        Point2::new(9.0, 2.0),
+        Point2::new(8.0, 2.0),
+    ];
+    let polygon = Polygon::new(outer, vec![hole1, hole2]).unwrap();
+    assert_eq!(polygon.num_holes(), 2);
+    assert_eq!(polygon.triangulation().num_triangles(), 5 + 3 + 4 + 2);
+    assert_relative_eq!(polygon.area(), 30.0 - 0.5 - 1.0, epsilon = 1.0e-5);
+    assert!(polygon.contains_local_point(&Point2::new(5.0, 1.0)));
+    assert!(!polygon.contains_local_point(&Point2::new(5.0, 3.0)));
+    assert!(!polygon.contains_local_point(&Point2::new(8.5, 1.5)));
+
+    // The mirrored and scaled polygons keep the same area.
+    let mirrored = polygon.mirrored(0).unwrap();
+    assert_relative_eq!(mirrored.volume(), polygon.area(), epsilon = 1.0e-4);
+    assert!(mirrored.contains_local_point(&Point2::new(-5.0, 1.0)));
+    let scaled = polygon.scaled(&Vector2::new(2.0, 0.5), 0).unwrap();
+    assert_relative_eq!(scaled.volume(), polygon.area(), epsilon = 1.0e-4);
+}
+
+#[test]
+fn invalid_polygons() {
+    let bowtie = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+    ];
+    assert!(Polygon::new(bowtie, vec![]).is_none());
+
+    let flat = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+    ];
+    assert!(Polygon::new(flat, vec![]).is_none());
+
+    // The hole overlaps the outer boundary.
+    let hole = vec![
+        Point2::new(1.0, 1.0),
+        Point2::new(3.0, 1.0),
+        Point2::new(3.0, 1.5),
+    ];
+    assert!(Polygon::new(square(2.0), vec![hole]).is_none());
+}
@@ -0,0 +1,73 @@
+use na::{Point2, Vector2};
+use parry2d::shape::Polyline;
+
+#[test]
+fn polyline_circle_vertices_lie_on_circle() {
+    let circle = Polyline::circle(2.0, 32);
+    assert_eq!(circle.vertices().len(), 32);
+    assert_eq!(circle.num_segments(), 32);
+
+    for pt in circle.vertices() {
+        assert_relative_eq!(pt.coords.norm(), 2.0, epsilon = 1.0e-5);
+    }
+
+    // The polyline is closed.
+    assert_eq!(circle.indices()[31], [31, 0]);
+}
+
+#[test]
+fn polyline_rectangle() {
+    let rect = Polyline::rectangle(Vector2::new(1.0, 2.0));
+    assert_eq!(rect.num_segments(), 4);
+    assert_eq!(rect.local_aabb().half_extents(), Vector2::new(1.0, 2.0));
+}
+
+#[test]
+fn polyline_append_arc_is_continuous() {
+    let mut polyline = Polyline::new(vec![Point2::new(-1.0, -1.0), Point2::new(1.0, -1.0)], None);
+
+    // Quarter arc starting exactly at the tail of the polyline.
+    polyline.append_arc(
+        Point2::new(1.0, 0.0),
+        1.0,
+        -std::f32::consts::FRAC_PI_2,
+        0.0,
+        8,
+    );
+    assert_eq!(polyline.vertices().len(), 10);
+    assert_eq!(polyline.num_segments(), 9);
+
+    for segs in polyline.indices().windows(2) {
+        assert_eq!(segs[0][1], segs[1][0]);
+    }
+
+    let last = polyline.vertices()[*polyline.indices().last().unwrap().last().unwrap() as usize];
+    assert_relative_eq!(last, Point2::new(2.0, 0.0), epsilon = 1.0e-5);
+
+    // An arc starting away from the tail gets connected to it by a segment.
+    polyline.append_arc(Point2::new(0.0, 3.0), 1.0, 0.0, 1.0, 4);
+    assert_eq!(polyline.vertices().len(), 15);
+    assert_eq!(polyline.indices()[9], [9, 10]);
+    assert_relative_eq!(polyline.vertices()[10], Point2::new(1.0, 3.0));
+}
+
+#[test]
+fn polyline_append_arc_to_empty_polyline() {
+    let mut polyline = Polyline::new(vec![], None);
+    polyline.append_arc(Point2::origin(), 1.0, 0.0, std::f32::consts::PI, 4);
+    assert_eq!(polyline.vertices().len(), 5);
+    assert_eq!(polyline.indices(), &[[0, 1], [1, 2], [2, 3], [3, 4]]);
+    assert_relative_eq!(polyline.vertices()[0], Point2::new(1.0, 0.0));
+    assert_relative_eq!(
+        polyline.vertices()[4],
+        Point2::new(-1.0, 0.0),
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+#[should_panic]
+fn polyline_append_arc_rejects_zero_subdivisions() {
+    let mut polyline = Polyline::new(vec![Point2::origin()], None);
+    polyline.append_arc(Point2::origin(), 1.0, 0.0, 1.0, 0);
+}
@@ -0,0 +1,95 @@
+use na::{Point2, Vector2};
+use parry2d::shape::{LoopOrientation, Polyline};
+
+#[test]
+fn polyline_loops_and_orientation() {
+    let ccw = Polyline::rectangle(Vector2::new(2.0, 1.0));
+    let loops = ccw.loops();
+    assert_eq!(loops, vec![vec![0, 1, 2, 3]]);
+    assert_eq!(
+        ccw.loop_orientation(&loops[0]),
+        Some(LoopOrientation::CounterClockwise)
+    );
+
+    let reversed: Vec<_> = ccw.indices().iter().map(|idx| [idx[1], idx[0]]).collect();
+    let cw = Polyline::new(ccw.vertices().to_vec(), Some(reversed));
+    let loops = cw.loops();
+    assert_eq!(loops.len(), 1);
+    assert_eq!(
+        cw.loop_orientation(&loops[0]),
+        Some(LoopOrientation::Clockwise)
+    );
+
+    // An open line strip isn't a loop.
+    let strip = Polyline::new(
+        vec![
+            Point2::origin(),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+        ],
+        None,
+    );
+    assert!(strip.loops().is_empty());
+}
+
+#[test]
+fn polyline_connected_components() {
+    // A clockwise triangle, an open strip, and a counterclockwise square, with interleaved
+    // segments and an unused vertex.
+    let vertices = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(5.0, 5.0),
+        Point2::new(6.0, 5.0),
+        Point2::new(7.0, 6.0),
+        Point2::new(100.0, 100.0),
+        Point2::new(10.0, 0.0),
+        Point2::new(11.0, 0.0),
+        Point2::new(11.0, 1.0),
+        Point2::new(10.0, 1.0),
+    ];
+    let indices = vec![
+        [0, 1],
+        [7, 8],
+        [3, 4],
+        [1, 2],
+        [8, 9],
+        [9, 10],
+        [4, 5],
+        [2, 0],
+        [10, 7],
+    ];
+    let polyline = Polyline::new(vertices, Some(indices));
+
+    let mut loops = polyline.loops();
+    loops.sort();
+    assert_eq!(loops, vec![vec![0, 3, 7], vec![1, 4, 5, 8]]);
+    assert_eq!(
+        polyline.loop_orientation(&loops[0]),
+        Some(LoopOrientation::Clockwise)
+    );
+    assert_eq!(
+        polyline.loop_orientation(&loops[1]),
+        Some(LoopOrientation::CounterClockwise)
+    );
+
+    let components = polyline.extract_connected_components();
+    assert_eq!(components.len(), 3);
+
+    assert_eq!(components[0].num_segments(), 3);
+    assert_eq!(components[0].vertices().len(), 3);
+    assert_eq!(components[0].loops().len(), 1);
+
+    assert_eq!(components[1].num_segments(), 4);
+    assert_eq!(components[1].vertices()[0], Point2::new(10.0, 0.0));
+    assert_eq!(components[1].indices(), &[[0, 1], [1, 2], [2, 3], [3, 0]]);
+
+    assert_eq!(components[2].num_segments(), 2);
+    assert_eq!(components[2].vertices().len(), 3);
+    assert!(components[2].loops().is_empty());
+
+    assert!(Polyline::new(vec![], None)
+        .extract_connected_components()
+        .is_empty());
+}
@@ -0,0 +1,65 @@
+use na::{Isometry2, Point2, Vector2};
+use parry2d::query::{self, PointQuery, Ray, RayCast};
+use parry2d::shape::{Ball, Polyline, RoundPolyline, Shape, SharedShape};
+
+#[test]
+fn round_polyline_is_dilated_by_its_border_radius() {
+    let polyline = Polyline::new(
+        vec![
+            Point2::new(-2.0, 1.0),
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 1.0),
+        ],
+        None,
+    );
+    let round = RoundPolyline::new(polyline, 0.25);
+
+    let aabb = round.local_aabb();
+    assert_relative_eq!(aabb.mins, Point2::new(-2.25, -0.25));
+    assert_relative_eq!(aabb.maxs, Point2::new(2.25, 1.25));
+
+    let ray = Ray::new(Point2::new(0.0, -3.0), Vector2::y());
+    let hit = round
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.75, epsilon = 1.0e-3);
+    assert_relative_eq!(hit.normal, -Vector2::y(), epsilon = 1.0e-3);
+
+    let proj = round.project_local_point(&Point2::new(0.0, -1.0), true);
+    assert_relative_eq!(proj.point, Point2::new(0.0, -0.25), epsilon = 1.0e-3);
+    assert!(round.contains_local_point(&Point2::new(0.0, -0.2)));
+    assert!(!round.contains_local_point(&Point2::new(0.0, -0.3)));
+}
+
+#[test]
+fn round_polyline_ball_contact() {
+    let wall = SharedShape::round_polyline(
+        vec![Point2::new(-2.0, 0.0), Point2::new(2.0, 0.0)],
+        None,
+        0.1,
+    );
+    assert!(wall.as_round_polyline().is_some());
+
+    let ball = Ball::new(0.5);
+    let pos_wall = Isometry2::identity();
+
+    let pos_ball = Isometry2::translation(0.5, 1.0);
+    let dist = query::distance(&pos_wall, &*wall, &pos_ball, &ball).unwrap();
+    assert_relative_eq!(dist, 0.4, epsilon = 1.0e-5);
+
+    let pos_ball = Isometry2::translation(0.5, 0.55);
+    let contact = query::contact(&pos_wall, &*wall, &pos_ball, &ball, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-5);
+    assert_relative_eq!(*contact.normal1, Vector2::y(), epsilon = 1.0e-5);
+}
+
+#[test]
+fn empty_round_polyline_queries() {
+    let round = RoundPolyline::new(Polyline::new(Vec::new(), None), 0.1);
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    assert!(round.cast_local_ray(&ray, f32::MAX, true).is_none());
+    assert!(!round.contains_local_point(&Point2::origin()));
+    assert_eq!(round.num_features(), (0, 0, 0));
+}
@@ -0,0 +1,70 @@
+use na::{Point2, Vector2};
+use parry2d::query::PointQuery;
+use parry2d::shape::{Ball, Cuboid, Polyline, TriMesh};
+use parry2d::transformation::{sample_surface, sample_volume};
+
+// A small linear congruential generator, enough for deterministic tests.
+fn lcg(mut state: u32) -> impl FnMut() -> f32 {
+    move || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (state >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+#[test]
+fn surface_samples_lie_on_shapes_boundary() {
+    let ball = Ball::new(1.5);
+    let points = sample_surface(&ball, 200, &mut lcg(0)).unwrap();
+    assert!(points
+        .iter()
+        .all(|pt| (pt.coords.norm() - 1.5).abs() < 1.0e-4));
+
+    let polyline = Polyline::new(
+        vec![
+            Point2::origin(),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 3.0),
+        ],
+        None,
+    );
+    let points = sample_surface(&polyline, 1000, &mut lcg(1)).unwrap();
+    let on_second = points.iter().filter(|pt| pt.y > 1.0e-5).count();
+    // The second segment accounts for 3 / 4 of the total length.
+    assert!((on_second as f32 / 1000.0 - 0.75).abs() < 0.05);
+
+    for pt in &points {
+        let proj = polyline.project_local_point(pt, false);
+        assert!(na::distance(pt, &proj.point) < 1.0e-4);
+    }
+}
+
+#[test]
+fn surface_samples_of_trimesh_lie_on_its_boundary() {
+    let trimesh = TriMesh::new(
+        vec![
+            Point2::origin(),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ],
+        vec![[0, 1, 2], [0, 2, 3]],
+    );
+    let points = sample_surface(&trimesh, 200, &mut lcg(2)).unwrap();
+
+    // The inner diagonal must never be sampled.
+    for pt in &points {
+        let on_boundary = pt.x.abs() < 1.0e-5
+            || pt.y.abs() < 1.0e-5
+            || (pt.x - 1.0).abs() < 1.0e-5
+            || (pt.y - 1.0).abs() < 1.0e-5;
+        assert!(on_boundary, "{:?} isn't on the boundary", pt);
+    }
+}
+
+#[test]
+fn volume_samples_are_contained() {
+    let cuboid = Cuboid::new(Vector2::new(2.0, 0.5));
+    let points = sample_volume(&cuboid, 500, &mut lcg(3)).unwrap();
+    assert_eq!(points.len(), 500);
+    assert!(points.iter().all(|pt| cuboid.contains_local_point(pt)));
+}
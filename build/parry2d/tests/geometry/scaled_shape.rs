@@ -0,0 +1,38 @@
+use na::{Point2, Vector2};
+use parry2d::math::Real;
+use parry2d::query::{PointQuery, Ray, RayCast};
+use parry2d::shape::{Cuboid, ScaledShape, Shape};
+
+#[test]
+fn scaled_cuboid_is_a_cuboid() {
+    let scale = Vector2::new(3.0, 0.5);
+    let scaled = ScaledShape::new(Cuboid::new(Vector2::new(1.0, 2.0)), scale);
+    let cuboid = Cuboid::new(Vector2::new(3.0, 1.0));
+
+    let ray = Ray::new(Point2::new(-5.0, 4.0), Vector2::new(1.0, -1.0));
+    let hit = scaled
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    let expected = cuboid
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, expected.toi, epsilon = 1.0e-5);
+    assert_relative_eq!(hit.normal, expected.normal, epsilon = 1.0e-5);
+
+    let pt = Point2::new(4.0, 2.0);
+    assert_relative_eq!(
+        scaled.project_local_point(&pt, true).point,
+        Point2::new(3.0, 1.0),
+        epsilon = 1.0e-5
+    );
+    assert!(scaled.contains_local_point(&Point2::new(2.9, -0.9)));
+
+    let mprops = scaled.mass_properties(2.0);
+    let expected = cuboid.mass_properties(2.0);
+    assert_relative_eq!(mprops.inv_mass, expected.inv_mass, max_relative = 1.0e-3);
+    assert_relative_eq!(
+        mprops.inv_principal_inertia_sqrt,
+        expected.inv_principal_inertia_sqrt,
+        max_relative = 1.0e-2
+    );
+}
@@ -0,0 +1,116 @@
+use na::{Isometry2, Point2, Vector2};
+use parry2d::query::{PointQuery, Ray, RayCast};
+use parry2d::shape::{Sector, Shape};
+use parry2d::transformation;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn sector_point_queries() {
+    // The quarter disk in the first quadrant.
+    let sector = Sector::new(2.0, 0.0, FRAC_PI_2);
+
+    assert!(sector.contains_local_point(&Point2::new(1.0, 1.0)));
+    assert!(!sector.contains_local_point(&Point2::new(-0.5, 1.0)));
+    assert!(!sector.contains_local_point(&Point2::new(1.5, 1.5)));
+
+    // Outside, closest to the arc.
+    let proj = sector.project_local_point(&Point2::new(3.0, 3.0), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(2.0f32.sqrt(), 2.0f32.sqrt()));
+
+    // Outside, closest to an edge.
+    let proj = sector.project_local_point(&Point2::new(-1.0, 1.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(0.0, 1.5));
+
+    // Outside, closest to the center.
+    let proj = sector.project_local_point(&Point2::new(-1.0, -1.0), true);
+    assert_relative_eq!(proj.point, Point2::origin());
+
+    // Inside, closest to an edge.
+    let pt = Point2::new(1.0, 0.2);
+    assert_eq!(sector.project_local_point(&pt, true).point, pt);
+    let proj = sector.project_local_point(&pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 0.0));
+    assert_relative_eq!(sector.distance_to_local_point(&pt, false), -0.2);
+}
+
+#[test]
+fn sector_ray_cast() {
+    // The disk without its fourth quadrant.
+    let sector = Sector::new(1.0, 0.0, 3.0 * FRAC_PI_2);
+
+    // Hitting the arc.
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+    let hit = sector
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.0);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+
+    // Hitting the edge at `end_angle` through the missing quadrant.
+    let ray = Ray::new(Point2::new(5.0, -0.5), -Vector2::x());
+    let hit = sector
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 5.0);
+    assert_relative_eq!(hit.normal, Vector2::x());
+
+    // Hitting the edge at `start_angle` from inside the missing quadrant.
+    let ray = Ray::new(Point2::new(0.5, -0.5), Vector2::y());
+    let hit = sector
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, -Vector2::y());
+
+    // From the inside.
+    let ray = Ray::new(Point2::new(-0.5, 0.0), -Vector2::x());
+    assert_eq!(sector.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+    let hit = sector
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, Vector2::x());
+
+    // Stopped by `max_toi`, and missing the sector.
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+    assert!(sector.cast_local_ray(&ray, 3.9, true).is_none());
+    let ray = Ray::new(Point2::new(2.0, -0.5), Vector2::x());
+    assert!(sector.cast_local_ray(&ray, f32::MAX, true).is_none());
+}
+
+#[test]
+fn sector_aabb() {
+    let sector = Sector::new(2.0, 0.0, FRAC_PI_2);
+    let aabb = sector.local_aabb();
+    assert_relative_eq!(aabb.mins, Point2::origin());
+    assert_relative_eq!(aabb.maxs, Point2::new(2.0, 2.0));
+
+    let pos = Isometry2::new(Vector2::new(1.0, 0.0), PI);
+    let aabb = sector.aabb(&pos);
+    assert_relative_eq!(aabb.mins, Point2::new(-1.0, -2.0), epsilon = 1.0e-6);
+    assert_relative_eq!(aabb.maxs, Point2::new(1.0, 0.0), epsilon = 1.0e-6);
+}
+
+#[test]
+fn sector_mass_properties() {
+    for (start, end) in [(0.0, FRAC_PI_2), (-0.5, 2.5), (1.0, 1.0 + 1.5 * PI)].iter() {
+        let sector = Sector::new(1.0, *start, *end);
+        let mprops = sector.mass_properties(2.0);
+        let voxelized = transformation::mass_properties_by_voxelization(&sector, 2.0, 0.005);
+
+        assert_relative_eq!(
+            1.0 / mprops.inv_mass,
+            1.0 / voxelized.inv_mass,
+            max_relative = 1.0e-2
+        );
+        assert_relative_eq!(mprops.local_com, voxelized.local_com, epsilon = 1.0e-2);
+        assert_relative_eq!(
+            mprops.inv_principal_inertia_sqrt,
+            voxelized.inv_principal_inertia_sqrt,
+            max_relative = 1.0e-2
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use approx::assert_relative_eq;
+use na::{Isometry2, Point2, Vector2};
+use parry2d::math::Real;
+use parry2d::shape::{
+    Annulus, Ball, Capsule, Compound, ConvexPolygon, Cuboid, HalfSpace, Polyline, Sector, Shape,
+    SharedShape, Triangle, Voxels,
+};
+use std::f32::consts::PI;
+
+// The signed area enclosed by the segments of a polyline, positive if its loops are oriented
+// counterclockwise around the interior.
+fn enclosed_area(vertices: &[Point2<Real>], indices: &[[u32; 2]]) -> Real {
+    indices
+        .iter()
+        .map(|idx| {
+            let a = vertices[idx[0] as usize].coords;
+            let b = vertices[idx[1] as usize].coords;
+            a.perp(&b) / 2.0
+        })
+        .sum()
+}
+
+#[test]
+fn discretized_shapes_enclose_their_area() {
+    let square = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Ball::new(1.5)),
+        Box::new(Cuboid::new(Vector2::new(1.0, 2.0))),
+        Box::new(Capsule::new(
+            Point2::new(0.0, -1.0),
+            Point2::new(0.0, 1.0),
+            0.5,
+        )),
+        // A clockwise triangle.
+        Box::new(Triangle::new(
+            Point2::origin(),
+            Point2::new(0.0, 1.0),
+            Point2::new(1.0, 0.0),
+        )),
+        Box::new(ConvexPolygon::from_convex_hull(&square).unwrap()),
+        Box::new(Annulus::new(1.0, 2.0)),
+        Box::new(Sector::new(1.0, 0.5, 2.0)),
+        Box::new(Voxels::new(
+            Vector2::new(0.5, 1.0),
+            &[Point2::new(0, 0), Point2::new(1, 0), Point2::new(3, 2)],
+        )),
+    ];
+
+    for shape in &shapes {
+        let (vertices, indices) = shape.to_polyline(256).unwrap();
+        let area = enclosed_area(&vertices, &indices);
+        assert_relative_eq!(area, shape.volume(), max_relative = 0.01);
+    }
+
+    // A full disk isn't closed by the center of the sector.
+    let disk: &dyn Shape = &Sector::new(1.0, 0.0, 2.0 * PI);
+    let (vertices, indices) = disk.to_polyline(32).unwrap();
+    assert_eq!(vertices.len(), 32);
+    assert_eq!(indices.len(), 32);
+}
+
+#[test]
+fn open_and_unbounded_shapes_discretization() {
+    let polyline = Polyline::new(
+        vec![
+            Point2::origin(),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+        ],
+        None,
+    );
+    let (vertices, indices) = polyline.to_polyline(16).unwrap();
+    assert_eq!(vertices, polyline.vertices());
+    assert_eq!(indices, polyline.indices());
+
+    let halfspace = HalfSpace::new(Vector2::y_axis());
+    assert!(halfspace.to_polyline(16).is_none());
+
+    let compound = Compound::new(vec![
+        (
+            Isometry2::translation(3.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0),
+        ),
+        (
+            Isometry2::identity(),
+            SharedShape::segment(Point2::origin(), Point2::new(1.0, 1.0)),
+        ),
+    ]);
+    let (vertices, indices) = compound.to_polyline(16).unwrap();
+    assert_eq!(vertices.len(), 6);
+    assert_eq!(indices.len(), 5);
+    assert_relative_eq!(vertices[0], Point2::new(2.0, -1.0));
+}
@@ -0,0 +1,19 @@
+use na::Vector2;
+use parry2d::shape::{Ball, Shape};
+
+#[test]
+fn non_uniformly_scaled_ball_is_a_convex_polygon() {
+    let ball = Ball::new(0.5);
+    let scaled = ball.scaled(&Vector2::repeat(2.0), 32).unwrap();
+    assert_eq!(scaled.as_ball().unwrap().radius, 1.0);
+
+    let scaled = ball.scaled(&Vector2::new(2.0, 1.0), 64).unwrap();
+    let polygon = scaled.as_convex_polygon().unwrap();
+    let aabb = polygon.local_aabb();
+    assert_relative_eq!(aabb.maxs.x, 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.maxs.y, 0.5, epsilon = 1.0e-5);
+
+    // The area of the ellipse, up to the discretization error.
+    let area = 1.0 / polygon.mass_properties(1.0).inv_mass;
+    assert_relative_eq!(area, std::f32::consts::PI * 0.5, max_relative = 0.01);
+}
@@ -0,0 +1,36 @@
+use na::Point2;
+use parry2d::mass_properties::MassProperties;
+use parry2d::shape::{Shape, TaperedCapsule};
+use parry2d::transformation;
+
+#[test]
+fn tapered_capsule_mass_properties() {
+    let shapes = [
+        TaperedCapsule::new(Point2::origin(), Point2::new(0.0, 1.0), 0.5, 0.5),
+        TaperedCapsule::new(Point2::new(0.1, -0.2), Point2::new(1.0, 0.5), 0.6, 0.2),
+        TaperedCapsule::new(Point2::origin(), Point2::new(-1.0, 0.0), 0.1, 0.7),
+    ];
+
+    for shape in &shapes {
+        let mprops = shape.mass_properties(2.0);
+        let voxelized = transformation::mass_properties_by_voxelization(shape, 2.0, 0.01);
+
+        assert_relative_eq!(
+            1.0 / mprops.inv_mass,
+            1.0 / voxelized.inv_mass,
+            max_relative = 1.0e-2
+        );
+        assert_relative_eq!(mprops.local_com, voxelized.local_com, epsilon = 1.0e-2);
+        assert_relative_eq!(
+            mprops.inv_principal_inertia_sqrt,
+            voxelized.inv_principal_inertia_sqrt,
+            max_relative = 1.0e-2
+        );
+    }
+
+    // One ball contains the other.
+    let shape = TaperedCapsule::new(Point2::origin(), Point2::new(1.0, 0.0), 0.2, 1.5);
+    let mut ball = MassProperties::from_ball(2.0, 1.5);
+    ball.local_com = Point2::new(1.0, 0.0);
+    assert_relative_eq!(shape.mass_properties(2.0), ball);
+}
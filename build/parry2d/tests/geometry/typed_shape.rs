@@ -0,0 +1,14 @@
+use na::Point2;
+use parry2d::shape::{SharedShape, TypedShape};
+
+#[test]
+fn typed_shape_matches_the_concrete_type() {
+    let annulus = SharedShape::annulus(0.5, 1.0);
+    match annulus.as_typed_shape() {
+        TypedShape::Annulus(a) => assert_eq!(a.outer_radius, 1.0),
+        _ => panic!("Expected an annulus."),
+    }
+
+    let capsule = SharedShape::capsule(Point2::origin(), Point2::new(0.0, 1.0), 0.5);
+    assert!(matches!(capsule.as_typed_shape(), TypedShape::Capsule(_)));
+}
@@ -0,0 +1,67 @@
+use approx::assert_relative_eq;
+use na::Point2;
+use parry2d::shape::{Ball, Capsule, Polyline, Sector, Shape, SharedShape, TriMesh};
+use std::f32::consts::PI;
+
+#[test]
+fn primitive_areas_and_perimeters() {
+    let ball = Ball::new(2.0);
+    assert_relative_eq!(ball.volume(), 4.0 * PI, epsilon = 1.0e-4);
+    assert_relative_eq!(ball.surface_area(), 4.0 * PI, epsilon = 1.0e-4);
+
+    let capsule = Capsule::new(Point2::new(0.0, -1.0), Point2::new(0.0, 1.0), 0.5);
+    assert_relative_eq!(capsule.surface_area(), 4.0 + PI, epsilon = 1.0e-4);
+
+    let sector = Sector::new(1.0, 0.0, PI / 2.0);
+    assert_relative_eq!(sector.surface_area(), 2.0 + PI / 2.0, epsilon = 1.0e-4);
+
+    let polyline = Polyline::new(
+        vec![
+            Point2::origin(),
+            Point2::new(3.0, 0.0),
+            Point2::new(3.0, 4.0),
+        ],
+        None,
+    );
+    assert_eq!(polyline.volume(), 0.0);
+    assert_relative_eq!(polyline.surface_area(), 7.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn trimesh_perimeter_ignores_inner_edges() {
+    let trimesh = TriMesh::new(
+        vec![
+            Point2::origin(),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ],
+        vec![[0, 1, 2], [0, 2, 3]],
+    );
+    assert_relative_eq!(trimesh.volume(), 4.0, epsilon = 1.0e-4);
+    assert_relative_eq!(trimesh.surface_area(), 8.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn round_shape_measures() {
+    let round_cuboid = SharedShape::round_cuboid(1.0, 2.0, 0.5);
+    assert_relative_eq!(
+        round_cuboid.volume(),
+        8.0 + 12.0 * 0.5 + PI * 0.25,
+        epsilon = 1.0e-4
+    );
+    assert_relative_eq!(round_cuboid.surface_area(), 12.0 + PI, epsilon = 1.0e-4);
+
+    let square = [
+        Point2::new(-1.0, -2.0),
+        Point2::new(1.0, -2.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(-1.0, 2.0),
+    ];
+    let round_polygon = SharedShape::round_convex_hull(&square, 0.5).unwrap();
+    assert_relative_eq!(
+        round_polygon.volume(),
+        round_cuboid.volume(),
+        epsilon = 1.0e-4
+    );
+}
@@ -0,0 +1,40 @@
+use na::{Isometry2, Point2, Vector2};
+use parry2d::math::Real;
+use parry2d::query::{PointQuery, Ray, RayCast};
+use parry2d::shape::{Cuboid, Shape, Voxels};
+
+#[test]
+fn voxels2_queries() {
+    // An L-shaped set of voxels.
+    let keys = [Point2::new(0, 0), Point2::new(1, 0), Point2::new(0, 1)];
+    let voxels = Voxels::new(Vector2::repeat(1.0), &keys);
+
+    let ray = Ray::new(Point2::new(3.0, 1.5), -Vector2::x());
+    let hit = voxels
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, Vector2::x());
+
+    // Along the diagonal, hitting the inner corner.
+    let ray = Ray::new(Point2::new(2.5, 2.5), Vector2::new(-1.0, -1.0));
+    assert_relative_eq!(voxels.cast_local_ray(&ray, Real::MAX, true).unwrap(), 1.5);
+
+    let proj = voxels.project_local_point(&Point2::new(1.5, 1.8), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 1.8));
+
+    let proj = voxels.project_local_point(&Point2::new(0.8, 0.9), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 1.0));
+}
+
+#[test]
+fn voxels2_mass_properties() {
+    let keys: Vec<_> = (0..6).map(|i| Point2::new(i % 3 - 1, i / 3 - 1)).collect();
+    let voxels = Voxels::new(Vector2::new(0.5, 2.0), &keys);
+    let expected = Cuboid::new(Vector2::new(0.75, 2.0))
+        .mass_properties(3.0)
+        .transform_by(&Isometry2::translation(0.25, 0.0));
+    assert_relative_eq!(voxels.mass_properties(3.0), expected, epsilon = 1.0e-4);
+}
@@ -1,3 +1,4 @@
 mod algorithm;
 mod contacts;
 mod ray;
+mod sweep_and_prune;
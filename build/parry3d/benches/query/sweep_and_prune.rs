@@ -0,0 +1,53 @@
+use na::{Point3, Vector3};
+use parry3d::bounding_volume::{BoundingVolume, AABB};
+use parry3d::query;
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+use test::Bencher;
+
+fn generate_aabbs(n: u32) -> Vec<(u32, AABB)> {
+    let mut rng = IsaacRng::seed_from_u64(0);
+    (0..n)
+        .map(|i| {
+            let center = Point3::from(rng.gen::<Vector3<f32>>() * 100.0);
+            let half_extents = rng.gen::<Vector3<f32>>();
+            (i, AABB::from_half_extents(center, half_extents))
+        })
+        .collect()
+}
+
+fn brute_force(aabbs: &[(u32, AABB)]) -> Vec<(u32, u32)> {
+    let mut result = Vec::new();
+    for i in 0..aabbs.len() {
+        for j in i + 1..aabbs.len() {
+            if aabbs[i].1.intersects(&aabbs[j].1) {
+                result.push((aabbs[i].0, aabbs[j].0));
+            }
+        }
+    }
+    result
+}
+
+#[bench]
+fn bench_sweep_and_prune_1000(bh: &mut Bencher) {
+    let aabbs = generate_aabbs(1000);
+    bh.iter(|| test::black_box(query::sweep_and_prune(&aabbs)))
+}
+
+#[bench]
+fn bench_brute_force_1000(bh: &mut Bencher) {
+    let aabbs = generate_aabbs(1000);
+    bh.iter(|| test::black_box(brute_force(&aabbs)))
+}
+
+#[bench]
+fn bench_sweep_and_prune_10000(bh: &mut Bencher) {
+    let aabbs = generate_aabbs(10000);
+    bh.iter(|| test::black_box(query::sweep_and_prune(&aabbs)))
+}
+
+#[bench]
+fn bench_brute_force_10000(bh: &mut Bencher) {
+    let aabbs = generate_aabbs(10000);
+    bh.iter(|| test::black_box(brute_force(&aabbs)))
+}
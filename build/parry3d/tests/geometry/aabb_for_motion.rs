@@ -0,0 +1,19 @@
+use na::{Isometry3, Vector3};
+use parry3d::bounding_volume::BoundingVolume;
+use parry3d::shape::{Cuboid, Shape};
+
+#[test]
+fn aabb_for_motion_contains_both_poses() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 0.25));
+    let start = Isometry3::new(Vector3::new(-1.0, 2.0, 0.0), Vector3::new(0.1, 0.2, 0.3));
+    let end = Isometry3::new(Vector3::new(3.0, 1.0, -2.0), Vector3::new(0.4, -0.2, 0.1));
+    let margin = 0.1;
+
+    let aabb = cuboid.compute_aabb_for_motion(&start, &end, margin);
+    let start_aabb = cuboid.compute_aabb(&start).loosened(margin);
+    let end_aabb = cuboid.compute_aabb(&end).loosened(margin);
+
+    assert!(aabb.contains(&start_aabb));
+    assert!(aabb.contains(&end_aabb));
+    assert_eq!(aabb, start_aabb.merged(&end_aabb));
+}
@@ -0,0 +1,77 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::bounding_volume::{BoundingSphere, BoundingVolume, AABB};
+use parry3d::shape::{Ball, Capsule, Cuboid, Cylinder, Shape};
+
+#[test]
+fn ball_local_bounding_sphere() {
+    let ball = Ball::new(1.5);
+    let bs = ball.compute_local_bounding_sphere();
+    assert_eq!(*bs.center(), Point3::origin());
+    assert_relative_eq!(bs.radius(), 1.5);
+}
+
+#[test]
+fn capsule_local_bounding_sphere() {
+    let capsule = Capsule::new(Point3::new(1.0, 2.0, 3.0), Point3::new(1.0, 6.0, 3.0), 0.5);
+    let bs = capsule.compute_local_bounding_sphere();
+    assert_relative_eq!(*bs.center(), Point3::new(1.0, 4.0, 3.0));
+    assert_relative_eq!(bs.radius(), 2.0 + 0.5);
+}
+
+#[test]
+fn cylinder_local_bounding_sphere() {
+    let cylinder = Cylinder::new(2.0, 1.5);
+    let bs = cylinder.compute_local_bounding_sphere();
+    assert_eq!(*bs.center(), Point3::origin());
+    assert_relative_eq!(bs.radius(), (2.0f32 * 2.0 + 1.5 * 1.5).sqrt());
+
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::x());
+    let bs = cylinder.compute_bounding_sphere(&pos);
+    assert_relative_eq!(*bs.center(), Point3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn default_local_bounding_sphere_encloses_aabb() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 2.0));
+    let bs = cuboid.compute_local_bounding_sphere();
+    assert_eq!(*bs.center(), Point3::origin());
+    assert_relative_eq!(bs.radius(), 3.0);
+}
+
+#[test]
+fn bounding_sphere_contains_sphere() {
+    let bs = BoundingSphere::new(Point3::origin(), 2.0);
+
+    let contained = BoundingSphere::new(Point3::new(0.5, 0.0, 0.0), 1.0);
+    let touching = BoundingSphere::new(Point3::new(1.0, 0.0, 0.0), 1.0);
+    let overlapping = BoundingSphere::new(Point3::new(1.5, 0.0, 0.0), 1.0);
+    let disjoint = BoundingSphere::new(Point3::new(5.0, 0.0, 0.0), 1.0);
+
+    assert!(bs.contains(&contained));
+    assert!(bs.contains(&touching));
+    assert!(!bs.contains(&overlapping));
+    assert!(!bs.contains(&disjoint));
+    assert!(!contained.contains(&bs));
+}
+
+#[test]
+fn bounding_sphere_contains_point() {
+    let bs = BoundingSphere::new(Point3::new(1.0, 1.0, 1.0), 2.0);
+
+    assert!(bs.contains_point(&Point3::new(1.0, 1.0, 1.0)));
+    assert!(bs.contains_point(&Point3::new(2.0, 2.0, 1.0)));
+    assert!(bs.contains_point(&Point3::new(3.0, 1.0, 1.0)));
+    assert!(!bs.contains_point(&Point3::new(3.0, 2.0, 1.0)));
+    assert!(!bs.contains_point(&Point3::new(-2.0, 1.0, 1.0)));
+}
+
+#[test]
+fn aabb_bounding_sphere_is_tight() {
+    let aabb = AABB::new(Point3::new(-1.0, 0.0, 1.0), Point3::new(1.0, 2.0, 3.0));
+    let bs = aabb.bounding_sphere();
+    assert_relative_eq!(*bs.center(), Point3::new(0.0, 1.0, 2.0));
+    assert_relative_eq!(bs.radius(), 3.0f32.sqrt());
+
+    // The corners of the AABB lie on the sphere.
+    assert_relative_eq!(na::distance(&aabb.maxs, bs.center()), bs.radius());
+}
@@ -0,0 +1,14 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::shape::Capsule;
+
+#[test]
+fn capsule_world_segment_is_transformed_local_segment() {
+    let capsule = Capsule::new(Point3::new(1.0, 2.0, 3.0), Point3::new(-1.0, 0.5, 2.0), 0.3);
+    let pos = Isometry3::new(Vector3::new(4.0, -2.0, 1.0), Vector3::new(0.3, -1.2, 0.7));
+    let (a, b) = capsule.cap_centers();
+    let world = capsule.world_segment(&pos);
+
+    assert_eq!((a, b), (capsule.segment.a, capsule.segment.b));
+    assert_relative_eq!(world.a, pos * a);
+    assert_relative_eq!(world.b, pos * b);
+}
@@ -0,0 +1,39 @@
+use na::{DMatrix, Point3, Vector3};
+use parry3d::shape::{Cuboid, HeightField, RoundTriMesh, Shape, TriMesh, Triangle};
+
+#[test]
+fn ccd_thickness_is_configurable_in_3d() {
+    let triangle = Triangle::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    );
+    assert_eq!(triangle.ccd_thickness(), 0.0);
+
+    let (vertices, indices) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    let mut trimesh = TriMesh::new(vertices, indices);
+    assert_eq!(trimesh.ccd_thickness(), 0.0);
+    trimesh.set_ccd_thickness(0.5);
+    assert_eq!(trimesh.ccd_thickness(), 0.5);
+
+    // The thickness follows the transformations of the mesh.
+    let scaled = trimesh.scaled(&Vector3::new(2.0, 0.5, 3.0), 10).unwrap();
+    assert_relative_eq!(scaled.ccd_thickness(), 0.25);
+    let mirrored = trimesh.mirrored(0).unwrap();
+    assert_eq!(mirrored.ccd_thickness(), 0.5);
+    trimesh.scale(&Vector3::new(-4.0, 2.0, 3.0));
+    assert_relative_eq!(trimesh.ccd_thickness(), 1.0);
+
+    let round = RoundTriMesh::new(trimesh, 0.1);
+    assert_relative_eq!(round.ccd_thickness(), 1.1);
+
+    let heights = DMatrix::from_fn(4, 5, |i, j| (i + j) as f32 * 0.1);
+    let heightfield = HeightField::new(heights, Vector3::new(10.0, 1.0, 10.0));
+    assert_eq!(heightfield.ccd_thickness(), 0.0);
+    let heightfield = heightfield.with_ccd_thickness(2.0);
+    assert_eq!(heightfield.ccd_thickness(), 2.0);
+
+    let scaled = heightfield.scaled(&Vector3::repeat(0.5), 10).unwrap();
+    assert_relative_eq!(scaled.ccd_thickness(), 1.0);
+    assert_eq!(TriMesh::from(heightfield).ccd_thickness(), 2.0);
+}
@@ -0,0 +1,60 @@
+use na::{Point3, Unit, Vector3};
+use parry3d::query::details::clip_polygon;
+
+fn square(center: Point3<f32>, half_width: f32) -> Vec<Point3<f32>> {
+    vec![
+        center + Vector3::new(-half_width, -half_width, 0.0),
+        center + Vector3::new(half_width, -half_width, 0.0),
+        center + Vector3::new(half_width, half_width, 0.0),
+        center + Vector3::new(-half_width, half_width, 0.0),
+    ]
+}
+
+#[test]
+fn clip_offset_squares() {
+    let reference = square(Point3::origin(), 1.0);
+    let normal = Unit::new_normalize(Vector3::z());
+    let incident = square(Point3::new(1.0, 1.0, -0.1), 1.0);
+
+    let expected = [
+        Point3::new(0.0, 0.0, -0.1),
+        Point3::new(1.0, 0.0, -0.1),
+        Point3::new(1.0, 1.0, -0.1),
+        Point3::new(0.0, 1.0, -0.1),
+    ];
+
+    // The result must not depend on the winding of the reference face.
+    let mut reversed = reference.clone();
+    reversed.reverse();
+
+    for reference in &[reference, reversed] {
+        let clipped = clip_polygon(reference, &normal, &incident);
+        assert_eq!(clipped.len(), 4);
+
+        for pt in &expected {
+            assert!(clipped
+                .iter()
+                .any(|clipped_pt| na::distance(clipped_pt, pt) < 1.0e-5));
+        }
+    }
+}
+
+#[test]
+fn clip_removes_points_in_front_of_reference_face() {
+    let reference = square(Point3::origin(), 1.0);
+    let normal = Unit::new_normalize(Vector3::z());
+
+    let incident = square(Point3::new(1.0, 1.0, 0.1), 1.0);
+    assert!(clip_polygon(&reference, &normal, &incident).is_empty());
+
+    // A tilted incident face only partially behind the reference face.
+    let incident = vec![
+        Point3::new(-0.5, 0.0, -0.2),
+        Point3::new(0.5, 0.0, 0.2),
+        Point3::new(0.5, 0.5, 0.2),
+        Point3::new(-0.5, 0.5, -0.2),
+    ];
+    let clipped = clip_polygon(&reference, &normal, &incident);
+    assert_eq!(clipped.len(), 2);
+    assert!(clipped.iter().all(|pt| pt.x == -0.5 && pt.z == -0.2));
+}
@@ -0,0 +1,33 @@
+use na::Vector3;
+use parry3d::shape::{Ball, Cuboid, Shape, SharedShape};
+
+#[test]
+fn boxed_shapes_can_be_cloned() {
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Ball::new(0.5)),
+        Box::new(Cuboid::new(Vector3::new(1.0, 2.0, 3.0))),
+    ];
+    let clones = shapes.clone();
+
+    assert_eq!(clones[0].as_ball().unwrap().radius, 0.5);
+    assert_eq!(
+        clones[1].as_cuboid().unwrap().half_extents,
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+}
+
+#[test]
+fn shared_shape_make_mut_is_copy_on_write() {
+    let mut shape = SharedShape::ball(0.5);
+    let other = shape.clone();
+
+    shape.make_mut().downcast_mut::<Ball>().unwrap().radius = 1.0;
+    assert_eq!(shape.as_ball().unwrap().radius, 1.0);
+    assert_eq!(other.as_ball().unwrap().radius, 0.5);
+
+    // The shape is no longer shared, so it is modified in-place.
+    let ptr = shape.as_ball().unwrap() as *const Ball;
+    shape.make_mut().downcast_mut::<Ball>().unwrap().radius = 2.0;
+    assert_eq!(shape.as_ball().unwrap() as *const Ball, ptr);
+    assert_eq!(shape.as_ball().unwrap().radius, 2.0);
+}
@@ -0,0 +1,101 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{self, ClosestPoints};
+use parry3d::shape::{Ball, Compound, Cuboid, FeatureId, Segment, SharedShape, TriMesh};
+
+fn closest_points_on(
+    pos1: &Isometry3<Real>,
+    g1: &dyn parry3d::shape::Shape,
+    pos2: &Isometry3<Real>,
+    g2: &dyn parry3d::shape::Shape,
+) -> (Point3<Real>, Point3<Real>, [query::ClosestPointFeature; 2]) {
+    match query::closest_points_with_features(pos1, g1, pos2, g2, 10.0).unwrap() {
+        (ClosestPoints::WithinMargin(pt1, pt2), Some(features)) => (pt1, pt2, features),
+        res => panic!("Unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn closest_points_with_features_segment_segment() {
+    let s1 = Segment::new(Point3::new(-1.0, 0.0, 0.0), Point3::new(3.0, 0.0, 0.0));
+    let s2 = Segment::new(Point3::new(0.0, 1.0, -1.0), Point3::new(0.0, 1.0, 1.0));
+    let identity = Isometry3::identity();
+
+    let (pt1, pt2, [f1, f2]) = closest_points_on(&identity, &s1, &identity, &s2);
+    assert_relative_eq!(pt1, Point3::origin(), epsilon = 1.0e-6);
+    assert_relative_eq!(pt2, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-6);
+    assert_eq!(f1.part_id, None);
+    assert_eq!(f1.feature, FeatureId::Edge(0));
+    assert_relative_eq!(
+        f1.barycentric_coordinates.unwrap()[..],
+        [0.75, 0.25, 0.0][..],
+        epsilon = 1.0e-6
+    );
+    assert_eq!(f2.feature, FeatureId::Edge(0));
+    assert_relative_eq!(
+        f2.barycentric_coordinates.unwrap()[..],
+        [0.5, 0.5, 0.0][..],
+        epsilon = 1.0e-6
+    );
+
+    // The second segment is now closest to the first endpoint of the first segment.
+    let pos2 = Isometry3::translation(-3.0, 0.0, 0.0);
+    let (_, _, [f1, _]) = closest_points_on(&identity, &s1, &pos2, &s2);
+    assert_eq!(f1.feature, FeatureId::Vertex(0));
+    assert_eq!(f1.barycentric_coordinates, Some([1.0, 0.0, 0.0]));
+}
+
+#[test]
+fn closest_points_with_features_ball_trimesh() {
+    let mesh = TriMesh::from(Cuboid::new(Vector3::repeat(1.0)));
+    let ball = Ball::new(0.5);
+    let pos1 = Isometry3::translation(0.3, 3.0, -0.2);
+
+    // The closest points between a ball and a triangle are computed by GJK, which
+    // is only approximate for balls.
+    let (pt1, pt2, [f1, f2]) = closest_points_on(&pos1, &ball, &Isometry3::identity(), &mesh);
+    assert_relative_eq!(pt1, Point3::new(0.3, 2.5, -0.2), epsilon = 1.0e-2);
+    assert_relative_eq!(pt2, Point3::new(0.3, 1.0, -0.2), epsilon = 1.0e-2);
+    assert_eq!(f1.part_id, None);
+    assert_eq!(f1.barycentric_coordinates, None);
+
+    // The barycentric coordinates locate the closest point on the triangle.
+    let id = f2.part_id.unwrap();
+    let tri = mesh.triangle(id);
+    let bcoords = f2.barycentric_coordinates.unwrap();
+    let pt = tri.a * bcoords[0] + tri.b.coords * bcoords[1] + tri.c.coords * bcoords[2];
+    assert_relative_eq!(pt, pt2, epsilon = 1.0e-2);
+    assert_relative_eq!(tri.normal().unwrap().into_inner(), Vector3::y());
+}
+
+#[test]
+fn closest_points_with_features_compound() {
+    let compound = Compound::new(vec![
+        (Isometry3::identity(), SharedShape::cuboid(1.0, 1.0, 1.0)),
+        (
+            Isometry3::translation(5.0, 0.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0, 1.0),
+        ),
+    ]);
+    let ball = Ball::new(0.5);
+    let pos2 = Isometry3::translation(5.2, 0.1, 3.0);
+
+    let (_, _, [f1, f2]) = closest_points_on(&Isometry3::identity(), &compound, &pos2, &ball);
+    assert_eq!(f1.part_id, Some(1));
+    assert_eq!(f1.feature, FeatureId::Face(2));
+    assert_eq!(f2.part_id, None);
+}
+
+#[test]
+fn closest_points_with_features_intersecting() {
+    let ball = Ball::new(1.0);
+    let res = query::closest_points_with_features(
+        &Isometry3::identity(),
+        &ball,
+        &Isometry3::translation(1.0, 0.0, 0.0),
+        &ball,
+        1.0,
+    )
+    .unwrap();
+    assert_eq!(res, (ClosestPoints::Intersecting, None));
+}
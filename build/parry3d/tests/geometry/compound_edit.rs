@@ -0,0 +1,108 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::bounding_volume::{BoundingVolume, AABB};
+use parry3d::math::Real;
+use parry3d::query::{Ray, RayCast};
+use parry3d::shape::{Compound, SharedShape};
+
+fn intersecting_parts(compound: &Compound, query: &AABB) -> Vec<usize> {
+    let mut parts: Vec<_> = compound
+        .shapes_in_aabb(&Isometry3::identity(), query)
+        .map(|(i, _, _)| i)
+        .collect();
+    parts.sort();
+    parts
+}
+
+#[test]
+fn compound_insert_and_remove_parts() {
+    let mut compound = Compound::new(vec![
+        (
+            Isometry3::translation(0.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+        (
+            Isometry3::translation(2.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+    ]);
+
+    let i = compound.insert(
+        Isometry3::translation(10.0, 0.0, 0.0),
+        SharedShape::cuboid(0.5, 0.5, 0.5),
+    );
+    assert_eq!(i, 2);
+    assert_eq!(compound.local_aabb().maxs.x, 10.5);
+
+    let ray = Ray::new(Point3::new(10.0, 5.0, 0.0), -Vector3::y());
+    let toi = compound.cast_local_ray(&ray, Real::MAX, true);
+    assert_relative_eq!(toi.unwrap(), 4.5, epsilon = 1.0e-5);
+
+    // The last part takes the place of the removed one.
+    let (pos, removed) = compound.remove(0);
+    assert_eq!(pos, Isometry3::identity());
+    assert!(removed.as_ball().is_some());
+    assert_eq!(compound.shapes().len(), 2);
+    assert!(compound.shapes()[0].1.as_cuboid().is_some());
+    assert_eq!(compound.local_aabb().mins.x, 1.5);
+
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    assert!(compound.cast_local_ray(&ray, Real::MAX, true).is_none());
+    let query = AABB::new(Point3::new(9.0, -1.0, -1.0), Point3::new(11.0, 1.0, 1.0));
+    assert_eq!(intersecting_parts(&compound, &query), vec![0]);
+
+    let _ = compound.remove(1);
+    assert_eq!(compound.shapes().len(), 1);
+    assert!(compound.cast_local_ray(&ray, Real::MAX, true).is_none());
+
+    compound.insert(
+        Isometry3::translation(0.0, 1.0, 0.0),
+        SharedShape::ball(0.5),
+    );
+    assert_relative_eq!(
+        compound.cast_local_ray(&ray, Real::MAX, true).unwrap(),
+        3.5,
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn compound_edits_keep_the_acceleration_structure_consistent() {
+    let part = |k: usize| {
+        let k = k as Real;
+        let pos = Isometry3::translation((k * 1.7) % 13.0, (k * 2.3) % 7.0, (k * 0.9) % 5.0);
+        (pos, SharedShape::cuboid(0.4, 0.3, 0.2))
+    };
+    let mut compound = Compound::new((0..5).map(part).collect());
+
+    for k in 5..60 {
+        compound.insert(part(k).0, part(k).1);
+
+        if k % 3 == 0 {
+            compound.remove((k * 7) % compound.shapes().len());
+        }
+    }
+
+    for k in 0..20 {
+        let k = k as Real;
+        let center = Point3::new((k * 3.1) % 13.0, (k * 1.3) % 7.0, (k * 0.7) % 5.0);
+        let query = AABB::from_half_extents(center, Vector3::repeat(1.0));
+        let expected: Vec<_> = compound
+            .aabbs()
+            .iter()
+            .enumerate()
+            .filter(|(_, aabb)| aabb.intersects(&query))
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(intersecting_parts(&compound, &query), expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "A compound shape must contain at least one shape.")]
+fn compound_remove_rejects_the_last_part() {
+    let mut compound = Compound::new(vec![(
+        Isometry3::identity(),
+        SharedShape::cuboid(1.0, 1.0, 1.0),
+    )]);
+    let _ = compound.remove(0);
+}
@@ -0,0 +1,51 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{Ray, RayCast};
+use parry3d::shape::{Compound, SharedShape};
+
+#[test]
+fn compound_flatten_composes_nested_positions() {
+    let inner = SharedShape::compound(vec![
+        (
+            Isometry3::translation(1.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+        (
+            Isometry3::translation(-1.0, 0.0, 0.0),
+            SharedShape::cuboid(0.5, 0.5, 0.5),
+        ),
+    ]);
+    let inner_pos = Isometry3::new(
+        Vector3::new(0.0, 5.0, 0.0),
+        Vector3::z() * std::f32::consts::FRAC_PI_2,
+    );
+    let compound = Compound::flatten(vec![
+        (inner_pos, inner),
+        (Isometry3::identity(), SharedShape::ball(0.25)),
+    ]);
+
+    let shapes = compound.shapes();
+    assert_eq!(shapes.len(), 3);
+    assert!(shapes.iter().all(|(_, s)| s.as_compound().is_none()));
+    assert!(shapes[0].1.as_ball().is_some());
+    assert!(shapes[1].1.as_cuboid().is_some());
+    assert!(shapes[2].1.as_ball().is_some());
+
+    // The rotation of the inner compound moves its parts along the y axis.
+    assert_relative_eq!(
+        shapes[0].0.translation.vector,
+        Vector3::new(0.0, 6.0, 0.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        shapes[1].0.translation.vector,
+        Vector3::new(0.0, 4.0, 0.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(compound.local_aabb().maxs.y, 6.5, epsilon = 1.0e-5);
+    assert_relative_eq!(compound.local_aabb().mins.y, -0.25, epsilon = 1.0e-5);
+
+    let ray = Ray::new(Point3::new(0.0, 10.0, 0.0), -Vector3::y());
+    let toi = compound.cast_local_ray(&ray, Real::MAX, true).unwrap();
+    assert_relative_eq!(toi, 3.5, epsilon = 1.0e-5);
+}
@@ -0,0 +1,101 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, Ray};
+use parry3d::shape::{Ball, Compound, Shape, SharedShape};
+
+fn three_balls() -> Compound {
+    Compound::new(vec![
+        (
+            Isometry3::translation(-2.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+        (
+            Isometry3::translation(0.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+        (
+            Isometry3::translation(2.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+    ])
+}
+
+#[test]
+fn compound_parts_data_from_queries() {
+    let compound = three_balls().with_parts_data(vec![10, 20, 30]);
+    assert_eq!(compound.parts_data(), Some(&[10, 20, 30][..]));
+    assert_eq!(compound.part_data(3), None);
+
+    let ray = Ray::new(Point3::new(2.0, 5.0, 0.0), -Vector3::y());
+    let (part, hit) = compound
+        .cast_local_ray_and_get_part(&ray, Real::MAX, true)
+        .unwrap();
+    assert_eq!(compound.part_data(part), Some(30));
+    assert_relative_eq!(hit.toi, 4.5, epsilon = 1.0e-5);
+
+    let pos = Isometry3::translation(0.0, 0.0, 4.0);
+    let ray = Ray::new(Point3::new(-2.0, 5.0, 4.0), -Vector3::y());
+    let (part, hit) = compound
+        .cast_ray_and_get_part(&pos, &ray, Real::MAX, true)
+        .unwrap();
+    assert_eq!(compound.part_data(part), Some(10));
+    assert_relative_eq!(hit.normal, Vector3::y(), epsilon = 1.0e-5);
+
+    // A ball touching the middle part only.
+    let ball = Ball::new(0.5);
+    let pos12 = Isometry3::translation(0.0, 0.95, 0.0);
+    let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+    let mut workspace = None;
+    DefaultQueryDispatcher
+        .contact_manifolds(
+            &pos12,
+            &compound,
+            &ball,
+            0.0,
+            &mut manifolds,
+            &mut workspace,
+        )
+        .unwrap();
+
+    let touching: Vec<_> = manifolds
+        .iter()
+        .filter(|m| !m.points.is_empty())
+        .map(|m| compound.part_data(m.subshape1))
+        .collect();
+    assert_eq!(touching, vec![Some(20)]);
+}
+
+#[test]
+fn compound_parts_data_follow_edits() {
+    let mut compound = three_balls();
+    assert_eq!(compound.parts_data(), None);
+    assert_eq!(compound.part_data(0), None);
+
+    compound.set_part_data(1, 7);
+    assert_eq!(compound.parts_data(), Some(&[0, 7, 0][..]));
+
+    let i = compound.insert(
+        Isometry3::translation(4.0, 0.0, 0.0),
+        SharedShape::ball(0.5),
+    );
+    compound.set_part_data(i as u32, 9);
+    assert_eq!(compound.parts_data(), Some(&[0, 7, 0, 9][..]));
+
+    // The last part and its data take the place of the removed one.
+    let _ = compound.remove(0);
+    assert_eq!(compound.parts_data(), Some(&[9, 7, 0][..]));
+
+    let mirrored = compound.mirrored(0).unwrap();
+    let mirrored = mirrored.as_compound().unwrap();
+    assert_eq!(mirrored.parts_data(), Some(&[9, 7, 0][..]));
+
+    let scaled = compound.scaled(&Vector3::repeat(2.0), 10).unwrap();
+    let scaled = scaled.as_compound().unwrap();
+    assert_eq!(scaled.parts_data(), Some(&[9, 7, 0][..]));
+}
+
+#[test]
+#[should_panic]
+fn compound_parts_data_length_mismatch() {
+    let _ = three_balls().with_parts_data(vec![1, 2]);
+}
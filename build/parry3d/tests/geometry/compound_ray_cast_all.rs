@@ -0,0 +1,60 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::Ray;
+use parry3d::shape::{Compound, SharedShape};
+
+#[test]
+fn compound_cast_ray_all_sorted_hits() {
+    // Children are not given in the order they are hit.
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(8.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+        (
+            Isometry3::translation(2.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+        (
+            Isometry3::translation(5.0, 0.0, 0.0),
+            SharedShape::cuboid(0.5, 0.5, 0.5),
+        ),
+    ]);
+    let position = Isometry3::translation(0.0, 1.0, 0.0);
+    let ray = Ray::new(Point3::new(0.0, 1.0, 0.0), Vector3::x());
+
+    let hits = compound.cast_ray_all(&position, &ray, f32::MAX, true);
+    assert_eq!(hits.len(), 3);
+    assert_eq!(hits[0].0, 1);
+    assert_eq!(hits[1].0, 2);
+    assert_eq!(hits[2].0, 0);
+
+    for (hit, expected_toi) in hits.iter().zip([1.5, 4.5, 7.5].iter()) {
+        assert_relative_eq!(hit.1.toi, *expected_toi, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.1.normal, -Vector3::x(), epsilon = 1.0e-5);
+    }
+
+    // Limiting the time of impact drops the farthest hits.
+    let hits = compound.cast_ray_all(&position, &ray, 5.0, true);
+    assert_eq!(hits.len(), 2);
+}
+
+#[test]
+fn compound_cast_ray_all_coincident_hits() {
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(2.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+        (
+            Isometry3::translation(2.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+    ]);
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+
+    let hits = compound.cast_ray_all(&Isometry3::identity(), &ray, f32::MAX, true);
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].0, 0);
+    assert_eq!(hits[1].0, 1);
+    assert_relative_eq!(hits[0].1.toi, hits[1].1.toi);
+}
@@ -0,0 +1,121 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use parry3d::query::{ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher};
+use parry3d::shape::{Compound, Cuboid, SharedShape, TriMesh};
+
+fn manifolds(
+    pos12: &Isometry3<f32>,
+    shape1: &SharedShape,
+    shape2: &SharedShape,
+    prediction: f32,
+) -> Vec<ContactManifold<(), ()>> {
+    DefaultQueryDispatcher
+        .contact_manifold(pos12, &**shape1, &**shape2, prediction)
+        .unwrap()
+}
+
+#[test]
+fn contact_manifold_support_maps() {
+    let ground = SharedShape::cuboid(3.0, 1.0, 3.0);
+
+    // A scaled cuboid resting on the ground touches it with a whole face.
+    let scaled = SharedShape::scaled(
+        Cuboid::new(Vector3::repeat(0.5)),
+        Vector3::new(2.0, 1.0, 2.0),
+    );
+    let pos12 = Isometry3::translation(0.0, -1.49, 0.0);
+    let result = manifolds(&pos12, &scaled, &ground, 0.1);
+    assert_eq!(result.len(), 1);
+    let manifold = &result[0];
+    assert_eq!(manifold.points.len(), 4);
+    assert_relative_eq!(manifold.local_n1, -Vector3::y(), epsilon = 1.0e-4);
+    for pt in &manifold.points {
+        assert_relative_eq!(pt.dist, -0.01, epsilon = 1.0e-3);
+        assert_relative_eq!(pt.local_p1.x.abs(), 1.0, epsilon = 1.0e-3);
+        assert_relative_eq!(pt.local_p1.z.abs(), 1.0, epsilon = 1.0e-3);
+    }
+
+    // A tapered capsule lying on the ground touches it along a segment.
+    let capsule = SharedShape::tapered_capsule(
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        0.5,
+        0.5,
+    );
+    let pos12 = Isometry3::translation(0.0, -1.49, 0.0);
+    let result = manifolds(&pos12, &capsule, &ground, 0.1);
+    assert_eq!(result.len(), 1);
+    let points = &result[0].points;
+    assert!(points.len() >= 2);
+    assert!(points.iter().any(|pt| pt.local_p1.x > 0.99));
+    assert!(points.iter().any(|pt| pt.local_p1.x < -0.99));
+    for pt in points {
+        assert!(pt.dist <= 0.1);
+        assert!(pt.dist >= -0.011);
+    }
+
+    // Smooth shapes still generate a contact.
+    let ellipsoid = SharedShape::ellipsoid(Vector3::new(1.0, 0.5, 1.0));
+    let pos12 = Isometry3::translation(0.0, -1.4, 0.0);
+    let result = manifolds(&pos12, &ellipsoid, &ground, 0.0);
+    assert_eq!(result.len(), 1);
+    let deepest = result[0].find_deepest_contact().unwrap();
+    assert_relative_eq!(deepest.dist, -0.1, epsilon = 1.0e-3);
+
+    // Separated shapes don't.
+    let pos12 = Isometry3::translation(0.0, -2.0, 0.0);
+    assert!(manifolds(&pos12, &ellipsoid, &ground, 0.1).is_empty());
+
+    // Half-spaces are supported too.
+    let halfspace = SharedShape::halfspace(Unit::new_normalize(Vector3::y()));
+    let pos12 = Isometry3::translation(0.0, 0.4, 0.0);
+    let result = manifolds(&pos12, &halfspace, &ellipsoid, 0.0);
+    assert_eq!(result.len(), 1);
+    assert_relative_eq!(result[0].points[0].dist, -0.1, epsilon = 1.0e-3);
+}
+
+#[test]
+fn contact_manifold_composite_shapes() {
+    let (vertices, indices) = Cuboid::new(Vector3::new(3.0, 1.0, 3.0)).to_trimesh();
+    let trimesh = SharedShape::new(TriMesh::new(vertices, indices));
+    let cuboid = SharedShape::cuboid(0.5, 0.5, 0.5);
+
+    // Convex-vs-trimesh.
+    let pos12 = Isometry3::translation(0.0, 1.49, 0.0);
+    let result = manifolds(&pos12, &trimesh, &cuboid, 0.0);
+    assert!(!result.is_empty());
+    let num_points: usize = result.iter().map(|m| m.points.len()).sum();
+    assert!(num_points >= 4);
+    for manifold in &result {
+        for pt in &manifold.points {
+            assert_relative_eq!(pt.dist, -0.01, epsilon = 1.0e-3);
+        }
+    }
+
+    // Compound-vs-compound: only the touching parts generate manifolds.
+    let compound = |x: f32| {
+        SharedShape::new(Compound::new(vec![
+            (
+                Isometry3::translation(x, 0.0, 0.0),
+                SharedShape::cuboid(0.5, 0.5, 0.5),
+            ),
+            (Isometry3::identity(), SharedShape::ball(0.5)),
+        ]))
+    };
+    let compound1 = compound(-5.0);
+    let compound2 = compound(5.0);
+    let pos12 = Isometry3::translation(0.0, 0.99, 0.0);
+    let result = manifolds(&pos12, &compound1, &compound2, 0.0);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].subshape1, 1);
+    assert_eq!(result[0].subshape2, 1);
+    assert_eq!(result[0].points.len(), 1);
+}
+
+#[test]
+fn contact_manifold_unsupported() {
+    let torus = SharedShape::torus(1.0, 0.2);
+    let cuboid = SharedShape::cuboid(0.5, 0.5, 0.5);
+    let result: Result<Vec<ContactManifold<(), ()>>, _> =
+        DefaultQueryDispatcher.contact_manifold(&Isometry3::identity(), &*torus, &*cuboid, 0.0);
+    assert!(result.is_err());
+}
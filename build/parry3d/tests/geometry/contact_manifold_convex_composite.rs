@@ -0,0 +1,56 @@
+use na::{Isometry3, Point3};
+use parry3d::query::details::contact_manifold_convex_composite;
+use parry3d::query::{ContactManifold, DefaultQueryDispatcher};
+use parry3d::shape::{Capsule, TriMesh};
+
+#[test]
+fn capsule_resting_on_two_floor_triangles() {
+    // A 4x4 floor split into two triangles along its diagonal x = z.
+    let vertices = vec![
+        Point3::new(-2.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, 2.0),
+        Point3::new(-2.0, 0.0, 2.0),
+    ];
+    let floor = TriMesh::new(vertices, vec![[0, 2, 1], [0, 3, 2]]);
+
+    // A capsule lying along the x axis, crossing the diagonal of the floor.
+    let radius = 0.2;
+    let capsule = Capsule::new_x(1.0, radius);
+    let capsule_pos = Isometry3::translation(0.0, radius, 0.0);
+    let pos12 = capsule_pos.inv_mul(&Isometry3::identity());
+
+    let mut manifold = ContactManifold::<(), ()>::new();
+    contact_manifold_convex_composite(
+        &DefaultQueryDispatcher,
+        &pos12,
+        &capsule,
+        &floor,
+        0.1,
+        &mut manifold,
+    );
+
+    assert!(manifold.points.len() >= 2);
+    assert_relative_eq!(manifold.local_n1.y, -1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(manifold.local_n2.y, 1.0, epsilon = 1.0e-5);
+
+    // Contacts on the triangle with z < x and on the triangle with z > x.
+    assert!(manifold
+        .points
+        .iter()
+        .any(|pt| pt.local_p2.z < pt.local_p2.x - 0.1));
+    assert!(manifold
+        .points
+        .iter()
+        .any(|pt| pt.local_p2.z > pt.local_p2.x + 0.1));
+
+    for (i, pt) in manifold.points.iter().enumerate() {
+        assert_relative_eq!(pt.dist, 0.0, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.local_p2.y, 0.0, epsilon = 1.0e-5);
+
+        // Near-coincident points along the shared edge must have been merged.
+        for other in &manifold.points[i + 1..] {
+            assert!(na::distance(&pt.local_p1, &other.local_p1) > 1.0e-3);
+        }
+    }
+}
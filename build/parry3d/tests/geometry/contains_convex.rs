@@ -0,0 +1,28 @@
+use na::{Isometry3, Vector3};
+use parry3d::query;
+use parry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn cuboid_contains_ball() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let cuboid_pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::y() * 0.3);
+    let ball = Ball::new(0.9);
+    let ball_pos = cuboid_pos * Isometry3::translation(0.0, 1.0, -2.0);
+
+    assert!(query::contains_convex(&cuboid_pos, &cuboid, &ball_pos, &ball).unwrap());
+}
+
+#[test]
+fn cuboid_does_not_contain_ball() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let cuboid_pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::y() * 0.3);
+    let ball_pos = cuboid_pos * Isometry3::translation(0.5, 0.0, 0.0);
+
+    // Partially outside.
+    let ball = Ball::new(0.9);
+    assert!(!query::contains_convex(&cuboid_pos, &cuboid, &ball_pos, &ball).unwrap());
+
+    // Larger than the cuboid.
+    let ball = Ball::new(5.0);
+    assert!(!query::contains_convex(&cuboid_pos, &cuboid, &cuboid_pos, &ball).unwrap());
+}
@@ -1,5 +1,7 @@
 use na::Point3;
 use parry3d::transformation;
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_isaac::IsaacRng;
 
 #[test]
 fn test_complex_convex_hull() {
@@ -4080,3 +4082,99 @@ fn test_planar_convex_hull() {
     let (vertices, indices) = transformation::convex_hull(&input);
     transformation::check_convex_hull(&vertices, &indices);
 }
+
+#[test]
+fn test_cube_convex_hull_topology() {
+    let mut input = Vec::new();
+    for i in 0..8 {
+        input.push(Point3::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            if i & 4 == 0 { -1.0 } else { 1.0 },
+        ));
+    }
+
+    let (vertices, indices, topology) = transformation::convex_hull_with_topology(&input);
+    transformation::check_convex_hull(&vertices, &indices);
+    assert_eq!(topology.faces.len(), indices.len());
+    assert_eq!(topology.half_edges.len(), indices.len() * 3);
+
+    // Every edge must be shared by exactly two faces.
+    for (i, he) in topology.half_edges.iter().enumerate() {
+        assert_ne!(he.twin, u32::MAX);
+        let twin = &topology.half_edges[he.twin as usize];
+        assert_eq!(twin.twin, i as u32);
+        assert_ne!(twin.face, he.face);
+        assert_eq!(twin.vertex, topology.half_edges[he.next as usize].vertex);
+        assert_eq!(topology.half_edges[twin.next as usize].vertex, he.vertex);
+    }
+
+    for (fid, face) in topology.faces.iter().enumerate() {
+        for (k, he) in topology.face_half_edges_ids(fid as u32).iter().enumerate() {
+            let he = &topology.half_edges[*he as usize];
+            assert_eq!(he.face, fid as u32);
+            assert_eq!(he.vertex, indices[fid][k]);
+        }
+        assert_eq!(face.half_edge, fid as u32 * 3);
+    }
+
+    // The topology updated while making the hull canonical matches the one rebuilt from scratch.
+    let rebuilt = parry3d::shape::TriMeshTopology::from_indices(vertices.len(), &indices).unwrap();
+    for (he, expected) in topology.half_edges.iter().zip(rebuilt.half_edges.iter()) {
+        assert_eq!(he.twin, expected.twin);
+    }
+}
+
+#[test]
+fn test_cube_face_planes() {
+    use parry3d::shape::ConvexPolyhedron;
+
+    let points = vec![
+        Point3::new(-1.0, -2.0, -3.0),
+        Point3::new(1.0, -2.0, -3.0),
+        Point3::new(1.0, 2.0, -3.0),
+        Point3::new(-1.0, 2.0, -3.0),
+        Point3::new(-1.0, -2.0, 3.0),
+        Point3::new(1.0, -2.0, 3.0),
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(-1.0, 2.0, 3.0),
+    ];
+    let half_extents = [1.0, 2.0, 3.0];
+    let hull = ConvexPolyhedron::from_convex_hull(&points).unwrap();
+    let planes = hull.face_planes();
+    assert_eq!(planes.len(), 6);
+
+    for (normal, offset) in &planes {
+        let axis = normal.iamax();
+        assert_relative_eq!(normal[axis].abs(), 1.0, epsilon = 1.0e-6);
+        assert_relative_eq!(*offset, half_extents[axis], epsilon = 1.0e-6);
+    }
+
+    for axis in 0..3 {
+        for sign in [-1.0, 1.0].iter() {
+            assert!(planes
+                .iter()
+                .any(|(n, _)| relative_eq!(n[axis], *sign, epsilon = 1.0e-6)));
+        }
+    }
+}
+
+#[test]
+fn test_convex_hull_canonical_output() {
+    let mut points: Vec<_> = (0..8)
+        .map(|i| Point3::new((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32))
+        .collect();
+    // A point inside of the cube.
+    points.push(Point3::new(0.5, 0.5, 0.5));
+
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let reference = transformation::convex_hull(&points);
+    assert_eq!(reference.0.len(), 8);
+    assert_eq!(reference.1.len(), 12);
+    transformation::check_convex_hull(&reference.0, &reference.1);
+
+    for _ in 0..50 {
+        points.shuffle(&mut rng);
+        assert_eq!(transformation::convex_hull(&points), reference);
+    }
+}
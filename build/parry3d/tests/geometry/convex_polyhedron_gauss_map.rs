@@ -0,0 +1,90 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use parry3d::shape::{ConvexPolyhedron, Cuboid};
+
+fn cube() -> ConvexPolyhedron {
+    let (vertices, _) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    ConvexPolyhedron::from_convex_hull(&vertices).unwrap()
+}
+
+fn wedge() -> ConvexPolyhedron {
+    let points = [
+        Point3::new(-1.0, 0.0, -0.5),
+        Point3::new(1.0, 0.0, -0.5),
+        Point3::new(0.2, 1.5, -0.5),
+        Point3::new(-1.0, 0.0, 0.7),
+        Point3::new(1.0, 0.0, 0.7),
+        Point3::new(0.0, 1.2, 0.9),
+    ];
+    ConvexPolyhedron::from_convex_hull(&points).unwrap()
+}
+
+#[test]
+fn convex_polyhedron_edge_normal_cones() {
+    let cube = cube();
+    let mut num_edges = 0;
+
+    for (i, edge) in cube.edges().iter().enumerate() {
+        let i = i as u32;
+        let [n1, n2] = cube.edge_face_normals(i);
+
+        if edge.is_deleted() {
+            // Diagonals of the square faces.
+            assert_eq!(edge.faces[0], edge.faces[1]);
+            assert!(!cube.edge_normal_cone_contains(i, &n1, 1.0e-3));
+            continue;
+        }
+
+        num_edges += 1;
+        assert_relative_eq!(n1.dot(&n2), 0.0, epsilon = 1.0e-6);
+        assert_relative_eq!(n1.dot(&edge.dir), 0.0, epsilon = 1.0e-6);
+
+        let middle = Unit::new_normalize(*n1 + *n2);
+        assert!(cube.edge_normal_cone_contains(i, &middle, 1.0e-3));
+        assert!(cube.edge_normal_cone_contains(i, &n1, 1.0e-3));
+        assert!(cube.edge_normal_cone_contains(i, &n2, 1.0e-3));
+        assert!(!cube.edge_normal_cone_contains(i, &-middle, 1.0e-3));
+        assert!(!cube.edge_normal_cone_contains(i, &-n1, 1.0e-3));
+        assert!(!cube.edge_normal_cone_contains(i, &edge.dir, 1.0e-3));
+
+        let beyond_n1 = Unit::new_normalize(*n1 * 2.0 - *n2);
+        assert!(!cube.edge_normal_cone_contains(i, &beyond_n1, 1.0e-3));
+    }
+
+    assert_eq!(num_edges, 12);
+}
+
+#[test]
+fn convex_polyhedron_edges_build_minkowski_face() {
+    let shape1 = wedge();
+    let shape2 = cube();
+    let pos12 = Isometry3::new(Vector3::new(0.5, 2.0, -0.3), Vector3::new(0.3, -0.7, 1.1));
+    let mut num_faces = 0;
+
+    for (i1, edge1) in shape1.edges().iter().enumerate() {
+        for (i2, edge2) in shape2.edges().iter().enumerate() {
+            let (i1, i2) = (i1 as u32, i2 as u32);
+            let builds_face = shape1.edges_build_minkowski_face(i1, &pos12, &shape2, i2);
+
+            if edge1.is_deleted() || edge2.is_deleted() {
+                assert!(!builds_face);
+                continue;
+            }
+
+            // Brute-force: the cross product of the edge directions, or its opposite, must be
+            // in the normal cone of the first edge while its opposite is in the normal cone
+            // of the second edge.
+            let dir2 = pos12 * edge2.dir;
+            let axis = Unit::new_normalize(edge1.dir.cross(&dir2));
+            let expected = [axis, -axis].iter().any(|n| {
+                let local_n2 = pos12.inverse_transform_unit_vector(&-*n);
+                shape1.edge_normal_cone_contains(i1, n, 1.0e-5)
+                    && shape2.edge_normal_cone_contains(i2, &local_n2, 1.0e-5)
+            });
+
+            assert_eq!(builds_face, expected, "edges {} and {}", i1, i2);
+            num_faces += builds_face as usize;
+        }
+    }
+
+    assert!(num_faces > 0);
+}
@@ -0,0 +1,82 @@
+use na::Point3;
+use parry3d::math::Real;
+use parry3d::shape::ConvexPolyhedron;
+
+// The corners of the cube `[-1, 1]^3` and points sampled on its faces, slightly perturbed.
+fn noisy_cube_points() -> Vec<Point3<Real>> {
+    let mut points = Vec::new();
+    let mut seed = 17u32;
+    let mut noise = || {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        ((seed >> 16) % 1000) as Real / 1000.0 * 6.0e-2 - 3.0e-2
+    };
+
+    for axis in 0..3 {
+        for side in &[-1.0, 1.0] {
+            for i in 1..4 {
+                for j in 1..4 {
+                    let u = -1.0 + i as Real * 0.5;
+                    let v = -1.0 + j as Real * 0.5;
+                    let mut pt = Point3::origin();
+                    pt[axis] = *side + noise();
+                    pt[(axis + 1) % 3] = u;
+                    pt[(axis + 2) % 3] = v;
+                    points.push(pt);
+                }
+            }
+        }
+    }
+
+    for k in 0..8 {
+        let coord = |bit: usize| if k & bit == 0 { -1.0 } else { 1.0 };
+        points.push(Point3::new(coord(1), coord(2), coord(4)));
+    }
+
+    points
+}
+
+#[test]
+fn merge_nearly_coplanar_faces_of_noisy_hull() {
+    let hull = ConvexPolyhedron::from_convex_hull(&noisy_cube_points()).unwrap();
+    assert!(hull.faces().len() > 6);
+
+    let merged = hull.merge_coplanar_faces(0.1).unwrap();
+    assert_eq!(merged.faces().len(), 6);
+    assert!(merged.is_closed());
+    assert!(merged.is_convex_with_tolerance(0.1));
+
+    for face in merged.faces() {
+        assert!(face.normal.iter().any(|e| e.abs() > 0.99));
+    }
+
+    // A zero tolerance only merges exactly coplanar faces.
+    let unmerged = hull.merge_coplanar_faces(0.0).unwrap();
+    assert!(unmerged.faces().len() >= hull.faces().len());
+    assert!(unmerged.is_valid(1.0e-4));
+}
+
+#[test]
+fn validate_non_convex_polyhedron() {
+    // An octahedron with its top vertex pushed below its equator.
+    let points = vec![
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, -0.5, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+    ];
+    let top = [[0, 3, 4], [3, 2, 4], [2, 1, 4], [1, 0, 4]];
+    let bottom = [[0, 5, 3], [3, 5, 2], [2, 5, 1], [1, 5, 0]];
+    let indices: Vec<_> = top.iter().chain(bottom.iter()).cloned().collect();
+
+    let dented = ConvexPolyhedron::from_convex_mesh(points.clone(), &indices).unwrap();
+    assert!(dented.is_closed());
+    assert!(!dented.is_convex_with_tolerance(1.0e-3));
+    assert!(!dented.is_valid(1.0e-3));
+
+    let mut points = points;
+    points[4].y = 1.0;
+    let octahedron = ConvexPolyhedron::from_convex_mesh(points, &indices).unwrap();
+    assert!(octahedron.is_valid(1.0e-5));
+}
@@ -0,0 +1,110 @@
+use approx::assert_relative_eq;
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query;
+use parry3d::shape::{Ball, ConvexPolyhedron, Cuboid, SupportMap};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+fn random_vector(rng: &mut IsaacRng) -> Vector3<Real> {
+    Vector3::new(
+        rng.gen::<Real>() - 0.5,
+        rng.gen::<Real>() - 0.5,
+        rng.gen::<Real>() - 0.5,
+    )
+}
+
+// A convex polyhedron with hundreds of vertices, approximating an ellipsoid.
+fn rounded_polyhedron(rng: &mut IsaacRng) -> ConvexPolyhedron {
+    let points: Vec<_> = (0..500)
+        .map(|_| {
+            let dir = random_vector(rng).normalize();
+            Point3::from(dir.component_mul(&Vector3::new(1.0, 2.0, 3.0)))
+        })
+        .collect();
+    ConvexPolyhedron::from_convex_hull(&points).unwrap()
+}
+
+#[test]
+fn hill_climbing_finds_the_support_points() {
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let poly = rounded_polyhedron(&mut rng);
+    let cached = poly.clone().with_support_cache();
+    assert!(!poly.has_support_cache());
+    assert!(cached.has_support_cache());
+    assert!(poly.points().len() > 100);
+
+    for _ in 0..1000 {
+        let dir = random_vector(&mut rng);
+        let expected = poly.local_support_point(&dir).coords.dot(&dir);
+        let found = cached.local_support_point(&dir).coords.dot(&dir);
+        assert_relative_eq!(found, expected);
+    }
+
+    // The walk reaches the support point from any vertex.
+    let dir = Vector3::new(1.0, -1.0, 0.5);
+    let expected = poly.local_support_point(&dir).coords.dot(&dir);
+
+    for start in 0..poly.points().len() as u32 {
+        let id = poly.local_support_point_id_from(&dir, start);
+        assert_relative_eq!(poly.points()[id as usize].coords.dot(&dir), expected);
+    }
+}
+
+#[test]
+fn hill_climbing_on_merged_faces() {
+    // The vertices in the middle of the merged faces of the cuboid are only connected to
+    // the others through the deleted edges.
+    let (vertices, _) = Cuboid::new(Vector3::new(1.0, 1.0, 1.0)).to_trimesh();
+    let mut points = vertices;
+    points.extend_from_slice(&[
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+    ]);
+    let poly = ConvexPolyhedron::from_convex_hull(&points)
+        .unwrap()
+        .with_support_cache();
+
+    for start in 0..poly.points().len() as u32 {
+        let id = poly.local_support_point_id_from(&Vector3::new(-1.0, -2.0, -3.0), start);
+        assert_eq!(poly.points()[id as usize], Point3::new(-1.0, -1.0, -1.0));
+    }
+}
+
+#[test]
+fn queries_with_support_cache() {
+    let mut rng = IsaacRng::seed_from_u64(1);
+    let poly = rounded_polyhedron(&mut rng);
+    let cached = poly.clone().with_support_cache();
+    assert_eq!(poly, cached);
+    assert!(cached.clone().has_support_cache());
+
+    let ball = Ball::new(0.5);
+
+    for _ in 0..50 {
+        let pos = Isometry3::translation(0.0, 0.0, 0.0);
+        let ball_pos = Isometry3::new(random_vector(&mut rng) * 10.0, Vector3::zeros());
+        let expected = query::distance(&pos, &poly, &ball_pos, &ball).unwrap();
+        let found = query::distance(&pos, &cached, &ball_pos, &ball).unwrap();
+        assert_relative_eq!(found, expected, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn support_cache_ignores_the_vertices_outside_of_faces() {
+    // The first point is not a vertex of any face of the mesh.
+    let (vertices, indices) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    let mut points = vec![Point3::origin()];
+    points.extend_from_slice(&vertices);
+    let indices: Vec<_> = indices
+        .iter()
+        .map(|idx| [idx[0] + 1, idx[1] + 1, idx[2] + 1])
+        .collect();
+    let poly = ConvexPolyhedron::from_convex_mesh(points, &indices)
+        .unwrap()
+        .with_support_cache();
+
+    let support = poly.local_support_point(&Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(support, Point3::new(1.0, 1.0, 1.0));
+}
@@ -83,3 +83,45 @@ fn shape_ray_cast_points_to_surface() {
         Cuboid::new(Vector3::new(0.5, 1.0, 0.5)),
     );
 }
+
+#[test]
+fn cuboid_ray_cast_feature_id() {
+    use parry3d::query::RayCast;
+    use parry3d::shape::FeatureId;
+
+    let cube = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+
+    for i in 0..6 {
+        let mut normal = Vector3::zeros();
+        normal[i % 3] = if i < 3 { 1.0 } else { -1.0 };
+        let origin = Point3::from(normal * 5.0 + Vector3::new(0.1, 0.2, 0.3));
+        let ray = Ray::new(origin, -normal);
+
+        let inter = cube
+            .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+            .unwrap();
+        assert_eq!(inter.feature, FeatureId::Face(i as u32));
+        assert_eq!(inter.normal, normal);
+    }
+
+    // A ray grazing the edge between the +x and +y faces.
+    let ray = Ray::new(Point3::new(1.0, 1.0, 5.0), -Vector3::z());
+    let inter = cube
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_eq!(inter.feature, FeatureId::Vertex(0));
+
+    let ray = Ray::new(Point3::new(1.0, 5.0, 0.5), -Vector3::y());
+    let inter = cube
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_eq!(inter.feature, FeatureId::Edge(2));
+
+    // The ball's surface is a single feature.
+    let ball = Ball::new(1.0);
+    let ray = Ray::new(Point3::new(0.1, 5.0, 0.2), -Vector3::y());
+    let inter = ball
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_eq!(inter.feature, FeatureId::Face(0));
+}
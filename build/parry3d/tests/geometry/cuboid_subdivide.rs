@@ -0,0 +1,31 @@
+use na::Vector3;
+use parry3d::bounding_volume::BoundingVolume;
+use parry3d::math::Real;
+use parry3d::shape::Cuboid;
+
+fn volume(extents: Vector3<Real>) -> Real {
+    extents.x.max(0.0) * extents.y.max(0.0) * extents.z.max(0.0)
+}
+
+#[test]
+fn cuboid_subdivision_tiles_parent() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let aabb = cuboid.local_aabb();
+    let parts = cuboid.subdivide();
+    let aabbs: Vec<_> = parts.iter().map(|(pos, part)| part.aabb(pos)).collect();
+
+    let mut total_volume = 0.0;
+    for (i, part_aabb) in aabbs.iter().enumerate() {
+        assert!(aabb.contains(part_aabb));
+        total_volume += volume(part_aabb.extents());
+
+        for other in &aabbs[i + 1..] {
+            // Overlapping volume between both parts.
+            let mins = part_aabb.mins.coords.sup(&other.mins.coords);
+            let maxs = part_aabb.maxs.coords.inf(&other.maxs.coords);
+            assert_eq!(volume(maxs - mins), 0.0);
+        }
+    }
+
+    assert_relative_eq!(total_volume, volume(aabb.extents()));
+}
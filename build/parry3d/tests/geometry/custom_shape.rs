@@ -0,0 +1,250 @@
+use na::{self, Isometry3, Point3, Vector3};
+use parry3d::bounding_volume::AABB;
+use parry3d::mass_properties::MassProperties;
+use parry3d::math::Real;
+use parry3d::motion::RigidMotion;
+use parry3d::query::gjk::VoronoiSimplex;
+use parry3d::query::{
+    self, ClosestPoints, Contact, PointProjection, PointQuery, QueryDispatcher, Ray, RayCast,
+    RayIntersection, Unsupported, TOI,
+};
+use parry3d::shape::{Cuboid, CustomShape, FeatureId, Shape, ShapeType, SupportMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// A regular octahedron with vertices at a distance `radius` from its center.
+#[derive(Clone)]
+struct Octahedron {
+    radius: Real,
+    dispatcher: CountingDispatcher,
+}
+
+impl Octahedron {
+    fn new(radius: Real) -> Self {
+        Self {
+            radius,
+            dispatcher: CountingDispatcher(AtomicUsize::new(0)),
+        }
+    }
+}
+
+// A dispatcher that doesn't support any query, but counts how many times it is called.
+struct CountingDispatcher(AtomicUsize);
+
+impl Clone for CountingDispatcher {
+    fn clone(&self) -> Self {
+        CountingDispatcher(AtomicUsize::new(self.0.load(Ordering::SeqCst)))
+    }
+}
+
+impl CountingDispatcher {
+    fn unsupported<T>(&self) -> Result<T, Unsupported> {
+        let _ = self.0.fetch_add(1, Ordering::SeqCst);
+        Err(Unsupported)
+    }
+}
+
+impl QueryDispatcher for CountingDispatcher {
+    fn intersection_test(
+        &self,
+        _: &Isometry3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+    ) -> Result<bool, Unsupported> {
+        self.unsupported()
+    }
+
+    fn distance(
+        &self,
+        _: &Isometry3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+    ) -> Result<Real, Unsupported> {
+        self.unsupported()
+    }
+
+    fn contact(
+        &self,
+        _: &Isometry3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+        _: Real,
+    ) -> Result<Option<Contact>, Unsupported> {
+        self.unsupported()
+    }
+
+    fn closest_points(
+        &self,
+        _: &Isometry3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+        _: Real,
+    ) -> Result<ClosestPoints, Unsupported> {
+        self.unsupported()
+    }
+
+    fn time_of_impact(
+        &self,
+        _: &Isometry3<Real>,
+        _: &Vector3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+        _: Real,
+        _: Real,
+    ) -> Result<Option<TOI>, Unsupported> {
+        self.unsupported()
+    }
+
+    fn nonlinear_time_of_impact(
+        &self,
+        _: &dyn RigidMotion,
+        _: &dyn Shape,
+        _: &dyn Shape,
+        _: Real,
+        _: Real,
+    ) -> Result<Option<TOI>, Unsupported> {
+        self.unsupported()
+    }
+}
+
+impl SupportMap for Octahedron {
+    fn local_support_point(&self, dir: &Vector3<Real>) -> Point3<Real> {
+        let i = dir.iamax();
+        let mut pt = Point3::origin();
+        pt[i] = self.radius.copysign(dir[i]);
+        pt
+    }
+}
+
+impl RayCast for Octahedron {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        query::details::local_ray_intersection_with_support_map_with_params(
+            self,
+            &mut VoronoiSimplex::new(),
+            ray,
+            max_toi,
+            solid,
+        )
+    }
+}
+
+impl PointQuery for Octahedron {
+    fn project_local_point(&self, pt: &Point3<Real>, solid: bool) -> PointProjection {
+        query::details::local_point_projection_on_support_map(
+            self,
+            &mut VoronoiSimplex::new(),
+            pt,
+            solid,
+        )
+    }
+
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point3<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Unknown)
+    }
+}
+
+impl CustomShape for Octahedron {
+    fn query_dispatcher(&self) -> &dyn QueryDispatcher {
+        &self.dispatcher
+    }
+}
+
+impl Shape for Octahedron {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        AABB::from_half_extents(Point3::origin(), Vector3::repeat(self.radius))
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        let mass = density * self.radius.powi(3) * 4.0 / 3.0;
+        let inertia = mass * self.radius * self.radius * 2.0 / 5.0;
+        MassProperties::new(Point3::origin(), mass, Vector3::repeat(inertia))
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Custom(1)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radius
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self)
+    }
+
+    fn as_custom(&self) -> Option<&dyn CustomShape> {
+        Some(self)
+    }
+}
+
+#[test]
+fn custom_shape_ray_cast() {
+    let shape: Box<dyn Shape> = Box::new(Octahedron::new(1.0));
+    let pos = Isometry3::translation(0.0, 2.0, 0.0);
+
+    assert_eq!(shape.shape_type(), ShapeType::Custom(1));
+    assert!(shape.as_custom().is_some());
+
+    let ray = Ray::new(Point3::new(-5.0, 2.2, 0.2), Vector3::x());
+    let inter = shape
+        .cast_ray_and_get_normal(&pos, &ray, Real::MAX, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 4.4, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        inter.normal,
+        Vector3::new(-1.0, 1.0, 1.0).normalize(),
+        epsilon = 1.0e-5
+    );
+
+    // The ray passes by the tip of the octahedron.
+    let ray = Ray::new(Point3::new(-5.0, 3.1, 0.0), Vector3::x());
+    assert!(shape.cast_ray(&pos, &ray, Real::MAX, true).is_none());
+}
+
+#[test]
+fn custom_shape_queries_fall_back_to_support_map() {
+    let shape = Octahedron::new(1.0);
+    let cuboid = Cuboid::new(Vector3::repeat(0.5));
+    let pos1 = Isometry3::identity();
+    let pos2 = Isometry3::translation(2.0, 0.0, 0.0);
+
+    let dist = query::distance(&pos1, &shape, &pos2, &cuboid).unwrap();
+    assert_relative_eq!(dist, 0.5, epsilon = 1.0e-5);
+    assert!(!query::intersection_test(&pos1, &cuboid, &pos2, &shape).unwrap());
+
+    let pos2 = Isometry3::translation(1.4, 0.0, 0.0);
+    assert!(query::intersection_test(&pos1, &shape, &pos2, &cuboid).unwrap());
+
+    // The custom dispatcher was given the first chance to handle each query.
+    assert_eq!(shape.dispatcher.0.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn custom_shape_pair_tries_both_dispatchers() {
+    let shape1 = Octahedron::new(1.0);
+    let shape2 = Octahedron::new(0.5);
+    let pos1 = Isometry3::identity();
+    let pos2 = Isometry3::translation(0.0, 0.0, 2.0);
+
+    let dist = query::distance(&pos1, &shape1, &pos2, &shape2).unwrap();
+    assert_relative_eq!(dist, 0.5, epsilon = 1.0e-5);
+    let contact = query::contact(&pos1, &shape1, &pos2, &shape2, 1.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, 0.5, epsilon = 1.0e-5);
+
+    // Both dispatchers were given a chance to handle each query, in order.
+    assert_eq!(shape1.dispatcher.0.load(Ordering::SeqCst), 2);
+    assert_eq!(shape2.dispatcher.0.load(Ordering::SeqCst), 2);
+}
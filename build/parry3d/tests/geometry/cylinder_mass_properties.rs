@@ -0,0 +1,35 @@
+use na::Vector3;
+use parry3d::mass_properties::MassProperties;
+
+#[test]
+fn z_aligned_cylinder_inertia() {
+    let (density, half_height, radius) = (2.0, 2.0, 0.5);
+    let props =
+        MassProperties::from_cylinder_with_axis(density, half_height, radius, Vector3::z_axis());
+    let reference = MassProperties::from_cylinder(density, half_height, radius);
+    let mass = 1.0 / props.inv_mass;
+
+    let inertia = props.reconstruct_inertia_matrix();
+    let axial = mass * radius * radius / 2.0;
+    let off_axial = mass * (radius * radius * 3.0 + half_height * half_height * 4.0) / 12.0;
+
+    assert_relative_eq!(mass, 1.0 / reference.inv_mass);
+    assert_relative_eq!(inertia[(2, 2)], axial, epsilon = 1.0e-4);
+    assert_relative_eq!(inertia[(0, 0)], off_axial, epsilon = 1.0e-4);
+    assert_relative_eq!(inertia[(1, 1)], off_axial, epsilon = 1.0e-4);
+    assert!(inertia[(2, 2)] < inertia[(0, 0)]);
+    assert!(inertia[(2, 2)] < inertia[(1, 1)]);
+    assert_relative_eq!(inertia[(0, 1)], 0.0, epsilon = 1.0e-4);
+    assert_relative_eq!(inertia[(0, 2)], 0.0, epsilon = 1.0e-4);
+    assert_relative_eq!(inertia[(1, 2)], 0.0, epsilon = 1.0e-4);
+
+    // Aligning with the `y` axis, in any direction, gives back the default cylinder.
+    for axis in &[Vector3::y_axis(), -Vector3::y_axis()] {
+        let props = MassProperties::from_cylinder_with_axis(density, half_height, radius, *axis);
+        assert_relative_eq!(
+            props.reconstruct_inertia_matrix(),
+            reference.reconstruct_inertia_matrix(),
+            epsilon = 1.0e-4
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use approx::assert_relative_eq;
+use na::{Isometry3, Point3};
+use parry3d::query::{self, PointQuery, PointQueryWithLocation};
+use parry3d::shape::{Ball, Degeneracy, Segment, Triangle};
+
+fn assert_segment_eq(seg: &Segment, a: Point3<f32>, b: Point3<f32>) {
+    let ok = (relative_eq!(seg.a, a) && relative_eq!(seg.b, b))
+        || (relative_eq!(seg.a, b) && relative_eq!(seg.b, a));
+    assert!(ok, "{:?} != [{:?}, {:?}]", seg, a, b);
+}
+
+#[test]
+fn triangle_and_segment_degeneracies() {
+    let o = Point3::origin();
+    let x = Point3::new(1.0, 0.0, 0.0);
+    let y = Point3::new(0.0, 1.0, 0.0);
+
+    assert_eq!(Triangle::new(o, x, y).degeneracy(1.0e-5), None);
+    assert_eq!(
+        Triangle::new(x, x, x).degeneracy(1.0e-5),
+        Some(Degeneracy::Point(x))
+    );
+
+    // Duplicate vertices.
+    match Triangle::new(o, x, o).degeneracy(1.0e-5) {
+        Some(Degeneracy::Segment(seg)) => assert_segment_eq(&seg, o, x),
+        d => panic!("Unexpected degeneracy: {:?}", d),
+    }
+
+    // Collinear vertices, up to the tolerance.
+    let tri = Triangle::new(o, Point3::new(2.0, 1.0e-3, 0.0), x);
+    assert_eq!(tri.degeneracy(1.0e-5), None);
+    match tri.degeneracy(1.0e-2) {
+        Some(Degeneracy::Segment(seg)) => {
+            assert_relative_eq!(seg.length(), 2.0, epsilon = 1.0e-3)
+        }
+        d => panic!("Unexpected degeneracy: {:?}", d),
+    }
+
+    assert_eq!(Segment::new(o, x).degeneracy(1.0e-5), None);
+    assert_eq!(
+        Segment::new(x, x).degeneracy(1.0e-5),
+        Some(Degeneracy::Point(x))
+    );
+}
+
+#[test]
+fn point_projection_on_degenerate_triangles() {
+    let o = Point3::origin();
+    let x = Point3::new(1.0, 0.0, 0.0);
+    let triangles = [
+        Triangle::new(o, o, x),
+        Triangle::new(o, x, Point3::new(0.5, 0.0, 0.0)),
+        Triangle::new(x, o, x),
+    ];
+    let points = [
+        Point3::new(0.5, 0.0, 0.3),
+        Point3::new(0.25, 0.0, 0.0),
+        Point3::new(2.0, 1.0, -1.0),
+        Point3::new(-1.0, 0.0, 0.0),
+    ];
+    let segment = Segment::new(o, x);
+
+    for tri in triangles.iter() {
+        for pt in points.iter() {
+            let expected = segment.project_local_point(pt, true);
+            let (proj, loc) = tri.project_local_point_and_get_location(pt, true);
+            assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-6);
+            assert_eq!(proj.is_inside, expected.is_inside);
+
+            // The location matches the projected point.
+            let bcoords = loc.barycentric_coordinates().unwrap();
+            let located =
+                tri.a * bcoords[0] + tri.b.coords * bcoords[1] + tri.c.coords * bcoords[2];
+            assert_relative_eq!(located, proj.point, epsilon = 1.0e-6);
+        }
+
+        // Queries relying on the projection don't miss the contact.
+        let ball = Ball::new(0.5);
+        let pos = Isometry3::translation(0.5, 0.0, 0.3);
+        let contact = query::contact(&Isometry3::identity(), tri, &pos, &ball, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.2, epsilon = 1.0e-5);
+        assert!(contact.normal1.iter().all(|e| e.is_finite()));
+    }
+}
@@ -0,0 +1,51 @@
+use na::{Isometry3, Vector3};
+use parry3d::query;
+use parry3d::shape::{Ball, Compound, Cuboid, SharedShape};
+
+#[test]
+fn distance_with_max_dist_is_capped() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let ball = Ball::new(0.5);
+    let pos1 = Isometry3::identity();
+    let far = Isometry3::translation(100.0, 0.0, 0.0);
+    let near = Isometry3::translation(2.0, 0.0, 0.0);
+
+    let dist = query::distance_with_max_dist(&pos1, &cuboid, &far, &ball, 1.0).unwrap();
+    assert_eq!(dist, 1.0);
+    let dist = query::distance_with_max_dist(&pos1, &cuboid, &far, &cuboid, 1.0).unwrap();
+    assert_eq!(dist, 1.0);
+
+    // Shapes closer than the bound get their exact distance.
+    let dist = query::distance_with_max_dist(&pos1, &cuboid, &near, &ball, 1.0).unwrap();
+    let exact = query::distance(&pos1, &cuboid, &near, &ball).unwrap();
+    assert_relative_eq!(dist, 0.5, epsilon = 1.0e-3);
+    assert_relative_eq!(dist, exact, epsilon = 1.0e-5);
+}
+
+#[test]
+fn composite_distance_with_max_dist_is_capped() {
+    let compound = Compound::new(
+        (0..10)
+            .map(|i| {
+                (
+                    Isometry3::translation(i as f32 * 3.0, 0.0, 0.0),
+                    SharedShape::cuboid(1.0, 1.0, 1.0),
+                )
+            })
+            .collect(),
+    );
+    let ball = Ball::new(0.5);
+    let pos1 = Isometry3::identity();
+
+    let far = Isometry3::translation(0.0, 50.0, 0.0);
+    let dist = query::distance_with_max_dist(&pos1, &compound, &far, &ball, 2.0).unwrap();
+    assert_eq!(dist, 2.0);
+    let dist = query::distance_with_max_dist(&pos1, &ball, &far, &compound, 2.0).unwrap();
+    assert_eq!(dist, 2.0);
+
+    let near = Isometry3::translation(12.0, 2.0, 0.0);
+    let dist = query::distance_with_max_dist(&pos1, &compound, &near, &ball, 2.0).unwrap();
+    let exact = query::distance(&pos1, &compound, &near, &ball).unwrap();
+    assert_relative_eq!(dist, 0.5, epsilon = 1.0e-3);
+    assert_relative_eq!(dist, exact, epsilon = 1.0e-5);
+}
@@ -0,0 +1,160 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::mass_properties::MassProperties;
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{Ellipsoid, Shape, SupportMap};
+use parry3d::transformation;
+
+fn surface_points(ellipsoid: &Ellipsoid, n: usize) -> Vec<Point3<f32>> {
+    let mut pts = Vec::new();
+
+    for i in 0..=n {
+        let phi = std::f32::consts::PI * i as f32 / n as f32;
+
+        for j in 0..2 * n {
+            let theta = std::f32::consts::PI * j as f32 / n as f32;
+            let unit = Vector3::new(phi.sin() * theta.cos(), phi.cos(), phi.sin() * theta.sin());
+            pts.push(Point3::from(ellipsoid.radii.component_mul(&unit)));
+        }
+    }
+
+    pts
+}
+
+#[test]
+fn ellipsoid_support_point() {
+    let ellipsoid = Ellipsoid::new(Vector3::new(2.0, 0.5, 1.0));
+    let pts = surface_points(&ellipsoid, 100);
+
+    for dir in &[
+        Vector3::x(),
+        -Vector3::y(),
+        Vector3::new(1.0, 1.0, 1.0),
+        Vector3::new(-0.3, 2.0, 0.7),
+    ] {
+        let support = ellipsoid.local_support_point(dir);
+        let best = pts
+            .iter()
+            .map(|pt| pt.coords.dot(dir))
+            .fold(f32::MIN, f32::max);
+
+        assert!(ellipsoid.contains_local_point(&(support * 0.999)));
+        assert_relative_eq!(support.coords.dot(dir), best, max_relative = 1.0e-3);
+    }
+}
+
+#[test]
+fn ellipsoid_ray_cast() {
+    let ellipsoid = Ellipsoid::new(Vector3::new(2.0, 0.5, 1.0));
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let hit = ellipsoid
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.0, epsilon = 1.0e-5);
+    assert_relative_eq!(hit.normal, -Vector3::x(), epsilon = 1.0e-5);
+
+    let ray = Ray::new(Point3::new(0.0, 3.0, 0.0), -Vector3::y() * 2.0);
+    let hit = ellipsoid
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.25, epsilon = 1.0e-5);
+    assert_relative_eq!(hit.normal, Vector3::y(), epsilon = 1.0e-5);
+
+    // From inside.
+    let ray = Ray::new(Point3::origin(), Vector3::z());
+    assert_eq!(ellipsoid.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+    assert_relative_eq!(
+        ellipsoid.cast_local_ray(&ray, f32::MAX, false).unwrap(),
+        1.0,
+        epsilon = 1.0e-5
+    );
+
+    // Passing next to it.
+    let ray = Ray::new(Point3::new(-5.0, 0.6, 0.0), Vector3::x());
+    assert!(ellipsoid.cast_local_ray(&ray, f32::MAX, true).is_none());
+
+    // Stopped by `max_toi`.
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    assert!(ellipsoid.cast_local_ray(&ray, 2.9, true).is_none());
+}
+
+#[test]
+fn ellipsoid_point_projection_is_closest_point() {
+    let ellipsoid = Ellipsoid::new(Vector3::new(2.0, 0.5, 1.0));
+    let surface = surface_points(&ellipsoid, 200);
+    let pts = [
+        Point3::new(3.0, 1.0, -2.0),
+        Point3::new(-0.2, 0.1, 0.3),
+        Point3::new(0.5, 0.0, 0.0),
+        Point3::new(0.3, 0.0, 0.2),
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(-1.5, 0.0, -0.5),
+    ];
+
+    for pt in &pts {
+        let proj = ellipsoid.project_local_point(pt, false);
+        let best = surface
+            .iter()
+            .map(|s| na::distance(s, pt))
+            .fold(f32::MAX, f32::min);
+
+        assert_eq!(proj.is_inside, ellipsoid.contains_local_point(pt));
+        assert_relative_eq!(
+            proj.point.coords.component_div(&ellipsoid.radii).norm(),
+            1.0,
+            epsilon = 1.0e-4
+        );
+        assert!(na::distance(&proj.point, pt) <= best + 1.0e-4);
+    }
+
+    let inside = Point3::new(0.3, 0.0, 0.2);
+    let proj = ellipsoid.project_local_point(&inside, true);
+    assert!(proj.is_inside);
+    assert_eq!(proj.point, inside);
+}
+
+#[test]
+fn ellipsoid_mass_properties() {
+    let ellipsoid = Ellipsoid::new(Vector3::new(1.0, 0.5, 0.75));
+    let mprops = ellipsoid.mass_properties(2.0);
+    let voxelized = transformation::mass_properties_by_voxelization(&ellipsoid, 2.0, 0.02);
+
+    assert_relative_eq!(
+        1.0 / mprops.inv_mass,
+        1.0 / voxelized.inv_mass,
+        max_relative = 1.0e-2
+    );
+    assert_relative_eq!(
+        mprops.reconstruct_inertia_matrix(),
+        voxelized.reconstruct_inertia_matrix(),
+        max_relative = 2.0e-2,
+        epsilon = 1.0e-3
+    );
+
+    // An ellipsoid with equal radii is a ball.
+    let ball = MassProperties::from_ball(2.0, 0.5);
+    let round = MassProperties::from_ellipsoid(2.0, &Vector3::repeat(0.5));
+    assert_relative_eq!(ball.inv_mass, round.inv_mass, max_relative = 1.0e-5);
+    assert_relative_eq!(
+        ball.reconstruct_inertia_matrix(),
+        round.reconstruct_inertia_matrix(),
+        max_relative = 1.0e-5
+    );
+}
+
+#[test]
+fn ellipsoid_aabb_is_tight() {
+    let ellipsoid = Ellipsoid::new(Vector3::new(2.0, 0.5, 1.0));
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.5, -0.3, 1.1));
+    let aabb = ellipsoid.compute_aabb(&pos);
+
+    for i in 0..3 {
+        let mut dir = Vector3::zeros();
+        dir[i] = 1.0;
+        let maxs = ellipsoid.support_point(&pos, &dir);
+        let mins = ellipsoid.support_point(&pos, &-dir);
+        assert_relative_eq!(aabb.maxs[i], maxs[i], epsilon = 1.0e-5);
+        assert_relative_eq!(aabb.mins[i], mins[i], epsilon = 1.0e-5);
+    }
+}
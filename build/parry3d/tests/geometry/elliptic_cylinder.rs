@@ -0,0 +1,113 @@
+use na::{Isometry3, Point3, Vector2, Vector3};
+use parry3d::mass_properties::MassProperties;
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{Cylinder, EllipticCylinder, Shape, SupportMap};
+
+fn rim_points(cylinder: &EllipticCylinder, n: usize) -> Vec<Point3<f32>> {
+    let mut pts = Vec::new();
+
+    for i in 0..n {
+        let theta = std::f32::consts::PI * 2.0 * i as f32 / n as f32;
+        let x = cylinder.radii.x * theta.cos();
+        let z = cylinder.radii.y * theta.sin();
+        pts.push(Point3::new(x, cylinder.half_height, z));
+        pts.push(Point3::new(x, -cylinder.half_height, z));
+    }
+
+    pts
+}
+
+#[test]
+fn elliptic_cylinder_support_point() {
+    let cylinder = EllipticCylinder::new(0.5, Vector2::new(2.0, 1.0));
+    let pts = rim_points(&cylinder, 2000);
+
+    for dir in &[
+        Vector3::x(),
+        -Vector3::z(),
+        Vector3::new(1.0, 1.0, 1.0),
+        Vector3::new(-0.3, -2.0, 0.7),
+    ] {
+        let support = cylinder.local_support_point(dir);
+        let best = pts
+            .iter()
+            .map(|pt| pt.coords.dot(dir))
+            .fold(-f32::MAX, f32::max);
+        assert_relative_eq!(support.coords.dot(dir), best, epsilon = 1.0e-4);
+    }
+
+    // Along the principal axis, the support point lies on the axis.
+    assert_eq!(
+        cylinder.local_support_point(&Vector3::y()),
+        Point3::new(0.0, 0.5, 0.0)
+    );
+}
+
+#[test]
+fn elliptic_cylinder_with_equal_radii_matches_cylinder() {
+    let elliptic = EllipticCylinder::new(0.75, Vector2::new(0.5, 0.5));
+    let cylinder = Cylinder::new(0.75, 0.5);
+
+    let mprops = elliptic.mass_properties(2.0);
+    let expected = MassProperties::from_cylinder(2.0, 0.75, 0.5);
+    assert_relative_eq!(mprops.inv_mass, expected.inv_mass, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        mprops.inv_principal_inertia_sqrt,
+        expected.inv_principal_inertia_sqrt,
+        epsilon = 1.0e-5
+    );
+
+    let dir = Vector3::new(0.2, -1.0, 0.6);
+    assert_relative_eq!(
+        elliptic.local_support_point(&dir),
+        cylinder.local_support_point(&dir),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn elliptic_cylinder_mass_properties() {
+    let cylinder = EllipticCylinder::new(0.5, Vector2::new(2.0, 1.0));
+    let mprops = cylinder.mass_properties(1.0);
+    assert_relative_eq!(
+        1.0 / mprops.inv_mass,
+        std::f32::consts::PI * 2.0,
+        epsilon = 1.0e-5
+    );
+
+    // An elliptic cylinder is a circular cylinder stretched along `x`.
+    let expected =
+        MassProperties::from_cylinder(1.0, 0.5, 1.0).scaled(&Vector3::new(2.0, 1.0, 1.0));
+    assert_relative_eq!(
+        mprops.reconstruct_inertia_matrix(),
+        expected.reconstruct_inertia_matrix(),
+        epsilon = 1.0e-4
+    );
+}
+
+#[test]
+fn elliptic_cylinder_queries() {
+    let cylinder = EllipticCylinder::new(0.5, Vector2::new(2.0, 1.0));
+
+    let aabb = cylinder.compute_local_aabb();
+    assert_relative_eq!(aabb.maxs, Point3::new(2.0, 0.5, 1.0), epsilon = 1.0e-6);
+    assert_relative_eq!(aabb.mins, Point3::new(-2.0, -0.5, -1.0), epsilon = 1.0e-6);
+
+    let pos = Isometry3::rotation(Vector3::y() * std::f32::consts::FRAC_PI_2);
+    let aabb = cylinder.compute_aabb(&pos);
+    assert_relative_eq!(aabb.maxs, Point3::new(1.0, 0.5, 2.0), epsilon = 1.0e-5);
+
+    assert!(cylinder.contains_local_point(&Point3::new(1.9, 0.0, 0.0)));
+    assert!(!cylinder.contains_local_point(&Point3::new(1.5, 0.0, 0.8)));
+
+    let ray = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::z());
+    let hit = cylinder
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.0, epsilon = 1.0e-4);
+    assert_relative_eq!(hit.normal, -Vector3::z(), epsilon = 1.0e-4);
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let toi = cylinder.cast_local_ray(&ray, f32::MAX, true).unwrap();
+    assert_relative_eq!(toi, 3.0, epsilon = 1.0e-4);
+}
@@ -0,0 +1,35 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::bounding_volume::BoundingVolume;
+use parry3d::query::{self, PointQuery, Ray, RayCast};
+use parry3d::shape::{Ball, Shape, TriMesh};
+
+#[test]
+fn empty_trimesh_queries() {
+    let mesh = TriMesh::new(Vec::new(), Vec::new());
+    let pos = Isometry3::translation(1.0, 2.0, 3.0);
+    assert!(mesh.is_empty());
+    assert_eq!(mesh.num_triangles(), 0);
+
+    let aabb = mesh.compute_aabb(&pos);
+    assert!(aabb.mins > aabb.maxs);
+    assert!(!aabb.intersects(&Ball::new(1.0).compute_aabb(&pos)));
+
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+    assert!(mesh.cast_ray(&pos, &ray, f32::MAX, true).is_none());
+    assert!(mesh
+        .cast_ray_and_get_normal(&pos, &ray, f32::MAX, true)
+        .is_none());
+    assert!(!mesh.intersects_ray(&pos, &ray, f32::MAX));
+
+    let pt = Point3::new(1.0, 2.0, 3.0);
+    assert!(!mesh.contains_point(&pos, &pt));
+    let proj = mesh.project_point(&pos, &pt, true);
+    assert!(!proj.is_inside);
+    assert!(mesh.distance_to_point(&pos, &pt, true) > 1.0e10);
+
+    let ball = Ball::new(1.0);
+    assert!(!query::intersection_test(&pos, &mesh, &pos, &ball).unwrap());
+    assert!(query::contact(&pos, &mesh, &pos, &ball, 1.0)
+        .unwrap()
+        .is_none());
+}
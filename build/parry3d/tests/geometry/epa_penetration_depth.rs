@@ -0,0 +1,41 @@
+use na::{Isometry3, Vector3};
+use parry3d::query::epa::{self, EPA};
+use parry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn epa_penetration_depth_cuboid_cuboid() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 2.0, 2.0));
+    let cuboid2 = Cuboid::new(Vector3::repeat(0.5));
+    let pos12 = Isometry3::translation(1.3, 0.1, -0.2);
+
+    let result = epa::penetration_depth(&pos12, &cuboid1, &cuboid2).unwrap();
+    assert_relative_eq!(result.depth, 0.2, epsilon = 1.0e-4);
+    assert_relative_eq!(result.normal1.into_inner(), Vector3::x(), epsilon = 1.0e-4);
+    assert_relative_eq!(result.normal2.into_inner(), -Vector3::x(), epsilon = 1.0e-4);
+    assert_relative_eq!(result.witness1.x, 1.0, epsilon = 1.0e-4);
+    assert_relative_eq!(result.witness2.x, -0.5, epsilon = 1.0e-4);
+
+    // Translating the second shape by the penetration depth separates the shapes.
+    let pos12 = Isometry3::translation(1.3 + result.depth + 1.0e-3, 0.1, -0.2);
+    assert!(epa::penetration_depth(&pos12, &cuboid1, &cuboid2).is_none());
+
+    let pos12 = Isometry3::translation(3.0, 0.0, 0.0);
+    assert!(epa::penetration_depth(&pos12, &cuboid1, &cuboid2).is_none());
+}
+
+#[test]
+fn epa_penetration_depth_with_budget() {
+    let ball1 = Ball::new(1.0);
+    let ball2 = Ball::new(0.5);
+    let pos12 = Isometry3::translation(0.3, 0.4, 0.0);
+
+    let mut epa = EPA::new();
+    let exact = epa::penetration_depth_with_epa(&pos12, &ball1, &ball2, &mut epa).unwrap();
+    assert_relative_eq!(exact.depth, 1.0, epsilon = 1.0e-3);
+
+    // A tiny budget yields a coarser approximation.
+    let mut epa = EPA::with_budget(2, 16);
+    let coarse = epa::penetration_depth_with_epa(&pos12, &ball1, &ball2, &mut epa).unwrap();
+    assert!(coarse.depth <= exact.depth + 1.0e-3);
+    assert!(coarse.depth >= 0.0);
+}
@@ -0,0 +1,105 @@
+use na::{Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::shape::{ConvexPolyhedron, Cuboid, FeatureId, Shape, TriMesh, Triangle};
+
+fn collect_features(shape: &dyn Shape) -> Vec<(FeatureId, Vec<Point3<Real>>)> {
+    let mut features = Vec::new();
+    shape.for_each_feature(&mut |id, vertices| features.push((id, vertices.to_vec())));
+    features
+}
+
+fn count_features(features: &[(FeatureId, Vec<Point3<Real>>)]) -> (usize, usize, usize) {
+    let mut counts = (0, 0, 0);
+
+    for (id, _) in features {
+        match id {
+            FeatureId::Vertex(_) => counts.0 += 1,
+            FeatureId::Edge(_) => counts.1 += 1,
+            FeatureId::Face(_) => counts.2 += 1,
+            FeatureId::Unknown => panic!("Unknown feature."),
+        }
+    }
+
+    counts
+}
+
+// Checks that projecting a point located outside of each feature returns this feature.
+fn assert_features_match_projections(shape: &dyn Shape, center: Point3<Real>) {
+    for (id, vertices) in collect_features(shape) {
+        let centroid = Point3::from(
+            vertices.iter().map(|pt| pt.coords).sum::<Vector3<Real>>() / vertices.len() as Real,
+        );
+        let dir = match id {
+            FeatureId::Face(_) => (vertices[1] - vertices[0]).cross(&(vertices[2] - vertices[0])),
+            _ => centroid - center,
+        };
+        let query = centroid + dir.normalize() * 0.5;
+        let (_, projected) = shape.project_local_point_and_get_feature(&query);
+        assert_eq!(projected, id, "{:?}", vertices);
+    }
+}
+
+#[test]
+fn cuboid_features() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let features = collect_features(&cuboid);
+    assert_eq!(count_features(&features), cuboid.num_features());
+    assert_features_match_projections(&cuboid, Point3::origin());
+}
+
+#[test]
+fn triangle_features() {
+    let triangle = Triangle::new(
+        Point3::origin(),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, -2.0),
+    );
+    let features = collect_features(&triangle);
+    assert_eq!(count_features(&features), (3, 3, 2));
+    assert_features_match_projections(&triangle, triangle.center());
+}
+
+#[test]
+fn convex_polyhedron_features() {
+    let (vertices, _) = Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).to_trimesh();
+    let polyhedron = ConvexPolyhedron::from_convex_hull(&vertices).unwrap();
+    let features = collect_features(&polyhedron);
+    assert_eq!(count_features(&features), polyhedron.num_features());
+
+    // The face vertices are counterclockwise around the outward face normals.
+    for (id, vertices) in &features {
+        if let FeatureId::Face(i) = id {
+            let normal = (vertices[1] - vertices[0]).cross(&(vertices[2] - vertices[0]));
+            let expected = polyhedron.faces()[*i as usize].normal;
+            assert!(normal.normalize().dot(&expected) > 0.999);
+        }
+    }
+
+    assert_features_match_projections(&polyhedron, Point3::origin());
+}
+
+#[test]
+fn trimesh_features() {
+    let (vertices, indices) = Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).to_trimesh();
+    let trimesh = TriMesh::new(vertices, indices);
+    let features = collect_features(&trimesh);
+    assert_eq!(count_features(&features), trimesh.num_features());
+
+    for (id, vertices) in &features {
+        match id {
+            FeatureId::Face(i) => {
+                let tri = trimesh.triangle(*i);
+                assert_eq!(vertices, &vec![tri.a, tri.b, tri.c]);
+            }
+            FeatureId::Edge(i) => {
+                // The edge is the `i % 3`-th edge of the triangle `i / 3`.
+                let tri = trimesh.triangle(i / 3);
+                let k = (i % 3) as usize;
+                let tri_vertices = tri.vertices();
+                assert_eq!(vertices[0], tri_vertices[k]);
+                assert_eq!(vertices[1], tri_vertices[(k + 1) % 3]);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use parry3d::query::{self, DefaultQueryDispatcher, PointQuery, Ray, RayCast};
+use parry3d::shape::{Ball, Compound, Cuboid, FilteredShape, HeightField, SharedShape, TriMesh};
+
+// A floor at y = 0 made of triangles 0 and 1, and a platform at y = 1 made of triangles 2 and 3.
+fn floor_and_platform() -> TriMesh {
+    let vertices = vec![
+        Point3::new(-5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, 5.0),
+        Point3::new(-5.0, 0.0, 5.0),
+        Point3::new(-1.0, 1.0, -1.0),
+        Point3::new(1.0, 1.0, -1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(-1.0, 1.0, 1.0),
+    ];
+    let indices = vec![[0, 2, 1], [0, 3, 2], [4, 6, 5], [4, 7, 6]];
+    TriMesh::new(vertices, indices)
+}
+
+#[test]
+fn filtered_trimesh_ray_cast_and_projection() {
+    let trimesh = floor_and_platform();
+    let no_platform = FilteredShape::new(&trimesh, |tri: u32| tri < 2);
+    let ray = Ray::new(Point3::new(0.2, 5.0, 0.1), -Vector3::y());
+    let position = Isometry3::translation(0.0, 1.0, 0.0);
+
+    let toi = trimesh.cast_ray(&position, &ray, 100.0, true).unwrap();
+    assert_relative_eq!(toi, 3.0, epsilon = 1.0e-5);
+    let toi = no_platform.cast_ray(&position, &ray, 100.0, true).unwrap();
+    assert_relative_eq!(toi, 4.0, epsilon = 1.0e-5);
+
+    let pt = Point3::new(0.2, 2.5, 0.1);
+    let proj = trimesh.project_point(&position, &pt, false);
+    assert_relative_eq!(proj.point, Point3::new(0.2, 2.0, 0.1), epsilon = 1.0e-5);
+    let proj = no_platform.project_point(&position, &pt, false);
+    assert_relative_eq!(proj.point, Point3::new(0.2, 1.0, 0.1), epsilon = 1.0e-5);
+
+    // Everything is filtered out.
+    let nothing = FilteredShape::new(&trimesh, |_: u32| false);
+    assert!(nothing.cast_ray(&position, &ray, 100.0, true).is_none());
+    assert!(!nothing.intersects_ray(&position, &ray, 100.0));
+}
+
+#[test]
+fn filtered_compound_shape_cast() {
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(0.0, 0.0, 0.0),
+            SharedShape::new(Cuboid::new(Vector3::new(5.0, 0.5, 5.0))),
+        ),
+        (
+            Isometry3::translation(0.0, 2.0, 0.0),
+            SharedShape::new(Cuboid::new(Vector3::new(1.0, 0.5, 1.0))),
+        ),
+    ]);
+    let destroyed = [false, true];
+    let filtered = FilteredShape::new(&compound, |part: u32| !destroyed[part as usize]);
+    let ball = Ball::new(0.5);
+    let pos12 = Isometry3::translation(0.0, 6.0, 0.0);
+    let vel12 = Vector3::new(0.0, -1.0, 0.0);
+
+    let toi = query::details::time_of_impact_composite_shape_shape(
+        &DefaultQueryDispatcher,
+        &pos12,
+        &vel12,
+        &compound,
+        &ball,
+        100.0,
+        0.0,
+    )
+    .unwrap();
+    assert_relative_eq!(toi.toi, 3.0, epsilon = 1.0e-4);
+
+    let toi = query::details::time_of_impact_composite_shape_shape(
+        &DefaultQueryDispatcher,
+        &pos12,
+        &vel12,
+        &filtered,
+        &ball,
+        100.0,
+        0.0,
+    )
+    .unwrap();
+    assert_relative_eq!(toi.toi, 5.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn filtered_heightfield_ray_cast_and_projection() {
+    let heightfield = HeightField::new(DMatrix::zeros(3, 3), Vector3::new(2.0, 1.0, 2.0));
+    let ray = Ray::new(Point3::new(0.1, 5.0, -0.2), -Vector3::y());
+
+    let inter = heightfield
+        .cast_local_ray_and_get_normal(&ray, 100.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 5.0, epsilon = 1.0e-5);
+
+    // Find the triangle hit by the ray, and ignore it.
+    for tri in 0..8 {
+        let only = FilteredShape::new(&heightfield, |t: u32| t == tri);
+        if only.cast_local_ray(&ray, 100.0, true).is_some() {
+            let without = FilteredShape::new(&heightfield, |t: u32| t != tri);
+            assert!(without.cast_local_ray(&ray, 100.0, true).is_none());
+
+            let pt = Point3::new(0.1, 1.0, -0.2);
+            let proj = without.project_local_point(&pt, false);
+            assert!(proj.point.y.abs() < 1.0e-5);
+            assert!(na::distance(&proj.point, &pt) > 1.0);
+            return;
+        }
+    }
+
+    panic!("No triangle of the heightfield was hit.");
+}
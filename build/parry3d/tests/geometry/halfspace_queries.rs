@@ -0,0 +1,83 @@
+use na::{DMatrix, Isometry3, Vector3};
+use parry3d::math::Real;
+use parry3d::query;
+use parry3d::shape::{HalfSpace, HeightField, Shape, SphericalSector, Torus};
+
+fn assert_distance_to_ground(shape: &dyn Shape, pos: &Isometry3<Real>, expected: Real) {
+    let ground = HalfSpace::new(Vector3::y_axis());
+    let identity = Isometry3::identity();
+
+    let dist = query::distance(&identity, &ground, pos, shape).unwrap();
+    assert_relative_eq!(dist, expected, epsilon = 1.0e-5);
+    let dist = query::distance(pos, shape, &identity, &ground).unwrap();
+    assert_relative_eq!(dist, expected, epsilon = 1.0e-5);
+
+    let contact = query::contact(&identity, &ground, pos, shape, 1.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, expected, epsilon = 1.0e-5);
+    assert_relative_eq!(contact.point1.y, 0.0, epsilon = 1.0e-5);
+    assert_relative_eq!(contact.point2.y, expected, epsilon = 1.0e-5);
+
+    let contact = query::contact(pos, shape, &identity, &ground, 1.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, expected, epsilon = 1.0e-5);
+    assert_relative_eq!(contact.point1.y, expected, epsilon = 1.0e-5);
+    assert_relative_eq!(contact.point2.y, 0.0, epsilon = 1.0e-5);
+
+    // The shape falls on the ground.
+    let toi = query::time_of_impact(
+        &identity,
+        &Vector3::zeros(),
+        &ground,
+        pos,
+        &Vector3::new(0.0, -2.0, 0.0),
+        shape,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+    assert_relative_eq!(toi.toi, expected / 2.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn halfspace_heightfield_queries() {
+    let mut heights = DMatrix::repeat(4, 4, 1.0);
+    heights[(1, 2)] = 0.25;
+    let heightfield = HeightField::new(heights, Vector3::new(3.0, 1.0, 3.0));
+    let pos = Isometry3::translation(0.0, 0.5, 0.0);
+
+    assert_distance_to_ground(&heightfield, &pos, 0.75);
+}
+
+#[test]
+fn halfspace_torus_queries() {
+    let torus = Torus::new(1.0, 0.25);
+    assert_distance_to_ground(&torus, &Isometry3::translation(0.0, 0.5, 0.0), 0.25);
+
+    // The torus standing on its side.
+    let pos = Isometry3::new(
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector3::x() * std::f32::consts::FRAC_PI_2,
+    );
+    assert_distance_to_ground(&torus, &pos, 0.75);
+}
+
+#[test]
+fn halfspace_concave_spherical_sector_queries() {
+    // The lowest points of this sector are on the rim of its spherical cap.
+    let sector = SphericalSector::new(1.0, 2.5);
+    let pos = Isometry3::translation(0.0, 1.0, 0.0);
+    let expected = 1.0 + 2.5f32.cos();
+
+    assert_distance_to_ground(&sector, &pos, expected);
+    assert!(query::intersection_test(
+        &Isometry3::identity(),
+        &HalfSpace::new(Vector3::y_axis()),
+        &Isometry3::translation(0.0, 0.5, 0.0),
+        &sector,
+    )
+    .unwrap());
+}
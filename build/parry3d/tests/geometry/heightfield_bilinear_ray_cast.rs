@@ -0,0 +1,68 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{Ray, RayCast};
+use parry3d::shape::HeightField;
+
+fn vertical_ray(x: Real, z: Real) -> Ray {
+    Ray::new(Point3::new(x, 2.0, z), -Vector3::y())
+}
+
+#[test]
+fn bilinear_ray_cast_on_non_flat_cell_is_smooth() {
+    // A single cell with only one raised corner.
+    let heights = DMatrix::from_row_slice(2, 2, &[0.0, 0.0, 0.0, 1.0]);
+    let heightfield = HeightField::new(heights, Vector3::repeat(1.0));
+
+    // At the center of the cell, the triangle diagonal has a height of 0 while the
+    // bilinear patch has a height of 0.25.
+    let ray = vertical_ray(0.0, 0.0);
+    let tri = heightfield
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    let bilinear = heightfield
+        .cast_local_ray_bilinear(&ray, Real::MAX)
+        .unwrap();
+    assert_relative_eq!(tri.toi, 2.0, epsilon = 1.0e-5);
+    assert_relative_eq!(bilinear.toi, 1.75, epsilon = 1.0e-5);
+
+    // The normals of the triangles jump across the cell diagonal, not the bilinear ones.
+    let (ray1, ray2) = (vertical_ray(0.01, 0.01), vertical_ray(-0.01, -0.01));
+    let tri1 = heightfield.cast_local_ray_and_get_normal(&ray1, Real::MAX, true);
+    let tri2 = heightfield.cast_local_ray_and_get_normal(&ray2, Real::MAX, true);
+    let bilinear1 = heightfield
+        .cast_local_ray_bilinear(&ray1, Real::MAX)
+        .unwrap();
+    let bilinear2 = heightfield
+        .cast_local_ray_bilinear(&ray2, Real::MAX)
+        .unwrap();
+
+    assert!(tri1.unwrap().normal.dot(&tri2.unwrap().normal) < 0.9);
+    assert!(bilinear1.normal.dot(&bilinear2.normal) > 0.999);
+    assert!(bilinear1.normal.dot(&ray1.dir) < 0.0);
+}
+
+#[test]
+fn bilinear_ray_cast_on_flat_cells_matches_triangles() {
+    // All the cells lie on the plane `y = x + 0.5 * z`.
+    let heights = DMatrix::from_fn(3, 3, |i, j| j as Real * 0.5 + i as Real * 0.25);
+    let heightfield = HeightField::new(heights, Vector3::new(2.0, 1.0, 2.0));
+    let pos = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.1, 0.4, -0.2));
+    let rays = [
+        Ray::new(Point3::new(-1.2, 3.0, -0.7), Vector3::new(0.8, -1.0, 0.3)),
+        Ray::new(Point3::new(0.9, 2.0, 0.6), Vector3::new(-0.6, -1.0, -0.4)),
+        Ray::new(Point3::new(0.3, 2.0, -0.2), -Vector3::y()),
+    ];
+
+    for ray in rays.iter() {
+        let ray = ray.transform_by(&pos);
+        let tri = heightfield
+            .cast_ray_and_get_normal(&pos, &ray, Real::MAX, true)
+            .unwrap();
+        let bilinear = heightfield
+            .cast_ray_bilinear(&pos, &ray, Real::MAX)
+            .unwrap();
+
+        assert_relative_eq!(tri.toi, bilinear.toi, epsilon = 1.0e-5);
+        assert_relative_eq!(tri.normal, bilinear.normal, epsilon = 1.0e-5);
+    }
+}
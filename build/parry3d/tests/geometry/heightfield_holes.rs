@@ -0,0 +1,72 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{
+    ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray, RayCast,
+};
+use parry3d::shape::{Ball, HeightField, HeightFieldCellStatus, Shape};
+
+// A flat 4x4 heightfield with unit cells and a hole at the cell spanning
+// x in [0, 1] and z in [-1, 0].
+fn heightfield_with_hole() -> HeightField {
+    let mut heightfield = HeightField::new(DMatrix::zeros(5, 5), Vector3::new(4.0, 1.0, 4.0));
+    heightfield.set_cell_status(1, 2, HeightFieldCellStatus::CELL_REMOVED);
+    heightfield
+}
+
+#[test]
+fn heightfield_queries_skip_holes() {
+    let heightfield = heightfield_with_hole();
+    assert_eq!(heightfield.triangles_at(1, 2), (None, None));
+    assert_eq!(heightfield.triangles().count(), 30);
+    assert_eq!(heightfield.to_trimesh().1.len(), 30);
+
+    let ray = Ray::new(Point3::new(0.5, 2.0, -0.5), -Vector3::y());
+    assert!(heightfield.cast_local_ray(&ray, Real::MAX, true).is_none());
+    let ray = Ray::new(Point3::new(-0.5, 2.0, -0.5), -Vector3::y());
+    assert_eq!(heightfield.cast_local_ray(&ray, Real::MAX, true), Some(2.0));
+
+    // The point above the hole is projected on the closest edge of the hole.
+    let proj = heightfield.project_local_point(&Point3::new(0.6, 1.0, -0.5), false);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.0, -0.5), epsilon = 1.0e-5);
+
+    let mut all_removed = heightfield.clone();
+    for i in 0..4 {
+        for j in 0..4 {
+            all_removed.set_cell_status(i, j, HeightFieldCellStatus::CELL_REMOVED);
+        }
+    }
+    let proj = all_removed.project_local_point(&Point3::origin(), false);
+    assert!(proj.point.x >= Real::MAX);
+}
+
+#[test]
+fn heightfield_contacts_skip_holes() {
+    let heightfield = heightfield_with_hole();
+    let ball = Ball::new(0.2);
+    let contacts_at = |x: Real, z: Real| {
+        let pos12 = Isometry3::translation(x, 0.1, z).inv_mul(&Isometry3::identity());
+        let mut manifolds: Vec<ContactManifold<(), ()>> = vec![];
+        DefaultQueryDispatcher
+            .contact_manifolds(&pos12, &ball, &heightfield, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        manifolds.iter().map(|m| m.points.len()).sum::<usize>()
+    };
+
+    assert!(contacts_at(-0.5, -0.5) > 0);
+    assert_eq!(contacts_at(0.5, -0.5), 0);
+}
+
+#[test]
+fn scaled_heightfield_keeps_its_holes() {
+    let mut heightfield = heightfield_with_hole();
+    heightfield.set_cell_status(3, 0, HeightFieldCellStatus::ZIGZAG_SUBDIVISION);
+
+    let scaled = heightfield
+        .scaled(&Vector3::new(2.0, 1.0, 2.0), 10)
+        .unwrap();
+    let scaled = scaled.as_heightfield().unwrap();
+    assert_eq!(scaled.cells_statuses(), heightfield.cells_statuses());
+
+    let ray = Ray::new(Point3::new(1.0, 2.0, -1.0), -Vector3::y());
+    assert!(scaled.cast_local_ray(&ray, Real::MAX, true).is_none());
+}
@@ -0,0 +1,105 @@
+use na::{DMatrix, Point3, Vector3};
+use parry3d::bounding_volume::AABB;
+use parry3d::math::Real;
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{HeightField, Shape, TriMesh};
+
+// A heightfield with irregularly spaced rows and columns, following the plane `y = x`.
+fn non_uniform_heightfield() -> HeightField {
+    let xs = vec![0.0, 1.0, 3.0, 6.0];
+    let zs = vec![10.0, 10.5, 12.0];
+    let heights = DMatrix::from_fn(zs.len(), xs.len(), |_, j| xs[j]);
+    HeightField::with_coordinates(heights, xs, zs, Vector3::repeat(1.0))
+}
+
+#[test]
+fn non_uniform_heightfield_geometry() {
+    let heightfield = non_uniform_heightfield();
+    assert_eq!(
+        *heightfield.root_aabb(),
+        AABB::new(Point3::new(0.0, 0.0, 10.0), Point3::new(6.0, 6.0, 12.0))
+    );
+    assert_eq!(heightfield.x_at(2), 3.0);
+    assert_eq!(heightfield.z_at(1), 10.5);
+    assert_eq!(
+        heightfield.cell_at_point(&Point3::new(2.0, 0.0, 11.0)),
+        Some((1, 1))
+    );
+    assert_eq!(
+        heightfield.cell_at_point(&Point3::new(5.0, 0.0, 10.2)),
+        Some((0, 2))
+    );
+    assert_eq!(
+        heightfield.cell_at_point(&Point3::new(7.0, 0.0, 11.0)),
+        None
+    );
+
+    let tri = heightfield.triangles_at(1, 2).0.unwrap();
+    assert_eq!(tri.a, Point3::new(3.0, 3.0, 10.5));
+    assert_eq!(tri.b, Point3::new(3.0, 3.0, 12.0));
+    assert_eq!(tri.c, Point3::new(6.0, 6.0, 10.5));
+
+    let mut cells = vec![];
+    let aabb = AABB::new(Point3::new(4.0, 4.0, 10.8), Point3::new(5.0, 5.0, 11.0));
+    heightfield.map_elements_in_local_aabb(&aabb, &mut |_, tri| cells.push(tri.a));
+    assert_eq!(cells.len(), 2);
+    assert!(cells.iter().all(|a| a.x >= 3.0 && a.z >= 10.5));
+}
+
+#[test]
+fn non_uniform_heightfield_queries() {
+    let heightfield = non_uniform_heightfield();
+
+    let ray = Ray::new(Point3::new(4.5, 5.0, 11.0), -Vector3::y());
+    assert_relative_eq!(
+        heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap(),
+        0.5,
+        epsilon = 1.0e-5
+    );
+
+    // Horizontal rays crossing several cells of different widths.
+    let ray = Ray::new(Point3::new(-1.0, 2.0, 11.0), Vector3::x());
+    assert_relative_eq!(
+        heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap(),
+        3.0,
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        heightfield
+            .cast_local_ray_bilinear(&ray, Real::MAX)
+            .unwrap()
+            .toi,
+        3.0,
+        epsilon = 1.0e-5
+    );
+    let ray = Ray::new(Point3::new(10.0, 4.0, 10.2), -Vector3::x());
+    assert_relative_eq!(
+        heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap(),
+        6.0,
+        epsilon = 1.0e-5
+    );
+
+    let trimesh = TriMesh::from(heightfield.clone());
+    for pt in &[
+        Point3::new(0.5, 3.0, 10.2),
+        Point3::new(5.0, 1.0, 11.5),
+        Point3::new(2.0, 2.5, 10.7),
+    ] {
+        let proj = heightfield.project_local_point(pt, false);
+        let expected = trimesh.project_local_point(pt, false);
+        assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn scaled_non_uniform_heightfield_keeps_its_coordinates() {
+    let heightfield = non_uniform_heightfield();
+    let scaled = heightfield
+        .scaled(&Vector3::new(2.0, 1.0, 3.0), 10)
+        .unwrap();
+    let scaled = scaled.as_heightfield().unwrap();
+    assert_eq!(scaled.x_coordinates(), heightfield.x_coordinates());
+    assert_eq!(scaled.z_coordinates(), heightfield.z_coordinates());
+    assert_eq!(scaled.x_at(3), 12.0);
+    assert_eq!(scaled.z_at(2), 36.0);
+}
@@ -0,0 +1,72 @@
+use na::{DMatrix, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::shape::{HeightField, HeightFieldCellStatus};
+
+#[test]
+fn heightfield_bilinear_height_sampling() {
+    // A single cell with only one raised corner.
+    let heights = DMatrix::from_row_slice(2, 2, &[0.0, 0.0, 0.0, 1.0]);
+    let heightfield = HeightField::new(heights, Vector3::new(2.0, 3.0, 2.0));
+
+    assert_relative_eq!(
+        heightfield.height_at(0.0, 0.0).unwrap(),
+        0.75,
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        heightfield.height_at(1.0, 1.0).unwrap(),
+        3.0,
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        heightfield.height_at(-1.0, 1.0).unwrap(),
+        0.0,
+        epsilon = 1.0e-5
+    );
+    assert_eq!(heightfield.height_at(1.1, 0.0), None);
+
+    // The normal is flat along the edges of the cell adjacent to the lowest corner.
+    assert_relative_eq!(
+        *heightfield.normal_at(-1.0, -1.0).unwrap(),
+        Vector3::y(),
+        epsilon = 1.0e-5
+    );
+    let normal = heightfield.normal_at(1.0, 1.0).unwrap();
+    assert_relative_eq!(
+        *normal,
+        Vector3::new(-1.5, 1.0, -1.5).normalize(),
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn heightfield_sampling_matches_bilinear_ray_cast() {
+    let heights = DMatrix::from_fn(4, 5, |i, j| ((i * 7 + j * 3) % 5) as Real * 0.3);
+    let mut heightfield = HeightField::new(heights, Vector3::new(4.0, 2.0, 3.0));
+
+    for k in 0..20 {
+        let x = -1.9 + k as Real * 0.187;
+        let z = 1.4 - k as Real * 0.14;
+        let ray = parry3d::query::Ray::new(Point3::new(x, 10.0, z), -Vector3::y());
+        let hit = heightfield
+            .cast_local_ray_bilinear(&ray, Real::MAX)
+            .unwrap();
+        assert_relative_eq!(
+            10.0 - hit.toi,
+            heightfield.height_at(x, z).unwrap(),
+            epsilon = 1.0e-4
+        );
+        assert_relative_eq!(
+            hit.normal,
+            *heightfield.normal_at(x, z).unwrap(),
+            epsilon = 1.0e-4
+        );
+    }
+
+    let (i, j) = heightfield
+        .cell_at_point(&Point3::new(0.1, 0.0, 0.1))
+        .unwrap();
+    heightfield.set_cell_status(i, j, HeightFieldCellStatus::LEFT_TRIANGLE_REMOVED);
+    assert_eq!(heightfield.height_at(0.1, 0.1), None);
+    assert_eq!(heightfield.normal_at(0.1, 0.1), None);
+}
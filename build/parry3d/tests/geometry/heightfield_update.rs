@@ -0,0 +1,45 @@
+use na::{DMatrix, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{Ray, RayCast};
+use parry3d::shape::HeightField;
+
+#[test]
+fn heightfield_set_height_refits_aabb() {
+    let mut heightfield = HeightField::new(DMatrix::zeros(5, 5), Vector3::new(4.0, 2.0, 4.0));
+    assert_eq!(heightfield.root_aabb().mins.y, 0.0);
+    assert_eq!(heightfield.root_aabb().maxs.y, 0.0);
+
+    // Dig a crater in the middle of the heightfield.
+    heightfield.set_height(2, 2, -1.0);
+    assert_eq!(heightfield.heights()[(2, 2)], -1.0);
+    assert_eq!(heightfield.root_aabb().mins.y, -2.0);
+    assert_eq!(heightfield.root_aabb().maxs.y, 0.0);
+
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    assert_eq!(heightfield.cast_local_ray(&ray, Real::MAX, true), Some(7.0));
+
+    // Fill it back: the AABB shrinks again.
+    heightfield.set_height(2, 2, 0.0);
+    assert_eq!(heightfield.root_aabb().mins.y, 0.0);
+    assert_eq!(heightfield.cast_local_ray(&ray, Real::MAX, true), Some(5.0));
+}
+
+#[test]
+fn heightfield_set_heights_in_region_refits_aabb() {
+    let mut heightfield = HeightField::new(DMatrix::zeros(5, 5), Vector3::new(4.0, 1.0, 4.0));
+    heightfield.set_height(0, 0, 3.0);
+
+    heightfield.set_heights_in_region(1, 1, &DMatrix::from_element(2, 3, 1.5));
+    assert_eq!(heightfield.heights()[(1, 1)], 1.5);
+    assert_eq!(heightfield.heights()[(2, 3)], 1.5);
+    assert_eq!(heightfield.heights()[(3, 3)], 0.0);
+    assert_eq!(heightfield.root_aabb().maxs.y, 3.0);
+
+    // Replace the highest vertex.
+    heightfield.set_heights_in_region(0, 0, &DMatrix::from_element(1, 1, -0.5));
+    assert_eq!(heightfield.root_aabb().mins.y, -0.5);
+    assert_eq!(heightfield.root_aabb().maxs.y, 1.5);
+
+    let expected = HeightField::new(heightfield.heights().clone(), *heightfield.scale());
+    assert_eq!(heightfield.root_aabb(), expected.root_aabb());
+}
@@ -0,0 +1,38 @@
+use na::{Isometry3, Matrix4, Vector3};
+use parry3d::math::{isometry_from_matrix_array, isometry_to_matrix_array};
+
+#[test]
+fn isometry_matrix_array_round_trip() {
+    let isometries = [
+        Isometry3::identity(),
+        Isometry3::translation(1.0, -2.0, 3.0),
+        Isometry3::new(Vector3::zeros(), Vector3::new(0.1, 0.2, 0.3)),
+        Isometry3::new(Vector3::new(-4.0, 5.0, 0.5), Vector3::new(2.0, -1.0, 0.7)),
+    ];
+
+    for iso in &isometries {
+        let array = isometry_to_matrix_array(iso);
+        assert_eq!(&array[..], iso.to_homogeneous().as_slice());
+
+        let result = isometry_from_matrix_array(&array).unwrap();
+        assert_relative_eq!(result, *iso, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn isometry_matrix_array_rejects_non_rigid() {
+    let iso = Isometry3::new(Vector3::new(-4.0, 5.0, 0.5), Vector3::new(2.0, -1.0, 0.7));
+
+    let scaled = iso.to_homogeneous() * Matrix4::new_scaling(2.0);
+    assert!(isometry_from_matrix_array(scaled.as_slice()).is_none());
+
+    let nonuniform =
+        iso.to_homogeneous() * Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 1.0, 1.1));
+    assert!(isometry_from_matrix_array(nonuniform.as_slice()).is_none());
+
+    let mirrored =
+        iso.to_homogeneous() * Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, -1.0, 1.0));
+    assert!(isometry_from_matrix_array(mirrored.as_slice()).is_none());
+
+    assert!(isometry_from_matrix_array(&[1.0; 9]).is_none());
+}
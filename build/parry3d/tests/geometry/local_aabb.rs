@@ -0,0 +1,65 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::shape::{Capsule, SharedShape, SupportMap};
+
+#[test]
+fn local_aabb_half_extents_and_center() {
+    let points = vec![
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, -0.5, 0.0),
+        Point3::new(0.0, -0.5, -1.0),
+        Point3::new(1.0, -0.5, 2.0),
+    ];
+    let shapes = vec![
+        SharedShape::ball(1.5),
+        SharedShape::cuboid(1.0, 2.0, 3.0),
+        SharedShape::capsule(points[0], points[1], 0.3),
+        SharedShape::cylinder(1.0, 0.5),
+        SharedShape::cone(1.0, 0.5),
+        SharedShape::round_cuboid(1.0, 2.0, 3.0, 0.1),
+        SharedShape::segment(points[0], points[3]),
+        SharedShape::triangle(points[0], points[1], points[2]),
+        SharedShape::convex_hull(&points).unwrap(),
+        SharedShape::trimesh(points.clone(), vec![[0, 1, 2], [0, 2, 3]]),
+        SharedShape::compound(vec![
+            (
+                Isometry3::translation(1.0, 0.0, 0.0),
+                SharedShape::ball(0.5),
+            ),
+            (
+                Isometry3::translation(0.0, 3.0, 0.0),
+                SharedShape::cuboid(1.0, 0.5, 0.5),
+            ),
+        ]),
+    ];
+
+    for shape in &shapes {
+        let aabb = shape.compute_local_aabb();
+        assert_relative_eq!(shape.local_aabb_half_extents(), aabb.half_extents());
+        assert_relative_eq!(shape.local_aabb_center(), aabb.center());
+    }
+}
+
+#[test]
+fn diagonal_capsule_aabb_is_tight() {
+    let capsule = Capsule::new(
+        Point3::new(-1.0, -2.0, 0.5),
+        Point3::new(2.0, 1.0, 3.0),
+        0.4,
+    );
+    let positions = [
+        Isometry3::identity(),
+        Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, -1.2, 0.7)),
+    ];
+
+    for pos in &positions {
+        let aabb = capsule.aabb(pos);
+
+        for i in 0..3 {
+            let axis = Vector3::ith(i, 1.0);
+            let max_pt = capsule.support_point(pos, &axis);
+            let min_pt = capsule.support_point(pos, &-axis);
+            assert_relative_eq!(aabb.maxs[i], max_pt[i], epsilon = 1.0e-5);
+            assert_relative_eq!(aabb.mins[i], min_pt[i], epsilon = 1.0e-5);
+        }
+    }
+}
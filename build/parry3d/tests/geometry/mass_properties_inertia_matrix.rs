@@ -0,0 +1,18 @@
+use na::{Matrix3, Point3};
+use parry3d::mass_properties::MassProperties;
+
+#[test]
+fn inertia_matrix_with_reflected_eigenbasis() {
+    // The eigenvectors computed for this matrix form a reflection.
+    let inertia = Matrix3::new(2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 3.0);
+    assert!(inertia.symmetric_eigen().eigenvectors.determinant() < 0.0);
+
+    let props = MassProperties::with_inertia_matrix(Point3::origin(), 1.0, inertia);
+    let frame = props.principal_inertia_local_frame.to_rotation_matrix();
+    assert_relative_eq!(frame.matrix().determinant(), 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        props.reconstruct_inertia_matrix(),
+        inertia,
+        epsilon = 1.0e-4
+    );
+}
@@ -0,0 +1,51 @@
+use na::{Isometry3, Vector3};
+use parry3d::query;
+use parry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn ball_ball_minimum_translation_vector() {
+    let ball1 = Ball::new(1.0);
+    let ball2 = Ball::new(0.5);
+    let pos1 = Isometry3::translation(1.0, 2.0, 3.0);
+    let pos2 = Isometry3::translation(2.0, 3.0, 3.0);
+
+    let (dir, depth) = query::minimum_translation_vector(&pos1, &ball1, &pos2, &ball2)
+        .unwrap()
+        .unwrap();
+    let expected_dir = Vector3::new(1.0, 1.0, 0.0).normalize();
+    assert_relative_eq!(*dir, expected_dir, epsilon = 1.0e-5);
+    assert_relative_eq!(depth, 1.5 - 2.0f32.sqrt(), epsilon = 1.0e-5);
+
+    // Applying the MTV leaves the balls touching.
+    let moved = Isometry3::from_parts((pos2.translation.vector + *dir * depth).into(), na::one());
+    let dist = query::distance(&pos1, &ball1, &moved, &ball2).unwrap();
+    assert_relative_eq!(dist, 0.0, epsilon = 1.0e-5);
+
+    let far = Isometry3::translation(5.0, 2.0, 3.0);
+    assert!(
+        query::minimum_translation_vector(&pos1, &ball1, &far, &ball2)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn cuboid_cuboid_minimum_translation_vector() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let cuboid2 = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let pos1 = Isometry3::new(Vector3::zeros(), Vector3::y() * std::f32::consts::FRAC_PI_2);
+    let pos2 = Isometry3::translation(0.2, 1.3, -0.1);
+
+    let (dir, depth) = query::minimum_translation_vector(&pos1, &cuboid1, &pos2, &cuboid2)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(*dir, Vector3::y(), epsilon = 1.0e-5);
+    assert_relative_eq!(depth, 0.2, epsilon = 1.0e-5);
+
+    let far = Isometry3::translation(0.2, 1.6, -0.1);
+    assert!(
+        query::minimum_translation_vector(&pos1, &cuboid1, &far, &cuboid2)
+            .unwrap()
+            .is_none()
+    );
+}
@@ -0,0 +1,86 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{self, PointQuery, Ray, RayCast};
+use parry3d::shape::{Ball, Cuboid, MinkowskiSum, RoundShape, Shape, SharedShape, SupportMap};
+
+#[test]
+fn cuboid_ball_minkowski_sum_is_a_round_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 2.0));
+    let sum = MinkowskiSum::new(cuboid, Ball::new(0.2));
+    let round_cuboid = RoundShape {
+        base_shape: cuboid,
+        border_radius: 0.2,
+    };
+
+    for dir in &[
+        Vector3::new(1.0, 0.3, -0.2),
+        Vector3::new(-0.4, 1.0, 0.7),
+        Vector3::new(0.1, -0.5, -1.0),
+    ] {
+        assert_relative_eq!(
+            sum.local_support_point(dir),
+            round_cuboid.local_support_point(dir),
+            epsilon = 1.0e-5
+        );
+
+        let ray = Ray::new(Point3::origin() + dir * 5.0, -dir);
+        let hit = sum
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        let expected = round_cuboid
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.toi, expected.toi, epsilon = 1.0e-4);
+        assert_relative_eq!(hit.normal, expected.normal, epsilon = 1.0e-3);
+
+        let pt = Point3::origin() + dir * 3.0;
+        assert_relative_eq!(
+            sum.distance_to_local_point(&pt, true),
+            round_cuboid.distance_to_local_point(&pt, true),
+            epsilon = 1.0e-2
+        );
+    }
+
+    let aabb = sum.compute_local_aabb();
+    assert_relative_eq!(aabb.mins, Point3::new(-1.2, -0.7, -2.2), epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.maxs, Point3::new(1.2, 0.7, 2.2), epsilon = 1.0e-5);
+    assert_relative_eq!(sum.ccd_thickness(), 0.7, epsilon = 1.0e-5);
+    assert!(sum.is_convex());
+}
+
+#[test]
+fn minkowski_sum_mass_properties() {
+    let sum = MinkowskiSum::new(
+        Cuboid::new(Vector3::new(1.0, 0.5, 0.25)),
+        Cuboid::new(Vector3::new(0.5, 1.0, 0.25)),
+    );
+    let mprops = sum.mass_properties(2.0);
+    let expected = Cuboid::new(Vector3::new(1.5, 1.5, 0.5)).mass_properties(2.0);
+    assert_relative_eq!(mprops, expected, epsilon = 1.0e-4);
+
+    // Curved shapes are approximated by the convex hull of some of their support points.
+    let sum = MinkowskiSum::new(Ball::new(0.5), Ball::new(0.25));
+    let expected = Ball::new(0.75).mass_properties(2.0);
+    let mprops = sum.mass_properties(2.0);
+    assert_relative_eq!(mprops.inv_mass, expected.inv_mass, max_relative = 0.02);
+    assert_relative_eq!(mprops.local_com, expected.local_com, epsilon = 1.0e-3);
+    assert_relative_eq!(
+        mprops.reconstruct_inertia_matrix(),
+        expected.reconstruct_inertia_matrix(),
+        epsilon = 1.0e-3,
+        max_relative = 0.03
+    );
+}
+
+#[test]
+fn minkowski_sum_contact() {
+    // A cuboid dilated by a ball touched by another ball.
+    let sum = SharedShape::minkowski_sum(Cuboid::new(Vector3::repeat(0.5)), Ball::new(0.25));
+    let ball = Ball::new(0.5);
+    let pos2 = Isometry3::translation(0.0, 1.0, 0.0);
+    let contact = query::contact(&Isometry3::identity(), &*sum, &pos2, &ball, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.25, epsilon = 1.0e-4);
+    assert_relative_eq!(*contact.normal1, Vector3::y(), epsilon = 1.0e-4);
+}
@@ -1,9 +1,92 @@
+mod aabb_for_motion;
 mod ball_ball_toi;
 mod ball_triangle_toi;
+mod bounding_sphere;
+mod capsule_world_segment;
+mod ccd_thickness;
+mod clip_polygon;
+mod clone_dyn;
+mod closest_points_with_features;
+mod compound_edit;
+mod compound_flatten;
+mod compound_parts_data;
+mod compound_ray_cast_all;
+mod contact_manifold_all_pairs;
+mod contact_manifold_convex_composite;
+mod contains_convex;
 mod convex_hull;
+mod convex_polyhedron_gauss_map;
+mod convex_polyhedron_merge_faces;
+mod convex_polyhedron_support_cache;
 mod cuboid_ray_cast;
+mod cuboid_subdivide;
+mod custom_shape;
 mod cylinder_cuboid_contact;
+mod cylinder_mass_properties;
+mod degenerate_shapes;
+mod distance_max_dist;
+mod ellipsoid;
+mod elliptic_cylinder;
+mod empty_trimesh;
 mod epa3;
+mod epa_penetration_depth;
+mod feature_enumeration;
+mod filtered_shape;
+mod halfspace_queries;
+mod heightfield_bilinear_ray_cast;
+mod heightfield_holes;
+mod heightfield_non_uniform;
+mod heightfield_sampling;
+mod heightfield_update;
+mod isometry_array;
+mod local_aabb;
+mod mass_properties_inertia_matrix;
+mod minimum_translation_vector;
+mod minkowski_sum;
+mod nonlinear_toi_conservative_advancement;
+mod num_features;
+mod parallel_queries;
+mod persistent_contact_manifolds;
+mod plate;
+mod point_distance;
+mod point_projection_features;
+mod point_projection_max_dist;
+mod point_query_consistency;
+mod prism;
+mod query_dispatcher_registry;
+mod ray_intersections;
+mod raycast_batch;
+mod round_shared_shape;
+mod round_trimesh;
+mod sampling;
+mod scaled_shape;
+mod sdf_shape;
+mod shape_approx_eq;
+mod shape_discretization;
+mod shape_mirror;
+mod shape_scale;
+mod shared_shape;
+mod slab;
+mod solid_of_revolution;
+mod spherical_sector;
 mod still_objects_toi;
+mod sweep_and_prune;
+mod tapered_capsule;
 mod time_of_impact3;
+mod toi_features;
+mod toi_grazing_normal;
+mod torus;
+mod transformed_shape;
+mod trimesh_from_triangle_soup;
+mod trimesh_intersects_ray;
+mod trimesh_obb;
+mod trimesh_pseudo_normals;
+mod trimesh_topology;
+mod trimesh_transform;
+mod trimesh_triangle_data;
 mod trimesh_trimesh_toi;
+mod trimesh_u16_indices;
+mod typed_shape;
+mod volume_surface_area;
+mod voxelized_mass_properties;
+mod voxels;
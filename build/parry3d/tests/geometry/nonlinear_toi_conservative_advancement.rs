@@ -0,0 +1,111 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::motion::{ConstantVelocityRigidMotion, RigidMotion};
+use parry3d::query::{self, TOIStatus};
+use parry3d::shape::{Cuboid, SharedShape, TriMesh};
+
+fn spinning_bat() -> (ConstantVelocityRigidMotion, SharedShape) {
+    // A long bar spinning fast around the z axis.
+    let motion = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry3::identity(),
+        Point3::origin(),
+        Vector3::zeros(),
+        Vector3::z() * 50.0,
+    );
+    (motion, SharedShape::cuboid(2.0, 0.05, 0.05))
+}
+
+fn static_motion(pos: Isometry3<f32>) -> ConstantVelocityRigidMotion {
+    ConstantVelocityRigidMotion::new(
+        0.0,
+        pos,
+        Point3::origin(),
+        Vector3::zeros(),
+        Vector3::zeros(),
+    )
+}
+
+#[test]
+fn conservative_advancement_spinning_bat_hits_thin_plate() {
+    let (motion1, bat) = spinning_bat();
+    let plate_pos = Isometry3::translation(0.0, 1.5, 0.0);
+    let motion2 = static_motion(plate_pos);
+    let plate = SharedShape::cuboid(0.2, 0.01, 0.5);
+
+    let toi = query::nonlinear_time_of_impact_conservative_advancement(
+        &motion1, &*bat, &motion2, &*plate, 1.0, 0.0,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(toi.status, TOIStatus::Converged);
+
+    // The side of the bat touches the corner (0.2, 1.49) of the plate.
+    let corner = Vector3::new(0.2f32, 1.49, 0.0);
+    let expected_angle = corner.y.atan2(corner.x) - (0.05 / corner.norm()).asin();
+    assert_relative_eq!(toi.toi, expected_angle / 50.0, epsilon = 1.0e-4);
+
+    let pos1 = motion1.position_at_time(toi.toi);
+    let dist = query::distance(&pos1, &*bat, &plate_pos, &*plate).unwrap();
+    assert!(dist < 1.0e-3);
+
+    // Same with the plate represented as a triangle mesh.
+    let (vertices, indices) = Cuboid::new(Vector3::new(0.2, 0.01, 0.5)).to_trimesh();
+    let mesh = SharedShape::new(TriMesh::new(vertices, indices));
+    let mesh_toi = query::nonlinear_time_of_impact_conservative_advancement(
+        &motion1, &*bat, &motion2, &*mesh, 1.0, 0.0,
+    )
+    .unwrap()
+    .unwrap();
+    assert_relative_eq!(mesh_toi.toi, toi.toi, epsilon = 1.0e-4);
+}
+
+#[test]
+fn conservative_advancement_target_distance_and_misses() {
+    let (motion1, bat) = spinning_bat();
+    let plate_pos = Isometry3::translation(0.0, 1.5, 0.0);
+    let motion2 = static_motion(plate_pos);
+    let plate = SharedShape::cuboid(0.2, 0.01, 0.5);
+
+    let toi = query::nonlinear_time_of_impact_conservative_advancement(
+        &motion1, &*bat, &motion2, &*plate, 1.0, 0.1,
+    )
+    .unwrap()
+    .unwrap();
+    let pos1 = motion1.position_at_time(toi.toi);
+    let dist = query::distance(&pos1, &*bat, &plate_pos, &*plate).unwrap();
+    assert_relative_eq!(dist, 0.1, epsilon = 1.0e-3);
+
+    // The plate is out of reach of the bat.
+    let far_pos = Isometry3::translation(0.0, 2.5, 0.0);
+    let result = query::nonlinear_time_of_impact_conservative_advancement(
+        &motion1,
+        &*bat,
+        &static_motion(far_pos),
+        &*plate,
+        1.0,
+        0.0,
+    )
+    .unwrap();
+    assert!(result.is_none());
+
+    // The impact happens after `max_toi`.
+    let result = query::nonlinear_time_of_impact_conservative_advancement(
+        &motion1, &*bat, &motion2, &*plate, 0.01, 0.0,
+    )
+    .unwrap();
+    assert!(result.is_none());
+
+    // The shapes already intersect.
+    let result = query::nonlinear_time_of_impact_conservative_advancement(
+        &motion1,
+        &*bat,
+        &static_motion(Isometry3::translation(1.0, 0.0, 0.0)),
+        &*plate,
+        1.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(result.status, TOIStatus::Penetrating);
+    assert_eq!(result.toi, 0.0);
+}
@@ -0,0 +1,34 @@
+use na::{Isometry3, Vector3};
+use parry3d::shape::{Ball, Compound, ConvexPolyhedron, Cuboid, Shape, SharedShape, TriMesh};
+
+#[test]
+fn cuboid_num_features() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(cuboid.num_features(), (8, 12, 6));
+
+    let (vertices, indices) = cuboid.to_trimesh();
+    let polyhedron = ConvexPolyhedron::from_convex_hull(&vertices).unwrap();
+    assert_eq!(polyhedron.num_features(), (8, 12, 6));
+
+    // Each square face is split into two triangles by a diagonal edge.
+    let trimesh = TriMesh::new(vertices, indices);
+    assert_eq!(trimesh.num_features(), (8, 18, 12));
+}
+
+#[test]
+fn curved_and_compound_num_features() {
+    assert_eq!(Ball::new(1.0).num_features(), (0, 0, 0));
+
+    let compound = Compound::new(vec![
+        (Isometry3::identity(), SharedShape::cuboid(1.0, 1.0, 1.0)),
+        (
+            Isometry3::translation(3.0, 0.0, 0.0),
+            SharedShape::ball(1.0),
+        ),
+        (
+            Isometry3::translation(-3.0, 0.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0, 1.0),
+        ),
+    ]);
+    assert_eq!(compound.num_features(), (16, 24, 12));
+}
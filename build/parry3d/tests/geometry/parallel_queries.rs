@@ -0,0 +1,132 @@
+#![cfg(feature = "parallel")]
+
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, DefaultQueryDispatcher, PointQuery, QueryDispatcher, Ray};
+use parry3d::shape::{Ball, Compound, Cuboid, SharedShape, TriMesh};
+
+fn wavy_trimesh(n: u32) -> TriMesh {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for i in 0..=n {
+        for j in 0..=n {
+            let y = (i as f32 * 0.7).sin() * (j as f32 * 0.3).cos();
+            vertices.push(Point3::new(i as f32, y, j as f32));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            indices.push([a, a + 1, a + n + 1]);
+            indices.push([a + 1, a + n + 2, a + n + 1]);
+        }
+    }
+
+    TriMesh::new(vertices, indices)
+}
+
+#[test]
+fn parallel_raycast_batch_matches_sequential() {
+    let trimesh = wavy_trimesh(30);
+    let position = Isometry3::translation(-2.0, 0.5, 1.0);
+    let rays: Vec<_> = (0..1001)
+        .map(|i| {
+            let origin = Point3::new((i % 37) as f32 * 0.9 - 3.0, 5.0, (i / 37) as f32 * 1.1);
+            Ray::new(origin, Vector3::new(0.05, -1.0, 0.02))
+        })
+        .collect();
+
+    let sequential = query::raycast_batch(&position, &trimesh, &rays, 100.0, true);
+    let parallel = query::parallel::raycast_batch(&position, &trimesh, &rays, 100.0, true);
+
+    assert_eq!(parallel.len(), rays.len());
+
+    for (par, seq) in parallel.iter().zip(sequential.iter()) {
+        assert_eq!(par.is_some(), seq.is_some());
+
+        if let (Some((par_part, par_hit)), Some((seq_part, seq_hit))) = (par, seq) {
+            assert_eq!(par_part, seq_part);
+            assert_eq!(par_hit.toi, seq_hit.toi);
+            assert_eq!(par_hit.normal, seq_hit.normal);
+        }
+    }
+}
+
+#[test]
+fn parallel_project_points_matches_sequential() {
+    let trimesh = wavy_trimesh(30);
+    let position = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 0.3, 0.0));
+    let points: Vec<_> = (0..500)
+        .map(|i| Point3::new((i % 23) as f32 * 1.3, (i % 7) as f32 - 3.0, (i / 23) as f32))
+        .collect();
+
+    let parallel = query::parallel::project_points(&position, &trimesh, &points, false);
+
+    assert_eq!(parallel.len(), points.len());
+
+    for (pt, proj) in points.iter().zip(parallel.iter()) {
+        let expected = trimesh.project_point(&position, pt, false);
+        assert_eq!(proj.is_inside, expected.is_inside);
+        assert_eq!(proj.point, expected.point);
+    }
+}
+
+#[test]
+fn parallel_contacts_compound_compound() {
+    let shapes1 = (0..10)
+        .map(|i| {
+            let pos = Isometry3::translation(i as f32 * 2.0, 0.0, 0.0);
+            (pos, SharedShape::new(Cuboid::new(Vector3::repeat(0.5))))
+        })
+        .collect();
+    let shapes2 = (0..10)
+        .map(|i| {
+            let pos = Isometry3::translation(i as f32 * 2.0 + 0.5, 0.0, 0.0);
+            (pos, SharedShape::new(Ball::new(0.6)))
+        })
+        .collect();
+    let compound1 = Compound::new(shapes1);
+    let compound2 = Compound::new(shapes2);
+    let pos12 = Isometry3::translation(0.0, 0.8, 0.0);
+
+    let contacts = query::parallel::contacts_compound_compound(
+        &DefaultQueryDispatcher,
+        &pos12,
+        &compound1,
+        &compound2,
+        0.0,
+    )
+    .unwrap();
+
+    // Each ball only touches the cuboid it is above.
+    assert_eq!(contacts.len(), 10);
+
+    for (k, (i1, i2, contact)) in contacts.iter().enumerate() {
+        assert_eq!((*i1, *i2), (k, k));
+
+        let (part_pos1, part1) = &compound1.shapes()[*i1];
+        let (part_pos2, part2) = &compound2.shapes()[*i2];
+        let expected = DefaultQueryDispatcher
+            .contact(
+                &part_pos1.inv_mul(&(pos12 * part_pos2)),
+                &**part1,
+                &**part2,
+                0.0,
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_relative_eq!(contact.dist, expected.dist, epsilon = 1.0e-5);
+        assert_relative_eq!(
+            contact.point1,
+            part_pos1 * expected.point1,
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(
+            contact.point2,
+            part_pos2 * expected.point2,
+            epsilon = 1.0e-5
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use na::{Isometry3, Vector3};
+use parry3d::query::{DefaultQueryDispatcher, PersistentContactManifolds};
+use parry3d::shape::{Compound, SharedShape};
+
+#[test]
+fn persistent_contact_manifolds_keep_contact_data() {
+    let ground = SharedShape::cuboid(3.0, 1.0, 3.0);
+    let cuboid = SharedShape::cuboid(0.5, 0.5, 0.5);
+    let mut manifolds = PersistentContactManifolds::<(), f32>::new();
+
+    let pos12 = Isometry3::translation(0.0, 1.49, 0.0);
+    manifolds
+        .update(&DefaultQueryDispatcher, &pos12, &*ground, &*cuboid, 0.0)
+        .unwrap();
+    assert_eq!(manifolds.num_contacts(), 4);
+
+    // Store some "impulses" identified by the contact feature ids.
+    for manifold in &mut manifolds.manifolds {
+        for contact in &mut manifold.points {
+            contact.data = (contact.fid1 * 100 + contact.fid2) as f32;
+        }
+    }
+
+    // A small rotation forces the contacts to be recomputed.
+    let pos12 = Isometry3::new(Vector3::new(0.01, 1.48, 0.0), Vector3::y() * 0.1);
+    manifolds
+        .update(&DefaultQueryDispatcher, &pos12, &*ground, &*cuboid, 0.0)
+        .unwrap();
+    assert_eq!(manifolds.num_contacts(), 4);
+
+    for manifold in manifolds.active_manifolds() {
+        for contact in &manifold.points {
+            assert_eq!(contact.data, (contact.fid1 * 100 + contact.fid2) as f32);
+        }
+    }
+
+    // Once the shapes are separated, the next contacts start with default data.
+    let far = Isometry3::translation(0.0, 3.0, 0.0);
+    manifolds
+        .update(&DefaultQueryDispatcher, &far, &*ground, &*cuboid, 0.0)
+        .unwrap();
+    assert_eq!(manifolds.num_contacts(), 0);
+    assert_eq!(manifolds.active_manifolds().count(), 0);
+
+    manifolds
+        .update(&DefaultQueryDispatcher, &pos12, &*ground, &*cuboid, 0.0)
+        .unwrap();
+    assert_eq!(manifolds.num_contacts(), 4);
+
+    for manifold in manifolds.active_manifolds() {
+        for contact in &manifold.points {
+            assert_eq!(contact.data, 0.0);
+        }
+    }
+}
+
+#[test]
+fn persistent_contact_manifolds_with_compounds() {
+    let compound = SharedShape::new(Compound::new(vec![
+        (
+            Isometry3::translation(-2.0, 0.0, 0.0),
+            SharedShape::cuboid(0.5, 0.5, 0.5),
+        ),
+        (
+            Isometry3::translation(2.0, 0.0, 0.0),
+            SharedShape::cuboid(0.5, 0.5, 0.5),
+        ),
+    ]));
+    let ground = SharedShape::cuboid(10.0, 0.5, 10.0);
+    let mut manifolds = PersistentContactManifolds::<(), u32>::new();
+
+    let pos12 = Isometry3::translation(0.0, 0.99, 0.0);
+    manifolds
+        .update(&DefaultQueryDispatcher, &pos12, &*ground, &*compound, 0.0)
+        .unwrap();
+    assert_eq!(manifolds.active_manifolds().count(), 2);
+
+    for manifold in &mut manifolds.manifolds {
+        for contact in &mut manifold.points {
+            contact.data = manifold.subshape2 + 1;
+        }
+    }
+
+    let pos12 = Isometry3::translation(0.05, 0.98, 0.0);
+    manifolds
+        .update(&DefaultQueryDispatcher, &pos12, &*ground, &*compound, 0.0)
+        .unwrap();
+    assert_eq!(manifolds.active_manifolds().count(), 2);
+
+    for manifold in manifolds.active_manifolds() {
+        assert!(!manifold.points.is_empty());
+        for contact in &manifold.points {
+            assert_eq!(contact.data, manifold.subshape2 + 1);
+        }
+    }
+
+    manifolds.clear();
+    assert!(manifolds.manifolds.is_empty());
+    assert!(manifolds.workspace.is_none());
+}
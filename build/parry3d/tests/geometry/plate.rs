@@ -0,0 +1,94 @@
+use na::{Isometry3, Point3, Vector2, Vector3};
+use parry3d::query::details::contact_manifold_pfm_pfm;
+use parry3d::query::{ContactManifold, PointQuery, Ray, RayCast};
+use parry3d::shape::{Cuboid, FeatureId, Plate, Shape};
+
+#[test]
+fn plate_ray_cast() {
+    let plate = Plate::new(Vector2::new(1.0, 2.0));
+
+    // From above.
+    let ray = Ray::new(Point3::new(0.5, 3.0, -1.5), -Vector3::y());
+    let hit = plate
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.0);
+    assert_eq!(hit.normal, Vector3::y());
+
+    // From below, with a non-solid cast that must give the same result.
+    let ray = Ray::new(Point3::new(0.0, -1.0, 0.0), Vector3::new(0.5, 1.0, 0.0));
+    let hit = plate
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.0);
+    assert_eq!(hit.normal, -Vector3::y());
+
+    // Missing the plate, parallel to it, and stopped by `max_toi`.
+    let ray = Ray::new(Point3::new(1.5, 3.0, 0.0), -Vector3::y());
+    assert!(plate.cast_local_ray(&ray, f32::MAX, true).is_none());
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    assert!(plate.cast_local_ray(&ray, f32::MAX, true).is_none());
+    let ray = Ray::new(Point3::new(0.0, 3.0, 0.0), -Vector3::y());
+    assert!(plate.cast_local_ray(&ray, 2.9, true).is_none());
+}
+
+#[test]
+fn plate_point_queries() {
+    let plate = Plate::new(Vector2::new(1.0, 2.0));
+
+    let (proj, feature) = plate.project_local_point_and_get_feature(&Point3::new(0.5, 1.0, 0.5));
+    assert!(!proj.is_inside);
+    assert_eq!(proj.point, Point3::new(0.5, 0.0, 0.5));
+    assert_eq!(feature, FeatureId::Face(0));
+
+    let (proj, feature) = plate.project_local_point_and_get_feature(&Point3::new(3.0, -1.0, 0.5));
+    assert_eq!(proj.point, Point3::new(1.0, 0.0, 0.5));
+    assert_eq!(feature, FeatureId::Edge(0));
+
+    let (proj, feature) = plate.project_local_point_and_get_feature(&Point3::new(-3.0, 1.0, -3.0));
+    assert_eq!(proj.point, Point3::new(-1.0, 0.0, -2.0));
+    assert_eq!(feature, FeatureId::Vertex(2));
+
+    assert!(plate.contains_local_point(&Point3::new(0.5, 0.0, -1.0)));
+    assert_relative_eq!(
+        plate.distance_to_local_point(&Point3::new(0.5, -0.25, -1.0), true),
+        0.25
+    );
+}
+
+#[test]
+fn plate_aabb_and_ccd_thickness() {
+    let plate = Plate::new(Vector2::new(1.0, 2.0));
+    let aabb = plate.compute_local_aabb();
+    assert_eq!(aabb.mins, Point3::new(-1.0, 0.0, -2.0));
+    assert_eq!(aabb.maxs, Point3::new(1.0, 0.0, 2.0));
+
+    let pos = Isometry3::rotation(Vector3::x() * std::f32::consts::FRAC_PI_2);
+    let aabb = plate.compute_aabb(&pos);
+    assert_relative_eq!(aabb.mins, Point3::new(-1.0, -2.0, 0.0), epsilon = 1.0e-6);
+    assert_relative_eq!(aabb.maxs, Point3::new(1.0, 2.0, 0.0), epsilon = 1.0e-6);
+
+    assert_eq!(plate.ccd_thickness(), 0.0);
+    assert!(plate.as_polygonal_feature_map().is_some());
+}
+
+#[test]
+fn cuboid_above_plate() {
+    let plate = Plate::new(Vector2::new(2.0, 2.0));
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let cuboid_pos = Isometry3::translation(0.0, 0.55, 0.0);
+    let pos12 = cuboid_pos.inv_mul(&Isometry3::identity());
+
+    let mut manifold = ContactManifold::<(), ()>::new();
+    contact_manifold_pfm_pfm(&pos12, &cuboid, 0.0, &plate, 0.0, 0.1, &mut manifold);
+
+    // The four corners of the bottom face of the cuboid.
+    assert!(manifold.points.len() >= 4);
+    assert_relative_eq!(manifold.local_n1.y, -1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(manifold.local_n2.y, 1.0, epsilon = 1.0e-5);
+
+    for pt in &manifold.points {
+        assert_relative_eq!(pt.dist, 0.05, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.local_p2.y, 0.0, epsilon = 1.0e-5);
+    }
+}
@@ -0,0 +1,67 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::PointQuery;
+use parry3d::shape::{Ball, Cuboid};
+
+// The signed distance as computed from a point projection.
+fn projection_distance(shape: &dyn PointQuery, pos: &Isometry3<Real>, pt: &Point3<Real>) -> Real {
+    let proj = shape.project_point(pos, pt, false);
+    let dist = na::distance(pt, &proj.point);
+
+    if proj.is_inside {
+        -dist
+    } else {
+        dist
+    }
+}
+
+fn test_points() -> Vec<Point3<Real>> {
+    vec![
+        Point3::new(0.0, 0.05, 0.0),
+        Point3::new(0.1, 0.2, -0.3),
+        Point3::new(0.9, -0.4, 0.0),
+        Point3::new(3.0, 0.5, -0.5),
+        Point3::new(-2.0, 4.0, 1.5),
+        Point3::new(0.5, -3.0, 5.0),
+    ]
+}
+
+#[test]
+fn ball_distance_to_point_matches_projection() {
+    let ball = Ball::new(1.5);
+    let pos = Isometry3::new(Vector3::new(0.1, -0.2, 0.3), Vector3::y() * 0.4);
+
+    for pt in test_points() {
+        let expected = projection_distance(&ball, &pos, &pt);
+        assert_relative_eq!(
+            ball.distance_to_point(&pos, &pt, false),
+            expected,
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(
+            ball.distance_to_point(&pos, &pt, true),
+            expected.max(0.0),
+            epsilon = 1.0e-5
+        );
+    }
+}
+
+#[test]
+fn cuboid_distance_to_point_matches_projection() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 0.5));
+    let pos = Isometry3::new(Vector3::new(0.1, -0.2, 0.3), Vector3::y() * 0.4);
+
+    for pt in test_points() {
+        let expected = projection_distance(&cuboid, &pos, &pt);
+        assert_relative_eq!(
+            cuboid.distance_to_point(&pos, &pt, false),
+            expected,
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(
+            cuboid.distance_to_point(&pos, &pt, true),
+            expected.max(0.0),
+            epsilon = 1.0e-5
+        );
+    }
+}
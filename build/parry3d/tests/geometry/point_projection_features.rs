@@ -0,0 +1,125 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use parry3d::query::{PointQuery, PointQueryWithLocation};
+use parry3d::shape::{
+    Compound, Cone, Cuboid, Cylinder, FeatureId, HeightField, RoundShape, ScaledShape, SharedShape,
+    SolidOfRevolution,
+};
+
+fn feature_at(shape: &dyn PointQuery, x: f32, y: f32, z: f32) -> FeatureId {
+    shape
+        .project_local_point_and_get_feature(&Point3::new(x, y, z))
+        .1
+}
+
+#[test]
+fn cylinder_projection_features() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+
+    assert_eq!(feature_at(&cylinder, 0.1, 2.0, 0.1), FeatureId::Face(0));
+    assert_eq!(feature_at(&cylinder, 0.1, -2.0, 0.1), FeatureId::Face(1));
+    assert_eq!(feature_at(&cylinder, 0.0, 0.2, 2.0), FeatureId::Face(2));
+    assert_eq!(feature_at(&cylinder, 2.0, 2.0, 0.0), FeatureId::Edge(0));
+    assert_eq!(feature_at(&cylinder, -2.0, -2.0, 0.0), FeatureId::Edge(1));
+
+    // Points inside of the cylinder are projected on the closest cap or on its side.
+    assert_eq!(feature_at(&cylinder, 0.0, 0.9, 0.0), FeatureId::Face(0));
+    assert_eq!(feature_at(&cylinder, 0.4, 0.0, 0.0), FeatureId::Face(2));
+}
+
+#[test]
+fn cone_projection_features() {
+    let cone = Cone::new(1.0, 1.0);
+
+    assert_eq!(feature_at(&cone, 0.1, -2.0, 0.1), FeatureId::Face(0));
+    assert_eq!(feature_at(&cone, 0.0, 3.0, 0.0), FeatureId::Vertex(0));
+    assert_eq!(feature_at(&cone, 1.0, 0.5, 0.0), FeatureId::Face(1));
+    assert_eq!(feature_at(&cone, 0.0, -2.0, 2.0), FeatureId::Edge(0));
+}
+
+#[test]
+fn solid_of_revolution_projection_features() {
+    // A cylinder of radius 1 topped by a cone.
+    let profile = vec![[1.0, -1.0].into(), [1.0, 1.0].into(), [0.0, 2.0].into()];
+    let solid = SolidOfRevolution::new(profile);
+
+    assert_eq!(feature_at(&solid, 0.0, -3.0, 0.1), FeatureId::Face(2));
+    assert_eq!(feature_at(&solid, 3.0, 0.0, 0.0), FeatureId::Face(0));
+    assert_eq!(feature_at(&solid, 0.0, 2.0, 2.0), FeatureId::Face(1));
+    assert_eq!(feature_at(&solid, 2.0, -2.0, 0.0), FeatureId::Edge(0));
+    assert_eq!(feature_at(&solid, 0.0, 4.0, 0.0), FeatureId::Vertex(2));
+}
+
+#[test]
+fn round_shape_projection_features() {
+    let round_cuboid = RoundShape {
+        base_shape: Cuboid::new(Vector3::repeat(1.0)),
+        border_radius: 0.1,
+    };
+    let (proj, feature) =
+        round_cuboid.project_local_point_and_get_feature(&Point3::new(2.0, 0.5, 0.0));
+    assert_eq!(feature, FeatureId::Face(0));
+    assert_relative_eq!(proj.point, Point3::new(1.1, 0.5, 0.0), epsilon = 1.0e-5);
+
+    let round_shared = RoundShape {
+        base_shape: SharedShape::cuboid(1.0, 1.0, 1.0),
+        border_radius: 0.1,
+    };
+    let (proj, feature) =
+        round_shared.project_local_point_and_get_feature(&Point3::new(0.5, 3.0, 0.0));
+    assert_eq!(feature, FeatureId::Face(1));
+    assert_relative_eq!(proj.point, Point3::new(0.5, 1.1, 0.0), epsilon = 1.0e-5);
+}
+
+#[test]
+fn scaled_shape_projection_features() {
+    let scaled = ScaledShape::new(
+        Cuboid::new(Vector3::repeat(1.0)),
+        Vector3::new(4.0, 1.0, 1.0),
+    );
+
+    assert_eq!(feature_at(&scaled, 3.0, 2.0, 0.0), FeatureId::Face(1));
+    assert_eq!(feature_at(&scaled, 5.0, 0.5, 0.0), FeatureId::Face(0));
+}
+
+#[test]
+fn compound_projection_features() {
+    let compound = Compound::new(vec![
+        (Isometry3::identity(), SharedShape::cuboid(1.0, 1.0, 1.0)),
+        (
+            Isometry3::translation(5.0, 0.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0, 1.0),
+        ),
+    ]);
+    let point = Point3::new(5.5, 3.0, 0.0);
+
+    let (proj, feature) = compound.project_local_point_and_get_feature(&point);
+    assert_eq!(feature, FeatureId::Face(1));
+    assert_relative_eq!(proj.point, Point3::new(5.5, 1.0, 0.0));
+
+    let (_, (part, feature)) = compound.project_local_point_and_get_location(&point, false);
+    assert_eq!(part, 1);
+    assert_eq!(feature, FeatureId::Face(1));
+}
+
+#[test]
+fn heightfield_projection_features() {
+    let heightfield = HeightField::new(DMatrix::zeros(3, 3), Vector3::new(2.0, 1.0, 2.0));
+    let num_triangles = 8;
+
+    // Points above and below the heightfield are projected on the front and back faces.
+    match feature_at(&heightfield, 0.3, 1.0, 0.6) {
+        FeatureId::Face(id) => assert!(id < num_triangles),
+        feature => panic!("Unexpected feature: {:?}", feature),
+    }
+
+    match feature_at(&heightfield, 0.3, -1.0, 0.6) {
+        FeatureId::Face(id) => assert!(id >= num_triangles && id < 2 * num_triangles),
+        feature => panic!("Unexpected feature: {:?}", feature),
+    }
+
+    // The last vertex of the grid.
+    assert_eq!(
+        feature_at(&heightfield, 2.0, 1.0, 2.0),
+        FeatureId::Vertex(8)
+    );
+}
@@ -0,0 +1,106 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use parry3d::query::PointQuery;
+use parry3d::shape::{Ball, Compound, Cuboid, HeightField, SharedShape, TriMesh};
+
+fn grid_trimesh(n: u32) -> TriMesh {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for i in 0..=n {
+        for j in 0..=n {
+            vertices.push(Point3::new(i as f32, 0.0, j as f32));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            indices.push([a, a + 1, a + n + 1]);
+            indices.push([a + 1, a + n + 2, a + n + 1]);
+        }
+    }
+
+    TriMesh::new(vertices, indices)
+}
+
+#[test]
+fn project_point_with_max_dist_trimesh() {
+    let trimesh = grid_trimesh(20);
+    let position = Isometry3::translation(1.0, 2.0, 3.0);
+    let pt = Point3::new(6.3, 2.5, 8.1);
+
+    let proj = trimesh
+        .project_point_with_max_dist(&position, &pt, false, 1.0)
+        .unwrap();
+    let expected = trimesh.project_point(&position, &pt, false);
+    assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-5);
+    assert_relative_eq!(proj.point, Point3::new(6.3, 2.0, 8.1), epsilon = 1.0e-5);
+
+    assert!(trimesh
+        .project_point_with_max_dist(&position, &pt, false, 0.4)
+        .is_none());
+}
+
+#[test]
+fn project_point_with_max_dist_solid_inside_closed_trimesh() {
+    let (vertices, indices) = Cuboid::new(Vector3::repeat(2.0)).to_trimesh();
+    let mut trimesh = TriMesh::new(vertices, indices);
+    trimesh.compute_pseudo_normals();
+
+    // The point is 1.5 away from the surface of the mesh, but inside of it.
+    let pt = Point3::new(0.5, 0.0, 0.0);
+    let proj = trimesh
+        .project_local_point_with_max_dist(&pt, true, 0.1)
+        .unwrap();
+    assert!(proj.is_inside);
+    assert_eq!(proj.point, pt);
+
+    assert!(trimesh
+        .project_local_point_with_max_dist(&pt, false, 0.1)
+        .is_none());
+    let proj = trimesh
+        .project_local_point_with_max_dist(&pt, false, 2.0)
+        .unwrap();
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(2.0, 0.0, 0.0), epsilon = 1.0e-5);
+}
+
+#[test]
+fn project_point_with_max_dist_other_shapes() {
+    let heightfield = HeightField::new(DMatrix::zeros(11, 11), Vector3::new(10.0, 1.0, 10.0));
+    let pt = Point3::new(1.2, 0.7, -2.3);
+    let proj = heightfield
+        .project_local_point_with_max_dist(&pt, false, 1.0)
+        .unwrap();
+    assert_relative_eq!(proj.point, Point3::new(1.2, 0.0, -2.3), epsilon = 1.0e-5);
+    assert!(heightfield
+        .project_local_point_with_max_dist(&pt, false, 0.5)
+        .is_none());
+
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(0.0, 0.0, 0.0),
+            SharedShape::new(Ball::new(1.0)),
+        ),
+        (
+            Isometry3::translation(10.0, 0.0, 0.0),
+            SharedShape::new(Ball::new(1.0)),
+        ),
+    ]);
+    let pt = Point3::new(12.0, 0.0, 0.0);
+    let proj = compound
+        .project_local_point_with_max_dist(&pt, false, 1.5)
+        .unwrap();
+    assert_relative_eq!(proj.point, Point3::new(11.0, 0.0, 0.0), epsilon = 1.0e-5);
+    assert!(compound
+        .project_local_point_with_max_dist(&pt, false, 0.5)
+        .is_none());
+
+    let ball = Ball::new(1.0);
+    assert!(ball
+        .project_local_point_with_max_dist(&pt, false, 11.5)
+        .is_some());
+    assert!(ball
+        .project_local_point_with_max_dist(&pt, false, 10.5)
+        .is_none());
+}
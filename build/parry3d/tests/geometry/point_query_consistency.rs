@@ -0,0 +1,139 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::shape::{HalfSpace, Shape, SharedShape};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+use std::sync::Arc;
+
+/// Checks that the point projections of `shape` agree with its containment test.
+///
+/// For every sample point:
+/// - the solid projection is flagged as inside iff `contains_local_point` returns `true`;
+/// - a solid projection flagged as inside is the sample point itself;
+/// - the projected point (solid or not) lies on or inside of the shape.
+fn assert_point_query_consistent(name: &str, shape: &dyn Shape, samples: &[Point3<Real>]) {
+    let eps = 1.0e-3;
+
+    for pt in samples {
+        let contained = shape.contains_local_point(pt);
+
+        for solid in [true, false].iter().copied() {
+            let proj = shape.project_local_point(pt, solid);
+
+            if solid {
+                assert_eq!(
+                    proj.is_inside, contained,
+                    "{}: is_inside disagrees with contains_local_point at {:?}",
+                    name, pt
+                );
+
+                if proj.is_inside {
+                    assert_eq!(
+                        proj.point, *pt,
+                        "{}: solid projection of an inside point moved it",
+                        name
+                    );
+                }
+            }
+
+            let dist_to_proj = shape.distance_to_local_point(&proj.point, true);
+            assert!(
+                dist_to_proj <= eps,
+                "{}: projection of {:?} (solid: {}) lies outside of the shape: {:?}, at distance {}",
+                name,
+                pt,
+                solid,
+                proj.point,
+                dist_to_proj
+            );
+        }
+    }
+}
+
+fn samples(rng: &mut IsaacRng, num: usize) -> Vec<Point3<Real>> {
+    (0..num)
+        .map(|_| {
+            Point3::new(
+                rng.gen_range(-4.0, 4.0),
+                rng.gen_range(-4.0, 4.0),
+                rng.gen_range(-4.0, 4.0),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn point_query_consistency() {
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let points = samples(&mut rng, 20);
+    let hull_points: Vec<_> = points.iter().map(|pt| pt * 0.5).collect();
+
+    let shapes: Vec<(&str, SharedShape)> = vec![
+        ("ball", SharedShape::ball(1.5)),
+        ("cuboid", SharedShape::cuboid(1.0, 2.0, 3.0)),
+        (
+            "capsule",
+            SharedShape::capsule(hull_points[0], hull_points[1], 0.3),
+        ),
+        ("cylinder", SharedShape::cylinder(1.0, 0.5)),
+        ("cone", SharedShape::cone(1.0, 0.5)),
+        (
+            "round_cuboid",
+            SharedShape::round_cuboid(1.0, 2.0, 3.0, 0.1),
+        ),
+        ("round_cylinder", SharedShape::round_cylinder(1.0, 0.5, 0.1)),
+        ("round_cone", SharedShape::round_cone(1.0, 0.5, 0.1)),
+        (
+            "segment",
+            SharedShape::segment(hull_points[0], hull_points[1]),
+        ),
+        (
+            "triangle",
+            SharedShape::triangle(hull_points[0], hull_points[1], hull_points[2]),
+        ),
+        (
+            "convex_hull",
+            SharedShape::convex_hull(&hull_points).unwrap(),
+        ),
+        (
+            "round_convex_hull",
+            SharedShape::round_convex_hull(&hull_points, 0.1).unwrap(),
+        ),
+        (
+            "trimesh",
+            SharedShape::trimesh(hull_points.clone(), vec![[0, 1, 2], [0, 2, 3]]),
+        ),
+        (
+            "compound",
+            SharedShape::compound(vec![
+                (
+                    Isometry3::translation(1.0, 0.0, 0.0),
+                    SharedShape::ball(0.5),
+                ),
+                (
+                    Isometry3::translation(0.0, 1.0, 0.0),
+                    SharedShape::cuboid(1.0, 0.5, 0.5),
+                ),
+            ]),
+        ),
+        (
+            "heightfield",
+            SharedShape::heightfield(
+                na::DMatrix::from_fn(5, 5, |i, j| ((i * j) as Real * 0.3).sin()),
+                Vector3::new(4.0, 1.0, 4.0),
+            ),
+        ),
+        (
+            "halfspace",
+            SharedShape(Arc::new(HalfSpace::new(na::Unit::new_normalize(
+                Vector3::new(1.0, 2.0, 3.0),
+            )))),
+        ),
+    ];
+
+    let queries = samples(&mut rng, 500);
+
+    for (name, shape) in &shapes {
+        assert_point_query_consistent(name, &**shape, &queries);
+    }
+}
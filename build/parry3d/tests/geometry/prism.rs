@@ -0,0 +1,157 @@
+use na::{Isometry3, Point2, Point3, Vector3};
+use parry3d::query::details::contact_manifold_pfm_pfm;
+use parry3d::query::{ContactManifold, PointQuery, Ray, RayCast};
+use parry3d::shape::{ConvexPolyhedron, Cuboid, FeatureId, Prism, Shape};
+
+fn square_prism() -> Prism {
+    let square = [
+        Point2::new(1.0, 1.0),
+        Point2::new(-1.0, 1.0),
+        Point2::new(-1.0, -1.0),
+        Point2::new(1.0, -1.0),
+        // An extra point that must be discarded by the convex hull.
+        Point2::new(0.0, 0.5),
+    ];
+    Prism::from_convex_hull(&square, 1.0).unwrap()
+}
+
+fn hexagonal_prism() -> Prism {
+    let hexagon: Vec<_> = (0..6)
+        .map(|i| {
+            let angle = i as f32 * std::f32::consts::PI / 3.0 + 0.1;
+            Point2::new(angle.cos() + 0.3, angle.sin() * 0.5 - 0.2)
+        })
+        .collect();
+    Prism::from_convex_polyline(hexagon, 0.4).unwrap()
+}
+
+#[test]
+fn prism_from_collinear_points() {
+    let points = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 1.0),
+    ];
+    let prism = Prism::from_convex_polyline(points, 1.0).unwrap();
+    assert_eq!(prism.cross_section().len(), 3);
+
+    let points = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)];
+    assert!(Prism::from_convex_polyline(points, 1.0).is_none());
+}
+
+#[test]
+fn square_prism_matches_cuboid() {
+    let prism = square_prism();
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    assert_eq!(prism.cross_section().len(), 4);
+
+    let points = [
+        Point3::new(2.0, 0.5, 0.2),
+        Point3::new(2.0, 3.0, -4.0),
+        Point3::new(0.1, 0.5, -0.2),
+        Point3::new(0.1, -0.95, 0.2),
+        Point3::new(-1.5, 0.0, 0.0),
+    ];
+
+    for pt in points.iter() {
+        for solid in [true, false].iter() {
+            let proj1 = prism.project_local_point(pt, *solid);
+            let proj2 = cuboid.project_local_point(pt, *solid);
+            assert_eq!(proj1.is_inside, proj2.is_inside);
+            assert_relative_eq!(proj1.point, proj2.point, epsilon = 1.0e-6);
+        }
+
+        let dir = Point3::new(0.2, 0.3, -0.1) - pt;
+        let ray = Ray::new(*pt, dir);
+
+        for solid in [true, false].iter() {
+            let hit1 = prism.cast_local_ray_and_get_normal(&ray, f32::MAX, *solid);
+            let hit2 = cuboid.cast_local_ray_and_get_normal(&ray, f32::MAX, *solid);
+            let (hit1, hit2) = (hit1.unwrap(), hit2.unwrap());
+            assert_relative_eq!(hit1.toi, hit2.toi, epsilon = 1.0e-6);
+            assert_relative_eq!(hit1.normal, hit2.normal, epsilon = 1.0e-6);
+        }
+    }
+
+    let ray = Ray::new(Point3::new(3.0, 1.5, 0.0), -Vector3::x());
+    assert!(prism.cast_local_ray(&ray, f32::MAX, true).is_none());
+    let ray = Ray::new(Point3::new(3.0, 0.0, 0.0), -Vector3::x());
+    assert!(prism.cast_local_ray(&ray, 1.9, true).is_none());
+    assert_eq!(prism.cast_local_ray(&ray, 2.1, true), Some(2.0));
+}
+
+#[test]
+fn prism_features() {
+    let prism = square_prism();
+    let n = prism.cross_section().len() as u32;
+    let first = prism.cross_section()[0];
+
+    let (_, feature) = prism.project_local_point_and_get_feature(&Point3::new(0.0, 5.0, 0.0));
+    assert_eq!(feature, FeatureId::Face(n + 1));
+    let (_, feature) = prism.project_local_point_and_get_feature(&Point3::new(0.0, -0.9, 0.0));
+    assert_eq!(feature, FeatureId::Face(n));
+
+    let corner = Point3::new(first.x * 2.0, 2.0, first.y * 2.0);
+    let (proj, feature) = prism.project_local_point_and_get_feature(&corner);
+    assert_eq!(proj.point, Point3::new(first.x, 1.0, first.y));
+    assert_eq!(feature, FeatureId::Vertex(n));
+
+    let side = Point3::new(first.x * 2.0, 0.0, first.y * 2.0);
+    let (_, feature) = prism.project_local_point_and_get_feature(&side);
+    assert_eq!(feature, FeatureId::Edge(2 * n));
+
+    for face in 0..n + 2 {
+        let normal = prism.face_normal(face);
+        let dir = na::Unit::new_normalize(normal);
+        assert_eq!(prism.support_feature_id_toward(&dir), FeatureId::Face(face));
+    }
+}
+
+#[test]
+fn prism_mass_properties() {
+    let prism = hexagonal_prism();
+    let vertices: Vec<_> = prism
+        .cross_section()
+        .iter()
+        .flat_map(|pt| {
+            let h = prism.half_height();
+            vec![Point3::new(pt.x, -h, pt.y), Point3::new(pt.x, h, pt.y)]
+        })
+        .collect();
+    let polyhedron = ConvexPolyhedron::from_convex_hull(&vertices).unwrap();
+
+    let mprops = prism.mass_properties(2.0);
+    let expected = polyhedron.mass_properties(2.0);
+
+    assert_relative_eq!(mprops.inv_mass, expected.inv_mass, max_relative = 1.0e-5);
+    assert_relative_eq!(mprops.local_com, expected.local_com, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        mprops.reconstruct_inertia_matrix(),
+        expected.reconstruct_inertia_matrix(),
+        epsilon = 1.0e-5
+    );
+
+    let (aabb1, aabb2) = (prism.local_aabb(), polyhedron.local_aabb());
+    assert_relative_eq!(aabb1.mins, aabb2.mins);
+    assert_relative_eq!(aabb1.maxs, aabb2.maxs);
+}
+
+#[test]
+fn cuboid_on_prism() {
+    let prism = hexagonal_prism();
+    let cuboid = Cuboid::new(Vector3::new(0.1, 0.1, 0.1));
+    let cuboid_pos = Isometry3::translation(0.3, 0.55, -0.2);
+    let pos12 = cuboid_pos.inv_mul(&Isometry3::identity());
+
+    let mut manifold = ContactManifold::<(), ()>::new();
+    contact_manifold_pfm_pfm(&pos12, &cuboid, 0.0, &prism, 0.0, 0.1, &mut manifold);
+
+    // The four corners of the bottom face of the cuboid.
+    assert!(manifold.points.len() >= 4);
+    assert_relative_eq!(manifold.local_n2.y, 1.0, epsilon = 1.0e-5);
+
+    for pt in &manifold.points {
+        assert_relative_eq!(pt.dist, 0.05, epsilon = 1.0e-5);
+    }
+}
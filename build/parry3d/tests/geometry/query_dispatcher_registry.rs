@@ -0,0 +1,126 @@
+use na::{Isometry3, Vector3};
+use parry3d::math::Real;
+use parry3d::motion::RigidMotion;
+use parry3d::query::{
+    ClosestPoints, Contact, QueryDispatcher, QueryDispatcherRegistry, Unsupported, TOI,
+};
+use parry3d::shape::{Ball, Cuboid, Shape, ShapeType};
+use std::sync::Arc;
+
+// A dispatcher that only supports distance queries, and always returns the same distance.
+struct ConstantDistance(Real);
+
+impl QueryDispatcher for ConstantDistance {
+    fn intersection_test(
+        &self,
+        _: &Isometry3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+    ) -> Result<bool, Unsupported> {
+        Err(Unsupported)
+    }
+
+    fn distance(
+        &self,
+        _: &Isometry3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+    ) -> Result<Real, Unsupported> {
+        Ok(self.0)
+    }
+
+    fn contact(
+        &self,
+        _: &Isometry3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+        _: Real,
+    ) -> Result<Option<Contact>, Unsupported> {
+        Err(Unsupported)
+    }
+
+    fn closest_points(
+        &self,
+        _: &Isometry3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+        _: Real,
+    ) -> Result<ClosestPoints, Unsupported> {
+        Err(Unsupported)
+    }
+
+    fn time_of_impact(
+        &self,
+        _: &Isometry3<Real>,
+        _: &Vector3<Real>,
+        _: &dyn Shape,
+        _: &dyn Shape,
+        _: Real,
+        _: Real,
+    ) -> Result<Option<TOI>, Unsupported> {
+        Err(Unsupported)
+    }
+
+    fn nonlinear_time_of_impact(
+        &self,
+        _: &dyn RigidMotion,
+        _: &dyn Shape,
+        _: &dyn Shape,
+        _: Real,
+        _: Real,
+    ) -> Result<Option<TOI>, Unsupported> {
+        Err(Unsupported)
+    }
+}
+
+#[test]
+fn query_dispatcher_registry_overrides_registered_pairs() {
+    let ball = Ball::new(1.0);
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    let pos12 = Isometry3::translation(5.0, 0.0, 0.0);
+
+    let mut registry = QueryDispatcherRegistry::default();
+    assert!(registry
+        .register(
+            ShapeType::Ball,
+            ShapeType::Cuboid,
+            Arc::new(ConstantDistance(42.0))
+        )
+        .is_none());
+
+    assert_eq!(registry.distance(&pos12, &ball, &cuboid).unwrap(), 42.0);
+
+    // The reversed pair isn't registered.
+    assert_relative_eq!(
+        registry.distance(&pos12, &cuboid, &ball).unwrap(),
+        3.0,
+        epsilon = 1.0e-3
+    );
+
+    // The queries not supported by the handler fall back to the default dispatcher.
+    let contact = registry.contact(&pos12, &ball, &cuboid, 5.0).unwrap();
+    assert_relative_eq!(contact.unwrap().dist, 3.0, epsilon = 1.0e-3);
+    assert!(!registry.intersection_test(&pos12, &ball, &cuboid).unwrap());
+
+    // Registering another handler for the same pair replaces it.
+    assert!(registry
+        .register(
+            ShapeType::Ball,
+            ShapeType::Cuboid,
+            Arc::new(ConstantDistance(7.0))
+        )
+        .is_some());
+    assert_eq!(registry.distance(&pos12, &ball, &cuboid).unwrap(), 7.0);
+
+    assert!(registry
+        .unregister(ShapeType::Ball, ShapeType::Cuboid)
+        .is_some());
+    assert!(registry
+        .handler(ShapeType::Ball, ShapeType::Cuboid)
+        .is_none());
+    assert_relative_eq!(
+        registry.distance(&pos12, &ball, &cuboid).unwrap(),
+        3.0,
+        epsilon = 1.0e-3
+    );
+}
@@ -0,0 +1,108 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, Ray};
+use parry3d::shape::{Ball, Compound, Cuboid, SharedShape, Slab, TriMesh, Triangle};
+
+#[test]
+fn ray_intersections_convex_entry_and_exit() {
+    let ball = Ball::new(1.0);
+    let position = Isometry3::translation(0.0, 0.0, 2.0);
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 2.0), Vector3::x());
+
+    let hits = query::ray_intersections(&position, &ball, &ray, 100.0);
+    assert_eq!(hits.len(), 2);
+    assert_relative_eq!(hits[0].toi, 4.0, epsilon = 1.0e-4);
+    assert_relative_eq!(hits[0].normal, -Vector3::x(), epsilon = 1.0e-4);
+    assert_relative_eq!(hits[1].toi, 6.0, epsilon = 1.0e-4);
+    assert_relative_eq!(hits[1].normal, Vector3::x(), epsilon = 1.0e-4);
+
+    // The exit is beyond `max_toi`.
+    let hits = query::ray_intersections(&position, &ball, &ray, 5.0);
+    assert_eq!(hits.len(), 1);
+    assert_relative_eq!(hits[0].toi, 4.0, epsilon = 1.0e-4);
+
+    // The ray starts inside of the ball.
+    let ray = Ray::new(Point3::new(0.5, 0.0, 2.0), Vector3::x());
+    let hits = query::ray_intersections(&position, &ball, &ray, 100.0);
+    assert_eq!(hits.len(), 1);
+    assert_relative_eq!(hits[0].toi, 0.5, epsilon = 1.0e-4);
+    assert_relative_eq!(hits[0].normal, Vector3::x(), epsilon = 1.0e-4);
+}
+
+#[test]
+fn ray_intersections_flat_shape() {
+    let triangle = Triangle::new(
+        Point3::new(0.0, -1.0, -1.0),
+        Point3::new(0.0, 1.0, -1.0),
+        Point3::new(0.0, 0.0, 1.0),
+    );
+    let ray = Ray::new(Point3::new(-3.0, 0.0, 0.0), Vector3::x());
+
+    let hits = query::ray_intersections(&Isometry3::identity(), &triangle, &ray, 100.0);
+    assert_eq!(hits.len(), 1);
+    assert_relative_eq!(hits[0].toi, 3.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn ray_intersections_compound_sorted() {
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(6.0, 0.0, 0.0),
+            SharedShape::new(Cuboid::new(Vector3::repeat(0.5))),
+        ),
+        (
+            Isometry3::translation(2.0, 0.0, 0.0),
+            SharedShape::new(Ball::new(0.5)),
+        ),
+        (
+            Isometry3::translation(2.0, 5.0, 0.0),
+            SharedShape::new(Ball::new(0.5)),
+        ),
+    ]);
+    let position = Isometry3::translation(0.0, 1.0, 0.0);
+    let ray = Ray::new(Point3::new(0.0, 1.0, 0.0), Vector3::x() * 2.0);
+
+    let hits = query::ray_intersections(&position, &compound, &ray, 100.0);
+    let tois: Vec<_> = hits.iter().map(|hit| hit.toi).collect();
+    assert_eq!(tois.len(), 4);
+    assert_relative_eq!(tois[0], 0.75, epsilon = 1.0e-4);
+    assert_relative_eq!(tois[1], 1.25, epsilon = 1.0e-4);
+    assert_relative_eq!(tois[2], 2.75, epsilon = 1.0e-4);
+    assert_relative_eq!(tois[3], 3.25, epsilon = 1.0e-4);
+}
+
+#[test]
+fn ray_intersections_closed_trimesh() {
+    let (vertices, indices) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    let trimesh = TriMesh::new(vertices, indices);
+    let ray = Ray::new(Point3::new(0.3, 0.2, -5.0), Vector3::z());
+
+    let hits = query::ray_intersections(&Isometry3::identity(), &trimesh, &ray, 100.0);
+    assert_eq!(hits.len(), 2);
+    assert_relative_eq!(hits[0].toi, 4.0, epsilon = 1.0e-4);
+    assert_relative_eq!(hits[1].toi, 6.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn ray_intersections_unbounded_convex_shape() {
+    let slab = Slab::new(Vector3::y_axis(), 1.0);
+    let position = Isometry3::identity();
+    let ray = Ray::new(Point3::new(0.0, -5.0, 0.0), Vector3::y());
+
+    let hits = query::ray_intersections(&position, &slab, &ray, f32::MAX);
+    assert_eq!(hits.len(), 2);
+    assert_relative_eq!(hits[0].toi, 4.0, epsilon = 1.0e-4);
+    assert_relative_eq!(hits[0].normal, -Vector3::y(), epsilon = 1.0e-4);
+    assert_relative_eq!(hits[1].toi, 6.0, epsilon = 1.0e-4);
+    assert_relative_eq!(hits[1].normal, Vector3::y(), epsilon = 1.0e-4);
+
+    // The ray starts inside of the slab.
+    let ray = Ray::new(Point3::new(3.0, 0.5, 0.0), Vector3::y());
+    let hits = query::ray_intersections(&position, &slab, &ray, 100.0);
+    assert_eq!(hits.len(), 1);
+    assert_relative_eq!(hits[0].toi, 0.5, epsilon = 1.0e-4);
+    assert_relative_eq!(hits[0].normal, Vector3::y(), epsilon = 1.0e-4);
+
+    // The ray never exits the slab.
+    let ray = Ray::new(Point3::new(3.0, 0.5, 0.0), Vector3::x());
+    assert!(query::ray_intersections(&position, &slab, &ray, f32::MAX).is_empty());
+}
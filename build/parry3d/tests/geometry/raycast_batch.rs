@@ -0,0 +1,104 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, Ray, RayCast};
+use parry3d::shape::{Ball, Compound, FeatureId, SharedShape, TriMesh};
+
+fn grid_trimesh(n: u32) -> TriMesh {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for i in 0..=n {
+        for j in 0..=n {
+            let y = ((i * 7 + j * 3) % 5) as f32 * 0.1;
+            vertices.push(Point3::new(i as f32, y, j as f32));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            let b = a + 1;
+            let c = a + n + 1;
+            let d = c + 1;
+            indices.push([a, b, c]);
+            indices.push([b, d, c]);
+        }
+    }
+
+    TriMesh::new(vertices, indices)
+}
+
+#[test]
+fn raycast_batch_matches_single_rays() {
+    let trimesh = grid_trimesh(10);
+    let position = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.1, 0.2, 0.3));
+    let mut rays = Vec::new();
+
+    // Not a multiple of the SIMD width, so the last packet is partially filled.
+    for i in 0..23 {
+        for j in 0..19 {
+            let origin = position * Point3::new(i as f32 * 0.5 - 0.7, 3.0, j as f32 * 0.6 - 0.4);
+            let dir = position * Vector3::new(0.1 * (i % 3) as f32, -1.0, -0.05 * (j % 4) as f32);
+            rays.push(Ray::new(origin, dir));
+        }
+    }
+
+    let hits = query::raycast_batch(&position, &trimesh, &rays, 10.0, true);
+    assert_eq!(hits.len(), rays.len());
+
+    let mut num_hits = 0;
+
+    for (ray, hit) in rays.iter().zip(hits.iter()) {
+        let expected = trimesh.cast_ray_and_get_normal(&position, ray, 10.0, true);
+        assert_eq!(hit.is_some(), expected.is_some());
+
+        if let (Some((part_id, hit)), Some(expected)) = (hit, expected) {
+            assert_relative_eq!(hit.toi, expected.toi, epsilon = 1.0e-5);
+            assert!(*part_id < trimesh.indices().len() as u32);
+
+            // Rays hitting an edge may report either of its adjacent triangles.
+            if expected.feature == FeatureId::Face(*part_id) {
+                assert_relative_eq!(hit.normal, expected.normal, epsilon = 1.0e-5);
+            }
+            num_hits += 1;
+        }
+    }
+
+    assert!(num_hits > 0 && num_hits < rays.len());
+}
+
+#[test]
+fn raycast_batch_compound_returns_closest_part() {
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(0.0, 0.0, 5.0),
+            SharedShape::new(Ball::new(1.0)),
+        ),
+        (
+            Isometry3::translation(0.0, 0.0, 2.0),
+            SharedShape::new(Ball::new(0.5)),
+        ),
+        (
+            Isometry3::translation(4.0, 0.0, 5.0),
+            SharedShape::new(Ball::new(1.0)),
+        ),
+    ]);
+    let rays = [
+        Ray::new(Point3::origin(), Vector3::z()),
+        Ray::new(Point3::new(4.0, 0.0, 0.0), Vector3::z()),
+        Ray::new(Point3::new(-4.0, 0.0, 0.0), Vector3::z()),
+    ];
+
+    let hits = query::raycast_batch(&Isometry3::identity(), &compound, &rays, 100.0, true);
+
+    let (part0, hit0) = hits[0].unwrap();
+    assert_eq!(part0, 1);
+    assert_relative_eq!(hit0.toi, 1.5, epsilon = 1.0e-5);
+    let (part1, hit1) = hits[1].unwrap();
+    assert_eq!(part1, 2);
+    assert_relative_eq!(hit1.toi, 4.0, epsilon = 1.0e-5);
+    assert!(hits[2].is_none());
+
+    // A `max_toi` smaller than the closest hit yields no intersection.
+    let hits = query::raycast_batch(&Isometry3::identity(), &compound, &rays, 1.0, true);
+    assert!(hits.iter().all(|hit| hit.is_none()));
+}
@@ -0,0 +1,132 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, PointQuery, Ray};
+use parry3d::shape::{Cuboid, RoundShape, Shape, ShapeType, SharedShape, SupportMap};
+
+#[test]
+fn round_shared_shape_matches_round_cuboid() {
+    let round_cuboid = RoundShape {
+        base_shape: Cuboid::new(Vector3::new(1.0, 2.0, 0.5)),
+        border_radius: 0.2,
+    };
+    let shape = SharedShape::round(SharedShape::cuboid(1.0, 2.0, 0.5), 0.2);
+    assert_eq!(shape.shape_type(), ShapeType::RoundSharedShape);
+    let round = shape.as_round_shared_shape().unwrap();
+
+    for dir in [
+        Vector3::new(1.0, 1.0, 1.0),
+        Vector3::new(-0.3, 2.0, 0.1),
+        Vector3::new(0.0, 0.0, -1.0),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(
+            round.local_support_point(dir),
+            round_cuboid.local_support_point(dir),
+            epsilon = 1.0e-6
+        );
+    }
+
+    let aabb = shape.compute_local_aabb();
+    let expected_aabb = round_cuboid.compute_local_aabb();
+    assert_relative_eq!(aabb.mins, expected_aabb.mins);
+    assert_relative_eq!(aabb.maxs, expected_aabb.maxs);
+    assert_relative_eq!(shape.ccd_thickness(), round_cuboid.ccd_thickness());
+
+    let ray = Ray::new(Point3::new(0.3, 10.0, 0.1), -Vector3::y());
+    let hit = shape
+        .cast_local_ray_and_get_normal(&ray, 100.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 7.8, epsilon = 1.0e-4);
+    assert_relative_eq!(hit.normal, Vector3::y(), epsilon = 1.0e-4);
+
+    for pt in [
+        Point3::new(3.0, 0.5, 0.0),
+        Point3::new(2.0, 3.0, 1.5),
+        Point3::new(0.1, 0.2, 0.3),
+    ]
+    .iter()
+    {
+        let proj = shape.project_local_point(pt, false);
+        let expected = round_cuboid.project_local_point(pt, false);
+        assert_eq!(proj.is_inside, expected.is_inside);
+        assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-4);
+    }
+
+    // The border radius of the base shape adds up with the one of the rounded shape.
+    let nested = SharedShape::round(SharedShape::round_cuboid(1.0, 2.0, 0.5, 0.1), 0.1);
+    let (_, border_radius) = nested.as_polygonal_feature_map().unwrap();
+    assert_relative_eq!(border_radius, 0.2);
+    assert_relative_eq!(
+        nested
+            .as_support_map()
+            .unwrap()
+            .local_support_point(&Vector3::x()),
+        round_cuboid.local_support_point(&Vector3::x()),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn round_shared_shape_queries() {
+    let shape = SharedShape::round(SharedShape::cone(1.0, 0.5), 0.25);
+    let ball = SharedShape::ball(0.5);
+
+    let pos1 = Isometry3::identity();
+    let pos2 = Isometry3::translation(0.0, 1.5, 0.0);
+    let contact = query::contact(&pos1, &*shape, &pos2, &*ball, 1.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.25, epsilon = 1.0e-4);
+    assert_relative_eq!(contact.normal1.into_inner(), Vector3::y(), epsilon = 1.0e-4);
+
+    let pos2 = Isometry3::translation(3.0, -1.0, 0.0);
+    let dist = query::distance(&pos1, &*shape, &pos2, &*ball).unwrap();
+    assert_relative_eq!(dist, 3.0 - 0.5 - 0.25 - 0.5, epsilon = 1.0e-3);
+
+    assert!(shape.contains_local_point(&Point3::new(0.0, 1.2, 0.0)));
+    assert!(!shape.contains_local_point(&Point3::new(0.0, 1.3, 0.0)));
+
+    // Uniform scaling keeps the base shape.
+    let scaled = shape.scaled(&Vector3::repeat(2.0), 10).unwrap();
+    let scaled = scaled.as_round_shared_shape().unwrap();
+    assert_relative_eq!(scaled.border_radius, 0.5);
+    assert_eq!(scaled.base_shape.shape_type(), ShapeType::Cone);
+    assert!(shape.scaled(&Vector3::new(1.0, 2.0, 1.0), 10).is_some());
+}
+
+#[test]
+#[should_panic]
+fn round_shared_shape_requires_support_map() {
+    let (vertices, indices) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    let _ = SharedShape::round(SharedShape::trimesh(vertices, indices), 0.1);
+}
+
+#[test]
+fn round_shared_shape_with_non_convex_base() {
+    let (vertices, indices) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    let round = RoundShape {
+        base_shape: SharedShape::trimesh(vertices, indices),
+        border_radius: 0.5,
+    };
+
+    // The checks of `SharedShape::round` can be bypassed through the public fields.
+    assert!(round.base_support_map().is_none());
+    assert!(round.as_support_map().is_none());
+    assert!(!round.is_convex());
+
+    // Queries don't panic.
+    let proj = round.project_local_point(&Point3::new(3.0, 0.0, 0.0), false);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(1.5, 0.0, 0.0), epsilon = 1.0e-5);
+    assert!(round.contains_local_point(&Point3::new(1.2, 0.0, 0.0)));
+
+    let ray = Ray::new(Point3::new(5.0, 0.0, 0.0), -Vector3::x());
+    assert!(query::ray_intersections(&Isometry3::identity(), &round, &ray, 10.0).len() <= 1);
+    assert!(query::distance(
+        &Isometry3::identity(),
+        &round,
+        &Isometry3::translation(5.0, 0.0, 0.0),
+        &Cuboid::new(Vector3::repeat(1.0)),
+    )
+    .is_err());
+}
@@ -0,0 +1,105 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{
+    self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+    RayCast,
+};
+use parry3d::shape::{Ball, RoundTriMesh, Shape, SharedShape, TriMesh};
+
+fn flat_round_trimesh(border_radius: Real) -> RoundTriMesh {
+    let vertices = vec![
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(-1.0, 0.0, 1.0),
+    ];
+    let indices = vec![[0, 2, 1], [0, 3, 2]];
+    RoundTriMesh::new(TriMesh::new(vertices, indices), border_radius)
+}
+
+#[test]
+fn round_trimesh_is_dilated_by_its_border_radius() {
+    let mesh = flat_round_trimesh(0.1);
+
+    let aabb = mesh.local_aabb();
+    assert_relative_eq!(aabb.mins, Point3::new(-1.1, -0.1, -1.1));
+    assert_relative_eq!(aabb.maxs, Point3::new(1.1, 0.1, 1.1));
+
+    let ray = Ray::new(Point3::new(0.2, 5.0, 0.3), -Vector3::y());
+    let hit = mesh
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.9, epsilon = 1.0e-3);
+    assert_relative_eq!(hit.normal, Vector3::y(), epsilon = 1.0e-3);
+
+    let pt = Point3::new(0.5, 1.0, 0.5);
+    let proj = mesh.project_local_point(&pt, true);
+    assert_relative_eq!(proj.point, Point3::new(0.5, 0.1, 0.5), epsilon = 1.0e-3);
+    assert!(mesh.contains_local_point(&Point3::new(0.5, -0.05, 0.5)));
+    assert!(!mesh.contains_local_point(&Point3::new(0.5, 0.15, 0.5)));
+}
+
+#[test]
+fn round_trimesh_ball_contact() {
+    let mesh = SharedShape::round_trimesh(
+        vec![
+            Point3::new(-1.0, 0.0, -1.0),
+            Point3::new(1.0, 0.0, -1.0),
+            Point3::new(1.0, 0.0, 1.0),
+            Point3::new(-1.0, 0.0, 1.0),
+        ],
+        vec![[0, 2, 1], [0, 3, 2]],
+        0.1,
+    );
+    assert!(mesh.as_round_trimesh().is_some());
+
+    let ball = Ball::new(0.2);
+    let pos_mesh = Isometry3::identity();
+
+    let pos_ball = Isometry3::translation(0.0, 0.5, 0.0);
+    let dist = query::distance(&pos_mesh, &*mesh, &pos_ball, &ball).unwrap();
+    assert_relative_eq!(dist, 0.2, epsilon = 1.0e-5);
+
+    // The ball doesn't touch the base mesh, but it touches its rounded border.
+    let pos_ball = Isometry3::translation(0.3, 0.25, 0.0);
+    assert!(query::intersection_test(&pos_mesh, &*mesh, &pos_ball, &ball).unwrap());
+    let contact = query::contact(&pos_mesh, &*mesh, &pos_ball, &ball, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-5);
+    assert_relative_eq!(*contact.normal1, Vector3::y(), epsilon = 1.0e-5);
+
+    let mut manifolds: Vec<ContactManifold<(), ()>> = vec![];
+    DefaultQueryDispatcher
+        .contact_manifolds(
+            &pos_ball.inv_mul(&pos_mesh),
+            &*mesh,
+            &ball,
+            0.0,
+            &mut manifolds,
+            &mut None,
+        )
+        .unwrap();
+    let deepest = manifolds
+        .iter()
+        .flat_map(|m| m.points.iter())
+        .map(|pt| pt.dist)
+        .fold(Real::MAX, Real::min);
+    assert_relative_eq!(deepest, -0.05, epsilon = 1.0e-5);
+}
+
+#[test]
+fn round_trimesh_mirror_and_scale() {
+    let mesh = flat_round_trimesh(0.1);
+
+    let scaled = mesh.scaled(&Vector3::repeat(2.0), 10).unwrap();
+    let scaled = scaled.as_round_trimesh().unwrap();
+    assert_relative_eq!(scaled.border_radius(), 0.2);
+    assert_relative_eq!(scaled.local_aabb().maxs, Point3::new(2.2, 0.2, 2.2));
+    assert!(mesh.scaled(&Vector3::new(1.0, 2.0, 1.0), 10).is_none());
+
+    let mirrored = mesh.mirrored(1).unwrap();
+    let mirrored = mirrored.as_round_trimesh().unwrap();
+    assert_relative_eq!(mirrored.border_radius(), 0.1);
+    assert_eq!(mirrored.trimesh().num_triangles(), 2);
+}
@@ -0,0 +1,126 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::PointQuery;
+use parry3d::shape::{Ball, Capsule, Compound, Cuboid, Cylinder, HalfSpace, SharedShape, TriMesh};
+use parry3d::transformation::{sample_surface, sample_volume};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+fn uniform(rng: &mut IsaacRng) -> impl FnMut() -> Real + '_ {
+    move || rng.gen::<Real>()
+}
+
+#[test]
+fn surface_samples_lie_on_convex_shapes() {
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let shapes: Vec<(&str, SharedShape)> = vec![
+        ("ball", SharedShape::ball(1.5)),
+        ("cuboid", SharedShape::cuboid(1.0, 2.0, 3.0)),
+        (
+            "capsule",
+            SharedShape::capsule(Point3::new(0.0, -1.0, 0.0), Point3::new(0.0, 1.0, 0.0), 0.5),
+        ),
+    ];
+
+    for (name, shape) in shapes {
+        let points = sample_surface(&*shape, 500, &mut uniform(&mut rng)).unwrap();
+        assert_eq!(points.len(), 500);
+
+        for pt in &points {
+            let proj = shape.project_local_point(pt, false);
+            assert!(
+                na::distance(pt, &proj.point) < 1.0e-3,
+                "{}: {:?} isn't on the boundary",
+                name,
+                pt
+            );
+        }
+    }
+}
+
+#[test]
+fn surface_samples_lie_on_cylinder() {
+    let mut rng = IsaacRng::seed_from_u64(5);
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let points = sample_surface(&cylinder, 500, &mut uniform(&mut rng)).unwrap();
+
+    for pt in &points {
+        let r = pt.xz().coords.norm();
+        let on_cap = (pt.y.abs() - 1.0).abs() < 1.0e-4 && r <= 0.5 + 1.0e-4;
+        let on_side = (r - 0.5).abs() < 1.0e-3 && pt.y.abs() <= 1.0 + 1.0e-4;
+        assert!(on_cap || on_side, "{:?} isn't on the boundary", pt);
+    }
+}
+
+#[test]
+fn surface_samples_of_cuboid_are_area_weighted() {
+    let mut rng = IsaacRng::seed_from_u64(1);
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 4.0));
+    let points = sample_surface(&cuboid, 10_000, &mut uniform(&mut rng)).unwrap();
+    // The two faces orthogonal to `z` cover 4 / 36 of the total area.
+    let on_z_faces = points.iter().filter(|pt| pt.z.abs() > 4.0 - 1.0e-4).count();
+    let ratio = on_z_faces as Real / points.len() as Real;
+    assert!((ratio - 4.0 / 36.0).abs() < 0.02, "ratio: {}", ratio);
+}
+
+#[test]
+fn surface_samples_lie_on_trimesh_and_compound() {
+    let mut rng = IsaacRng::seed_from_u64(2);
+    let (vtx, idx) = Cuboid::new(Vector3::new(1.0, 2.0, 0.5)).to_trimesh();
+    let trimesh = TriMesh::new(vtx, idx);
+    let points = sample_surface(&trimesh, 200, &mut uniform(&mut rng)).unwrap();
+
+    for pt in &points {
+        let proj = trimesh.project_local_point(pt, false);
+        assert!(na::distance(pt, &proj.point) < 1.0e-3);
+    }
+
+    let pos1 = Isometry3::translation(3.0, 0.0, 0.0);
+    let pos2 = Isometry3::new(Vector3::new(-3.0, 1.0, 0.0), Vector3::y());
+    let compound = Compound::new(vec![
+        (pos1, SharedShape::new(Ball::new(1.0))),
+        (pos2, SharedShape::new(Capsule::new_x(0.5, 0.25))),
+    ]);
+    let points = sample_surface(&compound, 200, &mut uniform(&mut rng)).unwrap();
+
+    for pt in &points {
+        let proj = compound.project_local_point(pt, false);
+        assert!(na::distance(pt, &proj.point) < 1.0e-3);
+    }
+}
+
+#[test]
+fn volume_samples_are_contained() {
+    let mut rng = IsaacRng::seed_from_u64(3);
+    let ball = Ball::new(2.0);
+    let points = sample_volume(&ball, 1000, &mut uniform(&mut rng)).unwrap();
+    assert_eq!(points.len(), 1000);
+    assert!(points.iter().all(|pt| pt.coords.norm() <= 2.0));
+
+    // The mean distance to the center of points uniformly distributed in a ball is 3r / 4.
+    let mean = points.iter().map(|pt| pt.coords.norm()).sum::<Real>() / 1000.0;
+    assert!((mean - 1.5).abs() < 0.05, "mean: {}", mean);
+}
+
+#[test]
+fn unbounded_shapes_cannot_be_sampled() {
+    let mut rng = IsaacRng::seed_from_u64(4);
+    let halfspace = HalfSpace::new(Vector3::y_axis());
+    assert!(sample_volume(&halfspace, 10, &mut uniform(&mut rng)).is_none());
+
+    let ball = Ball::new(0.0);
+    assert!(sample_surface(&ball, 10, &mut uniform(&mut rng)).is_none());
+}
+
+#[test]
+fn surfaces_with_infinite_area_cannot_be_sampled() {
+    // The area of this triangle overflows.
+    let vertices = vec![
+        Point3::origin(),
+        Point3::new(1.0e30, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0e30),
+    ];
+    let trimesh = TriMesh::new(vertices, vec![[0, 1, 2]]);
+    // Also checks that a zero random number doesn't produce a NaN sample.
+    assert!(sample_surface(&trimesh, 10, &mut || 0.0).is_none());
+}
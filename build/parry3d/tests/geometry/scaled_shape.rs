@@ -0,0 +1,89 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{self, PointQuery, Ray, RayCast};
+use parry3d::shape::{
+    Ball, ConvexPolyhedron, Cuboid, Ellipsoid, ScaledShape, Shape, SharedShape, SupportMap,
+};
+
+#[test]
+fn scaled_ball_is_an_ellipsoid() {
+    let radii = Vector3::new(1.0, 2.0, 0.5);
+    let scaled = ScaledShape::new(Ball::new(1.0), radii);
+    let ellipsoid = Ellipsoid::new(radii);
+
+    for dir in &[
+        Vector3::new(1.0, 0.3, -0.2),
+        Vector3::new(-0.4, 1.0, 0.7),
+        Vector3::new(0.1, -0.5, -1.0),
+    ] {
+        assert_relative_eq!(
+            scaled.local_support_point(dir),
+            ellipsoid.local_support_point(dir),
+            epsilon = 1.0e-5
+        );
+
+        let ray = Ray::new(Point3::origin() + dir * 5.0, -dir);
+        let hit = scaled
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        let expected = ellipsoid
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.toi, expected.toi, epsilon = 1.0e-4);
+        assert_relative_eq!(hit.normal, expected.normal, epsilon = 1.0e-4);
+
+        let pt = Point3::origin() + dir * 2.0;
+        assert_relative_eq!(
+            scaled.distance_to_local_point(&pt, true),
+            ellipsoid.distance_to_local_point(&pt, true),
+            epsilon = 1.0e-3
+        );
+    }
+
+    let aabb = scaled.compute_local_aabb();
+    assert_relative_eq!(aabb.mins, Point3::from(-radii), epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.maxs, Point3::from(radii), epsilon = 1.0e-5);
+    assert_relative_eq!(
+        scaled.mass_properties(2.0),
+        ellipsoid.mass_properties(2.0),
+        epsilon = 1.0e-3
+    );
+}
+
+#[test]
+fn scaled_convex_polyhedron() {
+    // An off-center box, to check the center of mass and the inertia tensor.
+    let points: Vec<_> = (0..8)
+        .map(|i| {
+            Point3::new(
+                (i % 2) as Real,
+                (i / 2 % 2) as Real * 2.0,
+                (i / 4) as Real * 0.5,
+            )
+        })
+        .collect();
+    let scale = Vector3::new(2.0, 0.5, 3.0);
+    let base = ConvexPolyhedron::from_convex_hull(&points).unwrap();
+    let scaled_points: Vec<_> = points
+        .iter()
+        .map(|pt| Point3::from(pt.coords.component_mul(&scale)))
+        .collect();
+    let expected = ConvexPolyhedron::from_convex_hull(&scaled_points).unwrap();
+    let scaled = ScaledShape::new(base, scale);
+
+    assert_relative_eq!(
+        scaled.mass_properties(1.5),
+        expected.mass_properties(1.5),
+        epsilon = 1.0e-4
+    );
+
+    // Contacts are computed through the support map of the scaled shape.
+    let cuboid = SharedShape::scaled(Cuboid::new(Vector3::repeat(0.5)), scale);
+    let ball = Ball::new(0.5);
+    let pos2 = Isometry3::translation(0.0, 0.6, 0.0);
+    let contact = query::contact(&Isometry3::identity(), &*cuboid, &pos2, &ball, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.15, epsilon = 1.0e-4);
+    assert_relative_eq!(*contact.normal1, Vector3::y(), epsilon = 1.0e-4);
+}
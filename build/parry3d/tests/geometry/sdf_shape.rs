@@ -0,0 +1,91 @@
+use na::{Point3, Vector3};
+use parry3d::bounding_volume::AABB;
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{SdfShape, Shape, Torus};
+
+fn sdf_torus(torus: Torus) -> SdfShape {
+    let aabb = torus.local_aabb();
+    SdfShape::new(move |pt| torus.signed_distance_to_local_point(pt), aabb)
+}
+
+#[test]
+fn sdf_shape_point_projection() {
+    let sphere = SdfShape::new(
+        |pt| pt.coords.norm() - 1.0,
+        AABB::from_half_extents(Point3::origin(), Vector3::repeat(1.0)),
+    );
+
+    let proj = sphere.project_local_point(&Point3::new(3.0, 0.0, 0.0), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-4);
+
+    let pt = Point3::new(0.2, 0.3, -0.1);
+    assert!(sphere.contains_local_point(&pt));
+    assert_eq!(sphere.project_local_point(&pt, true).point, pt);
+
+    let proj = sphere.project_local_point(&pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(
+        proj.point,
+        Point3::from(pt.coords.normalize()),
+        epsilon = 1.0e-3
+    );
+    assert_relative_eq!(
+        sphere.distance_to_local_point(&pt, false),
+        pt.coords.norm() - 1.0,
+        epsilon = 1.0e-3
+    );
+}
+
+#[test]
+fn sdf_shape_ray_cast_matches_analytic_shape() {
+    let torus = Torus::new(1.5, 0.4);
+    let sdf = sdf_torus(torus);
+
+    for i in 0..20 {
+        let angle = i as f32 * 0.3;
+        let origin = Point3::new(angle.cos() * 4.0, 0.3 * angle.sin(), angle.sin() * 4.0);
+        let dir = Point3::new(0.1 * angle.sin(), 0.05, 0.2) - origin;
+        let ray = Ray::new(origin, dir);
+
+        let expected = torus.cast_local_ray_and_get_normal(&ray, f32::MAX, true);
+        let hit = sdf.cast_local_ray_and_get_normal(&ray, f32::MAX, true);
+        assert_eq!(hit.is_some(), expected.is_some());
+
+        if let (Some(hit), Some(expected)) = (hit, expected) {
+            assert_relative_eq!(hit.toi, expected.toi, epsilon = 1.0e-3);
+            assert_relative_eq!(hit.normal, expected.normal, epsilon = 1.0e-2);
+        }
+    }
+
+    // From inside of the tube.
+    let ray = Ray::new(Point3::new(1.5, 0.0, 0.0), Vector3::x());
+    assert_eq!(sdf.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+    let hit = sdf
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.4, epsilon = 1.0e-3);
+    assert_relative_eq!(hit.normal, -Vector3::x(), epsilon = 1.0e-2);
+
+    // Through the hole, and stopped by `max_toi`.
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    assert!(sdf.cast_local_ray(&ray, f32::MAX, true).is_none());
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    assert!(sdf.cast_local_ray(&ray, 2.0, true).is_none());
+}
+
+#[test]
+fn sdf_shape_mass_properties() {
+    let torus = Torus::new(1.5, 0.4);
+    let sdf = sdf_torus(torus);
+    let expected = torus.mass_properties(2.0);
+    let mprops = sdf.mass_properties(2.0);
+
+    assert_relative_eq!(mprops.inv_mass, expected.inv_mass, max_relative = 0.02);
+    assert_relative_eq!(mprops.local_com, expected.local_com, epsilon = 1.0e-3);
+    assert_relative_eq!(
+        mprops.reconstruct_inertia_matrix(),
+        expected.reconstruct_inertia_matrix(),
+        max_relative = 0.02
+    );
+}
@@ -0,0 +1,113 @@
+use na::{DMatrix, Isometry3, Point3, UnitQuaternion, Vector3};
+use parry3d::shape::{
+    self, Ball, Compound, ConvexPolyhedron, Cuboid, HeightField, HeightFieldCellStatus, RoundShape,
+    SdfShape, SharedShape, TransformedShape, TriMesh,
+};
+
+#[test]
+fn approx_eq_primitives() {
+    let eps = 1.0e-5;
+
+    assert!(shape::approx_eq(&Ball::new(1.0), &Ball::new(1.000001), eps));
+    assert!(!shape::approx_eq(&Ball::new(1.0), &Ball::new(1.1), eps));
+    assert!(shape::approx_eq(
+        &Cuboid::new(Vector3::new(1.0, 2.0, 3.0)),
+        &Cuboid::new(Vector3::new(1.0, 2.000001, 3.0)),
+        eps
+    ));
+    assert!(!shape::approx_eq(
+        &Cuboid::new(Vector3::new(1.0, 2.0, 3.0)),
+        &Cuboid::new(Vector3::new(1.0, 2.0, 3.1)),
+        eps
+    ));
+
+    // Shapes of different types are never equal.
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    let (vertices, _) = cuboid.to_trimesh();
+    let polyhedron = ConvexPolyhedron::from_convex_hull(&vertices).unwrap();
+    assert!(!shape::approx_eq(&cuboid, &polyhedron, eps));
+    assert!(shape::approx_eq(&polyhedron, &polyhedron.clone(), eps));
+
+    let round1 = RoundShape {
+        base_shape: cuboid,
+        border_radius: 0.1,
+    };
+    let mut round2 = round1;
+    assert!(shape::approx_eq(&round1, &round2, eps));
+    round2.border_radius = 0.2;
+    assert!(!shape::approx_eq(&round1, &round2, eps));
+}
+
+#[test]
+fn approx_eq_trimeshes() {
+    let eps = 1.0e-5;
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(10, 10);
+    let mesh1 = TriMesh::new(vertices.clone(), indices.clone());
+
+    let jittered: Vec<_> = vertices
+        .iter()
+        .map(|pt| pt + Vector3::repeat(1.0e-6))
+        .collect();
+    let mesh2 = TriMesh::new(jittered, indices.clone());
+    assert!(shape::approx_eq(&mesh1, &mesh2, eps));
+    assert!(!shape::approx_eq(&mesh1, &mesh2, 1.0e-7));
+
+    let mut flipped = indices.clone();
+    flipped[0].swap(0, 1);
+    let mesh3 = TriMesh::new(vertices.clone(), flipped);
+    assert!(!shape::approx_eq(&mesh1, &mesh3, eps));
+
+    let mesh4 = TriMesh::new(vertices[1..].to_vec(), vec![[0, 1, 2]]);
+    assert!(!shape::approx_eq(&mesh1, &mesh4, eps));
+
+    let heights = DMatrix::from_fn(4, 5, |i, j| (i * j) as f32 * 0.1);
+    let hf1 = HeightField::new(heights.clone(), Vector3::new(10.0, 1.0, 10.0));
+    let mut hf2 = HeightField::new(heights, Vector3::new(10.0, 1.0, 10.0));
+    assert!(shape::approx_eq(&hf1, &hf2, eps));
+    hf2.set_cell_status(1, 1, HeightFieldCellStatus::CELL_REMOVED);
+    assert!(!shape::approx_eq(&hf1, &hf2, eps));
+}
+
+#[test]
+fn approx_eq_compounds_and_transformed_shapes() {
+    let eps = 1.0e-5;
+    let rot = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+    let pos1 = Isometry3::from_parts(Vector3::new(1.0, 2.0, 3.0).into(), rot);
+    // The opposite quaternion represents the same rotation.
+    let opposite_rot = UnitQuaternion::new_unchecked(-rot.into_inner());
+    let pos2 = Isometry3::from_parts(Vector3::new(1.0, 2.0, 3.0).into(), opposite_rot);
+
+    let compound1 = Compound::new(vec![
+        (pos1, SharedShape::ball(0.5)),
+        (Isometry3::identity(), SharedShape::cuboid(1.0, 1.0, 1.0)),
+    ]);
+    let compound2 = Compound::new(vec![
+        (pos2, SharedShape::ball(0.5)),
+        (Isometry3::identity(), SharedShape::cuboid(1.0, 1.0, 1.0)),
+    ]);
+    let compound3 = Compound::new(vec![
+        (pos2, SharedShape::ball(0.5)),
+        (
+            Isometry3::translation(0.1, 0.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0, 1.0),
+        ),
+    ]);
+    assert!(shape::approx_eq(&compound1, &compound2, eps));
+    assert!(!shape::approx_eq(&compound1, &compound3, eps));
+
+    let transformed1 = TransformedShape::new(pos1, Ball::new(0.5));
+    let transformed2 = TransformedShape::new(pos2, Ball::new(0.5));
+    let transformed3 = TransformedShape::new(Isometry3::identity(), Ball::new(0.5));
+    assert!(shape::approx_eq(&transformed1, &transformed2, eps));
+    assert!(!shape::approx_eq(&transformed1, &transformed3, eps));
+
+    // Shapes that can't be inspected are only equal to themselves.
+    let sdf = SdfShape::new(
+        |pt: &Point3<f32>| pt.coords.norm() - 1.0,
+        Cuboid::new(Vector3::repeat(1.0)).local_aabb(),
+    );
+    let sdf_clone = sdf.clone();
+    assert!(shape::approx_eq(&sdf, &sdf, eps));
+    assert!(!shape::approx_eq(&sdf, &sdf_clone, eps));
+    assert!(!shape::approx_eq(&sdf, &Ball::new(1.0), eps));
+}
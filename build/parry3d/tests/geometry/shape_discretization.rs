@@ -0,0 +1,97 @@
+use approx::assert_relative_eq;
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::shape::{
+    Ball, Capsule, Compound, Cone, ConvexPolyhedron, Cuboid, Cylinder, Ellipsoid, HalfSpace,
+    RoundShape, Segment, Shape, SharedShape, Torus,
+};
+
+// The signed volume enclosed by a triangle mesh, positive if its triangles are oriented
+// counterclockwise around the outward normals.
+fn enclosed_volume(vertices: &[Point3<Real>], indices: &[[u32; 3]]) -> Real {
+    indices
+        .iter()
+        .map(|idx| {
+            let a = vertices[idx[0] as usize].coords;
+            let b = vertices[idx[1] as usize].coords;
+            let c = vertices[idx[2] as usize].coords;
+            a.dot(&b.cross(&c)) / 6.0
+        })
+        .sum()
+}
+
+#[test]
+fn discretized_shapes_enclose_their_volume() {
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(0.0, 0.0, 2.0),
+    ];
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Ball::new(1.5)),
+        Box::new(Cuboid::new(Vector3::new(1.0, 2.0, 3.0))),
+        Box::new(Capsule::new(
+            Point3::new(0.0, -1.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            0.5,
+        )),
+        Box::new(Cylinder::new(1.0, 0.5)),
+        Box::new(Cone::new(1.0, 0.5)),
+        Box::new(Torus::new(2.0, 0.5)),
+        Box::new(Ellipsoid::new(Vector3::new(1.0, 2.0, 0.5))),
+        Box::new(ConvexPolyhedron::from_convex_hull(&points).unwrap()),
+        Box::new(RoundShape {
+            base_shape: Cuboid::new(Vector3::new(1.0, 1.0, 1.0)),
+            border_radius: 0.2,
+        }),
+    ];
+
+    for shape in &shapes {
+        let (vertices, indices) = shape.to_trimesh(64).unwrap();
+        let volume = enclosed_volume(&vertices, &indices);
+        assert_relative_eq!(volume, shape.volume(), max_relative = 0.02);
+    }
+}
+
+#[test]
+fn compound_discretization_merges_its_parts() {
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(-2.0, 0.0, 0.0),
+            SharedShape::new(cuboid),
+        ),
+        (
+            Isometry3::translation(2.0, 0.0, 0.0),
+            SharedShape::new(cuboid),
+        ),
+    ]);
+
+    let (vertices, indices) = compound.to_trimesh(8).unwrap();
+    assert_eq!(vertices.len(), 16);
+    assert_eq!(indices.len(), 24);
+    assert_relative_eq!(enclosed_volume(&vertices, &indices), 2.0, epsilon = 1.0e-5);
+
+    let aabb = compound.compute_local_aabb();
+    assert!(vertices.iter().all(|pt| aabb.contains_local_point(pt)));
+    assert_eq!(vertices.iter().filter(|pt| pt.x < 0.0).count(), 8);
+}
+
+#[test]
+fn faceless_and_unbounded_shapes_are_not_discretized() {
+    let segment = Segment::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0));
+    let halfspace = HalfSpace::new(Vector3::y_axis());
+    let shapes: [&dyn Shape; 2] = [&segment, &halfspace];
+
+    for shape in shapes.iter() {
+        assert!(shape.to_trimesh(16).is_none());
+    }
+
+    // Compound shapes can't be discretized if one of their parts can't.
+    let compound = Compound::new(vec![
+        (Isometry3::identity(), SharedShape::ball(1.0)),
+        (Isometry3::identity(), SharedShape::new(segment)),
+    ]);
+    assert!(compound.to_trimesh(16).is_none());
+}
@@ -0,0 +1,106 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::PointQuery;
+use parry3d::shape::{Cone, Cuboid, Shape, SharedShape, TriMesh};
+
+fn signed_volume(mesh: &TriMesh) -> f32 {
+    mesh.triangles()
+        .map(|tri| tri.a.coords.dot(&tri.b.coords.cross(&tri.c.coords)) / 6.0)
+        .sum()
+}
+
+#[test]
+fn mirrored_cuboid_aabb() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let pos = Isometry3::translation(1.0, 2.0, 3.0);
+
+    for axis in 0..3 {
+        let mirrored = cuboid.mirrored(axis).unwrap();
+        let mut mirrored_pos = pos;
+        mirrored_pos.translation.vector[axis] *= -1.0;
+
+        let aabb = cuboid.compute_aabb(&pos);
+        let mirrored_aabb = mirrored.compute_aabb(&mirrored_pos);
+        let mut expected_mins = aabb.mins;
+        let mut expected_maxs = aabb.maxs;
+        expected_mins[axis] = -aabb.maxs[axis];
+        expected_maxs[axis] = -aabb.mins[axis];
+        assert_relative_eq!(mirrored_aabb.mins, expected_mins);
+        assert_relative_eq!(mirrored_aabb.maxs, expected_maxs);
+    }
+}
+
+#[test]
+fn mirrored_trimesh_winding() {
+    let (vertices, indices) = Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).to_trimesh();
+    let vertices = vertices
+        .into_iter()
+        .map(|pt| pt + Vector3::new(2.0, 1.0, 0.5))
+        .collect();
+    let mesh = TriMesh::new(vertices, indices);
+    let volume = signed_volume(&mesh);
+    assert!(volume > 0.0);
+
+    for axis in 0..3 {
+        let mirrored = mesh.mirrored(axis).unwrap();
+        let mirrored = mirrored.as_trimesh().unwrap();
+
+        for (idx, mirrored_idx) in mesh.indices().iter().zip(mirrored.indices()) {
+            assert_eq!(*mirrored_idx, [idx[0], idx[2], idx[1]]);
+        }
+
+        assert_relative_eq!(
+            mirrored.local_aabb().mins[axis],
+            -mesh.local_aabb().maxs[axis]
+        );
+        assert_relative_eq!(signed_volume(mirrored), volume, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn mirrored_cone_and_compound() {
+    let cone = Cone::new(1.0, 0.5);
+    let mirrored = cone.mirrored(1).unwrap();
+    // Near the base of the mirrored cone, but outside of the original cone.
+    assert!(mirrored.contains_local_point(&Point3::new(0.4, 0.9, 0.0)));
+    assert!(!mirrored.contains_local_point(&Point3::new(0.4, -0.9, 0.0)));
+    assert!(cone.contains_local_point(&Point3::new(0.4, -0.9, 0.0)));
+
+    let compound = SharedShape::compound(vec![
+        (
+            Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::z() * 0.3),
+            SharedShape::cuboid(1.0, 0.2, 0.2),
+        ),
+        (
+            Isometry3::translation(0.0, 2.0, 0.0),
+            SharedShape::cone(1.0, 0.5),
+        ),
+    ]);
+    let mirrored = compound.mirrored(0).unwrap();
+    let aabb = compound.compute_local_aabb();
+    let mirrored_aabb = mirrored.compute_local_aabb();
+    assert_relative_eq!(mirrored_aabb.mins.x, -aabb.maxs.x, epsilon = 1.0e-5);
+    assert_relative_eq!(mirrored_aabb.maxs.x, -aabb.mins.x, epsilon = 1.0e-5);
+    assert_relative_eq!(mirrored_aabb.mins.yz(), aabb.mins.yz(), epsilon = 1.0e-5);
+    assert_relative_eq!(mirrored_aabb.maxs.yz(), aabb.maxs.yz(), epsilon = 1.0e-5);
+}
+
+#[test]
+fn mirrored_compound_with_cone_along_cone_axis() {
+    let compound = SharedShape::compound(vec![
+        (
+            Isometry3::translation(0.0, 2.0, 0.0),
+            SharedShape::cone(1.0, 0.5),
+        ),
+        (Isometry3::identity(), SharedShape::cuboid(0.5, 0.5, 0.5)),
+    ]);
+
+    // The cone is reflected by flipping it, which must not nest a compound into the result.
+    let mirrored = compound.mirrored(1).unwrap();
+    let mirrored = mirrored.as_compound().unwrap();
+    assert_eq!(mirrored.shapes().len(), 2);
+
+    // Near the base of the mirrored cone, but outside of the original cone.
+    assert!(mirrored.contains_local_point(&Point3::new(0.4, -1.1, 0.0)));
+    assert!(!mirrored.contains_local_point(&Point3::new(0.4, -2.9, 0.0)));
+    assert!(compound.contains_local_point(&Point3::new(0.4, 1.1, 0.0)));
+}
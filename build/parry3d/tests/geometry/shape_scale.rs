@@ -0,0 +1,81 @@
+use na::{Isometry3, Point3, Unit, UnitQuaternion, Vector3};
+use parry3d::shape::{Ball, Cuboid, Cylinder, HalfSpace, Shape, SharedShape, TriMesh};
+
+#[test]
+fn scaled_shapes_are_exact_when_possible() {
+    let ball = Ball::new(0.5);
+    let scaled = ball.scaled(&Vector3::repeat(2.0), 16).unwrap();
+    assert_eq!(scaled.as_ball().unwrap().radius, 1.0);
+
+    // A non-uniformly scaled ball is an ellipsoid.
+    let scaled = ball.scaled(&Vector3::new(1.0, 2.0, 3.0), 16).unwrap();
+    assert_eq!(
+        scaled.as_ellipsoid().unwrap().radii,
+        Vector3::new(0.5, 1.0, 1.5)
+    );
+
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let scaled = cuboid.scaled(&Vector3::new(2.0, 0.5, 1.0), 16).unwrap();
+    assert_eq!(
+        scaled.as_cuboid().unwrap().half_extents,
+        Vector3::new(2.0, 1.0, 3.0)
+    );
+
+    let halfspace = HalfSpace::new(Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0)));
+    let scaled = halfspace.scaled(&Vector3::new(2.0, 1.0, 1.0), 16).unwrap();
+    let normal = scaled.as_shape::<HalfSpace>().unwrap().normal;
+    assert_relative_eq!(*normal, Vector3::new(1.0, 2.0, 0.0).normalize());
+
+    let (vertices, indices) = cuboid.to_trimesh();
+    let mesh = TriMesh::new(vertices, indices);
+    let scale = Vector3::new(2.0, 0.5, 1.0);
+    let scaled = mesh.scaled(&scale, 16).unwrap();
+    assert_relative_eq!(scaled.compute_local_aabb().maxs, Point3::new(2.0, 1.0, 3.0));
+
+    assert!(cuboid.scaled(&Vector3::new(-1.0, 1.0, 1.0), 16).is_none());
+}
+
+#[test]
+fn scaled_shapes_are_discretized_otherwise() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let scale = Vector3::new(2.0, 1.0, 3.0);
+    let scaled = cylinder.scaled(&scale, 32).unwrap();
+    let polyhedron = scaled.as_convex_polyhedron().unwrap();
+
+    let aabb = polyhedron.local_aabb();
+    assert_relative_eq!(aabb.maxs, Point3::new(1.0, 1.0, 1.5), epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.mins, Point3::new(-1.0, -1.0, -1.5), epsilon = 1.0e-5);
+
+    // The volume of the elliptic cylinder, up to the discretization error.
+    let volume = 1.0 / polyhedron.mass_properties(1.0).inv_mass;
+    let expected = std::f32::consts::PI * 1.0 * 1.5 * 2.0;
+    assert_relative_eq!(volume, expected, max_relative = 0.02);
+}
+
+#[test]
+fn scaled_compound() {
+    let compound = SharedShape::compound(vec![
+        (
+            Isometry3::translation(1.0, 0.0, 0.0),
+            SharedShape::ball(0.5),
+        ),
+        (
+            Isometry3::translation(0.0, 1.0, 0.0),
+            SharedShape::cuboid(0.5, 0.5, 0.5),
+        ),
+    ]);
+    let scale = Vector3::new(2.0, 3.0, 1.0);
+    let scaled = compound.scaled(&scale, 16).unwrap();
+    let aabb = scaled.compute_local_aabb();
+    assert_relative_eq!(aabb.mins, Point3::new(-1.0, -1.5, -0.5));
+    assert_relative_eq!(aabb.maxs, Point3::new(3.0, 4.5, 0.5));
+
+    // Rotated sub-shapes can only be scaled uniformly.
+    let rotation = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 0.3);
+    let compound = SharedShape::compound(vec![(
+        Isometry3::from_parts(Vector3::x().into(), rotation),
+        SharedShape::cuboid(0.5, 0.5, 0.5),
+    )]);
+    assert!(compound.scaled(&scale, 16).is_none());
+    assert!(compound.scaled(&Vector3::repeat(2.0), 16).is_some());
+}
@@ -0,0 +1,28 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query;
+use parry3d::shape::{Ball, Shape, ShapeType, SharedShape};
+
+#[test]
+fn shared_shape_wraps_any_shape() {
+    let ball = SharedShape::new(Ball::new(0.5));
+    let clone = ball.clone();
+    assert_eq!(clone.shape_type(), ShapeType::Ball);
+    assert_eq!(clone.as_ball().unwrap().radius, 0.5);
+    assert_eq!(format!("{:?}", ball), "SharedShape(Ball)");
+
+    let as_ref: &dyn Shape = ball.as_ref();
+    assert!(as_ref.contains_local_point(&Point3::new(0.0, 0.4, 0.0)));
+}
+
+#[test]
+fn shared_shape_halfspace() {
+    let ground = SharedShape::halfspace(Vector3::y_axis());
+    let ball = SharedShape::ball(0.5);
+    assert_eq!(ground.shape_type(), ShapeType::HalfSpace);
+
+    let pos2 = Isometry3::translation(0.0, 0.4, 0.0);
+    let contact = query::contact(&Isometry3::identity(), &*ground, &pos2, &*ball, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-5);
+}
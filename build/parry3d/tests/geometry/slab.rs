@@ -0,0 +1,198 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, PersistentQueryDispatcher, PointQuery, Ray, RayCast};
+use parry3d::query::{ContactManifold, DefaultQueryDispatcher};
+use parry3d::shape::{Ball, Cuboid, FeatureId, Slab};
+
+fn water() -> Slab {
+    // The region between the planes y = -1 and y = 1.
+    Slab::new(Vector3::y_axis(), 1.0)
+}
+
+#[test]
+fn slab_point_queries() {
+    let slab = water();
+
+    assert!(slab.contains_local_point(&Point3::new(5.0, 0.5, -3.0)));
+    assert!(!slab.contains_local_point(&Point3::new(0.0, -1.5, 0.0)));
+
+    let proj = slab.project_local_point(&Point3::new(2.0, 3.0, 1.0), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(2.0, 1.0, 1.0));
+
+    // Non-solid projection of an inner point on the closest boundary.
+    let (proj, feature) = slab.project_local_point_and_get_feature(&Point3::new(0.0, -0.75, 0.0));
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.0, -1.0, 0.0));
+    assert_eq!(feature, FeatureId::Face(1));
+
+    assert_relative_eq!(
+        slab.distance_to_local_point(&Point3::new(0.0, -4.0, 0.0), true),
+        3.0
+    );
+    assert_relative_eq!(
+        slab.distance_to_local_point(&Point3::new(0.0, 0.25, 0.0), false),
+        -0.75
+    );
+    assert_eq!(
+        slab.distance_to_local_point(&Point3::new(0.0, 0.25, 0.0), true),
+        0.0
+    );
+}
+
+#[test]
+fn slab_ray_casts() {
+    let slab = water();
+
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    let hit = slab
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.0);
+    assert_relative_eq!(hit.normal, Vector3::y());
+
+    let ray = Ray::new(Point3::new(0.0, -5.0, 0.0), Vector3::new(1.0, 1.0, 0.0));
+    let hit = slab
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.0);
+    assert_relative_eq!(hit.normal, -Vector3::y());
+    assert!(slab.cast_local_ray(&ray, 3.0, true).is_none());
+
+    // From the inside, the ray hits the boundary it exits through.
+    let ray = Ray::new(Point3::new(0.0, 0.5, 0.0), Vector3::y());
+    assert_eq!(slab.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+    let hit = slab
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, -Vector3::y());
+
+    // Rays parallel to the slab never hit its boundaries.
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), Vector3::x());
+    assert!(slab.cast_local_ray(&ray, f32::MAX, true).is_none());
+    let ray = Ray::new(Point3::new(0.0, 0.0, 0.0), Vector3::x());
+    assert!(slab.cast_local_ray(&ray, f32::MAX, false).is_none());
+}
+
+#[test]
+fn slab_cuboid_queries() {
+    let slab = water();
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let origin = Isometry3::identity();
+
+    // Sinking through the top boundary.
+    let pos = Isometry3::translation(3.0, 1.25, 0.0);
+    let contact = query::contact(&origin, &slab, &pos, &cuboid, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.25, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal1, Vector3::y());
+    assert_relative_eq!(contact.point1.y, 1.0, epsilon = 1.0e-6);
+    assert!(query::intersection_test(&origin, &slab, &pos, &cuboid).unwrap());
+
+    // Separated from the bottom boundary, with flipped arguments.
+    let pos = Isometry3::translation(0.0, -2.0, 0.0);
+    assert!(query::contact(&pos, &cuboid, &origin, &slab, 0.4)
+        .unwrap()
+        .is_none());
+    let contact = query::contact(&pos, &cuboid, &origin, &slab, 1.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, 0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal1, Vector3::y());
+    assert_relative_eq!(
+        query::distance(&origin, &slab, &pos, &cuboid).unwrap(),
+        0.5,
+        epsilon = 1.0e-6
+    );
+    assert!(!query::intersection_test(&origin, &slab, &pos, &cuboid).unwrap());
+
+    // Deep inside a thick slab, the contact pushes toward the closest boundary.
+    let thick = Slab::new(Vector3::y_axis(), 10.0);
+    let pos = Isometry3::translation(0.0, -8.0, 0.0);
+    let contact = query::contact(&origin, &thick, &pos, &cuboid, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -2.5, epsilon = 1.0e-5);
+    assert_relative_eq!(*contact.normal1, -Vector3::y());
+}
+
+#[test]
+fn slab_ball_time_of_impact() {
+    let slab = water();
+    let ball = Ball::new(0.5);
+    let origin = Isometry3::identity();
+    let pos = Isometry3::translation(0.0, 4.0, 0.0);
+    let zero = Vector3::zeros();
+
+    let toi = query::time_of_impact(
+        &origin,
+        &zero,
+        &slab,
+        &pos,
+        &Vector3::new(0.0, -2.0, 0.0),
+        &ball,
+        f32::MAX,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+    assert_relative_eq!(toi.toi, 1.25, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness1, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness2, Point3::new(0.0, -0.5, 0.0), epsilon = 1.0e-6);
+
+    // Moving away from the slab.
+    assert!(query::time_of_impact(
+        &origin,
+        &zero,
+        &slab,
+        &pos,
+        &Vector3::new(0.0, 2.0, 0.0),
+        &ball,
+        f32::MAX,
+        0.0,
+    )
+    .unwrap()
+    .is_none());
+}
+
+#[test]
+fn cuboid_resting_on_slab_manifold() {
+    let slab = water();
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let pos12 = Isometry3::translation(2.0, 1.49, -1.0);
+
+    let mut manifolds = Vec::new();
+    DefaultQueryDispatcher
+        .contact_manifolds(&pos12, &slab, &cuboid, 0.1, &mut manifolds, &mut None)
+        .unwrap();
+
+    let manifold: &ContactManifold<(), ()> = &manifolds[0];
+    assert_eq!(manifold.points.len(), 4);
+    assert_relative_eq!(manifold.local_n1, Vector3::y());
+    assert_relative_eq!(manifold.local_n2, -Vector3::y());
+
+    for pt in &manifold.points {
+        assert_relative_eq!(pt.dist, -0.01, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.local_p1.y, 1.0, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.local_p2.y, -0.5, epsilon = 1.0e-5);
+    }
+
+    // With flipped arguments.
+    let mut manifolds = Vec::new();
+    DefaultQueryDispatcher
+        .contact_manifolds(
+            &pos12.inverse(),
+            &cuboid,
+            &slab,
+            0.1,
+            &mut manifolds,
+            &mut None,
+        )
+        .unwrap();
+
+    let manifold: &ContactManifold<(), ()> = &manifolds[0];
+    assert_eq!(manifold.points.len(), 4);
+    assert_relative_eq!(manifold.local_n1, -Vector3::y());
+    assert_relative_eq!(manifold.points[0].local_p1.y, -0.5, epsilon = 1.0e-5);
+}
@@ -0,0 +1,96 @@
+use na::{Point2, Point3, Vector3};
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{Cone, Cylinder, Shape, SolidOfRevolution, SupportMap};
+
+#[test]
+fn solid_of_revolution_matches_cylinder_and_cone() {
+    let cylinder = Cylinder::new(0.5, 0.3);
+    let cylinder_profile = vec![Point2::new(0.3, -0.5), Point2::new(0.3, 0.5)];
+    let solid = SolidOfRevolution::new(cylinder_profile);
+
+    let dirs = [
+        Vector3::new(1.0, 0.2, 0.3),
+        Vector3::new(-0.5, -1.0, 0.1),
+        Vector3::new(0.05, 1.0, 0.0),
+        Vector3::new(0.1, -0.2, -3.0),
+    ];
+
+    for dir in dirs.iter() {
+        assert_relative_eq!(
+            solid.local_support_point(dir),
+            cylinder.local_support_point(dir),
+            epsilon = 1.0e-6
+        );
+    }
+
+    let (mprops1, mprops2) = (solid.mass_properties(2.0), cylinder.mass_properties(2.0));
+    assert_relative_eq!(mprops1.inv_mass, mprops2.inv_mass, max_relative = 1.0e-5);
+    assert_relative_eq!(mprops1.local_com, mprops2.local_com, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        mprops1.reconstruct_inertia_matrix(),
+        mprops2.reconstruct_inertia_matrix(),
+        max_relative = 1.0e-5
+    );
+
+    let cone = Cone::new(0.5, 0.3);
+    let cone_profile = vec![Point2::new(0.3, -0.5), Point2::new(0.0, 0.5)];
+    let solid = SolidOfRevolution::new(cone_profile);
+
+    for dir in dirs.iter() {
+        assert_relative_eq!(
+            solid.local_support_point(dir),
+            cone.local_support_point(dir),
+            epsilon = 1.0e-6
+        );
+    }
+
+    // The inertia of a cone of height 1 and radius 0.3 wrt. its center of mass.
+    let (mprops1, mprops2) = (solid.mass_properties(2.0), cone.mass_properties(2.0));
+    let mass = 1.0 / mprops2.inv_mass;
+    let transverse = mass * (3.0 * 0.3 * 0.3 / 20.0 + 3.0 / 80.0);
+    let axial = mass * 3.0 * 0.3 * 0.3 / 10.0;
+    assert_relative_eq!(mprops1.inv_mass, mprops2.inv_mass, max_relative = 1.0e-5);
+    assert_relative_eq!(mprops1.local_com, mprops2.local_com, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        mprops1.reconstruct_inertia_matrix(),
+        na::Matrix3::from_diagonal(&Vector3::new(transverse, axial, transverse)),
+        max_relative = 1.0e-5
+    );
+}
+
+#[test]
+fn solid_of_revolution_barrel() {
+    // The point at `y = 0.2` makes the profile non-convex and is discarded.
+    let profile = vec![
+        Point2::new(0.5, -1.0),
+        Point2::new(0.9, -0.3),
+        Point2::new(0.7, 0.2),
+        Point2::new(0.9, 0.3),
+        Point2::new(0.5, 1.0),
+    ];
+    let barrel = SolidOfRevolution::new(profile);
+    assert_eq!(barrel.profile().len(), 4);
+    assert_relative_eq!(barrel.radius_at(0.0).unwrap(), 0.9);
+    assert!(barrel.radius_at(1.1).is_none());
+
+    assert!(barrel.contains_local_point(&Point3::new(0.6, 0.0, 0.6)));
+    assert!(!barrel.contains_local_point(&Point3::new(0.6, 0.9, 0.0)));
+
+    let ray = Ray::new(Point3::new(-3.0, 0.0, 0.0), Vector3::x());
+    let hit = barrel
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.1, epsilon = 1.0e-4);
+    assert_relative_eq!(hit.normal, -Vector3::x(), epsilon = 1.0e-4);
+
+    let ray = Ray::new(Point3::new(0.0, 3.0, 0.0), -Vector3::y());
+    let hit = barrel
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0, epsilon = 1.0e-4);
+    assert_relative_eq!(hit.normal, Vector3::y(), epsilon = 1.0e-4);
+
+    let aabb = barrel.local_aabb();
+    assert_relative_eq!(aabb.mins, Point3::new(-0.9, -1.0, -0.9), epsilon = 1.0e-6);
+    assert_relative_eq!(aabb.maxs, Point3::new(0.9, 1.0, 0.9), epsilon = 1.0e-6);
+}
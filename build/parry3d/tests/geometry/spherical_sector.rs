@@ -0,0 +1,208 @@
+use na::{self, Isometry3, Matrix3, Point3, Vector3};
+use parry3d::bounding_volume::BoundingVolume;
+use parry3d::mass_properties::MassProperties;
+use parry3d::query::{self, PointQuery, Ray, RayCast};
+use parry3d::shape::{Ball, FeatureId, Shape, SphericalSector, SupportMap, Tetrahedron};
+
+#[test]
+fn spherical_sector_point_queries() {
+    let sector = SphericalSector::new(2.0, std::f32::consts::FRAC_PI_4);
+
+    assert!(sector.contains_local_point(&Point3::new(0.0, 1.0, 0.0)));
+    assert!(sector.contains_local_point(&Point3::new(0.5, 1.0, 0.2)));
+    assert!(!sector.contains_local_point(&Point3::new(1.0, 0.5, 0.0)));
+    assert!(!sector.contains_local_point(&Point3::new(0.0, 2.1, 0.0)));
+    assert!(!sector.contains_local_point(&Point3::new(0.0, -0.1, 0.0)));
+
+    // Closest to the spherical cap.
+    let (proj, feature) = sector.project_local_point_and_get_feature(&Point3::new(0.1, 3.0, 0.0));
+    assert!(!proj.is_inside);
+    assert_eq!(feature, FeatureId::Face(0));
+    assert_relative_eq!(proj.point.coords.norm(), 2.0, epsilon = 1.0e-5);
+
+    // Closest to the lateral surface of the cone.
+    let pt = Point3::new(1.0, 0.0, 0.0);
+    let (proj, feature) = sector.project_local_point_and_get_feature(&pt);
+    assert!(!proj.is_inside);
+    assert_eq!(feature, FeatureId::Face(1));
+    assert_relative_eq!(proj.point, Point3::new(0.5, 0.5, 0.0), epsilon = 1.0e-5);
+    assert_relative_eq!(
+        sector.distance_to_local_point(&pt, true),
+        0.5f32.sqrt(),
+        epsilon = 1.0e-5
+    );
+
+    // Behind the apex.
+    let proj = sector.project_local_point(&Point3::new(0.0, -1.0, 0.0), true);
+    assert_relative_eq!(proj.point, Point3::origin(), epsilon = 1.0e-5);
+
+    // Inside, closer to the cone than to the cap.
+    let proj = sector.project_local_point(&Point3::new(0.3, 0.5, 0.0), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.4, 0.4, 0.0), epsilon = 1.0e-5);
+}
+
+#[test]
+fn spherical_sector_ray_cast() {
+    let sector = SphericalSector::new(2.0, std::f32::consts::FRAC_PI_4);
+
+    // Hits the spherical cap.
+    let ray = Ray::new(Point3::new(0.2, 5.0, 0.0), -Vector3::y());
+    let hit = sector
+        .cast_local_ray_and_get_normal(&ray, 10.0, true)
+        .unwrap();
+    let pt = ray.point_at(hit.toi);
+    assert_relative_eq!(pt.coords.norm(), 2.0, epsilon = 1.0e-5);
+    assert_relative_eq!(hit.normal, pt.coords / 2.0, epsilon = 1.0e-5);
+
+    // Hits the lateral surface of the cone.
+    let ray = Ray::new(Point3::new(3.0, 1.0, 0.0), -Vector3::x());
+    let hit = sector
+        .cast_local_ray_and_get_normal(&ray, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0, epsilon = 1.0e-5);
+    let expected_normal = Vector3::new(1.0, -1.0, 0.0).normalize();
+    assert_relative_eq!(hit.normal, expected_normal, epsilon = 1.0e-5);
+
+    // Passes below the sector.
+    let ray = Ray::new(Point3::new(3.0, -0.1, 0.0), -Vector3::x());
+    assert!(!sector.intersects_local_ray(&ray, 10.0));
+
+    // Passes through the empty half of the double cone.
+    let ray = Ray::new(Point3::new(0.5, -3.0, 0.0), Vector3::y());
+    let hit = sector
+        .cast_local_ray_and_get_normal(&ray, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.5, epsilon = 1.0e-5);
+}
+
+#[test]
+fn spherical_sector_support_map() {
+    let sector = SphericalSector::new(1.5, 0.6);
+    let ball = Ball::new(1.5);
+
+    // Within the cone, the support points match the ones of the ball.
+    let dir = Vector3::new(0.1, 1.0, -0.2);
+    assert_relative_eq!(
+        sector.local_support_point(&dir),
+        ball.local_support_point(&dir),
+        epsilon = 1.0e-6
+    );
+
+    // Sideways, the support point is on the rim.
+    let pt = sector.local_support_point(&Vector3::x());
+    assert_relative_eq!(
+        pt,
+        Point3::new(1.5 * 0.6f32.sin(), 1.5 * 0.6f32.cos(), 0.0),
+        epsilon = 1.0e-6
+    );
+
+    // Downward, the support point is the apex.
+    let pt = sector.local_support_point(&-Vector3::y());
+    assert_relative_eq!(pt, Point3::origin());
+
+    // Only convex sectors are support maps.
+    assert!(sector.as_support_map().is_some());
+    assert!(SphericalSector::new(1.5, 2.0).as_support_map().is_none());
+}
+
+#[test]
+fn spherical_sector_mass_properties_and_bounding_volumes() {
+    // Same orientation as the triangle-mesh of a ball: sum of signed volumes of the
+    // tetrahedra formed by the origin and each triangle.
+    let signed_volume = |(vertices, indices): (Vec<Point3<f32>>, Vec<[u32; 3]>)| -> f32 {
+        indices
+            .iter()
+            .map(|idx| {
+                let [a, b, c] = [idx[0], idx[1], idx[2]].map(|i| vertices[i as usize]);
+                Tetrahedron::new(Point3::origin(), a, b, c).signed_volume()
+            })
+            .sum()
+    };
+    let ball_volume = signed_volume(Ball::new(1.5).to_trimesh(100, 100));
+
+    for half_angle in [0.4, 1.2, 2.5].iter() {
+        let sector = SphericalSector::new(1.5, *half_angle);
+        let (vertices, indices) = sector.to_trimesh(200, 100);
+        let mprops = sector.mass_properties(2.0);
+
+        let mesh_volume = signed_volume((vertices.clone(), indices.clone()));
+        assert_eq!(mesh_volume.signum(), ball_volume.signum());
+        assert_relative_eq!(
+            mesh_volume.abs(),
+            1.0 / (mprops.inv_mass * 2.0),
+            max_relative = 1.0e-2
+        );
+
+        let sphere = sector.local_bounding_sphere();
+        let loosened_aabb = sector.local_aabb().loosened(1.0e-5);
+
+        for pt in vertices.iter() {
+            assert!(na::distance(pt, sphere.center()) <= sphere.radius() + 1.0e-5);
+            assert!(loosened_aabb.contains_local_point(pt));
+        }
+
+        if sector.is_convex() {
+            let expected = MassProperties::from_convex_polyhedron(2.0, &vertices, &indices);
+            assert_relative_eq!(mprops.inv_mass, expected.inv_mass, max_relative = 1.0e-2);
+            assert_relative_eq!(mprops.local_com, expected.local_com, epsilon = 1.0e-2);
+            assert_relative_eq!(
+                mprops.reconstruct_inertia_matrix(),
+                expected.reconstruct_inertia_matrix(),
+                epsilon = 1.0e-3,
+                max_relative = 1.0e-2
+            );
+        } else {
+            // A concave sector and the convex sector pointing downward that complements it
+            // make up a ball.
+            let complement = SphericalSector::new(1.5, std::f32::consts::PI - half_angle);
+            let flip = Isometry3::rotation(Vector3::x() * std::f32::consts::PI);
+            let complement_mprops = complement.mass_properties(2.0).transform_by(&flip);
+            let ball_mprops = Ball::new(1.5).mass_properties(2.0);
+
+            let mass = |m: &MassProperties| 1.0 / m.inv_mass;
+            // The angular inertia wrt. the center of the ball.
+            let inertia = |m: &MassProperties| {
+                let com = m.local_com.coords;
+                m.reconstruct_inertia_matrix()
+                    + (Matrix3::identity() * com.norm_squared() - com * com.transpose()) * mass(m)
+            };
+
+            assert_relative_eq!(
+                mass(&mprops) + mass(&complement_mprops),
+                mass(&ball_mprops),
+                max_relative = 1.0e-5
+            );
+            assert_relative_eq!(
+                mprops.local_com.coords * mass(&mprops)
+                    + complement_mprops.local_com.coords * mass(&complement_mprops),
+                Vector3::zeros(),
+                epsilon = 1.0e-4
+            );
+            assert_relative_eq!(
+                inertia(&mprops) + inertia(&complement_mprops),
+                inertia(&ball_mprops),
+                epsilon = 1.0e-3,
+                max_relative = 1.0e-4
+            );
+        }
+    }
+}
+
+#[test]
+fn spherical_sector_ball_contact() {
+    let sector = SphericalSector::new(2.0, std::f32::consts::FRAC_PI_4);
+    let ball = Ball::new(0.5);
+
+    let pos1 = Isometry3::identity();
+    let pos2 = Isometry3::translation(0.0, 2.4, 0.0);
+    let contact = query::contact(&pos1, &sector, &pos2, &ball, 1.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-4);
+    assert_relative_eq!(contact.normal1.into_inner(), Vector3::y(), epsilon = 1.0e-4);
+
+    let pos2 = Isometry3::translation(2.0, 0.0, 0.0);
+    let dist = query::distance(&pos1, &sector, &pos2, &ball).unwrap();
+    assert_relative_eq!(dist, 2.0f32.sqrt() - 0.5, epsilon = 1.0e-3);
+}
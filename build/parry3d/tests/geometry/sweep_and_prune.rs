@@ -0,0 +1,36 @@
+use na::{Point3, Vector3};
+use parry3d::bounding_volume::{BoundingVolume, AABB};
+use parry3d::query;
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+
+#[test]
+fn sweep_and_prune_matches_brute_force() {
+    let mut rng = IsaacRng::seed_from_u64(0);
+    let aabbs: Vec<_> = (0..500u32)
+        .map(|i| {
+            let center = Point3::from(rng.gen::<Vector3<f32>>() * 20.0);
+            let half_extents = rng.gen::<Vector3<f32>>() + Vector3::repeat(0.01);
+            (i * 2, AABB::from_half_extents(center, half_extents))
+        })
+        .collect();
+
+    let mut expected = Vec::new();
+    for i in 0..aabbs.len() {
+        for j in i + 1..aabbs.len() {
+            if aabbs[i].1.intersects(&aabbs[j].1) {
+                expected.push((aabbs[i].0, aabbs[j].0));
+            }
+        }
+    }
+
+    let mut result: Vec<_> = query::sweep_and_prune(&aabbs)
+        .into_iter()
+        .map(|(a, b)| (a.min(b), a.max(b)))
+        .collect();
+    result.sort();
+    expected.sort();
+
+    assert!(!expected.is_empty());
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,144 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::mass_properties::MassProperties;
+use parry3d::query::gjk::VoronoiSimplex;
+use parry3d::query::{self, details, PointQuery, Ray, RayCast};
+use parry3d::shape::{Ball, Shape, SupportMap, TaperedCapsule};
+use parry3d::transformation;
+
+#[test]
+fn tapered_capsule_mass_properties() {
+    let shapes = [
+        TaperedCapsule::new(Point3::origin(), Point3::new(0.0, 1.0, 0.0), 0.5, 0.5),
+        TaperedCapsule::new(
+            Point3::new(0.1, -0.2, 0.3),
+            Point3::new(1.0, 0.5, -0.4),
+            0.6,
+            0.2,
+        ),
+        TaperedCapsule::new(Point3::origin(), Point3::new(0.0, 0.0, -1.0), 0.1, 0.7),
+    ];
+
+    for shape in &shapes {
+        let mprops = shape.mass_properties(2.0);
+        let voxelized = transformation::mass_properties_by_voxelization(shape, 2.0, 0.03);
+
+        assert_relative_eq!(
+            1.0 / mprops.inv_mass,
+            1.0 / voxelized.inv_mass,
+            max_relative = 1.0e-2
+        );
+        assert_relative_eq!(mprops.local_com, voxelized.local_com, epsilon = 1.0e-2);
+        assert_relative_eq!(
+            mprops.reconstruct_inertia_matrix(),
+            voxelized.reconstruct_inertia_matrix(),
+            max_relative = 3.0e-2,
+            epsilon = 1.0e-2
+        );
+    }
+
+    // One ball contains the other.
+    let shape = TaperedCapsule::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0), 0.2, 1.5);
+    let mut ball = MassProperties::from_ball(2.0, 1.5);
+    ball.local_com = Point3::new(1.0, 0.0, 0.0);
+    assert_relative_eq!(shape.mass_properties(2.0), ball);
+}
+
+#[test]
+fn tapered_capsule_aabb_is_tight() {
+    let shape = TaperedCapsule::new(
+        Point3::new(0.1, -0.2, 0.3),
+        Point3::new(1.0, 0.5, -0.4),
+        0.6,
+        0.2,
+    );
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.5, -0.3, 1.1));
+    let aabb = shape.compute_aabb(&pos);
+
+    for i in 0..3 {
+        let mut dir = Vector3::zeros();
+        dir[i] = 1.0;
+        assert_relative_eq!(
+            aabb.maxs[i],
+            shape.support_point(&pos, &dir)[i],
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(
+            aabb.mins[i],
+            shape.support_point(&pos, &-dir)[i],
+            epsilon = 1.0e-5
+        );
+    }
+
+    let sphere = shape.compute_bounding_sphere(&pos);
+    assert!(aabb.bounding_sphere().radius() >= sphere.radius());
+}
+
+#[test]
+fn tapered_capsule_queries() {
+    let shape = TaperedCapsule::new(Point3::origin(), Point3::new(2.0, 0.0, 0.0), 1.0, 0.5);
+
+    // The lateral surface touches the balls at the points with normals
+    // making an angle `acos(0.25)` with the x axis.
+    let ray = Ray::new(Point3::new(1.0, 5.0, 0.0), -Vector3::y());
+    let hit = shape
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    let sqrt15 = 15.0f32.sqrt();
+    assert_relative_eq!(hit.toi, 5.0 - sqrt15 / 5.0, epsilon = 1.0e-4);
+    assert_relative_eq!(
+        hit.normal,
+        Vector3::new(0.25, sqrt15 / 4.0, 0.0),
+        epsilon = 1.0e-4
+    );
+
+    let ball = Ball::new(0.5);
+    let pos_ball = Isometry3::translation(-3.0, 0.0, 0.0);
+    let dist = query::distance(&Isometry3::identity(), &shape, &pos_ball, &ball).unwrap();
+    assert_relative_eq!(dist, 1.5, epsilon = 1.0e-5);
+
+    let pos_ball = Isometry3::translation(4.0, 0.0, 0.0);
+    let dist = query::distance(&Isometry3::identity(), &shape, &pos_ball, &ball).unwrap();
+    assert_relative_eq!(dist, 1.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn tapered_capsule_point_projection() {
+    let shape = TaperedCapsule::new(
+        Point3::new(0.1, -0.2, 0.3),
+        Point3::new(1.0, 0.5, -0.4),
+        0.6,
+        0.2,
+    );
+
+    for i in 0..10 {
+        for j in 0..10 {
+            for k in 0..10 {
+                let pt = Point3::new(i as f32, j as f32, k as f32) * 0.4 - Vector3::repeat(1.5);
+                let proj = shape.project_local_point(&pt, true);
+                let expected = details::local_point_projection_on_support_map(
+                    &shape,
+                    &mut VoronoiSimplex::new(),
+                    &pt,
+                    true,
+                );
+
+                let dist = na::distance(&proj.point, &pt);
+                let expected_dist = na::distance(&expected.point, &pt);
+
+                assert_eq!(proj.is_inside, expected.is_inside);
+                // GJK is only approximate on curved surfaces.
+                assert!(dist <= expected_dist + 1.0e-5);
+                if !proj.is_inside {
+                    // `proj` is the support point along the direction to `pt`.
+                    let dir = (pt - proj.point) / dist;
+                    let support = shape.local_support_point(&dir);
+                    assert_relative_eq!(
+                        dir.dot(&proj.point.coords),
+                        dir.dot(&support.coords),
+                        epsilon = 1.0e-5
+                    );
+                }
+            }
+        }
+    }
+}
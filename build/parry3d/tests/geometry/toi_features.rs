@@ -0,0 +1,72 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, TOIStatus};
+use parry3d::shape::{Ball, Cuboid, FeatureId, TriMesh};
+
+#[test]
+fn toi_features_cuboid_cuboid() {
+    let ground = Cuboid::new(Vector3::new(5.0, 0.5, 5.0));
+    let cuboid = Cuboid::new(Vector3::repeat(0.5));
+    let pos1 = Isometry3::translation(0.2, 3.0, 0.1);
+    let pos2 = Isometry3::identity();
+
+    let toi = query::time_of_impact(
+        &pos1,
+        &Vector3::new(0.0, -1.0, 0.0),
+        &cuboid,
+        &pos2,
+        &Vector3::zeros(),
+        &ground,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, 2.0, epsilon = 1.0e-4);
+    assert_relative_eq!(toi.normal1.into_inner(), -Vector3::y(), epsilon = 1.0e-4);
+    assert_relative_eq!(toi.normal2.into_inner(), Vector3::y(), epsilon = 1.0e-4);
+    assert_relative_eq!(toi.witness1.y, -0.5, epsilon = 1.0e-4);
+    assert_relative_eq!(toi.witness2.y, 0.5, epsilon = 1.0e-4);
+    assert!(matches!(toi.feature1, FeatureId::Face(_)));
+    assert!(matches!(toi.feature2, FeatureId::Face(_)));
+
+    // The features are swapped with the shapes.
+    let swapped = toi.swapped();
+    assert_eq!(swapped.feature1, toi.feature2);
+    assert_eq!(swapped.feature2, toi.feature1);
+}
+
+#[test]
+fn toi_features_ball_on_ramp() {
+    // A ramp made of two triangles, with a normal pointing toward (-1, 1, 0).
+    let vertices = vec![
+        Point3::new(-2.0, -2.0, -2.0),
+        Point3::new(2.0, 2.0, -2.0),
+        Point3::new(2.0, 2.0, 2.0),
+        Point3::new(-2.0, -2.0, 2.0),
+    ];
+    let ramp = TriMesh::new(vertices, vec![[0, 2, 1], [0, 3, 2]]);
+    let ball = Ball::new(0.25);
+
+    let toi = query::time_of_impact(
+        &Isometry3::identity(),
+        &Vector3::zeros(),
+        &ramp,
+        &Isometry3::translation(-1.0, 3.0, 1.0),
+        &Vector3::new(0.0, -1.0, 0.0),
+        &ball,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+
+    // The hit normal can be used to slide along the ramp.
+    let expected_normal = Vector3::new(-1.0, 1.0, 0.0).normalize();
+    assert_relative_eq!(toi.normal1.into_inner(), expected_normal, epsilon = 1.0e-3);
+    assert_relative_eq!(toi.normal2.into_inner(), -expected_normal, epsilon = 1.0e-3);
+    assert_relative_eq!(toi.witness1.x, toi.witness1.y, epsilon = 1.0e-3);
+    assert_eq!(toi.feature1, FeatureId::Face(1));
+    assert_eq!(toi.feature2, FeatureId::Face(0));
+}
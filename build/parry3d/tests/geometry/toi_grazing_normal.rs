@@ -0,0 +1,76 @@
+use na::{self, Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{self, TOIStatus};
+use parry3d::shape::{Ball, Cuboid};
+
+fn sweep_ball_past_corner(
+    offset: Real,
+    vel: Vector3<Real>,
+    target_distance: Real,
+) -> (Vector3<Real>, Vector3<Real>) {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    let ball = Ball::new(0.5);
+    let corner = Point3::new(1.0, 1.0, 1.0);
+    let start = corner + Vector3::new(0.0, 1.0, 1.0).normalize() * offset;
+    let pos2 = Isometry3::translation(start.x, start.y, start.z);
+
+    let toi = query::time_of_impact(
+        &Isometry3::identity(),
+        &Vector3::zeros(),
+        &cuboid,
+        &pos2,
+        &vel,
+        &ball,
+        10.0,
+        target_distance,
+    )
+    .unwrap()
+    .expect("The ball should hit the corner.");
+
+    assert!(toi.normal1.iter().all(|e| e.is_finite()));
+    assert!(toi.normal2.iter().all(|e| e.is_finite()));
+    let center = start + vel * toi.toi;
+    (center - corner, *toi.normal1)
+}
+
+#[test]
+fn toi_normal_at_grazing_corner_contact() {
+    for target_distance in [0.0, 0.01].iter() {
+        let offset = 0.5 + target_distance - 1.0e-5;
+        let vel = Vector3::new(-1.0, -0.01, 0.0);
+        let (corner_to_center, normal) = sweep_ball_past_corner(offset, vel, *target_distance);
+        assert!(normal.dot(&corner_to_center.normalize()) > 0.99);
+    }
+}
+
+#[test]
+fn toi_normal_of_ball_touching_corner_points_away_from_it() {
+    // The ball is already touching the cuboid corner and slides along one of its edges.
+    let vel = -Vector3::x();
+
+    for offset in [0.5, 0.5 - 1.0e-5, 0.5 - 1.0e-3].iter() {
+        let (corner_to_center, normal) = sweep_ball_past_corner(*offset, vel, 0.0);
+        assert!(normal.dot(&corner_to_center) > 0.0);
+        // Previously, the normal was the opposite of the velocity, i.e., parallel to the edge.
+        assert!(normal.dot(&vel).abs() < 1.0e-2);
+    }
+
+    let toi = query::time_of_impact(
+        &Isometry3::identity(),
+        &Vector3::zeros(),
+        &Cuboid::new(Vector3::repeat(1.0)),
+        &Isometry3::translation(1.0, 1.2, 1.2),
+        &vel,
+        &Ball::new(0.5),
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+    assert_relative_eq!(
+        *toi.normal1,
+        Vector3::new(0.0, 1.0, 1.0).normalize(),
+        epsilon = 1.0e-2
+    );
+}
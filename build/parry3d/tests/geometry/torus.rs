@@ -0,0 +1,130 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{Shape, Torus};
+use parry3d::transformation;
+
+#[test]
+fn torus_ray_cast() {
+    let torus = Torus::new(2.0, 0.5);
+
+    // Through the tube, from outside.
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let hit = torus
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.5, epsilon = 1.0e-4);
+    assert_relative_eq!(hit.normal, -Vector3::x(), epsilon = 1.0e-4);
+
+    // Through the hole.
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    assert!(torus.cast_local_ray(&ray, f32::MAX, true).is_none());
+
+    // Down onto the top of the tube.
+    let ray = Ray::new(Point3::new(0.0, 5.0, 2.0), -Vector3::y() * 2.0);
+    let hit = torus
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.25, epsilon = 1.0e-4);
+    assert_relative_eq!(hit.normal, Vector3::y(), epsilon = 1.0e-4);
+
+    // From inside of the tube.
+    let ray = Ray::new(Point3::new(2.0, 0.0, 0.0), Vector3::x());
+    assert_eq!(torus.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+    let hit = torus
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5, epsilon = 1.0e-4);
+    assert_relative_eq!(hit.normal, -Vector3::x(), epsilon = 1.0e-4);
+
+    // Stopped by `max_toi`.
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    assert!(torus.cast_local_ray(&ray, 2.0, true).is_none());
+}
+
+#[test]
+fn torus_ray_cast_hits_the_surface_first() {
+    let torus = Torus::new(1.5, 0.4);
+    let pos = Isometry3::new(Vector3::new(0.3, -0.2, 0.1), Vector3::new(0.4, 0.9, -0.2));
+    let mut num_hits = 0;
+
+    for i in 0..20 {
+        for j in 0..20 {
+            let origin = Point3::new(-4.0, i as f32 * 0.2 - 2.0, j as f32 * 0.2 - 2.0);
+            let dir = Vector3::new(1.0, 0.1 * (j as f32 - 10.0) / 10.0, 0.05);
+            let ray = Ray::new(pos * origin, pos * dir);
+
+            if let Some(toi) = torus.cast_ray(&pos, &ray, f32::MAX, true) {
+                num_hits += 1;
+                let hit = ray.point_at(toi);
+                assert_relative_eq!(
+                    torus.distance_to_point(&pos, &hit, false),
+                    0.0,
+                    epsilon = 1.0e-4
+                );
+
+                // All the points before the hit are outside of the torus.
+                for k in 0..50 {
+                    let pt = ray.point_at(toi * k as f32 / 50.0);
+                    assert!(torus.distance_to_point(&pos, &pt, false) > -1.0e-4);
+                }
+            }
+        }
+    }
+
+    assert!(num_hits > 0);
+}
+
+#[test]
+fn torus_point_projection() {
+    let torus = Torus::new(2.0, 0.5);
+
+    let proj = torus.project_local_point(&Point3::new(0.0, 0.0, 4.0), false);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.0, 0.0, 2.5), epsilon = 1.0e-6);
+
+    // The center of the torus is in its hole.
+    let proj = torus.project_local_point(&Point3::origin(), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point.coords.norm(), 1.5, epsilon = 1.0e-6);
+
+    let pt = Point3::new(2.2, 0.1, 0.0);
+    assert!(torus.contains_local_point(&pt));
+    assert_eq!(torus.distance_to_local_point(&pt, true), 0.0);
+    assert_relative_eq!(
+        torus.distance_to_local_point(&pt, false),
+        -0.5 + 0.05f32.sqrt(),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn torus_mass_properties() {
+    let torus = Torus::new(1.0, 0.4);
+    let mprops = torus.mass_properties(2.0);
+    let voxelized = transformation::mass_properties_by_voxelization(&torus, 2.0, 0.025);
+
+    assert_relative_eq!(
+        1.0 / mprops.inv_mass,
+        1.0 / voxelized.inv_mass,
+        max_relative = 1.0e-2
+    );
+    assert_relative_eq!(
+        mprops.reconstruct_inertia_matrix(),
+        voxelized.reconstruct_inertia_matrix(),
+        max_relative = 1.0e-2,
+        epsilon = 1.0e-3
+    );
+}
+
+#[test]
+fn torus_aabb_contains_rotated_torus() {
+    let torus = Torus::new(1.0, 0.3);
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.5, -0.3, 1.1));
+    let aabb = torus.compute_aabb(&pos);
+
+    for i in 0..64 {
+        let angle = i as f32 / 64.0 * std::f32::consts::PI * 2.0;
+        let pt = pos * Point3::new(angle.cos() * 1.3, 0.0, angle.sin() * 1.3);
+        assert!(aabb.contains_local_point(&pt));
+    }
+}
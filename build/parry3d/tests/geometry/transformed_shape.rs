@@ -0,0 +1,147 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{
+    self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery,
+    QueryDispatcher, Ray, RayCast,
+};
+use parry3d::shape::{Ball, Compound, Cuboid, Shape, ShapeType, SharedShape, TransformedShape};
+
+fn offset_box() -> (Isometry3<Real>, Cuboid) {
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, -0.5), Vector3::new(0.3, -0.2, 0.5));
+    (pos, Cuboid::new(Vector3::new(1.0, 0.5, 0.25)))
+}
+
+#[test]
+fn transformed_shape_matches_compound_of_one() {
+    let (pos, cuboid) = offset_box();
+    let transformed = TransformedShape::new(pos, cuboid);
+    let compound = Compound::new(vec![(pos, SharedShape::new(cuboid))]);
+
+    assert_eq!(transformed.shape_type(), ShapeType::TransformedShape);
+    let aabb = transformed.compute_local_aabb();
+    let expected = compound.compute_local_aabb();
+    assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-5);
+    let props = transformed.mass_properties(2.0);
+    let rot = pos.rotation.to_rotation_matrix();
+    let inertia = cuboid.mass_properties(2.0).reconstruct_inertia_matrix();
+    assert_relative_eq!(props.local_com, pos * Point3::origin(), epsilon = 1.0e-5);
+    assert_relative_eq!(props.inv_mass, 0.5, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        props.reconstruct_inertia_matrix(),
+        rot * inertia * rot.transpose(),
+        epsilon = 1.0e-4
+    );
+
+    for dir in &[
+        Vector3::new(1.0, 0.3, -0.2),
+        Vector3::new(-0.4, 1.0, 0.7),
+        Vector3::new(0.1, -0.5, -1.0),
+    ] {
+        let ray = Ray::new(pos * Point3::origin() + dir * 5.0, -dir);
+        let hit = transformed
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        let expected = compound
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.toi, expected.toi, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, expected.normal, epsilon = 1.0e-5);
+
+        let pt = ray.origin;
+        let proj = transformed.project_local_point(&pt, true);
+        let expected = compound.project_local_point(&pt, true);
+        assert_eq!(proj.is_inside, expected.is_inside);
+        assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn transformed_shape_pairwise_queries() {
+    let (pos, cuboid) = offset_box();
+    let transformed = TransformedShape::new(pos, cuboid);
+    let compound = Compound::new(vec![(pos, SharedShape::new(cuboid))]);
+    let ball = Ball::new(0.5);
+    let pos1 = Isometry3::new(Vector3::new(0.5, -1.0, 0.2), Vector3::new(0.0, 0.4, 0.1));
+    let pos2 = Isometry3::translation(3.0, 1.5, 0.5);
+
+    let dist = query::distance(&pos1, &transformed, &pos2, &ball).unwrap();
+    let expected = query::distance(&pos1, &compound, &pos2, &ball).unwrap();
+    assert_relative_eq!(dist, expected, epsilon = 1.0e-5);
+
+    // With the transformed shape as the second shape too.
+    let dist = query::distance(&pos2, &ball, &pos1, &transformed).unwrap();
+    assert_relative_eq!(dist, expected, epsilon = 1.0e-5);
+
+    let contact = query::contact(&pos1, &transformed, &pos2, &ball, 10.0)
+        .unwrap()
+        .unwrap();
+    let expected = query::contact(&pos1, &compound, &pos2, &ball, 10.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.point1, expected.point1, epsilon = 1.0e-4);
+    assert_relative_eq!(contact.point2, expected.point2, epsilon = 1.0e-4);
+    assert_relative_eq!(contact.normal1, expected.normal1, epsilon = 1.0e-4);
+
+    let flipped = query::contact(&pos2, &ball, &pos1, &transformed, 10.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(flipped.point2, expected.point1, epsilon = 1.0e-4);
+    assert_relative_eq!(flipped.normal2, expected.normal1, epsilon = 1.0e-4);
+
+    // The ball moves toward the center of the box.
+    let pos12 = pos1.inv_mul(&pos2);
+    let vel12 = pos * Point3::origin() - Point3::from(pos12.translation.vector);
+    let toi = DefaultQueryDispatcher
+        .time_of_impact(&pos12, &vel12, &transformed, &ball, Real::MAX, 0.0)
+        .unwrap()
+        .unwrap();
+    let pos12_at_toi = Isometry3::from_parts(
+        (pos12.translation.vector + vel12 * toi.toi).into(),
+        pos12.rotation,
+    );
+    let dist = DefaultQueryDispatcher
+        .distance(&pos12_at_toi, &transformed, &ball)
+        .unwrap();
+    assert!(toi.toi > 0.0 && toi.toi < 1.0);
+    assert_relative_eq!(dist, 0.0, epsilon = 1.0e-4);
+    assert_relative_eq!(
+        transformed.distance_to_local_point(&toi.witness1, false),
+        0.0,
+        epsilon = 1.0e-4
+    );
+    assert_relative_eq!(pos12_at_toi * toi.witness2, toi.witness1, epsilon = 1.0e-4);
+}
+
+#[test]
+fn transformed_shape_contact_manifolds() {
+    let (pos, cuboid) = offset_box();
+    let transformed = TransformedShape::new(pos, cuboid);
+    let ball = Ball::new(0.5);
+    // The ball is slightly penetrating the top face of the box.
+    let pos12 = pos * Isometry3::translation(0.2, 0.95, 0.0);
+
+    let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+    let mut workspace = None;
+    DefaultQueryDispatcher
+        .contact_manifolds(
+            &pos12,
+            &transformed,
+            &ball,
+            0.1,
+            &mut manifolds,
+            &mut workspace,
+        )
+        .unwrap();
+
+    assert_eq!(manifolds.len(), 1);
+    let manifold = &manifolds[0];
+    assert_eq!(manifold.subshape_pos1, Some(pos));
+    assert_eq!(manifold.points.len(), 1);
+
+    // The contact is expressed in the local-space of the cuboid.
+    let pt = &manifold.points[0];
+    assert_relative_eq!(pt.local_p1, Point3::new(0.2, 0.5, 0.0), epsilon = 1.0e-5);
+    assert_relative_eq!(pt.dist, -0.05, epsilon = 1.0e-5);
+    assert_relative_eq!(manifold.local_n1, Vector3::y(), epsilon = 1.0e-5);
+}
@@ -0,0 +1,27 @@
+use na::Point3;
+use parry3d::transformation::trimesh_from_triangle_soup;
+
+#[test]
+fn triangle_soup_quad_is_welded() {
+    let a = Point3::new(0.0, 0.0, 0.0);
+    let b = Point3::new(1.0, 0.0, 0.0);
+    let c = Point3::new(1.0, 0.0, 1.0);
+    let d = Point3::new(0.0, 0.0, 1.0);
+    // The second triangle's copy of the shared edge is slightly off.
+    let c2 = c + na::Vector3::repeat(1.0e-5);
+    let soup = [[a, b, c], [a, c2, d]];
+
+    let mesh = trimesh_from_triangle_soup(&soup, 1.0e-3);
+    assert_eq!(mesh.vertices().len(), 4);
+    assert_eq!(mesh.indices(), &[[0, 1, 2], [0, 2, 3]]);
+
+    let unwelded = trimesh_from_triangle_soup(&soup, 0.0);
+    assert_eq!(unwelded.vertices().len(), 5);
+}
+
+#[test]
+fn empty_triangle_soup() {
+    let mesh = trimesh_from_triangle_soup(&[], 1.0e-3);
+    assert!(mesh.vertices().is_empty());
+    assert!(mesh.indices().is_empty());
+}
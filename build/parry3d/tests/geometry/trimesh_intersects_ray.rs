@@ -0,0 +1,61 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{Ray, RayCast};
+use parry3d::shape::TriMesh;
+
+// A 10x10 grid of squares on the plane `y = 0`, each split into two triangles.
+fn floor() -> TriMesh {
+    let n = 10;
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for i in 0..=n {
+        for j in 0..=n {
+            vertices.push(Point3::new(i as f32, 0.0, j as f32));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            let b = a + n + 1;
+            indices.push([a, a + 1, b]);
+            indices.push([b, a + 1, b + 1]);
+        }
+    }
+
+    TriMesh::new(vertices, indices)
+}
+
+#[test]
+fn trimesh_intersects_ray() {
+    let mesh = floor();
+    let pos = Isometry3::translation(0.0, -1.0, 0.0);
+
+    // A grazing ray crossing many triangles.
+    let grazing = Ray::new(
+        Point3::new(-1.0, -0.75, -1.0),
+        Vector3::new(1.0, -0.05, 1.0),
+    );
+    assert!(mesh.intersects_ray(&pos, &grazing, f32::MAX));
+
+    let down = Ray::new(Point3::new(5.5, 1.0, 5.5), -Vector3::y());
+    assert!(mesh.intersects_ray(&pos, &down, 2.5));
+    assert!(!mesh.intersects_ray(&pos, &down, 1.5));
+
+    let up = Ray::new(Point3::new(5.5, 1.0, 5.5), Vector3::y());
+    assert!(!mesh.intersects_ray(&pos, &up, f32::MAX));
+
+    let outside = Ray::new(Point3::new(12.0, 1.0, 5.5), -Vector3::y());
+    assert!(!mesh.intersects_ray(&pos, &outside, f32::MAX));
+
+    // Must agree with the full ray cast.
+    for i in 0..100 {
+        let x = (i % 10) as f32 * 1.3 - 1.0;
+        let z = (i / 10) as f32 * 1.3 - 1.0;
+        let ray = Ray::new(Point3::new(x, 1.0, z), Vector3::new(0.3, -1.0, 0.2));
+        assert_eq!(
+            mesh.intersects_ray(&pos, &ray, f32::MAX),
+            mesh.cast_ray(&pos, &ray, f32::MAX, true).is_some()
+        );
+    }
+}
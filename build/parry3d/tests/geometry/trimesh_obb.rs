@@ -0,0 +1,23 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::shape::{Cuboid, TriMesh};
+
+#[test]
+fn trimesh_obb_is_tighter_than_aabb() {
+    let cuboid = Cuboid::new(Vector3::new(5.0, 0.2, 0.3));
+    let (vertices, indices) = cuboid.to_trimesh();
+    let pos = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 0.6, 0.9));
+    let vertices: Vec<Point3<f32>> = vertices.iter().map(|pt| pos * pt).collect();
+    let mesh = TriMesh::new(vertices, indices);
+
+    let (obb_pos, obb) = mesh.compute_obb();
+    let obb_volume = obb.half_extents.iter().product::<f32>() * 8.0;
+    let aabb_volume = mesh.local_aabb().extents().iter().product::<f32>();
+    assert!(obb_volume * 10.0 < aabb_volume);
+    assert_relative_eq!(obb_volume, 5.0 * 0.2 * 0.3 * 8.0, epsilon = 1.0e-3);
+
+    // All the vertices are contained in the OBB.
+    for pt in mesh.vertices() {
+        let local = obb_pos.inverse_transform_point(pt);
+        assert!(local.coords.abs() <= obb.half_extents.add_scalar(1.0e-4));
+    }
+}
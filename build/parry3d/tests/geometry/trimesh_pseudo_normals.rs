@@ -0,0 +1,120 @@
+use na::{Point3, Vector3};
+use parry3d::query::PointQuery;
+use parry3d::shape::{Ball, Cuboid, Shape, TriMesh};
+
+fn sample_points() -> Vec<Point3<f32>> {
+    let mut points = vec![];
+    let values = [-1.7, -1.2, -0.99, -0.5, 0.0, 0.3, 0.98, 1.01, 1.5];
+
+    for x in &values {
+        for y in &values {
+            for z in &values {
+                points.push(Point3::new(*x, *y, *z));
+            }
+        }
+    }
+
+    points
+}
+
+#[test]
+fn trimesh_pseudo_normals_classify_points_like_a_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let mut mesh = TriMesh::from(cuboid);
+    assert!(mesh.pseudo_normals().is_none());
+    mesh.compute_pseudo_normals();
+
+    let pseudo_normals = mesh.pseudo_normals().unwrap();
+    assert_eq!(pseudo_normals.vertices_pseudo_normal.len(), 8);
+    for (pt, n) in mesh
+        .vertices()
+        .iter()
+        .zip(pseudo_normals.vertices_pseudo_normal.iter())
+    {
+        assert_relative_eq!(*n, pt.coords.normalize(), epsilon = 1.0e-5);
+    }
+
+    for pt in sample_points() {
+        let expected = cuboid.contains_local_point(&pt);
+        assert_eq!(mesh.contains_local_point(&pt), expected, "{:?}", pt);
+
+        let proj = mesh.project_local_point(&pt, false);
+        assert_eq!(proj.is_inside, expected, "{:?}", pt);
+        assert_relative_eq!(
+            mesh.distance_to_local_point(&pt, false),
+            cuboid.distance_to_local_point(&pt, false),
+            epsilon = 1.0e-5
+        );
+
+        let solid_proj = mesh.project_local_point(&pt, true);
+        if expected {
+            assert_eq!(solid_proj.point, pt);
+            assert_eq!(mesh.distance_to_local_point(&pt, true), 0.0);
+        } else {
+            assert_relative_eq!(solid_proj.point, proj.point);
+        }
+    }
+}
+
+#[test]
+fn trimesh_pseudo_normals_on_a_tessellated_sphere() {
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(20, 10);
+    let mut mesh = TriMesh::new(vertices, indices);
+    mesh.compute_pseudo_normals();
+
+    for pt in sample_points() {
+        let norm = pt.coords.norm();
+        // Skip the points too close to the tessellated surface.
+        if norm < 0.9 {
+            assert!(mesh.contains_local_point(&pt), "{:?}", pt);
+        } else if norm > 1.01 {
+            assert!(!mesh.contains_local_point(&pt), "{:?}", pt);
+        }
+    }
+}
+
+#[test]
+fn trimesh_pseudo_normals_are_kept_up_to_date() {
+    let mut mesh = TriMesh::from(Cuboid::new(Vector3::new(1.0, 2.0, 3.0)));
+    mesh.compute_pseudo_normals();
+
+    let scaled = mesh.scaled(&Vector3::new(3.0, 1.0, 0.5), 10).unwrap();
+    let mirrored = mesh.mirrored(2).unwrap();
+    let pt_in = Point3::new(2.9, 1.9, 1.4);
+    let pt_out = Point3::new(0.9, 1.9, 1.6);
+
+    for shape in &[scaled, mirrored] {
+        let trimesh = shape.as_trimesh().unwrap();
+        assert!(trimesh.pseudo_normals().is_some());
+        let cuboid = Cuboid::new(trimesh.local_aabb().half_extents());
+        assert_eq!(
+            trimesh.contains_local_point(&pt_in),
+            cuboid.contains_local_point(&pt_in)
+        );
+        assert_eq!(
+            trimesh.contains_local_point(&pt_out),
+            cuboid.contains_local_point(&pt_out)
+        );
+    }
+}
+
+#[test]
+fn trimesh_signed_distance_matches_the_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let mut mesh = TriMesh::from(cuboid);
+    assert!(mesh
+        .signed_distance_to_local_point(&Point3::origin())
+        .is_none());
+    mesh.compute_pseudo_normals();
+
+    for pt in sample_points() {
+        let proj = cuboid.project_local_point(&pt, false);
+        let dist = na::distance(&pt, &proj.point);
+        let expected = if proj.is_inside { -dist } else { dist };
+        assert_relative_eq!(
+            mesh.signed_distance_to_local_point(&pt).unwrap(),
+            expected,
+            epsilon = 1.0e-5
+        );
+    }
+}
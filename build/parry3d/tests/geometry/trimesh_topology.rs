@@ -0,0 +1,107 @@
+use na::{Point3, Vector3};
+use parry3d::shape::{Cuboid, Shape, TriMesh};
+
+// A 2x2 grid of quads, each split into two triangles, in the plane y = 0.
+fn grid() -> TriMesh {
+    let mut vertices = vec![];
+    for i in 0..3 {
+        for j in 0..3 {
+            vertices.push(Point3::new(i as f32, 0.0, j as f32));
+        }
+    }
+
+    let mut indices = vec![];
+    for i in 0..2 {
+        for j in 0..2 {
+            let a = i * 3 + j;
+            let (b, c, d) = (a + 3, a + 4, a + 1);
+            indices.push([a, d, c]);
+            indices.push([a, c, b]);
+        }
+    }
+
+    TriMesh::new(vertices, indices)
+}
+
+#[test]
+fn trimesh_topology_of_a_grid() {
+    let mut mesh = grid();
+    assert!(mesh.topology().is_none());
+    assert!(mesh.triangle_neighbors(0).is_none());
+    assert!(mesh.compute_topology());
+
+    // The triangle [0, 1, 4] is adjacent to [0, 4, 3] through its edge (4, 0), and to
+    // the triangle [1, 5, 4] of the next quad through its edge (1, 4).
+    let neighbors = mesh.triangle_neighbors(0).unwrap();
+    assert_eq!(neighbors, [None, Some(3), Some(1)]);
+
+    let topology = mesh.topology().unwrap();
+    let mut center_faces = topology.vertex_faces(4);
+    center_faces.sort_unstable();
+    assert_eq!(center_faces, vec![0, 1, 3, 4, 6, 7]);
+
+    // A corner vertex on the boundary.
+    let mut corner_faces = topology.vertex_faces(2);
+    corner_faces.sort_unstable();
+    assert_eq!(corner_faces, vec![2]);
+    let mut border_faces = topology.vertex_faces(1);
+    border_faces.sort_unstable();
+    assert_eq!(border_faces, vec![0, 2, 3]);
+
+    assert_eq!(topology.edge_faces(0, 4), [Some(1), Some(0)]);
+    assert_eq!(topology.edge_faces(0, 1), [Some(0), None]);
+    assert_eq!(topology.edge_faces(0, 8), [None, None]);
+
+    let he = topology.half_edge_between(4, 0).unwrap();
+    assert_eq!(topology.half_edge_end(he), 0);
+}
+
+#[test]
+fn trimesh_topology_of_a_closed_mesh() {
+    let mut mesh = TriMesh::from(Cuboid::new(Vector3::new(1.0, 2.0, 3.0)));
+    assert!(mesh.compute_topology());
+
+    for i in 0..mesh.num_triangles() as u32 {
+        let neighbors = mesh.triangle_neighbors(i).unwrap();
+        assert!(neighbors.iter().all(|n| n.is_some()));
+    }
+
+    let topology = mesh.topology().unwrap();
+    for v in 0..mesh.vertices().len() as u32 {
+        let faces = topology.vertex_faces(v);
+        assert!(faces.len() >= 3);
+        for face in faces {
+            assert!(mesh.indices()[face as usize].contains(&v));
+        }
+    }
+
+    // The topology is kept consistent by the mirror and scale operations.
+    for shape in &[
+        mesh.mirrored(0).unwrap(),
+        mesh.scaled(&Vector3::new(1.0, 2.0, 3.0), 10).unwrap(),
+    ] {
+        let trimesh = shape.as_trimesh().unwrap();
+        let mut expected = trimesh.clone();
+        assert!(expected.compute_topology());
+        for i in 0..trimesh.num_triangles() as u32 {
+            assert_eq!(
+                trimesh.triangle_neighbors(i),
+                expected.triangle_neighbors(i)
+            );
+        }
+    }
+}
+
+#[test]
+fn trimesh_topology_of_a_non_manifold_mesh() {
+    let vertices = vec![
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    // Both triangles contain the directed edge (0, 1).
+    let mut mesh = TriMesh::new(vertices, vec![[0, 1, 2], [0, 1, 3]]);
+    assert!(!mesh.compute_topology());
+    assert!(mesh.topology().is_none());
+}
@@ -0,0 +1,133 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{Ball, TriMesh};
+
+fn ball_trimesh() -> TriMesh {
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(20, 20);
+    let num_triangles = indices.len() as u32;
+    let mut trimesh =
+        TriMesh::new(vertices, indices).with_triangle_data((0..num_triangles).collect());
+    trimesh.compute_pseudo_normals();
+    trimesh
+}
+
+fn assert_same_queries(trimesh: &TriMesh, expected: &TriMesh) {
+    assert_relative_eq!(trimesh.local_aabb().mins, expected.local_aabb().mins);
+    assert_relative_eq!(trimesh.local_aabb().maxs, expected.local_aabb().maxs);
+
+    let rays = [
+        Ray::new(Point3::new(0.2, 10.0, 0.3), -Vector3::y()),
+        Ray::new(Point3::new(-10.0, 1.2, 2.0), Vector3::new(1.0, 0.1, -0.2)),
+        Ray::new(Point3::new(3.0, -4.0, 1.5), Vector3::new(-0.1, 1.0, 0.05)),
+    ];
+
+    for ray in rays.iter() {
+        let hit = trimesh.cast_local_ray_and_get_normal(ray, Real::MAX, true);
+        let expected_hit = expected.cast_local_ray_and_get_normal(ray, Real::MAX, true);
+        assert_eq!(hit.is_some(), expected_hit.is_some());
+
+        if let (Some(hit), Some(expected_hit)) = (hit, expected_hit) {
+            assert_relative_eq!(hit.toi, expected_hit.toi, epsilon = 1.0e-5);
+            assert_relative_eq!(hit.normal, expected_hit.normal, epsilon = 1.0e-5);
+            assert_eq!(hit.feature, expected_hit.feature);
+        }
+    }
+
+    let points = [
+        Point3::new(0.3, 2.0, 1.0),
+        Point3::new(2.0, 1.4, 0.1),
+        Point3::new(-2.5, 0.5, 4.0),
+    ];
+
+    for pt in points.iter() {
+        let proj = trimesh.project_local_point(pt, true);
+        let expected_proj = expected.project_local_point(pt, true);
+        assert_eq!(proj.is_inside, expected_proj.is_inside);
+        assert_relative_eq!(proj.point, expected_proj.point, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn trimesh_transform_vertices_matches_rebuilt_mesh() {
+    let mut trimesh = ball_trimesh();
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.2, 1.1));
+    trimesh.transform_vertices(&pos);
+
+    let vertices = ball_trimesh()
+        .vertices()
+        .iter()
+        .map(|pt| pos * pt)
+        .collect();
+    let mut expected = TriMesh::new(vertices, ball_trimesh().indices().to_vec());
+    expected.compute_pseudo_normals();
+
+    assert_same_queries(&trimesh, &expected);
+    assert_eq!(trimesh.triangle_data(42), Some(42));
+
+    // The rotated pseudo-normals match the ones of the transformed mesh.
+    let pseudo_normals = trimesh.pseudo_normals().unwrap();
+    let expected_pseudo_normals = expected.pseudo_normals().unwrap();
+
+    for (n1, n2) in pseudo_normals
+        .vertices_pseudo_normal
+        .iter()
+        .zip(expected_pseudo_normals.vertices_pseudo_normal.iter())
+    {
+        assert_relative_eq!(n1, n2, epsilon = 1.0e-5);
+    }
+
+    assert!(trimesh.contains_local_point(&Point3::new(1.0, 2.0, 3.0)));
+    assert!(!trimesh.contains_local_point(&Point3::origin()));
+}
+
+#[test]
+fn trimesh_scale_matches_rebuilt_mesh() {
+    let scale = Vector3::new(2.0, 0.5, 3.0);
+    let mut trimesh = ball_trimesh();
+    trimesh.scale(&scale);
+
+    let vertices = ball_trimesh()
+        .vertices()
+        .iter()
+        .map(|pt| Point3::from(pt.coords.component_mul(&scale)))
+        .collect();
+    let mut expected = TriMesh::new(vertices, ball_trimesh().indices().to_vec());
+    expected.compute_pseudo_normals();
+
+    assert_same_queries(&trimesh, &expected);
+    assert_eq!(trimesh.triangle_data(42), Some(42));
+
+    // Shrinking the mesh also shrinks its AABB.
+    trimesh.scale(&Vector3::repeat(0.1));
+    assert_relative_eq!(trimesh.local_aabb().maxs, Point3::new(0.2, 0.05, 0.3));
+    assert!(trimesh.contains_local_point(&Point3::new(0.15, 0.0, 0.0)));
+    assert!(!trimesh.contains_local_point(&Point3::new(0.0, 0.06, 0.0)));
+}
+
+#[test]
+fn trimesh_mirroring_scale_keeps_orientation() {
+    let (vertices, indices) = parry3d::shape::Cuboid::new(Vector3::repeat(0.5)).to_trimesh();
+    let mut trimesh = TriMesh::new(vertices, indices);
+    assert!(trimesh.compute_topology());
+    trimesh.compute_pseudo_normals();
+
+    trimesh.scale(&Vector3::new(-1.0, 1.0, 1.0));
+
+    assert!(trimesh.topology().is_some());
+    assert!(trimesh.contains_local_point(&Point3::origin()));
+    assert!(!trimesh.contains_local_point(&Point3::new(5.0, 0.0, 0.0)));
+    assert_relative_eq!(
+        trimesh
+            .signed_distance_to_local_point(&Point3::origin())
+            .unwrap(),
+        -0.5,
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+#[should_panic]
+fn trimesh_scale_rejects_zero_factors() {
+    ball_trimesh().scale(&Vector3::new(1.0, 0.0, 1.0));
+}
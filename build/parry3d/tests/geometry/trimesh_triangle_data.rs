@@ -0,0 +1,71 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{
+    ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray, RayCast,
+};
+use parry3d::shape::{Ball, FeatureId, Shape, TriMesh};
+
+const GRASS: u32 = 7;
+const GRAVEL: u32 = 42;
+
+// A floor made of a grass triangle for z < x and a gravel triangle for z > x.
+fn floor() -> TriMesh {
+    let vertices = vec![
+        Point3::new(-2.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, 2.0),
+        Point3::new(-2.0, 0.0, 2.0),
+    ];
+    TriMesh::new(vertices, vec![[0, 2, 1], [0, 3, 2]]).with_triangle_data(vec![GRASS, GRAVEL])
+}
+
+#[test]
+fn trimesh_triangle_data_from_queries() {
+    let floor = floor();
+    assert_eq!(floor.triangles_data(), Some(&[GRASS, GRAVEL][..]));
+    assert_eq!(floor.triangle_data(1), Some(GRAVEL));
+    assert_eq!(floor.feature_data(FeatureId::Vertex(0)), None);
+
+    let ray = Ray::new(Point3::new(1.0, 5.0, -1.0), -Vector3::y());
+    let hit = floor
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert_eq!(floor.feature_data(hit.feature), Some(GRASS));
+
+    let (_, feature) = floor.project_local_point_and_get_feature(&Point3::new(-1.0, 1.0, 1.0));
+    assert_eq!(floor.feature_data(feature), Some(GRAVEL));
+
+    let ball = Ball::new(0.5);
+    let pos12 = Isometry3::translation(-1.0, 0.4, 1.0).inv_mul(&Isometry3::identity());
+    let mut manifolds: Vec<ContactManifold<(), ()>> = vec![];
+    DefaultQueryDispatcher
+        .contact_manifolds(&pos12, &ball, &floor, 0.0, &mut manifolds, &mut None)
+        .unwrap();
+    let manifold = manifolds.iter().find(|m| !m.points.is_empty()).unwrap();
+    assert_eq!(floor.triangle_data(manifold.subshape2), Some(GRAVEL));
+}
+
+#[test]
+fn trimesh_triangle_data_is_preserved_by_mirror_and_scale() {
+    let floor = floor();
+
+    let mirrored = floor.mirrored(0).unwrap();
+    let mirrored = mirrored.as_trimesh().unwrap();
+    assert_eq!(mirrored.triangles_data(), floor.triangles_data());
+
+    let scaled = floor.scaled(&Vector3::new(1.0, 2.0, 3.0), 10).unwrap();
+    let scaled = scaled.as_trimesh().unwrap();
+    assert_eq!(scaled.triangles_data(), floor.triangles_data());
+}
+
+#[test]
+fn trimesh_without_triangle_data() {
+    let floor = TriMesh::from(parry3d::shape::Cuboid::new(Vector3::repeat(1.0)));
+    assert!(floor.triangles_data().is_none());
+    assert_eq!(floor.feature_data(FeatureId::Face(0)), None);
+}
+
+#[test]
+#[should_panic]
+fn trimesh_triangle_data_length_mismatch() {
+    let _ = floor().with_triangle_data(vec![GRASS]);
+}
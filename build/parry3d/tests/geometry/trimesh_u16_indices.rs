@@ -0,0 +1,32 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::TriMesh;
+
+#[test]
+fn trimesh_from_u16_indices() {
+    let vertices = vec![
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, -0.5, 0.0),
+        Point3::new(0.0, -0.5, -1.0),
+        Point3::new(1.0, -0.5, 0.0),
+    ];
+    let indices16 = [[0u16, 1, 2], [0, 2, 3], [0, 3, 1]];
+    let indices32 = vec![[0u32, 1, 2], [0, 2, 3], [0, 3, 1]];
+
+    let mesh16 = TriMesh::from_u16_indices(vertices.clone(), &indices16);
+    let mesh32 = TriMesh::new(vertices, indices32);
+    assert_eq!(mesh16.indices(), mesh32.indices());
+    assert_eq!(mesh16.local_aabb(), mesh32.local_aabb());
+
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::x() * 0.5);
+    let ray = Ray::new(Point3::new(1.0, 10.0, 3.0), -Vector3::y());
+    let toi16 = mesh16.cast_ray(&pos, &ray, 100.0, true);
+    let toi32 = mesh32.cast_ray(&pos, &ray, 100.0, true);
+    assert!(toi16.is_some());
+    assert_eq!(toi16, toi32);
+
+    let pt = Point3::new(2.0, 3.0, 4.0);
+    let proj16 = mesh16.project_point(&pos, &pt, true);
+    let proj32 = mesh32.project_point(&pos, &pt, true);
+    assert_eq!(proj16.point, proj32.point);
+}
@@ -0,0 +1,36 @@
+use na::Vector3;
+use parry3d::shape::{Ball, Cuboid, ScaledShape, Shape, SharedShape, TypedShape};
+
+#[test]
+fn typed_shape_matches_the_concrete_type() {
+    let ball = SharedShape::ball(0.5);
+    match ball.as_typed_shape() {
+        TypedShape::Ball(b) => assert_eq!(b.radius, 0.5),
+        _ => panic!("Expected a ball."),
+    }
+
+    let round_cuboid = SharedShape::round_cuboid(1.0, 2.0, 3.0, 0.1);
+    match round_cuboid.as_typed_shape() {
+        TypedShape::RoundCuboid(c) => {
+            assert_eq!(c.base_shape.half_extents, Vector3::new(1.0, 2.0, 3.0));
+            assert_eq!(c.border_radius, 0.1);
+        }
+        _ => panic!("Expected a round cuboid."),
+    }
+
+    let cylinder = SharedShape::cylinder(1.0, 0.5);
+    assert!(matches!(cylinder.as_typed_shape(), TypedShape::Cylinder(_)));
+
+    // Generic shapes are returned as trait-objects that can be downcast further.
+    let scaled = SharedShape::scaled(Ball::new(1.0), Vector3::new(1.0, 2.0, 3.0));
+    match scaled.as_typed_shape() {
+        TypedShape::ScaledShape(s) => {
+            let s = s.as_shape::<ScaledShape<Ball>>().unwrap();
+            assert_eq!(s.scale, Vector3::new(1.0, 2.0, 3.0));
+        }
+        _ => panic!("Expected a scaled shape."),
+    }
+
+    let shape: &dyn Shape = &Cuboid::new(Vector3::repeat(1.0));
+    assert!(matches!(shape.as_typed_shape(), TypedShape::Cuboid(_)));
+}
@@ -0,0 +1,172 @@
+use approx::assert_relative_eq;
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::shape::{
+    Ball, Compound, Cone, Cuboid, Cylinder, Ellipsoid, HalfSpace, Shape, SharedShape, TriMesh,
+    Voxels,
+};
+use std::f32::consts::PI;
+
+// Estimates the volume of `shape` by counting the points of a regular grid it contains.
+fn grid_volume(shape: &dyn Shape, n: usize) -> Real {
+    let aabb = shape.compute_local_aabb();
+    let cell = aabb.extents() / n as Real;
+    let mut inside = 0;
+
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let pt = aabb.mins
+                    + Vector3::new(i as Real + 0.5, j as Real + 0.5, k as Real + 0.5)
+                        .component_mul(&cell);
+
+                if shape.contains_local_point(&pt) {
+                    inside += 1;
+                }
+            }
+        }
+    }
+
+    inside as Real * cell.x * cell.y * cell.z
+}
+
+#[test]
+fn primitive_volumes_and_surface_areas() {
+    let ball = Ball::new(2.0);
+    assert_relative_eq!(ball.volume(), 4.0 / 3.0 * PI * 8.0, epsilon = 1.0e-3);
+    assert_relative_eq!(ball.surface_area(), 16.0 * PI, epsilon = 1.0e-4);
+
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(cuboid.volume(), 48.0, epsilon = 1.0e-4);
+    assert_relative_eq!(cuboid.surface_area(), 88.0, epsilon = 1.0e-4);
+
+    let cylinder = Cylinder::new(1.0, 0.5);
+    assert_relative_eq!(cylinder.volume(), PI * 0.5, epsilon = 1.0e-4);
+    assert_relative_eq!(cylinder.surface_area(), PI * 2.5, epsilon = 1.0e-4);
+
+    let cone = Cone::new(2.0, 3.0);
+    assert_relative_eq!(cone.volume(), PI * 12.0, epsilon = 1.0e-3);
+    assert_relative_eq!(cone.surface_area(), PI * 24.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn support_map_surface_area_is_approximated() {
+    let round_cuboid = SharedShape::round_cuboid(1.0, 1.0, 1.0, 0.5);
+    // The faces, the quarter-cylinders along the edges, and the spherical corners.
+    let expected = 24.0 + 12.0 * PI * 0.5 + 4.0 * PI * 0.25;
+    assert!((round_cuboid.surface_area() - expected).abs() < expected * 0.02);
+}
+
+#[test]
+fn ellipsoid_surface_area() {
+    let sphere = Ellipsoid::new(Vector3::repeat(1.0));
+    assert_relative_eq!(sphere.surface_area(), 4.0 * PI, epsilon = 1.0e-4);
+
+    let sphere = Ellipsoid::new(Vector3::repeat(1.5));
+    assert_relative_eq!(
+        sphere.surface_area(),
+        4.0 * PI * 1.5 * 1.5,
+        epsilon = 1.0e-4
+    );
+
+    // A prolate spheroid, whose surface area has a closed form.
+    let (a, c): (Real, Real) = (1.0, 2.0);
+    let e = (1.0 - a * a / (c * c)).sqrt();
+    let expected = 2.0 * PI * a * a * (1.0 + c / (a * e) * e.asin());
+    let spheroid = Ellipsoid::new(Vector3::new(a, a, c));
+    assert!((spheroid.surface_area() - expected).abs() < expected * 0.0107);
+}
+
+#[test]
+fn trimesh_volume_requires_a_closed_mesh() {
+    let (vtx, mut idx) = Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).to_trimesh();
+    let closed = TriMesh::new(vtx.clone(), idx.clone());
+    assert!(closed.boundary_edges().is_empty());
+    assert_relative_eq!(closed.volume(), 48.0, epsilon = 1.0e-4);
+    assert_relative_eq!(closed.surface_area(), 88.0, epsilon = 1.0e-4);
+
+    // Flipping the winding doesn't change the volume.
+    let flipped: Vec<_> = idx.iter().map(|t| [t[0], t[2], t[1]]).collect();
+    assert_relative_eq!(
+        TriMesh::new(vtx.clone(), flipped).volume(),
+        48.0,
+        epsilon = 1.0e-4
+    );
+
+    let _ = idx.pop();
+    let open = TriMesh::new(vtx, idx);
+    assert_eq!(open.boundary_edges().len(), 3);
+    assert_eq!(open.volume(), 0.0);
+}
+
+#[test]
+fn compound_measures_are_sums_of_parts() {
+    let compound = Compound::new(vec![
+        (Isometry3::identity(), SharedShape::ball(1.0)),
+        (
+            Isometry3::translation(5.0, 0.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0, 1.0),
+        ),
+    ]);
+    assert_relative_eq!(compound.volume(), 4.0 / 3.0 * PI + 8.0, epsilon = 1.0e-3);
+    assert_relative_eq!(compound.surface_area(), 4.0 * PI + 24.0, epsilon = 1.0e-3);
+}
+
+#[test]
+fn round_shape_measures() {
+    // A round cuboid with no extent is a ball.
+    let round_point = SharedShape::round_cuboid(0.0, 0.0, 0.0, 1.0);
+    assert_relative_eq!(round_point.volume(), 4.0 / 3.0 * PI, epsilon = 1.0e-4);
+    assert_relative_eq!(round_point.surface_area(), 4.0 * PI, epsilon = 1.0e-4);
+
+    // A round convex polyhedron and a round cuboid with the same geometry agree.
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 2.0));
+    let (vtx, _) = cuboid.to_trimesh();
+    let round_hull = SharedShape::round_convex_hull(&vtx, 0.3).unwrap();
+    let round_cuboid = SharedShape::round_cuboid(1.0, 0.5, 2.0, 0.3);
+    assert_relative_eq!(round_hull.volume(), round_cuboid.volume(), epsilon = 1.0e-3);
+    assert_relative_eq!(
+        round_hull.surface_area(),
+        round_cuboid.surface_area(),
+        epsilon = 1.0e-3
+    );
+
+    let shapes = vec![
+        round_cuboid,
+        SharedShape::round_cylinder(1.0, 0.5, 0.2),
+        SharedShape::round_cone(1.0, 0.5, 0.2),
+    ];
+
+    for shape in shapes {
+        let estimated = grid_volume(&*shape, 24);
+        let volume = shape.volume();
+        assert!(
+            (volume - estimated).abs() < volume * 0.02,
+            "{} vs {}",
+            volume,
+            estimated
+        );
+    }
+}
+
+#[test]
+fn voxels_surface_area_counts_exposed_faces() {
+    let voxels = Voxels::new(
+        Vector3::new(1.0, 2.0, 3.0),
+        &[Point3::new(0, 0, 0), Point3::new(1, 0, 0)],
+    );
+    assert_relative_eq!(voxels.volume(), 12.0, epsilon = 1.0e-4);
+    // Two faces orthogonal to `x`, and four faces orthogonal to `y` and `z` each.
+    assert_relative_eq!(
+        voxels.surface_area(),
+        2.0 * 6.0 + 4.0 * 3.0 + 4.0 * 2.0,
+        epsilon = 1.0e-4
+    );
+}
+
+#[test]
+fn unbounded_shapes_have_max_measures() {
+    let halfspace = HalfSpace::new(Vector3::y_axis());
+    assert_eq!(halfspace.volume(), Real::MAX);
+    assert_eq!(halfspace.surface_area(), Real::MAX);
+}
@@ -0,0 +1,31 @@
+use parry3d::mass_properties::MassProperties;
+use parry3d::shape::Ball;
+use parry3d::transformation::mass_properties_by_voxelization;
+
+#[test]
+fn voxelized_ball_mass_properties_converge() {
+    let density = 2.0;
+    let ball = Ball::new(1.0);
+    let expected = MassProperties::from_ball(density, ball.radius);
+    let expected_mass = 1.0 / expected.inv_mass;
+    let expected_inertia = expected.reconstruct_inertia_matrix();
+
+    let mut last_mass_err = f32::MAX;
+    let mut last_inertia_err = f32::MAX;
+
+    for cell_size in &[0.2, 0.1, 0.05] {
+        let props = mass_properties_by_voxelization(&ball, density, *cell_size);
+        let mass_err = (1.0 / props.inv_mass - expected_mass).abs() / expected_mass;
+        let inertia_err = (props.reconstruct_inertia_matrix() - expected_inertia).norm()
+            / expected_inertia.norm();
+
+        assert!(props.local_com.coords.norm() < 1.0e-4);
+        assert!(mass_err < last_mass_err);
+        assert!(inertia_err < last_inertia_err);
+        last_mass_err = mass_err;
+        last_inertia_err = inertia_err;
+    }
+
+    assert!(last_mass_err < 0.01);
+    assert!(last_inertia_err < 0.02);
+}
@@ -0,0 +1,163 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::math::Real;
+use parry3d::query::{self, PointQuery, Ray, RayCast};
+use parry3d::shape::{Ball, Cuboid, FeatureId, Shape, SharedShape, Voxels};
+
+// A 3x2x4 block of voxels, with a hole at the key (1, 1, 1).
+fn block_with_hole() -> Voxels {
+    let mut keys = vec![];
+
+    for i in 0..3 {
+        for j in 0..2 {
+            for k in 0..4 {
+                if (i, j, k) != (1, 1, 1) {
+                    keys.push(Point3::new(i, j, k));
+                }
+            }
+        }
+    }
+
+    Voxels::new(Vector3::new(0.5, 1.0, 0.25), &keys)
+}
+
+#[test]
+fn voxels_lookup() {
+    let voxels = block_with_hole();
+    assert_eq!(voxels.num_voxels(), 23);
+    assert_relative_eq!(voxels.local_aabb().mins, Point3::origin());
+    assert_relative_eq!(voxels.local_aabb().maxs, Point3::new(1.5, 2.0, 1.0));
+
+    assert!(voxels.contains_voxel(&Point3::new(2, 1, 3)));
+    assert!(!voxels.contains_voxel(&Point3::new(1, 1, 1)));
+    assert!(!voxels.contains_voxel(&Point3::new(3, 0, 0)));
+    assert_eq!(
+        voxels.voxel_key_at(&Point3::new(0.7, 1.5, -0.1)),
+        Point3::new(1, 1, -1)
+    );
+
+    // Duplicate keys are ignored.
+    let voxels = Voxels::from_points(
+        Vector3::repeat(1.0),
+        &[Point3::new(0.2, 0.3, 0.4), Point3::new(0.5, 0.5, 0.5)],
+    );
+    assert_eq!(voxels.keys(), &[Point3::origin()]);
+}
+
+#[test]
+fn voxels_ray_cast() {
+    let voxels = block_with_hole();
+
+    // From outside, along the x axis.
+    let ray = Ray::new(Point3::new(-1.0, 0.5, 0.1), Vector3::x());
+    let hit = voxels
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.0);
+    assert_relative_eq!(hit.normal, -Vector3::x());
+    assert_eq!(hit.feature, FeatureId::Face(0));
+
+    // Through the hole, from above.
+    let ray = Ray::new(Point3::new(0.75, 5.0, 0.3), -Vector3::y() * 2.0);
+    let hit = voxels
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, Vector3::y());
+    assert_eq!(
+        voxels.keys()[hit.feature.unwrap_face() as usize],
+        Point3::new(1, 0, 1)
+    );
+
+    // From inside.
+    let ray = Ray::new(Point3::new(0.2, 0.5, 0.3), Vector3::x());
+    assert_eq!(voxels.cast_local_ray(&ray, Real::MAX, true), Some(0.0));
+    let hit = voxels
+        .cast_local_ray_and_get_normal(&ray, Real::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.3);
+    assert_relative_eq!(hit.normal, -Vector3::x());
+
+    // Missing the voxels, or stopped by `max_toi`.
+    let ray = Ray::new(Point3::new(-1.0, 2.5, 0.1), Vector3::x());
+    assert!(voxels.cast_local_ray(&ray, Real::MAX, true).is_none());
+    let ray = Ray::new(Point3::new(-1.0, 0.5, 0.1), Vector3::x());
+    assert!(voxels.cast_local_ray(&ray, 0.9, true).is_none());
+}
+
+#[test]
+fn voxels_ray_cast_matches_cuboids() {
+    let voxels = block_with_hole();
+    let cuboid = Cuboid::new(voxels.voxel_size() / 2.0);
+
+    for i in 0..50 {
+        let t = i as Real * 0.37;
+        let origin = Point3::new(t.cos() * 4.0, 1.0 + t.sin() * 3.0, (t * 1.3).sin() * 4.0);
+        let target = Point3::new(0.2 + (t * 0.7).sin(), 1.0 + t.cos(), 0.5 + (t * 2.1).cos());
+        let ray = Ray::new(origin, target - origin);
+
+        let expected = voxels
+            .keys()
+            .iter()
+            .filter_map(|key| {
+                let pos = Isometry3::new(voxels.voxel_center(key).coords, na::zero());
+                cuboid.cast_ray(&pos, &ray, Real::MAX, true)
+            })
+            .fold(None, |best: Option<Real>, toi| {
+                Some(best.map_or(toi, |best| best.min(toi)))
+            });
+        let toi = voxels.cast_local_ray(&ray, Real::MAX, true);
+
+        assert_eq!(toi.is_some(), expected.is_some());
+        if let (Some(toi), Some(expected)) = (toi, expected) {
+            assert_relative_eq!(toi, expected, epsilon = 1.0e-4);
+        }
+    }
+}
+
+#[test]
+fn voxels_point_query() {
+    let voxels = block_with_hole();
+
+    // Outside.
+    let proj = voxels.project_local_point(&Point3::new(2.0, 0.5, 0.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(1.5, 0.5, 0.5));
+
+    // Inside of the hole.
+    let pt = Point3::new(0.75, 1.6, 0.3);
+    assert!(!voxels.contains_local_point(&pt));
+    let proj = voxels.project_local_point(&pt, true);
+    assert_relative_eq!(proj.point, Point3::new(0.75, 1.6, 0.25));
+
+    // Inside, close to the hole.
+    let pt = Point3::new(0.75, 0.9, 0.4);
+    assert!(voxels.contains_local_point(&pt));
+    assert_eq!(voxels.project_local_point(&pt, true).point, pt);
+    let proj = voxels.project_local_point(&pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.75, 1.0, 0.4));
+    assert_relative_eq!(voxels.distance_to_local_point(&pt, false), -0.1);
+}
+
+#[test]
+fn voxels_mass_properties_and_queries() {
+    let keys: Vec<_> = (0..8)
+        .map(|i| Point3::new(i % 2, (i / 2) % 2, i / 4))
+        .collect();
+    let voxels = Voxels::new(Vector3::new(1.0, 2.0, 3.0), &keys);
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let expected = cuboid
+        .mass_properties(2.0)
+        .transform_by(&Isometry3::translation(1.0, 2.0, 3.0));
+    assert_relative_eq!(voxels.mass_properties(2.0), expected, epsilon = 1.0e-3);
+
+    // Voxels are composite shapes so they support all the queries against convex shapes.
+    let voxels = SharedShape::voxels(Vector3::repeat(1.0), &keys);
+    let ball = Ball::new(0.5);
+    let pos2 = Isometry3::translation(1.0, 2.4, 1.0);
+    let contact = query::contact(&Isometry3::identity(), &*voxels, &pos2, &ball, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-5);
+    assert_relative_eq!(*contact.normal1, Vector3::y(), epsilon = 1.0e-5);
+}
@@ -86,11 +86,31 @@ impl AABB {
         AABB::new(center + (-ws_half_extents), center + ws_half_extents)
     }
 
+    /// Computes the AABB of `self` scaled by `factor` with respect to the origin.
+    ///
+    /// Negative scaling factors flip the AABB along the corresponding axes. The
+    /// result is always a valid AABB, i.e., with `mins <= maxs`.
+    #[inline]
+    pub fn scaled(&self, factor: &Vector<Real>) -> Self {
+        let a = self.mins.coords.component_mul(factor);
+        let b = self.maxs.coords.component_mul(factor);
+        AABB::new(a.inf(&b).into(), a.sup(&b).into())
+    }
+
+    /// Computes the AABB of `self` scaled by `factor` while keeping its center fixed.
+    ///
+    /// Negative scaling factors have the same effect as their absolute values.
+    #[inline]
+    pub fn scaled_wrt_center(&self, factor: &Vector<Real>) -> Self {
+        let half_extents = self.half_extents().component_mul(factor).abs();
+        AABB::from_half_extents(self.center(), half_extents)
+    }
+
     /// The smallest bounding sphere containing this AABB.
     #[inline]
     pub fn bounding_sphere(&self) -> BoundingSphere {
         let center = self.center();
-        let rad = na::distance(&self.mins, &self.maxs);
+        let rad = na::distance(&self.mins, &self.maxs) / 2.0;
 
         BoundingSphere::new(center, rad)
     }
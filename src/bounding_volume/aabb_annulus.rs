@@ -0,0 +1,19 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::Annulus;
+
+use super::aabb_ball::{ball_aabb, local_ball_aabb};
+
+impl Annulus {
+    /// Computes the world-space AABB of this annulus transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        ball_aabb(&Point::from(pos.translation.vector), self.outer_radius)
+    }
+
+    /// Computes the local-space AABB of this annulus.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        local_ball_aabb(self.outer_radius)
+    }
+}
@@ -1,5 +1,5 @@
-use crate::bounding_volume::AABB;
-use crate::math::{Isometry, Real, Vector};
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Real};
 use crate::shape::Capsule;
 
 impl Capsule {
@@ -10,12 +10,18 @@ impl Capsule {
     }
 
     /// The axis-aligned bounding box of this capsule.
+    ///
+    /// This is the AABB of the capsule's segment, enlarged by its radius. It is tight
+    /// whatever the orientation of the segment since each extremal point of the capsule
+    /// along a coordinate axis lies on the spherical cap centered at one of the endpoints.
     #[inline]
     pub fn local_aabb(&self) -> AABB {
         let a = self.segment.a;
         let b = self.segment.b;
-        let mins = a.coords.inf(&b.coords) - Vector::repeat(self.radius);
-        let maxs = a.coords.sup(&b.coords) + Vector::repeat(self.radius);
-        AABB::new(mins.into(), maxs.into())
+        let segment_aabb = AABB::new(
+            a.coords.inf(&b.coords).into(),
+            a.coords.sup(&b.coords).into(),
+        );
+        segment_aabb.loosened(self.radius)
     }
 }
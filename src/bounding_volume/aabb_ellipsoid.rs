@@ -0,0 +1,24 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::Ellipsoid;
+
+impl Ellipsoid {
+    /// Computes the world-space AABB of this ellipsoid, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        // The half-extent along an axis is the norm of the corresponding
+        // row of the rotation matrix scaled by the radii.
+        let rot = pos.rotation.to_rotation_matrix();
+        let scaled = rot.matrix() * na::Matrix3::from_diagonal(&self.radii);
+        let half_extents = Vector::from_fn(|i, _| scaled.row(i).norm());
+        let center = Point::from(pos.translation.vector);
+
+        AABB::new(center - half_extents, center + half_extents)
+    }
+
+    /// Computes the local-space AABB of this ellipsoid.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        AABB::new(Point::from(-self.radii), Point::from(self.radii))
+    }
+}
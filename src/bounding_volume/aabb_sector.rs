@@ -0,0 +1,44 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::Sector;
+use na::RealField;
+
+impl Sector {
+    /// Computes the world-space AABB of this sector, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        // A rotation only shifts the polar angles bounding the sector.
+        let angle = pos.rotation.angle();
+        let rotated = Sector {
+            radius: self.radius,
+            start_angle: self.start_angle + angle,
+            end_angle: self.end_angle + angle,
+        };
+        let local = rotated.local_aabb();
+        let translation = pos.translation.vector;
+
+        AABB::new(local.mins + translation, local.maxs + translation)
+    }
+
+    /// Computes the local-space AABB of this sector.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        let mut aabb = AABB::from_points(&[Point::origin(), self.start_point(), self.end_point()]);
+
+        // Add the extremal points of the arc along each axis.
+        let extremes = [
+            (0.0, Vector::x()),
+            (Real::frac_pi_2(), Vector::y()),
+            (Real::pi(), -Vector::x()),
+            (-Real::frac_pi_2(), -Vector::y()),
+        ];
+
+        for (angle, dir) in extremes.iter() {
+            if self.contains_angle(*angle) {
+                aabb.take_point(Point::from(dir * self.radius));
+            }
+        }
+
+        aabb
+    }
+}
@@ -1,9 +1,25 @@
 use crate::bounding_volume;
 use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Real};
-use crate::shape::Segment;
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, Cylinder};
+use crate::shape::{Cone, Cylinder, EllipticCylinder, Prism, SolidOfRevolution, SphericalSector};
+use crate::shape::{MinkowskiSum, ScaledShape, Segment, SupportMap};
+
+impl<A: SupportMap, B: SupportMap> MinkowskiSum<A, B> {
+    /// Computes the local-space AABB of this Minkowski sum.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
+
+impl<S: SupportMap> ScaledShape<S> {
+    /// Computes the local-space AABB of this scaled shape.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
 
 #[cfg(feature = "dim3")]
 impl Cone {
@@ -35,6 +51,69 @@ impl Cylinder {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl EllipticCylinder {
+    /// Computes the world-space AABB of this elliptic cylinder, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space AABB of this elliptic cylinder.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Prism {
+    /// Computes the world-space AABB of this prism, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space AABB of this prism.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl SolidOfRevolution {
+    /// Computes the world-space AABB of this solid of revolution, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space AABB of this solid of revolution.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl SphericalSector {
+    /// Computes the world-space AABB of this spherical sector, transformed by `pos`.
+    ///
+    /// The support map of a non-convex sector is the one of its convex hull, which has
+    /// the same AABB.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space AABB of this spherical sector.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
+
 impl Segment {
     /// Computes the world-space AABB of this segment, transformed by `pos`.
     #[inline]
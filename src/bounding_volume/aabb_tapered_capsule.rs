@@ -0,0 +1,24 @@
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Real};
+use crate::shape::TaperedCapsule;
+
+impl TaperedCapsule {
+    /// The axis-aligned bounding box of this tapered capsule.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        self.transform_by(pos).local_aabb()
+    }
+
+    /// The axis-aligned bounding box of this tapered capsule.
+    ///
+    /// This is the union of the AABBs of its two balls, which is tight since the convex
+    /// hull of two balls has the same extremal points along the coordinate axes.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        let a = self.segment.a;
+        let b = self.segment.b;
+        let aabb_a = AABB::new(a, a).loosened(self.radius_a);
+        let aabb_b = AABB::new(b, b).loosened(self.radius_b);
+        aabb_a.merged(&aabb_b)
+    }
+}
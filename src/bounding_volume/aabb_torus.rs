@@ -0,0 +1,28 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::Torus;
+use na::ComplexField;
+
+impl Torus {
+    /// Computes the world-space AABB of this torus, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        // The AABB of the circle at the center of the tube, enlarged by the tube radius.
+        let axis = pos * Vector::y();
+        let circle_half_extents =
+            axis.map(|e| self.major_radius * ComplexField::sqrt((1.0 - e * e).max(0.0)));
+        let half_extents = circle_half_extents + Vector::repeat(self.minor_radius);
+        let center = Point::from(pos.translation.vector);
+
+        AABB::new(center - half_extents, center + half_extents)
+    }
+
+    /// Computes the local-space AABB of this torus.
+    #[inline]
+    pub fn local_aabb(&self) -> AABB {
+        let xz = self.major_radius + self.minor_radius;
+        let half_extents = Vector::new(xz, self.minor_radius, xz);
+
+        AABB::new(Point::from(-half_extents), Point::from(half_extents))
+    }
+}
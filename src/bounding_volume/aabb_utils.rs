@@ -1,8 +1,8 @@
 use std::iter::IntoIterator;
 
-use crate::bounding_volume::AABB;
+use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::{Isometry, Point, Real, Vector, DIM};
-use crate::shape::SupportMap;
+use crate::shape::{Shape, SupportMap};
 use na;
 
 /// Computes the AABB of an support mapped shape.
@@ -97,3 +97,16 @@ where
 
     AABB::new(min, max)
 }
+
+/// Computes the AABB enclosing all the given shapes, each transformed by its isometry.
+///
+/// Returns `None` if `shapes` is empty.
+pub fn aabb_of_shapes(shapes: &[(Isometry<Real>, Box<dyn Shape>)]) -> Option<AABB> {
+    shapes
+        .iter()
+        .map(|(pos, shape)| shape.compute_aabb(pos))
+        .fold(None, |acc, aabb| match acc {
+            Some(acc) => Some(aabb.merged(&acc)),
+            None => Some(aabb),
+        })
+}
@@ -31,6 +31,12 @@ impl BoundingSphere {
         self.radius
     }
 
+    /// Tests whether the point `pt` is inside of this bounding sphere (or on its boundary).
+    #[inline]
+    pub fn contains_point(&self, pt: &Point<Real>) -> bool {
+        na::distance_squared(&self.center, pt) <= self.radius * self.radius
+    }
+
     /// Transforms this bounding sphere by `m`.
     #[inline]
     pub fn transform_by(&self, m: &Isometry<Real>) -> BoundingSphere {
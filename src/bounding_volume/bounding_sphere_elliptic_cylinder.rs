@@ -0,0 +1,23 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::EllipticCylinder;
+use na::ComplexField;
+
+impl EllipticCylinder {
+    /// Computes the world-space bounding sphere of this elliptic cylinder, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        let bv: BoundingSphere = self.local_bounding_sphere();
+        bv.transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this elliptic cylinder.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let max_radius = self.radii.max();
+        let radius =
+            ComplexField::sqrt(max_radius * max_radius + self.half_height * self.half_height);
+
+        BoundingSphere::new(Point::origin(), radius)
+    }
+}
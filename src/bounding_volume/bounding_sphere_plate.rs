@@ -0,0 +1,19 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::Plate;
+
+impl Plate {
+    /// Computes the world-space bounding sphere of this plate, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        let bv: BoundingSphere = self.local_bounding_sphere();
+        bv.transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this plate.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let radius = self.half_extents.norm();
+        BoundingSphere::new(Point::origin(), radius)
+    }
+}
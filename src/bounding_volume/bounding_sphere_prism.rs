@@ -0,0 +1,33 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::Prism;
+use na::{ComplexField, Vector2};
+
+impl Prism {
+    /// Computes the world-space bounding sphere of this prism, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        let bv: BoundingSphere = self.local_bounding_sphere();
+        bv.transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this prism.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let points = self.cross_section();
+        let center = points
+            .iter()
+            .fold(Vector2::zeros(), |sum, pt| sum + pt.coords)
+            / points.len() as Real;
+        let sqradius = points
+            .iter()
+            .map(|pt| (pt.coords - center).norm_squared())
+            .fold(0.0, Real::max)
+            + self.half_height() * self.half_height();
+
+        BoundingSphere::new(
+            Point::new(center.x, 0.0, center.y),
+            ComplexField::sqrt(sqradius),
+        )
+    }
+}
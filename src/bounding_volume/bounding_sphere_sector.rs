@@ -0,0 +1,18 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::Sector;
+
+impl Sector {
+    /// Computes the world-space bounding sphere of this sector, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        let center = Point::from(pos.translation.vector);
+        BoundingSphere::new(center, self.radius)
+    }
+
+    /// Computes the local-space bounding sphere of this sector.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        BoundingSphere::new(Point::origin(), self.radius)
+    }
+}
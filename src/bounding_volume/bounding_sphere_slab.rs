@@ -0,0 +1,22 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::Slab;
+
+use num::Bounded;
+
+impl Slab {
+    /// Computes the world-space bounding sphere of this slab, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        let bv: BoundingSphere = self.local_bounding_sphere();
+        bv.transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this slab.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let radius = Real::max_value();
+
+        BoundingSphere::new(Point::origin(), radius)
+    }
+}
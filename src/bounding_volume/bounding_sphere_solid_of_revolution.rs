@@ -0,0 +1,26 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::SolidOfRevolution;
+use na::ComplexField;
+
+impl SolidOfRevolution {
+    /// Computes the world-space bounding sphere of this solid of revolution, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        let bv: BoundingSphere = self.local_bounding_sphere();
+        bv.transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this solid of revolution.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let center_y = (self.min_height() + self.max_height()) / 2.0;
+        let sqradius = self
+            .profile()
+            .iter()
+            .map(|pt| pt.x * pt.x + (pt.y - center_y) * (pt.y - center_y))
+            .fold(0.0, Real::max);
+
+        BoundingSphere::new(Point::new(0.0, center_y, 0.0), ComplexField::sqrt(sqradius))
+    }
+}
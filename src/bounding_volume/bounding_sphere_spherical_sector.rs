@@ -0,0 +1,32 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::SphericalSector;
+use na::ComplexField;
+
+impl SphericalSector {
+    /// Computes the world-space bounding sphere of this spherical sector, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        let bv: BoundingSphere = self.local_bounding_sphere();
+        bv.transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this spherical sector.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let (sin, cos) = ComplexField::sin_cos(self.half_angle);
+
+        if cos < 0.0 {
+            // The sector contains a hemisphere.
+            BoundingSphere::new(Point::origin(), self.radius)
+        } else if sin >= cos {
+            // The smallest sphere containing the rim of the spherical cap also contains the
+            // apex and the rest of the cap.
+            BoundingSphere::new(Point::new(0.0, self.radius * cos, 0.0), self.radius * sin)
+        } else {
+            // The sphere at equal distance from the apex and the rim.
+            let radius = self.radius / (2.0 * cos);
+            BoundingSphere::new(Point::new(0.0, radius, 0.0), radius)
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Real};
+use crate::shape::TaperedCapsule;
+
+impl TaperedCapsule {
+    /// Computes the world-space bounding sphere of this tapered capsule, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        self.local_bounding_sphere().transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this tapered capsule.
+    ///
+    /// This is the smallest sphere containing its two balls.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let (a, b) = (self.segment.a, self.segment.b);
+        let height = self.height();
+
+        if height + self.radius_b <= self.radius_a {
+            BoundingSphere::new(a, self.radius_a)
+        } else if height + self.radius_a <= self.radius_b {
+            BoundingSphere::new(b, self.radius_b)
+        } else {
+            let radius = (height + self.radius_a + self.radius_b) / 2.0;
+            let center = a + (b - a) * ((radius - self.radius_a) / height);
+            BoundingSphere::new(center, radius)
+        }
+    }
+}
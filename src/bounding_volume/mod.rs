@@ -14,21 +14,35 @@ pub mod bounding_volume;
 
 #[doc(hidden)]
 pub mod aabb;
+#[cfg(feature = "dim2")]
+mod aabb_annulus;
 mod aabb_ball;
 #[cfg(feature = "dim2")]
 mod aabb_convex_polygon;
 #[cfg(feature = "dim3")]
 mod aabb_convex_polyhedron;
 mod aabb_cuboid;
+#[cfg(feature = "dim3")]
+mod aabb_ellipsoid;
 mod aabb_halfspace;
 mod aabb_heightfield;
+#[cfg(feature = "dim3")]
+mod aabb_plate;
+#[cfg(feature = "dim2")]
+mod aabb_sector;
+mod aabb_slab;
 mod aabb_support_map;
+mod aabb_tapered_capsule;
+#[cfg(feature = "dim3")]
+mod aabb_torus;
 mod aabb_triangle;
 mod aabb_utils;
 
 mod aabb_capsule;
 #[doc(hidden)]
 pub mod bounding_sphere;
+#[cfg(feature = "dim2")]
+mod bounding_sphere_annulus;
 mod bounding_sphere_ball;
 mod bounding_sphere_capsule;
 #[cfg(feature = "dim3")]
@@ -40,10 +54,28 @@ mod bounding_sphere_convex_polygon;
 mod bounding_sphere_cuboid;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_cylinder;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_ellipsoid;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_elliptic_cylinder;
 mod bounding_sphere_halfspace;
 mod bounding_sphere_heightfield;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_plate;
 mod bounding_sphere_polyline;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_prism;
+#[cfg(feature = "dim2")]
+mod bounding_sphere_sector;
 mod bounding_sphere_segment;
+mod bounding_sphere_slab;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_solid_of_revolution;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_spherical_sector;
+mod bounding_sphere_tapered_capsule;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_torus;
 mod bounding_sphere_triangle;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_trimesh;
@@ -54,6 +86,8 @@ mod simd_aabb;
 pub mod details {
     #[cfg(feature = "dim3")]
     pub use super::aabb_utils::support_map_aabb;
-    pub use super::aabb_utils::{local_point_cloud_aabb, local_support_map_aabb, point_cloud_aabb};
+    pub use super::aabb_utils::{
+        aabb_of_shapes, local_point_cloud_aabb, local_support_map_aabb, point_cloud_aabb,
+    };
     pub use super::bounding_sphere_utils::point_cloud_bounding_sphere;
 }
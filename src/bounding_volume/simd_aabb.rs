@@ -124,6 +124,11 @@ impl SimdAABB {
         self.maxs.replace(i, aabb.maxs);
     }
 
+    /// Extracts the `i-th` AABB of this SIMD AABB.
+    pub fn extract(&self, i: usize) -> AABB {
+        AABB::new(self.mins.extract(i), self.maxs.extract(i))
+    }
+
     /// Casts a ray on all the AABBs represented by `self`.
     pub fn cast_local_ray(&self, ray: &SimdRay, max_toi: SimdReal) -> (SimdBool, SimdReal) {
         let zero = SimdReal::zero();
@@ -83,6 +83,9 @@ mod real {
 pub mod math {
     pub use super::real::*;
     pub use super::simd::*;
+    pub use crate::utils::{
+        isometry_from_matrix_array, isometry_to_matrix_array, HOMOGENEOUS_MATRIX_LEN,
+    };
     use na::{Isometry3, Matrix3, Point3, Translation3, UnitQuaternion, Vector3, Vector6, U3, U6};
 
     /// The default tolerance used for geometric operations.
@@ -148,6 +151,9 @@ pub mod math {
 pub mod math {
     pub use super::real::*;
     pub use super::simd::*;
+    pub use crate::utils::{
+        isometry_from_matrix_array, isometry_to_matrix_array, HOMOGENEOUS_MATRIX_LEN,
+    };
     use na::{
         Isometry2, Matrix2, Point2, Translation2, UnitComplex, Vector1, Vector2, Vector3, U2,
     };
@@ -83,8 +83,15 @@ impl MassProperties {
     #[cfg(feature = "dim3")]
     pub fn with_inertia_matrix(local_com: Point<Real>, mass: Real, inertia: Matrix3<Real>) -> Self {
         let eigen = inertia.symmetric_eigen();
+        let mut eigenvectors = eigen.eigenvectors;
+
+        // The eigenvectors may form a reflection instead of a rotation.
+        if eigenvectors.determinant() < 0.0 {
+            eigenvectors.column_mut(2).neg_mut();
+        }
+
         let principal_inertia_local_frame =
-            Rotation::from_matrix_eps(&eigen.eigenvectors, 1.0e-6, 10, na::one());
+            Rotation::from_matrix_eps(&eigenvectors, 1.0e-6, 10, na::one());
         // Drop negative eigenvalues.
         let principal_inertia = eigen.eigenvalues.map(|e| if e < EPSILON { 0.0 } else { e });
 
@@ -178,6 +185,30 @@ impl MassProperties {
         }
     }
 
+    /// The mass properties of this rigid-body after scaling all its points componentwise by the
+    /// positive factors `scale`, with an unchanged density.
+    #[cfg(feature = "dim3")]
+    pub fn scaled(&self, scale: &Vector<Real>) -> Self {
+        if self.inv_mass == 0.0 {
+            return *self;
+        }
+
+        let volume_ratio = scale.x * scale.y * scale.z;
+        let mass = volume_ratio / self.inv_mass;
+        let inertia = self.reconstruct_inertia_matrix();
+        // The second moments of the mass distribution wrt. the center of mass.
+        let covariance = Matrix3::identity() * (inertia.trace() / 2.0) - inertia;
+        let scale_matrix = Matrix3::from_diagonal(scale);
+        let covariance = scale_matrix * covariance * scale_matrix * volume_ratio;
+        let inertia = Matrix3::identity() * covariance.trace() - covariance;
+
+        Self::with_inertia_matrix(
+            Point::from(self.local_com.coords.component_mul(scale)),
+            mass,
+            inertia,
+        )
+    }
+
     /// Transform each element of the mass properties.
     pub fn transform_by(&self, m: &Isometry<Real>) -> Self {
         // NOTE: we don't apply the parallel axis theorem here
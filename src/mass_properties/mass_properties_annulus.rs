@@ -0,0 +1,14 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real};
+use na::RealField;
+
+impl MassProperties {
+    /// Computes the mass properties of an annulus.
+    pub fn from_annulus(density: Real, inner_radius: Real, outer_radius: Real) -> Self {
+        let inner_sq = inner_radius * inner_radius;
+        let outer_sq = outer_radius * outer_radius;
+        let mass = Real::pi() * (outer_sq - inner_sq) * density;
+
+        Self::new(Point::origin(), mass, mass * (outer_sq + inner_sq) / 2.0)
+    }
+}
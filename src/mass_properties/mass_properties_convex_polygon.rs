@@ -7,10 +7,21 @@ use crate::shape::Triangle;
 impl MassProperties {
     /// Computes the mass properties of a convex polygon.
     pub fn from_convex_polygon(density: Real, vertices: &[Point<Real>]) -> MassProperties {
+        Self::from_convex_polygon_with_geometry(density, vertices).0
+    }
+
+    /// Computes the mass properties, the center-of-mass, and the area of a convex polygon.
+    ///
+    /// The center-of-mass is also the geometric centroid of the polygon since its
+    /// density is uniform.
+    pub fn from_convex_polygon_with_geometry(
+        density: Real,
+        vertices: &[Point<Real>],
+    ) -> (MassProperties, Point<Real>, Real) {
         let (area, com) = convex_polygon_area_and_center_of_mass(vertices);
 
         if area == 0.0 {
-            return MassProperties::new(com, 0.0, 0.0);
+            return (MassProperties::new(com, 0.0, 0.0), com, area);
         }
 
         let mut itot = 0.0;
@@ -24,7 +35,7 @@ impl MassProperties {
             itot += ipart * area;
         }
 
-        Self::new(com, area * density, itot * density)
+        (Self::new(com, area * density, itot * density), com, area)
     }
 }
 
@@ -3,7 +3,7 @@ use crate::math::{PrincipalAngularInertia, Real, Vector};
 #[cfg(feature = "dim3")]
 use {
     crate::math::{Point, Rotation},
-    na::RealField,
+    na::{RealField, Unit},
 };
 
 impl MassProperties {
@@ -41,4 +41,25 @@ impl MassProperties {
             Rotation::identity(),
         )
     }
+
+    /// Computes the mass properties of a cylinder with its principal axis aligned with `axis`.
+    ///
+    /// While `Self::from_cylinder` assumes the cylinder is aligned with the `y` axis, this
+    /// rotates its principal inertia frame so the cylinder is aligned with `axis` instead.
+    #[cfg(feature = "dim3")]
+    pub fn from_cylinder_with_axis(
+        density: Real,
+        half_height: Real,
+        radius: Real,
+        axis: Unit<Vector<Real>>,
+    ) -> Self {
+        let (cyl_vol, cyl_unit_i) = Self::cylinder_y_volume_unit_inertia(half_height, radius);
+        let cyl_mass = cyl_vol * density;
+        // If `axis` is `-y` there is no unique rotation, but the inertia of the
+        // cylinder is symmetric wrt. the `xz` plane anyway.
+        let frame = Rotation::rotation_between_axis(&Vector::y_axis(), &axis)
+            .unwrap_or_else(Rotation::identity);
+
+        Self::with_principal_inertia_frame(Point::origin(), cyl_mass, cyl_unit_i * cyl_mass, frame)
+    }
 }
@@ -0,0 +1,23 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, PrincipalAngularInertia, Real, Vector};
+use na::RealField;
+
+impl MassProperties {
+    pub(crate) fn ellipsoid_volume_unit_angular_inertia(
+        radii: &Vector<Real>,
+    ) -> (Real, PrincipalAngularInertia<Real>) {
+        let volume = Real::pi() * radii.x * radii.y * radii.z * 4.0 / 3.0;
+        let sq = radii.component_mul(radii);
+        let i = Vector::new(sq.y + sq.z, sq.x + sq.z, sq.x + sq.y) / 5.0;
+
+        (volume, i)
+    }
+
+    /// Computes the mass properties of an ellipsoid.
+    pub fn from_ellipsoid(density: Real, radii: &Vector<Real>) -> Self {
+        let (volume, unit_i) = Self::ellipsoid_volume_unit_angular_inertia(radii);
+        let mass = volume * density;
+
+        Self::new(Point::origin(), mass, unit_i * mass)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, PrincipalAngularInertia, Real, Vector};
+use na::{RealField, Vector2};
+
+impl MassProperties {
+    pub(crate) fn elliptic_cylinder_volume_unit_angular_inertia(
+        half_height: Real,
+        radii: &Vector2<Real>,
+    ) -> (Real, PrincipalAngularInertia<Real>) {
+        let volume = half_height * radii.x * radii.y * Real::pi() * 2.0;
+        let sq_radii = radii.component_mul(radii) / 4.0;
+        let sq_height = half_height * half_height / 3.0;
+        let i = Vector::new(
+            sq_radii.y + sq_height,
+            sq_radii.x + sq_radii.y,
+            sq_radii.x + sq_height,
+        );
+
+        (volume, i)
+    }
+
+    /// Computes the mass properties of an elliptic cylinder aligned with the `y` axis.
+    pub fn from_elliptic_cylinder(density: Real, half_height: Real, radii: &Vector2<Real>) -> Self {
+        let (volume, unit_i) =
+            Self::elliptic_cylinder_volume_unit_angular_inertia(half_height, radii);
+        let mass = volume * density;
+
+        Self::new(Point::origin(), mass, unit_i * mass)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real};
+use na::{Matrix2, Matrix3, Point2, Vector2};
+
+impl MassProperties {
+    /// Computes the mass properties of a prism obtained by extruding the counter-clockwise
+    /// convex polygon `cross_section` along the `y` axis.
+    ///
+    /// The 2D points `(u, v)` of the cross-section correspond to the 3D points `(u, y, v)`.
+    pub fn from_prism(density: Real, cross_section: &[Point2<Real>], half_height: Real) -> Self {
+        let mut area = 0.0;
+        let mut first_moment = Vector2::zeros();
+        // The second moments of area of the cross-section wrt. the origin.
+        let mut second_moment = Matrix2::zeros();
+        let a = cross_section[0].coords;
+
+        for i in 1..cross_section.len() - 1 {
+            let b = cross_section[i].coords;
+            let c = cross_section[i + 1].coords;
+            let tri_area = (b - a).perp(&(c - a)) / 2.0;
+            let sum = a + b + c;
+
+            area += tri_area;
+            first_moment += sum * (tri_area / 3.0);
+            second_moment +=
+                (a * a.transpose() + b * b.transpose() + c * c.transpose() + sum * sum.transpose())
+                    * (tri_area / 12.0);
+        }
+
+        let centroid = first_moment / area;
+        let central_moment = second_moment - centroid * centroid.transpose() * area;
+
+        let height = half_height * 2.0;
+        let mass = area * height * density;
+        let areal_density = height * density;
+        let axial = mass * half_height * half_height / 3.0;
+
+        let (uu, vv, uv) = (
+            central_moment.m11 * areal_density,
+            central_moment.m22 * areal_density,
+            central_moment.m12 * areal_density,
+        );
+
+        #[rustfmt::skip]
+        let inertia = Matrix3::new(
+            axial + vv, 0.0,     -uv,
+            0.0,        uu + vv, 0.0,
+            -uv,        0.0,     axial + uu,
+        );
+
+        let com = Point::new(centroid.x, 0.0, centroid.y);
+        Self::with_inertia_matrix(com, mass, inertia)
+    }
+}
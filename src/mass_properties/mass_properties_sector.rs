@@ -0,0 +1,41 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real, Vector};
+use na::ComplexField;
+use num::Zero;
+
+impl MassProperties {
+    /// Computes the mass properties of a circular sector centered at the origin, bounded by
+    /// the polar angles `start_angle` and `end_angle`.
+    pub fn from_sector(density: Real, radius: Real, start_angle: Real, end_angle: Real) -> Self {
+        let half_angle = (end_angle - start_angle) / 2.0;
+        let bisector_angle = start_angle + half_angle;
+        let dir = Vector::new(
+            ComplexField::cos(bisector_angle),
+            ComplexField::sin(bisector_angle),
+        );
+
+        Self::from_circular_sector(density, Point::origin(), radius, dir, half_angle)
+    }
+
+    /// Computes the mass properties of a circular sector centered at `center`, symmetric wrt.
+    /// the unit vector `dir`, and with an opening angle equal to `2 * half_angle`.
+    pub(crate) fn from_circular_sector(
+        density: Real,
+        center: Point<Real>,
+        radius: Real,
+        dir: Vector<Real>,
+        half_angle: Real,
+    ) -> Self {
+        if half_angle <= 0.0 || radius == 0.0 {
+            return Self::zero();
+        }
+
+        let area = half_angle * radius * radius;
+        let mass = area * density;
+        let com_dist = 2.0 * radius * ComplexField::sin(half_angle) / (3.0 * half_angle);
+        // The polar inertia of a sector wrt. its center is the same as the disk's.
+        let inertia = mass * (radius * radius / 2.0 - com_dist * com_dist);
+
+        Self::new(center + dir * com_dist, mass, inertia)
+    }
+}
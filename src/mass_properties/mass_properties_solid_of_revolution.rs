@@ -0,0 +1,52 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real, Vector};
+use na::{Point2, RealField};
+
+impl MassProperties {
+    /// Computes the mass properties of a convex solid obtained by rotating the given profile
+    /// around the `y` axis.
+    ///
+    /// The profile is a sequence of points `(r, y)` sorted by increasing heights `y`, as given
+    /// by `SolidOfRevolution::profile`.
+    pub fn from_solid_of_revolution(density: Real, profile: &[Point2<Real>]) -> Self {
+        // The solid is sliced into disks. Along each profile segment, the integrands
+        // are polynomials of degree at most 4 so the 3-points Gauss-Legendre
+        // quadrature is exact.
+        let offset = (0.6 as Real).sqrt() / 2.0;
+        let quadrature = [
+            (0.5 - offset, 5.0 / 18.0),
+            (0.5, 8.0 / 18.0),
+            (0.5 + offset, 5.0 / 18.0),
+        ];
+
+        let mut volume = 0.0;
+        let mut first_moment = 0.0;
+        // The inertia wrt. the `y` axis, and wrt. the `x` axis through the origin, per unit density.
+        let mut axial_inertia = 0.0;
+        let mut transverse_inertia = 0.0;
+
+        for segment in profile.windows(2) {
+            let (a, b) = (segment[0], segment[1]);
+            let length = b.y - a.y;
+
+            for (t, weight) in quadrature.iter() {
+                let r = a.x + (b.x - a.x) * *t;
+                let y = a.y + length * *t;
+                let sq_r = r * r;
+                let disk_volume = Real::pi() * sq_r * length * *weight;
+
+                volume += disk_volume;
+                first_moment += disk_volume * y;
+                axial_inertia += disk_volume * sq_r / 2.0;
+                transverse_inertia += disk_volume * (sq_r / 4.0 + y * y);
+            }
+        }
+
+        let mass = volume * density;
+        let com_y = first_moment / volume;
+        let transverse = (transverse_inertia - volume * com_y * com_y) * density;
+        let principal_inertia = Vector::new(transverse, axial_inertia * density, transverse);
+
+        Self::new(Point::new(0.0, com_y, 0.0), mass, principal_inertia)
+    }
+}
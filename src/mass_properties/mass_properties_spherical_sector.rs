@@ -0,0 +1,26 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real, Vector};
+use na::{ComplexField, RealField};
+
+impl MassProperties {
+    /// Computes the mass properties of a spherical sector centered at the origin, with its
+    /// axis aligned with the `y` axis.
+    pub fn from_spherical_sector(density: Real, radius: Real, half_angle: Real) -> Self {
+        let cos = ComplexField::cos(half_angle);
+        let sq_radius = radius * radius;
+        let volume = Real::two_pi() * sq_radius * radius * (1.0 - cos) / 3.0;
+        let mass = volume * density;
+        let com_dist = radius * (1.0 + cos) * 3.0 / 8.0;
+
+        // The inertia wrt. the apex, obtained by integrating in spherical coordinates.
+        let principal = mass * sq_radius * (1.0 - cos) * (2.0 + cos) / 5.0;
+        let off_principal_apex = principal / 2.0 + mass * sq_radius * (1.0 + cos + cos * cos) / 5.0;
+        let off_principal = off_principal_apex - mass * com_dist * com_dist;
+
+        Self::new(
+            Point::new(0.0, com_dist, 0.0),
+            mass,
+            Vector::new(off_principal, principal, off_principal),
+        )
+    }
+}
@@ -0,0 +1,124 @@
+use crate::mass_properties::MassProperties;
+#[cfg(feature = "dim3")]
+use crate::math::Rotation;
+use crate::math::{Point, Real, Vector};
+use na::{ComplexField, RealField};
+
+impl MassProperties {
+    /// Computes the mass properties of a tapered capsule, i.e., the convex hull of the ball
+    /// of radius `radius_a` centered at `a` and the ball of radius `radius_b` centered at `b`.
+    pub fn from_tapered_capsule(
+        density: Real,
+        a: Point<Real>,
+        b: Point<Real>,
+        radius_a: Real,
+        radius_b: Real,
+    ) -> Self {
+        let height = (b - a).norm();
+
+        // One ball contains the other.
+        if height + radius_b <= radius_a || height + radius_a <= radius_b {
+            let (center, radius) = if radius_a >= radius_b {
+                (a, radius_a)
+            } else {
+                (b, radius_b)
+            };
+            let mut ball = Self::from_ball(density, radius);
+            ball.local_com = center;
+            return ball;
+        }
+
+        let axis = (b - a) / height;
+        // The sine of the angle between the lateral surface and the axis of the shape.
+        // The lateral surface touches the balls at the points with normals
+        // making an angle `acos(sin_slope)` with `axis`.
+        let sin_slope = (radius_a - radius_b) / height;
+        let cos_slope = ComplexField::sqrt(1.0 - sin_slope * sin_slope);
+
+        #[cfg(feature = "dim2")]
+        {
+            // Decomposition into a circular sector for each ball, and the two
+            // right trapezoids between the sectors and the lateral edges.
+            let angle = ComplexField::acos(sin_slope);
+            let ortho = Vector::new(-axis.y, axis.x);
+            let n1 = axis * sin_slope + ortho * cos_slope;
+            let n2 = axis * sin_slope - ortho * cos_slope;
+
+            Self::from_circular_sector(density, a, radius_a, -axis, Real::pi() - angle)
+                + Self::from_circular_sector(density, b, radius_b, axis, angle)
+                + Self::from_convex_polygon(density, &[a, b, b + n1 * radius_b, a + n1 * radius_a])
+                + Self::from_convex_polygon(density, &[a, a + n2 * radius_a, b + n2 * radius_b, b])
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            // Integrate the mass properties of the disks orthogonal to the axis, with their
+            // radius `rho(t)` at the distance `t` from `a` along the axis. The integrands are
+            // polynomials of degree at most 4 on each of the three parts of the shape (the two
+            // spherical caps and the frustum between them) so the 3-points Gauss-Legendre
+            // quadrature is exact.
+            let tangent_a = radius_a * sin_slope;
+            let tangent_b = height + radius_b * sin_slope;
+            let rho_a = radius_a * cos_slope;
+            let rho_b = radius_b * cos_slope;
+
+            let sq_rho = |t: Real| {
+                if t <= tangent_a {
+                    radius_a * radius_a - t * t
+                } else if t >= tangent_b {
+                    radius_b * radius_b - (t - height) * (t - height)
+                } else {
+                    let rho = rho_a + (rho_b - rho_a) * (t - tangent_a) / (tangent_b - tangent_a);
+                    rho * rho
+                }
+            };
+
+            // The volume, first moment, and unit-density inertia wrt. `a` along the axis
+            // and orthogonal to it, of the slices between `t0` and `t1`.
+            let integrate = |t0: Real, t1: Real| {
+                let half = (t1 - t0) / 2.0;
+                let mid = (t0 + t1) / 2.0;
+                let node = ComplexField::sqrt(0.6);
+                let mut res = [0.0; 4];
+
+                for (x, w) in [(-node, 5.0 / 9.0), (0.0, 8.0 / 9.0), (node, 5.0 / 9.0)].iter() {
+                    let t = mid + half * *x;
+                    let area = Real::pi() * sq_rho(t);
+                    let weight = half * *w;
+                    res[0] += area * weight;
+                    res[1] += area * t * weight;
+                    res[2] += area * sq_rho(t) / 2.0 * weight;
+                    res[3] += area * (sq_rho(t) / 4.0 + t * t) * weight;
+                }
+
+                res
+            };
+
+            let mut res = [0.0; 4];
+            for (t0, t1) in [
+                (-radius_a, tangent_a),
+                (tangent_a, tangent_b),
+                (tangent_b, height + radius_b),
+            ]
+            .iter()
+            {
+                for (r, part) in res.iter_mut().zip(integrate(*t0, *t1).iter()) {
+                    *r += *part;
+                }
+            }
+
+            let [volume, moment, axial_i, ortho_i] = res;
+            let com_t = moment / volume;
+            let ortho_i = ortho_i - volume * com_t * com_t;
+            let mass = volume * density;
+            let principal_inertia = Vector::new(ortho_i, axial_i, ortho_i) * density;
+
+            // The inertia is symmetric wrt. the axis orientation.
+            let dir = if axis.y < 0.0 { -axis } else { axis };
+            let frame =
+                Rotation::rotation_between(&Vector::y(), &dir).unwrap_or(Rotation::identity());
+
+            Self::with_principal_inertia_frame(a + axis * com_t, mass, principal_inertia, frame)
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, PrincipalAngularInertia, Real, Vector};
+use na::RealField;
+
+impl MassProperties {
+    pub(crate) fn torus_volume_unit_angular_inertia(
+        major_radius: Real,
+        minor_radius: Real,
+    ) -> (Real, PrincipalAngularInertia<Real>) {
+        let major_sq = major_radius * major_radius;
+        let minor_sq = minor_radius * minor_radius;
+        let volume = Real::pi() * Real::pi() * 2.0 * major_radius * minor_sq;
+        let off_principal = major_sq / 2.0 + minor_sq * 5.0 / 8.0;
+        let principal = major_sq + minor_sq * 3.0 / 4.0;
+
+        (volume, Vector::new(off_principal, principal, off_principal))
+    }
+
+    /// Computes the mass properties of a torus.
+    pub fn from_torus(density: Real, major_radius: Real, minor_radius: Real) -> Self {
+        let (volume, unit_i) = Self::torus_volume_unit_angular_inertia(major_radius, minor_radius);
+        let mass = volume * density;
+
+        Self::new(Point::origin(), mass, unit_i * mass)
+    }
+}
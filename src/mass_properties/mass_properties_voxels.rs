@@ -0,0 +1,43 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Matrix, Point, Real, Vector};
+use num::Zero;
+
+impl MassProperties {
+    /// Computes the mass properties of a set of voxels, given the size of each voxel and
+    /// the keys of all the occupied voxels.
+    ///
+    /// The voxel with the key `k` occupies the box from `k * voxel_size` to `(k + 1) * voxel_size`.
+    pub fn from_voxels(density: Real, voxel_size: Vector<Real>, keys: &[Point<i32>]) -> Self {
+        if keys.is_empty() {
+            return Self::zero();
+        }
+
+        let (voxel_volume, voxel_unit_inertia) = Self::cuboid_volume_unit_inertia(voxel_size / 2.0);
+        let voxel_mass = voxel_volume * density;
+        let centers = keys.iter().map(|key| {
+            (key.coords.map(|e| e as Real) + Vector::repeat(0.5)).component_mul(&voxel_size)
+        });
+
+        let mass = voxel_mass * keys.len() as Real;
+        let com = centers.clone().sum::<Vector<Real>>() / keys.len() as Real;
+        // The second moments of the voxel centers wrt. the center of mass.
+        let second_moment = centers.fold(Matrix::zeros(), |acc, center| {
+            let dpt = center - com;
+            acc + dpt * dpt.transpose() * voxel_mass
+        });
+
+        #[cfg(feature = "dim2")]
+        {
+            let inertia = voxel_unit_inertia * mass + second_moment.trace();
+            Self::new(Point::from(com), mass, inertia)
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            let inertia = Matrix::from_diagonal(&(voxel_unit_inertia * mass))
+                + Matrix::identity() * second_moment.trace()
+                - second_moment;
+            Self::with_inertia_matrix(Point::from(com), mass, inertia)
+        }
+    }
+}
@@ -3,6 +3,8 @@
 pub use self::mass_properties::MassProperties;
 
 mod mass_properties;
+#[cfg(feature = "dim2")]
+mod mass_properties_annulus;
 mod mass_properties_ball;
 mod mass_properties_capsule;
 mod mass_properties_compound;
@@ -16,10 +18,26 @@ mod mass_properties_convex_polygon;
 mod mass_properties_convex_polyhedron;
 mod mass_properties_cuboid;
 mod mass_properties_cylinder;
+#[cfg(feature = "dim3")]
+mod mass_properties_ellipsoid;
+#[cfg(feature = "dim3")]
+mod mass_properties_elliptic_cylinder;
+#[cfg(feature = "dim3")]
+mod mass_properties_prism;
+#[cfg(feature = "dim2")]
+mod mass_properties_sector;
+#[cfg(feature = "dim3")]
+mod mass_properties_solid_of_revolution;
+#[cfg(feature = "dim3")]
+mod mass_properties_spherical_sector;
+mod mass_properties_tapered_capsule;
+#[cfg(feature = "dim3")]
+mod mass_properties_torus;
 #[cfg(feature = "dim2")]
 mod mass_properties_triangle;
 #[cfg(feature = "dim2")]
 mod mass_properties_trimesh;
+mod mass_properties_voxels;
 
 /// Free functions for some special-cases of mass-properties computation.
 pub mod details {
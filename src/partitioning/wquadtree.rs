@@ -244,6 +244,173 @@ impl<T: IndexedData> SimdQuadTree<T> {
         }
     }
 
+    /// Recomputes the AABBs of all the nodes of this quad-tree, without changing its structure.
+    ///
+    /// The AABB of each piece of data is given by `aabb_builder`. Unlike `self.update`, this
+    /// shrinks the AABBs of the nodes if needed. This is much cheaper than `self.clear_and_rebuild`
+    /// but the quality of the tree may degrade if the pieces of data moved significantly relative
+    /// to each other.
+    pub fn refit<F>(&mut self, aabb_builder: F, dilation_factor: Real)
+    where
+        F: Fn(&T) -> AABB,
+    {
+        let dilation_factor = SimdReal::splat(dilation_factor);
+
+        // The children of a node are always stored after it, so iterating backward
+        // refits all the children of a node before the node itself.
+        for id in (0..self.nodes.len()).rev() {
+            let node = &self.nodes[id];
+            let mut new_aabbs = [AABB::new_invalid(); SIMD_WIDTH];
+
+            for (child_id, new_aabb) in node.children.iter().zip(new_aabbs.iter_mut()) {
+                if node.leaf {
+                    if let Some(proxy) = self.proxies.get(*child_id as usize) {
+                        *new_aabb = aabb_builder(&proxy.data);
+                    }
+                } else if let Some(child) = self.nodes.get(*child_id as usize) {
+                    *new_aabb = child.simd_aabb.to_merged_aabb();
+                }
+            }
+
+            let node = &mut self.nodes[id];
+            node.simd_aabb = SimdAABB::from(new_aabbs);
+            node.dirty = false;
+
+            // NOTE: the root isn't dilated, consistently with `self.clear_and_rebuild`.
+            if id != 0 {
+                node.simd_aabb.dilate_by_factor(dilation_factor);
+            }
+        }
+
+        self.dirty_nodes.clear();
+        self.root_aabb = self
+            .nodes
+            .first()
+            .map(|root| root.simd_aabb.extract(0))
+            .unwrap_or_else(AABB::new_invalid);
+    }
+
+    /// Inserts a piece of data with the given AABB into this quad-tree, without rebuilding it.
+    ///
+    /// If the data was already part of this quad-tree, it is moved to its new AABB. The new leaf
+    /// is placed in the subtree with the smallest AABB growth so the quality of the tree may
+    /// degrade after many insertions: call `self.clear_and_rebuild` from time to time to fix this.
+    pub fn insert(&mut self, data: T, aabb: AABB) {
+        if self.nodes.is_empty() {
+            self.clear_and_rebuild(std::iter::empty(), 0.0);
+        }
+
+        let index = data.index();
+        self.remove(data);
+
+        if index >= self.proxies.len() {
+            self.proxies.resize(index + 1, SimdQuadTreeProxy::invalid());
+        }
+
+        self.root_aabb.merge(&aabb);
+        let mut curr = 0u32;
+
+        loop {
+            let node = self.nodes[curr as usize];
+
+            if let Some(lane) = node.children.iter().position(|c| *c == u32::MAX) {
+                let proxy_node = if node.leaf {
+                    self.nodes[curr as usize].children[lane] = index as u32;
+                    NodeIndex::new(curr, lane as u8)
+                } else {
+                    // Add a new leaf to this internal node.
+                    let leaf_id = self.nodes.len() as u32;
+                    self.nodes.push(SimdQuadTreeNode {
+                        simd_aabb: SimdAABB::from([
+                            aabb,
+                            AABB::new_invalid(),
+                            AABB::new_invalid(),
+                            AABB::new_invalid(),
+                        ]),
+                        children: [index as u32, u32::MAX, u32::MAX, u32::MAX],
+                        parent: NodeIndex::new(curr, lane as u8),
+                        leaf: true,
+                        dirty: false,
+                    });
+                    self.nodes[curr as usize].children[lane] = leaf_id;
+                    NodeIndex::new(leaf_id, 0)
+                };
+
+                self.nodes[curr as usize].simd_aabb.replace(lane, aabb);
+                self.proxies[index] = SimdQuadTreeProxy {
+                    node: proxy_node,
+                    data,
+                };
+                return;
+            }
+
+            if node.leaf {
+                // The leaf is full: move its content to a new leaf and turn it into an internal
+                // node so the next iteration can add a new leaf next to it.
+                let moved_id = self.nodes.len() as u32;
+                let mut moved = node;
+                moved.parent = NodeIndex::new(curr, 0);
+                moved.dirty = false;
+                self.nodes.push(moved);
+
+                for (lane, child) in moved.children.iter().enumerate() {
+                    if let Some(proxy) = self.proxies.get_mut(*child as usize) {
+                        proxy.node = NodeIndex::new(moved_id, lane as u8);
+                    }
+                }
+
+                let node = &mut self.nodes[curr as usize];
+                node.leaf = false;
+                node.children = [moved_id, u32::MAX, u32::MAX, u32::MAX];
+                node.simd_aabb = SimdAABB::from([
+                    moved.simd_aabb.to_merged_aabb(),
+                    AABB::new_invalid(),
+                    AABB::new_invalid(),
+                    AABB::new_invalid(),
+                ]);
+                continue;
+            }
+
+            // Descend into the child with the smallest AABB growth.
+            let mut best_lane = 0;
+            let mut best_growth = Real::max_value();
+
+            for lane in 0..SIMD_WIDTH {
+                let lane_aabb = node.simd_aabb.extract(lane);
+                let growth = lane_aabb.merged(&aabb).extents().sum() - lane_aabb.extents().sum();
+
+                if growth < best_growth {
+                    best_lane = lane;
+                    best_growth = growth;
+                }
+            }
+
+            let lane_aabb = node.simd_aabb.extract(best_lane).merged(&aabb);
+            self.nodes[curr as usize]
+                .simd_aabb
+                .replace(best_lane, lane_aabb);
+            curr = node.children[best_lane];
+        }
+    }
+
+    /// Removes a piece of data from this quad-tree, without rebuilding it.
+    ///
+    /// The AABBs of the ancestors of the removed leaf are not shrunk. Does nothing if the data
+    /// is not part of this quad-tree.
+    pub fn remove(&mut self, data: T) {
+        let index = data.index();
+        let node_id = match self.proxies.get(index) {
+            Some(proxy) if proxy.node != NodeIndex::invalid() => proxy.node,
+            _ => return,
+        };
+
+        let node = &mut self.nodes[node_id.index as usize];
+        node.children[node_id.lane as usize] = u32::MAX;
+        node.simd_aabb
+            .replace(node_id.lane as usize, AABB::new_invalid());
+        self.proxies[index] = SimdQuadTreeProxy::invalid();
+    }
+
     fn do_recurse_build(
         &mut self,
         indices: &mut [usize],
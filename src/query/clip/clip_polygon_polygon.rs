@@ -0,0 +1,45 @@
+use crate::math::{Point, Real, Vector};
+use na::Unit;
+
+/// Clips the `incident` polygon against the side planes of the `reference_face` polygon.
+///
+/// This is the Sutherland-Hodgman clipping step used to generate contact manifolds
+/// between polyhedra with the separating axis theorem: each edge of the convex
+/// `reference_face` defines a side plane orthogonal to the face, and the convex `incident`
+/// polygon is clipped by all of them. Then, only the resulting points lying behind the
+/// reference face (i.e. such that `reference_normal.dot(&(pt - reference_face[0])) <= 0.0`)
+/// are kept.
+///
+/// The reference face vertices may be given in any winding order.
+pub fn clip_polygon(
+    reference_face: &[Point<Real>],
+    reference_normal: &Unit<Vector<Real>>,
+    incident: &[Point<Real>],
+) -> Vec<Point<Real>> {
+    let mut result = incident.to_vec();
+
+    if reference_face.is_empty() {
+        return result;
+    }
+
+    let mut workspace = Vec::with_capacity(incident.len());
+    let centroid = crate::utils::center(reference_face);
+
+    for i in 0..reference_face.len() {
+        let a = &reference_face[i];
+        let b = &reference_face[(i + 1) % reference_face.len()];
+        let mut side_normal = (b - a).cross(reference_normal);
+
+        // Make the side normal point outward whatever the face winding is.
+        if side_normal.dot(&(centroid - a)) > 0.0 {
+            side_normal = -side_normal;
+        }
+
+        super::clip_halfspace_polygon(a, &side_normal, &result, &mut workspace);
+        std::mem::swap(&mut result, &mut workspace);
+    }
+
+    let origin = reference_face[0];
+    result.retain(|pt| reference_normal.dot(&(pt - origin)) <= 0.0);
+    result
+}
@@ -0,0 +1,40 @@
+use crate::math::{Isometry, Real};
+use crate::query::ClosestPoints;
+use crate::shape::{Slab, SupportMap};
+
+/// Closest points between a slab and a support-mapped shape (Cuboid, ConvexHull, etc.)
+pub fn closest_points_slab_support_map<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    slab: &Slab,
+    other: &G,
+    margin: Real,
+) -> ClosestPoints {
+    assert!(
+        margin >= 0.0,
+        "The proximity margin must be positive or null."
+    );
+
+    let (normal1, deepest, distance) = slab.separating_side(pos12, other);
+
+    if distance <= margin {
+        if distance <= 0.0 {
+            ClosestPoints::Intersecting
+        } else {
+            let p1 = deepest - *normal1 * distance;
+            let p2 = pos12.inverse_transform_point(&deepest);
+            ClosestPoints::WithinMargin(p1, p2)
+        }
+    } else {
+        ClosestPoints::Disjoint
+    }
+}
+
+/// Closest points between a support-mapped shape (Cuboid, ConvexHull, etc.) and a slab.
+pub fn closest_points_support_map_slab<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    other: &G,
+    slab: &Slab,
+    margin: Real,
+) -> ClosestPoints {
+    closest_points_slab_support_map(&pos12.inverse(), slab, other, margin).flipped()
+}
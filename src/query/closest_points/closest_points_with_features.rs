@@ -0,0 +1,107 @@
+use crate::math::{Isometry, Point, Real};
+use crate::query::{ClosestPoints, PointQuery, PointQueryWithLocation, Unsupported};
+use crate::shape::{FeatureId, Shape, TypedShape};
+
+/// The feature of a shape containing one of the closest points between two shapes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ClosestPointFeature {
+    /// The part of the composite shape containing the closest point, e.g., the index of a
+    /// triangle of a triangle mesh, the index of a segment of a polyline, or the index of a
+    /// part of a compound shape.
+    ///
+    /// This is `None` if the shape is not a triangle mesh, a polyline, or a compound shape.
+    pub part_id: Option<u32>,
+    /// The feature containing the closest point.
+    ///
+    /// If `part_id` is not `None`, this is a feature of the part of the composite shape, e.g.,
+    /// `FeatureId::Vertex(1)` for the second vertex of a triangle of a triangle mesh.
+    pub feature: FeatureId,
+    /// The barycentric coordinates of the closest point on the segment or triangle containing it.
+    ///
+    /// This is `None` if the closest point isn't on a segment or a triangle, or on a segment or
+    /// triangle of a composite shape. The last coordinate is always zero for a segment.
+    pub barycentric_coordinates: Option<[Real; 3]>,
+}
+
+/// Computes the pair of closest points between two shapes, and the features containing them.
+///
+/// This is the same as `query::closest_points`, except that the features containing the closest
+/// points are also returned if the shapes are separated by a distance smaller than `max_dist`,
+/// i.e., if the closest points are `ClosestPoints::WithinMargin`. This includes the triangle
+/// or segment of composite shapes containing each point, with its barycentric coordinates.
+pub fn closest_points_with_features(
+    pos1: &Isometry<Real>,
+    g1: &dyn Shape,
+    pos2: &Isometry<Real>,
+    g2: &dyn Shape,
+    max_dist: Real,
+) -> Result<(ClosestPoints, Option<[ClosestPointFeature; 2]>), Unsupported> {
+    let pts = crate::query::closest_points(pos1, g1, pos2, g2, max_dist)?;
+
+    if let ClosestPoints::WithinMargin(pt1, pt2) = pts {
+        // Each closest point is the projection, on its shape, of the closest point of the
+        // other shape. Projecting the latter again yields the feature containing the former.
+        let feature1 = closest_point_feature(g1, &pos1.inverse_transform_point(&pt2));
+        let feature2 = closest_point_feature(g2, &pos2.inverse_transform_point(&pt1));
+        Ok((pts, Some([feature1, feature2])))
+    } else {
+        Ok((pts, None))
+    }
+}
+
+// Projects a point on a shape, and returns the feature it is projected on.
+fn closest_point_feature(shape: &dyn Shape, pt: &Point<Real>) -> ClosestPointFeature {
+    if let Some((pos, inner)) = shape.as_transformed_shape() {
+        return closest_point_feature(inner, &pos.inverse_transform_point(pt));
+    }
+
+    match shape.as_typed_shape() {
+        TypedShape::Segment(s) => {
+            let [u, v] = s
+                .project_local_point_and_get_location(pt, false)
+                .1
+                .barycentric_coordinates();
+            ClosestPointFeature {
+                part_id: None,
+                feature: s.project_local_point_and_get_feature(pt).1,
+                barycentric_coordinates: Some([u, v, 0.0]),
+            }
+        }
+        TypedShape::Triangle(t) => {
+            let location = t.project_local_point_and_get_location(pt, false).1;
+            ClosestPointFeature {
+                part_id: None,
+                feature: t.project_local_point_and_get_feature(pt).1,
+                barycentric_coordinates: location.barycentric_coordinates(),
+            }
+        }
+        TypedShape::TriMesh(t) => {
+            let (id, _) = t.project_local_point_and_get_location(pt, false).1;
+            ClosestPointFeature {
+                part_id: Some(id),
+                ..closest_point_feature(&t.triangle(id), pt)
+            }
+        }
+        TypedShape::Polyline(p) => {
+            let (id, _) = p.project_local_point_and_get_location(pt, false).1;
+            ClosestPointFeature {
+                part_id: Some(id),
+                ..closest_point_feature(&p.segment(id), pt)
+            }
+        }
+        TypedShape::Compound(c) => {
+            let (id, _) = c.project_local_point_and_get_location(pt, false).1;
+            let (part_pos, part) = &c.shapes()[id as usize];
+            ClosestPointFeature {
+                part_id: Some(id),
+                ..closest_point_feature(&**part, &part_pos.inverse_transform_point(pt))
+            }
+        }
+        _ => ClosestPointFeature {
+            part_id: None,
+            feature: shape.project_local_point_and_get_feature(pt).1,
+            barycentric_coordinates: None,
+        },
+    }
+}
@@ -18,8 +18,12 @@ pub use self::closest_points_segment_segment::{
     closest_points_segment_segment_with_locations_nD,
 };
 pub use self::closest_points_shape_shape::closest_points;
+pub use self::closest_points_slab_support_map::{
+    closest_points_slab_support_map, closest_points_support_map_slab,
+};
 pub use self::closest_points_support_map_support_map::closest_points_support_map_support_map;
 pub use self::closest_points_support_map_support_map::closest_points_support_map_support_map_with_params;
+pub use self::closest_points_with_features::{closest_points_with_features, ClosestPointFeature};
 
 mod closest_points;
 mod closest_points_ball_ball;
@@ -28,4 +32,6 @@ mod closest_points_halfspace_support_map;
 mod closest_points_line_line;
 mod closest_points_segment_segment;
 mod closest_points_shape_shape;
+mod closest_points_slab_support_map;
 mod closest_points_support_map_support_map;
+mod closest_points_with_features;
@@ -0,0 +1,33 @@
+use crate::math::{Isometry, Real};
+use crate::query::Contact;
+use crate::shape::{Slab, SupportMap};
+
+/// Contact between a slab and a support-mapped shape (Cuboid, ConvexHull, etc.)
+pub fn contact_slab_support_map<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    slab: &Slab,
+    other: &G,
+    prediction: Real,
+) -> Option<Contact> {
+    let (normal1, deepest, distance) = slab.separating_side(pos12, other);
+
+    if distance <= prediction {
+        let point1 = deepest - normal1.into_inner() * distance;
+        let point2 = pos12.inverse_transform_point(&deepest);
+        let normal2 = pos12.inverse_transform_unit_vector(&-normal1);
+
+        Some(Contact::new(point1, point2, normal1, normal2, distance))
+    } else {
+        None
+    }
+}
+
+/// Contact between a support-mapped shape (Cuboid, ConvexHull, etc.) and a slab.
+pub fn contact_support_map_slab<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    other: &G,
+    slab: &Slab,
+    prediction: Real,
+) -> Option<Contact> {
+    contact_slab_support_map(&pos12.inverse(), slab, other, prediction).map(|c| c.flipped())
+}
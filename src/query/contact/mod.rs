@@ -12,6 +12,7 @@ pub use self::contact_halfspace_support_map::{
     contact_halfspace_support_map, contact_support_map_halfspace,
 };
 pub use self::contact_shape_shape::contact;
+pub use self::contact_slab_support_map::{contact_slab_support_map, contact_support_map_slab};
 pub use self::contact_support_map_support_map::contact_support_map_support_map;
 pub use self::contact_support_map_support_map::contact_support_map_support_map_with_params;
 
@@ -21,4 +22,5 @@ mod contact_ball_convex_polyhedron;
 mod contact_composite_shape_shape;
 mod contact_halfspace_support_map;
 mod contact_shape_shape;
+mod contact_slab_support_map;
 mod contact_support_map_support_map;
@@ -0,0 +1,113 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{Isometry, Real};
+use crate::query::query_dispatcher::PersistentQueryDispatcher;
+use crate::query::visitors::BoundingVolumeIntersectionsVisitor;
+use crate::query::{ContactManifold, TrackedContact};
+use crate::shape::{Shape, SimdCompositeShape};
+use crate::utils::IsometryOpt;
+use na::ComplexField;
+
+/// Computes a single contact manifold between a convex shape and a composite shape.
+///
+/// Contrary to `contact_manifolds_composite_shape_shape` which outputs one manifold per
+/// part of the composite shape, this accumulates the contacts with all the parts of
+/// `composite2` closer than `prediction` to `convex1` into the single `manifold`.
+/// Contact points closer than `sqrt(DEFAULT_EPSILON)` to an already accumulated point
+/// (e.g. at the shared edge of two adjacent triangles) are merged, keeping the deepest one.
+///
+/// The contact normal of the manifold is the normal of the deepest contact. The points
+/// `local_p2` of the contacts, as well as `local_n2`, are expressed in the local-space of
+/// `composite2` (not the local-space of its parts).
+pub fn contact_manifold_convex_composite<ManifoldData, ContactData>(
+    dispatcher: &dyn PersistentQueryDispatcher<ManifoldData, ContactData>,
+    pos12: &Isometry<Real>,
+    convex1: &dyn Shape,
+    composite2: &dyn SimdCompositeShape,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    ManifoldData: Default,
+    ContactData: Default + Copy,
+{
+    let merge_threshold = ComplexField::sqrt(crate::math::DEFAULT_EPSILON);
+    let pos21 = pos12.inverse();
+    let ls_aabb1_2 = convex1.compute_aabb(&pos21).loosened(prediction);
+
+    let old_manifold_points = manifold.points.clone();
+    manifold.clear();
+
+    let mut deepest_dist = Real::MAX;
+    let mut sub_manifold = ContactManifold::<ManifoldData, ContactData>::new();
+
+    let mut leaf2_fn = |leaf2: &u32| {
+        composite2.map_part_at(*leaf2, &mut |part_pos2, part_shape2| {
+            sub_manifold.clear();
+            sub_manifold.local_n1.fill(0.0);
+
+            let _ = dispatcher.contact_manifold_convex_convex(
+                &part_pos2.prepend_to(pos12),
+                convex1,
+                part_shape2,
+                prediction,
+                &mut sub_manifold,
+            );
+
+            for contact in &sub_manifold.points {
+                let mut contact = *contact;
+                contact.local_p2 = part_pos2.transform_point(&contact.local_p2);
+
+                if contact.dist < deepest_dist {
+                    deepest_dist = contact.dist;
+                    manifold.local_n1 = sub_manifold.local_n1;
+                    manifold.local_n2 = part_pos2.transform_vector(&sub_manifold.local_n2);
+                }
+
+                push_merged_contact(manifold, contact, merge_threshold);
+            }
+        });
+
+        true
+    };
+
+    let mut visitor2 = BoundingVolumeIntersectionsVisitor::new(&ls_aabb1_2, &mut leaf2_fn);
+    composite2.quadtree().traverse_depth_first(&mut visitor2);
+
+    // Transfer impulses.
+    manifold.match_contacts_using_positions(&old_manifold_points, merge_threshold);
+}
+
+fn push_merged_contact<ManifoldData, ContactData: Default + Copy>(
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    contact: TrackedContact<ContactData>,
+    merge_threshold: Real,
+) {
+    let sq_threshold = merge_threshold * merge_threshold;
+
+    for existing in &mut manifold.points {
+        if na::distance_squared(&existing.local_p1, &contact.local_p1) < sq_threshold {
+            if contact.dist < existing.dist {
+                existing.copy_geometry_from(contact);
+            }
+
+            return;
+        }
+    }
+
+    // In 2D, a manifold contains at most two points: keep the deepest ones.
+    #[cfg(feature = "dim2")]
+    if manifold.points.is_full() {
+        let shallowest = manifold
+            .points
+            .iter_mut()
+            .max_by(|a, b| a.dist.partial_cmp(&b.dist).unwrap())
+            .unwrap();
+
+        if contact.dist < shallowest.dist {
+            *shallowest = contact;
+        }
+
+        return;
+    }
+
+    manifold.points.push(contact);
+}
@@ -0,0 +1,106 @@
+use crate::math::{Isometry, Real};
+use crate::query::{ContactManifold, TrackedContact};
+use crate::shape::{PolygonalFeature, Shape, Slab};
+
+/// Computes the contact manifold between a slab and a convex shape, both represented as `Shape` trait-objects.
+pub fn contact_manifold_slab_shape_shapes<ManifoldData, ContactData>(
+    pos12: &Isometry<Real>,
+    shape1: &dyn Shape,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    ContactData: Default + Copy,
+{
+    if let Some(slab1) = shape1.as_shape::<Slab>() {
+        contact_manifold_slab_shape(pos12, slab1, shape2, prediction, manifold, false);
+    } else if let Some(slab2) = shape2.as_shape::<Slab>() {
+        contact_manifold_slab_shape(&pos12.inverse(), slab2, shape1, prediction, manifold, true);
+    }
+}
+
+/// Computes the contact manifold between a slab and a support-mapped shape.
+///
+/// If the shape also has a polygonal feature map, one contact is generated for each vertex of its
+/// support feature close enough to the slab. Otherwise, a single contact is generated.
+pub fn contact_manifold_slab_shape<ManifoldData, ContactData, S2>(
+    pos12: &Isometry<Real>,
+    slab1: &Slab,
+    shape2: &S2,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    flipped: bool,
+) where
+    S2: ?Sized + Shape,
+    ContactData: Default + Copy,
+{
+    let support_map2 = match shape2.as_support_map() {
+        Some(support_map2) => support_map2,
+        None => return,
+    };
+
+    let (local_n1, deepest, dist) = slab1.separating_side(pos12, support_map2);
+    let old_manifold_points = manifold.points.clone();
+    manifold.clear();
+
+    if dist > prediction {
+        return;
+    }
+
+    let local_n2 = pos12.inverse_transform_unit_vector(&-local_n1);
+    // The face 0 of the slab has the normal `slab1.normal`, the face 1 has the normal `-slab1.normal`.
+    let fid1 = if local_n1.dot(&slab1.normal) > 0.0 {
+        0
+    } else {
+        1
+    };
+
+    if let Some((pfm2, border_radius2)) = shape2.as_polygonal_feature_map() {
+        let mut feature2 = PolygonalFeature::default();
+        pfm2.local_support_feature(&local_n2, &mut feature2);
+
+        for i in 0..feature2.num_vertices {
+            let local_p2 = feature2.vertices[i] + *local_n2 * border_radius2;
+            let p2_1 = pos12 * local_p2;
+            let dist = local_n1.dot(&p2_1.coords) - slab1.half_thickness;
+
+            if dist <= prediction {
+                let local_p1 = p2_1 - *local_n1 * dist;
+                let contact = TrackedContact::flipped(
+                    local_p1,
+                    local_p2,
+                    fid1,
+                    feature2.vids[i],
+                    dist,
+                    flipped,
+                );
+                manifold.points.push(contact);
+            }
+        }
+    }
+
+    if manifold.points.is_empty() {
+        let local_p1 = deepest - *local_n1 * dist;
+        let local_p2 = pos12.inverse_transform_point(&deepest);
+        let contact = TrackedContact::flipped(
+            local_p1,
+            local_p2,
+            fid1,
+            u32::MAX, // We don't know what feature is involved.
+            dist,
+            flipped,
+        );
+        manifold.points.push(contact);
+    }
+
+    if flipped {
+        manifold.local_n1 = *local_n2;
+        manifold.local_n2 = *local_n1;
+    } else {
+        manifold.local_n1 = *local_n1;
+        manifold.local_n2 = *local_n2;
+    }
+
+    // Transfer impulses.
+    manifold.match_contacts(&old_manifold_points);
+}
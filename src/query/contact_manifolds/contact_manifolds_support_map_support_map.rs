@@ -0,0 +1,214 @@
+use crate::math::{Isometry, Point, Real, Rotation, Vector};
+use crate::query::{ContactManifold, TrackedContact};
+use crate::shape::{Shape, SupportMap};
+#[cfg(feature = "dim3")]
+use crate::utils::WBasis;
+#[cfg(feature = "dim2")]
+use arrayvec::ArrayVec;
+
+// Angle of the small rotations applied to the second shape to find additional contact points.
+const PERTURBATION_ANGLE: Real = 0.01;
+// Contact points closer than this are merged.
+const MERGE_DIST: Real = 1.0e-3;
+
+/// Computes the contact manifold between two convex shapes implementing `SupportMap`, both represented as `Shape` trait-objects.
+pub fn contact_manifold_support_map_support_map_shapes<ManifoldData, ContactData>(
+    pos12: &Isometry<Real>,
+    shape1: &dyn Shape,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    ContactData: Default + Copy,
+{
+    if let (Some(sm1), Some(sm2)) = (shape1.as_support_map(), shape2.as_support_map()) {
+        contact_manifold_support_map_support_map(pos12, sm1, sm2, prediction, manifold);
+    }
+}
+
+/// Computes the contact manifold between two convex shapes implementing `SupportMap`.
+///
+/// The deepest contact is computed with GJK/EPA. Additional contacts are then found by
+/// slightly rotating the second shape around the deepest contact point, about axes
+/// orthogonal to the contact normal. This yields several contact points when the
+/// shapes touch along a segment or a face, even if none of them implements
+/// `PolygonalFeatureMap`.
+pub fn contact_manifold_support_map_support_map<ManifoldData, ContactData, S1, S2>(
+    pos12: &Isometry<Real>,
+    shape1: &S1,
+    shape2: &S2,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    S1: ?Sized + SupportMap,
+    S2: ?Sized + SupportMap,
+    ContactData: Default + Copy,
+{
+    let contact = match crate::query::details::contact_support_map_support_map(
+        pos12, shape1, shape2, prediction,
+    ) {
+        Some(contact) => contact,
+        None => {
+            manifold.clear();
+            return;
+        }
+    };
+
+    let n1 = contact.normal1;
+    let mut points = vec![TrackedContact::<ContactData>::new(
+        contact.point1,
+        contact.point2,
+        0,
+        0,
+        contact.dist,
+    )];
+
+    #[cfg(feature = "dim2")]
+    let perturbations = [
+        Rotation::new(PERTURBATION_ANGLE),
+        Rotation::new(-PERTURBATION_ANGLE),
+    ];
+    #[cfg(feature = "dim3")]
+    let perturbations = {
+        // Rotation axes evenly distributed on the tangent plane so that both the
+        // edges and the vertices of a polygonal contact area are found.
+        let [t1, t2] = n1.orthonormal_basis();
+        let diag1 = (t1 + t2).normalize();
+        let diag2 = (t1 - t2).normalize();
+        let mut rots = [Rotation::identity(); 8];
+
+        for (i, axis) in [t1, t2, diag1, diag2].iter().enumerate() {
+            rots[i * 2] = Rotation::new(axis * PERTURBATION_ANGLE);
+            rots[i * 2 + 1] = Rotation::new(axis * -PERTURBATION_ANGLE);
+        }
+
+        rots
+    };
+
+    for (i, rot) in perturbations.iter().enumerate() {
+        let perturbed_pos12 = Isometry::rotation_wrt_point(*rot, contact.point1) * pos12;
+
+        if let Some(perturbed) = crate::query::details::contact_support_map_support_map(
+            &perturbed_pos12,
+            shape1,
+            shape2,
+            prediction,
+        ) {
+            // Measure the point found on the second shape against the tangent
+            // plane of the first shape at the deepest contact, in the unperturbed
+            // configuration.
+            let local_p2 = perturbed.point2;
+            let p2_1 = pos12 * local_p2;
+            let dist = (p2_1 - contact.point1).dot(&n1);
+            let local_p1 = p2_1 - *n1 * dist;
+
+            if dist <= prediction
+                && points
+                    .iter()
+                    .all(|pt| na::distance(&pt.local_p1, &local_p1) > MERGE_DIST)
+            {
+                let fid = i as u32 + 1;
+                points.push(TrackedContact::new(local_p1, local_p2, fid, fid, dist));
+            }
+        }
+    }
+
+    let mut points = reduce_points(points, &n1);
+
+    // Keep the user data of the contacts that were already present.
+    for pt in &mut points {
+        if let Some(old) = manifold
+            .points
+            .iter()
+            .find(|old| old.fid1 == pt.fid1 && old.fid2 == pt.fid2)
+        {
+            pt.data = old.data;
+        }
+    }
+
+    manifold.clear();
+    manifold.points.extend(points);
+    manifold.local_n1 = *n1;
+    manifold.local_n2 = *contact.normal2;
+}
+
+// Selects the points spanning the largest contact area: at most two in 2D, and four in 3D.
+#[cfg(feature = "dim2")]
+fn reduce_points<Data: Copy>(
+    points: Vec<TrackedContact<Data>>,
+    _n1: &Vector<Real>,
+) -> ArrayVec<[TrackedContact<Data>; 2]> {
+    let mut result = ArrayVec::new();
+    let a = farthest_point(&points, &points[0].local_p1);
+    result.push(points[a]);
+
+    if points.len() > 1 {
+        let b = farthest_point(&points, &points[a].local_p1);
+        result.push(points[b]);
+    }
+
+    result
+}
+
+// Selects the points spanning the largest contact area: at most two in 2D, and four in 3D.
+#[cfg(feature = "dim3")]
+fn reduce_points<Data: Copy>(
+    points: Vec<TrackedContact<Data>>,
+    n1: &Vector<Real>,
+) -> Vec<TrackedContact<Data>> {
+    if points.len() <= 2 {
+        return points;
+    }
+
+    let a = farthest_point(&points, &points[0].local_p1);
+    let b = farthest_point(&points, &points[a].local_p1);
+    let pa = points[a].local_p1;
+    let ab = points[b].local_p1 - pa;
+    let mut result = vec![points[a], points[b]];
+
+    // Signed area of the triangle formed by `a`, `b`, and each point.
+    let areas: Vec<_> = points
+        .iter()
+        .map(|pt| ab.cross(&(pt.local_p1 - pa)).dot(n1))
+        .collect();
+    // Ignore the points too close to the segment `ab`.
+    let min_area = MERGE_DIST * ab.norm();
+
+    let (c, area_c) = areas
+        .iter()
+        .enumerate()
+        .max_by(|x, y| x.1.abs().partial_cmp(&y.1.abs()).unwrap())
+        .unwrap();
+
+    if area_c.abs() > min_area {
+        result.push(points[c]);
+
+        let (d, area_d) = areas
+            .iter()
+            .enumerate()
+            .max_by(|x, y| (-x.1 * area_c).partial_cmp(&(-y.1 * area_c)).unwrap())
+            .unwrap();
+
+        if -area_d * area_c.signum() > min_area {
+            result.push(points[d]);
+        }
+    }
+
+    result
+}
+
+fn farthest_point<Data>(points: &[TrackedContact<Data>], from: &Point<Real>) -> usize {
+    let mut best = 0;
+    let mut best_dist = -1.0;
+
+    for (i, pt) in points.iter().enumerate() {
+        let dist = na::distance_squared(&pt.local_p1, from);
+
+        if dist > best_dist {
+            best = i;
+            best_dist = dist;
+        }
+    }
+
+    best
+}
@@ -13,6 +13,7 @@ pub use self::contact_manifolds_convex_ball::{
 // };
 pub use self::contact_manifolds_composite_shape_composite_shape::contact_manifolds_composite_shape_composite_shape;
 pub use self::contact_manifolds_composite_shape_shape::contact_manifolds_composite_shape_shape;
+pub use self::contact_manifolds_convex_composite::contact_manifold_convex_composite;
 pub use self::contact_manifolds_cuboid_cuboid::{
     contact_manifold_cuboid_cuboid, contact_manifold_cuboid_cuboid_shapes,
 };
@@ -26,10 +27,17 @@ pub use self::contact_manifolds_heightfield_shape::{
 pub use self::contact_manifolds_pfm_pfm::{
     contact_manifold_pfm_pfm, contact_manifold_pfm_pfm_shapes,
 };
+pub use self::contact_manifolds_slab_shape::{
+    contact_manifold_slab_shape, contact_manifold_slab_shape_shapes,
+};
+pub use self::contact_manifolds_support_map_support_map::{
+    contact_manifold_support_map_support_map, contact_manifold_support_map_support_map_shapes,
+};
 pub use self::contact_manifolds_trimesh_shape::{
     contact_manifolds_trimesh_shape, contact_manifolds_trimesh_shape_shapes,
 };
 pub use self::contact_manifolds_workspace::ContactManifoldsWorkspace;
+pub use self::persistent_contact_manifolds::PersistentContactManifolds;
 
 #[cfg(feature = "serde-serialize")]
 pub(self) use {
@@ -48,10 +56,14 @@ mod contact_manifolds_convex_ball;
 // mod contact_manifolds_cuboid_capsule;
 mod contact_manifolds_composite_shape_composite_shape;
 mod contact_manifolds_composite_shape_shape;
+mod contact_manifolds_convex_composite;
 mod contact_manifolds_cuboid_cuboid;
 mod contact_manifolds_cuboid_triangle;
 mod contact_manifolds_heightfield_composite_shape;
 mod contact_manifolds_heightfield_shape;
 mod contact_manifolds_pfm_pfm;
+mod contact_manifolds_slab_shape;
+mod contact_manifolds_support_map_support_map;
 mod contact_manifolds_trimesh_shape;
 mod contact_manifolds_workspace;
+mod persistent_contact_manifolds;
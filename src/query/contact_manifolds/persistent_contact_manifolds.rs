@@ -0,0 +1,110 @@
+use crate::math::{Isometry, Real};
+use crate::query::{
+    ContactManifold, ContactManifoldsWorkspace, PersistentQueryDispatcher, TrackedContact,
+    Unsupported,
+};
+use crate::shape::Shape;
+use crate::utils::hashmap::HashMap;
+
+/// The contact manifolds between two shapes, kept up-to-date from one frame to the next.
+///
+/// This bundles the contact manifolds and the workspace needed by
+/// `PersistentQueryDispatcher::contact_manifolds`. Each call to `update` recomputes the
+/// manifolds and transfers the data (e.g. the warmstart impulses) of the contacts of the
+/// previous update to the new contacts having the same subshapes and feature ids.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct PersistentContactManifolds<ManifoldData, ContactData> {
+    /// The contact manifolds computed by the last update.
+    ///
+    /// Some of them may be empty, they are kept to benefit from spatial coherence.
+    pub manifolds: Vec<ContactManifold<ManifoldData, ContactData>>,
+    /// The workspace used by the contact manifolds computation.
+    pub workspace: Option<ContactManifoldsWorkspace>,
+}
+
+impl<ManifoldData, ContactData> Default for PersistentContactManifolds<ManifoldData, ContactData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ManifoldData, ContactData> PersistentContactManifolds<ManifoldData, ContactData> {
+    /// Creates an empty set of contact manifolds.
+    pub fn new() -> Self {
+        Self {
+            manifolds: Vec::new(),
+            workspace: None,
+        }
+    }
+
+    /// Removes all the contact manifolds, as well as the workspace.
+    ///
+    /// This should be called when the shapes this set was updated with are replaced.
+    pub fn clear(&mut self) {
+        self.manifolds.clear();
+        self.workspace = None;
+    }
+
+    /// Iterates through the contact manifolds having at least one contact point.
+    pub fn active_manifolds(
+        &self,
+    ) -> impl Iterator<Item = &ContactManifold<ManifoldData, ContactData>> {
+        self.manifolds.iter().filter(|m| !m.points.is_empty())
+    }
+
+    /// The total number of contact points.
+    pub fn num_contacts(&self) -> usize {
+        self.manifolds.iter().map(|m| m.points.len()).sum()
+    }
+}
+
+impl<ManifoldData, ContactData: Default + Copy>
+    PersistentContactManifolds<ManifoldData, ContactData>
+{
+    /// Updates the contact manifolds between `g1` and `g2`.
+    ///
+    /// The data of the contacts computed by the previous update are copied to the new
+    /// contacts with the same subshapes and the same feature ids. The other contacts keep
+    /// the data set by the contact manifolds computation.
+    pub fn update<D>(
+        &mut self,
+        dispatcher: &D,
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        prediction: Real,
+    ) -> Result<(), Unsupported>
+    where
+        D: ?Sized + PersistentQueryDispatcher<ManifoldData, ContactData>,
+    {
+        let mut old_contacts: HashMap<(u32, u32), Vec<TrackedContact<ContactData>>> =
+            HashMap::default();
+
+        for manifold in &self.manifolds {
+            if !manifold.points.is_empty() {
+                let _ = old_contacts.insert(
+                    (manifold.subshape1, manifold.subshape2),
+                    manifold.points.to_vec(),
+                );
+            }
+        }
+
+        dispatcher.contact_manifolds(
+            pos12,
+            g1,
+            g2,
+            prediction,
+            &mut self.manifolds,
+            &mut self.workspace,
+        )?;
+
+        for manifold in &mut self.manifolds {
+            if let Some(old) = old_contacts.get(&(manifold.subshape1, manifold.subshape2)) {
+                manifold.match_contacts(old);
+            }
+        }
+
+        Ok(())
+    }
+}
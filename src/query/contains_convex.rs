@@ -0,0 +1,74 @@
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::query::Unsupported;
+use crate::shape::Shape;
+use na::{ComplexField, RealField};
+
+/// The number of directions sampled by `contains_convex`.
+const NUM_SAMPLES: usize = 128;
+
+/// Tests whether the shape `g1` fully contains the convex shape `g2`.
+///
+/// This evaluates the support points of `g2` along a fixed set of directions and checks
+/// that all of them lie inside of `g1`. Because only a finite number of directions are
+/// sampled, this is an approximation: it is exact if `g2` is a polyhedral shape with
+/// vertices reached by the sampled directions (e.g. a cuboid) and `g1` is a convex polyhedral
+/// shape. If `g2` is curved (e.g. a ball), a small part of it may poke out of `g1` between
+/// two sampled support points while this still returns `true`.
+///
+/// Returns `Err(Unsupported)` if `g2` is not a support-mapped shape.
+pub fn contains_convex(
+    pos1: &Isometry<Real>,
+    g1: &dyn Shape,
+    pos2: &Isometry<Real>,
+    g2: &dyn Shape,
+) -> Result<bool, Unsupported> {
+    let s2 = g2.as_support_map().ok_or(Unsupported)?;
+    let pos12 = pos1.inv_mul(pos2);
+
+    for dir in sample_directions() {
+        let pt: Point<Real> = s2.support_point(&pos12, &dir);
+
+        if !g1.contains_local_point(&pt) {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(feature = "dim2")]
+fn sample_directions() -> impl Iterator<Item = Vector<Real>> {
+    // NOTE: NUM_SAMPLES is a multiple of 8 so the coordinate
+    // axes and diagonals are always part of the samples.
+    (0..NUM_SAMPLES).map(|i| {
+        let angle = Real::two_pi() * (i as Real) / (NUM_SAMPLES as Real);
+        Vector::new(ComplexField::cos(angle), ComplexField::sin(angle))
+    })
+}
+
+#[cfg(feature = "dim3")]
+fn sample_directions() -> impl Iterator<Item = Vector<Real>> {
+    // The 26 directions of the form (±1 or 0, ±1 or 0, ±1 or 0)
+    // so the vertices, edges and faces of axis-aligned boxes are
+    // always hit, followed by a Fibonacci lattice on the unit sphere.
+    let grid = (0..27).filter(|i| *i != 13).map(|i| {
+        Vector::new(
+            (i % 3) as Real - 1.0,
+            ((i / 3) % 3) as Real - 1.0,
+            (i / 9) as Real - 1.0,
+        )
+    });
+    let golden_angle = Real::pi() * (3.0 - ComplexField::sqrt(5.0));
+    let lattice = (0..NUM_SAMPLES).map(move |i| {
+        let y = 1.0 - 2.0 * (i as Real + 0.5) / (NUM_SAMPLES as Real);
+        let r = ComplexField::sqrt(1.0 - y * y);
+        let theta = golden_angle * (i as Real);
+        Vector::new(
+            ComplexField::cos(theta) * r,
+            y,
+            ComplexField::sin(theta) * r,
+        )
+    });
+
+    grid.chain(lattice)
+}
@@ -1,11 +1,84 @@
 use crate::math::{Isometry, Point, Real, Vector};
-use crate::motion::RigidMotion;
+use crate::motion::{RigidMotion, RigidMotionComposition};
 use crate::query::contact_manifolds::ContactManifoldsWorkspace;
 use crate::query::query_dispatcher::PersistentQueryDispatcher;
 use crate::query::{
     self, ClosestPoints, Contact, ContactManifold, QueryDispatcher, Unsupported, TOI,
 };
-use crate::shape::{HalfSpace, Segment, Shape, ShapeType};
+use crate::shape::{HalfSpace, Segment, Shape, ShapeType, Slab, SupportMap, TypedShape};
+
+// Gives the query dispatchers of the custom shapes involved in a query the
+// first chance to handle it, before falling back to the builtin queries.
+macro_rules! dispatch_to_custom_shape {
+    ($shape1: expr, $shape2: expr, $method: ident($($arg: expr),*)) => {
+        for dispatcher in custom_query_dispatchers($shape1, $shape2).iter().flatten() {
+            match dispatcher.$method($($arg),*) {
+                Err(Unsupported) => {}
+                result => return result,
+            }
+        }
+    };
+}
+
+// The query dispatchers of the first and second shapes, if they are custom shapes.
+fn custom_query_dispatchers<'a>(
+    shape1: &'a dyn Shape,
+    shape2: &'a dyn Shape,
+) -> [Option<&'a dyn QueryDispatcher>; 2] {
+    [
+        shape1.as_custom().map(|custom| custom.query_dispatcher()),
+        shape2.as_custom().map(|custom| custom.query_dispatcher()),
+    ]
+}
+
+// The support map of the convex hull of `shape`, if it has one.
+//
+// The queries between a halfspace and a shape only depend on the convex hull of the
+// shape, so they also work for the non-convex shapes with a support map of their hull.
+fn convex_hull_support_map(shape: &dyn Shape) -> Option<&dyn SupportMap> {
+    shape
+        .as_support_map()
+        .or_else(|| match shape.as_typed_shape() {
+            TypedShape::HeightField(heightfield) => Some(heightfield as &dyn SupportMap),
+            #[cfg(feature = "dim3")]
+            TypedShape::Torus(torus) => Some(torus as &dyn SupportMap),
+            #[cfg(feature = "dim3")]
+            TypedShape::SphericalSector(sector) => Some(sector as &dyn SupportMap),
+            _ => None,
+        })
+}
+
+// The position of the subshape `subshape` of `shape` relative to `shape`, as recorded by the
+// contact manifolds involving this shape.
+fn subshape_pos(shape: &dyn Shape, subshape: u32) -> Option<Isometry<Real>> {
+    if let Some((pos, inner)) = shape.as_transformed_shape() {
+        Some(subshape_pos(inner, subshape).map_or(*pos, |sub_pos| pos * sub_pos))
+    } else {
+        let mut result = None;
+
+        if let Some(composite) = shape.as_composite_shape() {
+            composite.map_part_at(subshape, &mut |part_pos, _| result = part_pos.copied());
+        }
+
+        result
+    }
+}
+
+// Transforms the contact points and normal of `manifold` on the first shape by `pos1`, and
+// those on the second shape by `pos2`.
+fn transform_manifold<ManifoldData, ContactData>(
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    pos1: &Isometry<Real>,
+    pos2: &Isometry<Real>,
+) {
+    manifold.local_n1 = pos1 * manifold.local_n1;
+    manifold.local_n2 = pos2 * manifold.local_n2;
+
+    for pt in &mut manifold.points {
+        pt.local_p1 = pos1 * pt.local_p1;
+        pt.local_p2 = pos2 * pt.local_p2;
+    }
+}
 
 /// A dispatcher that exposes built-in queries
 #[derive(Debug, Clone)]
@@ -18,6 +91,14 @@ impl QueryDispatcher for DefaultQueryDispatcher {
         shape1: &dyn Shape,
         shape2: &dyn Shape,
     ) -> Result<bool, Unsupported> {
+        dispatch_to_custom_shape!(shape1, shape2, intersection_test(pos12, shape1, shape2));
+
+        if let Some((pos1, inner1)) = shape1.as_transformed_shape() {
+            return self.intersection_test(&pos1.inv_mul(pos12), inner1, shape2);
+        } else if let Some((pos2, inner2)) = shape2.as_transformed_shape() {
+            return self.intersection_test(&(pos12 * pos2), shape1, inner2);
+        }
+
         if let (Some(b1), Some(b2)) = (shape1.as_ball(), shape2.as_ball()) {
             let p12 = Point::from(pos12.translation.vector);
             Ok(query::details::intersection_test_ball_ball(&p12, b1, b2))
@@ -41,18 +122,28 @@ impl QueryDispatcher for DefaultQueryDispatcher {
             Ok(query::details::intersection_test_point_query_ball(
                 &pos12, shape1, b2,
             ))
-        } else if let (Some(p1), Some(s2)) =
-            (shape1.as_shape::<HalfSpace>(), shape2.as_support_map())
-        {
+        } else if let (Some(p1), Some(s2)) = (
+            shape1.as_shape::<HalfSpace>(),
+            convex_hull_support_map(shape2),
+        ) {
             Ok(query::details::intersection_test_halfspace_support_map(
                 pos12, p1, s2,
             ))
-        } else if let (Some(s1), Some(p2)) =
-            (shape1.as_support_map(), shape2.as_shape::<HalfSpace>())
-        {
+        } else if let (Some(s1), Some(p2)) = (
+            convex_hull_support_map(shape1),
+            shape2.as_shape::<HalfSpace>(),
+        ) {
             Ok(query::details::intersection_test_support_map_halfspace(
                 pos12, s1, p2,
             ))
+        } else if let (Some(p1), Some(s2)) = (shape1.as_shape::<Slab>(), shape2.as_support_map()) {
+            Ok(query::details::intersection_test_slab_support_map(
+                pos12, p1, s2,
+            ))
+        } else if let (Some(s1), Some(p2)) = (shape1.as_support_map(), shape2.as_shape::<Slab>()) {
+            Ok(query::details::intersection_test_support_map_slab(
+                pos12, s1, p2,
+            ))
         } else if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
             Ok(query::details::intersection_test_support_map_support_map(
                 pos12, s1, s2,
@@ -79,33 +170,63 @@ impl QueryDispatcher for DefaultQueryDispatcher {
         shape1: &dyn Shape,
         shape2: &dyn Shape,
     ) -> Result<Real, Unsupported> {
+        self.distance_with_max_dist(pos12, shape1, shape2, Real::MAX)
+    }
+
+    fn distance_with_max_dist(
+        &self,
+        pos12: &Isometry<Real>,
+        shape1: &dyn Shape,
+        shape2: &dyn Shape,
+        max_dist: Real,
+    ) -> Result<Real, Unsupported> {
+        dispatch_to_custom_shape!(
+            shape1,
+            shape2,
+            distance_with_max_dist(pos12, shape1, shape2, max_dist)
+        );
+
+        if let Some((pos1, inner1)) = shape1.as_transformed_shape() {
+            return self.distance_with_max_dist(&pos1.inv_mul(pos12), inner1, shape2, max_dist);
+        } else if let Some((pos2, inner2)) = shape2.as_transformed_shape() {
+            return self.distance_with_max_dist(&(pos12 * pos2), shape1, inner2, max_dist);
+        }
+
         if let (Some(b1), Some(b2)) = (shape1.as_ball(), shape2.as_ball()) {
             let p2 = Point::from(pos12.translation.vector);
-            Ok(query::details::distance_ball_ball(b1, &p2, b2))
-        } else if let (Some(p1), Some(s2)) =
-            (shape1.as_shape::<HalfSpace>(), shape2.as_support_map())
-        {
-            Ok(query::details::distance_halfspace_support_map(
-                pos12, p1, s2,
-            ))
-        } else if let (Some(s1), Some(p2)) =
-            (shape1.as_support_map(), shape2.as_shape::<HalfSpace>())
-        {
-            Ok(query::details::distance_support_map_halfspace(
-                pos12, s1, p2,
-            ))
+            Ok(query::details::distance_ball_ball(b1, &p2, b2).min(max_dist))
+        } else if let (Some(p1), Some(s2)) = (
+            shape1.as_shape::<HalfSpace>(),
+            convex_hull_support_map(shape2),
+        ) {
+            Ok(query::details::distance_halfspace_support_map(pos12, p1, s2).min(max_dist))
+        } else if let (Some(s1), Some(p2)) = (
+            convex_hull_support_map(shape1),
+            shape2.as_shape::<HalfSpace>(),
+        ) {
+            Ok(query::details::distance_support_map_halfspace(pos12, s1, p2).min(max_dist))
+        } else if let (Some(p1), Some(s2)) = (shape1.as_shape::<Slab>(), shape2.as_support_map()) {
+            Ok(query::details::distance_slab_support_map(pos12, p1, s2).min(max_dist))
+        } else if let (Some(s1), Some(p2)) = (shape1.as_support_map(), shape2.as_shape::<Slab>()) {
+            Ok(query::details::distance_support_map_slab(pos12, s1, p2).min(max_dist))
         } else if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
-            Ok(query::details::distance_support_map_support_map(
-                pos12, s1, s2,
-            ))
+            Ok(
+                query::details::distance_support_map_support_map_with_max_dist(
+                    pos12, s1, s2, max_dist,
+                ),
+            )
         } else if let Some(c1) = shape1.as_composite_shape() {
-            Ok(query::details::distance_composite_shape_shape(
-                self, pos12, c1, shape2,
-            ))
+            Ok(
+                query::details::distance_composite_shape_shape_with_max_dist(
+                    self, pos12, c1, shape2, max_dist,
+                ),
+            )
         } else if let Some(c2) = shape2.as_composite_shape() {
-            Ok(query::details::distance_shape_composite_shape(
-                self, pos12, shape1, c2,
-            ))
+            Ok(
+                query::details::distance_shape_composite_shape_with_max_dist(
+                    self, pos12, shape1, c2, max_dist,
+                ),
+            )
         } else {
             Err(Unsupported)
         }
@@ -118,23 +239,49 @@ impl QueryDispatcher for DefaultQueryDispatcher {
         shape2: &dyn Shape,
         prediction: Real,
     ) -> Result<Option<Contact>, Unsupported> {
+        dispatch_to_custom_shape!(shape1, shape2, contact(pos12, shape1, shape2, prediction));
+
+        if let Some((pos1, inner1)) = shape1.as_transformed_shape() {
+            let contact = self.contact(&pos1.inv_mul(pos12), inner1, shape2, prediction)?;
+            return Ok(contact.map(|mut c| {
+                c.transform1_by_mut(pos1);
+                c
+            }));
+        } else if let Some((pos2, inner2)) = shape2.as_transformed_shape() {
+            let contact = self.contact(&(pos12 * pos2), shape1, inner2, prediction)?;
+            return Ok(contact.map(|mut c| {
+                c.transform_by_mut(&Isometry::identity(), pos2);
+                c
+            }));
+        }
+
         let ball1 = shape1.as_ball();
         let ball2 = shape2.as_ball();
 
         if let (Some(b1), Some(b2)) = (ball1, ball2) {
             Ok(query::details::contact_ball_ball(pos12, b1, b2, prediction))
-        } else if let (Some(p1), Some(s2)) =
-            (shape1.as_shape::<HalfSpace>(), shape2.as_support_map())
-        {
+        } else if let (Some(p1), Some(s2)) = (
+            shape1.as_shape::<HalfSpace>(),
+            convex_hull_support_map(shape2),
+        ) {
             Ok(query::details::contact_halfspace_support_map(
                 pos12, p1, s2, prediction,
             ))
-        } else if let (Some(s1), Some(p2)) =
-            (shape1.as_support_map(), shape2.as_shape::<HalfSpace>())
-        {
+        } else if let (Some(s1), Some(p2)) = (
+            convex_hull_support_map(shape1),
+            shape2.as_shape::<HalfSpace>(),
+        ) {
             Ok(query::details::contact_support_map_halfspace(
                 pos12, s1, p2, prediction,
             ))
+        } else if let (Some(p1), Some(s2)) = (shape1.as_shape::<Slab>(), shape2.as_support_map()) {
+            Ok(query::details::contact_slab_support_map(
+                pos12, p1, s2, prediction,
+            ))
+        } else if let (Some(s1), Some(p2)) = (shape1.as_support_map(), shape2.as_shape::<Slab>()) {
+            Ok(query::details::contact_support_map_slab(
+                pos12, s1, p2, prediction,
+            ))
         } else if let (Some(b1), true) = (ball1, shape2.is_convex()) {
             Ok(query::details::contact_ball_convex_polyhedron(
                 pos12, b1, shape2, prediction,
@@ -167,6 +314,20 @@ impl QueryDispatcher for DefaultQueryDispatcher {
         shape2: &dyn Shape,
         max_dist: Real,
     ) -> Result<ClosestPoints, Unsupported> {
+        dispatch_to_custom_shape!(
+            shape1,
+            shape2,
+            closest_points(pos12, shape1, shape2, max_dist)
+        );
+
+        if let Some((pos1, inner1)) = shape1.as_transformed_shape() {
+            let pts = self.closest_points(&pos1.inv_mul(pos12), inner1, shape2, max_dist)?;
+            return Ok(pts.transform_by(pos1, &Isometry::identity()));
+        } else if let Some((pos2, inner2)) = shape2.as_transformed_shape() {
+            let pts = self.closest_points(&(pos12 * pos2), shape1, inner2, max_dist)?;
+            return Ok(pts.transform_by(&Isometry::identity(), pos2));
+        }
+
         if let (Some(b1), Some(b2)) = (shape1.as_ball(), shape2.as_ball()) {
             Ok(query::details::closest_points_ball_ball(
                 &pos12, b1, b2, max_dist,
@@ -177,18 +338,28 @@ impl QueryDispatcher for DefaultQueryDispatcher {
             Ok(query::details::closest_points_segment_segment(
                 &pos12, s1, s2, max_dist,
             ))
-        } else if let (Some(p1), Some(s2)) =
-            (shape1.as_shape::<HalfSpace>(), shape2.as_support_map())
-        {
+        } else if let (Some(p1), Some(s2)) = (
+            shape1.as_shape::<HalfSpace>(),
+            convex_hull_support_map(shape2),
+        ) {
             Ok(query::details::closest_points_halfspace_support_map(
                 &pos12, p1, s2, max_dist,
             ))
-        } else if let (Some(s1), Some(p2)) =
-            (shape1.as_support_map(), shape2.as_shape::<HalfSpace>())
-        {
+        } else if let (Some(s1), Some(p2)) = (
+            convex_hull_support_map(shape1),
+            shape2.as_shape::<HalfSpace>(),
+        ) {
             Ok(query::details::closest_points_support_map_halfspace(
                 &pos12, s1, p2, max_dist,
             ))
+        } else if let (Some(p1), Some(s2)) = (shape1.as_shape::<Slab>(), shape2.as_support_map()) {
+            Ok(query::details::closest_points_slab_support_map(
+                &pos12, p1, s2, max_dist,
+            ))
+        } else if let (Some(s1), Some(p2)) = (shape1.as_support_map(), shape2.as_shape::<Slab>()) {
+            Ok(query::details::closest_points_support_map_slab(
+                &pos12, s1, p2, max_dist,
+            ))
         } else if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
             Ok(query::details::closest_points_support_map_support_map(
                 &pos12, s1, s2, max_dist,
@@ -215,6 +386,34 @@ impl QueryDispatcher for DefaultQueryDispatcher {
         max_toi: Real,
         target_distance: Real,
     ) -> Result<Option<TOI>, Unsupported> {
+        dispatch_to_custom_shape!(
+            shape1,
+            shape2,
+            time_of_impact(pos12, vel12, shape1, shape2, max_toi, target_distance)
+        );
+
+        if let Some((pos1, inner1)) = shape1.as_transformed_shape() {
+            let toi = self.time_of_impact(
+                &pos1.inv_mul(pos12),
+                &pos1.inverse_transform_vector(vel12),
+                inner1,
+                shape2,
+                max_toi,
+                target_distance,
+            )?;
+            return Ok(toi.map(|toi| toi.transform1_by(pos1)));
+        } else if let Some((pos2, inner2)) = shape2.as_transformed_shape() {
+            let toi = self.time_of_impact(
+                &(pos12 * pos2),
+                vel12,
+                shape1,
+                inner2,
+                max_toi,
+                target_distance,
+            )?;
+            return Ok(toi.map(|toi| toi.transform2_by(pos2)));
+        }
+
         if let (Some(b1), Some(b2)) = (shape1.as_ball(), shape2.as_ball()) {
             Ok(query::details::time_of_impact_ball_ball(
                 pos12,
@@ -224,9 +423,10 @@ impl QueryDispatcher for DefaultQueryDispatcher {
                 max_toi,
                 target_distance,
             ))
-        } else if let (Some(p1), Some(s2)) =
-            (shape1.as_shape::<HalfSpace>(), shape2.as_support_map())
-        {
+        } else if let (Some(p1), Some(s2)) = (
+            shape1.as_shape::<HalfSpace>(),
+            convex_hull_support_map(shape2),
+        ) {
             Ok(query::details::time_of_impact_halfspace_support_map(
                 pos12,
                 vel12,
@@ -235,9 +435,10 @@ impl QueryDispatcher for DefaultQueryDispatcher {
                 max_toi,
                 target_distance,
             ))
-        } else if let (Some(s1), Some(p2)) =
-            (shape1.as_support_map(), shape2.as_shape::<HalfSpace>())
-        {
+        } else if let (Some(s1), Some(p2)) = (
+            convex_hull_support_map(shape1),
+            shape2.as_shape::<HalfSpace>(),
+        ) {
             Ok(query::details::time_of_impact_support_map_halfspace(
                 pos12,
                 vel12,
@@ -246,6 +447,24 @@ impl QueryDispatcher for DefaultQueryDispatcher {
                 max_toi,
                 target_distance,
             ))
+        } else if let (Some(p1), Some(s2)) = (shape1.as_shape::<Slab>(), shape2.as_support_map()) {
+            Ok(query::details::time_of_impact_slab_support_map(
+                pos12,
+                vel12,
+                p1,
+                s2,
+                max_toi,
+                target_distance,
+            ))
+        } else if let (Some(s1), Some(p2)) = (shape1.as_support_map(), shape2.as_shape::<Slab>()) {
+            Ok(query::details::time_of_impact_support_map_slab(
+                pos12,
+                vel12,
+                s1,
+                p2,
+                max_toi,
+                target_distance,
+            ))
         } else if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
             Ok(query::details::time_of_impact_support_map_support_map(
                 pos12,
@@ -288,6 +507,32 @@ impl QueryDispatcher for DefaultQueryDispatcher {
         max_toi: Real,
         target_distance: Real,
     ) -> Result<Option<TOI>, Unsupported> {
+        dispatch_to_custom_shape!(
+            shape1,
+            shape2,
+            nonlinear_time_of_impact(motion12, shape1, shape2, max_toi, target_distance)
+        );
+
+        if let Some((pos1, inner1)) = shape1.as_transformed_shape() {
+            let toi = self.nonlinear_time_of_impact(
+                &motion12.append_transformation(pos1.inverse()),
+                inner1,
+                shape2,
+                max_toi,
+                target_distance,
+            )?;
+            return Ok(toi.map(|toi| toi.transform1_by(pos1)));
+        } else if let Some((pos2, inner2)) = shape2.as_transformed_shape() {
+            let toi = self.nonlinear_time_of_impact(
+                &motion12.prepend_transformation(*pos2),
+                shape1,
+                inner2,
+                max_toi,
+                target_distance,
+            )?;
+            return Ok(toi.map(|toi| toi.transform2_by(pos2)));
+        }
+
         if let (Some(b1), Some(b2)) = (shape1.as_ball(), shape2.as_ball()) {
             Ok(query::details::nonlinear_time_of_impact_ball_ball(
                 motion12,
@@ -357,6 +602,41 @@ where
     ) -> Result<(), Unsupported> {
         use crate::query::contact_manifolds::*;
 
+        // The contacts with the inner shape of a transformed shape remain expressed in the
+        // local-space of the inner shape, which is positioned by the subshape position of
+        // the contact manifolds.
+        if let Some((pos1, inner1)) = shape1.as_transformed_shape() {
+            self.contact_manifolds(
+                &pos1.inv_mul(pos12),
+                inner1,
+                shape2,
+                prediction,
+                manifolds,
+                workspace,
+            )?;
+
+            for manifold in manifolds.iter_mut() {
+                manifold.subshape_pos1 = subshape_pos(shape1, manifold.subshape1);
+            }
+
+            return Ok(());
+        } else if let Some((pos2, inner2)) = shape2.as_transformed_shape() {
+            self.contact_manifolds(
+                &(pos12 * pos2),
+                shape1,
+                inner2,
+                prediction,
+                manifolds,
+                workspace,
+            )?;
+
+            for manifold in manifolds.iter_mut() {
+                manifold.subshape_pos2 = subshape_pos(shape2, manifold.subshape2);
+            }
+
+            return Ok(());
+        }
+
         let composite1 = shape1.as_composite_shape();
         let composite2 = shape2.as_composite_shape();
 
@@ -457,6 +737,34 @@ where
     ) -> Result<(), Unsupported> {
         use crate::query::contact_manifolds::*;
 
+        // The contacts are computed in the local-space of the inner shape of a transformed
+        // shape, then expressed back in the local-space of the transformed shape.
+        if let Some((pos1, inner1)) = shape1.as_transformed_shape() {
+            let id = Isometry::identity();
+            transform_manifold(manifold, &pos1.inverse(), &id);
+            let result = self.contact_manifold_convex_convex(
+                &pos1.inv_mul(pos12),
+                inner1,
+                shape2,
+                prediction,
+                manifold,
+            );
+            transform_manifold(manifold, pos1, &id);
+            return result;
+        } else if let Some((pos2, inner2)) = shape2.as_transformed_shape() {
+            let id = Isometry::identity();
+            transform_manifold(manifold, &id, &pos2.inverse());
+            let result = self.contact_manifold_convex_convex(
+                &(pos12 * pos2),
+                shape1,
+                inner2,
+                prediction,
+                manifold,
+            );
+            transform_manifold(manifold, &id, pos2);
+            return result;
+        }
+
         match (shape1.shape_type(), shape2.shape_type()) {
             (ShapeType::Ball, ShapeType::Ball) => {
                 contact_manifold_ball_ball_shapes(pos12, shape1, shape2, prediction, manifold)
@@ -474,6 +782,12 @@ where
             (ShapeType::Capsule, ShapeType::Capsule) => {
                 contact_manifold_capsule_capsule_shapes(pos12, shape1, shape2, prediction, manifold)
             }
+            (ShapeType::Slab, _) if shape2.as_support_map().is_some() => {
+                contact_manifold_slab_shape_shapes(pos12, shape1, shape2, prediction, manifold)
+            }
+            (_, ShapeType::Slab) if shape1.as_support_map().is_some() => {
+                contact_manifold_slab_shape_shapes(pos12, shape1, shape2, prediction, manifold)
+            }
             (_, ShapeType::Ball) | (ShapeType::Ball, _) => {
                 contact_manifold_convex_ball_shapes(pos12, shape1, shape2, prediction, manifold)
             }
@@ -490,8 +804,34 @@ where
                     contact_manifold_pfm_pfm(
                         pos12, pfm1.0, pfm1.1, pfm2.0, pfm2.1, prediction, manifold,
                     )
+                } else if let (Some(sm1), Some(sm2)) =
+                    (shape1.as_support_map(), shape2.as_support_map())
+                {
+                    contact_manifold_support_map_support_map(
+                        pos12, sm1, sm2, prediction, manifold,
+                    )
+                } else if let Some(contact) = self.contact(pos12, shape1, shape2, prediction)? {
+                    // Fallback to a single-point manifold for the remaining pairs.
+                    let contact_point = TrackedContact::new(
+                        contact.point1,
+                        contact.point2,
+                        0,
+                        0,
+                        contact.dist,
+                    );
+
+                    if manifold.points.len() != 1 {
+                        manifold.clear();
+                        manifold.points.push(contact_point);
+                    } else {
+                        // Copy only the geometry so we keep the warmstart impulses.
+                        manifold.points[0].copy_geometry_from(contact_point);
+                    }
+
+                    manifold.local_n1 = *contact.normal1;
+                    manifold.local_n2 = *contact.normal2;
                 } else {
-                    return Err(Unsupported);
+                    manifold.clear();
                 }
             }
         }
@@ -15,3 +15,20 @@ pub fn distance(
     let pos12 = pos1.inv_mul(&pos2);
     DefaultQueryDispatcher.distance(&pos12, g1, g2)
 }
+
+/// Computes the minimum distance separating two shapes, bounded by `max_dist`.
+///
+/// Returns `0.0` if the objects are touching or penetrating, and `max_dist` if they are
+/// separated by a distance greater than `max_dist`. This is faster than `distance` for
+/// shapes far apart since the computation stops as soon as the distance is known to
+/// exceed `max_dist`.
+pub fn distance_with_max_dist(
+    pos1: &Isometry<Real>,
+    g1: &dyn Shape,
+    pos2: &Isometry<Real>,
+    g2: &dyn Shape,
+    max_dist: Real,
+) -> Result<Real, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+    DefaultQueryDispatcher.distance_with_max_dist(&pos12, g1, g2, max_dist)
+}
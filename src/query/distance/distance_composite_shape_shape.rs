@@ -25,6 +25,29 @@ where
          .1
 }
 
+/// Smallest distance between a composite shape and any other shape, bounded by `max_dist`.
+///
+/// Parts of the composite shape farther than `max_dist` are ignored. Returns `max_dist`
+/// if all the parts are farther than `max_dist`.
+pub fn distance_composite_shape_shape_with_max_dist<D: ?Sized, G1: ?Sized>(
+    dispatcher: &D,
+    pos12: &Isometry<Real>,
+    g1: &G1,
+    g2: &dyn Shape,
+    max_dist: Real,
+) -> Real
+where
+    D: QueryDispatcher,
+    G1: TypedSimdCompositeShape,
+{
+    let mut visitor = CompositeShapeAgainstAnyDistanceVisitor::new(dispatcher, pos12, g1, g2)
+        .with_max_dist(max_dist);
+    g1.typed_quadtree()
+        .traverse_best_first(&mut visitor)
+        .map(|res| (res.1).1)
+        .unwrap_or(max_dist)
+}
+
 /// Smallest distance between a shape and a composite shape.
 pub fn distance_shape_composite_shape<D: ?Sized, G2: ?Sized>(
     dispatcher: &D,
@@ -39,10 +62,26 @@ where
     distance_composite_shape_shape(dispatcher, &pos12.inverse(), g2, g1)
 }
 
+/// Smallest distance between a shape and a composite shape, bounded by `max_dist`.
+pub fn distance_shape_composite_shape_with_max_dist<D: ?Sized, G2: ?Sized>(
+    dispatcher: &D,
+    pos12: &Isometry<Real>,
+    g1: &dyn Shape,
+    g2: &G2,
+    max_dist: Real,
+) -> Real
+where
+    D: QueryDispatcher,
+    G2: TypedSimdCompositeShape,
+{
+    distance_composite_shape_shape_with_max_dist(dispatcher, &pos12.inverse(), g2, g1, max_dist)
+}
+
 /// A visitor for computing the distance between a composite shape and a shape.
 pub struct CompositeShapeAgainstAnyDistanceVisitor<'a, D: ?Sized, G1: ?Sized + 'a> {
     msum_shift: Vector<SimdReal>,
     msum_margin: Vector<SimdReal>,
+    max_dist: Real,
 
     dispatcher: &'a D,
     pos12: &'a Isometry<Real>,
@@ -64,11 +103,18 @@ impl<'a, D: ?Sized, G1: ?Sized + 'a> CompositeShapeAgainstAnyDistanceVisitor<'a,
             dispatcher,
             msum_shift: Vector::splat(-ls_aabb2.center().coords),
             msum_margin: Vector::splat(ls_aabb2.half_extents()),
+            max_dist: Real::MAX,
             pos12,
             g1,
             g2,
         }
     }
+
+    /// Ignores the parts of the composite shape farther than `max_dist` from the other shape.
+    pub fn with_max_dist(mut self, max_dist: Real) -> Self {
+        self.max_dist = max_dist;
+        self
+    }
 }
 
 impl<'a, D: ?Sized, G1: ?Sized> SimdBestFirstVisitor<G1::PartId, SimdAABB>
@@ -91,7 +137,8 @@ where
             maxs: bv.maxs + self.msum_shift + self.msum_margin,
         };
         let dist = msum.distance_to_origin();
-        let mask = dist.simd_lt(SimdReal::splat(best));
+        let max_dist = best.min(self.max_dist);
+        let mask = dist.simd_lt(SimdReal::splat(max_dist));
 
         if let Some(data) = data {
             let bitmask = mask.bitmask();
@@ -104,9 +151,12 @@ where
                     let part_id = *data[ii].unwrap();
                     let mut dist = Ok(0.0);
                     self.g1.map_untyped_part_at(part_id, |part_pos1, g1| {
-                        dist =
-                            self.dispatcher
-                                .distance(&part_pos1.inv_mul(self.pos12), g1, self.g2);
+                        dist = self.dispatcher.distance_with_max_dist(
+                            &part_pos1.inv_mul(self.pos12),
+                            g1,
+                            self.g2,
+                            max_dist,
+                        );
                     });
 
                     match dist {
@@ -115,7 +165,7 @@ where
                                 return SimdBestFirstVisitStatus::ExitEarly(Some((part_id, 0.0)));
                             } else {
                                 weights[ii] = dist;
-                                mask[ii] = dist < best;
+                                mask[ii] = dist < max_dist;
                                 results[ii] = Some((part_id, dist));
                             }
                         }
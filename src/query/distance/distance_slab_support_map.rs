@@ -0,0 +1,20 @@
+use crate::math::{Isometry, Real};
+use crate::shape::{Slab, SupportMap};
+
+/// Distance between a slab and a support-mapped shape.
+pub fn distance_slab_support_map<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    slab: &Slab,
+    other: &G,
+) -> Real {
+    slab.separating_side(pos12, other).2.max(0.0)
+}
+
+/// Distance between a support-mapped shape and a slab.
+pub fn distance_support_map_slab<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    other: &G,
+    slab: &Slab,
+) -> Real {
+    distance_slab_support_map(&pos12.inverse(), slab, other)
+}
@@ -18,6 +18,35 @@ where
     distance_support_map_support_map_with_params(pos12, g1, g2, &mut VoronoiSimplex::new(), None)
 }
 
+/// Distance between support-mapped shapes, bounded by `max_dist`.
+///
+/// The underlying GJK algorithm stops as soon as the shapes are proven to be separated by
+/// more than `max_dist`, in which case `max_dist` is returned. If `max_dist` is `Real::MAX`,
+/// this returns the same distance as `distance_support_map_support_map`.
+pub fn distance_support_map_support_map_with_max_dist<G1: ?Sized, G2: ?Sized>(
+    pos12: &Isometry<Real>,
+    g1: &G1,
+    g2: &G2,
+    max_dist: Real,
+) -> Real
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    let mut simplex = VoronoiSimplex::new();
+    init_simplex(pos12, g1, g2, &mut simplex, None);
+
+    match gjk::closest_points(pos12, g1, g2, max_dist, true, &mut simplex) {
+        GJKResult::Intersection => 0.0,
+        GJKResult::ClosestPoints(p1, p2, _) => na::distance(&p1, &p2).min(max_dist),
+        GJKResult::Proximity(_) => unreachable!(),
+        // With an unbounded `max_dist`, this means GJK did not converge: return the same
+        // distance as `distance_support_map_support_map` does in this case.
+        GJKResult::NoIntersection(_) if max_dist < Real::max_value() => max_dist,
+        GJKResult::NoIntersection(_) => 0.0,
+    }
+}
+
 /// Distance between support-mapped shapes.
 ///
 /// This allows a more fine grained control other the underlying GJK algorigtm.
@@ -31,6 +60,26 @@ pub fn distance_support_map_support_map_with_params<G1: ?Sized, G2: ?Sized>(
 where
     G1: SupportMap,
     G2: SupportMap,
+{
+    init_simplex(pos12, g1, g2, simplex, init_dir);
+
+    match gjk::closest_points(pos12, g1, g2, Real::max_value(), true, simplex) {
+        GJKResult::Intersection => 0.0,
+        GJKResult::ClosestPoints(p1, p2, _) => na::distance(&p1, &p2),
+        GJKResult::Proximity(_) => unreachable!(),
+        GJKResult::NoIntersection(_) => 0.0, // FIXME: GJK did not converge.
+    }
+}
+
+fn init_simplex<G1: ?Sized, G2: ?Sized>(
+    pos12: &Isometry<Real>,
+    g1: &G1,
+    g2: &G2,
+    simplex: &mut VoronoiSimplex,
+    init_dir: Option<Vector<Real>>,
+) where
+    G1: SupportMap,
+    G2: SupportMap,
 {
     // FIXME: or m2.translation - m1.translation ?
     let dir = init_dir.unwrap_or_else(|| -pos12.translation.vector);
@@ -45,11 +94,4 @@ where
             &Vector::<Real>::x_axis(),
         ));
     }
-
-    match gjk::closest_points(pos12, g1, g2, Real::max_value(), true, simplex) {
-        GJKResult::Intersection => 0.0,
-        GJKResult::ClosestPoints(p1, p2, _) => na::distance(&p1, &p2),
-        GJKResult::Proximity(_) => unreachable!(),
-        GJKResult::NoIntersection(_) => 0.0, // FIXME: GJK did not converge.
-    }
 }
@@ -1,20 +1,24 @@
 //! Implementation details of the `distance` function.
 
-pub use self::distance::distance;
+pub use self::distance::{distance, distance_with_max_dist};
 pub use self::distance_ball_ball::distance_ball_ball;
 pub use self::distance_composite_shape_shape::{
-    distance_composite_shape_shape, distance_shape_composite_shape,
+    distance_composite_shape_shape, distance_composite_shape_shape_with_max_dist,
+    distance_shape_composite_shape, distance_shape_composite_shape_with_max_dist,
     CompositeShapeAgainstAnyDistanceVisitor,
 };
 pub use self::distance_halfspace_support_map::{
     distance_halfspace_support_map, distance_support_map_halfspace,
 };
+pub use self::distance_slab_support_map::{distance_slab_support_map, distance_support_map_slab};
 pub use self::distance_support_map_support_map::{
-    distance_support_map_support_map, distance_support_map_support_map_with_params,
+    distance_support_map_support_map, distance_support_map_support_map_with_max_dist,
+    distance_support_map_support_map_with_params,
 };
 
 mod distance;
 mod distance_ball_ball;
 mod distance_composite_shape_shape;
 mod distance_halfspace_support_map;
+mod distance_slab_support_map;
 mod distance_support_map_support_map;
@@ -109,11 +109,16 @@ impl Face {
     }
 }
 
+const DEFAULT_MAX_ITERATIONS: usize = 10_000;
+const DEFAULT_MAX_FACES: usize = usize::MAX;
+
 /// The Expanding Polytope Algorithm in 2D.
 pub struct EPA {
     vertices: Vec<CSOPoint>,
     faces: Vec<Face>,
     heap: BinaryHeap<FaceId>,
+    max_iterations: usize,
+    max_faces: usize,
 }
 
 impl EPA {
@@ -123,6 +128,21 @@ impl EPA {
             vertices: Vec::new(),
             faces: Vec::new(),
             heap: BinaryHeap::new(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            max_faces: DEFAULT_MAX_FACES,
+        }
+    }
+
+    /// Creates a new instance of the 2D Expanding Polytope Algorithm with a limited budget.
+    ///
+    /// The expansion stops after `max_iterations` iterations, or once the polytope has more
+    /// than `max_faces` faces (including the deleted ones). The best approximation found so
+    /// far is returned in this case.
+    pub fn with_budget(max_iterations: usize, max_faces: usize) -> Self {
+        EPA {
+            max_iterations,
+            max_faces,
+            ..Self::new()
         }
     }
 
@@ -339,8 +359,8 @@ impl EPA {
             }
 
             niter += 1;
-            if niter > 10000 {
-                return None;
+            if niter >= self.max_iterations || self.faces.len() > self.max_faces {
+                break;
             }
         }
 
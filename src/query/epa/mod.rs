@@ -1,11 +1,17 @@
 //! The EPA algorithm for penetration depth computation.
 //!
+//! The [`penetration_depth`] function is the simplest way of computing the penetration depth
+//! between two convex shapes implementing `SupportMap`.
 #[cfg(feature = "dim2")]
 pub use self::epa2::EPA;
 #[cfg(feature = "dim3")]
 pub use self::epa3::EPA;
+pub use self::penetration_depth::{
+    penetration_depth, penetration_depth_with_epa, PenetrationDepth,
+};
 
 #[cfg(feature = "dim2")]
 pub mod epa2;
 #[cfg(feature = "dim3")]
 pub mod epa3;
+mod penetration_depth;
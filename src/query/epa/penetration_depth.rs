@@ -0,0 +1,78 @@
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::query::epa::EPA;
+use crate::query::gjk::{self, CSOPoint, GJKResult, VoronoiSimplex};
+use crate::shape::SupportMap;
+use na::Unit;
+
+/// The penetration depth between two penetrating shapes, and the associated witness points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct PenetrationDepth {
+    /// The penetration depth, i.e., the length of the smallest translation separating the shapes.
+    ///
+    /// This is always non-negative.
+    pub depth: Real,
+    /// The deepest point of the first shape inside of the second shape, in the local-space of the first shape.
+    pub witness1: Point<Real>,
+    /// The deepest point of the second shape inside of the first shape, in the local-space of the second shape.
+    pub witness2: Point<Real>,
+    /// The penetration direction, pointing toward the exterior of the first shape, in its local-space.
+    ///
+    /// Translating the second shape by `depth` along this direction separates both shapes.
+    pub normal1: Unit<Vector<Real>>,
+    /// The penetration direction, pointing toward the exterior of the second shape, in its local-space.
+    pub normal2: Unit<Vector<Real>>,
+}
+
+/// Computes the penetration depth between two convex shapes using GJK and EPA.
+///
+/// Returns `None` if the shapes are not penetrating, or if EPA failed to compute the
+/// penetration depth.
+pub fn penetration_depth<G1: ?Sized, G2: ?Sized>(
+    pos12: &Isometry<Real>,
+    g1: &G1,
+    g2: &G2,
+) -> Option<PenetrationDepth>
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    penetration_depth_with_epa(pos12, g1, g2, &mut EPA::new())
+}
+
+/// Computes the penetration depth between two convex shapes using GJK and the given EPA instance.
+///
+/// This allows the iteration and face budgets of the EPA to be configured with
+/// `EPA::with_budget`, and its allocations to be reused between multiple calls.
+///
+/// Returns `None` if the shapes are not penetrating, or if EPA failed to compute the
+/// penetration depth.
+pub fn penetration_depth_with_epa<G1: ?Sized, G2: ?Sized>(
+    pos12: &Isometry<Real>,
+    g1: &G1,
+    g2: &G2,
+    epa: &mut EPA,
+) -> Option<PenetrationDepth>
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    let dir = Unit::try_new(pos12.translation.vector, crate::math::DEFAULT_EPSILON)
+        .unwrap_or_else(Vector::x_axis);
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::from_shapes(pos12, g1, g2, &dir));
+
+    if gjk::closest_points(pos12, g1, g2, 0.0, true, &mut simplex) != GJKResult::Intersection {
+        return None;
+    }
+
+    let (witness1, witness2_1, normal1) = epa.closest_points(pos12, g1, g2, &simplex)?;
+
+    Some(PenetrationDepth {
+        depth: (witness1 - witness2_1).dot(&normal1).max(0.0),
+        witness1,
+        witness2: pos12.inverse_transform_point(&witness2_1),
+        normal1,
+        normal2: pos12.inverse_transform_unit_vector(&-normal1),
+    })
+}
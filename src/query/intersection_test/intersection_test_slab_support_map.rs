@@ -0,0 +1,20 @@
+use crate::math::{Isometry, Real};
+use crate::shape::{Slab, SupportMap};
+
+/// Intersection test between a slab and a support-mapped shape (Cuboid, ConvexHull, etc.)
+pub fn intersection_test_slab_support_map<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    slab: &Slab,
+    other: &G,
+) -> bool {
+    slab.separating_side(pos12, other).2 <= 0.0
+}
+
+/// Intersection test between a support-mapped shape (Cuboid, ConvexHull, etc.) and a slab.
+pub fn intersection_test_support_map_slab<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    other: &G,
+    slab: &Slab,
+) -> bool {
+    intersection_test_slab_support_map(&pos12.inverse(), slab, other)
+}
@@ -21,6 +21,9 @@ pub use self::intersection_test_cuboid_triangle::{
 pub use self::intersection_test_halfspace_support_map::{
     intersection_test_halfspace_support_map, intersection_test_support_map_halfspace,
 };
+pub use self::intersection_test_slab_support_map::{
+    intersection_test_slab_support_map, intersection_test_support_map_slab,
+};
 pub use self::intersection_test_support_map_support_map::intersection_test_support_map_support_map;
 pub use self::intersection_test_support_map_support_map::intersection_test_support_map_support_map_with_params;
 
@@ -32,4 +35,5 @@ mod intersection_test_cuboid_cuboid;
 mod intersection_test_cuboid_segment;
 mod intersection_test_cuboid_triangle;
 mod intersection_test_halfspace_support_map;
+mod intersection_test_slab_support_map;
 mod intersection_test_support_map_support_map;
@@ -0,0 +1,65 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::{sat, DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::{Cuboid, Shape};
+use na::Unit;
+
+/// The direction, and the depth along this direction, of a minimum translation vector.
+pub type MinimumTranslation = (Unit<Vector<Real>>, Real);
+
+/// Computes the minimum translation vector separating two penetrating shapes.
+///
+/// Returns the world-space direction and the depth such that translating `g2` by
+/// `direction * depth` (or `g1` by `-direction * depth`) makes both shapes touch without
+/// penetrating. Returns `Ok(None)` if the shapes do not overlap.
+///
+/// Pairs of cuboids are handled with the separating axis theorem. Other convex shapes
+/// rely on the penetrating contact computed by GJK and EPA.
+pub fn minimum_translation_vector(
+    pos1: &Isometry<Real>,
+    g1: &dyn Shape,
+    pos2: &Isometry<Real>,
+    g2: &dyn Shape,
+) -> Result<Option<MinimumTranslation>, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+
+    if let (Some(c1), Some(c2)) = (g1.as_cuboid(), g2.as_cuboid()) {
+        return Ok(minimum_translation_vector_cuboid_cuboid(&pos12, c1, c2)
+            .map(|(dir, depth)| (pos1 * dir, depth)));
+    }
+
+    let contact = DefaultQueryDispatcher.contact(&pos12, g1, g2, 0.0)?;
+    Ok(contact
+        .filter(|c| c.dist < 0.0)
+        .map(|c| (pos1 * c.normal1, -c.dist)))
+}
+
+/// Computes the minimum translation vector between two cuboids, expressed in the
+/// local-space of `cuboid1`.
+fn minimum_translation_vector_cuboid_cuboid(
+    pos12: &Isometry<Real>,
+    cuboid1: &Cuboid,
+    cuboid2: &Cuboid,
+) -> Option<MinimumTranslation> {
+    let pos21 = pos12.inverse();
+
+    let sep1 = sat::cuboid_cuboid_find_local_separating_normal_oneway(cuboid1, cuboid2, pos12);
+    let sep2 = sat::cuboid_cuboid_find_local_separating_normal_oneway(cuboid2, cuboid1, &pos21);
+    #[cfg(feature = "dim2")]
+    let sep3 = (-Real::MAX, Vector::x()); // This case does not exist in 2D.
+    #[cfg(feature = "dim3")]
+    let sep3 = sat::cuboid_cuboid_find_local_separating_edge_twoway(cuboid1, cuboid2, pos12);
+
+    let mut best_sep = sep1;
+
+    if sep2.0 > sep1.0 && sep2.0 > sep3.0 {
+        best_sep = (sep2.0, pos12 * -sep2.1);
+    } else if sep3.0 > sep1.0 {
+        best_sep = sep3;
+    }
+
+    if best_sep.0 < 0.0 {
+        Some((Unit::new_normalize(best_sep.1), -best_sep.0))
+    } else {
+        None
+    }
+}
@@ -4,17 +4,28 @@
 //! The most general methods provided by this module are:
 //!
 //! * [`query::closest_points()`] to compute the closest points between two shapes.
+//! * [`query::closest_points_with_features()`] to also compute the features containing these closest points.
 //! * [`query::distance()`] to compute the distance between two shapes.
+//! * [`query::distance_with_max_dist()`] to compute the distance between two shapes, stopping early beyond a given bound.
 //! * [`query::contact()`] to compute one pair of contact points between two shapes, including penetrating contact.
 //! * [`query::intersection_test()`] to determine if two shapes are intersecting or not.
+//! * [`query::minimum_translation_vector()`] to compute the smallest translation separating two penetrating shapes.
+//! * [`query::epa::penetration_depth()`] to compute the penetration depth and witness points of two penetrating convex shapes.
+//! * [`query::contains_convex()`] to determine if a shape fully contains a convex shape.
 //! * [`query::time_of_impact()`] to determine when two shapes undergoing translational motions hit for the first time.
 //! * [`query::nonlinear_time_of_impact()`] to determine when two shapes undergoing continuous rigid motions hit for the first time.
+//! * [`query::nonlinear_time_of_impact_conservative_advancement()`] to determine when two shapes moving with constant linear and angular velocities hit for the first time, without missing impacts of fast-spinning shapes.
 //!
 //! Ray-casting and point-projection can be achieved by importing traits:
 //!
 //! * [`query::RayCast`] for ray-casting.
 //! * [`query::PointQuery`] for point projection.
 //!
+//! Many rays can be cast on the same composite shape at once with [`query::raycast_batch()`], and
+//! all the intersections along a ray, instead of just the first one, are given by [`query::ray_intersections()`].
+//! When the `parallel` feature is enabled, the [`query::parallel`] module provides multithreaded
+//! versions of the most expensive queries involving composite shapes.
+//!
 //! # Specific cases
 //! The functions exported by the `details` submodule are more specific versions of the ones described above.
 //! For example `distance_ball_ball` computes the distance between two shapes known at compile-time to be balls.
@@ -25,36 +36,52 @@
 //! * `[shape1]` is the type of the first shape passed to the function, e.g., `ball`, or `halfspace`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 //! * `[shape2]` is the type of the second shape passed to the function, e.g., `ball`, or `halfspace`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 
-pub use self::closest_points::{closest_points, ClosestPoints};
+pub use self::closest_points::{
+    closest_points, closest_points_with_features, ClosestPointFeature, ClosestPoints,
+};
 pub use self::contact::{contact, Contact};
-pub use self::contact_manifolds::{ContactManifold, ContactManifoldsWorkspace, TrackedContact};
+pub use self::contact_manifolds::{
+    ContactManifold, ContactManifoldsWorkspace, PersistentContactManifolds, TrackedContact,
+};
+pub use self::contains_convex::contains_convex;
 pub use self::default_query_dispatcher::DefaultQueryDispatcher;
-pub use self::distance::distance;
+pub use self::distance::{distance, distance_with_max_dist};
 pub use self::error::Unsupported;
 pub use self::intersection_test::intersection_test;
-pub use self::nonlinear_time_of_impact::nonlinear_time_of_impact;
+pub use self::minimum_translation_vector::{minimum_translation_vector, MinimumTranslation};
+pub use self::nonlinear_time_of_impact::{
+    nonlinear_time_of_impact, nonlinear_time_of_impact_conservative_advancement,
+};
 pub use self::point::{PointProjection, PointQuery, PointQueryWithLocation};
 pub use self::query_dispatcher::{
     PersistentQueryDispatcher, QueryDispatcher, QueryDispatcherChain,
 };
-pub use self::ray::{Ray, RayCast, RayIntersection, SimdRay};
+pub use self::query_dispatcher_registry::QueryDispatcherRegistry;
+pub use self::ray::{ray_intersections, raycast_batch, Ray, RayCast, RayIntersection, SimdRay};
+pub use self::sweep_and_prune::sweep_and_prune;
 pub use self::time_of_impact::{time_of_impact, TOIStatus, TOI};
 
 mod clip;
 pub mod closest_points;
 pub mod contact;
 mod contact_manifolds;
+mod contains_convex;
 mod default_query_dispatcher;
 mod distance;
 pub mod epa;
 mod error;
 pub mod gjk;
 mod intersection_test;
+mod minimum_translation_vector;
 mod nonlinear_time_of_impact;
+#[cfg(feature = "parallel")]
+pub mod parallel;
 pub mod point;
 mod query_dispatcher;
+mod query_dispatcher_registry;
 mod ray;
 pub mod sat;
+mod sweep_and_prune;
 mod time_of_impact;
 pub mod visitors;
 
@@ -65,25 +92,30 @@ pub mod details {
     pub use super::contact::{
         contact_ball_ball, contact_ball_convex_polyhedron, contact_composite_shape_shape,
         contact_convex_polyhedron_ball, contact_halfspace_support_map,
-        contact_shape_composite_shape, contact_support_map_halfspace,
-        contact_support_map_support_map, contact_support_map_support_map_with_params,
+        contact_shape_composite_shape, contact_slab_support_map, contact_support_map_halfspace,
+        contact_support_map_slab, contact_support_map_support_map,
+        contact_support_map_support_map_with_params,
     };
     pub use super::contact_manifolds::{
         contact_manifold_ball_ball, contact_manifold_ball_ball_shapes,
         contact_manifold_capsule_capsule, contact_manifold_capsule_capsule_shapes,
         contact_manifold_convex_ball, contact_manifold_convex_ball_shapes,
-        contact_manifold_cuboid_cuboid, contact_manifold_cuboid_cuboid_shapes,
-        contact_manifold_cuboid_triangle, contact_manifold_cuboid_triangle_shapes,
-        contact_manifold_pfm_pfm, contact_manifold_pfm_pfm_shapes,
-        contact_manifolds_heightfield_shape, contact_manifolds_heightfield_shape_shapes,
-        contact_manifolds_trimesh_shape_shapes,
+        contact_manifold_convex_composite, contact_manifold_cuboid_cuboid,
+        contact_manifold_cuboid_cuboid_shapes, contact_manifold_cuboid_triangle,
+        contact_manifold_cuboid_triangle_shapes, contact_manifold_pfm_pfm,
+        contact_manifold_pfm_pfm_shapes, contact_manifold_slab_shape,
+        contact_manifold_slab_shape_shapes, contact_manifold_support_map_support_map,
+        contact_manifold_support_map_support_map_shapes, contact_manifolds_heightfield_shape,
+        contact_manifolds_heightfield_shape_shapes, contact_manifolds_trimesh_shape_shapes,
     };
 
     pub use super::distance::{
-        distance_ball_ball, distance_composite_shape_shape, distance_halfspace_support_map,
-        distance_shape_composite_shape, distance_support_map_halfspace,
-        distance_support_map_support_map, distance_support_map_support_map_with_params,
-        CompositeShapeAgainstAnyDistanceVisitor,
+        distance_ball_ball, distance_composite_shape_shape,
+        distance_composite_shape_shape_with_max_dist, distance_halfspace_support_map,
+        distance_shape_composite_shape, distance_shape_composite_shape_with_max_dist,
+        distance_slab_support_map, distance_support_map_halfspace, distance_support_map_slab,
+        distance_support_map_support_map, distance_support_map_support_map_with_max_dist,
+        distance_support_map_support_map_with_params, CompositeShapeAgainstAnyDistanceVisitor,
     };
     pub use super::intersection_test::*;
     pub use super::nonlinear_time_of_impact::{
@@ -109,7 +141,8 @@ pub mod details {
     pub use super::time_of_impact::{
         time_of_impact_ball_ball, time_of_impact_composite_shape_shape,
         time_of_impact_halfspace_support_map, time_of_impact_shape_composite_shape,
-        time_of_impact_support_map_halfspace, time_of_impact_support_map_support_map,
+        time_of_impact_slab_support_map, time_of_impact_support_map_halfspace,
+        time_of_impact_support_map_slab, time_of_impact_support_map_support_map,
         TOICompositeShapeShapeBestFirstVisitor,
     };
 }
@@ -7,6 +7,7 @@ pub use self::nonlinear_time_of_impact_composite_shape_shape::{
 };
 //pub use self::nonlinear_time_of_impact_halfspace_support_map::{nonlinear_time_of_impact_halfspace_support_map, nonlinear_time_of_impact_support_map_halfspace};
 pub use self::nonlinear_time_of_impact::nonlinear_time_of_impact;
+pub use self::nonlinear_time_of_impact_conservative_advancement::nonlinear_time_of_impact_conservative_advancement;
 pub use self::nonlinear_time_of_impact_support_map_support_map::{
     nonlinear_time_of_impact_support_map_support_map,
     nonlinear_time_of_impact_support_map_support_map_with_closest_points_function,
@@ -16,4 +17,5 @@ mod nonlinear_time_of_impact_ball_ball;
 mod nonlinear_time_of_impact_composite_shape_shape;
 //mod nonlinear_time_of_impact_halfspace_support_map;
 mod nonlinear_time_of_impact;
+mod nonlinear_time_of_impact_conservative_advancement;
 mod nonlinear_time_of_impact_support_map_support_map;
@@ -13,5 +13,12 @@ pub fn nonlinear_time_of_impact(
     target_distance: Real,
 ) -> Result<Option<TOI>, Unsupported> {
     let motion12 = motion1.inv_mul(motion2);
-    DefaultQueryDispatcher.nonlinear_time_of_impact(&motion12, g1, g2, max_toi, target_distance)
+    let toi = DefaultQueryDispatcher.nonlinear_time_of_impact(
+        &motion12,
+        g1,
+        g2,
+        max_toi,
+        target_distance,
+    )?;
+    Ok(toi.map(|toi| toi.with_features(g1, g2)))
 }
@@ -0,0 +1,114 @@
+use na::{ComplexField, Unit};
+
+use crate::math::{Point, Real, Vector};
+use crate::motion::{ConstantVelocityRigidMotion, RigidMotion};
+use crate::query::{self, ClosestPoints, DefaultQueryDispatcher, QueryDispatcher, TOIStatus};
+use crate::query::{Unsupported, TOI};
+use crate::shape::{FeatureId, Shape};
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Computes the smallest time of impact of two shapes moving with constant linear and angular
+/// velocities, using conservative advancement.
+///
+/// Unlike `query::nonlinear_time_of_impact`, the shapes are advanced by steps small enough to
+/// guarantee that no impact is missed, even if they spin fast or if one of them is thin. Each
+/// step is computed from the distance between the shapes and from an upper bound of the speed
+/// of their points, deduced from their velocities and from their bounding spheres centered at
+/// the `local_center` of their motion.
+///
+/// Returns `Ok(None)` if the shapes don't get closer than `target_distance` before `max_toi`.
+/// Returns `Err(Unsupported)` if the closest points between `g1` and `g2` can't be computed.
+pub fn nonlinear_time_of_impact_conservative_advancement(
+    motion1: &ConstantVelocityRigidMotion,
+    g1: &dyn Shape,
+    motion2: &ConstantVelocityRigidMotion,
+    g2: &dyn Shape,
+    max_toi: Real,
+    target_distance: Real,
+) -> Result<Option<TOI>, Unsupported> {
+    let tolerance = ComplexField::sqrt(query::gjk::eps_tol());
+    let angular_bound = max_angular_speed(motion1, g1) + max_angular_speed(motion2, g2);
+    let mut result = TOI {
+        toi: 0.0,
+        normal1: Vector::<Real>::x_axis(),
+        normal2: Vector::<Real>::x_axis(),
+        witness1: Point::<Real>::origin(),
+        witness2: Point::<Real>::origin(),
+        feature1: FeatureId::Unknown,
+        feature2: FeatureId::Unknown,
+        status: TOIStatus::OutOfIterations,
+    };
+
+    for _ in 0..MAX_ITERATIONS {
+        let pos1 = motion1.position_at_time(result.toi);
+        let pos2 = motion2.position_at_time(result.toi);
+        let pos12 = pos1.inv_mul(&pos2);
+
+        match DefaultQueryDispatcher.closest_points(&pos12, g1, g2, Real::MAX)? {
+            ClosestPoints::Intersecting => {
+                result.status = if result.toi == 0.0 {
+                    TOIStatus::Penetrating
+                } else {
+                    TOIStatus::Failed
+                };
+                return Ok(Some(result));
+            }
+            ClosestPoints::WithinMargin(p1, p2) => {
+                result.witness1 = p1;
+                result.witness2 = p2;
+
+                let (normal1, dist) =
+                    match Unit::try_new_and_get(pos12 * p2 - p1, crate::math::DEFAULT_EPSILON) {
+                        Some(res) => res,
+                        None => {
+                            result.status = TOIStatus::Failed;
+                            return Ok(Some(result));
+                        }
+                    };
+
+                result.normal1 = normal1;
+                result.normal2 = pos12.inverse_transform_unit_vector(&-normal1);
+
+                if dist <= target_distance + tolerance {
+                    result.status = TOIStatus::Converged;
+                    return Ok(Some(result.with_features(g1, g2)));
+                }
+
+                // The separation along the current normal is a lower bound of the
+                // distance, and it can't decrease faster than this.
+                let normal = pos1 * normal1;
+                let closing_speed = (motion1.linvel - motion2.linvel).dot(&normal) + angular_bound;
+
+                if closing_speed <= 0.0 {
+                    return Ok(None);
+                }
+
+                result.toi += (dist - target_distance) / closing_speed;
+
+                if result.toi > max_toi {
+                    return Ok(None);
+                }
+            }
+            // The closest points can't be disjoint with an infinite margin, unless the
+            // closest-points computation failed to converge.
+            ClosestPoints::Disjoint => {
+                result.status = TOIStatus::Failed;
+                return Ok(Some(result));
+            }
+        }
+    }
+
+    Ok(Some(result.with_features(g1, g2)))
+}
+
+// Upper bound of the speed of the points of `shape` due to the rotational part of `motion`.
+fn max_angular_speed(motion: &ConstantVelocityRigidMotion, shape: &dyn Shape) -> Real {
+    let sphere = shape.compute_local_bounding_sphere();
+    let radius = na::distance(sphere.center(), &motion.local_center) + sphere.radius();
+
+    #[cfg(feature = "dim2")]
+    return motion.angvel.abs() * radius;
+    #[cfg(feature = "dim3")]
+    return motion.angvel.norm() * radius;
+}
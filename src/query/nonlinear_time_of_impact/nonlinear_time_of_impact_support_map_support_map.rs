@@ -3,7 +3,7 @@ use na::{ComplexField, Unit};
 use crate::math::{Isometry, Point, Real, Vector};
 use crate::motion::RigidMotion;
 use crate::query::{self, ClosestPoints, TOIStatus, TOI};
-use crate::shape::SupportMap;
+use crate::shape::{FeatureId, SupportMap};
 
 use num::Bounded;
 
@@ -59,6 +59,8 @@ where
         normal2: Vector::<Real>::x_axis(),
         witness1: Point::<Real>::origin(),
         witness2: Point::<Real>::origin(),
+        feature1: FeatureId::Unknown,
+        feature2: FeatureId::Unknown,
         status: TOIStatus::Penetrating,
     };
 
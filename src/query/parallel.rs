@@ -0,0 +1,101 @@
+//! Parallel versions of expensive queries involving composite shapes.
+//!
+//! These are only available when the `parallel` feature is enabled. The work is distributed
+//! among the threads of the global rayon thread pool. The results are always ordered the
+//! same way as those of the sequential versions, independently from the number of threads.
+
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{Isometry, Point, Real, SIMD_WIDTH};
+use crate::query::{
+    self, Contact, PointProjection, PointQuery, QueryDispatcher, Ray, RayIntersection, Unsupported,
+};
+use crate::shape::{Compound, TypedSimdCompositeShape};
+use rayon::prelude::*;
+
+// Number of rays cast by each rayon task. This is a multiple of `SIMD_WIDTH` so
+// that only the last packet of rays may be partially filled.
+const RAYS_PER_TASK: usize = 64 * SIMD_WIDTH;
+
+/// Casts many rays on the same transformed composite shape, in parallel.
+///
+/// This is a parallel version of `query::raycast_batch`: the rays are split into groups that
+/// are cast in parallel, and the results are returned in the same order as `rays`.
+pub fn raycast_batch<S>(
+    position: &Isometry<Real>,
+    shape: &S,
+    rays: &[Ray],
+    max_toi: Real,
+    solid: bool,
+) -> Vec<Option<(S::PartId, RayIntersection)>>
+where
+    S: TypedSimdCompositeShape + Sync,
+    S::PartId: Send,
+{
+    rays.par_chunks(RAYS_PER_TASK)
+        .flat_map_iter(|rays| query::raycast_batch(position, shape, rays, max_toi, solid))
+        .collect()
+}
+
+/// Projects many points on the same transformed shape, in parallel.
+///
+/// The projections are returned in the same order as `points`. This is useful for projecting
+/// a large number of points on a huge triangle mesh.
+pub fn project_points<S>(
+    position: &Isometry<Real>,
+    shape: &S,
+    points: &[Point<Real>],
+    solid: bool,
+) -> Vec<PointProjection>
+where
+    S: ?Sized + PointQuery + Sync,
+{
+    points
+        .par_iter()
+        .map(|pt| shape.project_point(position, pt, solid))
+        .collect()
+}
+
+/// Computes the contacts between all the pairs of parts of two compound shapes, in parallel.
+///
+/// Returns, for each pair of parts separated by a distance smaller than `prediction`, the
+/// index of the part of `compound1`, the index of the part of `compound2`, and their contact.
+/// The contact points and normals are expressed in the local-space of their compound shape.
+/// The results are sorted by increasing part indices.
+pub fn contacts_compound_compound<D>(
+    dispatcher: &D,
+    pos12: &Isometry<Real>,
+    compound1: &Compound,
+    compound2: &Compound,
+    prediction: Real,
+) -> Result<Vec<(usize, usize, Contact)>, Unsupported>
+where
+    D: ?Sized + QueryDispatcher,
+{
+    let contacts: Vec<Vec<_>> = compound1
+        .shapes()
+        .par_iter()
+        .zip(compound1.aabbs().par_iter())
+        .enumerate()
+        .map(|(i1, ((part_pos1, part1), aabb1))| {
+            let aabb1 = aabb1.loosened(prediction);
+            let mut part_contacts = Vec::new();
+            let mut candidates: Vec<_> = compound2.shapes_in_aabb(pos12, &aabb1).collect();
+            candidates.sort_by_key(|candidate| candidate.0);
+
+            for (i2, part_pos2, part2) in candidates {
+                let part_pos12 = part_pos1.inv_mul(&(pos12 * part_pos2));
+
+                if let Some(mut contact) =
+                    dispatcher.contact(&part_pos12, &**part1, part2, prediction)?
+                {
+                    contact.transform_by_mut(part_pos1, part_pos2);
+                    part_contacts.push((i1, i2, contact));
+                }
+            }
+
+            Ok(part_contacts)
+        })
+        .collect::<Result<_, Unsupported>>()?;
+
+    Ok(contacts.into_iter().flatten().collect())
+}
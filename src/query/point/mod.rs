@@ -9,18 +9,40 @@ pub use self::point_query::{PointProjection, PointQuery, PointQueryWithLocation}
 pub use self::point_support_map::local_point_projection_on_support_map;
 
 mod point_aabb;
+#[cfg(feature = "dim2")]
+mod point_annulus;
 mod point_ball;
 mod point_bounding_sphere;
 mod point_capsule;
 mod point_composite_shape;
 mod point_cuboid;
+#[cfg(feature = "dim3")]
+mod point_ellipsoid;
 mod point_halfspace;
 mod point_heightfield;
+#[cfg(feature = "dim3")]
+mod point_plate;
+#[cfg(feature = "dim2")]
+mod point_polygon;
+#[cfg(feature = "dim3")]
+mod point_prism;
 #[doc(hidden)]
 pub mod point_query;
 mod point_round_shape;
+mod point_scaled_shape;
+mod point_sdf_shape;
+#[cfg(feature = "dim2")]
+mod point_sector;
 mod point_segment;
+mod point_slab;
+#[cfg(feature = "dim3")]
+mod point_spherical_sector;
 mod point_support_map;
+mod point_tapered_capsule;
 #[cfg(feature = "dim3")]
 mod point_tetrahedron;
+#[cfg(feature = "dim3")]
+mod point_torus;
+mod point_transformed_shape;
 mod point_triangle;
+mod point_voxels;
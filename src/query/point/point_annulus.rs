@@ -0,0 +1,55 @@
+use crate::math::{Point, Real, Vector};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{Annulus, FeatureId};
+
+impl PointQuery for Annulus {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let distance = pt.coords.norm();
+        let inside = distance >= self.inner_radius && distance <= self.outer_radius;
+
+        if inside && solid {
+            return PointProjection::new(true, *pt);
+        }
+
+        let radius = if distance * 2.0 >= self.inner_radius + self.outer_radius {
+            self.outer_radius
+        } else {
+            self.inner_radius
+        };
+
+        if distance > 0.0 {
+            PointProjection::new(inside, Point::from(pt.coords * (radius / distance)))
+        } else {
+            // All the points of the inner circle are equally close.
+            PointProjection::new(inside, Point::from(Vector::x() * radius))
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Face(0))
+    }
+
+    #[inline]
+    fn distance_to_local_point(&self, pt: &Point<Real>, solid: bool) -> Real {
+        let distance = pt.coords.norm();
+        let dist = (distance - self.outer_radius).max(self.inner_radius - distance);
+
+        if solid && dist < 0.0 {
+            0.0
+        } else {
+            dist
+        }
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        let distance_squared = pt.coords.norm_squared();
+        distance_squared >= self.inner_radius * self.inner_radius
+            && distance_squared <= self.outer_radius * self.outer_radius
+    }
+}
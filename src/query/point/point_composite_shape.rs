@@ -1,24 +1,47 @@
 #![allow(unused_parens)] // Needed by the macro.
 
 use crate::bounding_volume::SimdAABB;
-use crate::math::{Point, Real, SimdReal, SIMD_WIDTH};
+use crate::math::{Point, Real, SimdReal, Vector, SIMD_WIDTH};
 use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
 use crate::query::{
     visitors::CompositePointContainmentTest, PointProjection, PointQuery, PointQueryWithLocation,
 };
+#[cfg(feature = "dim3")]
+use crate::shape::TriMeshPseudoNormals;
 use crate::shape::{
-    Compound, FeatureId, Polyline, SegmentPointLocation, TriMesh, TrianglePointLocation,
-    TypedSimdCompositeShape,
+    Compound, FeatureId, FilteredShape, Polyline, RoundPolyline, RoundTriMesh,
+    SegmentPointLocation, TriMesh, TrianglePointLocation, TypedSimdCompositeShape,
 };
 use na;
 use simba::simd::{SimdBool as _, SimdPartialOrd, SimdValue};
 
+/// The projection of a point on a composite shape without any part.
+///
+/// There is no closest point so this returns a point infinitely far away.
+fn empty_shape_projection() -> PointProjection {
+    PointProjection::new(false, Point::from(Vector::repeat(Real::MAX)))
+}
+
 impl PointQuery for Polyline {
     #[inline]
     fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
         self.project_local_point_and_get_location(point, solid).0
     }
 
+    #[inline]
+    fn project_local_point_with_max_dist(
+        &self,
+        point: &Point<Real>,
+        solid: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid)
+            .with_max_dist(max_dist);
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (proj, _))| proj)
+    }
+
     #[inline]
     fn project_local_point_and_get_feature(
         &self,
@@ -26,10 +49,13 @@ impl PointQuery for Polyline {
     ) -> (PointProjection, FeatureId) {
         let mut visitor =
             PointCompositeShapeProjWithFeatureBestFirstVisitor::new(self, point, false);
-        let (proj, (id, feature)) = self.quadtree().traverse_best_first(&mut visitor).unwrap().1;
-        let polyline_feature = self.segment_feature_to_polyline_feature(id, feature);
-
-        (proj, polyline_feature)
+        match self.quadtree().traverse_best_first(&mut visitor) {
+            Some((_, (proj, (id, feature)))) => {
+                let polyline_feature = self.segment_feature_to_polyline_feature(id, feature);
+                (proj, polyline_feature)
+            }
+            None => (empty_shape_projection(), FeatureId::Unknown),
+        }
     }
 
     // FIXME: implement distance_to_point too?
@@ -48,20 +74,161 @@ impl PointQuery for TriMesh {
         self.project_local_point_and_get_location(point, solid).0
     }
 
+    #[inline]
+    fn project_local_point_with_max_dist(
+        &self,
+        point: &Point<Real>,
+        solid: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        let mut visitor =
+            PointCompositeShapeProjWithLocationBestFirstVisitor::new(self, point, solid)
+                .with_max_dist(max_dist);
+        let result = self.quadtree().traverse_best_first(&mut visitor);
+
+        #[cfg(feature = "dim3")]
+        if let Some(pseudo_normals) = self.pseudo_normals() {
+            return match result {
+                Some((_, result)) => {
+                    Some(classify_with_pseudo_normals(self, pseudo_normals, point, solid, result).0)
+                }
+                // A point deep inside of the mesh is projected on itself when `solid` is true,
+                // even if the mesh surface is farther than `max_dist`.
+                None if solid => {
+                    Some(self.project_local_point(point, true)).filter(|proj| proj.is_inside)
+                }
+                None => None,
+            };
+        }
+
+        result.map(|(_, (proj, _))| proj)
+    }
+
     #[inline]
     fn project_local_point_and_get_feature(
         &self,
         point: &Point<Real>,
     ) -> (PointProjection, FeatureId) {
+        #[cfg(feature = "dim3")]
+        if self.pseudo_normals().is_some() && !self.is_empty() {
+            let (proj, (id, _)) = self.project_local_point_and_get_location(point, false);
+            return (proj, FeatureId::Face(id));
+        }
+
         let mut visitor =
             PointCompositeShapeProjWithFeatureBestFirstVisitor::new(self, point, false);
-        let (proj, (id, _feature)) = self.quadtree().traverse_best_first(&mut visitor).unwrap().1;
-        let feature_id = FeatureId::Face(id);
-        (proj, feature_id)
+        match self.quadtree().traverse_best_first(&mut visitor) {
+            Some((_, (proj, (id, _feature)))) => (proj, FeatureId::Face(id)),
+            None => (empty_shape_projection(), FeatureId::Unknown),
+        }
     }
 
     // FIXME: implement distance_to_point too?
 
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        #[cfg(feature = "dim3")]
+        if self.pseudo_normals().is_some() {
+            return self
+                .project_local_point_and_get_location(point, false)
+                .0
+                .is_inside;
+        }
+
+        let mut visitor = CompositePointContainmentTest::new(self, point);
+        self.quadtree().traverse_depth_first(&mut visitor);
+        visitor.found
+    }
+}
+
+impl PointQuery for RoundPolyline {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid);
+        match self.quadtree().traverse_best_first(&mut visitor) {
+            Some((_, (proj, _))) => proj,
+            None => empty_shape_projection(),
+        }
+    }
+
+    #[inline]
+    fn project_local_point_with_max_dist(
+        &self,
+        point: &Point<Real>,
+        solid: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid)
+            .with_max_dist(max_dist);
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (proj, _))| proj)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let mut visitor =
+            PointCompositeShapeProjWithFeatureBestFirstVisitor::new(self, point, false);
+        match self.quadtree().traverse_best_first(&mut visitor) {
+            Some((_, (proj, (id, feature)))) => {
+                let polyline = self.polyline();
+                (
+                    proj,
+                    polyline.segment_feature_to_polyline_feature(id, feature),
+                )
+            }
+            None => (empty_shape_projection(), FeatureId::Unknown),
+        }
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        let mut visitor = CompositePointContainmentTest::new(self, point);
+        self.quadtree().traverse_depth_first(&mut visitor);
+        visitor.found
+    }
+}
+
+impl PointQuery for RoundTriMesh {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid);
+        match self.quadtree().traverse_best_first(&mut visitor) {
+            Some((_, (proj, _))) => proj,
+            None => empty_shape_projection(),
+        }
+    }
+
+    #[inline]
+    fn project_local_point_with_max_dist(
+        &self,
+        point: &Point<Real>,
+        solid: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid)
+            .with_max_dist(max_dist);
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (proj, _))| proj)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let mut visitor =
+            PointCompositeShapeProjWithFeatureBestFirstVisitor::new(self, point, false);
+        match self.quadtree().traverse_best_first(&mut visitor) {
+            Some((_, (proj, (id, _feature)))) => (proj, FeatureId::Face(id)),
+            None => (empty_shape_projection(), FeatureId::Unknown),
+        }
+    }
+
     #[inline]
     fn contains_local_point(&self, point: &Point<Real>) -> bool {
         let mut visitor = CompositePointContainmentTest::new(self, point);
@@ -81,12 +248,32 @@ impl PointQuery for Compound {
              .0
     }
 
+    #[inline]
+    fn project_local_point_with_max_dist(
+        &self,
+        point: &Point<Real>,
+        solid: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid)
+            .with_max_dist(max_dist);
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (proj, _))| proj)
+    }
+
+    /// Projects a point on this compound shape and returns the feature of the part it is
+    /// projected on.
+    ///
+    /// The feature identifier is the one of the part, so it doesn't identify the part itself.
+    /// Use `project_local_point_and_get_location` to also retrieve the index of the part.
     #[inline]
     fn project_local_point_and_get_feature(
         &self,
         point: &Point<Real>,
     ) -> (PointProjection, FeatureId) {
-        (self.project_local_point(point, false), FeatureId::Unknown)
+        let (proj, (_, feature)) = self.project_local_point_and_get_location(point, false);
+        (proj, feature)
     }
 
     #[inline]
@@ -108,7 +295,37 @@ impl PointQueryWithLocation for Polyline {
     ) -> (PointProjection, Self::Location) {
         let mut visitor =
             PointCompositeShapeProjWithLocationBestFirstVisitor::new(self, point, solid);
-        self.quadtree().traverse_best_first(&mut visitor).unwrap().1
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|res| res.1)
+            .unwrap_or_else(|| {
+                (
+                    empty_shape_projection(),
+                    (0, SegmentPointLocation::OnVertex(0)),
+                )
+            })
+    }
+}
+
+impl PointQueryWithLocation for Compound {
+    /// The index of the part the point is projected on, and the feature of this part.
+    type Location = (u32, FeatureId);
+
+    #[inline]
+    fn project_local_point_and_get_location(
+        &self,
+        point: &Point<Real>,
+        solid: bool,
+    ) -> (PointProjection, Self::Location) {
+        let mut visitor =
+            PointCompositeShapeProjWithFeatureBestFirstVisitor::new(self, point, false);
+        let (proj, location) = self.quadtree().traverse_best_first(&mut visitor).unwrap().1;
+
+        if solid && proj.is_inside {
+            (PointProjection::new(true, *point), location)
+        } else {
+            (proj, location)
+        }
     }
 }
 
@@ -123,7 +340,100 @@ impl PointQueryWithLocation for TriMesh {
     ) -> (PointProjection, Self::Location) {
         let mut visitor =
             PointCompositeShapeProjWithLocationBestFirstVisitor::new(self, point, solid);
-        self.quadtree().traverse_best_first(&mut visitor).unwrap().1
+        let result = match self.quadtree().traverse_best_first(&mut visitor) {
+            Some((_, result)) => result,
+            None => {
+                return (
+                    empty_shape_projection(),
+                    (0, TrianglePointLocation::OnVertex(0)),
+                )
+            }
+        };
+
+        #[cfg(feature = "dim3")]
+        if let Some(pseudo_normals) = self.pseudo_normals() {
+            return classify_with_pseudo_normals(self, pseudo_normals, point, solid, result);
+        }
+
+        result
+    }
+}
+
+/// Sets `is_inside` on the projection of `point` on a triangle mesh, from the pseudo-normal of
+/// the mesh feature it has been projected on.
+#[cfg(feature = "dim3")]
+fn classify_with_pseudo_normals(
+    trimesh: &TriMesh,
+    pseudo_normals: &TriMeshPseudoNormals,
+    point: &Point<Real>,
+    solid: bool,
+    (mut proj, (id, location)): (PointProjection, (u32, TrianglePointLocation)),
+) -> (PointProjection, (u32, TrianglePointLocation)) {
+    let pseudo_normal = match location {
+        TrianglePointLocation::OnVertex(k) => {
+            let vid = trimesh.indices()[id as usize][k as usize];
+            Some(pseudo_normals.vertices_pseudo_normal[vid as usize])
+        }
+        TrianglePointLocation::OnEdge(k, _) => {
+            Some(pseudo_normals.edges_pseudo_normal[id as usize][k as usize])
+        }
+        TrianglePointLocation::OnFace(..) => trimesh.triangle(id).normal().map(|n| *n),
+        TrianglePointLocation::OnSolid => None,
+    };
+
+    if let Some(pseudo_normal) = pseudo_normal {
+        proj.is_inside = (point - proj.point).dot(&pseudo_normal) < 0.0;
+
+        if solid && proj.is_inside {
+            return (
+                PointProjection::new(true, *point),
+                (id, TrianglePointLocation::OnSolid),
+            );
+        }
+    }
+
+    (proj, (id, location))
+}
+
+impl<'a, S, F> PointQuery for FilteredShape<'a, S, F>
+where
+    S: TypedSimdCompositeShape,
+    F: Fn(S::PartId) -> bool,
+{
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid);
+        match self.typed_quadtree().traverse_best_first(&mut visitor) {
+            Some((_, (proj, _))) => proj,
+            None => empty_shape_projection(),
+        }
+    }
+
+    #[inline]
+    fn project_local_point_with_max_dist(
+        &self,
+        point: &Point<Real>,
+        solid: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid)
+            .with_max_dist(max_dist);
+        self.typed_quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (proj, _))| proj)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let mut visitor =
+            PointCompositeShapeProjWithFeatureBestFirstVisitor::new(self, point, false);
+        match self.typed_quadtree().traverse_best_first(&mut visitor) {
+            Some((_, (proj, (_, feature)))) => (proj, feature),
+            None => (empty_shape_projection(), FeatureId::Unknown),
+        }
     }
 }
 
@@ -139,6 +449,7 @@ macro_rules! gen_visitor(
             simd_point: Point<SimdReal>,
             #[allow(dead_code)] // This won't be used for the projection with feature.
             solid: bool,
+            max_dist: Real,
         }
 
         impl<'a, S> $Visitor<'a, S> {
@@ -149,8 +460,17 @@ macro_rules! gen_visitor(
                     point,
                     simd_point: Point::splat(*point),
                     solid,
+                    max_dist: Real::MAX,
                 }
             }
+
+            /// Ignores the parts of the composite shape farther than `max_dist` from the point.
+            ///
+            /// The traversal then yields no result if all the parts are farther than `max_dist`.
+            pub fn with_max_dist(mut self, max_dist: Real) -> Self {
+                self.max_dist = max_dist;
+                self
+            }
         }
 
         impl<'a, S> SimdBestFirstVisitor<S::PartId, SimdAABB> for $Visitor<'a, S>
@@ -167,7 +487,8 @@ macro_rules! gen_visitor(
                 data: Option<[Option<&S::PartId>; SIMD_WIDTH]>,
             ) -> SimdBestFirstVisitStatus<Self::Result> {
                 let dist = aabb.distance_to_local_point(&self.simd_point);
-                let mask = dist.simd_lt(SimdReal::splat(best));
+                let mask = dist.simd_lt(SimdReal::splat(best))
+                    & dist.simd_le(SimdReal::splat(self.max_dist));
 
                 if let Some(data) = data {
                     let mut weights = [0.0; SIMD_WIDTH];
@@ -192,7 +513,10 @@ macro_rules! gen_visitor(
                                 };
 
                                 weights[ii] = na::distance(self.point, &proj.point);
-                                results[ii] = Some((proj, (subshape_id $(, $extra_info)*)));
+
+                                if weights[ii] <= self.max_dist {
+                                    results[ii] = Some((proj, (subshape_id $(, $extra_info)*)));
+                                }
                             });
                         }
                     }
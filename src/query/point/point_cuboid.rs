@@ -23,9 +23,15 @@ impl PointQuery for Cuboid {
 
     #[inline]
     fn distance_to_local_point(&self, pt: &Point<Real>, solid: bool) -> Real {
-        let dl = Point::from(-self.half_extents);
-        let ur = Point::from(self.half_extents);
-        AABB::new(dl, ur).distance_to_local_point(pt, solid)
+        // The exact signed distance to a box centered at the origin.
+        let q = pt.coords.abs() - self.half_extents;
+        let outside = q.sup(&na::zero()).norm();
+
+        if solid {
+            outside
+        } else {
+            outside + q.max().min(0.0)
+        }
     }
 
     #[inline]
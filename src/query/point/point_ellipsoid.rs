@@ -0,0 +1,103 @@
+use crate::math::{Point, Real, Vector, DIM};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{Ellipsoid, FeatureId};
+use na::ComplexField;
+
+impl PointQuery for Ellipsoid {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let inside = self.contains_local_point(pt);
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            PointProjection::new(inside, self.project_local_point_on_boundary(pt))
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Face(0))
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        pt.coords.component_div(&self.radii).norm_squared() <= 1.0
+    }
+}
+
+impl Ellipsoid {
+    // The closest point to `pt` on the boundary of this ellipsoid.
+    //
+    // With `y = |pt|` (component-wise), the closest point in the first octant is
+    // `x_i = r_i² y_i / (t + r_i²)` where `t >= -min(r_i²)` is the root of
+    // `F(t) = Σ (r_i y_i / (t + r_i²))² - 1`, which is decreasing on this interval.
+    // If `F(-min(r_i²)) < 1`, there is no such root and the closest point is reached
+    // for `t = -min(r_i²)` instead, with a non-zero coordinate along the
+    // axis of smallest radius (this happens for points close to its principal plane).
+    fn project_local_point_on_boundary(&self, pt: &Point<Real>) -> Point<Real> {
+        let y = pt.coords.abs();
+        let sq_radii = self.radii.component_mul(&self.radii);
+        let imin = sq_radii.imin();
+        let tmin = -sq_radii[imin];
+
+        let eval = |t: Real| {
+            let mut res = 0.0;
+
+            for i in 0..DIM {
+                if y[i] != 0.0 {
+                    let ratio = self.radii[i] * y[i] / (t + sq_radii[i]);
+                    res += ratio * ratio;
+                }
+            }
+
+            res
+        };
+
+        let closest = |t: Real| -> Vector<Real> {
+            Vector::from_fn(|i, _| {
+                if y[i] != 0.0 {
+                    sq_radii[i] * y[i] / (t + sq_radii[i])
+                } else {
+                    0.0
+                }
+            })
+        };
+
+        let mut result = if y[imin] == 0.0 && eval(tmin) < 1.0 {
+            let mut result = closest(tmin);
+            let rem = (1.0 - result.component_div(&self.radii).norm_squared()).max(0.0);
+            result[imin] = self.radii[imin] * ComplexField::sqrt(rem);
+            result
+        } else {
+            let mut lo = tmin;
+            let mut hi = self.radii.component_mul(&y).norm();
+
+            // Bisection, until the interval can't be split any further.
+            for _ in 0..200 {
+                let mid = (lo + hi) * 0.5;
+
+                if mid <= lo || mid >= hi {
+                    break;
+                }
+
+                if eval(mid) > 1.0 {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+
+            closest((lo + hi) * 0.5)
+        };
+
+        for i in 0..DIM {
+            result[i] = result[i].copysign(pt[i]);
+        }
+
+        Point::from(result)
+    }
+}
@@ -1,30 +1,24 @@
-use crate::math::{Point, Real};
+use crate::bounding_volume::AABB;
+use crate::math::{Point, Real, Vector};
 use crate::query::{PointProjection, PointQuery, PointQueryWithLocation};
-use crate::shape::{FeatureId, HeightField, TrianglePointLocation};
+use crate::shape::{FeatureId, FilteredShape, HeightField, TrianglePointLocation};
 use na;
 use num::Bounded;
 
 impl PointQuery for HeightField {
     #[inline]
     fn project_local_point(&self, point: &Point<Real>, _: bool) -> PointProjection {
-        let mut smallest_dist = Real::max_value();
-        let mut best_proj = PointProjection::new(false, *point);
-
-        #[cfg(feature = "dim2")]
-        let iter = self.segments();
-        #[cfg(feature = "dim3")]
-        let iter = self.triangles();
-        for elt in iter {
-            let proj = elt.project_local_point(point, false);
-            let dist = na::distance_squared(point, &proj.point);
-
-            if dist < smallest_dist {
-                smallest_dist = dist;
-                best_proj = proj;
-            }
-        }
+        project_local_point_on_heightfield(self, point, |_| true).0
+    }
 
-        best_proj
+    #[inline]
+    fn project_local_point_with_max_dist(
+        &self,
+        point: &Point<Real>,
+        _: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        project_local_point_on_heightfield_with_max_dist(self, point, max_dist, |_| true)
     }
 
     #[inline]
@@ -32,8 +26,7 @@ impl PointQuery for HeightField {
         &self,
         point: &Point<Real>,
     ) -> (PointProjection, FeatureId) {
-        // FIXME: compute the feature properly.
-        (self.project_local_point(point, false), FeatureId::Unknown)
+        project_local_point_on_heightfield(self, point, |_| true)
     }
 
     // FIXME: implement distance_to_point too?
@@ -56,3 +49,122 @@ impl PointQueryWithLocation for HeightField {
         unimplemented!()
     }
 }
+
+impl<'a, F: Fn(u32) -> bool> PointQuery for FilteredShape<'a, HeightField, F> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, _: bool) -> PointProjection {
+        project_local_point_on_heightfield(self.shape(), point, self.filter()).0
+    }
+
+    #[inline]
+    fn project_local_point_with_max_dist(
+        &self,
+        point: &Point<Real>,
+        _: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        project_local_point_on_heightfield_with_max_dist(
+            self.shape(),
+            point,
+            max_dist,
+            self.filter(),
+        )
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        project_local_point_on_heightfield(self.shape(), point, self.filter())
+    }
+
+    #[inline]
+    fn contains_local_point(&self, _point: &Point<Real>) -> bool {
+        false
+    }
+}
+
+// Projects a point on the elements of the heightfield for which `filter` returns `true`, and
+// returns the heightfield feature it is projected on.
+fn project_local_point_on_heightfield(
+    heightfield: &HeightField,
+    point: &Point<Real>,
+    filter: impl Fn(u32) -> bool,
+) -> (PointProjection, FeatureId) {
+    let mut smallest_dist = Real::max_value();
+    // If all the elements are ignored there is no closest point so this stays infinitely far.
+    let mut best_proj = PointProjection::new(false, Point::from(Vector::repeat(Real::MAX)));
+    let mut best_feature = FeatureId::Unknown;
+
+    #[cfg(feature = "dim2")]
+    let iter = (0..heightfield.num_cells())
+        .filter(|i| filter(*i as u32))
+        .filter_map(|i| heightfield.segment_at(i).map(|seg| (seg, i)));
+    #[cfg(feature = "dim3")]
+    let iter = (0..heightfield.ncols()).flat_map(|j| {
+        let filter = &filter;
+        (0..heightfield.nrows()).flat_map(move |i| {
+            let (tri1, tri2) = heightfield.triangles_at(i, j);
+            let tri1 = tri1
+                .filter(|_| filter(heightfield.triangle_id(i, j, true)))
+                .map(|tri| (tri, (i, j, true)));
+            let tri2 = tri2
+                .filter(|_| filter(heightfield.triangle_id(i, j, false)))
+                .map(|tri| (tri, (i, j, false)));
+            tri1.into_iter().chain(tri2)
+        })
+    });
+
+    for (elt, elt_id) in iter {
+        let (proj, feature) = elt.project_local_point_and_get_feature(point);
+        let dist = na::distance_squared(point, &proj.point);
+
+        if dist < smallest_dist {
+            smallest_dist = dist;
+            best_proj = proj;
+
+            #[cfg(feature = "dim2")]
+            {
+                best_feature = heightfield.convert_segment_feature_id(elt_id, feature);
+            }
+            #[cfg(feature = "dim3")]
+            {
+                let (i, j, left) = elt_id;
+                best_feature = heightfield.convert_triangle_feature_id(i, j, left, feature);
+            }
+        }
+    }
+
+    (best_proj, best_feature)
+}
+
+// Projects a point on the elements of the heightfield for which `filter` returns `true`, only
+// considering the elements intersecting the AABB of radius `max_dist` centered at the point.
+fn project_local_point_on_heightfield_with_max_dist(
+    heightfield: &HeightField,
+    point: &Point<Real>,
+    max_dist: Real,
+    filter: impl Fn(u32) -> bool,
+) -> Option<PointProjection> {
+    let aabb = AABB::new(
+        point - Vector::repeat(max_dist),
+        point + Vector::repeat(max_dist),
+    );
+    let mut smallest_dist = max_dist * max_dist;
+    let mut best_proj = None;
+
+    heightfield.map_elements_in_local_aabb(&aabb, &mut |id, elt| {
+        if filter(id) {
+            let proj = elt.project_local_point(point, false);
+            let dist = na::distance_squared(point, &proj.point);
+
+            if dist <= smallest_dist {
+                smallest_dist = dist;
+                best_proj = Some(proj);
+            }
+        }
+    });
+
+    best_proj
+}
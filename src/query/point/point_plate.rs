@@ -0,0 +1,46 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Plate};
+
+impl PointQuery for Plate {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, _: bool) -> PointProjection {
+        let proj = Point::new(
+            pt.x.max(-self.half_extents.x).min(self.half_extents.x),
+            0.0,
+            pt.z.max(-self.half_extents.y).min(self.half_extents.y),
+        );
+
+        PointProjection::new(*pt == proj, proj)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let proj = self.project_local_point(pt, false);
+        let on_x_edge = proj.point.x.abs() == self.half_extents.x;
+        let on_z_edge = proj.point.z.abs() == self.half_extents.y;
+
+        // See the `PolygonalFeatureMap` implementation of `Plate` for the feature numbering.
+        let vertex_id = |x: Real, z: Real| match (x >= 0.0, z >= 0.0) {
+            (true, true) => 0,
+            (true, false) => 1,
+            (false, false) => 2,
+            (false, true) => 3,
+        };
+
+        let feature = match (on_x_edge, on_z_edge) {
+            (true, true) => FeatureId::Vertex(vertex_id(proj.point.x, proj.point.z)),
+            (true, false) if proj.point.x >= 0.0 => FeatureId::Edge(0),
+            (true, false) => FeatureId::Edge(2),
+            (false, true) if proj.point.z >= 0.0 => FeatureId::Edge(3),
+            (false, true) => FeatureId::Edge(1),
+            (false, false) if pt.y >= 0.0 => FeatureId::Face(0),
+            (false, false) => FeatureId::Face(1),
+        };
+
+        (proj, feature)
+    }
+}
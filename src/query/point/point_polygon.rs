@@ -0,0 +1,34 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Polygon};
+
+impl PointQuery for Polygon {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let inside = self.contains_local_point(pt);
+
+        if inside && solid {
+            return PointProjection::new(true, *pt);
+        }
+
+        let proj = self.boundary().project_local_point(pt, false);
+        PointProjection::new(inside, proj.point)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let (proj, feature) = self.boundary().project_local_point_and_get_feature(pt);
+        (
+            PointProjection::new(self.contains_local_point(pt), proj.point),
+            feature,
+        )
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        self.triangulation().contains_local_point(pt)
+    }
+}
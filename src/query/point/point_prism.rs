@@ -0,0 +1,132 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Prism};
+use na::Point2;
+
+/// The feature of the cross-section of a prism a 2D point projects on.
+enum CrossSectionFeature {
+    Interior,
+    Vertex(u32),
+    Edge(u32),
+}
+
+impl Prism {
+    /// Projects a 2D point on the boundary of the cross-section of this prism, assuming
+    /// the point lies outside of the cross-section.
+    fn project_on_cross_section_boundary(
+        &self,
+        pt: &Point2<Real>,
+    ) -> (Point2<Real>, CrossSectionFeature) {
+        let points = self.cross_section();
+        let mut best = (points[0], CrossSectionFeature::Vertex(0));
+        let mut best_dist = Real::MAX;
+
+        for i1 in 0..points.len() {
+            let i2 = (i1 + 1) % points.len();
+            let edge = points[i2] - points[i1];
+            let t = (pt - points[i1]).dot(&edge) / edge.norm_squared();
+
+            let candidate = if t <= 0.0 {
+                (points[i1], CrossSectionFeature::Vertex(i1 as u32))
+            } else if t >= 1.0 {
+                (points[i2], CrossSectionFeature::Vertex(i2 as u32))
+            } else {
+                (points[i1] + edge * t, CrossSectionFeature::Edge(i1 as u32))
+            };
+
+            let dist = na::distance_squared(pt, &candidate.0);
+
+            if dist < best_dist {
+                best_dist = dist;
+                best = candidate;
+            }
+        }
+
+        best
+    }
+
+    fn project_local_point_with_feature(
+        &self,
+        pt: &Point<Real>,
+        solid: bool,
+    ) -> (PointProjection, FeatureId) {
+        let n = self.cross_section().len() as u32;
+        let h = self.half_height();
+        let pt2 = Point2::new(pt.x, pt.z);
+
+        // The signed distance to the closest side face, and its index.
+        let (side, side_dist) = self
+            .cross_section()
+            .iter()
+            .zip(self.cross_section_normals().iter())
+            .map(|(p, normal)| normal.dot(&(pt2 - p)))
+            .enumerate()
+            .fold((0, -Real::MAX), |best, (i, dist)| {
+                if dist > best.1 {
+                    (i, dist)
+                } else {
+                    best
+                }
+            });
+
+        let inside_cross_section = side_dist <= 0.0;
+        let cap = if pt.y >= 0.0 { n + 1 } else { n };
+
+        if inside_cross_section && pt.y.abs() <= h {
+            if solid {
+                return (PointProjection::new(true, *pt), FeatureId::Unknown);
+            }
+
+            // Project on the closest face.
+            let cap_dist = h - pt.y.abs();
+
+            return if cap_dist <= -side_dist {
+                let proj = Point::new(pt.x, h.copysign(pt.y), pt.z);
+                (PointProjection::new(true, proj), FeatureId::Face(cap))
+            } else {
+                let normal = self.face_normal(side as u32);
+                let proj = pt - normal * side_dist;
+                (
+                    PointProjection::new(true, proj),
+                    FeatureId::Face(side as u32),
+                )
+            };
+        }
+
+        let (proj2, feature2) = if inside_cross_section {
+            (pt2, CrossSectionFeature::Interior)
+        } else {
+            self.project_on_cross_section_boundary(&pt2)
+        };
+
+        let y = pt.y.max(-h).min(h);
+        let proj = Point::new(proj2.x, y, proj2.y);
+        let on_cap = pt.y.abs() > h;
+        let shift = if pt.y >= 0.0 { n } else { 0 };
+
+        let feature = match (feature2, on_cap) {
+            (CrossSectionFeature::Interior, _) => FeatureId::Face(cap),
+            (CrossSectionFeature::Vertex(i), true) => FeatureId::Vertex(i + shift),
+            (CrossSectionFeature::Edge(i), true) => FeatureId::Edge(i + shift),
+            (CrossSectionFeature::Vertex(i), false) => FeatureId::Edge(2 * n + i),
+            (CrossSectionFeature::Edge(i), false) => FeatureId::Face(i),
+        };
+
+        (PointProjection::new(false, proj), feature)
+    }
+}
+
+impl PointQuery for Prism {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        self.project_local_point_with_feature(pt, solid).0
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        self.project_local_point_with_feature(pt, false)
+    }
+}
@@ -54,12 +54,45 @@ pub trait PointQuery {
         self.project_local_point(pt, false).is_inside
     }
 
+    /// Projects a point on `self`, unless it is farther than `max_dist` from `self`.
+    ///
+    /// Returns `None` if the distance between the point and its projection is greater than
+    /// `max_dist`. Shapes with an acceleration structure, e.g., triangle meshes, stop looking
+    /// for the projection as soon as every part left is known to be farther than `max_dist`.
+    fn project_local_point_with_max_dist(
+        &self,
+        pt: &Point<Real>,
+        solid: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        let proj = self.project_local_point(pt, solid);
+
+        if na::distance(pt, &proj.point) > max_dist {
+            None
+        } else {
+            Some(proj)
+        }
+    }
+
     /// Projects a point on `self` transformed by `m`.
     fn project_point(&self, m: &Isometry<Real>, pt: &Point<Real>, solid: bool) -> PointProjection {
         self.project_local_point(&m.inverse_transform_point(pt), solid)
             .transform_by(m)
     }
 
+    /// Projects a point on `self` transformed by `m`, unless it is farther than `max_dist`
+    /// from `self`.
+    fn project_point_with_max_dist(
+        &self,
+        m: &Isometry<Real>,
+        pt: &Point<Real>,
+        solid: bool,
+        max_dist: Real,
+    ) -> Option<PointProjection> {
+        self.project_local_point_with_max_dist(&m.inverse_transform_point(pt), solid, max_dist)
+            .map(|proj| proj.transform_by(m))
+    }
+
     /// Computes the minimal distance between a point and `self` transformed by `m`.
     #[inline]
     fn distance_to_point(&self, m: &Isometry<Real>, pt: &Point<Real>, solid: bool) -> Real {
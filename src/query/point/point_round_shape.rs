@@ -1,26 +1,127 @@
 use crate::math::{Point, Real};
 use crate::query::gjk::VoronoiSimplex;
 use crate::query::{PointProjection, PointQuery};
-use crate::shape::{FeatureId, RoundShape, SupportMap};
+use crate::shape::{FeatureId, RoundShape, SharedShape, SupportMap};
+use na::Unit;
 
-// TODO: if PointQuery had a `project_point_with_normal` method, we could just
-// call this and adjust the projected point accordingly.
-impl<S: SupportMap> PointQuery for RoundShape<S> {
+impl<S: SupportMap + PointQuery> PointQuery for RoundShape<S> {
     #[inline]
     fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
-        crate::query::details::local_point_projection_on_support_map(
-            self,
-            &mut VoronoiSimplex::new(),
-            point,
-            solid,
-        )
+        let base_proj = support_map_projection(&self.base_shape, point, solid);
+        round_projection(base_proj, self.border_radius, point, solid, || {
+            support_map_projection(self, point, solid)
+        })
     }
 
+    /// Projects a point on this rounded shape and returns the feature of the base shape
+    /// closest to the point.
     #[inline]
     fn project_local_point_and_get_feature(
         &self,
         point: &Point<Real>,
     ) -> (PointProjection, FeatureId) {
-        (self.project_local_point(point, false), FeatureId::Unknown)
+        let (base_proj, feature) = self.base_shape.project_local_point_and_get_feature(point);
+        let proj = round_projection(base_proj, self.border_radius, point, false, || {
+            support_map_projection(self, point, false)
+        });
+        (proj, feature)
+    }
+}
+
+impl PointQuery for RoundShape<SharedShape> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        let base_proj = match self.base_support_map() {
+            Some(base_support_map) => support_map_projection(base_support_map, point, solid),
+            // The base shape isn't convex, but the dilation of its own projection is still exact.
+            None => self.base_shape.project_local_point(point, solid),
+        };
+
+        round_projection(base_proj, self.border_radius, point, solid, || {
+            self.boundary_point_projection(point, solid)
+        })
+    }
+
+    /// Projects a point on this rounded shape and returns the feature of the base shape
+    /// closest to the point.
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let (base_proj, feature) = self.base_shape.project_local_point_and_get_feature(point);
+        let proj = round_projection(base_proj, self.border_radius, point, false, || {
+            self.boundary_point_projection(point, false)
+        });
+        (proj, feature)
+    }
+}
+
+impl RoundShape<SharedShape> {
+    // Projects a point lying on the boundary of the base shape.
+    fn boundary_point_projection(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        if self.base_support_map().is_some() {
+            return support_map_projection(self, point, solid);
+        }
+
+        // Without a support map, move the point along the normal of the base shape.
+        let (_, feature) = self.base_shape.project_local_point_and_get_feature(point);
+        match self.base_shape.feature_normal_at_point(feature, point) {
+            Some(normal) if !solid => {
+                PointProjection::new(true, point + *normal * self.border_radius)
+            }
+            _ => PointProjection::new(true, *point),
+        }
+    }
+}
+
+fn support_map_projection<G: ?Sized + SupportMap>(
+    shape: &G,
+    point: &Point<Real>,
+    solid: bool,
+) -> PointProjection {
+    crate::query::details::local_point_projection_on_support_map(
+        shape,
+        &mut VoronoiSimplex::new(),
+        point,
+        solid,
+    )
+}
+
+// Projects a point on a base shape dilated by `border_radius`, given the projection `proj`
+// of this point on the base shape. `on_boundary` projects the point if it lies on the
+// boundary of the base shape.
+fn round_projection(
+    proj: PointProjection,
+    border_radius: Real,
+    point: &Point<Real>,
+    solid: bool,
+    on_boundary: impl FnOnce() -> PointProjection,
+) -> PointProjection {
+    // Shift the projection on the base shape by the border radius. This is more
+    // accurate than running GJK on the rounded shape directly since the base
+    // shape is usually polyhedral.
+    if proj.is_inside && solid {
+        return proj;
+    }
+
+    let shift = if proj.is_inside {
+        proj.point - point
+    } else {
+        point - proj.point
+    };
+
+    if let Some((dir, dist)) = Unit::try_new_and_get(shift, crate::math::DEFAULT_EPSILON) {
+        let is_inside = proj.is_inside || dist <= border_radius;
+
+        if is_inside && solid {
+            PointProjection::new(true, *point)
+        } else {
+            PointProjection::new(is_inside, proj.point + *dir * border_radius)
+        }
+    } else {
+        // The point lies on the boundary of the base shape so the
+        // projection direction is ill-defined.
+        on_boundary()
     }
 }
@@ -0,0 +1,51 @@
+use crate::math::{Point, Real};
+use crate::query::gjk::VoronoiSimplex;
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, ScaledShape, SupportMap};
+use na::Unit;
+
+impl<S: SupportMap + PointQuery> PointQuery for ScaledShape<S> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        // A non-uniform scaling doesn't preserve distances so we can't project the
+        // point on the base shape directly.
+        crate::query::details::local_point_projection_on_support_map(
+            self,
+            &mut VoronoiSimplex::new(),
+            point,
+            solid,
+        )
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let proj = self.project_local_point(point, false);
+        let dpt = *point - proj.point;
+        let local_dir = if proj.is_inside { -dpt } else { dpt };
+
+        // The scaling maps an outward normal `n` of this shape to the outward normal
+        // `scale * n` of the base shape. Because the base shape is convex, the point
+        // offset along this normal is projected on the feature we are looking for.
+        let base_dir = local_dir.component_mul(&self.scale);
+
+        if let Some(base_dir) = Unit::try_new(base_dir, crate::math::DEFAULT_EPSILON) {
+            let base_pt = self.unscale_point(&proj.point) + *base_dir;
+            let feature = self
+                .base_shape
+                .project_local_point_and_get_feature(&base_pt)
+                .1;
+            (proj, feature)
+        } else {
+            (proj, FeatureId::Unknown)
+        }
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        self.base_shape
+            .contains_local_point(&self.unscale_point(point))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, SdfShape};
+
+impl PointQuery for SdfShape {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let dist = self.signed_distance_to_local_point(pt);
+        let inside = dist <= 0.0;
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            PointProjection::new(inside, self.descend_to_surface(pt, dist))
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Unknown)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        self.signed_distance_to_local_point(pt) <= 0.0
+    }
+}
+
+impl SdfShape {
+    // Moves `pt`, with the signed distance `dist`, along the gradient of the SDF
+    // until it reaches the surface of this shape.
+    fn descend_to_surface(&self, pt: &Point<Real>, mut dist: Real) -> Point<Real> {
+        let mut proj = *pt;
+
+        for _ in 0..self.max_iterations {
+            if dist.abs() <= self.tolerance {
+                break;
+            }
+
+            match self.gradient(&proj) {
+                Some(gradient) => proj -= gradient * dist,
+                // We reached a critical point of the SDF, e.g., the medial axis of the shape.
+                None => break,
+            }
+
+            dist = self.signed_distance_to_local_point(&proj);
+        }
+
+        proj
+    }
+}
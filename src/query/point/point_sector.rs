@@ -0,0 +1,69 @@
+use crate::math::{Point, Real, Vector};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Sector};
+use na::RealField;
+
+impl Sector {
+    /// Projects `pt` on the boundary of this sector.
+    ///
+    /// The feature is `FeatureId::Face(0)` for the arc, `FeatureId::Face(1)` for the edge
+    /// at `start_angle`, and `FeatureId::Face(2)` for the edge at `end_angle`.
+    fn project_local_point_on_boundary(&self, pt: &Point<Real>) -> (Point<Real>, FeatureId) {
+        let project_on_edge = |dir: Vector<Real>| {
+            let s = pt.coords.dot(&dir).max(0.0).min(self.radius);
+            Point::from(dir * s)
+        };
+
+        let on_start = project_on_edge(self.start_dir());
+        let on_end = project_on_edge(self.end_dir());
+        let mut best = (on_start, FeatureId::Face(1));
+
+        if na::distance_squared(pt, &on_end) < na::distance_squared(pt, &best.0) {
+            best = (on_end, FeatureId::Face(2));
+        }
+
+        let distance = pt.coords.norm();
+        if distance > 0.0 && self.contains_angle(RealField::atan2(pt.y, pt.x)) {
+            let on_arc = Point::from(pt.coords * (self.radius / distance));
+
+            if na::distance_squared(pt, &on_arc) < na::distance_squared(pt, &best.0) {
+                best = (on_arc, FeatureId::Face(0));
+            }
+        }
+
+        best
+    }
+}
+
+impl PointQuery for Sector {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let inside = self.contains_local_point(pt);
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let (proj, _) = self.project_local_point_on_boundary(pt);
+            PointProjection::new(inside, proj)
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let inside = self.contains_local_point(pt);
+        let (proj, feature) = self.project_local_point_on_boundary(pt);
+        (PointProjection::new(inside, proj), feature)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        let distance_squared = pt.coords.norm_squared();
+
+        distance_squared == 0.0
+            || (distance_squared <= self.radius * self.radius
+                && self.contains_angle(RealField::atan2(pt.y, pt.x)))
+    }
+}
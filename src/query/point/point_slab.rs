@@ -0,0 +1,63 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Slab};
+
+impl PointQuery for Slab {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        self.project_local_point_and_get_feature_with_solid(pt, solid)
+            .0
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        self.project_local_point_and_get_feature_with_solid(pt, false)
+    }
+
+    #[inline]
+    fn distance_to_local_point(&self, pt: &Point<Real>, solid: bool) -> Real {
+        // This will automatically be negative if the point is inside.
+        let dist = self.normal.dot(&pt.coords).abs() - self.half_thickness;
+
+        if dist < 0.0 && solid {
+            0.0
+        } else {
+            dist
+        }
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        self.normal.dot(&pt.coords).abs() <= self.half_thickness
+    }
+}
+
+impl Slab {
+    // The face 0 is the boundary with the outward normal `self.normal`, and the face 1
+    // is the boundary with the outward normal `-self.normal`.
+    fn project_local_point_and_get_feature_with_solid(
+        &self,
+        pt: &Point<Real>,
+        solid: bool,
+    ) -> (PointProjection, FeatureId) {
+        let d = self.normal.dot(&pt.coords);
+        let inside = d.abs() <= self.half_thickness;
+        let (offset, feature) = if d >= 0.0 {
+            (d - self.half_thickness, FeatureId::Face(0))
+        } else {
+            (d + self.half_thickness, FeatureId::Face(1))
+        };
+
+        if inside && solid {
+            (PointProjection::new(true, *pt), feature)
+        } else {
+            (
+                PointProjection::new(inside, *pt - *self.normal * offset),
+                feature,
+            )
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::math::{Point, Real, Vector};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, SphericalSector};
+use na::ComplexField;
+
+impl SphericalSector {
+    /// Projects `pt` on the boundary of this spherical sector.
+    ///
+    /// The feature is `FeatureId::Face(0)` for the spherical cap, and `FeatureId::Face(1)`
+    /// for the lateral surface of the cone.
+    fn project_local_point_on_boundary(&self, pt: &Point<Real>) -> (Point<Real>, FeatureId) {
+        // The closest point lies on the half-plane bounded by the `y` axis containing `pt`,
+        // where the lateral surface of the cone is the segment between the apex and the rim.
+        let rim = self.rim_point(&pt.coords);
+        let rim_dir = rim.coords / self.radius;
+        let s = pt.coords.dot(&rim_dir).max(0.0).min(self.radius);
+        let mut best = (Point::from(rim_dir * s), FeatureId::Face(1));
+
+        let distance = pt.coords.norm();
+        if distance > 0.0 && self.contains_direction(&pt.coords) {
+            let on_cap = Point::from(pt.coords * (self.radius / distance));
+
+            if na::distance_squared(pt, &on_cap) < na::distance_squared(pt, &best.0) {
+                best = (on_cap, FeatureId::Face(0));
+            }
+        }
+
+        best
+    }
+
+    /// The outward normal of the lateral surface of the cone, on the half-plane bounded by
+    /// the `y` axis containing `pt`.
+    pub(crate) fn lateral_normal(&self, pt: &Point<Real>) -> Vector<Real> {
+        let rim_dir = self.rim_point(&pt.coords).coords / self.radius;
+        let (sin, cos) = ComplexField::sin_cos(self.half_angle);
+        // Rotate the direction of the rim by pi / 2 away from the axis.
+        (rim_dir - Vector::y() * cos) * (cos / sin) - Vector::y() * sin
+    }
+}
+
+impl PointQuery for SphericalSector {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let inside = self.contains_local_point(pt);
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let (proj, _) = self.project_local_point_on_boundary(pt);
+            PointProjection::new(inside, proj)
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let inside = self.contains_local_point(pt);
+        let (proj, feature) = self.project_local_point_on_boundary(pt);
+        (PointProjection::new(inside, proj), feature)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        pt.coords.norm_squared() <= self.radius * self.radius && self.contains_direction(&pt.coords)
+    }
+}
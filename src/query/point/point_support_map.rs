@@ -7,11 +7,11 @@ use crate::query::{PointProjection, PointQuery};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexPolyhedron, Cylinder};
-use crate::shape::{FeatureId, SupportMap};
+use crate::shape::{Cone, ConvexPolyhedron, Cylinder, EllipticCylinder, SolidOfRevolution};
+use crate::shape::{FeatureId, MinkowskiSum, SupportMap};
 
 /// Projects a point on a shape using the GJK algorithm.
-pub fn local_point_projection_on_support_map<G>(
+pub fn local_point_projection_on_support_map<G: ?Sized>(
     shape: &G,
     simplex: &mut VoronoiSimplex,
     point: &Point<Real>,
@@ -60,7 +60,41 @@ impl PointQuery for Cylinder {
         &self,
         point: &Point<Real>,
     ) -> (PointProjection, FeatureId) {
-        (self.project_local_point(point, false), FeatureId::Unknown)
+        let proj = self.project_local_point(point, false);
+        let dpt = *point - proj.point;
+        let local_dir = if proj.is_inside { -dpt } else { dpt };
+
+        if let Some(local_dir) = Unit::try_new(local_dir, crate::math::DEFAULT_EPSILON) {
+            let feature = self.support_feature_id_toward(&local_dir);
+            (proj, feature)
+        } else {
+            (proj, FeatureId::Unknown)
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl PointQuery for EllipticCylinder {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        local_point_projection_on_support_map(self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let proj = self.project_local_point(point, false);
+        let dpt = *point - proj.point;
+        let local_dir = if proj.is_inside { -dpt } else { dpt };
+
+        if let Some(local_dir) = Unit::try_new(local_dir, crate::math::DEFAULT_EPSILON) {
+            let feature = self.support_feature_id_toward(&local_dir);
+            (proj, feature)
+        } else {
+            (proj, FeatureId::Unknown)
+        }
     }
 }
 
@@ -76,7 +110,48 @@ impl PointQuery for Cone {
         &self,
         point: &Point<Real>,
     ) -> (PointProjection, FeatureId) {
-        (self.project_local_point(point, false), FeatureId::Unknown)
+        let proj = self.project_local_point(point, false);
+        let dpt = *point - proj.point;
+        let local_dir = if proj.is_inside { -dpt } else { dpt };
+
+        if let Some(local_dir) = Unit::try_new(local_dir, crate::math::DEFAULT_EPSILON) {
+            let feature = self.support_feature_id_toward(&local_dir);
+            (proj, feature)
+        } else {
+            (proj, FeatureId::Unknown)
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl PointQuery for SolidOfRevolution {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        local_point_projection_on_support_map(self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let proj = self.project_local_point(point, false);
+        let dpt = *point - proj.point;
+        let local_dir = if proj.is_inside { -dpt } else { dpt };
+
+        if let Some(local_dir) = Unit::try_new(local_dir, crate::math::DEFAULT_EPSILON) {
+            let feature = self.support_feature_id_toward(&local_dir);
+            (proj, feature)
+        } else {
+            (proj, FeatureId::Unknown)
+        }
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        self.radius_at(point.y)
+            .map(|r| point.x * point.x + point.z * point.z <= r * r)
+            .unwrap_or(false)
     }
 }
 
@@ -129,3 +204,18 @@ impl PointQuery for ConvexPolygon {
         }
     }
 }
+
+impl<A: SupportMap, B: SupportMap> PointQuery for MinkowskiSum<A, B> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        local_point_projection_on_support_map(self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(point, false), FeatureId::Unknown)
+    }
+}
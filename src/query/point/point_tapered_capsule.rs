@@ -0,0 +1,82 @@
+use crate::math::{Point, Real, Vector};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, TaperedCapsule};
+use crate::utils::WBasis;
+use na::ComplexField;
+
+impl PointQuery for TaperedCapsule {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let (a, b) = (self.segment.a, self.segment.b);
+        let (radius_a, radius_b) = (self.radius_a, self.radius_b);
+        let height = self.height();
+
+        // One ball contains the other.
+        if height + radius_b <= radius_a {
+            return project_on_ball(&a, radius_a, pt, solid);
+        } else if height + radius_a <= radius_b {
+            return project_on_ball(&b, radius_b, pt, solid);
+        }
+
+        // Work on the half-plane containing `pt` and the axis, where `t` is the coordinate
+        // along the axis and `h` the distance to the axis. The lateral surface is the set of
+        // points with the normal `(sin_slope, cos_slope)` in this half-plane.
+        let axis = (b - a) / height;
+        let sin_slope = (radius_a - radius_b) / height;
+        let cos_slope = ComplexField::sqrt(1.0 - sin_slope * sin_slope);
+        let dpt = pt - a;
+        let t = dpt.dot(&axis);
+        let perp = dpt - axis * t;
+        let h = perp.norm();
+        // The coordinate of `pt` along the lateral edge, from its point touching the ball at `a`.
+        let u = t * cos_slope - h * sin_slope;
+
+        if u < 0.0 {
+            project_on_ball(&a, radius_a, pt, solid)
+        } else if u > height * cos_slope {
+            project_on_ball(&b, radius_b, pt, solid)
+        } else {
+            let ortho = if h > 0.0 {
+                perp / h
+            } else {
+                axis.orthonormal_basis()[0]
+            };
+            let normal = axis * sin_slope + ortho * cos_slope;
+            let dist = t * sin_slope + h * cos_slope - radius_a;
+            let inside = dist <= 0.0;
+
+            if inside && solid {
+                PointProjection::new(true, *pt)
+            } else {
+                PointProjection::new(inside, pt - normal * dist)
+            }
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Face(0))
+    }
+}
+
+fn project_on_ball(
+    center: &Point<Real>,
+    radius: Real,
+    pt: &Point<Real>,
+    solid: bool,
+) -> PointProjection {
+    let dpt = pt - center;
+    let dist = dpt.norm();
+    let inside = dist <= radius;
+
+    if inside && solid {
+        PointProjection::new(true, *pt)
+    } else if dist > 0.0 {
+        PointProjection::new(inside, center + dpt * (radius / dist))
+    } else {
+        PointProjection::new(inside, center + Vector::ith(1, radius))
+    }
+}
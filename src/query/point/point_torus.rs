@@ -0,0 +1,54 @@
+use crate::math::{Point, Real, Vector};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Torus};
+
+impl PointQuery for Torus {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let tube_center = self.tube_center_point(pt);
+        let dpt = pt - tube_center;
+        let distance_squared = dpt.norm_squared();
+
+        let inside = distance_squared <= self.minor_radius * self.minor_radius;
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else if distance_squared > 0.0 {
+            let proj = tube_center + dpt * (self.minor_radius / distance_squared.sqrt());
+            PointProjection::new(inside, proj)
+        } else {
+            // The point is on the circle at the center of the tube: all the points of
+            // the circle of radius `minor_radius` around it are equally close.
+            let dir = tube_center
+                .coords
+                .try_normalize(0.0)
+                .unwrap_or_else(Vector::x);
+            let proj = tube_center + dir * self.minor_radius;
+            PointProjection::new(inside, proj)
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Face(0))
+    }
+
+    #[inline]
+    fn distance_to_local_point(&self, pt: &Point<Real>, solid: bool) -> Real {
+        let dist = self.signed_distance_to_local_point(pt);
+
+        if solid && dist < 0.0 {
+            0.0
+        } else {
+            dist
+        }
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        self.signed_distance_to_local_point(pt) <= 0.0
+    }
+}
@@ -0,0 +1,29 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, TransformedShape};
+
+impl<S: PointQuery> PointQuery for TransformedShape<S> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        self.shape.project_point(&self.position, point, solid)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        self.shape
+            .project_point_and_get_feature(&self.position, point)
+    }
+
+    #[inline]
+    fn distance_to_local_point(&self, point: &Point<Real>, solid: bool) -> Real {
+        self.shape.distance_to_point(&self.position, point, solid)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        self.shape.contains_point(&self.position, point)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::math::{Point, Real, Vector, DIM};
 use crate::query::{PointProjection, PointQuery, PointQueryWithLocation};
-use crate::shape::{FeatureId, Triangle, TrianglePointLocation};
+use crate::shape::{FeatureId, Segment, SegmentPointLocation, Triangle, TrianglePointLocation};
 
 #[inline]
 fn compute_result(pt: &Point<Real>, proj: Point<Real>) -> PointProjection {
@@ -17,6 +17,43 @@ fn compute_result(pt: &Point<Real>, proj: Point<Real>) -> PointProjection {
     }
 }
 
+// Projects `pt` on the closest edge of `triangle`, which is well-defined even if the
+// triangle is degenerate.
+fn project_on_closest_edge(
+    triangle: &Triangle,
+    pt: &Point<Real>,
+) -> (Point<Real>, TrianglePointLocation) {
+    // The edges, with the vertex ids of their endpoints, following the edge numbering of
+    // `TrianglePointLocation::OnEdge`.
+    let edges = [
+        (Segment::new(triangle.a, triangle.b), [0, 1]),
+        (Segment::new(triangle.b, triangle.c), [1, 2]),
+        (Segment::new(triangle.a, triangle.c), [0, 2]),
+    ];
+    let mut best = None;
+    let mut best_dist = Real::MAX;
+
+    for (i, (edge, vids)) in edges.iter().enumerate() {
+        let (proj, loc) = edge.project_local_point_and_get_location(pt, false);
+        let dist = na::distance_squared(pt, &proj.point);
+
+        if dist < best_dist {
+            let loc = match loc {
+                SegmentPointLocation::OnVertex(k) => {
+                    TrianglePointLocation::OnVertex(vids[k as usize])
+                }
+                SegmentPointLocation::OnEdge(bcoords) => {
+                    TrianglePointLocation::OnEdge(i as u32, bcoords)
+                }
+            };
+            best_dist = dist;
+            best = Some((proj.point, loc));
+        }
+    }
+
+    best.unwrap_or((triangle.a, TrianglePointLocation::OnVertex(0)))
+}
+
 impl PointQuery for Triangle {
     #[inline]
     fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
@@ -215,23 +252,26 @@ impl PointQueryWithLocation for Triangle {
                 );
             }
             ProjectionInfo::OnFace(face_side, va, vb, vc) => {
+                // NOTE: the denominator is zero if the triangle is degenerate, or because of
+                // numerical instability if it is nearly degenerate. It has no interior then,
+                // so the point is projected on its closest edge.
+                if va + vb + vc == 0.0 {
+                    let (res, loc) = project_on_closest_edge(self, pt);
+                    return (compute_result(pt, res), loc);
+                }
+
                 // Voronoï region of the face.
                 if DIM != 2 {
-                    // NOTE: in some cases, numerical instability
-                    // may result in the denominator being zero
-                    // when the triangle is nearly degenerate.
-                    if va + vb + vc != 0.0 {
-                        let denom = _1 / (va + vb + vc);
-                        let v = vb * denom;
-                        let w = vc * denom;
-                        let bcoords = [_1 - v - w, v, w];
-                        let res = a + ab * v + ac * w;
-
-                        return (
-                            compute_result(pt, res),
-                            TrianglePointLocation::OnFace(face_side as u32, bcoords),
-                        );
-                    }
+                    let denom = _1 / (va + vb + vc);
+                    let v = vb * denom;
+                    let w = vc * denom;
+                    let bcoords = [_1 - v - w, v, w];
+                    let res = a + ab * v + ac * w;
+
+                    return (
+                        compute_result(pt, res),
+                        TrianglePointLocation::OnFace(face_side as u32, bcoords),
+                    );
                 }
             }
         }
@@ -245,47 +285,7 @@ impl PointQueryWithLocation for Triangle {
             )
         } else {
             // We have to project on the closest edge.
-
-            // FIXME: this might be optimizable.
-            // FIXME: be careful with numerical errors.
-            let v = ab_ap / (ab_ap - ab_bp); // proj on ab = a + ab * v
-            let w = ac_ap / (ac_ap - ac_cp); // proj on ac = a + ac * w
-            let u = (ac_bp - ab_bp) / (ac_bp - ab_bp + ab_cp - ac_cp); // proj on bc = b + bc * u
-
-            let bc = c - b;
-            let d_ab = ap.norm_squared() - (ab.norm_squared() * v * v);
-            let d_ac = ap.norm_squared() - (ac.norm_squared() * u * u);
-            let d_bc = bp.norm_squared() - (bc.norm_squared() * w * w);
-
-            let proj;
-            let loc;
-
-            if d_ab < d_ac {
-                if d_ab < d_bc {
-                    // ab
-                    let bcoords = [_1 - v, v];
-                    proj = a + ab * v;
-                    loc = TrianglePointLocation::OnEdge(0, bcoords);
-                } else {
-                    // bc
-                    let bcoords = [_1 - u, u];
-                    proj = b + bc * u;
-                    loc = TrianglePointLocation::OnEdge(1, bcoords);
-                }
-            } else {
-                if d_ac < d_bc {
-                    // ac
-                    let bcoords = [_1 - w, w];
-                    proj = a + ac * w;
-                    loc = TrianglePointLocation::OnEdge(2, bcoords);
-                } else {
-                    // bc
-                    let bcoords = [_1 - u, u];
-                    proj = b + bc * u;
-                    loc = TrianglePointLocation::OnEdge(1, bcoords);
-                }
-            }
-
+            let (proj, loc) = project_on_closest_edge(self, pt);
             (PointProjection::new(true, proj), loc)
         }
     }
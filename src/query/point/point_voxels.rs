@@ -0,0 +1,86 @@
+use crate::math::{Point, Real, Vector, DIM};
+use crate::query::point::PointCompositeShapeProjBestFirstVisitor;
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Voxels};
+
+impl PointQuery for Voxels {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        if self.contains_local_point(point) {
+            if solid {
+                PointProjection::new(true, *point)
+            } else {
+                PointProjection::new(true, self.project_on_empty_voxels(point))
+            }
+        } else {
+            let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, true);
+
+            match self.quadtree().traverse_best_first(&mut visitor) {
+                Some((_, (proj, _))) => PointProjection::new(false, proj.point),
+                // There is no closest point so this returns a point infinitely far away.
+                None => PointProjection::new(false, Point::from(Vector::repeat(Real::MAX))),
+            }
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(point, false), FeatureId::Unknown)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        self.contains_voxel(&self.voxel_key_at(point))
+    }
+}
+
+impl Voxels {
+    // Projects a point located inside of an occupied voxel on the boundary of this set of voxels.
+    //
+    // This boundary is also the boundary of the empty voxels, so we look for the closest empty
+    // voxel by visiting the rings of voxels of increasing radius around the point.
+    fn project_on_empty_voxels(&self, point: &Point<Real>) -> Point<Real> {
+        let center = self.voxel_key_at(point);
+        let min_size = self.voxel_size().min();
+        let mut best_proj = *point;
+        let mut best_dist = Real::MAX;
+
+        for radius in 1i32.. {
+            // All the voxels of this ring are at least that far from the point.
+            if (radius - 1) as Real * min_size >= best_dist {
+                break;
+            }
+
+            let width = 2 * radius + 1;
+            let num_cells = (0..DIM).fold(1, |n, _| n * width);
+
+            for cell in 0..num_cells {
+                let mut key = center;
+                let mut index = cell;
+                let mut on_ring = false;
+
+                for i in 0..DIM {
+                    let offset = index % width - radius;
+                    key[i] += offset;
+                    index /= width;
+                    on_ring = on_ring || offset.abs() == radius;
+                }
+
+                if on_ring && !self.contains_voxel(&key) {
+                    let proj = self.voxel_aabb(&key).project_local_point(point, true).point;
+                    let dist = na::distance(point, &proj);
+
+                    if dist < best_dist {
+                        best_dist = dist;
+                        best_proj = proj;
+                    }
+                }
+            }
+        }
+
+        best_proj
+    }
+}
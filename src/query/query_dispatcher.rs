@@ -23,6 +23,24 @@ pub trait PersistentQueryDispatcher<ManifoldData, ContactData>: QueryDispatcher
         workspace: &mut Option<ContactManifoldsWorkspace>,
     ) -> Result<(), Unsupported>;
 
+    /// Computes all the contact manifolds between two shapes, without persistence.
+    ///
+    /// This is a one-shot version of `contact_manifolds`: each returned manifold contains at
+    /// least one contact point, and may contain several when the shapes touch along a segment
+    /// or a face. Composite shapes yield one manifold per pair of touching subshapes.
+    fn contact_manifold(
+        &self,
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        prediction: Real,
+    ) -> Result<Vec<ContactManifold<ManifoldData, ContactData>>, Unsupported> {
+        let mut manifolds = Vec::new();
+        self.contact_manifolds(pos12, g1, g2, prediction, &mut manifolds, &mut None)?;
+        manifolds.retain(|m| !m.points.is_empty());
+        Ok(manifolds)
+    }
+
     /// Computes the contact-manifold between two convex shapes.
     fn contact_manifold_convex_convex(
         &self,
@@ -57,6 +75,21 @@ pub trait QueryDispatcher: Send + Sync {
         g2: &dyn Shape,
     ) -> Result<Real, Unsupported>;
 
+    /// Computes the minimum distance separating two shapes, bounded by `max_dist`.
+    ///
+    /// Returns `0.0` if the objects are touching or penetrating, and `max_dist` if they
+    /// are separated by a distance greater than `max_dist`. Implementations may use
+    /// `max_dist` to stop the distance computation early.
+    fn distance_with_max_dist(
+        &self,
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        max_dist: Real,
+    ) -> Result<Real, Unsupported> {
+        self.distance(pos12, g1, g2).map(|dist| dist.min(max_dist))
+    }
+
     /// Computes one pair of contact points point between two shapes.
     ///
     /// Returns `None` if the objects are separated by a distance greater than `prediction`.
@@ -138,6 +171,13 @@ where
 
     chain_method!(distance(pos12: &Isometry<Real>, g1: &dyn Shape, g2: &dyn Shape,) -> Real);
 
+    chain_method!(distance_with_max_dist(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        max_dist: Real,
+    ) -> Real);
+
     chain_method!(contact(
         pos12: &Isometry<Real>,
         g1: &dyn Shape,
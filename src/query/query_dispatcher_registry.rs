@@ -0,0 +1,173 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::motion::RigidMotion;
+use crate::query::contact_manifolds::ContactManifoldsWorkspace;
+use crate::query::{
+    ClosestPoints, Contact, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher,
+    QueryDispatcher, Unsupported, TOI,
+};
+use crate::shape::{Shape, ShapeType};
+use crate::utils::hashmap::HashMap;
+use std::sync::Arc;
+
+/// A query dispatcher to which handlers for specific pairs of shape types can be added at runtime.
+///
+/// Each handler is registered for an ordered pair of `ShapeType`, e.g., `(ShapeType::Ball,
+/// ShapeType::Custom(0))`, and is given the first chance to handle the queries between two
+/// shapes of these types. The queries the handler doesn't support, as well as the queries
+/// involving pairs without any handler, are forwarded to the fallback dispatcher, which is a
+/// `DefaultQueryDispatcher` by default.
+///
+/// Handlers are keyed by ordered pairs: a handler registered for `(type1, type2)` will not be
+/// called for a query between shapes of types `type2` and `type1`. Register it for both orders
+/// if it supports both.
+///
+/// The fallback dispatcher is used for the subshapes of composite shapes, so the registered
+/// handlers are only called for the shapes passed directly to this dispatcher. Contact
+/// manifolds are always computed by the fallback dispatcher.
+pub struct QueryDispatcherRegistry<D = DefaultQueryDispatcher> {
+    handlers: HashMap<(ShapeType, ShapeType), Arc<dyn QueryDispatcher>>,
+    fallback: D,
+}
+
+impl Default for QueryDispatcherRegistry {
+    fn default() -> Self {
+        Self::new(DefaultQueryDispatcher)
+    }
+}
+
+impl<D: QueryDispatcher> QueryDispatcherRegistry<D> {
+    /// Creates a registry without any handler, forwarding all the queries to `fallback`.
+    pub fn new(fallback: D) -> Self {
+        Self {
+            handlers: HashMap::default(),
+            fallback,
+        }
+    }
+
+    /// The dispatcher used for queries not supported by any registered handler.
+    pub fn fallback(&self) -> &D {
+        &self.fallback
+    }
+
+    /// Registers the handler of the queries between shapes of types `type1` and `type2`.
+    ///
+    /// Returns the handler previously registered for this pair, if any.
+    pub fn register(
+        &mut self,
+        type1: ShapeType,
+        type2: ShapeType,
+        handler: Arc<dyn QueryDispatcher>,
+    ) -> Option<Arc<dyn QueryDispatcher>> {
+        self.handlers.insert((type1, type2), handler)
+    }
+
+    /// Removes the handler of the queries between shapes of types `type1` and `type2`.
+    ///
+    /// Returns the removed handler, if any.
+    pub fn unregister(
+        &mut self,
+        type1: ShapeType,
+        type2: ShapeType,
+    ) -> Option<Arc<dyn QueryDispatcher>> {
+        self.handlers.remove(&(type1, type2))
+    }
+
+    /// The handler registered for queries between shapes of types `type1` and `type2`, if any.
+    pub fn handler(&self, type1: ShapeType, type2: ShapeType) -> Option<&dyn QueryDispatcher> {
+        self.handlers.get(&(type1, type2)).map(|h| &**h)
+    }
+}
+
+macro_rules! registry_method {
+    ($g1:ident, $g2:ident; $name:ident ( $( $arg:ident : $ty:ty,)*) -> $result:ty) => {
+        fn $name(&self, $($arg : $ty,)*
+        ) -> Result<$result, Unsupported> {
+            if let Some(handler) = self.handler($g1.shape_type(), $g2.shape_type()) {
+                if let Ok(result) = handler.$name($($arg,)*) {
+                    return Ok(result);
+                }
+            }
+
+            self.fallback.$name($($arg,)*)
+        }
+    }
+}
+
+impl<D: QueryDispatcher> QueryDispatcher for QueryDispatcherRegistry<D> {
+    registry_method!(g1, g2; intersection_test(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+    ) -> bool);
+
+    registry_method!(g1, g2; distance(pos12: &Isometry<Real>, g1: &dyn Shape, g2: &dyn Shape,) -> Real);
+
+    registry_method!(g1, g2; distance_with_max_dist(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        max_dist: Real,
+    ) -> Real);
+
+    registry_method!(g1, g2; contact(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        prediction: Real,
+    ) -> Option<Contact>);
+
+    registry_method!(g1, g2; closest_points(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        max_dist: Real,
+    ) -> ClosestPoints);
+
+    registry_method!(g1, g2; time_of_impact(
+        pos12: &Isometry<Real>,
+        vel12: &Vector<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        max_toi: Real,
+        target_distance: Real,
+    ) -> Option<TOI>);
+
+    registry_method!(g1, g2; nonlinear_time_of_impact(
+        motion12: &dyn RigidMotion,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        max_toi: Real,
+        target_distance: Real,
+    ) -> Option<TOI>);
+}
+
+impl<D, ManifoldData, ContactData> PersistentQueryDispatcher<ManifoldData, ContactData>
+    for QueryDispatcherRegistry<D>
+where
+    D: PersistentQueryDispatcher<ManifoldData, ContactData>,
+{
+    fn contact_manifolds(
+        &self,
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        prediction: Real,
+        manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData>>,
+        workspace: &mut Option<ContactManifoldsWorkspace>,
+    ) -> Result<(), Unsupported> {
+        self.fallback
+            .contact_manifolds(pos12, g1, g2, prediction, manifolds, workspace)
+    }
+
+    fn contact_manifold_convex_convex(
+        &self,
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        prediction: Real,
+        manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    ) -> Result<(), Unsupported> {
+        self.fallback
+            .contact_manifold_convex_convex(pos12, g1, g2, prediction, manifold)
+    }
+}
@@ -3,10 +3,12 @@
 #[doc(inline)]
 pub use self::ray::{Ray, RayCast, RayIntersection};
 pub use self::ray_ball::ray_toi_with_ball;
+pub use self::ray_batch::raycast_batch;
 pub use self::ray_composite_shape::{
     RayCompositeShapeToiAndNormalBestFirstVisitor, RayCompositeShapeToiBestFirstVisitor,
 };
 pub use self::ray_halfspace::{line_toi_with_halfspace, ray_toi_with_halfspace};
+pub use self::ray_intersections::ray_intersections;
 pub use self::ray_support_map::local_ray_intersection_with_support_map_with_params;
 #[cfg(feature = "dim3")]
 pub use self::ray_triangle::local_ray_intersection_with_triangle;
@@ -15,13 +17,36 @@ pub use self::simd_ray::SimdRay;
 #[doc(hidden)]
 pub mod ray;
 mod ray_aabb;
+#[cfg(feature = "dim2")]
+mod ray_annulus;
 mod ray_ball;
+mod ray_batch;
 mod ray_bounding_sphere;
 mod ray_composite_shape;
 mod ray_cuboid;
+#[cfg(feature = "dim3")]
+mod ray_ellipsoid;
 mod ray_halfspace;
 mod ray_heightfield;
+mod ray_intersections;
+#[cfg(feature = "dim3")]
+mod ray_plate;
+#[cfg(feature = "dim2")]
+mod ray_polygon;
+#[cfg(feature = "dim3")]
+mod ray_prism;
 mod ray_round_shape;
+mod ray_scaled_shape;
+mod ray_sdf_shape;
+#[cfg(feature = "dim2")]
+mod ray_sector;
+mod ray_slab;
+#[cfg(feature = "dim3")]
+mod ray_spherical_sector;
 mod ray_support_map;
+#[cfg(feature = "dim3")]
+mod ray_torus;
+mod ray_transformed_shape;
 mod ray_triangle;
+mod ray_voxels;
 mod simd_ray;
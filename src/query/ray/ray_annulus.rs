@@ -0,0 +1,50 @@
+use crate::math::{Point, Real};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::{Annulus, FeatureId};
+
+use super::ray_ball::ray_toi_with_ball;
+
+impl RayCast for Annulus {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let inside = self.contains_local_point(&ray.origin);
+
+        if inside && solid {
+            return Some(RayIntersection::new(0.0, na::zero(), FeatureId::Face(0)));
+        }
+
+        // The first intersection with each circle, which is the first boundary
+        // crossing of the ray whatever the region it starts from.
+        let outer = ray_toi_with_ball(&Point::origin(), self.outer_radius, ray, false).1;
+        let inner = if self.inner_radius > 0.0 {
+            ray_toi_with_ball(&Point::origin(), self.inner_radius, ray, false).1
+        } else {
+            None
+        };
+
+        let (toi, on_outer) = match (outer, inner) {
+            (Some(outer), Some(inner)) if inner < outer => (inner, false),
+            (Some(outer), _) => (outer, true),
+            (None, Some(inner)) => (inner, false),
+            (None, None) => return None,
+        };
+
+        if toi > max_toi {
+            return None;
+        }
+
+        // The outward normal of the outer circle, or of the inner circle wrt. the annulus.
+        let dir = ray.point_at(toi).coords.normalize();
+        let normal = if on_outer { dir } else { -dir };
+
+        Some(RayIntersection::new(
+            toi,
+            if inside { -normal } else { normal },
+            FeatureId::Face(0),
+        ))
+    }
+}
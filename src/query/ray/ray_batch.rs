@@ -0,0 +1,138 @@
+use crate::bounding_volume::SimdAABB;
+use crate::math::{Isometry, Real, SimdBool, SimdReal, SIMD_WIDTH};
+use crate::partitioning::{SimdVisitStatus, SimdVisitor};
+use crate::query::{Ray, RayCast, RayIntersection, SimdRay};
+use crate::shape::TypedSimdCompositeShape;
+use simba::simd::SimdBool as _;
+
+/// Casts many rays on the same transformed composite shape.
+///
+/// The rays are grouped into packets of `SIMD_WIDTH` rays, and each packet is tested against
+/// the bounding volumes of the shape's quadtree at once. A single traversal stack is reused
+/// for all the packets. This is much faster than casting each ray independently when a large
+/// number of rays are cast, e.g., for lidar simulation or visibility checks.
+///
+/// Returns, for each ray, the part of the composite shape hit first by the ray together with
+/// the world-space intersection, or `None` if the ray doesn't hit the shape before `max_toi`.
+/// The feature of each intersection is the feature of the part hit by the ray.
+pub fn raycast_batch<S>(
+    position: &Isometry<Real>,
+    shape: &S,
+    rays: &[Ray],
+    max_toi: Real,
+    solid: bool,
+) -> Vec<Option<(S::PartId, RayIntersection)>>
+where
+    S: TypedSimdCompositeShape,
+{
+    let mut result = Vec::with_capacity(rays.len());
+    let mut stack = Vec::new();
+
+    for packet in rays.chunks(SIMD_WIDTH) {
+        let mut visitor = RayBatchVisitor::new(shape, position, packet, max_toi, solid);
+        shape
+            .typed_quadtree()
+            .traverse_depth_first_with_stack(&mut visitor, &mut stack);
+
+        result.extend(
+            visitor.results[..packet.len()]
+                .iter()
+                .map(|hit| hit.map(|(part_id, hit)| (part_id, hit.transform_by(position)))),
+        );
+    }
+
+    result
+}
+
+// Casts a packet of at most `SIMD_WIDTH` rays on a composite shape.
+struct RayBatchVisitor<'a, S: TypedSimdCompositeShape> {
+    shape: &'a S,
+    rays: [Ray; SIMD_WIDTH],
+    simd_ray: SimdRay,
+    // The time of impact of the closest hit found so far, for each ray.
+    max_toi: [Real; SIMD_WIDTH],
+    solid: bool,
+    results: [Option<(S::PartId, RayIntersection)>; SIMD_WIDTH],
+}
+
+impl<'a, S: TypedSimdCompositeShape> RayBatchVisitor<'a, S> {
+    fn new(
+        shape: &'a S,
+        position: &Isometry<Real>,
+        packet: &[Ray],
+        max_toi: Real,
+        solid: bool,
+    ) -> Self {
+        // Unused lanes are given a negative `max_toi` so they never hit anything.
+        let rays = array![|ii| packet.get(ii).unwrap_or(&packet[0]).inverse_transform_by(position); SIMD_WIDTH];
+        let max_toi = array![|ii| if ii < packet.len() { max_toi } else { -1.0 }; SIMD_WIDTH];
+
+        Self {
+            shape,
+            rays,
+            simd_ray: SimdRay::from(rays),
+            max_toi,
+            solid,
+            results: [None; SIMD_WIDTH],
+        }
+    }
+}
+
+impl<'a, S: TypedSimdCompositeShape> SimdVisitor<S::PartId, SimdAABB> for RayBatchVisitor<'a, S> {
+    #[inline]
+    fn visit(
+        &mut self,
+        bv: &SimdAABB,
+        data: Option<[Option<&S::PartId>; SIMD_WIDTH]>,
+    ) -> SimdVisitStatus {
+        let mut mask = [false; SIMD_WIDTH];
+
+        for ii in 0..SIMD_WIDTH {
+            // Test all the rays of the packet against the `ii`-th child at once.
+            let child = SimdAABB::splat(bv.extract(ii));
+            let hits = child
+                .cast_local_ray(&self.simd_ray, SimdReal::from(self.max_toi))
+                .0
+                .bitmask();
+
+            if hits == 0 {
+                continue;
+            }
+
+            mask[ii] = true;
+
+            if let Some(part_id) = data.and_then(|data| data[ii]) {
+                let part_id = *part_id;
+
+                for lane in 0..SIMD_WIDTH {
+                    if (hits & (1 << lane)) == 0 {
+                        continue;
+                    }
+
+                    let ray = &self.rays[lane];
+                    let max_toi = self.max_toi[lane];
+                    let solid = self.solid;
+                    let mut part_hit = None;
+
+                    self.shape
+                        .map_typed_part_at(part_id, |part_pos, part_shape| {
+                            part_hit = if let Some(part_pos) = part_pos {
+                                part_shape.cast_ray_and_get_normal(part_pos, ray, max_toi, solid)
+                            } else {
+                                part_shape.cast_local_ray_and_get_normal(ray, max_toi, solid)
+                            };
+                        });
+
+                    if let Some(hit) = part_hit {
+                        if hit.toi < self.max_toi[lane] || self.results[lane].is_none() {
+                            self.max_toi[lane] = hit.toi;
+                            self.results[lane] = Some((part_id, hit));
+                        }
+                    }
+                }
+            }
+        }
+
+        SimdVisitStatus::MaybeContinue(SimdBool::from(mask))
+    }
+}
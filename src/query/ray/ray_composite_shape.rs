@@ -1,9 +1,14 @@
 use crate::bounding_volume::SimdAABB;
-use crate::math::{Real, SimdBool, SimdReal, SIMD_WIDTH};
+use crate::math::{Isometry, Real, SimdBool, SimdReal, SIMD_WIDTH};
 use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
+use crate::query::visitors::RayIntersectionsVisitor;
 use crate::query::{Ray, RayCast, RayIntersection, SimdRay};
-use crate::shape::{Compound, FeatureId, Polyline, TriMesh, TypedSimdCompositeShape};
+use crate::shape::{
+    Compound, FeatureId, FilteredShape, Polyline, RoundPolyline, RoundTriMesh, TriMesh,
+    TypedSimdCompositeShape,
+};
 use simba::simd::{SimdBool as _, SimdPartialOrd, SimdValue};
+use std::cmp::Ordering;
 
 impl RayCast for TriMesh {
     #[inline]
@@ -32,6 +37,11 @@ impl RayCast for TriMesh {
                 res
             })
     }
+
+    #[inline]
+    fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
+        intersects_local_ray_with_composite_shape(self, ray, max_toi)
+    }
 }
 
 impl RayCast for Polyline {
@@ -58,6 +68,76 @@ impl RayCast for Polyline {
             .traverse_best_first(&mut visitor)
             .map(|(_, (_, res))| res)
     }
+
+    #[inline]
+    fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
+        intersects_local_ray_with_composite_shape(self, ray, max_toi)
+    }
+}
+
+impl RayCast for RoundTriMesh {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_toi: Real, solid: bool) -> Option<Real> {
+        let mut visitor = RayCompositeShapeToiBestFirstVisitor::new(self, ray, max_toi, solid);
+
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|res| res.1 .1)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let mut visitor =
+            RayCompositeShapeToiAndNormalBestFirstVisitor::new(self, ray, max_toi, solid);
+
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (best, mut res))| {
+                res.feature = FeatureId::Face(best);
+                res
+            })
+    }
+
+    #[inline]
+    fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
+        intersects_local_ray_with_composite_shape(self, ray, max_toi)
+    }
+}
+
+impl RayCast for RoundPolyline {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_toi: Real, solid: bool) -> Option<Real> {
+        let mut visitor = RayCompositeShapeToiBestFirstVisitor::new(self, ray, max_toi, solid);
+
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|res| res.1 .1)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let mut visitor =
+            RayCompositeShapeToiAndNormalBestFirstVisitor::new(self, ray, max_toi, solid);
+
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (_, res))| res)
+    }
+
+    #[inline]
+    fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
+        intersects_local_ray_with_composite_shape(self, ray, max_toi)
+    }
 }
 
 impl RayCast for Compound {
@@ -84,6 +164,144 @@ impl RayCast for Compound {
             .traverse_best_first(&mut visitor)
             .map(|(_, (_, res))| res)
     }
+
+    #[inline]
+    fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
+        intersects_local_ray_with_composite_shape(self, ray, max_toi)
+    }
+}
+
+impl<'a, S, F> RayCast for FilteredShape<'a, S, F>
+where
+    S: TypedSimdCompositeShape,
+    F: Fn(S::PartId) -> bool,
+{
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_toi: Real, solid: bool) -> Option<Real> {
+        let mut visitor = RayCompositeShapeToiBestFirstVisitor::new(self, ray, max_toi, solid);
+
+        self.typed_quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|res| res.1 .1)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let mut visitor =
+            RayCompositeShapeToiAndNormalBestFirstVisitor::new(self, ray, max_toi, solid);
+
+        self.typed_quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (_, res))| res)
+    }
+
+    #[inline]
+    fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
+        intersects_local_ray_with_composite_shape(self, ray, max_toi)
+    }
+}
+
+/// Tests whether a ray intersects a composite shape, stopping at the first part hit.
+fn intersects_local_ray_with_composite_shape<S>(shape: &S, ray: &Ray, max_toi: Real) -> bool
+where
+    S: TypedSimdCompositeShape,
+{
+    let mut hit = false;
+    let mut leaf_fn = |part_id: &S::PartId| {
+        shape.map_typed_part_at(*part_id, |part_pos, part_shape| {
+            hit = if let Some(part_pos) = part_pos {
+                part_shape.intersects_ray(part_pos, ray, max_toi)
+            } else {
+                part_shape.intersects_local_ray(ray, max_toi)
+            };
+        });
+
+        // Exit early as soon as one part is hit.
+        !hit
+    };
+
+    let mut visitor = RayIntersectionsVisitor::new(ray, max_toi, &mut leaf_fn);
+    shape.typed_quadtree().traverse_depth_first(&mut visitor);
+    hit
+}
+
+impl Compound {
+    /// Computes the first intersection between the ray and this compound shape, together with
+    /// the index of the part hit by the ray.
+    ///
+    /// The feature of the returned intersection is the feature of the part hit by the ray. The
+    /// part index can be used to retrieve the user-defined value attached to this part with
+    /// `self.part_data`.
+    pub fn cast_local_ray_and_get_part(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, RayIntersection)> {
+        let mut visitor =
+            RayCompositeShapeToiAndNormalBestFirstVisitor::new(self, ray, max_toi, solid);
+
+        self.quadtree()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, result)| result)
+    }
+
+    /// Computes the first intersection between the ray and the transformed compound shape,
+    /// together with the index of the part hit by the ray.
+    pub fn cast_ray_and_get_part(
+        &self,
+        position: &Isometry<Real>,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, RayIntersection)> {
+        let ls_ray = ray.inverse_transform_by(position);
+        self.cast_local_ray_and_get_part(&ls_ray, max_toi, solid)
+            .map(|(part, hit)| (part, hit.transform_by(position)))
+    }
+
+    /// Computes all the intersections between the transformed compound shape and the ray.
+    ///
+    /// Returns, for each child hit by the ray, its index in `self.shapes()` together with
+    /// the world-space intersection. The hits are sorted by increasing time of impact. Every
+    /// child is reported, even if its hit coincides with the hit of another child.
+    pub fn cast_ray_all(
+        &self,
+        position: &Isometry<Real>,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Vec<(usize, RayIntersection)> {
+        let ls_ray = ray.inverse_transform_by(position);
+        let mut hits = Vec::new();
+
+        let mut leaf_fn = |part_id: &u32| {
+            let (part_pos, part_shape) = &self.shapes()[*part_id as usize];
+
+            if let Some(hit) = part_shape.cast_ray_and_get_normal(part_pos, &ls_ray, max_toi, solid)
+            {
+                hits.push((*part_id as usize, hit.transform_by(position)));
+            }
+
+            true
+        };
+
+        let mut visitor = RayIntersectionsVisitor::new(&ls_ray, max_toi, &mut leaf_fn);
+        self.quadtree().traverse_depth_first(&mut visitor);
+
+        hits.sort_by(|a, b| {
+            a.1.toi
+                .partial_cmp(&b.1.toi)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        hits
+    }
 }
 
 /*
@@ -1,7 +1,7 @@
 use crate::bounding_volume::AABB;
-use crate::math::{Point, Real};
+use crate::math::{Point, Real, DIM};
 use crate::query::{Ray, RayCast, RayIntersection};
-use crate::shape::Cuboid;
+use crate::shape::{Cuboid, FeatureId};
 
 impl RayCast for Cuboid {
     #[inline]
@@ -20,6 +20,51 @@ impl RayCast for Cuboid {
     ) -> Option<RayIntersection> {
         let dl = Point::from(-self.half_extents);
         let ur = Point::from(self.half_extents);
-        AABB::new(dl, ur).cast_local_ray_and_get_normal(ray, max_toi, solid)
+        AABB::new(dl, ur)
+            .cast_local_ray_and_get_normal(ray, max_toi, solid)
+            .map(|mut inter| {
+                if inter.toi != 0.0 || !solid {
+                    inter.feature = self.boundary_feature_at(&ray.point_at(inter.toi));
+                }
+
+                inter
+            })
+    }
+}
+
+impl Cuboid {
+    /// The feature of this cuboid containing `pt`, assuming `pt` lies on its boundary.
+    ///
+    /// Points lying on several faces at once (up to rounding errors) are
+    /// reported as an edge or a vertex, following the same feature numbering
+    /// as `Cuboid::support_feature_id_toward`.
+    fn boundary_feature_at(&self, pt: &Point<Real>) -> FeatureId {
+        let eps = crate::math::DEFAULT_EPSILON * 100.0 * self.half_extents.max();
+        let mut num_faces = 0;
+        let mut face = 0;
+        let mut vertex_id = 0;
+
+        for i in 0..DIM {
+            if pt[i] < 0.0 {
+                vertex_id |= 1 << i;
+            }
+
+            if self.half_extents[i] - pt[i].abs() <= eps {
+                num_faces += 1;
+                face = if pt[i] < 0.0 { i + DIM } else { i };
+            }
+        }
+
+        match num_faces {
+            0 | 1 => FeatureId::Face(face as u32),
+            #[cfg(feature = "dim3")]
+            2 => {
+                let free_axis = (0..DIM)
+                    .find(|i| self.half_extents[*i] - pt[*i].abs() > eps)
+                    .unwrap_or(0);
+                FeatureId::Edge(free_axis as u32 | ((vertex_id & !(1 << free_axis)) << 2))
+            }
+            _ => FeatureId::Vertex(vertex_id),
+        }
     }
 }
@@ -0,0 +1,41 @@
+use crate::math::{Point, Real};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Ellipsoid, FeatureId};
+
+use super::ray_ball::ray_toi_with_ball;
+
+impl RayCast for Ellipsoid {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        // Scaling the space by the inverse of the radii maps the ellipsoid to the
+        // unit ball, without changing the time of impact.
+        let scaled_ray = Ray::new(
+            ray.origin.coords.component_div(&self.radii).into(),
+            ray.dir.component_div(&self.radii),
+        );
+        let (inside, toi) = ray_toi_with_ball(&Point::origin(), 1.0, &scaled_ray, solid);
+        let toi = toi.filter(|toi| *toi <= max_toi)?;
+
+        if inside && solid && toi == 0.0 {
+            return Some(RayIntersection::new(0.0, na::zero(), FeatureId::Face(0)));
+        }
+
+        // The gradient of `(x / a)² + (y / b)² + (z / c)²`.
+        let pt = ray.point_at(toi);
+        let normal = pt
+            .coords
+            .component_div(&self.radii.component_mul(&self.radii))
+            .normalize();
+
+        Some(RayIntersection::new(
+            toi,
+            if inside { -normal } else { normal },
+            FeatureId::Face(0),
+        ))
+    }
+}
@@ -1,10 +1,15 @@
 use crate::math::Real;
+#[cfg(feature = "dim3")]
+use crate::math::{Isometry, Vector};
 #[cfg(feature = "dim2")]
 use crate::query;
 use crate::query::{Ray, RayCast, RayIntersection};
-#[cfg(feature = "dim2")]
 use crate::shape::FeatureId;
-use crate::shape::HeightField;
+#[cfg(feature = "dim3")]
+use crate::shape::HeightFieldCellStatus;
+use crate::shape::{FilteredShape, HeightField};
+#[cfg(feature = "dim3")]
+use na::ComplexField;
 
 #[cfg(feature = "dim2")]
 impl RayCast for HeightField {
@@ -15,111 +20,141 @@ impl RayCast for HeightField {
         max_toi: Real,
         _: bool,
     ) -> Option<RayIntersection> {
-        let aabb = self.local_aabb();
-        let (min_t, mut max_t) = aabb.clip_ray_parameters(&ray)?;
+        cast_local_ray_on_segments(self, ray, max_toi, |_| true)
+    }
+}
 
-        if min_t > max_toi {
-            return None;
-        }
+#[cfg(feature = "dim2")]
+impl<'a, F: Fn(u32) -> bool> RayCast for FilteredShape<'a, HeightField, F> {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        _: bool,
+    ) -> Option<RayIntersection> {
+        cast_local_ray_on_segments(self.shape(), ray, max_toi, self.filter())
+    }
+}
+
+// Casts a ray on the segments of the heightfield for which `filter` returns `true`.
+#[cfg(feature = "dim2")]
+fn cast_local_ray_on_segments(
+    heightfield: &HeightField,
+    ray: &Ray,
+    max_toi: Real,
+    filter: impl Fn(u32) -> bool,
+) -> Option<RayIntersection> {
+    let aabb = heightfield.local_aabb();
+    let (min_t, mut max_t) = aabb.clip_ray_parameters(&ray)?;
 
-        max_t = max_t.min(max_toi);
+    if min_t > max_toi {
+        return None;
+    }
 
-        let clip_ray_a = ray.point_at(min_t);
+    max_t = max_t.min(max_toi);
 
-        // None may happen due to slight numerical errors.
-        let mut curr = self.cell_at_point(&clip_ray_a).unwrap_or_else(|| {
-            if ray.origin.x > 0.0 {
-                self.num_cells() - 1
+    let clip_ray_a = ray.point_at(min_t);
+
+    // None may happen due to slight numerical errors.
+    let mut curr = heightfield.cell_at_point(&clip_ray_a).unwrap_or_else(|| {
+        if ray.origin.x > 0.0 {
+            heightfield.num_cells() - 1
+        } else {
+            0_usize
+        }
+    });
+
+    /*
+     * Test the segment under the ray.
+     */
+    if let Some(seg) = heightfield.segment_at(curr).filter(|_| filter(curr as u32)) {
+        let (s, t) = query::details::closest_points_line_line_parameters(
+            &ray.origin,
+            &ray.dir,
+            &seg.a,
+            &seg.scaled_direction(),
+        );
+        if s >= 0.0 && t >= 0.0 && t <= 1.0 {
+            // Cast succeeded on the first element!
+            let n = seg.normal().unwrap().into_inner();
+            let fid = if n.dot(&ray.dir) > 0.0 {
+                // The ray hit the back face.
+                curr + heightfield.num_cells()
             } else {
-                0_usize
-            }
-        });
+                // The ray hit the front face.
+                curr
+            };
 
-        /*
-         * Test the segment under the ray.
-         */
-        if let Some(seg) = self.segment_at(curr) {
+            return Some(RayIntersection::new(s, n, FeatureId::Face(fid as u32)));
+        }
+    }
+
+    /*
+     * Test other segments in the path of the ray.
+     */
+    if ray.dir.x == 0.0 {
+        return None;
+    }
+
+    let right = ray.dir.x > 0.0;
+    while (right && curr < heightfield.num_cells()) || (!right && curr > 0) {
+        let curr_param;
+
+        if right {
+            curr += 1;
+            curr_param = (heightfield.x_at(curr) - ray.origin.x) / ray.dir.x;
+        } else {
+            curr_param = (ray.origin.x - heightfield.x_at(curr)) / ray.dir.x;
+            curr -= 1;
+        }
+
+        if curr_param >= max_t {
+            // The part of the ray after max_t is outside of the heightfield AABB.
+            return None;
+        }
+
+        if let Some(seg) = heightfield.segment_at(curr).filter(|_| filter(curr as u32)) {
+            // TODO: test the y-coordinates (equivalent to an AABB test) before actually computing the intersection.
             let (s, t) = query::details::closest_points_line_line_parameters(
                 &ray.origin,
                 &ray.dir,
                 &seg.a,
                 &seg.scaled_direction(),
             );
-            if s >= 0.0 && t >= 0.0 && t <= 1.0 {
-                // Cast succeeded on the first element!
+
+            if t >= 0.0 && t <= 1.0 && s <= max_toi {
                 let n = seg.normal().unwrap().into_inner();
                 let fid = if n.dot(&ray.dir) > 0.0 {
                     // The ray hit the back face.
-                    curr + self.num_cells()
+                    curr + heightfield.num_cells()
                 } else {
                     // The ray hit the front face.
                     curr
                 };
-
                 return Some(RayIntersection::new(s, n, FeatureId::Face(fid as u32)));
             }
         }
+    }
 
-        /*
-         * Test other segments in the path of the ray.
-         */
-        if ray.dir.x == 0.0 {
-            return None;
-        }
-
-        let right = ray.dir.x > 0.0;
-        let cell_width = self.cell_width();
-        let start_x = self.start_x();
-
-        while (right && curr < self.num_cells()) || (!right && curr > 0) {
-            let curr_param;
-
-            if right {
-                curr += 1;
-                curr_param = (cell_width * na::convert::<f64, Real>(curr as f64) + start_x
-                    - ray.origin.x)
-                    / ray.dir.x;
-            } else {
-                curr_param =
-                    (ray.origin.x - cell_width * na::convert::<f64, Real>(curr as f64) - start_x)
-                        / ray.dir.x;
-                curr -= 1;
-            }
-
-            if curr_param >= max_t {
-                // The part of the ray after max_t is outside of the heightfield AABB.
-                return None;
-            }
-
-            if let Some(seg) = self.segment_at(curr) {
-                // TODO: test the y-coordinates (equivalent to an AABB test) before actually computing the intersection.
-                let (s, t) = query::details::closest_points_line_line_parameters(
-                    &ray.origin,
-                    &ray.dir,
-                    &seg.a,
-                    &seg.scaled_direction(),
-                );
-
-                if t >= 0.0 && t <= 1.0 && s <= max_toi {
-                    let n = seg.normal().unwrap().into_inner();
-                    let fid = if n.dot(&ray.dir) > 0.0 {
-                        // The ray hit the back face.
-                        curr + self.num_cells()
-                    } else {
-                        // The ray hit the front face.
-                        curr
-                    };
-                    return Some(RayIntersection::new(s, n, FeatureId::Face(fid as u32)));
-                }
-            }
-        }
+    None
+}
 
-        None
+#[cfg(feature = "dim3")]
+impl RayCast for HeightField {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        cast_local_ray_on_triangles(self, ray, max_toi, solid, |_| true)
     }
 }
 
 #[cfg(feature = "dim3")]
-impl RayCast for HeightField {
+impl<'a, F: Fn(u32) -> bool> RayCast for FilteredShape<'a, HeightField, F> {
     #[inline]
     fn cast_local_ray_and_get_normal(
         &self,
@@ -127,118 +162,282 @@ impl RayCast for HeightField {
         max_toi: Real,
         solid: bool,
     ) -> Option<RayIntersection> {
-        use num_traits::Bounded;
-
-        let aabb = self.local_aabb();
-        let (min_t, mut max_t) = aabb.clip_ray_parameters(&ray)?;
-        max_t = max_t.min(max_toi);
-        let clip_ray_a = ray.point_at(min_t);
-        let mut cell = match self.cell_at_point(&clip_ray_a) {
-            Some(cell) => cell,
-            // None may happen due to slight numerical errors.
-            None => {
-                let i = if ray.origin.z > 0.0 {
-                    self.nrows() - 1
-                } else {
-                    0
-                };
+        cast_local_ray_on_triangles(self.shape(), ray, max_toi, solid, self.filter())
+    }
+}
 
-                let j = if ray.origin.x > 0.0 {
-                    self.ncols() - 1
-                } else {
-                    0
-                };
+// Casts a ray on the triangles of the heightfield for which `filter` returns `true`.
+#[cfg(feature = "dim3")]
+fn cast_local_ray_on_triangles(
+    heightfield: &HeightField,
+    ray: &Ray,
+    max_toi: Real,
+    solid: bool,
+    filter: impl Fn(u32) -> bool,
+) -> Option<RayIntersection> {
+    cast_local_ray_on_cells(heightfield, ray, max_toi, |i, j| {
+        let tris = heightfield.triangles_at(i, j);
+        let inter1 = tris
+            .0
+            .filter(|_| filter(heightfield.triangle_id(i, j, true)))
+            .and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, solid));
+        let inter2 = tris
+            .1
+            .filter(|_| filter(heightfield.triangle_id(i, j, false)))
+            .and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, solid));
 
-                (i, j)
+        match (inter1, inter2) {
+            (Some(mut inter1), Some(mut inter2)) => {
+                if inter1.toi < inter2.toi {
+                    inter1.feature =
+                        heightfield.convert_triangle_feature_id(i, j, true, inter1.feature);
+                    Some(inter1)
+                } else {
+                    inter2.feature =
+                        heightfield.convert_triangle_feature_id(i, j, false, inter2.feature);
+                    Some(inter2)
+                }
             }
-        };
+            (Some(mut inter), None) => {
+                inter.feature = heightfield.convert_triangle_feature_id(i, j, true, inter.feature);
+                Some(inter)
+            }
+            (None, Some(mut inter)) => {
+                inter.feature = heightfield.convert_triangle_feature_id(i, j, false, inter.feature);
+                Some(inter)
+            }
+            (None, None) => None,
+        }
+    })
+}
 
-        loop {
-            let tris = self.triangles_at(cell.0, cell.1);
-            let inter1 = tris
-                .0
-                .and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, solid));
-            let inter2 = tris
-                .1
-                .and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, solid));
-
-            match (inter1, inter2) {
-                (Some(mut inter1), Some(mut inter2)) => {
-                    if inter1.toi < inter2.toi {
-                        inter1.feature =
-                            self.convert_triangle_feature_id(cell.0, cell.1, true, inter1.feature);
-                        return Some(inter1);
-                    } else {
-                        inter2.feature =
-                            self.convert_triangle_feature_id(cell.0, cell.1, false, inter2.feature);
-                        return Some(inter2);
-                    }
-                }
-                (Some(mut inter), None) => {
-                    inter.feature =
-                        self.convert_triangle_feature_id(cell.0, cell.1, true, inter.feature);
-                    return Some(inter);
-                }
-                (None, Some(mut inter)) => {
-                    inter.feature =
-                        self.convert_triangle_feature_id(cell.0, cell.1, false, inter.feature);
-                    return Some(inter);
-                }
-                (None, None) => {}
+#[cfg(feature = "dim3")]
+impl HeightField {
+    /// Computes the first intersection between a ray and this heightfield, with each
+    /// cell treated as a bilinear patch instead of two triangles.
+    ///
+    /// The bilinear patch of a cell interpolates the heights of its four corners, so the
+    /// hit points and normals vary smoothly across the cell diagonals. Cells with at least
+    /// one removed triangle are ignored. The feature of the returned intersection is always
+    /// `FeatureId::Unknown`, and its normal faces the ray origin.
+    pub fn cast_ray_bilinear(
+        &self,
+        m: &Isometry<Real>,
+        ray: &Ray,
+        max_toi: Real,
+    ) -> Option<RayIntersection> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_bilinear(&ls_ray, max_toi)
+            .map(|inter| inter.transform_by(m))
+    }
+
+    /// Computes the first intersection between a ray in the local-space of this heightfield
+    /// and this heightfield, with each cell treated as a bilinear patch.
+    ///
+    /// See `HeightField::cast_ray_bilinear` for details.
+    pub fn cast_local_ray_bilinear(&self, ray: &Ray, max_toi: Real) -> Option<RayIntersection> {
+        cast_local_ray_on_cells(self, ray, max_toi, |i, j| {
+            if self
+                .cell_status(i, j)
+                .intersects(HeightFieldCellStatus::CELL_REMOVED)
+            {
+                return None;
             }
 
-            /*
-             * Find the next cell to cast the ray on.
-             */
-            let (toi_x, right) = if ray.dir.x > 0.0 {
-                let x = self.x_at(cell.1 + 1);
-                ((x - ray.origin.x) / ray.dir.x, true)
-            } else if ray.dir.x < 0.0 {
-                let x = self.x_at(cell.1 + 0);
-                ((x - ray.origin.x) / ray.dir.x, false)
+            cast_local_ray_on_bilinear_cell(self, i, j, ray, max_toi)
+        })
+    }
+}
+
+// Calls `cast_on_cell` on each cell traversed by the ray, in order, until it returns an intersection.
+#[cfg(feature = "dim3")]
+fn cast_local_ray_on_cells(
+    heightfield: &HeightField,
+    ray: &Ray,
+    max_toi: Real,
+    mut cast_on_cell: impl FnMut(usize, usize) -> Option<RayIntersection>,
+) -> Option<RayIntersection> {
+    use num_traits::Bounded;
+
+    let aabb = heightfield.local_aabb();
+    let (min_t, mut max_t) = aabb.clip_ray_parameters(&ray)?;
+    max_t = max_t.min(max_toi);
+    let clip_ray_a = ray.point_at(min_t);
+    let mut cell = match heightfield.cell_at_point(&clip_ray_a) {
+        Some(cell) => cell,
+        // None may happen due to slight numerical errors.
+        None => {
+            let i = if ray.origin.z > 0.0 {
+                heightfield.nrows() - 1
             } else {
-                (Real::max_value(), false)
+                0
             };
 
-            let (toi_z, down) = if ray.dir.z > 0.0 {
-                let z = self.z_at(cell.0 + 1);
-                ((z - ray.origin.z) / ray.dir.z, true)
-            } else if ray.dir.z < 0.0 {
-                let z = self.z_at(cell.0 + 0);
-                ((z - ray.origin.z) / ray.dir.z, false)
+            let j = if ray.origin.x > 0.0 {
+                heightfield.ncols() - 1
             } else {
-                (Real::max_value(), false)
+                0
             };
 
-            if toi_x > max_t && toi_z > max_t {
-                break;
-            }
+            (i, j)
+        }
+    };
 
-            if toi_x >= 0.0 && toi_x < toi_z {
-                if right {
-                    cell.1 += 1
-                } else if cell.1 > 0 {
-                    cell.1 -= 1
-                } else {
-                    break;
-                }
-            } else if toi_z >= 0.0 {
-                if down {
-                    cell.0 += 1
-                } else if cell.0 > 0 {
-                    cell.0 -= 1
-                } else {
-                    break;
-                }
+    loop {
+        if let Some(inter) = cast_on_cell(cell.0, cell.1) {
+            return Some(inter);
+        }
+
+        /*
+         * Find the next cell to cast the ray on.
+         */
+        let (toi_x, right) = if ray.dir.x > 0.0 {
+            let x = heightfield.x_at(cell.1 + 1);
+            ((x - ray.origin.x) / ray.dir.x, true)
+        } else if ray.dir.x < 0.0 {
+            let x = heightfield.x_at(cell.1 + 0);
+            ((x - ray.origin.x) / ray.dir.x, false)
+        } else {
+            (Real::max_value(), false)
+        };
+
+        let (toi_z, down) = if ray.dir.z > 0.0 {
+            let z = heightfield.z_at(cell.0 + 1);
+            ((z - ray.origin.z) / ray.dir.z, true)
+        } else if ray.dir.z < 0.0 {
+            let z = heightfield.z_at(cell.0 + 0);
+            ((z - ray.origin.z) / ray.dir.z, false)
+        } else {
+            (Real::max_value(), false)
+        };
+
+        if toi_x > max_t && toi_z > max_t {
+            break;
+        }
+
+        if toi_x >= 0.0 && toi_x < toi_z {
+            if right {
+                cell.1 += 1
+            } else if cell.1 > 0 {
+                cell.1 -= 1
             } else {
                 break;
             }
-
-            if cell.0 >= self.nrows() || cell.1 >= self.ncols() {
+        } else if toi_z >= 0.0 {
+            if down {
+                cell.0 += 1
+            } else if cell.0 > 0 {
+                cell.0 -= 1
+            } else {
                 break;
             }
+        } else {
+            break;
+        }
+
+        if cell.0 >= heightfield.nrows() || cell.1 >= heightfield.ncols() {
+            break;
+        }
+    }
+
+    None
+}
+
+// Intersects the ray with the bilinear patch of the cell `(i, j)`, see
+// `HeightField::bilinear_patch`.
+#[cfg(feature = "dim3")]
+fn cast_local_ray_on_bilinear_cell(
+    heightfield: &HeightField,
+    i: usize,
+    j: usize,
+    ray: &Ray,
+    max_toi: Real,
+) -> Option<RayIntersection> {
+    let ([a, b, c, e], width, height) = heightfield.bilinear_patch(i, j);
+
+    // The ray expressed with the (u, v) cell coordinates.
+    let (u0, du) = (
+        (ray.origin.x - heightfield.x_at(j)) / width,
+        ray.dir.x / width,
+    );
+    let (v0, dv) = (
+        (ray.origin.z - heightfield.z_at(i)) / height,
+        ray.dir.z / height,
+    );
+
+    // The range of ray parameters for which the ray is above the cell.
+    let (min_u, max_u) = slab_ray_parameters(u0, du)?;
+    let (min_v, max_v) = slab_ray_parameters(v0, dv)?;
+    let min_t = min_u.max(min_v).max(0.0);
+    let max_t = max_u.min(max_v).min(max_toi);
+
+    if min_t > max_t {
+        return None;
+    }
+
+    // Solve `ray.origin.y + t * ray.dir.y = y(u0 + t * du, v0 + t * dv)`.
+    let qa = -e * du * dv;
+    let qb = ray.dir.y - (b * du + c * dv + e * (u0 * dv + v0 * du));
+    let qc = ray.origin.y - (a + b * u0 + c * v0 + e * u0 * v0);
+    let eps = crate::math::DEFAULT_EPSILON;
+
+    let toi = if qa.abs() <= eps * (qb.abs() + qc.abs()) {
+        if qb == 0.0 {
+            return None;
         }
 
-        None
+        let t = -qc / qb;
+
+        if t < min_t || t > max_t {
+            return None;
+        }
+
+        t
+    } else {
+        let discr = qb * qb - 4.0 * qa * qc;
+
+        if discr < 0.0 {
+            return None;
+        }
+
+        let sqrt_discr = ComplexField::sqrt(discr);
+        let t1 = (-qb - sqrt_discr) / (2.0 * qa);
+        let t2 = (-qb + sqrt_discr) / (2.0 * qa);
+        let (t1, t2) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+
+        if t1 >= min_t && t1 <= max_t {
+            t1
+        } else if t2 >= min_t && t2 <= max_t {
+            t2
+        } else {
+            return None;
+        }
+    };
+
+    let (u, v) = (u0 + toi * du, v0 + toi * dv);
+    let mut normal = Vector::new(-(b + e * v) / width, 1.0, -(c + e * u) / height).normalize();
+
+    if normal.dot(&ray.dir) > 0.0 {
+        normal = -normal;
+    }
+
+    Some(RayIntersection::new(toi, normal, FeatureId::Unknown))
+}
+
+// The range of parameters `t` such that `origin + t * dir` lies in `[0, 1]`.
+#[cfg(feature = "dim3")]
+fn slab_ray_parameters(origin: Real, dir: Real) -> Option<(Real, Real)> {
+    use num_traits::Bounded;
+
+    if dir == 0.0 {
+        if (0.0..=1.0).contains(&origin) {
+            Some((-Real::max_value(), Real::max_value()))
+        } else {
+            None
+        }
+    } else {
+        let t0 = -origin / dir;
+        let t1 = (1.0 - origin) / dir;
+        Some((t0.min(t1), t0.max(t1)))
     }
 }
@@ -0,0 +1,128 @@
+use na::ComplexField;
+
+use crate::math::{Isometry, Real};
+use crate::query::visitors::RayIntersectionsVisitor;
+use crate::query::{Ray, RayIntersection};
+use crate::shape::Shape;
+use std::cmp::Ordering;
+
+/// Computes all the intersections between a ray and a transformed shape, sorted by increasing
+/// time of impact.
+///
+/// * For a convex shape, both the point where the ray enters the shape and the point where it
+///   exits it are reported. If the ray origin is inside the shape, only the exit is reported.
+///   Shapes with no thickness, e.g., 3D triangles, yield a single intersection.
+/// * For a composite shape, the intersections with all its parts are reported. A ray hitting
+///   an edge or a vertex shared by several parts yields one intersection per part. The parts
+///   of round composite shapes, e.g., `RoundTriMesh`, are convex and overlap, so each of them
+///   reports its own entry and exit, and these pairs overlap along the ray.
+/// * For any other shape, only the first intersection is reported.
+///
+/// The normals of the intersections point toward the exterior of the shape.
+pub fn ray_intersections(
+    position: &Isometry<Real>,
+    shape: &dyn Shape,
+    ray: &Ray,
+    max_toi: Real,
+) -> Vec<RayIntersection> {
+    let mut result = Vec::new();
+    collect_ray_intersections(position, shape, ray, max_toi, &mut result);
+    result.sort_by(|a, b| a.toi.partial_cmp(&b.toi).unwrap_or(Ordering::Equal));
+    result
+}
+
+fn collect_ray_intersections(
+    position: &Isometry<Real>,
+    shape: &dyn Shape,
+    ray: &Ray,
+    max_toi: Real,
+    out: &mut Vec<RayIntersection>,
+) {
+    if let Some((inner_pos, inner)) = shape.as_transformed_shape() {
+        collect_ray_intersections(&(position * inner_pos), inner, ray, max_toi, out);
+    } else if let Some(composite) = shape.as_composite_shape() {
+        let ls_ray = ray.inverse_transform_by(position);
+        let mut leaf_fn = |part_id: &u32| {
+            composite.map_part_at(*part_id, &mut |part_pos, part_shape| {
+                let part_pos = part_pos.map(|p| position * p).unwrap_or(*position);
+                collect_ray_intersections(&part_pos, part_shape, ray, max_toi, out);
+            });
+            true
+        };
+
+        let mut visitor = RayIntersectionsVisitor::new(&ls_ray, max_toi, &mut leaf_fn);
+        composite.quadtree().traverse_depth_first(&mut visitor);
+    } else if shape.is_convex() {
+        let ls_ray = ray.inverse_transform_by(position);
+        out.extend(
+            local_ray_intersections_with_convex_shape(shape, &ls_ray, max_toi)
+                .iter()
+                .flatten()
+                .map(|hit| hit.transform_by(position)),
+        );
+    } else if let Some(hit) = shape.cast_ray_and_get_normal(position, ray, max_toi, false) {
+        out.push(hit);
+    }
+}
+
+// Computes the points where the ray enters and exits a convex shape.
+fn local_ray_intersections_with_convex_shape(
+    shape: &dyn Shape,
+    ray: &Ray,
+    max_toi: Real,
+) -> [Option<RayIntersection>; 2] {
+    let entry = match shape.cast_local_ray_and_get_normal(ray, max_toi, true) {
+        Some(entry) => entry,
+        None => return [None, None],
+    };
+
+    // Cast the ray backward, from a point beyond the shape, to find the exit. This
+    // also works if the ray starts inside of the shape, and yields an outward normal.
+    let dir_norm = ray.dir.norm();
+    let mut t_far = entry.toi + shape.compute_local_aabb().extents().norm() / dir_norm;
+
+    if !t_far.is_finite() {
+        // The shape is unbounded, e.g. a slab: march along the ray, with increasing steps,
+        // until a point outside of the shape is found. There is no exit within `max_toi`
+        // if there is no such point.
+        let mut step = 1.0 / dir_norm;
+        t_far = entry.toi + step;
+
+        while t_far < max_toi && shape.contains_local_point(&ray.point_at(t_far)) {
+            step *= 2.0;
+            t_far = entry.toi + step;
+        }
+
+        t_far = t_far.min(max_toi);
+
+        if shape.contains_local_point(&ray.point_at(t_far)) {
+            return if entry.toi == 0.0 {
+                [None, None]
+            } else {
+                [Some(entry), None]
+            };
+        }
+    }
+
+    let backward = Ray::new(ray.point_at(t_far), -ray.dir);
+    let exit = shape
+        .cast_local_ray_and_get_normal(&backward, t_far - entry.toi, true)
+        .and_then(|mut exit| {
+            exit.toi = t_far - exit.toi;
+
+            // Ignore the exit if it coincides with the entry, e.g. for flat shapes.
+            let tolerance = ComplexField::sqrt(crate::math::DEFAULT_EPSILON) * t_far;
+            if exit.toi <= max_toi && exit.toi - entry.toi > tolerance {
+                Some(exit)
+            } else {
+                None
+            }
+        });
+
+    if entry.toi == 0.0 {
+        // The ray starts inside of the shape, so there is no entry.
+        [exit, None]
+    } else {
+        [Some(entry), exit]
+    }
+}
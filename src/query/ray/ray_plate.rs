@@ -0,0 +1,36 @@
+use crate::math::{Real, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Plate};
+
+impl RayCast for Plate {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        _: bool,
+    ) -> Option<RayIntersection> {
+        // The plate has no interior, so the ray hits it where it crosses the `xz` plane.
+        if ray.dir.y == 0.0 {
+            return None;
+        }
+
+        let toi = -ray.origin.y / ray.dir.y;
+
+        if toi < 0.0 || toi > max_toi {
+            return None;
+        }
+
+        let pt = ray.point_at(toi);
+
+        if pt.x.abs() > self.half_extents.x || pt.z.abs() > self.half_extents.y {
+            return None;
+        }
+
+        // The normal points toward the side of the plate the ray comes from.
+        if ray.dir.y < 0.0 {
+            Some(RayIntersection::new(toi, Vector::y(), FeatureId::Face(0)))
+        } else {
+            Some(RayIntersection::new(toi, -Vector::y(), FeatureId::Face(1)))
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::math::{Real, Vector};
+use crate::query::details::RayCompositeShapeToiAndNormalBestFirstVisitor;
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Polygon};
+
+impl RayCast for Polygon {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let inside = self.contains_local_point(&ray.origin);
+
+        if inside && solid {
+            return Some(RayIntersection::new(0.0, na::zero(), FeatureId::Unknown));
+        }
+
+        let boundary = self.boundary();
+        let mut visitor =
+            RayCompositeShapeToiAndNormalBestFirstVisitor::new(boundary, ray, max_toi, false);
+        let (_, (segment, hit)) = boundary.quadtree().traverse_best_first(&mut visitor)?;
+
+        // The interior of the polygon is on the left of its boundary.
+        let dir = boundary.segment(segment).scaled_direction();
+        let normal = Vector::new(dir.y, -dir.x).normalize();
+
+        Some(RayIntersection::new(
+            hit.toi,
+            if inside { -normal } else { normal },
+            FeatureId::Face(segment),
+        ))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::math::{Real, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Prism};
+
+impl RayCast for Prism {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let n = self.cross_section().len();
+        let h = self.half_height();
+        let mut inside = true;
+        let mut enter = (-Real::MAX, 0);
+        let mut exit = (Real::MAX, 0);
+
+        // Clip the ray with the half-spaces bounding the prism, i.e. its side
+        // faces followed by its bottom and top caps.
+        for face in 0..n + 2 {
+            let (normal, offset) = if face < n {
+                let pt = self.cross_section()[face];
+                let normal = self.face_normal(face as u32);
+                (normal, normal.x * pt.x + normal.z * pt.y)
+            } else {
+                (self.face_normal(face as u32), h)
+            };
+
+            let dist = normal.dot(&ray.origin.coords) - offset;
+            let denom = normal.dot(&ray.dir);
+            inside = inside && dist <= 0.0;
+
+            if denom == 0.0 {
+                if dist > 0.0 {
+                    return None;
+                }
+            } else {
+                let toi = -dist / denom;
+
+                if denom < 0.0 {
+                    if toi > enter.0 {
+                        enter = (toi, face);
+                    }
+                } else if toi < exit.0 {
+                    exit = (toi, face);
+                }
+            }
+        }
+
+        if enter.0 > exit.0 {
+            return None;
+        }
+
+        let (toi, face, normal_sign) = if inside {
+            if solid {
+                return Some(RayIntersection::new(
+                    0.0,
+                    Vector::zeros(),
+                    FeatureId::Face(0),
+                ));
+            }
+
+            (exit.0, exit.1, -1.0)
+        } else {
+            (enter.0, enter.1, 1.0)
+        };
+
+        if toi > max_toi || toi == Real::MAX {
+            None
+        } else {
+            let normal = self.face_normal(face as u32) * normal_sign;
+            Some(RayIntersection::new(
+                toi,
+                normal,
+                FeatureId::Face(face as u32),
+            ))
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::math::Real;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::ScaledShape;
+
+impl<S: RayCast> ScaledShape<S> {
+    // The ray in the local-space of the base shape, with the same time of impacts.
+    fn unscale_ray(&self, ray: &Ray) -> Ray {
+        Ray::new(
+            self.unscale_point(&ray.origin),
+            ray.dir.component_div(&self.scale),
+        )
+    }
+}
+
+impl<S: RayCast> RayCast for ScaledShape<S> {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_toi: Real, solid: bool) -> Option<Real> {
+        self.base_shape
+            .cast_local_ray(&self.unscale_ray(ray), max_toi, solid)
+    }
+
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let mut hit = self.base_shape.cast_local_ray_and_get_normal(
+            &self.unscale_ray(ray),
+            max_toi,
+            solid,
+        )?;
+        // Normals are transformed by the inverse transpose of the scaling.
+        hit.normal = hit
+            .normal
+            .component_div(&self.scale)
+            .try_normalize(0.0)
+            .unwrap_or(hit.normal);
+        Some(hit)
+    }
+
+    #[inline]
+    fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
+        self.base_shape
+            .intersects_local_ray(&self.unscale_ray(ray), max_toi)
+    }
+}
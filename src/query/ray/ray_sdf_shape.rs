@@ -0,0 +1,60 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{Real, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, SdfShape};
+use num::Zero;
+
+impl RayCast for SdfShape {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let inside = self.signed_distance_to_local_point(&ray.origin) <= 0.0;
+
+        if inside && solid {
+            return Some(RayIntersection::new(
+                0.0,
+                Vector::zeros(),
+                FeatureId::Unknown,
+            ));
+        }
+
+        let dir_norm = ray.dir.norm();
+
+        if dir_norm.is_zero() {
+            return None;
+        }
+
+        // Sphere tracing restricted to the part of the ray inside of the AABB.
+        // Each step is as long as the distance to the surface so it can't jump over it.
+        let aabb = self.aabb().loosened(self.tolerance);
+        let (tmin, tmax) = aabb.clip_ray_parameters(ray)?;
+        let mut toi = tmin;
+
+        for _ in 0..self.max_iterations {
+            if toi > tmax || toi > max_toi {
+                return None;
+            }
+
+            let pt = ray.point_at(toi);
+            let dist = self.signed_distance_to_local_point(&pt);
+            let dist = if inside { -dist } else { dist };
+
+            if dist <= self.tolerance {
+                let normal = self.gradient(&pt).unwrap_or_else(|| -ray.dir / dir_norm);
+
+                return Some(RayIntersection::new(
+                    toi,
+                    if inside { -normal } else { normal },
+                    FeatureId::Unknown,
+                ));
+            }
+
+            toi += dist / dir_norm;
+        }
+
+        None
+    }
+}
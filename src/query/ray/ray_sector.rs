@@ -0,0 +1,69 @@
+use crate::math::{Real, Vector};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Sector};
+use na::{ComplexField, RealField};
+
+impl RayCast for Sector {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let inside = self.contains_local_point(&ray.origin);
+
+        if inside && solid {
+            return Some(RayIntersection::new(0.0, na::zero(), FeatureId::Face(0)));
+        }
+
+        let mut best: Option<(Real, Vector<Real>, FeatureId)> = None;
+        let mut keep = |toi: Real, normal: Vector<Real>, feature: FeatureId| {
+            if toi >= 0.0 && toi <= max_toi && best.map(|b| toi < b.0).unwrap_or(true) {
+                best = Some((toi, normal, feature));
+            }
+        };
+
+        // Intersections with the arc.
+        let a = ray.dir.norm_squared();
+        let b = ray.origin.coords.dot(&ray.dir);
+        let c = ray.origin.coords.norm_squared() - self.radius * self.radius;
+        let delta = b * b - a * c;
+
+        if a > 0.0 && delta >= 0.0 {
+            let sqrt_delta = ComplexField::sqrt(delta);
+
+            for toi in [(-b - sqrt_delta) / a, (-b + sqrt_delta) / a].iter() {
+                let pt = ray.point_at(*toi);
+
+                if self.contains_angle(RealField::atan2(pt.y, pt.x)) {
+                    keep(*toi, pt.coords / self.radius, FeatureId::Face(0));
+                }
+            }
+        }
+
+        // Intersections with the straight edges.
+        let edges = [
+            (self.start_dir(), 1.0, FeatureId::Face(1)),
+            (self.end_dir(), -1.0, FeatureId::Face(2)),
+        ];
+
+        for (dir, sign, feature) in edges.iter() {
+            let denom = dir.perp(&ray.dir);
+
+            if denom != 0.0 {
+                let toi = -dir.perp(&ray.origin.coords) / denom;
+                let s = ray.point_at(toi).coords.dot(dir);
+
+                if s >= 0.0 && s <= self.radius {
+                    // The outward normal of the edge.
+                    let normal = Vector::new(dir.y, -dir.x) * *sign;
+                    keep(toi, normal, *feature);
+                }
+            }
+        }
+
+        best.map(|(toi, normal, feature)| {
+            RayIntersection::new(toi, if inside { -normal } else { normal }, feature)
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use na;
+
+use crate::math::Real;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Slab};
+
+impl RayCast for Slab {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let orig = self.normal.dot(&ray.origin.coords);
+        let dir = self.normal.dot(&ray.dir);
+        let inside = orig.abs() <= self.half_thickness;
+
+        if solid && inside {
+            // The ray starts inside of the solid slab.
+            return Some(RayIntersection::new(0.0, na::zero(), FeatureId::Face(0)));
+        }
+
+        if dir == 0.0 {
+            // The ray is parallel to the boundaries of the slab.
+            return None;
+        }
+
+        // The parameters of the intersections with the boundaries with outward normals
+        // `self.normal` (face 0) and `-self.normal` (face 1).
+        let t0 = (self.half_thickness - orig) / dir;
+        let t1 = (-self.half_thickness - orig) / dir;
+
+        let (t, face) = if inside {
+            // Exit through the boundary the ray is moving toward.
+            if dir > 0.0 {
+                (t0, 0)
+            } else {
+                (t1, 1)
+            }
+        } else if dir < 0.0 {
+            // Enter through the boundary facing the ray.
+            (t0, 0)
+        } else {
+            (t1, 1)
+        };
+
+        if t >= 0.0 && t <= max_toi {
+            let outward = if face == 0 { self.normal } else { -self.normal };
+            let n = if inside { -outward } else { outward };
+
+            Some(RayIntersection::new(t, *n, FeatureId::Face(face)))
+        } else {
+            None
+        }
+    }
+}
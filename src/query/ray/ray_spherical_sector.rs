@@ -0,0 +1,84 @@
+use crate::math::{Real, Vector};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, SphericalSector};
+use na::ComplexField;
+
+impl RayCast for SphericalSector {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let inside = self.contains_local_point(&ray.origin);
+
+        if inside && solid {
+            return Some(RayIntersection::new(0.0, na::zero(), FeatureId::Face(0)));
+        }
+
+        let mut best: Option<(Real, Vector<Real>, FeatureId)> = None;
+        let mut keep = |toi: Real, normal: Vector<Real>, feature: FeatureId| {
+            if toi >= 0.0 && toi <= max_toi && best.map(|b| toi < b.0).unwrap_or(true) {
+                best = Some((toi, normal, feature));
+            }
+        };
+
+        let sq_radius = self.radius * self.radius;
+        let a = ray.dir.norm_squared();
+
+        if a == 0.0 {
+            return None;
+        }
+
+        // Intersections with the spherical cap.
+        let b = ray.origin.coords.dot(&ray.dir);
+        let c = ray.origin.coords.norm_squared() - sq_radius;
+        let delta = b * b - a * c;
+
+        if delta >= 0.0 {
+            let sqrt_delta = ComplexField::sqrt(delta);
+
+            for toi in [(-b - sqrt_delta) / a, (-b + sqrt_delta) / a].iter() {
+                let pt = ray.point_at(*toi);
+
+                if self.contains_direction(&pt.coords) {
+                    keep(*toi, pt.coords / self.radius, FeatureId::Face(0));
+                }
+            }
+        }
+
+        // Intersections with the lateral surface of the cone, i.e., the points such that
+        // `y^2 = cos^2 * |p|^2` on the same side of the `xz` plane as the sector's axis.
+        let cos = ComplexField::cos(self.half_angle);
+        let sq_cos = cos * cos;
+        let (o, d) = (&ray.origin.coords, &ray.dir);
+        let qa = d.y * d.y - sq_cos * a;
+        let qb = o.y * d.y - sq_cos * b;
+        let qc = o.y * o.y - sq_cos * o.norm_squared();
+        let mut tois = [None, None];
+
+        if qa != 0.0 {
+            let delta = qb * qb - qa * qc;
+
+            if delta >= 0.0 {
+                let sqrt_delta = ComplexField::sqrt(delta);
+                tois = [Some((-qb - sqrt_delta) / qa), Some((-qb + sqrt_delta) / qa)];
+            }
+        } else if qb != 0.0 {
+            tois[0] = Some(-qc / (2.0 * qb));
+        }
+
+        for toi in tois.iter().flatten() {
+            let pt = ray.point_at(*toi);
+
+            // The tolerance accounts for the rounding errors when the cone is almost flat.
+            if pt.coords.norm_squared() <= sq_radius && pt.y * cos >= -Real::EPSILON * sq_radius {
+                keep(*toi, self.lateral_normal(&pt), FeatureId::Face(1));
+            }
+        }
+
+        best.map(|(toi, normal, feature)| {
+            RayIntersection::new(toi, if inside { -normal } else { normal }, feature)
+        })
+    }
+}
@@ -5,11 +5,11 @@ use crate::math::Real;
 use crate::query;
 use crate::query::gjk::{self, CSOPoint, VoronoiSimplex};
 use crate::query::{Ray, RayCast, RayIntersection};
-#[cfg(feature = "dim2")]
-use crate::shape::ConvexPolygon;
-use crate::shape::{Capsule, FeatureId, Segment, SupportMap};
+use crate::shape::{Capsule, FeatureId, MinkowskiSum, Segment, SupportMap, TaperedCapsule};
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexPolyhedron, Cylinder};
+use crate::shape::{Cone, ConvexPolyhedron, Cylinder, EllipticCylinder, SolidOfRevolution};
+#[cfg(feature = "dim2")]
+use crate::shape::{ConvexPolygon, Degeneracy};
 use num::Zero;
 
 /// Cast a ray on a shape using the GJK algorithm.
@@ -78,6 +78,24 @@ impl RayCast for Cylinder {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl RayCast for EllipticCylinder {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        local_ray_intersection_with_support_map_with_params(
+            self,
+            &mut VoronoiSimplex::new(),
+            &ray,
+            max_toi,
+            solid,
+        )
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl RayCast for Cone {
     fn cast_local_ray_and_get_normal(
@@ -96,6 +114,41 @@ impl RayCast for Cone {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl RayCast for SolidOfRevolution {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        local_ray_intersection_with_support_map_with_params(
+            self,
+            &mut VoronoiSimplex::new(),
+            &ray,
+            max_toi,
+            solid,
+        )
+    }
+}
+
+impl<A: SupportMap, B: SupportMap> RayCast for MinkowskiSum<A, B> {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        local_ray_intersection_with_support_map_with_params(
+            self,
+            &mut VoronoiSimplex::new(),
+            &ray,
+            max_toi,
+            solid,
+        )
+    }
+}
+
 impl RayCast for Capsule {
     fn cast_local_ray_and_get_normal(
         &self,
@@ -113,6 +166,23 @@ impl RayCast for Capsule {
     }
 }
 
+impl RayCast for TaperedCapsule {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        local_ray_intersection_with_support_map_with_params(
+            self,
+            &mut VoronoiSimplex::new(),
+            &ray,
+            max_toi,
+            solid,
+        )
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl RayCast for ConvexPolyhedron {
     fn cast_local_ray_and_get_normal(
@@ -159,6 +229,25 @@ impl RayCast for Segment {
     ) -> Option<RayIntersection> {
         #[cfg(feature = "dim2")]
         {
+            if let Some(Degeneracy::Point(pt)) = self.degeneracy(crate::math::DEFAULT_EPSILON) {
+                // The segment is a point, which is hit only if it lies on the ray.
+                let dpos = pt - ray.origin;
+                let toi = dpos.dot(&ray.dir) / ray.dir.norm_squared();
+
+                return if toi >= 0.0
+                    && toi <= max_toi
+                    && (dpos - ray.dir * toi).norm() <= crate::math::DEFAULT_EPSILON
+                {
+                    Some(RayIntersection::new(
+                        toi,
+                        -ray.dir.normalize(),
+                        FeatureId::Vertex(0),
+                    ))
+                } else {
+                    None
+                };
+            }
+
             let seg_dir = self.scaled_direction();
             let (s, t, parallel) = query::details::closest_points_line_line_parameters_eps(
                 &ray.origin,
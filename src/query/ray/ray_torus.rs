@@ -0,0 +1,160 @@
+use crate::math::{Point, Real, Vector};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Torus};
+use na::{ComplexField, RealField};
+use num::Zero;
+
+impl RayCast for Torus {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let inside = self.contains_local_point(&ray.origin);
+
+        if inside && solid {
+            return Some(RayIntersection::new(
+                0.0,
+                Vector::zeros(),
+                FeatureId::Face(0),
+            ));
+        }
+
+        let dir_norm = ray.dir.norm();
+
+        if dir_norm.is_zero() {
+            return None;
+        }
+
+        let (tmin, tmax) = self.local_aabb().clip_ray_parameters(ray)?;
+        let tmax = tmax.min(max_toi);
+
+        if tmin > tmax {
+            return None;
+        }
+
+        // Solve the quartic on the part of the ray inside of the AABB, with a
+        // normalized direction, to keep its coefficients well-conditioned.
+        let origin = ray.point_at(tmin);
+        let dir = ray.dir / dir_norm;
+        let length = (tmax - tmin) * dir_norm;
+        let toi = tmin + self.first_root_on_ray(&origin, &dir, length, inside)? / dir_norm;
+
+        let pt = ray.point_at(toi);
+        let normal = (pt - self.tube_center_point(&pt))
+            .try_normalize(0.0)
+            .unwrap_or_else(|| -ray.dir / dir_norm);
+
+        Some(RayIntersection::new(
+            toi,
+            if inside { -normal } else { normal },
+            FeatureId::Face(0),
+        ))
+    }
+}
+
+impl Torus {
+    // The smallest `t` in `[0, length]` such that `origin + dir * t` is on the boundary of
+    // this torus, assuming `dir` is normalized. `inside` indicates on which side of the
+    // boundary the ray starts, which can't be deduced from the sign of the quartic if
+    // `origin` is on the surface of the torus.
+    //
+    // The points on the surface of the torus are the roots of the quartic
+    // `(|p|² + R² - r²)² - 4R²(p.x² + p.z²)`, which is negative inside of the torus.
+    fn first_root_on_ray(
+        &self,
+        origin: &Point<Real>,
+        dir: &Vector<Real>,
+        length: Real,
+        inside: bool,
+    ) -> Option<Real> {
+        let r2 = self.major_radius * self.major_radius;
+        let h = 2.0 * origin.coords.dot(dir);
+        let i = origin.coords.norm_squared() + r2 - self.minor_radius * self.minor_radius;
+        let j = dir.x * dir.x + dir.z * dir.z;
+        let k = 2.0 * (origin.x * dir.x + origin.z * dir.z);
+        let l = origin.x * origin.x + origin.z * origin.z;
+
+        let c3 = 2.0 * h;
+        let c2 = h * h + 2.0 * i - 4.0 * r2 * j;
+        let c1 = 2.0 * h * i - 4.0 * r2 * k;
+        let c0 = i * i - 4.0 * r2 * l;
+        let eval = |t: Real| (((t + c3) * t + c2) * t + c1) * t + c0;
+
+        // The quartic is monotonic between two consecutive roots of its derivative,
+        // so it has at most one root there.
+        let (mut crit, num_crit) = monic_cubic_roots(c3 * 0.75, c2 * 0.5, c1 * 0.25);
+        crit[..num_crit].sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut a = 0.0;
+        let outside = !inside;
+
+        for b in crit[..num_crit]
+            .iter()
+            .copied()
+            .filter(|t| *t > 0.0 && *t < length)
+            .chain(std::iter::once(length))
+        {
+            let fb = eval(b);
+            let crosses_boundary = if outside { fb <= 0.0 } else { fb >= 0.0 };
+
+            if crosses_boundary {
+                return Some(bisect(eval, a, b, outside));
+            }
+
+            a = b;
+        }
+
+        None
+    }
+}
+
+// Finds the root of `f` on `[a, b]` assuming `f` changes sign exactly once on this interval.
+fn bisect(f: impl Fn(Real) -> Real, mut a: Real, mut b: Real, positive_at_a: bool) -> Real {
+    for _ in 0..100 {
+        let mid = (a + b) * 0.5;
+
+        if mid <= a || mid >= b {
+            break;
+        }
+
+        if (f(mid) > 0.0) == positive_at_a {
+            a = mid;
+        } else {
+            b = mid;
+        }
+    }
+
+    // Return the bound on the surface side first reached by the ray.
+    b
+}
+
+// The real roots of `t³ + a t² + b t + c`.
+fn monic_cubic_roots(a: Real, b: Real, c: Real) -> ([Real; 3], usize) {
+    // Substitute `t = x - a / 3` to obtain the depressed cubic `x³ + p x + q`.
+    let shift = a / 3.0;
+    let p = b - a * shift;
+    let q = 2.0 * shift * shift * shift - b * shift + c;
+    let disc = q * q / 4.0 + p * p * p / 27.0;
+
+    if disc > 0.0 {
+        let sqrt_disc = ComplexField::sqrt(disc);
+        let x = ComplexField::cbrt(-q / 2.0 + sqrt_disc) + ComplexField::cbrt(-q / 2.0 - sqrt_disc);
+        ([x - shift, 0.0, 0.0], 1)
+    } else if p.is_zero() {
+        ([-shift, 0.0, 0.0], 1)
+    } else {
+        let m = 2.0 * ComplexField::sqrt(-p / 3.0);
+        let cos_3theta = (3.0 * q / (p * m)).clamp(-1.0, 1.0);
+        let theta = ComplexField::acos(cos_3theta) / 3.0;
+        let mut roots = [0.0; 3];
+
+        for (k, root) in roots.iter_mut().enumerate() {
+            let angle = theta - Real::two_pi() * (k as Real) / 3.0;
+            *root = m * ComplexField::cos(angle) - shift;
+        }
+
+        (roots, 3)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::math::Real;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::TransformedShape;
+
+impl<S: RayCast> RayCast for TransformedShape<S> {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_toi: Real, solid: bool) -> Option<Real> {
+        self.shape.cast_ray(&self.position, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        self.shape
+            .cast_ray_and_get_normal(&self.position, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
+        self.shape.intersects_ray(&self.position, ray, max_toi)
+    }
+}
@@ -17,12 +17,14 @@ impl RayCast for Triangle {
     ) -> Option<RayIntersection> {
         let edges = self.edges();
 
-        if solid {
+        // A degenerate triangle has no interior, so its edges are enough to find the hit.
+        if solid && self.degeneracy(crate::math::DEFAULT_EPSILON).is_none() {
             let perp1 = edges[0].scaled_direction().perp(&(ray.origin - edges[0].a));
             let perp2 = edges[1].scaled_direction().perp(&(ray.origin - edges[1].a));
             let perp3 = edges[2].scaled_direction().perp(&(ray.origin - edges[2].a));
 
-            if perp1 * perp2 <= 0.0 || perp1 * perp3 <= 0.0 {
+            // The ray origin is inside if it is on the same side of all the edges.
+            if perp1 * perp2 >= 0.0 && perp1 * perp3 >= 0.0 {
                 return Some(RayIntersection::new(0.0, Vector::y(), FeatureId::Face(0)));
             }
         }
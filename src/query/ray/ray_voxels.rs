@@ -0,0 +1,136 @@
+use crate::math::{Point, Real, Vector, DIM};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Voxels};
+use num::Zero;
+
+impl RayCast for Voxels {
+    /// Casts a ray on this set of voxels.
+    ///
+    /// The voxels traversed by the ray are visited in order by a digital differential analyzer.
+    /// The feature of the returned intersection is `FeatureId::Face(i)` where `i` is the index
+    /// of the voxel hit in `self.keys()`.
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let origin_key = self.voxel_key_at(&ray.origin);
+        let origin_voxel = self.voxel_index(&origin_key);
+        let inside = origin_voxel.is_some();
+
+        if inside && solid {
+            return Some(RayIntersection::new(
+                0.0,
+                Vector::zeros(),
+                FeatureId::Face(origin_voxel?),
+            ));
+        }
+
+        if ray.dir.is_zero() {
+            return None;
+        }
+
+        let aabb = self.local_aabb();
+        let voxel_size = self.voxel_size();
+        let (tmin, tmax) = if inside {
+            // The ray is stopped by the first empty voxel, which may be outside of the AABB.
+            (0.0, max_toi)
+        } else {
+            let (tmin, tmax) = aabb.clip_ray_parameters(ray)?;
+            (tmin, tmax.min(max_toi))
+        };
+
+        if tmin > tmax {
+            return None;
+        }
+
+        // The axis orthogonal to the last voxel face crossed by the ray.
+        let mut axis = None;
+        let mut key = if inside {
+            origin_key
+        } else if tmin > 0.0 {
+            let mut best_entry = -Real::MAX;
+
+            for i in 0..DIM {
+                if !ray.dir[i].is_zero() {
+                    let bound = if ray.dir[i] > 0.0 {
+                        aabb.mins[i]
+                    } else {
+                        aabb.maxs[i]
+                    };
+                    let entry = (bound - ray.origin[i]) / ray.dir[i];
+
+                    if entry > best_entry {
+                        best_entry = entry;
+                        axis = Some(i);
+                    }
+                }
+            }
+
+            // Clamp the key to the AABB to account for rounding errors.
+            let key = self.voxel_key_at(&ray.point_at(tmin));
+            let key_mins = self.voxel_key_at(&(aabb.mins + voxel_size / 2.0));
+            let key_maxs = self.voxel_key_at(&(aabb.maxs - voxel_size / 2.0));
+            Point::from(key.coords.sup(&key_mins.coords).inf(&key_maxs.coords))
+        } else {
+            origin_key
+        };
+
+        let mut step = Point::<i32>::origin();
+        let mut next_toi = Vector::repeat(Real::MAX);
+        let mut delta_toi = Vector::repeat(Real::MAX);
+
+        for i in 0..DIM {
+            if ray.dir[i] > 0.0 {
+                step[i] = 1;
+                next_toi[i] = ((key[i] + 1) as Real * voxel_size[i] - ray.origin[i]) / ray.dir[i];
+                delta_toi[i] = voxel_size[i] / ray.dir[i];
+            } else if ray.dir[i] < 0.0 {
+                step[i] = -1;
+                next_toi[i] = (key[i] as Real * voxel_size[i] - ray.origin[i]) / ray.dir[i];
+                delta_toi[i] = -voxel_size[i] / ray.dir[i];
+            }
+        }
+
+        let mut toi = tmin;
+        let mut prev_voxel = origin_voxel;
+
+        loop {
+            let voxel = self.voxel_index(&key);
+
+            if voxel.is_some() != inside {
+                // The normal of the crossed face, pointing toward the ray origin.
+                let mut normal = Vector::zeros();
+
+                if let Some(i) = axis {
+                    normal[i] = -step[i] as Real;
+                }
+
+                let hit_voxel = if inside { prev_voxel } else { voxel };
+                return Some(RayIntersection::new(
+                    toi,
+                    normal,
+                    hit_voxel.map(FeatureId::Face).unwrap_or(FeatureId::Unknown),
+                ));
+            }
+
+            prev_voxel = voxel;
+
+            let i = next_toi.imin();
+            toi = next_toi[i];
+
+            if toi > tmax {
+                return None;
+            }
+
+            key[i] += step[i];
+            next_toi[i] += delta_toi[i];
+            axis = Some(i);
+        }
+    }
+}
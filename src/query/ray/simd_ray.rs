@@ -1,4 +1,4 @@
-use crate::math::{Point, SimdReal, Vector};
+use crate::math::{Point, SimdReal, Vector, SIMD_WIDTH};
 use crate::query::Ray;
 use simba::simd::SimdValue;
 
@@ -20,3 +20,15 @@ impl SimdRay {
         }
     }
 }
+
+impl From<[Ray; SIMD_WIDTH]> for SimdRay {
+    fn from(rays: [Ray; SIMD_WIDTH]) -> Self {
+        let origins = array![|ii| rays[ii].origin; SIMD_WIDTH];
+        let dirs = array![|ii| rays[ii].dir; SIMD_WIDTH];
+
+        Self {
+            origin: Point::from(origins),
+            dir: Vector::from(dirs),
+        }
+    }
+}
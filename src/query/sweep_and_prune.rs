@@ -0,0 +1,73 @@
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Real, Vector, DIM};
+
+/// Computes all the pairs of intersecting AABBs using the sweep-and-prune algorithm.
+///
+/// The AABBs are sorted along the axis where their centers are the most spread out. This
+/// list is then swept while maintaining the set of AABBs overlapping the current position
+/// along that axis. Only those are tested against each new AABB, so this performs much
+/// better than testing every pair when the AABBs are well distributed in space.
+///
+/// Each element of `aabbs` is an AABB with the user-defined identifier of its shape. Each
+/// pair `(id1, id2)` of the output identifies two intersecting AABBs, and each pair is
+/// reported only once.
+pub fn sweep_and_prune(aabbs: &[(u32, AABB)]) -> Vec<(u32, u32)> {
+    let mut result = Vec::new();
+
+    if aabbs.len() < 2 {
+        return result;
+    }
+
+    let axis = sweep_axis(aabbs);
+    let mut sorted: Vec<usize> = (0..aabbs.len()).collect();
+    sorted.sort_by(|a, b| {
+        let a = aabbs[*a].1.mins[axis];
+        let b = aabbs[*b].1.mins[axis];
+        a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut active: Vec<usize> = Vec::new();
+
+    for i in sorted {
+        let (id, aabb) = &aabbs[i];
+
+        // Remove all the AABBs that end before the beginning of the current one.
+        active.retain(|j| aabbs[*j].1.maxs[axis] >= aabb.mins[axis]);
+
+        for j in &active {
+            let (other_id, other_aabb) = &aabbs[*j];
+
+            if aabb.intersects(other_aabb) {
+                result.push((*other_id, *id));
+            }
+        }
+
+        active.push(i);
+    }
+
+    result
+}
+
+// Selects the axis with the largest variance of the AABB centers.
+fn sweep_axis(aabbs: &[(u32, AABB)]) -> usize {
+    let mut mean = Vector::zeros();
+    let mut sq_mean = Vector::zeros();
+    let denom = 1.0 / (aabbs.len() as Real);
+
+    for (_, aabb) in aabbs {
+        let center = aabb.center().coords;
+        mean += center * denom;
+        sq_mean += center.component_mul(&center) * denom;
+    }
+
+    let variance = sq_mean - mean.component_mul(&mean);
+    let mut axis = 0;
+
+    for i in 1..DIM {
+        if variance[i] > variance[axis] {
+            axis = i;
+        }
+    }
+
+    axis
+}
@@ -9,10 +9,14 @@ pub use self::time_of_impact_composite_shape_shape::{
 pub use self::time_of_impact_halfspace_support_map::{
     time_of_impact_halfspace_support_map, time_of_impact_support_map_halfspace,
 };
+pub use self::time_of_impact_slab_support_map::{
+    time_of_impact_slab_support_map, time_of_impact_support_map_slab,
+};
 pub use self::time_of_impact_support_map_support_map::time_of_impact_support_map_support_map;
 
 mod time_of_impact;
 mod time_of_impact_ball_ball;
 mod time_of_impact_composite_shape_shape;
 mod time_of_impact_halfspace_support_map;
+mod time_of_impact_slab_support_map;
 mod time_of_impact_support_map_support_map;
@@ -2,7 +2,7 @@ use na::Unit;
 
 use crate::math::{Isometry, Point, Real, Vector};
 use crate::query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
-use crate::shape::Shape;
+use crate::shape::{FeatureId, Shape};
 
 /// The status of the time-of-impact computation algorithm.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -38,6 +38,16 @@ pub struct TOI {
     pub normal1: Unit<Vector<Real>>,
     /// The local-space outward normal on the second shape at the time of impact.
     pub normal2: Unit<Vector<Real>>,
+    /// The feature of the first shape the witness point `self.witness1` lies on.
+    ///
+    /// This is computed by the time-of-impact functions of the `query` module and by
+    /// `TOI::with_features`. It is `FeatureId::Unknown` for the results of a `QueryDispatcher`.
+    pub feature1: FeatureId,
+    /// The feature of the second shape the witness point `self.witness2` lies on.
+    ///
+    /// This is computed by the time-of-impact functions of the `query` module and by
+    /// `TOI::with_features`. It is `FeatureId::Unknown` for the results of a `QueryDispatcher`.
+    pub feature2: FeatureId,
     /// The way the time-of-impact computation algorithm terminated.
     pub status: TOIStatus,
 }
@@ -53,6 +63,8 @@ impl TOI {
             witness2: self.witness1,
             normal1: self.normal2,
             normal2: self.normal1,
+            feature1: self.feature2,
+            feature2: self.feature1,
             status: self.status,
         }
     }
@@ -63,11 +75,41 @@ impl TOI {
             toi: self.toi,
             witness1: pos * self.witness1,
             witness2: self.witness2,
+            normal1: pos * self.normal1,
+            normal2: self.normal2,
+            feature1: self.feature1,
+            feature2: self.feature2,
+            status: self.status,
+        }
+    }
+
+    /// Transform `self.witness2` and `self.normal2` by `pos`.
+    pub fn transform2_by(&self, pos: &Isometry<Real>) -> Self {
+        Self {
+            toi: self.toi,
+            witness1: self.witness1,
+            witness2: pos * self.witness2,
             normal1: self.normal1,
             normal2: pos * self.normal2,
+            feature1: self.feature1,
+            feature2: self.feature2,
             status: self.status,
         }
     }
+
+    /// Sets `self.feature1` and `self.feature2` to the features of `g1` and `g2` the witness
+    /// points lie on.
+    ///
+    /// The features are left unchanged if the shapes are penetrating, since the witness
+    /// points are not meaningful in this case.
+    pub fn with_features(mut self, g1: &dyn Shape, g2: &dyn Shape) -> Self {
+        if self.status != TOIStatus::Penetrating {
+            self.feature1 = g1.project_local_point_and_get_feature(&self.witness1).1;
+            self.feature2 = g2.project_local_point_and_get_feature(&self.witness2).1;
+        }
+
+        self
+    }
 }
 
 /// Computes the smallest time when two shapes under translational movement are separated by a
@@ -86,5 +128,7 @@ pub fn time_of_impact(
 ) -> Result<Option<TOI>, Unsupported> {
     let pos12 = pos1.inv_mul(pos2);
     let vel12 = vel2 - vel1;
-    DefaultQueryDispatcher.time_of_impact(&pos12, &vel12, g1, g2, max_toi, target_distance)
+    let toi =
+        DefaultQueryDispatcher.time_of_impact(&pos12, &vel12, g1, g2, max_toi, target_distance)?;
+    Ok(toi.map(|toi| toi.with_features(g1, g2)))
 }
@@ -2,7 +2,7 @@ use na::Unit;
 
 use crate::math::{Isometry, Point, Real, Vector};
 use crate::query::{self, Ray, TOIStatus, TOI};
-use crate::shape::Ball;
+use crate::shape::{Ball, FeatureId};
 use num::Zero;
 
 /// Time Of Impact of two balls under translational movement.
@@ -55,6 +55,8 @@ pub fn time_of_impact_ball_ball(
             normal2,
             witness1,
             witness2,
+            feature1: FeatureId::Unknown,
+            feature2: FeatureId::Unknown,
             status,
         })
     } else {
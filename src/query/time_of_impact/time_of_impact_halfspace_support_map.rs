@@ -1,6 +1,6 @@
 use crate::math::{Isometry, Real, Vector};
 use crate::query::{Ray, RayCast, TOIStatus, TOI};
-use crate::shape::{HalfSpace, SupportMap};
+use crate::shape::{FeatureId, HalfSpace, SupportMap};
 
 /// Time Of Impact of a halfspace with a support-mapped shape under translational movement.
 pub fn time_of_impact_halfspace_support_map<G: ?Sized>(
@@ -44,6 +44,8 @@ where
             normal2: pos12.inverse_transform_unit_vector(&-halfspace.normal),
             witness1,
             witness2: pos12.inverse_transform_point(&witness2),
+            feature1: FeatureId::Unknown,
+            feature2: FeatureId::Unknown,
             status,
         })
     } else {
@@ -0,0 +1,83 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::{TOIStatus, TOI};
+use crate::shape::{FeatureId, Slab, SupportMap};
+
+/// Time Of Impact of a slab with a support-mapped shape under translational movement.
+pub fn time_of_impact_slab_support_map<G: ?Sized>(
+    pos12: &Isometry<Real>,
+    vel12: &Vector<Real>,
+    slab: &Slab,
+    other: &G,
+    max_toi: Real,
+    target_distance: Real,
+) -> Option<TOI>
+where
+    G: SupportMap,
+{
+    let (normal1, deepest, distance) = slab.separating_side(pos12, other);
+
+    let (toi, status) = if distance <= target_distance {
+        let status = if distance < 0.0 {
+            TOIStatus::Penetrating
+        } else {
+            TOIStatus::Converged
+        };
+
+        (0.0, status)
+    } else {
+        // `other` is separated from the boundary with the normal `normal1`,
+        // and must move toward it to hit the slab.
+        let normal_vel = normal1.dot(vel12);
+
+        if normal_vel >= 0.0 {
+            return None;
+        }
+
+        (
+            (distance - target_distance) / -normal_vel,
+            TOIStatus::Converged,
+        )
+    };
+
+    if toi > max_toi {
+        return None;
+    }
+
+    // Project the witness point to the boundary of the slab.
+    let witness2 = deepest + vel12 * toi;
+    let witness1 = witness2 - *normal1 * (normal1.dot(&witness2.coords) - slab.half_thickness);
+
+    Some(TOI {
+        toi,
+        normal1,
+        normal2: pos12.inverse_transform_unit_vector(&-normal1),
+        witness1,
+        witness2: pos12.inverse_transform_point(&deepest),
+        feature1: FeatureId::Unknown,
+        feature2: FeatureId::Unknown,
+        status,
+    })
+}
+
+/// Time Of Impact of a support-mapped shape with a slab under translational movement.
+pub fn time_of_impact_support_map_slab<G: ?Sized>(
+    pos12: &Isometry<Real>,
+    vel12: &Vector<Real>,
+    other: &G,
+    slab: &Slab,
+    max_toi: Real,
+    target_distance: Real,
+) -> Option<TOI>
+where
+    G: SupportMap,
+{
+    time_of_impact_slab_support_map(
+        &pos12.inverse(),
+        &-vel12,
+        slab,
+        other,
+        max_toi,
+        target_distance,
+    )
+    .map(|toi| toi.swapped())
+}
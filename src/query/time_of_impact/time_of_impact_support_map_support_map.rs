@@ -1,9 +1,9 @@
 use na::Unit;
 
-use crate::math::{Isometry, Real, Vector};
+use crate::math::{Isometry, Point, Real, Vector};
 use crate::query::gjk::{self, DilatedShape, GJKResult, VoronoiSimplex};
 use crate::query::{self, TOIStatus, TOI};
-use crate::shape::SupportMap;
+use crate::shape::{FeatureId, SupportMap};
 use num::Zero;
 
 /// Time of impacts between two support-mapped shapes under translational movement.
@@ -24,6 +24,20 @@ where
             |(toi, normal1, witness1, witness2)| {
                 if toi > max_toi {
                     None
+                } else if toi.is_zero() {
+                    let (normal1, witness1, witness2) =
+                        penetration_normal_and_witnesses(pos12, g1, g2, normal1);
+
+                    Some(TOI {
+                        toi,
+                        normal1,
+                        normal2: Unit::new_unchecked(pos12.inverse_transform_vector(&-normal1)),
+                        witness1,
+                        witness2: pos12.inverse_transform_point(&witness2),
+                        feature1: FeatureId::Unknown,
+                        feature2: FeatureId::Unknown,
+                        status: TOIStatus::Penetrating,
+                    })
                 } else {
                     Some(TOI {
                         toi,
@@ -31,11 +45,9 @@ where
                         normal2: Unit::new_unchecked(pos12.inverse_transform_vector(&-normal1)),
                         witness1,
                         witness2: pos12.inverse_transform_point(&witness2),
-                        status: if toi.is_zero() {
-                            TOIStatus::Penetrating
-                        } else {
-                            TOIStatus::Converged
-                        },
+                        feature1: FeatureId::Unknown,
+                        feature2: FeatureId::Unknown,
+                        status: TOIStatus::Converged,
                     })
                 }
             },
@@ -51,10 +63,6 @@ where
                 if toi > max_toi {
                     None
                 } else {
-                    // This is mutable because, if the TOI is zero, we have to determine
-                    // if the status isn't really a TOIStatus::Penetrating.
-                    let mut status = TOIStatus::Converged;
-
                     if toi.is_zero() {
                         // The TOI is zero but we don't have valid witness points and normal
                         // yet because of we based our computations so far on the dilated shape.
@@ -72,7 +80,9 @@ where
                         ) {
                             GJKResult::ClosestPoints(pt1, pt2, _) => {
                                 // Ok, we managed to compute the witness points.
-                                let normal1 = Unit::new_normalize(pt2 - pt1);
+                                let normal1 =
+                                    Unit::try_new(pt2 - pt1, crate::math::DEFAULT_EPSILON)
+                                        .unwrap_or_else(|| Unit::new_unchecked(normal1));
                                 return Some(TOI {
                                     toi,
                                     normal1,
@@ -81,13 +91,31 @@ where
                                     ),
                                     witness1,
                                     witness2: pos12.inverse_transform_point(&pt2),
+                                    feature1: FeatureId::Unknown,
+                                    feature2: FeatureId::Unknown,
                                     status: TOIStatus::Converged,
                                 });
                             }
                             GJKResult::NoIntersection(_) => {
                                 // This should never happen.
                             }
-                            GJKResult::Intersection => status = TOIStatus::Penetrating,
+                            GJKResult::Intersection => {
+                                let (normal1, witness1, witness2) =
+                                    penetration_normal_and_witnesses(pos12, g1, g2, normal1);
+
+                                return Some(TOI {
+                                    toi,
+                                    normal1,
+                                    normal2: Unit::new_unchecked(
+                                        pos12.inverse_transform_vector(&-normal1),
+                                    ),
+                                    witness1,
+                                    witness2: pos12.inverse_transform_point(&witness2),
+                                    feature1: FeatureId::Unknown,
+                                    feature2: FeatureId::Unknown,
+                                    status: TOIStatus::Penetrating,
+                                });
+                            }
                             GJKResult::Proximity(_) => unreachable!(),
                         }
                     }
@@ -98,9 +126,49 @@ where
                         normal2: Unit::new_unchecked(pos12.inverse_transform_vector(&-normal1)),
                         witness1: witness1 - normal1 * target_distance,
                         witness2: pos12.inverse_transform_point(&witness2),
-                        status,
+                        feature1: FeatureId::Unknown,
+                        feature2: FeatureId::Unknown,
+                        status: TOIStatus::Converged,
                     })
                 }
             })
     }
 }
+
+// The contact normal and witness points of two touching or penetrating shapes.
+//
+// The GJK ray-cast gives no meaningful normal in this case (it is just the opposite of
+// the relative velocity) so we compute it with GJK/EPA instead, falling back to the
+// ray-cast normal if this fails. The second witness point is expressed in the
+// local-space of `g1`.
+fn penetration_normal_and_witnesses<G1: ?Sized, G2: ?Sized>(
+    pos12: &Isometry<Real>,
+    g1: &G1,
+    g2: &G2,
+    fallback: Vector<Real>,
+) -> (Unit<Vector<Real>>, Point<Real>, Point<Real>)
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    if let GJKResult::ClosestPoints(pt1, pt2, normal1) =
+        query::details::contact_support_map_support_map_with_params(
+            pos12,
+            g1,
+            g2,
+            Real::MAX,
+            &mut VoronoiSimplex::new(),
+            Unit::try_new(fallback, crate::math::DEFAULT_EPSILON),
+        )
+    {
+        if let Some(normal1) = Unit::try_new(*normal1, crate::math::DEFAULT_EPSILON) {
+            return (normal1, pt1, pt2);
+        }
+    }
+
+    (
+        Unit::new_unchecked(fallback),
+        Point::origin(),
+        Point::origin(),
+    )
+}
@@ -0,0 +1,30 @@
+//! Definition of the annulus shape.
+
+use crate::math::Real;
+
+/// A ring-shaped 2D shape: the region between two concentric circles centered at the origin.
+///
+/// The annulus is not convex so it does not implement `SupportMap`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Annulus {
+    /// The radius of the circle bounding the hole of the annulus.
+    pub inner_radius: Real,
+    /// The radius of the circle bounding the annulus from the outside.
+    pub outer_radius: Real,
+}
+
+impl Annulus {
+    /// Creates a new annulus from the radii of its two circles.
+    pub fn new(inner_radius: Real, outer_radius: Real) -> Annulus {
+        assert!(
+            inner_radius.is_sign_positive() && inner_radius <= outer_radius,
+            "The inner radius of an annulus must be positive and smaller than its outer radius."
+        );
+
+        Annulus {
+            inner_radius,
+            outer_radius,
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::math::{Isometry, Real};
+use crate::shape::{HeightField, Polyline, Shape, TriMesh, TypedShape};
+use approx::AbsDiffEq;
+
+/// Checks if two shapes of the same type are equal, up to the tolerance `eps`.
+///
+/// The scalar parameters, the vertices, and the positions of the sub-shapes of both shapes are
+/// compared componentwise with the tolerance `eps`, while their index buffers, their holes, and
+/// their voxel keys must be exactly equal. Shapes defined by a list of vertices are considered
+/// equal only if their vertices are listed in the same order. Only the geometry is compared:
+/// user-defined data, topologies, and pseudo-normals are ignored.
+///
+/// Shapes of different types are never equal, even if they have the same geometry, e.g., a
+/// `Cuboid` and a `ConvexPolyhedron` with the same vertices. Shapes that can't be inspected,
+/// i.e., `SdfShape`, `ScaledShape`, `MinkowskiSum`, and custom shapes, are only equal to
+/// themselves.
+pub fn approx_eq(shape1: &dyn Shape, shape2: &dyn Shape, eps: Real) -> bool {
+    if let (Some((pos1, inner1)), Some((pos2, inner2))) =
+        (shape1.as_transformed_shape(), shape2.as_transformed_shape())
+    {
+        return isometry_eq(pos1, pos2, eps) && approx_eq(inner1, inner2, eps);
+    }
+
+    match (shape1.as_typed_shape(), shape2.as_typed_shape()) {
+        (TypedShape::Ball(s1), TypedShape::Ball(s2)) => eq(&s1.radius, &s2.radius, eps),
+        (TypedShape::Cuboid(s1), TypedShape::Cuboid(s2)) => {
+            eq(&s1.half_extents, &s2.half_extents, eps)
+        }
+        (TypedShape::Capsule(s1), TypedShape::Capsule(s2)) => {
+            approx_eq(&s1.segment, &s2.segment, eps) && eq(&s1.radius, &s2.radius, eps)
+        }
+        (TypedShape::TaperedCapsule(s1), TypedShape::TaperedCapsule(s2)) => {
+            approx_eq(&s1.segment, &s2.segment, eps)
+                && eq(&s1.radius_a, &s2.radius_a, eps)
+                && eq(&s1.radius_b, &s2.radius_b, eps)
+        }
+        (TypedShape::Segment(s1), TypedShape::Segment(s2)) => {
+            eq(&s1.a, &s2.a, eps) && eq(&s1.b, &s2.b, eps)
+        }
+        (TypedShape::Triangle(s1), TypedShape::Triangle(s2)) => {
+            eq(&s1.a, &s2.a, eps) && eq(&s1.b, &s2.b, eps) && eq(&s1.c, &s2.c, eps)
+        }
+        (TypedShape::TriMesh(s1), TypedShape::TriMesh(s2)) => trimesh_eq(s1, s2, eps),
+        (TypedShape::Polyline(s1), TypedShape::Polyline(s2)) => polyline_eq(s1, s2, eps),
+        (TypedShape::HalfSpace(s1), TypedShape::HalfSpace(s2)) => eq(&s1.normal, &s2.normal, eps),
+        (TypedShape::Slab(s1), TypedShape::Slab(s2)) => {
+            eq(&s1.normal, &s2.normal, eps) && eq(&s1.half_thickness, &s2.half_thickness, eps)
+        }
+        (TypedShape::HeightField(s1), TypedShape::HeightField(s2)) => heightfield_eq(s1, s2, eps),
+        (TypedShape::Compound(s1), TypedShape::Compound(s2)) => {
+            s1.shapes().len() == s2.shapes().len()
+                && s1.shapes().iter().zip(s2.shapes()).all(|(part1, part2)| {
+                    isometry_eq(&part1.0, &part2.0, eps) && approx_eq(&*part1.1, &*part2.1, eps)
+                })
+        }
+        #[cfg(feature = "dim2")]
+        (TypedShape::ConvexPolygon(s1), TypedShape::ConvexPolygon(s2)) => {
+            all_eq(s1.points(), s2.points(), eps)
+        }
+        #[cfg(feature = "dim2")]
+        (TypedShape::Annulus(s1), TypedShape::Annulus(s2)) => {
+            eq(&s1.inner_radius, &s2.inner_radius, eps)
+                && eq(&s1.outer_radius, &s2.outer_radius, eps)
+        }
+        #[cfg(feature = "dim2")]
+        (TypedShape::Sector(s1), TypedShape::Sector(s2)) => {
+            eq(&s1.radius, &s2.radius, eps)
+                && eq(&s1.start_angle, &s2.start_angle, eps)
+                && eq(&s1.end_angle, &s2.end_angle, eps)
+        }
+        #[cfg(feature = "dim2")]
+        (TypedShape::Polygon(s1), TypedShape::Polygon(s2)) => {
+            // The triangulation only depends on the boundary.
+            polyline_eq(s1.boundary(), s2.boundary(), eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::ConvexPolyhedron(s1), TypedShape::ConvexPolyhedron(s2)) => {
+            all_eq(s1.points(), s2.points(), eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::Cylinder(s1), TypedShape::Cylinder(s2)) => {
+            eq(&s1.half_height, &s2.half_height, eps) && eq(&s1.radius, &s2.radius, eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::Cone(s1), TypedShape::Cone(s2)) => {
+            eq(&s1.half_height, &s2.half_height, eps) && eq(&s1.radius, &s2.radius, eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::Torus(s1), TypedShape::Torus(s2)) => {
+            eq(&s1.major_radius, &s2.major_radius, eps)
+                && eq(&s1.minor_radius, &s2.minor_radius, eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::Ellipsoid(s1), TypedShape::Ellipsoid(s2)) => eq(&s1.radii, &s2.radii, eps),
+        #[cfg(feature = "dim3")]
+        (TypedShape::EllipticCylinder(s1), TypedShape::EllipticCylinder(s2)) => {
+            eq(&s1.half_height, &s2.half_height, eps) && eq(&s1.radii, &s2.radii, eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::Plate(s1), TypedShape::Plate(s2)) => {
+            eq(&s1.half_extents, &s2.half_extents, eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::Prism(s1), TypedShape::Prism(s2)) => {
+            eq(&s1.half_height(), &s2.half_height(), eps)
+                && all_eq(s1.cross_section(), s2.cross_section(), eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::SolidOfRevolution(s1), TypedShape::SolidOfRevolution(s2)) => {
+            all_eq(s1.profile(), s2.profile(), eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::SphericalSector(s1), TypedShape::SphericalSector(s2)) => {
+            eq(&s1.radius, &s2.radius, eps) && eq(&s1.half_angle, &s2.half_angle, eps)
+        }
+        (TypedShape::Voxels(s1), TypedShape::Voxels(s2)) => {
+            eq(s1.voxel_size(), s2.voxel_size(), eps) && s1.keys() == s2.keys()
+        }
+        (TypedShape::RoundCuboid(s1), TypedShape::RoundCuboid(s2)) => {
+            eq(&s1.border_radius, &s2.border_radius, eps)
+                && approx_eq(&s1.base_shape, &s2.base_shape, eps)
+        }
+        (TypedShape::RoundTriangle(s1), TypedShape::RoundTriangle(s2)) => {
+            eq(&s1.border_radius, &s2.border_radius, eps)
+                && approx_eq(&s1.base_shape, &s2.base_shape, eps)
+        }
+        (TypedShape::RoundTriMesh(s1), TypedShape::RoundTriMesh(s2)) => {
+            eq(&s1.border_radius(), &s2.border_radius(), eps)
+                && trimesh_eq(s1.trimesh(), s2.trimesh(), eps)
+        }
+        (TypedShape::RoundPolyline(s1), TypedShape::RoundPolyline(s2)) => {
+            eq(&s1.border_radius(), &s2.border_radius(), eps)
+                && polyline_eq(s1.polyline(), s2.polyline(), eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::RoundCylinder(s1), TypedShape::RoundCylinder(s2)) => {
+            eq(&s1.border_radius, &s2.border_radius, eps)
+                && approx_eq(&s1.base_shape, &s2.base_shape, eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::RoundCone(s1), TypedShape::RoundCone(s2)) => {
+            eq(&s1.border_radius, &s2.border_radius, eps)
+                && approx_eq(&s1.base_shape, &s2.base_shape, eps)
+        }
+        #[cfg(feature = "dim3")]
+        (TypedShape::RoundConvexPolyhedron(s1), TypedShape::RoundConvexPolyhedron(s2)) => {
+            eq(&s1.border_radius, &s2.border_radius, eps)
+                && approx_eq(&s1.base_shape, &s2.base_shape, eps)
+        }
+        #[cfg(feature = "dim2")]
+        (TypedShape::RoundConvexPolygon(s1), TypedShape::RoundConvexPolygon(s2)) => {
+            eq(&s1.border_radius, &s2.border_radius, eps)
+                && approx_eq(&s1.base_shape, &s2.base_shape, eps)
+        }
+        (TypedShape::RoundSharedShape(s1), TypedShape::RoundSharedShape(s2)) => {
+            eq(&s1.border_radius, &s2.border_radius, eps)
+                && approx_eq(&*s1.base_shape, &*s2.base_shape, eps)
+        }
+        _ => std::ptr::eq(
+            shape1 as *const dyn Shape as *const (),
+            shape2 as *const dyn Shape as *const (),
+        ),
+    }
+}
+
+fn eq<T: AbsDiffEq<Epsilon = Real>>(a: &T, b: &T, eps: Real) -> bool {
+    a.abs_diff_eq(b, eps)
+}
+
+fn all_eq<T: AbsDiffEq<Epsilon = Real>>(a: &[T], b: &[T], eps: Real) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.abs_diff_eq(b, eps))
+}
+
+/// Compares the translations and the rotation matrices of both isometries.
+///
+/// This doesn't compare the rotations componentwise since two opposite quaternions represent
+/// the same rotation.
+fn isometry_eq(pos1: &Isometry<Real>, pos2: &Isometry<Real>, eps: Real) -> bool {
+    eq(&pos1.translation.vector, &pos2.translation.vector, eps)
+        && eq(
+            &pos1.rotation.to_rotation_matrix(),
+            &pos2.rotation.to_rotation_matrix(),
+            eps,
+        )
+}
+
+fn trimesh_eq(mesh1: &TriMesh, mesh2: &TriMesh, eps: Real) -> bool {
+    mesh1.indices() == mesh2.indices() && all_eq(mesh1.vertices(), mesh2.vertices(), eps)
+}
+
+fn polyline_eq(polyline1: &Polyline, polyline2: &Polyline, eps: Real) -> bool {
+    polyline1.indices() == polyline2.indices()
+        && all_eq(polyline1.vertices(), polyline2.vertices(), eps)
+}
+
+#[cfg(feature = "dim2")]
+fn heightfield_eq(hf1: &HeightField, hf2: &HeightField, eps: Real) -> bool {
+    let heights_eq = hf1.heights().len() == hf2.heights().len()
+        && all_eq(hf1.heights().as_slice(), hf2.heights().as_slice(), eps);
+
+    heights_eq
+        && eq(hf1.scale(), hf2.scale(), eps)
+        && (0..hf1.num_cells()).all(|i| hf1.is_segment_removed(i) == hf2.is_segment_removed(i))
+        && coordinates_eq(hf1.x_coordinates(), hf2.x_coordinates(), eps)
+}
+
+#[cfg(feature = "dim3")]
+fn heightfield_eq(hf1: &HeightField, hf2: &HeightField, eps: Real) -> bool {
+    let heights_eq = hf1.heights().shape() == hf2.heights().shape()
+        && all_eq(hf1.heights().as_slice(), hf2.heights().as_slice(), eps);
+
+    heights_eq
+        && eq(hf1.scale(), hf2.scale(), eps)
+        && hf1.cells_statuses() == hf2.cells_statuses()
+        && coordinates_eq(hf1.x_coordinates(), hf2.x_coordinates(), eps)
+        && coordinates_eq(hf1.z_coordinates(), hf2.z_coordinates(), eps)
+}
+
+fn coordinates_eq(coords1: Option<&[Real]>, coords2: Option<&[Real]>, eps: Real) -> bool {
+    match (coords1, coords2) {
+        (Some(coords1), Some(coords2)) => all_eq(coords1, coords2, eps),
+        (None, None) => true,
+        _ => false,
+    }
+}
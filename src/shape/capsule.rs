@@ -53,6 +53,16 @@ impl Capsule {
         na::center(&self.segment.a, &self.segment.b)
     }
 
+    /// The centers of the two hemispherical caps of this capsule, i.e., its segment endpoints.
+    pub fn cap_centers(&self) -> (Point<Real>, Point<Real>) {
+        (self.segment.a, self.segment.b)
+    }
+
+    /// The segment of this capsule transformed by `pos`.
+    pub fn world_segment(&self, pos: &Isometry<Real>) -> Segment {
+        self.segment.transformed(pos)
+    }
+
     /// Creates a new capsule equal to `self` with all its endpoints transformed by `pos`.
     pub fn transform_by(&self, pos: &Isometry<Real>) -> Self {
         Self::new(pos * self.segment.a, pos * self.segment.b, self.radius)
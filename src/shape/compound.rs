@@ -19,6 +19,8 @@ pub struct Compound {
     quadtree: SimdQuadTree<u32>,
     aabbs: Vec<AABB>,
     aabb: AABB,
+    #[cfg_attr(feature = "serde", serde(default))]
+    parts_data: Option<Vec<u64>>,
 }
 
 impl Compound {
@@ -57,8 +59,66 @@ impl Compound {
             quadtree,
             aabbs,
             aabb,
+            parts_data: None,
         }
     }
+
+    /// Attaches one user-defined value to each part of this compound shape.
+    ///
+    /// The `i`-th element of `data` is associated to the `i`-th element of `self.shapes()`.
+    /// This can be used, e.g., to map the parts hit by a query back to the entities they
+    /// represent: the part index is given by the `subshape` index of the contact manifolds
+    /// involving this compound, or by `self.cast_local_ray_and_get_part`.
+    ///
+    /// Panics if `data` does not contain exactly one element per part.
+    pub fn with_parts_data(mut self, data: Vec<u64>) -> Self {
+        assert_eq!(
+            data.len(),
+            self.shapes.len(),
+            "There must be exactly one data per part."
+        );
+        self.parts_data = Some(data);
+        self
+    }
+
+    /// Copies the user-defined values attached to the parts of `other`, which has the same
+    /// number of parts as this compound shape.
+    pub(crate) fn with_attributes_of(mut self, other: &Compound) -> Self {
+        self.parts_data = other.parts_data.clone();
+        self
+    }
+
+    /// Builds a new compound shape, replacing each compound shape from `shapes` by its own parts.
+    ///
+    /// The parts of nested compound shapes are recursively moved into the resulting compound,
+    /// with their positions composed with the positions of their parents. This avoids traversing
+    /// one acceleration structure per nesting level when querying the result.
+    ///
+    /// Panics if the flattened list of shapes is empty, or if some of the provided shapes are
+    /// composite shapes other than compound shapes.
+    pub fn flatten(shapes: Vec<(Isometry<Real>, SharedShape)>) -> Compound {
+        let mut flattened = Vec::with_capacity(shapes.len());
+
+        for (pos, shape) in shapes {
+            push_flattened(pos, shape, &mut flattened);
+        }
+
+        Compound::new(flattened)
+    }
+}
+
+fn push_flattened(
+    pos: Isometry<Real>,
+    shape: SharedShape,
+    out: &mut Vec<(Isometry<Real>, SharedShape)>,
+) {
+    if let Some(compound) = shape.as_compound() {
+        for (part_pos, part) in compound.shapes() {
+            push_flattened(pos * part_pos, part.clone(), out);
+        }
+    } else {
+        out.push((pos, shape));
+    }
 }
 
 impl Compound {
@@ -68,6 +128,93 @@ impl Compound {
         &self.shapes[..]
     }
 
+    /// The user-defined values attached to the parts of this compound shape, if any.
+    pub fn parts_data(&self) -> Option<&[u64]> {
+        self.parts_data.as_deref()
+    }
+
+    /// The user-defined value attached to the `i`-th part of this compound shape, if any.
+    pub fn part_data(&self, i: u32) -> Option<u64> {
+        self.parts_data.as_ref()?.get(i as usize).copied()
+    }
+
+    /// Sets the user-defined value attached to the `i`-th part of this compound shape.
+    ///
+    /// If this compound had no user-defined values yet, the value `0` is attached to all
+    /// its other parts.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn set_part_data(&mut self, i: u32, data: u64) {
+        assert!(
+            (i as usize) < self.shapes.len(),
+            "Part index out of bounds."
+        );
+        let num_parts = self.shapes.len();
+        let parts_data = self.parts_data.get_or_insert_with(|| vec![0; num_parts]);
+        parts_data[i as usize] = data;
+    }
+
+    /// Adds a new part to this compound shape and returns its index.
+    ///
+    /// The acceleration structure of this compound is updated incrementally instead of being
+    /// rebuilt from scratch. If user-defined values are attached to the parts of this compound,
+    /// the value `0` is attached to the new part.
+    ///
+    /// Panics if `shape` is a composite shape (nested composite shapes are not allowed).
+    pub fn insert(&mut self, pos: Isometry<Real>, shape: SharedShape) -> usize {
+        assert!(
+            shape.as_composite_shape().is_none(),
+            "Nested composite shapes are not allowed."
+        );
+
+        let i = self.shapes.len();
+        let bv = shape.compute_aabb(&pos);
+        self.aabb.merge(&bv);
+        self.aabbs.push(bv);
+        self.shapes.push((pos, shape));
+        self.quadtree.insert(i as u32, bv);
+
+        if let Some(parts_data) = &mut self.parts_data {
+            parts_data.push(0);
+        }
+
+        i
+    }
+
+    /// Removes the `i`-th part of this compound shape and returns it.
+    ///
+    /// Like `Vec::swap_remove`, the last part of this compound, and its user-defined value if
+    /// any, are moved to the index `i`. The acceleration structure of this compound is updated
+    /// incrementally instead of being rebuilt from scratch.
+    ///
+    /// Panics if `i` is out of bounds, or if this is the last part of this compound (a compound
+    /// shape must contain at least one shape).
+    pub fn remove(&mut self, i: usize) -> (Isometry<Real>, SharedShape) {
+        assert!(
+            self.shapes.len() > 1,
+            "A compound shape must contain at least one shape."
+        );
+        let last = self.shapes.len() - 1;
+        let removed = self.shapes.swap_remove(i);
+        let _ = self.aabbs.swap_remove(i);
+
+        if let Some(parts_data) = &mut self.parts_data {
+            let _ = parts_data.swap_remove(i);
+        }
+
+        self.quadtree.remove(last as u32);
+        if i != last {
+            self.quadtree.insert(i as u32, self.aabbs[i]);
+        }
+
+        self.aabb = AABB::new_invalid();
+        for bv in &self.aabbs {
+            self.aabb.merge(bv);
+        }
+
+        removed
+    }
+
     /// The AABB of this compound in its local-space.
     #[inline]
     pub fn local_aabb(&self) -> &AABB {
@@ -85,6 +232,35 @@ impl Compound {
     pub fn quadtree(&self) -> &SimdQuadTree<u32> {
         &self.quadtree
     }
+
+    /// Iterates through the sub-shapes with a world-space AABB intersecting `query`.
+    ///
+    /// Here, `position` is the position of `self`, and `query` is expressed in world-space.
+    /// Each item yielded is the index of the sub-shape, its position relative to `self`, and
+    /// the sub-shape itself. This uses the acceleration structure of this compound shape so
+    /// the sub-shapes far from `query` are not visited at all.
+    pub fn shapes_in_aabb<'a>(
+        &'a self,
+        position: &Isometry<Real>,
+        query: &AABB,
+    ) -> impl Iterator<Item = (usize, &'a Isometry<Real>, &'a dyn Shape)> {
+        let local_query = query.transform_by(&position.inverse());
+        let mut candidates = Vec::new();
+        self.quadtree.intersect_aabb(&local_query, &mut candidates);
+
+        let query = *query;
+        let position = *position;
+        candidates.into_iter().filter_map(move |i| {
+            let i = i as usize;
+
+            if self.aabbs[i].transform_by(&position).intersects(&query) {
+                let (part_pos, part) = &self.shapes[i];
+                Some((i, part_pos, &**part))
+            } else {
+                None
+            }
+        })
+    }
 }
 
 impl SimdCompositeShape for Compound {
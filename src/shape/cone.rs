@@ -1,8 +1,8 @@
 //! Support mapping based Cone shape.
 
 use crate::math::{Point, Real, Vector};
-use crate::shape::SupportMap;
-use na;
+use crate::shape::{FeatureId, SupportMap};
+use na::{self, ComplexField, RealField, Unit};
 use num::Zero;
 
 /// Cone shape with its principal axis aligned with the `y` axis.
@@ -27,6 +27,34 @@ impl Cone {
             radius,
         }
     }
+
+    /// Get the ID of the feature with a normal that maximizes the dot product with `local_dir`.
+    ///
+    /// The base of the cone, with the outward normal `-y`, is identified by `FeatureId::Face(0)`
+    /// and its lateral surface by `FeatureId::Face(1)`. The circle bounding the base is identified
+    /// by `FeatureId::Edge(0)` and the apex by `FeatureId::Vertex(0)`.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<Real>>) -> FeatureId {
+        let eps: Real = Real::pi() / 180.0;
+        let ceps = ComplexField::cos(eps);
+
+        if -local_dir.y >= ceps {
+            return FeatureId::Face(0);
+        }
+
+        // The normal of the lateral surface in the plane containing `local_dir` and the `y` axis.
+        let slant = (self.half_height * 2.0).hypot(self.radius);
+        let normal_xz = self.half_height * 2.0 / slant;
+        let normal_y = self.radius / slant;
+        let dir_xz = local_dir.x.hypot(local_dir.z);
+
+        if dir_xz * normal_xz + local_dir.y * normal_y >= ceps {
+            FeatureId::Face(1)
+        } else if local_dir.y > normal_y {
+            FeatureId::Vertex(0)
+        } else {
+            FeatureId::Edge(0)
+        }
+    }
 }
 
 impl SupportMap for Cone {
@@ -1,5 +1,5 @@
 use crate::math::{Point, Real, Vector};
-use crate::shape::{FeatureId, PolygonalFeature, PolygonalFeatureMap, SupportMap};
+use crate::shape::{Degeneracy, FeatureId, PolygonalFeature, PolygonalFeatureMap, SupportMap};
 use crate::utils;
 use na::{self, ComplexField, RealField, Unit};
 
@@ -67,6 +67,104 @@ impl ConvexPolygon {
         }
     }
 
+    /// Creates a new 2D convex polygon from a set of points describing a counter-clockwise convex polyline.
+    ///
+    /// Collinear consecutive points are removed. Unlike `Self::from_convex_polyline`, this checks the
+    /// input: returns `None` if the polyline is degenerate, not counter-clockwise, or not convex.
+    pub fn try_from_convex_polyline(points: Vec<Point<Real>>) -> Option<Self> {
+        let eps = ComplexField::sqrt(crate::math::DEFAULT_EPSILON);
+        let polygon = Self::from_convex_polyline(points)?;
+
+        if polygon.points.len() >= 3
+            && signed_area(&polygon.points) > 0.0
+            && polygon.is_convex_with_tolerance(eps)
+        {
+            Some(polygon)
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new 2D convex polygon from a set of points describing a counter-clockwise
+    /// convex polyline, after removing its degenerate vertices.
+    ///
+    /// A vertex is removed if it is within the distance `eps` of the previous vertex, or of the
+    /// line passing through its two adjacent vertices. Returns `None` if the vertices left
+    /// collapse to a point or a segment, see `Degeneracy`.
+    pub fn from_convex_polyline_with_tolerance(
+        mut points: Vec<Point<Real>>,
+        eps: Real,
+    ) -> Option<Self> {
+        let mut removed = true;
+
+        while removed && points.len() >= 3 {
+            removed = false;
+
+            for i in 0..points.len() {
+                let n = points.len();
+                let prev = points[(i + n - 1) % n];
+                let next = points[(i + 1) % n];
+                let prev_next = next - prev;
+                let prev_curr = points[i] - prev;
+                let prev_next_len = prev_next.norm();
+
+                if prev_curr.norm() <= eps
+                    || (prev_next_len > eps
+                        && prev_next.perp(&prev_curr).abs() <= eps * prev_next_len)
+                {
+                    let _ = points.remove(i);
+                    removed = true;
+                    break;
+                }
+            }
+        }
+
+        if points.len() < 3 || Degeneracy::of_points(&points, eps).is_some() {
+            return None;
+        }
+
+        Self::from_convex_polyline(points)
+    }
+
+    /// The point or segment this polygon collapses to if its vertices are all within the
+    /// distance `eps` of it.
+    ///
+    /// Returns `None` if this polygon isn't degenerate.
+    pub fn degeneracy(&self, eps: Real) -> Option<Degeneracy> {
+        Degeneracy::of_points(&self.points, eps)
+    }
+
+    /// Checks if this polygon is convex, up to the given distance tolerance.
+    ///
+    /// This is the case if no vertex of this polygon is further than `tolerance` in front of
+    /// the line supporting any of its edges.
+    pub fn is_convex_with_tolerance(&self, tolerance: Real) -> bool {
+        self.points
+            .iter()
+            .zip(self.normals.iter())
+            .all(|(a, n)| self.points.iter().all(|pt| n.dot(&(pt - a)) <= tolerance))
+    }
+
+    /// Fixes the winding of this polygon and removes its collinear consecutive vertices.
+    ///
+    /// If the vertices of this polygon are ordered clockwise, they are reversed and the normals
+    /// are recomputed so they point outward. Returns `false` if the resulting polygon is not
+    /// convex or is degenerate, i.e., if it has less than three vertices.
+    pub fn normalize(&mut self) -> bool {
+        let eps = ComplexField::sqrt(crate::math::DEFAULT_EPSILON);
+
+        if signed_area(&self.points) < 0.0 {
+            let mut points = self.points.clone();
+            points.reverse();
+
+            if let Some(normalized) = Self::from_convex_polyline(points) {
+                *self = normalized;
+            }
+        }
+
+        self.points.len() >= 3 && self.is_convex_with_tolerance(eps)
+    }
+
     /// The vertices of this convex polygon.
     #[inline]
     pub fn points(&self) -> &[Point<Real>] {
@@ -100,6 +198,19 @@ impl ConvexPolygon {
     }
 }
 
+// Twice the signed area of the polygon with the given vertices, positive if they are ordered
+// counter-clockwise.
+fn signed_area(points: &[Point<Real>]) -> Real {
+    let mut area = 0.0;
+
+    for (i, a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        area += a.x * b.y - a.y * b.x;
+    }
+
+    area
+}
+
 impl SupportMap for ConvexPolygon {
     #[inline]
     fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
@@ -1,10 +1,11 @@
-use crate::math::{Point, Real, Vector, DIM};
+use crate::math::{Isometry, Point, Real, Vector, DIM};
 use crate::shape::{FeatureId, PolygonalFeature, PolygonalFeatureMap, SupportMap};
 // use crate::transformation;
 use crate::utils::hashmap::{Entry, HashMap};
 use crate::utils::{self, SortedPair};
 use na::{self, ComplexField, Point2, Unit};
 use std::f64;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Copy, Clone)]
@@ -15,14 +16,27 @@ pub struct Vertex {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Copy, Clone)]
+/// An edge of a convex polyhedron.
 pub struct Edge {
+    /// The indices of the two endpoints of this edge.
     pub vertices: Point2<u32>,
+    /// The indices of the two faces adjacent to this edge.
     pub faces: Point2<u32>,
+    /// The unit direction of this edge, from its first to its second endpoint.
     pub dir: Unit<Vector<Real>>,
     deleted: bool,
 }
 
 impl Edge {
+    /// Whether this edge lies inside of a face of the polyhedron.
+    ///
+    /// This happens to the edges shared by coplanar triangles merged into a single face. Both
+    /// sides of such an edge are the same face.
+    #[inline]
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
     fn other_triangle(&self, id: u32) -> u32 {
         if id == self.faces[0] {
             self.faces[1]
@@ -62,6 +76,30 @@ impl Triangle {
     }
 }
 
+// The vertex from which the next hill-climbing search of a support point starts, i.e., the
+// last support vertex found, or `u32::MAX` if the support points are computed by scanning all
+// the vertices.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+struct SupportVertexCache(AtomicU32);
+
+impl SupportVertexCache {
+    const DISABLED: u32 = u32::MAX;
+}
+
+impl Clone for SupportVertexCache {
+    fn clone(&self) -> Self {
+        SupportVertexCache(AtomicU32::new(self.0.load(Ordering::Relaxed)))
+    }
+}
+
+impl PartialEq for SupportVertexCache {
+    // The cache doesn't affect the geometry of the polyhedron.
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Clone)]
 /// A convex polyhedron without degenerate faces.
@@ -78,6 +116,12 @@ pub struct ConvexPolyhedron {
     edges_adj_to_face: Vec<u32>,
     // Vertices adjascent to a face.
     vertices_adj_to_face: Vec<u32>,
+    // Vertices adjascent to a vertex, through an edge of the triangulation of the faces. The
+    // ones adjascent to the i-th vertex are in the range given by the i-th and (i+1)-th
+    // elements of `first_vertex_adj_to_vertex`.
+    vertices_adj_to_vertex: Vec<u32>,
+    first_vertex_adj_to_vertex: Vec<u32>,
+    support_cache: SupportVertexCache,
 }
 
 impl ConvexPolyhedron {
@@ -101,6 +145,17 @@ impl ConvexPolyhedron {
     pub fn from_convex_mesh(
         points: Vec<Point<Real>>,
         indices: &[[u32; DIM]],
+    ) -> Option<ConvexPolyhedron> {
+        Self::from_convex_mesh_with_face_tolerance(points, indices, None)
+    }
+
+    // If `face_angle_tolerance` is `None`, two adjacent triangles are part of the same face if
+    // their normals are almost equal. Otherwise, they are part of the same face if they belong
+    // to the same cluster computed by `cluster_triangles`.
+    fn from_convex_mesh_with_face_tolerance(
+        points: Vec<Point<Real>>,
+        indices: &[[u32; DIM]],
+        face_angle_tolerance: Option<Real>,
     ) -> Option<ConvexPolyhedron> {
         let eps = ComplexField::sqrt(crate::math::DEFAULT_EPSILON);
 
@@ -183,12 +238,41 @@ impl ConvexPolyhedron {
         }
 
         // Find edges that must be deleted.
+        if let Some(angle) = face_angle_tolerance {
+            let clusters = cluster_triangles(&points, &triangles, &edges, angle);
+            let num_clusters = clusters.iter().max().map(|c| *c as usize + 1).unwrap_or(0);
+
+            for e in &mut edges {
+                let cluster1 = clusters.get(e.faces[0] as usize)?;
+                let cluster2 = clusters.get(e.faces[1] as usize)?;
+                if cluster1 == cluster2 {
+                    e.deleted = true;
+                }
+            }
 
-        for e in &mut edges {
-            let tri1 = triangles.get(e.faces[0] as usize)?;
-            let tri2 = triangles.get(e.faces[1] as usize)?;
-            if tri1.normal.dot(&tri2.normal) > 1.0 - eps {
-                e.deleted = true;
+            // The normal of each cluster is the area-weighted average of its triangle normals.
+            let mut cluster_normals = vec![Vector::zeros(); num_clusters];
+            for (tri, cluster) in triangles.iter().zip(clusters.iter()) {
+                let [a, b, c] = tri.vertices;
+                let (pa, pb, pc) = (points[a as usize], points[b as usize], points[c as usize]);
+                cluster_normals[*cluster as usize] += (pb - pa).cross(&(pc - pa));
+            }
+
+            for (tri, cluster) in triangles.iter_mut().zip(clusters.iter()) {
+                if let Some(normal) =
+                    cluster_normals[*cluster as usize].try_normalize(crate::math::DEFAULT_EPSILON)
+                {
+                    tri.normal = normal;
+                    tri.is_degenerate = false;
+                }
+            }
+        } else {
+            for e in &mut edges {
+                let tri1 = triangles.get(e.faces[0] as usize)?;
+                let tri2 = triangles.get(e.faces[1] as usize)?;
+                if tri1.normal.dot(&tri2.normal) > 1.0 - eps {
+                    e.deleted = true;
+                }
             }
         }
 
@@ -329,6 +413,32 @@ impl ConvexPolyhedron {
         // Note numerical errors may throw off the Euler characteristic.
         // So we don't check it right now.
 
+        /*
+         * Vertex adjacency, including the edges deleted by the face merging.
+         */
+        let mut first_vertex_adj_to_vertex = vec![0; points.len() + 1];
+
+        for edge in &edges {
+            first_vertex_adj_to_vertex[edge.vertices[0] as usize + 1] += 1;
+            first_vertex_adj_to_vertex[edge.vertices[1] as usize + 1] += 1;
+        }
+
+        for i in 0..points.len() {
+            first_vertex_adj_to_vertex[i + 1] += first_vertex_adj_to_vertex[i];
+        }
+
+        let mut vertices_adj_to_vertex = vec![0; edges.len() * 2];
+        let mut num_adj = vec![0; points.len()];
+
+        for edge in &edges {
+            for k in 0..2 {
+                let (vid, other) = (edge.vertices[k] as usize, edge.vertices[1 - k]);
+                vertices_adj_to_vertex[(first_vertex_adj_to_vertex[vid] + num_adj[vid]) as usize] =
+                    other;
+                num_adj[vid] += 1;
+            }
+        }
+
         let res = ConvexPolyhedron {
             points,
             vertices,
@@ -338,6 +448,9 @@ impl ConvexPolyhedron {
             edges_adj_to_vertex,
             edges_adj_to_face,
             vertices_adj_to_face,
+            vertices_adj_to_vertex,
+            first_vertex_adj_to_vertex,
+            support_cache: SupportVertexCache(AtomicU32::new(SupportVertexCache::DISABLED)),
         };
 
         // FIXME: for debug.
@@ -359,6 +472,139 @@ impl ConvexPolyhedron {
         }
     }
 
+    /// Checks if the faces of this convex polyhedron form a closed surface.
+    ///
+    /// This is the case if each face has at least three vertices, and if each edge that is not
+    /// in the interior of a face is shared by exactly two faces.
+    pub fn is_closed(&self) -> bool {
+        let mut num_adj_faces = vec![0u32; self.edges.len()];
+
+        for face in &self.faces {
+            if face.num_vertices_or_edges < 3 {
+                return false;
+            }
+
+            let first = face.first_vertex_or_edge as usize;
+            let last = first + face.num_vertices_or_edges as usize;
+
+            for edge_id in &self.edges_adj_to_face[first..last] {
+                num_adj_faces[*edge_id as usize] += 1;
+            }
+        }
+
+        self.edges
+            .iter()
+            .zip(num_adj_faces.iter())
+            .all(|(edge, n)| if edge.deleted { *n == 0 } else { *n == 2 })
+    }
+
+    /// Checks if this convex polyhedron is convex, up to the given distance tolerance.
+    ///
+    /// This is the case if the vertices of each face are at a distance smaller than `tolerance`
+    /// from the plane of the face, and if no point of this polyhedron is further than `tolerance`
+    /// in front of the plane of any face.
+    pub fn is_convex_with_tolerance(&self, tolerance: Real) -> bool {
+        self.faces
+            .iter()
+            .zip(self.face_planes())
+            .all(|(face, (normal, offset))| {
+                let first = face.first_vertex_or_edge as usize;
+                let last = first + face.num_vertices_or_edges as usize;
+                let is_planar = self.vertices_adj_to_face[first..last].iter().all(|i| {
+                    (normal.dot(&self.points[*i as usize].coords) - offset).abs() <= tolerance
+                });
+
+                is_planar
+                    && self
+                        .points
+                        .iter()
+                        .all(|pt| normal.dot(&pt.coords) - offset <= tolerance)
+            })
+    }
+
+    /// Checks if this convex polyhedron is closed and convex, up to the given distance tolerance.
+    ///
+    /// See `self.is_closed` and `self.is_convex_with_tolerance` for details.
+    pub fn is_valid(&self, tolerance: Real) -> bool {
+        self.is_closed() && self.is_convex_with_tolerance(tolerance)
+    }
+
+    /// Builds a new convex polyhedron by merging the nearly-coplanar faces of `self`.
+    ///
+    /// The faces are grouped greedily, starting with the largest ones: a face is merged into
+    /// an adjacent group if its normal deviates by at most `angle_tolerance` radians from
+    /// the normal of the first face of this group. The normal of each resulting face is the
+    /// area-weighted average of the normals of the merged faces.
+    ///
+    /// This is useful to clean up the many sliver faces of convex hulls computed from noisy
+    /// points. Note that the resulting faces are only planar up to this angular tolerance.
+    /// Returns `None` if the resulting polyhedron is not manifold.
+    pub fn merge_coplanar_faces(&self, angle_tolerance: Real) -> Option<ConvexPolyhedron> {
+        // NOTE: we don't triangulate the faces of `self` because they may not be convex.
+        let (vertices, indices) = crate::transformation::convex_hull(&self.points);
+        let merged =
+            Self::from_convex_mesh_with_face_tolerance(vertices, &indices, Some(angle_tolerance))?;
+
+        if self.has_support_cache() {
+            Some(merged.with_support_cache())
+        } else {
+            Some(merged)
+        }
+    }
+
+    /// Enables the warm-started computation of the support points of this convex polyhedron.
+    ///
+    /// Instead of scanning all the vertices, each support point is then found by walking the
+    /// edges from the last support point found, toward adjacent vertices further along the
+    /// support direction. This is much faster for polyhedra with many vertices when the support
+    /// directions change little between successive calls, as during the iterations of GJK.
+    /// Vertices that are not adjacent to any face are ignored by this search.
+    pub fn with_support_cache(self) -> Self {
+        // Start from a vertex of the first face, since vertex 0 may not be adjacent to any face.
+        let start = self
+            .faces
+            .first()
+            .map(|face| self.vertices_adj_to_face[face.first_vertex_or_edge as usize])
+            .unwrap_or(0);
+        self.support_cache.0.store(start, Ordering::Relaxed);
+        self
+    }
+
+    /// Checks if the support points of this convex polyhedron are computed from the last
+    /// support point found, see `with_support_cache`.
+    pub fn has_support_cache(&self) -> bool {
+        self.support_cache.0.load(Ordering::Relaxed) != SupportVertexCache::DISABLED
+    }
+
+    /// The index of the support point of this convex polyhedron toward `dir`, found by walking
+    /// the edges from the vertex with index `start` toward adjacent vertices further along `dir`.
+    ///
+    /// Because the polyhedron is convex, this walk ends at a vertex maximizing the dot product
+    /// with `dir`, as long as `start` is adjacent to at least one face.
+    pub fn local_support_point_id_from(&self, dir: &Vector<Real>, start: u32) -> u32 {
+        let mut best = start;
+        let mut best_dot = self.points[best as usize].coords.dot(dir);
+
+        loop {
+            let curr = best as usize;
+            let adj = self.first_vertex_adj_to_vertex[curr] as usize
+                ..self.first_vertex_adj_to_vertex[curr + 1] as usize;
+
+            for &vid in &self.vertices_adj_to_vertex[adj] {
+                let dot = self.points[vid as usize].coords.dot(dir);
+
+                if dot > best_dot {
+                    best_dot = dot;
+                    best = vid;
+                }
+            }
+
+            if best as usize == curr {
+                return best;
+            }
+        }
+    }
+
     /// The set of vertices of this convex polyhedron.
     #[inline]
     pub fn points(&self) -> &[Point<Real>] {
@@ -383,6 +629,23 @@ impl ConvexPolyhedron {
         &self.faces[..]
     }
 
+    /// The plane of each face of this convex polyhedron.
+    ///
+    /// Each plane is given by the outward normal of the face and its signed distance from
+    /// the origin, i.e., the face lies on the plane `{ x | normal.dot(x) = offset }`. This
+    /// is the half-space representation of this convex polyhedron: it is the intersection
+    /// of all the half-spaces `{ x | normal.dot(x) <= offset }`.
+    pub fn face_planes(&self) -> Vec<(Unit<Vector<Real>>, Real)> {
+        self.faces
+            .iter()
+            .map(|face| {
+                let vid = self.vertices_adj_to_face[face.first_vertex_or_edge as usize];
+                let offset = face.normal.dot(&self.points[vid as usize].coords);
+                (face.normal, offset)
+            })
+            .collect()
+    }
+
     /// The array containing the indices of the vertices adjacent to each face.
     #[inline]
     pub fn vertices_adj_to_face(&self) -> &[u32] {
@@ -401,6 +664,91 @@ impl ConvexPolyhedron {
         &self.faces_adj_to_vertex[..]
     }
 
+    /// The array containing the indices of the edges adjacent to each vertex.
+    #[inline]
+    pub fn edges_adj_to_vertex(&self) -> &[u32] {
+        &self.edges_adj_to_vertex[..]
+    }
+
+    /// The outward normals of the two faces adjacent to the `edge_id`-th edge.
+    ///
+    /// On the Gauss map of this polyhedron, i.e., the unit sphere where each face is mapped to
+    /// its normal, this edge is the shortest arc of great circle between these two normals.
+    #[inline]
+    pub fn edge_face_normals(&self, edge_id: u32) -> [Unit<Vector<Real>>; 2] {
+        let edge = &self.edges[edge_id as usize];
+        [
+            self.faces[edge.faces[0] as usize].normal,
+            self.faces[edge.faces[1] as usize].normal,
+        ]
+    }
+
+    /// Checks if `dir` is in the normal cone of the `edge_id`-th edge, up to `angle_tolerance`.
+    ///
+    /// This is the case if `dir` lies on the arc of the Gauss map of this polyhedron between
+    /// the normals of the faces adjacent to this edge, i.e., if the plane orthogonal to `dir`
+    /// touching this polyhedron supports it along this edge. This is always `false` for edges
+    /// lying inside of a face.
+    pub fn edge_normal_cone_contains(
+        &self,
+        edge_id: u32,
+        dir: &Unit<Vector<Real>>,
+        angle_tolerance: Real,
+    ) -> bool {
+        let edge = &self.edges[edge_id as usize];
+
+        if edge.deleted {
+            return false;
+        }
+
+        let [n1, n2] = self.edge_face_normals(edge_id);
+        let eps = ComplexField::sin(angle_tolerance);
+
+        // `dir` must be orthogonal to the edge, and between the two normals on the
+        // great circle orthogonal to the edge.
+        let axis = n1.cross(&n2);
+        dir.dot(&edge.dir).abs() <= eps
+            && n1.cross(dir).dot(&axis) >= -eps
+            && dir.cross(&n2).dot(&axis) >= -eps
+    }
+
+    /// Checks if the `edge1`-th edge of `self` and the `edge2`-th edge of `other` build a face
+    /// of the Minkowski difference of `self` and `other`.
+    ///
+    /// Here, `pos12` is the position of `other` relative to `self`. This is the case if the
+    /// arcs of these edges intersect on the Gauss maps of `self` and of `-other`. Only the
+    /// pairs of edges passing this test need to be checked by the separating axis theorem,
+    /// since the cross products of the directions of the other pairs are not face normals
+    /// of the Minkowski difference. This is always `false` for edges lying inside of a face.
+    pub fn edges_build_minkowski_face(
+        &self,
+        edge1: u32,
+        pos12: &Isometry<Real>,
+        other: &ConvexPolyhedron,
+        edge2: u32,
+    ) -> bool {
+        if self.edges[edge1 as usize].deleted || other.edges[edge2 as usize].deleted {
+            return false;
+        }
+
+        let [a, b] = self.edge_face_normals(edge1);
+        let [c, d] = other.edge_face_normals(edge2);
+        let c = -(pos12 * *c);
+        let d = -(pos12 * *d);
+
+        // The arcs AB and CD intersect if A and B are on different sides of the plane
+        // containing CD, C and D are on different sides of the plane containing AB, and
+        // the arcs lie on the same hemisphere.
+        let bxa = b.cross(&a);
+        let dxc = d.cross(&c);
+        let cba = c.dot(&bxa);
+        let dba = d.dot(&bxa);
+        let adc = a.dot(&dxc);
+        let bdc = b.dot(&dxc);
+
+        cba * dba < 0.0 && adc * bdc < 0.0 && cba * bdc > 0.0
+    }
+
     fn support_feature_id_toward_eps(
         &self,
         local_dir: &Unit<Vector<Real>>,
@@ -441,10 +789,85 @@ impl ConvexPolyhedron {
     }
 }
 
+// Groups the triangles into clusters of adjacent triangles with normals deviating by at most
+// `angle` from the normal of the first triangle of their cluster. Clusters are grown from the
+// largest triangles first. Degenerate triangles join any adjacent cluster.
+fn cluster_triangles(
+    points: &[Point<Real>],
+    triangles: &[Triangle],
+    edges: &[Edge],
+    angle: Real,
+) -> Vec<u32> {
+    let cos_angle = ComplexField::cos(angle);
+    let areas: Vec<Real> = triangles
+        .iter()
+        .map(|tri| {
+            let [a, b, c] = tri.vertices;
+            let (pa, pb, pc) = (points[a as usize], points[b as usize], points[c as usize]);
+            (pb - pa).cross(&(pc - pa)).norm()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..triangles.len()).collect();
+    order.sort_by(|a, b| {
+        areas[*b]
+            .partial_cmp(&areas[*a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut clusters = vec![u32::MAX; triangles.len()];
+    let mut num_clusters = 0;
+    let mut stack = Vec::new();
+
+    for seed in order {
+        if clusters[seed] != u32::MAX || triangles[seed].is_degenerate {
+            continue;
+        }
+
+        let seed_normal = triangles[seed].normal;
+        clusters[seed] = num_clusters;
+        stack.push(seed);
+
+        while let Some(curr) = stack.pop() {
+            for edge_id in &triangles[curr].edges {
+                let adj = edges[*edge_id as usize].other_triangle(curr as u32) as usize;
+
+                if let Some(tri) = triangles.get(adj) {
+                    if clusters[adj] == u32::MAX
+                        && (tri.is_degenerate || tri.normal.dot(&seed_normal) >= cos_angle)
+                    {
+                        clusters[adj] = num_clusters;
+                        stack.push(adj);
+                    }
+                }
+            }
+        }
+
+        num_clusters += 1;
+    }
+
+    // Degenerate triangles without any non-degenerate neighbor get their own cluster.
+    for cluster in &mut clusters {
+        if *cluster == u32::MAX {
+            *cluster = num_clusters;
+            num_clusters += 1;
+        }
+    }
+
+    clusters
+}
+
 impl SupportMap for ConvexPolyhedron {
     #[inline]
     fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
-        utils::point_cloud_support_point(dir, self.points())
+        let start = self.support_cache.0.load(Ordering::Relaxed);
+
+        if start == SupportVertexCache::DISABLED {
+            utils::point_cloud_support_point(dir, self.points())
+        } else {
+            let id = self.local_support_point_id_from(dir, start);
+            self.support_cache.0.store(id, Ordering::Relaxed);
+            self.points[id as usize]
+        }
     }
 }
 
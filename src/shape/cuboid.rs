@@ -1,6 +1,6 @@
 //! Support mapping based Cuboid shape.
 
-use crate::math::{Point, Real, Vector};
+use crate::math::{Isometry, Point, Real, Vector};
 use crate::shape::{PolygonalFeature, SupportMap};
 use crate::utils::WSign;
 
@@ -20,6 +20,56 @@ impl Cuboid {
         Cuboid { half_extents }
     }
 
+    /// Splits this cuboid into 4 equal sub-cuboids, one for each quadrant.
+    ///
+    /// Each sub-cuboid is returned with its position relative to `self`. The `i`-th
+    /// sub-cuboid is located on the positive side of the `j`-th axis iff. the `j`-th
+    /// bit of `i` is set.
+    #[cfg(feature = "dim2")]
+    pub fn subdivide(&self) -> [(Isometry<Real>, Cuboid); 4] {
+        [
+            self.subdivision_part(0),
+            self.subdivision_part(1),
+            self.subdivision_part(2),
+            self.subdivision_part(3),
+        ]
+    }
+
+    /// Splits this cuboid into 8 equal sub-cuboids, one for each octant.
+    ///
+    /// Each sub-cuboid is returned with its position relative to `self`. The `i`-th
+    /// sub-cuboid is located on the positive side of the `j`-th axis iff. the `j`-th
+    /// bit of `i` is set.
+    #[cfg(feature = "dim3")]
+    pub fn subdivide(&self) -> [(Isometry<Real>, Cuboid); 8] {
+        [
+            self.subdivision_part(0),
+            self.subdivision_part(1),
+            self.subdivision_part(2),
+            self.subdivision_part(3),
+            self.subdivision_part(4),
+            self.subdivision_part(5),
+            self.subdivision_part(6),
+            self.subdivision_part(7),
+        ]
+    }
+
+    fn subdivision_part(&self, i: usize) -> (Isometry<Real>, Cuboid) {
+        let half_extents = self.half_extents / 2.0;
+        let mut shift = half_extents;
+
+        for k in 0..shift.len() {
+            if i & (1 << k) == 0 {
+                shift[k] = -shift[k];
+            }
+        }
+
+        (
+            Isometry::from_parts(shift.into(), na::one()),
+            Cuboid::new(half_extents),
+        )
+    }
+
     /// Return the id of the vertex of this cuboid with a normal that maximizes
     /// the dot product with `dir`.
     #[cfg(feature = "dim2")]
@@ -1,8 +1,8 @@
 //! Support mapping based Cylinder shape.
 
 use crate::math::{Point, Real, Vector};
-use crate::shape::SupportMap;
-use na;
+use crate::shape::{FeatureId, SupportMap};
+use na::{self, ComplexField, RealField, Unit};
 use num::Zero;
 
 /// Cylinder shape with its principal axis aligned with the `y` axis.
@@ -29,6 +29,31 @@ impl Cylinder {
             radius,
         }
     }
+
+    /// Get the ID of the feature with a normal that maximizes the dot product with `local_dir`.
+    ///
+    /// The caps with the outward normals `+y` and `-y` are identified by `FeatureId::Face(0)`
+    /// and `FeatureId::Face(1)`, and the lateral surface by `FeatureId::Face(2)`. The circles
+    /// bounding the caps with the normals `+y` and `-y` are identified by `FeatureId::Edge(0)` and
+    /// `FeatureId::Edge(1)`.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<Real>>) -> FeatureId {
+        support_feature_id_toward_on_cylinder(local_dir)
+    }
+}
+
+/// The feature of a cylinder, with a circular or elliptical cross-section, with a normal that
+/// maximizes the dot product with `local_dir`.
+pub(crate) fn support_feature_id_toward_on_cylinder(local_dir: &Unit<Vector<Real>>) -> FeatureId {
+    let eps: Real = Real::pi() / 180.0;
+    let (seps, ceps) = ComplexField::sin_cos(eps);
+
+    if local_dir.y.abs() >= ceps {
+        FeatureId::Face(if local_dir.y > 0.0 { 0 } else { 1 })
+    } else if local_dir.y.abs() <= seps {
+        FeatureId::Face(2)
+    } else {
+        FeatureId::Edge(if local_dir.y > 0.0 { 0 } else { 1 })
+    }
 }
 
 impl SupportMap for Cylinder {
@@ -0,0 +1,65 @@
+//! Detection of degenerate shapes.
+
+use crate::math::{Point, Real};
+use crate::shape::Segment;
+
+/// The lower-dimensional shape a degenerate shape collapses to.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Degeneracy {
+    /// All the vertices of the shape are within the tolerance of this point.
+    Point(Point<Real>),
+    /// All the vertices of the shape are within the tolerance of this segment, e.g., they are
+    /// collinear.
+    Segment(Segment),
+}
+
+impl Degeneracy {
+    /// The point or segment the given points collapse to, up to the distance tolerance `eps`.
+    ///
+    /// Returns `None` if `points` is empty or if its points are not all within `eps` of a
+    /// single segment.
+    pub(crate) fn of_points(points: &[Point<Real>], eps: Real) -> Option<Degeneracy> {
+        let farthest_from = |pt: &Point<Real>| {
+            points
+                .iter()
+                .copied()
+                .max_by(|p1, p2| {
+                    na::distance_squared(pt, p1)
+                        .partial_cmp(&na::distance_squared(pt, p2))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(*pt)
+        };
+
+        // Two points approximately realizing the diameter of the point set.
+        let a = farthest_from(points.first()?);
+        let b = farthest_from(&a);
+        let ab = b - a;
+        let length = ab.norm();
+
+        if length <= eps {
+            return Some(Degeneracy::Point(na::center(&a, &b)));
+        }
+
+        let dir = ab / length;
+        let mut min = 0.0;
+        let mut max = length;
+
+        for pt in points {
+            let ap = pt - a;
+            let t = ap.dot(&dir);
+
+            if (ap - dir * t).norm() > eps {
+                return None;
+            }
+
+            min = t.min(min);
+            max = t.max(max);
+        }
+
+        Some(Degeneracy::Segment(Segment::new(
+            a + dir * min,
+            a + dir * max,
+        )))
+    }
+}
@@ -0,0 +1,39 @@
+//! Support mapping based Ellipsoid shape.
+
+use crate::math::{Point, Real, Vector};
+use crate::shape::SupportMap;
+
+/// An ellipsoid centered at the origin, with its principal axes aligned with the coordinate axes.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Ellipsoid {
+    /// The radii of the ellipsoid along each coordinate axis.
+    pub radii: Vector<Real>,
+}
+
+impl Ellipsoid {
+    /// Creates a new ellipsoid from its radii along each coordinate axis.
+    pub fn new(radii: Vector<Real>) -> Ellipsoid {
+        assert!(
+            radii.iter().all(|r| *r > 0.0),
+            "The radii of an ellipsoid must be positive."
+        );
+
+        Ellipsoid { radii }
+    }
+}
+
+impl SupportMap for Ellipsoid {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        // The support point of the unit ball along `radii * dir`, scaled by `radii`.
+        let scaled_dir = self.radii.component_mul(dir);
+        let norm = scaled_dir.norm();
+
+        if norm > 0.0 {
+            Point::from(self.radii.component_mul(&scaled_dir) / norm)
+        } else {
+            Point::origin()
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Support mapping based EllipticCylinder shape.
+
+use crate::math::{Point, Real, Vector};
+use crate::shape::{FeatureId, SupportMap};
+use na::{Unit, Vector2};
+
+/// Cylinder shape with an elliptical cross-section and its principal axis aligned with the `y` axis.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct EllipticCylinder {
+    /// The half-height of the cylinder.
+    pub half_height: Real,
+    /// The radii of the cross-section of the cylinder along the `x` and `z` axes.
+    pub radii: Vector2<Real>,
+}
+
+impl EllipticCylinder {
+    /// Creates a new elliptic cylinder.
+    ///
+    /// # Arguments:
+    /// * `half_height` - the half length of the cylinder along the `y` axis.
+    /// * `radii` - the radii of the elliptical cross-section along the `x` and `z` axes.
+    pub fn new(half_height: Real, radii: Vector2<Real>) -> EllipticCylinder {
+        assert!(
+            half_height > 0.0 && radii.x > 0.0 && radii.y > 0.0,
+            "The half-height and radii of an elliptic cylinder must be positive."
+        );
+
+        EllipticCylinder { half_height, radii }
+    }
+
+    /// Get the ID of the feature with a normal that maximizes the dot product with `local_dir`.
+    ///
+    /// The features are identified the same way as the features of a `Cylinder`.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<Real>>) -> FeatureId {
+        crate::shape::cylinder::support_feature_id_toward_on_cylinder(local_dir)
+    }
+}
+
+impl SupportMap for EllipticCylinder {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        // The support point of the unit disk along `radii * dir`, scaled by `radii`.
+        let scaled_dir = Vector2::new(self.radii.x * dir.x, self.radii.y * dir.z);
+        let norm = scaled_dir.norm();
+        let y = self.half_height.copysign(dir.y);
+
+        if norm > 0.0 {
+            let xz = self.radii.component_mul(&scaled_dir) / norm;
+            Point::new(xz.x, y, xz.y)
+        } else {
+            Point::new(0.0, y, 0.0)
+        }
+    }
+}
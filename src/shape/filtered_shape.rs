@@ -0,0 +1,72 @@
+use crate::math::{Isometry, Real};
+use crate::partitioning::SimdQuadTree;
+use crate::shape::{Shape, TypedSimdCompositeShape};
+
+/// A composite shape or heightfield with some of its parts ignored by geometric queries.
+///
+/// The parts for which `filter` returns `false` are skipped by the ray-casts and point
+/// projections on this shape. This allows ignoring some triangles of a `TriMesh` or
+/// `HeightField`, or some parts of a `Compound`, without modifying the shape itself. The
+/// filter is given the index of each part: the triangle index for a triangle mesh or a
+/// heightfield, the segment index for a 2D heightfield, and the part index for a compound.
+///
+/// Because this implements `TypedSimdCompositeShape` when the filtered shape is a composite
+/// shape, it can also be used with the composite-shape queries of the `query::details` module,
+/// e.g., `time_of_impact_composite_shape_shape` for shape-casting.
+pub struct FilteredShape<'a, S: ?Sized, F> {
+    shape: &'a S,
+    filter: F,
+}
+
+impl<'a, S: ?Sized, F> FilteredShape<'a, S, F> {
+    /// Creates a filtered view of `shape` where only the parts for which `filter` returns `true`
+    /// are taken into account.
+    pub fn new(shape: &'a S, filter: F) -> Self {
+        Self { shape, filter }
+    }
+
+    /// The shape being filtered.
+    pub fn shape(&self) -> &'a S {
+        self.shape
+    }
+
+    /// The filter deciding which parts of the shape are taken into account.
+    pub fn filter(&self) -> &F {
+        &self.filter
+    }
+}
+
+impl<'a, S, F> TypedSimdCompositeShape for FilteredShape<'a, S, F>
+where
+    S: ?Sized + TypedSimdCompositeShape,
+    F: Fn(S::PartId) -> bool,
+{
+    type PartShape = S::PartShape;
+    type PartId = S::PartId;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        shape_id: Self::PartId,
+        f: impl FnMut(Option<&Isometry<Real>>, &Self::PartShape),
+    ) {
+        if (self.filter)(shape_id) {
+            self.shape.map_typed_part_at(shape_id, f)
+        }
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(
+        &self,
+        shape_id: Self::PartId,
+        f: impl FnMut(Option<&Isometry<Real>>, &dyn Shape),
+    ) {
+        if (self.filter)(shape_id) {
+            self.shape.map_untyped_part_at(shape_id, f)
+        }
+    }
+
+    fn typed_quadtree(&self) -> &SimdQuadTree<Self::PartId> {
+        self.shape.typed_quadtree()
+    }
+}
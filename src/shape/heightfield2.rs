@@ -1,10 +1,11 @@
-use na::{DVector, Point2};
+use na::{DVector, Point2, Unit};
+use std::cmp::Ordering;
 use std::iter;
 
 use crate::bounding_volume::AABB;
 use crate::math::{Real, Vector};
 
-use crate::shape::Segment;
+use crate::shape::{FeatureId, Segment, SupportMap};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
@@ -14,6 +15,11 @@ pub struct HeightField {
     scale: Vector<Real>,
     removed: Vec<bool>,
     aabb: AABB,
+    // The unscaled coordinates of the cell endpoints, or empty if they are uniformly spaced.
+    #[cfg_attr(feature = "serde", serde(default))]
+    xs: Vec<Real>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) ccd_thickness: Real,
 }
 
 impl HeightField {
@@ -37,9 +43,51 @@ impl HeightField {
             scale,
             aabb,
             removed: Vec::new(),
+            xs: Vec::new(),
+            ccd_thickness: 0.0,
         }
     }
 
+    /// Creates a new 2D heightfield with non-uniformly spaced cell endpoints.
+    ///
+    /// The i-th height is located at the x coordinate `xs[i]`. These coordinates must be
+    /// strictly increasing. They are multiplied by `scale`, just like the heights.
+    pub fn with_coordinates(heights: DVector<Real>, xs: Vec<Real>, scale: Vector<Real>) -> Self {
+        assert_eq!(
+            xs.len(),
+            heights.len(),
+            "A heightfield needs one x coordinate per height."
+        );
+        assert!(
+            xs.windows(2).all(|w| w[0] < w[1]),
+            "The coordinates of a heightfield cell endpoints must be strictly increasing."
+        );
+
+        let mut result = Self::new(heights, scale);
+        result.aabb.mins.x = xs[0] * scale.x;
+        result.aabb.maxs.x = xs[xs.len() - 1] * scale.x;
+        result.xs = xs;
+        result
+    }
+
+    /// Sets the thickness of this heightfield used by continuous collision detection.
+    ///
+    /// A heightfield has no interior so its thickness is zero by default, which forces
+    /// continuous collision detection to substep any motion toward it. A positive thickness
+    /// can be set to model the ground below its surface. It is returned by
+    /// `Shape::ccd_thickness`.
+    pub fn with_ccd_thickness(mut self, thickness: Real) -> Self {
+        self.ccd_thickness = thickness;
+        self
+    }
+
+    /// Sets the thickness of this heightfield used by continuous collision detection.
+    ///
+    /// See `self.with_ccd_thickness` for details.
+    pub fn set_ccd_thickness(&mut self, thickness: Real) {
+        self.ccd_thickness = thickness;
+    }
+
     /// The number of cells of this heightfield.
     pub fn num_cells(&self) -> usize {
         self.heights.len() - 1
@@ -50,6 +98,46 @@ impl HeightField {
         &self.heights
     }
 
+    /// Sets the height of the i-th endpoint of the cells of this heightfield.
+    ///
+    /// The AABB of this heightfield is refitted incrementally: it is only recomputed from
+    /// scratch if the modified height was the lowest or highest height of this heightfield.
+    pub fn set_height(&mut self, i: usize, height: Real) {
+        let old_height = self.heights[i];
+        self.heights[i] = height;
+        self.refit_aabb(old_height, old_height, height, height);
+    }
+
+    /// Replaces the heights of the consecutive cell endpoints starting at the i-th one by `heights`.
+    ///
+    /// This is more efficient than calling `self.set_height` for each endpoint since the AABB
+    /// of this heightfield is refitted only once.
+    pub fn set_heights_in_range(&mut self, i: usize, heights: &DVector<Real>) {
+        if heights.is_empty() {
+            return;
+        }
+
+        let mut range = self.heights.rows_mut(i, heights.len());
+        let (old_min, old_max) = (range.min(), range.max());
+        range.copy_from(heights);
+        self.refit_aabb(old_min, old_max, heights.min(), heights.max());
+    }
+
+    // Updates the AABB after heights in the range `[old_min, old_max]` were
+    // replaced by heights in the range `[new_min, new_max]`.
+    fn refit_aabb(&mut self, old_min: Real, old_max: Real, new_min: Real, new_max: Real) {
+        let min_was_removed = new_min > old_min && old_min * self.scale.y <= self.aabb.mins.y;
+        let max_was_removed = new_max < old_max && old_max * self.scale.y >= self.aabb.maxs.y;
+
+        if min_was_removed || max_was_removed {
+            self.aabb.mins.y = self.heights.min() * self.scale.y;
+            self.aabb.maxs.y = self.heights.max() * self.scale.y;
+        } else {
+            self.aabb.mins.y = self.aabb.mins.y.min(new_min * self.scale.y);
+            self.aabb.maxs.y = self.aabb.maxs.y.max(new_max * self.scale.y);
+        }
+    }
+
     /// The scale factor applied to this heightfield.
     pub fn scale(&self) -> &Vector<Real> {
         &self.scale
@@ -61,21 +149,65 @@ impl HeightField {
     }
 
     /// The width of a single cell of this heightfield.
+    ///
+    /// If the cells of this heightfield are not uniformly spaced, this is their average width.
     pub fn cell_width(&self) -> Real {
         self.unit_cell_width() * self.scale.x
     }
 
     /// The width of a single cell of this heightfield, without taking the scale factor into account.
+    ///
+    /// If the cells of this heightfield are not uniformly spaced, this is their average width.
     pub fn unit_cell_width(&self) -> Real {
-        1.0 / na::convert::<f64, Real>(self.heights.len() as f64 - 1.0)
+        let extent = match self.xs.last() {
+            Some(last) => *last - self.xs[0],
+            None => 1.0,
+        };
+        extent / na::convert::<f64, Real>(self.heights.len() as f64 - 1.0)
     }
 
     /// The left-most x-coordinate of this heightfield.
     pub fn start_x(&self) -> Real {
-        self.scale.x * na::convert::<f64, Real>(-0.5)
+        self.x_at(0)
+    }
+
+    /// The x coordinate of the i-th cell endpoint of this heightfield.
+    pub fn x_at(&self, i: usize) -> Real {
+        self.unit_x_at(i) * self.scale.x
+    }
+
+    /// The x coordinates of the cell endpoints of this heightfield, excluding the scale factor.
+    ///
+    /// Returns `None` if the cells of this heightfield are uniformly spaced.
+    pub fn x_coordinates(&self) -> Option<&[Real]> {
+        if self.xs.is_empty() {
+            None
+        } else {
+            Some(&self.xs)
+        }
+    }
+
+    fn unit_x_at(&self, i: usize) -> Real {
+        if self.xs.is_empty() {
+            let _0_5: Real = na::convert::<f64, Real>(0.5);
+            -_0_5 + self.unit_cell_width() * na::convert::<f64, Real>(i as f64)
+        } else {
+            self.xs[i]
+        }
     }
 
     fn quantize_floor(&self, val: Real, seg_length: Real) -> usize {
+        if !self.xs.is_empty() {
+            let i = match self
+                .xs
+                .binary_search_by(|x| x.partial_cmp(&val).unwrap_or(Ordering::Less))
+            {
+                Ok(i) => i,
+                Err(i) => i.saturating_sub(1),
+            };
+            return i.min(self.num_cells() - 1);
+        }
+
         let _0_5: Real = na::convert::<f64, Real>(0.5);
         let i = na::clamp(
             ((val + _0_5) / seg_length).floor(),
@@ -86,6 +218,16 @@ impl HeightField {
     }
 
     fn quantize_ceil(&self, val: Real, seg_length: Real) -> usize {
+        if !self.xs.is_empty() {
+            let i = match self
+                .xs
+                .binary_search_by(|x| x.partial_cmp(&val).unwrap_or(Ordering::Less))
+            {
+                Ok(i) | Err(i) => i,
+            };
+            return i.min(self.num_cells());
+        }
+
         let _0_5: Real = na::convert::<f64, Real>(0.5);
         let i = na::clamp(
             ((val + _0_5) / seg_length).ceil(),
@@ -97,11 +239,10 @@ impl HeightField {
 
     /// Index of the cell a point is on after vertical projection.
     pub fn cell_at_point(&self, pt: &Point2<Real>) -> Option<usize> {
-        let _0_5: Real = na::convert::<f64, Real>(0.5);
         let scaled_pt = pt.coords.component_div(&self.scale);
         let seg_length = self.unit_cell_width();
 
-        if scaled_pt.x < -_0_5 || scaled_pt.x > _0_5 {
+        if scaled_pt.x < self.unit_x_at(0) || scaled_pt.x > self.unit_x_at(self.num_cells()) {
             // Outside of the heightfield bounds.
             None
         } else {
@@ -109,6 +250,31 @@ impl HeightField {
         }
     }
 
+    /// The height of this heightfield at the x coordinate `x`.
+    ///
+    /// This is the height of the segment of the cell containing `x`, i.e., the linear
+    /// interpolation of the heights of the endpoints of this cell. Returns `None` if `x` is
+    /// outside of this heightfield bounds, or if the segment of its cell has been removed.
+    pub fn height_at(&self, x: Real) -> Option<Real> {
+        let seg = self.segment_at_x(x)?;
+        let t = (x - seg.a.x) / (seg.b.x - seg.a.x);
+        Some(seg.a.y + (seg.b.y - seg.a.y) * t)
+    }
+
+    /// The upward unit normal of this heightfield at the x coordinate `x`.
+    ///
+    /// This is the normal of the segment of the cell containing `x`. Returns `None` if `x` is
+    /// outside of this heightfield bounds, or if the segment of its cell has been removed.
+    pub fn normal_at(&self, x: Real) -> Option<Unit<Vector<Real>>> {
+        let dir = self.segment_at_x(x)?.scaled_direction();
+        Some(Unit::new_normalize(Vector::new(-dir.y, dir.x)))
+    }
+
+    fn segment_at_x(&self, x: Real) -> Option<Segment> {
+        let i = self.cell_at_point(&Point2::new(x, 0.0))?;
+        self.segment_at(i)
+    }
+
     /// Iterator through all the segments of this heightfield.
     pub fn segments<'a>(&'a self) -> impl Iterator<Item = Segment> + 'a {
         // FIXME: this is not very efficient since this wil
@@ -122,11 +288,8 @@ impl HeightField {
             return None;
         }
 
-        let _0_5: Real = na::convert::<f64, Real>(0.5);
-        let seg_length = 1.0 / na::convert::<f64, Real>(self.heights.len() as f64 - 1.0);
-
-        let x0 = -_0_5 + seg_length * na::convert::<f64, Real>(i as f64);
-        let x1 = x0 + seg_length;
+        let x0 = self.unit_x_at(i);
+        let x1 = self.unit_x_at(i + 1);
 
         let y0 = self.heights[i + 0];
         let y1 = self.heights[i + 1];
@@ -141,6 +304,21 @@ impl HeightField {
         Some(Segment::new(p0, p1))
     }
 
+    /// Converts the FeatureID of the `i`-th segment into a FeatureId of the whole heightfield.
+    ///
+    /// The vertices of the heightfield are identified by the index of their height. The upper
+    /// side of the `i`-th segment is identified by `FeatureId::Face(i)`, and its lower side by
+    /// `FeatureId::Face(i + self.num_cells())`.
+    pub fn convert_segment_feature_id(&self, i: usize, fid: FeatureId) -> FeatureId {
+        match fid {
+            FeatureId::Vertex(ivertex) => FeatureId::Vertex(i as u32 + ivertex),
+            // The first face of a segment is on the right of its direction, i.e., below it.
+            FeatureId::Face(0) => FeatureId::Face((i + self.num_cells()) as u32),
+            FeatureId::Face(_) => FeatureId::Face(i as u32),
+            FeatureId::Unknown => FeatureId::Unknown,
+        }
+    }
+
     /// Mark the i-th segment of this heightfield as removed or not.
     pub fn set_segment_removed(&mut self, i: usize, removed: bool) {
         if self.removed.len() == 0 {
@@ -157,12 +335,11 @@ impl HeightField {
 
     /// Applies `f` to each segment of this heightfield that intersects the given `aabb`.
     pub fn map_elements_in_local_aabb(&self, aabb: &AABB, f: &mut impl FnMut(u32, &Segment)) {
-        let _0_5: Real = na::convert::<f64, Real>(0.5);
         let ref_mins = aabb.mins.coords.component_div(&self.scale);
         let ref_maxs = aabb.maxs.coords.component_div(&self.scale);
-        let seg_length = 1.0 / na::convert::<f64, Real>(self.heights.len() as f64 - 1.0);
+        let seg_length = self.unit_cell_width();
 
-        if ref_maxs.x < -_0_5 || ref_mins.x > _0_5 {
+        if ref_maxs.x < self.unit_x_at(0) || ref_mins.x > self.unit_x_at(self.num_cells()) {
             // Outside of the heightfield bounds.
             return;
         }
@@ -177,8 +354,8 @@ impl HeightField {
                 continue;
             }
 
-            let x0 = -_0_5 + seg_length * na::convert::<f64, Real>(i as f64);
-            let x1 = x0 + seg_length;
+            let x0 = self.unit_x_at(i);
+            let x1 = self.unit_x_at(i + 1);
 
             let y0 = self.heights[i + 0];
             let y1 = self.heights[i + 1];
@@ -202,3 +379,21 @@ impl HeightField {
         }
     }
 }
+
+impl SupportMap for HeightField {
+    /// The support point of the convex hull of this heightfield.
+    ///
+    /// The vertices of the segments removed from this heightfield are ignored. This returns
+    /// the origin if all the segments have been removed.
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point2<Real> {
+        self.segments()
+            .flat_map(|seg| [seg.a, seg.b])
+            .max_by(|a, b| {
+                a.coords
+                    .dot(dir)
+                    .partial_cmp(&b.coords.dot(dir))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or_else(Point2::origin)
+    }
+}
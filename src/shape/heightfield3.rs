@@ -1,8 +1,9 @@
-use na::{DMatrix, Point3};
+use na::{DMatrix, Point3, Unit};
+use std::cmp::Ordering;
 
 use crate::bounding_volume::AABB;
 use crate::math::{Real, Vector};
-use crate::shape::{FeatureId, Triangle};
+use crate::shape::{FeatureId, SupportMap, Triangle};
 
 bitflags! {
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -29,6 +30,13 @@ pub struct HeightField {
     aabb: AABB,
     num_triangles: usize,
     status: DMatrix<HeightFieldCellStatus>,
+    // The unscaled coordinates of the columns and rows, or empty if they are uniformly spaced.
+    #[cfg_attr(feature = "serde", serde(default))]
+    xs: Vec<Real>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    zs: Vec<Real>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) ccd_thickness: Real,
 }
 
 impl HeightField {
@@ -58,9 +66,66 @@ impl HeightField {
             aabb,
             num_triangles,
             status,
+            xs: Vec::new(),
+            zs: Vec::new(),
+            ccd_thickness: 0.0,
         }
     }
 
+    /// Initializes a new heightfield with non-uniformly spaced rows and columns.
+    ///
+    /// The `j`-th column of `heights` is located at the x coordinate `xs[j]` and its `i`-th row
+    /// is located at the z coordinate `zs[i]`. Both coordinate arrays must be strictly increasing.
+    /// They are multiplied by `scale`, just like the heights.
+    pub fn with_coordinates(
+        heights: DMatrix<Real>,
+        xs: Vec<Real>,
+        zs: Vec<Real>,
+        scale: Vector<Real>,
+    ) -> Self {
+        assert_eq!(
+            xs.len(),
+            heights.ncols(),
+            "A heightfield needs one x coordinate per column of its heights."
+        );
+        assert_eq!(
+            zs.len(),
+            heights.nrows(),
+            "A heightfield needs one z coordinate per row of its heights."
+        );
+        assert!(
+            xs.windows(2).all(|w| w[0] < w[1]) && zs.windows(2).all(|w| w[0] < w[1]),
+            "The coordinates of a heightfield rows and columns must be strictly increasing."
+        );
+
+        let mut result = Self::new(heights, scale);
+        result.aabb.mins.x = xs[0] * scale.x;
+        result.aabb.maxs.x = xs[xs.len() - 1] * scale.x;
+        result.aabb.mins.z = zs[0] * scale.z;
+        result.aabb.maxs.z = zs[zs.len() - 1] * scale.z;
+        result.xs = xs;
+        result.zs = zs;
+        result
+    }
+
+    /// Sets the thickness of this heightfield used by continuous collision detection.
+    ///
+    /// A heightfield has no interior so its thickness is zero by default, which forces
+    /// continuous collision detection to substep any motion toward it. A positive thickness
+    /// can be set to model the ground below its surface. It is returned by
+    /// `Shape::ccd_thickness`.
+    pub fn with_ccd_thickness(mut self, thickness: Real) -> Self {
+        self.ccd_thickness = thickness;
+        self
+    }
+
+    /// Sets the thickness of this heightfield used by continuous collision detection.
+    ///
+    /// See `self.with_ccd_thickness` for details.
+    pub fn set_ccd_thickness(&mut self, thickness: Real) {
+        self.ccd_thickness = thickness;
+    }
+
     /// The number of rows of this heightfield.
     pub fn nrows(&self) -> usize {
         self.heights.nrows() - 1
@@ -71,7 +136,7 @@ impl HeightField {
         self.heights.ncols() - 1
     }
 
-    fn triangle_id(&self, i: usize, j: usize, left: bool) -> u32 {
+    pub(crate) fn triangle_id(&self, i: usize, j: usize, left: bool) -> u32 {
         let tid = j * (self.heights.nrows() - 1) + i;
         if left {
             tid as u32
@@ -89,7 +154,22 @@ impl HeightField {
         }
     }
 
-    fn quantize_floor(&self, val: Real, cell_size: Real, num_cells: usize) -> usize {
+    fn quantize_floor(
+        &self,
+        val: Real,
+        cell_size: Real,
+        coords: &[Real],
+        num_cells: usize,
+    ) -> usize {
+        if !coords.is_empty() {
+            let i = match coords.binary_search_by(|x| x.partial_cmp(&val).unwrap_or(Ordering::Less))
+            {
+                Ok(i) => i,
+                Err(i) => i.saturating_sub(1),
+            };
+            return i.min(num_cells - 1);
+        }
+
         let _0_5: Real = na::convert::<f64, Real>(0.5);
         let i = na::clamp(
             ((val + _0_5) / cell_size).floor(),
@@ -99,7 +179,21 @@ impl HeightField {
         na::convert_unchecked::<Real, f64>(i) as usize
     }
 
-    fn quantize_ceil(&self, val: Real, cell_size: Real, num_cells: usize) -> usize {
+    fn quantize_ceil(
+        &self,
+        val: Real,
+        cell_size: Real,
+        coords: &[Real],
+        num_cells: usize,
+    ) -> usize {
+        if !coords.is_empty() {
+            let i = match coords.binary_search_by(|x| x.partial_cmp(&val).unwrap_or(Ordering::Less))
+            {
+                Ok(i) | Err(i) => i,
+            };
+            return i.min(num_cells);
+        }
+
         let _0_5: Real = na::convert::<f64, Real>(0.5);
         let i = na::clamp(
             ((val + _0_5) / cell_size).ceil(),
@@ -111,33 +205,127 @@ impl HeightField {
 
     /// The pair of index of the cell containing the vertical projection of the given point.
     pub fn cell_at_point(&self, pt: &Point3<Real>) -> Option<(usize, usize)> {
-        let _0_5: Real = na::convert::<f64, Real>(0.5);
         let scaled_pt = pt.coords.component_div(&self.scale);
         let cell_width = self.unit_cell_width();
         let cell_height = self.unit_cell_height();
         let ncells_x = self.ncols();
         let ncells_z = self.nrows();
 
-        if scaled_pt.x < -_0_5 || scaled_pt.x > _0_5 || scaled_pt.z < -_0_5 || scaled_pt.z > _0_5 {
+        if scaled_pt.x < self.unit_x_at(0)
+            || scaled_pt.x > self.unit_x_at(ncells_x)
+            || scaled_pt.z < self.unit_z_at(0)
+            || scaled_pt.z > self.unit_z_at(ncells_z)
+        {
             // Outside of the heightfield bounds.
             None
         } else {
-            let j = self.quantize_floor(scaled_pt.x, cell_width, ncells_x);
-            let i = self.quantize_floor(scaled_pt.z, cell_height, ncells_z);
+            let j = self.quantize_floor(scaled_pt.x, cell_width, &self.xs, ncells_x);
+            let i = self.quantize_floor(scaled_pt.z, cell_height, &self.zs, ncells_z);
             Some((i, j))
         }
     }
 
+    /// The height of this heightfield at the point with the coordinates `x` and `z`.
+    ///
+    /// The cell containing this point is treated as a bilinear patch interpolating the heights
+    /// of its four corners, like with `HeightField::cast_local_ray_bilinear`, so the sampled
+    /// heights vary smoothly across the cell diagonals. Returns `None` if the point is outside
+    /// of this heightfield bounds, or if a triangle of its cell has been removed.
+    pub fn height_at(&self, x: Real, z: Real) -> Option<Real> {
+        self.bilinear_sample(x, z).map(|(height, _)| height)
+    }
+
+    /// The upward unit normal of this heightfield at the point with the coordinates `x` and `z`.
+    ///
+    /// This is the normal of the bilinear patch of the cell containing this point, see
+    /// `HeightField::height_at` for details. Returns `None` if the point is outside of this
+    /// heightfield bounds, or if a triangle of its cell has been removed.
+    pub fn normal_at(&self, x: Real, z: Real) -> Option<Unit<Vector<Real>>> {
+        self.bilinear_sample(x, z).map(|(_, normal)| normal)
+    }
+
+    // The height and normal of the bilinear patch of the cell containing the point `(x, z)`.
+    fn bilinear_sample(&self, x: Real, z: Real) -> Option<(Real, Unit<Vector<Real>>)> {
+        let (i, j) = self.cell_at_point(&Point3::new(x, 0.0, z))?;
+
+        if self.status[(i, j)].intersects(HeightFieldCellStatus::CELL_REMOVED) {
+            return None;
+        }
+
+        let ([a, b, c, e], width, height) = self.bilinear_patch(i, j);
+        let (u, v) = ((x - self.x_at(j)) / width, (z - self.z_at(i)) / height);
+        let normal = Vector::new(-(b + e * v) / width, 1.0, -(c + e * u) / height);
+        Some((a + b * u + c * v + e * u * v, Unit::new_normalize(normal)))
+    }
+
+    // The coefficients `[a, b, c, e]` of the bilinear patch
+    // `y(u, v) = a + b * u + c * v + e * u * v` interpolating the heights of the four corners
+    // of the cell `(i, j)`, where `u` and `v` are the normalized coordinates of a point of the
+    // cell along `x` and `z`. Also returns the extents of the cell along `x` and `z`.
+    pub(crate) fn bilinear_patch(&self, i: usize, j: usize) -> ([Real; 4], Real, Real) {
+        let width = self.x_at(j + 1) - self.x_at(j);
+        let height = self.z_at(i + 1) - self.z_at(i);
+
+        let y00 = self.heights[(i, j)] * self.scale.y;
+        let y10 = self.heights[(i + 1, j)] * self.scale.y;
+        let y01 = self.heights[(i, j + 1)] * self.scale.y;
+        let y11 = self.heights[(i + 1, j + 1)] * self.scale.y;
+
+        (
+            [y00, y01 - y00, y10 - y00, y00 - y01 - y10 + y11],
+            width,
+            height,
+        )
+    }
+
     /// The smallest x coordinate of the `j`-th column of this heightfield.
     pub fn x_at(&self, j: usize) -> Real {
-        let _0_5: Real = na::convert::<f64, Real>(0.5);
-        (-_0_5 + self.unit_cell_width() * na::convert::<f64, Real>(j as f64)) * self.scale.x
+        self.unit_x_at(j) * self.scale.x
     }
 
     /// The smallest z coordinate of the start of the `i`-th row of this heightfield.
     pub fn z_at(&self, i: usize) -> Real {
-        let _0_5: Real = na::convert::<f64, Real>(0.5);
-        (-_0_5 + self.unit_cell_height() * na::convert::<f64, Real>(i as f64)) * self.scale.z
+        self.unit_z_at(i) * self.scale.z
+    }
+
+    /// The x coordinates of the columns of this heightfield, excluding the scale factor.
+    ///
+    /// Returns `None` if the columns of this heightfield are uniformly spaced.
+    pub fn x_coordinates(&self) -> Option<&[Real]> {
+        if self.xs.is_empty() {
+            None
+        } else {
+            Some(&self.xs)
+        }
+    }
+
+    /// The z coordinates of the rows of this heightfield, excluding the scale factor.
+    ///
+    /// Returns `None` if the rows of this heightfield are uniformly spaced.
+    pub fn z_coordinates(&self) -> Option<&[Real]> {
+        if self.zs.is_empty() {
+            None
+        } else {
+            Some(&self.zs)
+        }
+    }
+
+    fn unit_x_at(&self, j: usize) -> Real {
+        if self.xs.is_empty() {
+            let _0_5: Real = na::convert::<f64, Real>(0.5);
+            -_0_5 + self.unit_cell_width() * na::convert::<f64, Real>(j as f64)
+        } else {
+            self.xs[j]
+        }
+    }
+
+    fn unit_z_at(&self, i: usize) -> Real {
+        if self.zs.is_empty() {
+            let _0_5: Real = na::convert::<f64, Real>(0.5);
+            -_0_5 + self.unit_cell_height() * na::convert::<f64, Real>(i as f64)
+        } else {
+            self.zs[i]
+        }
     }
 
     /// An iterator through all the triangles of this heightfield.
@@ -163,15 +351,11 @@ impl HeightField {
             return (None, None);
         }
 
-        let cell_width = self.unit_cell_width();
-        let cell_height = self.unit_cell_height();
+        let z0 = self.unit_z_at(i);
+        let z1 = self.unit_z_at(i + 1);
 
-        let _0_5: Real = na::convert::<f64, Real>(0.5);
-        let z0 = -_0_5 + cell_height * na::convert::<f64, Real>(i as f64);
-        let z1 = z0 + cell_height;
-
-        let x0 = -_0_5 + cell_width * na::convert::<f64, Real>(j as f64);
-        let x1 = x0 + cell_width;
+        let x0 = self.unit_x_at(j);
+        let x1 = self.unit_x_at(j + 1);
 
         let y00 = self.heights[(i + 0, j + 0)];
         let y10 = self.heights[(i + 1, j + 0)];
@@ -245,29 +429,87 @@ impl HeightField {
         &self.heights
     }
 
+    /// Sets the height of the `(i, j)`-th vertex of this heightfield.
+    ///
+    /// The AABB of this heightfield is refitted incrementally: it is only recomputed from
+    /// scratch if the modified height was the lowest or highest height of this heightfield.
+    pub fn set_height(&mut self, i: usize, j: usize, height: Real) {
+        let old_height = self.heights[(i, j)];
+        self.heights[(i, j)] = height;
+        self.refit_aabb(old_height, old_height, height, height);
+    }
+
+    /// Replaces the heights of the block of vertices starting at the `(i, j)`-th vertex by `heights`.
+    ///
+    /// This is more efficient than calling `self.set_height` for each vertex of the block since
+    /// the AABB of this heightfield is refitted only once.
+    pub fn set_heights_in_region(&mut self, i: usize, j: usize, heights: &DMatrix<Real>) {
+        let (nrows, ncols) = heights.shape();
+
+        if nrows == 0 || ncols == 0 {
+            return;
+        }
+
+        let mut block = self.heights.slice_mut((i, j), (nrows, ncols));
+        let (old_min, old_max) = (block.min(), block.max());
+        block.copy_from(heights);
+        self.refit_aabb(old_min, old_max, heights.min(), heights.max());
+    }
+
+    // Updates the AABB after heights in the range `[old_min, old_max]` were
+    // replaced by heights in the range `[new_min, new_max]`.
+    fn refit_aabb(&mut self, old_min: Real, old_max: Real, new_min: Real, new_max: Real) {
+        let min_was_removed = new_min > old_min && old_min * self.scale.y <= self.aabb.mins.y;
+        let max_was_removed = new_max < old_max && old_max * self.scale.y >= self.aabb.maxs.y;
+
+        if min_was_removed || max_was_removed {
+            self.aabb.mins.y = self.heights.min() * self.scale.y;
+            self.aabb.maxs.y = self.heights.max() * self.scale.y;
+        } else {
+            self.aabb.mins.y = self.aabb.mins.y.min(new_min * self.scale.y);
+            self.aabb.maxs.y = self.aabb.maxs.y.max(new_max * self.scale.y);
+        }
+    }
+
     /// The scale factor applied to this heightfield.
     pub fn scale(&self) -> &Vector<Real> {
         &self.scale
     }
 
     /// The width (extent along its local `x` axis) of each cell of this heightmap, including the scale factor.
+    ///
+    /// If the columns of this heightfield are not uniformly spaced, this is the average width of its cells.
     pub fn cell_width(&self) -> Real {
         self.unit_cell_width() * self.scale.x
     }
 
     /// The height (extent along its local `z` axis) of each cell of this heightmap, including the scale factor.
+    ///
+    /// If the rows of this heightfield are not uniformly spaced, this is the average height of its cells.
     pub fn cell_height(&self) -> Real {
         self.unit_cell_height() * self.scale.z
     }
 
     /// The width (extent along its local `x` axis) of each cell of this heightmap, excluding the scale factor.
+    ///
+    /// If the columns of this heightfield are not uniformly spaced, this is the average width of its cells.
     pub fn unit_cell_width(&self) -> Real {
-        1.0 / na::convert::<f64, Real>(self.heights.ncols() as f64 - 1.0)
+        let extent = match self.xs.last() {
+            Some(last) => *last - self.xs[0],
+            None => 1.0,
+        };
+        extent / na::convert::<f64, Real>(self.heights.ncols() as f64 - 1.0)
     }
 
     /// The height (extent along its local `z` axis) of each cell of this heightmap, excluding the scale factor.
+    ///
+    /// If the rows of this heightfield are not uniformly spaced, this is the average height of its cells.
     pub fn unit_cell_height(&self) -> Real {
-        1.0 / na::convert::<f64, Real>(self.heights.nrows() as f64 - 1.0)
+        let extent = match self.zs.last() {
+            Some(last) => *last - self.zs[0],
+            None => 1.0,
+        };
+        extent / na::convert::<f64, Real>(self.heights.nrows() as f64 - 1.0)
     }
 
     /// The AABB of this heightmap.
@@ -363,7 +605,6 @@ impl HeightField {
 
     /// Applies the function `f` to all the triangles of this heightfield intersecting the given AABB.
     pub fn map_elements_in_local_aabb(&self, aabb: &AABB, f: &mut impl FnMut(u32, &Triangle)) {
-        let _0_5: Real = na::convert::<f64, Real>(0.5);
         let ncells_x = self.ncols();
         let ncells_z = self.nrows();
 
@@ -372,16 +613,20 @@ impl HeightField {
         let cell_width = self.unit_cell_width();
         let cell_height = self.unit_cell_height();
 
-        if ref_maxs.x <= -_0_5 || ref_maxs.z <= -_0_5 || ref_mins.x >= _0_5 || ref_mins.z >= _0_5 {
+        if ref_maxs.x <= self.unit_x_at(0)
+            || ref_maxs.z <= self.unit_z_at(0)
+            || ref_mins.x >= self.unit_x_at(ncells_x)
+            || ref_mins.z >= self.unit_z_at(ncells_z)
+        {
             // Outside of the heightfield bounds.
             return;
         }
 
-        let min_x = self.quantize_floor(ref_mins.x, cell_width, ncells_x);
-        let min_z = self.quantize_floor(ref_mins.z, cell_height, ncells_z);
+        let min_x = self.quantize_floor(ref_mins.x, cell_width, &self.xs, ncells_x);
+        let min_z = self.quantize_floor(ref_mins.z, cell_height, &self.zs, ncells_z);
 
-        let max_x = self.quantize_ceil(ref_maxs.x, cell_width, ncells_x);
-        let max_z = self.quantize_ceil(ref_maxs.z, cell_height, ncells_z);
+        let max_x = self.quantize_ceil(ref_maxs.x, cell_width, &self.xs, ncells_x);
+        let max_z = self.quantize_ceil(ref_maxs.z, cell_height, &self.zs, ncells_z);
 
         // FIXME: find a way to avoid recomputing the same vertices
         // multiple times.
@@ -393,11 +638,11 @@ impl HeightField {
                     continue;
                 }
 
-                let z0 = -_0_5 + cell_height * na::convert::<f64, Real>(i as f64);
-                let z1 = z0 + cell_height;
+                let z0 = self.unit_z_at(i);
+                let z1 = self.unit_z_at(i + 1);
 
-                let x0 = -_0_5 + cell_width * na::convert::<f64, Real>(j as f64);
-                let x1 = x0 + cell_width;
+                let x0 = self.unit_x_at(j);
+                let x1 = self.unit_x_at(j + 1);
 
                 let y00 = self.heights[(i + 0, j + 0)];
                 let y10 = self.heights[(i + 1, j + 0)];
@@ -456,6 +701,24 @@ struct HeightfieldTriangles<'a> {
     tris: (Option<Triangle>, Option<Triangle>),
 }
 
+impl SupportMap for HeightField {
+    /// The support point of the convex hull of this heightfield.
+    ///
+    /// The vertices of the triangles removed from this heightfield are ignored. This returns
+    /// the origin if all the triangles have been removed.
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point3<Real> {
+        self.triangles()
+            .flat_map(|tri| [tri.a, tri.b, tri.c])
+            .max_by(|a, b| {
+                a.coords
+                    .dot(dir)
+                    .partial_cmp(&b.coords.dot(dir))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or_else(Point3::origin)
+    }
+}
+
 impl<'a> Iterator for HeightfieldTriangles<'a> {
     type Item = Triangle;
 
@@ -0,0 +1,37 @@
+use crate::math::{Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::Unit;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug)]
+/// The Minkowski sum of two convex shapes.
+///
+/// This is the set of all the points `a + b` where `a` is a point of `shape1` and `b` a point
+/// of `shape2`. This can be used, e.g., to dilate a shape by another one, or to represent the
+/// configuration-space obstacle of two shapes.
+pub struct MinkowskiSum<A, B> {
+    /// The first shape involved in the Minkowski sum.
+    pub shape1: A,
+    /// The second shape involved in the Minkowski sum.
+    pub shape2: B,
+}
+
+impl<A, B> MinkowskiSum<A, B> {
+    /// Creates the Minkowski sum of `shape1` and `shape2`.
+    pub fn new(shape1: A, shape2: B) -> Self {
+        MinkowskiSum { shape1, shape2 }
+    }
+}
+
+impl<A: SupportMap, B: SupportMap> SupportMap for MinkowskiSum<A, B> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        self.shape1.local_support_point(dir) + self.shape2.local_support_point(dir).coords
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<Real>>) -> Point<Real> {
+        self.shape1.local_support_point_toward(dir)
+            + self.shape2.local_support_point_toward(dir).coords
+    }
+}
@@ -0,0 +1,361 @@
+use crate::math::{Isometry, Point, Real, Rotation};
+#[cfg(feature = "dim2")]
+use crate::shape::{Annulus, ConvexPolygon, Polygon, Sector};
+use crate::shape::{
+    Ball, Capsule, Compound, Cuboid, HalfSpace, Polyline, RoundPolyline, RoundShape, RoundTriMesh,
+    Segment, Shape, SharedShape, Slab, TaperedCapsule, TriMesh, Triangle, Voxels,
+};
+use na::RealField;
+use std::sync::Arc;
+#[cfg(feature = "dim3")]
+use {
+    crate::math::Vector,
+    crate::shape::{
+        Cone, ConvexPolyhedron, Cylinder, Ellipsoid, EllipticCylinder, Plate, Prism,
+        SolidOfRevolution, SphericalSector,
+    },
+    na::Point2,
+};
+
+/// Shapes that can be reflected across the plane orthogonal to a coordinate axis.
+pub(crate) trait Mirror: Sized {
+    /// The reflection of this shape, and the position it must be placed at.
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self);
+}
+
+/// Reflects `shape` across the plane orthogonal to the coordinate axis `axis`.
+pub(crate) fn mirrored_shape<S: Shape + Mirror>(shape: &S, axis: usize) -> Box<dyn Shape> {
+    let (pos, mirrored) = shape.mirror(axis);
+
+    if pos == Isometry::identity() {
+        Box::new(mirrored)
+    } else {
+        Box::new(Compound::new(vec![(pos, SharedShape(Arc::new(mirrored)))]))
+    }
+}
+
+pub(crate) fn mirror_point(mut pt: Point<Real>, axis: usize) -> Point<Real> {
+    pt[axis] = -pt[axis];
+    pt
+}
+
+/// The isometry `R * pos * R` where `R` is the reflection across the plane
+/// orthogonal to the coordinate axis `axis`.
+pub(crate) fn mirror_isometry(pos: &Isometry<Real>, axis: usize) -> Isometry<Real> {
+    let mut tra = pos.translation.vector;
+    tra[axis] = -tra[axis];
+
+    let mut rot = pos.rotation.to_rotation_matrix().into_inner();
+    for k in 0..rot.nrows() {
+        if k != axis {
+            rot[(k, axis)] = -rot[(k, axis)];
+            rot[(axis, k)] = -rot[(axis, k)];
+        }
+    }
+
+    let rot = na::Rotation::from_matrix_unchecked(rot);
+    Isometry::from_parts(tra.into(), Rotation::from_rotation_matrix(&rot))
+}
+
+impl Mirror for Ball {
+    fn mirror(&self, _: usize) -> (Isometry<Real>, Self) {
+        (Isometry::identity(), *self)
+    }
+}
+
+impl Mirror for Cuboid {
+    fn mirror(&self, _: usize) -> (Isometry<Real>, Self) {
+        (Isometry::identity(), *self)
+    }
+}
+
+impl Mirror for Segment {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        // Swap the endpoints so the (2D) segment normal remains
+        // the reflection of the original normal.
+        let seg = Segment::new(mirror_point(self.b, axis), mirror_point(self.a, axis));
+        (Isometry::identity(), seg)
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Mirror for Annulus {
+    fn mirror(&self, _: usize) -> (Isometry<Real>, Self) {
+        (Isometry::identity(), *self)
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Mirror for Sector {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        // Mirroring maps the polar angle `theta` to `-theta` along `y`,
+        // and to `pi - theta` along `x`, reversing the angular range.
+        let offset = if axis == 0 { Real::pi() } else { 0.0 };
+        let sector = Sector {
+            radius: self.radius,
+            start_angle: offset - self.end_angle,
+            end_angle: offset - self.start_angle,
+        };
+        (Isometry::identity(), sector)
+    }
+}
+
+impl Mirror for Capsule {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let capsule = Capsule {
+            segment: self.segment.mirror(axis).1,
+            radius: self.radius,
+        };
+        (Isometry::identity(), capsule)
+    }
+}
+
+impl Mirror for TaperedCapsule {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let capsule = TaperedCapsule {
+            segment: self.segment.mirror(axis).1,
+            radius_a: self.radius_b,
+            radius_b: self.radius_a,
+        };
+        (Isometry::identity(), capsule)
+    }
+}
+
+impl Mirror for Triangle {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        // Flip the winding so the triangle normal remains outward.
+        let tri = Triangle::new(
+            mirror_point(self.a, axis),
+            mirror_point(self.c, axis),
+            mirror_point(self.b, axis),
+        );
+        (Isometry::identity(), tri)
+    }
+}
+
+impl Mirror for HalfSpace {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let mut normal = self.normal.into_inner();
+        normal[axis] = -normal[axis];
+        let halfspace = HalfSpace::new(na::Unit::new_unchecked(normal));
+        (Isometry::identity(), halfspace)
+    }
+}
+
+impl Mirror for Slab {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let mut normal = self.normal.into_inner();
+        normal[axis] = -normal[axis];
+        let slab = Slab::new(na::Unit::new_unchecked(normal), self.half_thickness);
+        (Isometry::identity(), slab)
+    }
+}
+
+impl Mirror for TriMesh {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let vertices = self
+            .vertices()
+            .iter()
+            .map(|pt| mirror_point(*pt, axis))
+            .collect();
+        // Flip the winding so the triangle normals remain outward.
+        let indices = self
+            .indices()
+            .iter()
+            .map(|idx| [idx[0], idx[2], idx[1]])
+            .collect();
+        let mut trimesh = TriMesh::new(vertices, indices).with_attributes_of(self);
+
+        if self.topology().is_some() {
+            // The flipped winding reverses all the half-edges.
+            let _ = trimesh.compute_topology();
+        }
+
+        (Isometry::identity(), trimesh)
+    }
+}
+
+impl Mirror for Polyline {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let vertices = self
+            .vertices()
+            .iter()
+            .map(|pt| mirror_point(*pt, axis))
+            .collect();
+        let indices = self.indices().iter().map(|idx| [idx[1], idx[0]]).collect();
+        (Isometry::identity(), Polyline::new(vertices, Some(indices)))
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Mirror for Polygon {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        // Both the boundary and the triangles get their orientation flipped back.
+        let polygon = Polygon {
+            boundary: self.boundary.mirror(axis).1,
+            triangulation: self.triangulation.mirror(axis).1,
+        };
+        (Isometry::identity(), polygon)
+    }
+}
+
+impl Mirror for RoundPolyline {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let (pos, polyline) = self.polyline().mirror(axis);
+        (pos, RoundPolyline::new(polyline, self.border_radius()))
+    }
+}
+
+impl Mirror for RoundTriMesh {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let (pos, trimesh) = self.trimesh().mirror(axis);
+        (pos, RoundTriMesh::new(trimesh, self.border_radius()))
+    }
+}
+
+impl Mirror for Voxels {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        // The voxel `k` spans `[k, k + 1]` so its reflection spans `[-k - 1, -k]`.
+        let keys: Vec<_> = self
+            .keys()
+            .iter()
+            .map(|key| {
+                let mut key = *key;
+                key[axis] = -key[axis] - 1;
+                key
+            })
+            .collect();
+        (Isometry::identity(), Voxels::new(*self.voxel_size(), &keys))
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Mirror for ConvexPolygon {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let points: Vec<_> = self
+            .points()
+            .iter()
+            .map(|pt| mirror_point(*pt, axis))
+            .collect();
+        let polygon = ConvexPolygon::from_convex_hull(&points)
+            .expect("The reflection of a convex polygon is a convex polygon.");
+        (Isometry::identity(), polygon)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for ConvexPolyhedron {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let points: Vec<_> = self
+            .points()
+            .iter()
+            .map(|pt| mirror_point(*pt, axis))
+            .collect();
+        let polyhedron = ConvexPolyhedron::from_convex_hull(&points)
+            .expect("The reflection of a convex polyhedron is a convex polyhedron.");
+        (Isometry::identity(), polyhedron)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for Prism {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        if axis == 1 {
+            return (Isometry::identity(), self.clone());
+        }
+
+        // Mirroring along `x` or `z` reverses the orientation of the cross-section.
+        let mut points: Vec<_> = self.cross_section().to_vec();
+        let coord = if axis == 0 { 0 } else { 1 };
+        points.iter_mut().for_each(|pt| pt[coord] = -pt[coord]);
+        points.reverse();
+
+        let prism = Prism::from_convex_polyline(points, self.half_height())
+            .expect("The reflection of a prism is a prism.");
+        (Isometry::identity(), prism)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for SolidOfRevolution {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        if axis != 1 {
+            return (Isometry::identity(), self.clone());
+        }
+
+        let profile = self
+            .profile()
+            .iter()
+            .rev()
+            .map(|pt| Point2::new(pt.x, -pt.y))
+            .collect();
+        (Isometry::identity(), SolidOfRevolution::new(profile))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for Cylinder {
+    fn mirror(&self, _: usize) -> (Isometry<Real>, Self) {
+        (Isometry::identity(), *self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for Ellipsoid {
+    fn mirror(&self, _: usize) -> (Isometry<Real>, Self) {
+        (Isometry::identity(), *self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for EllipticCylinder {
+    fn mirror(&self, _: usize) -> (Isometry<Real>, Self) {
+        (Isometry::identity(), *self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for Plate {
+    fn mirror(&self, _: usize) -> (Isometry<Real>, Self) {
+        (Isometry::identity(), *self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for Cone {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        if axis == 1 {
+            // The cone is symmetric wrt. its axis so its reflection
+            // is the same cone pointing downward.
+            let flip = Isometry::rotation(Vector::x() * Real::pi());
+            (flip, *self)
+        } else {
+            (Isometry::identity(), *self)
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirror for SphericalSector {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        if axis == 1 {
+            // The sector is symmetric wrt. its axis so its reflection
+            // is the same sector pointing downward.
+            let flip = Isometry::rotation(Vector::x() * Real::pi());
+            (flip, *self)
+        } else {
+            (Isometry::identity(), *self)
+        }
+    }
+}
+
+impl<S: Mirror> Mirror for RoundShape<S> {
+    fn mirror(&self, axis: usize) -> (Isometry<Real>, Self) {
+        let (pos, base_shape) = self.base_shape.mirror(axis);
+        let round_shape = RoundShape {
+            base_shape,
+            border_radius: self.border_radius,
+        };
+        (pos, round_shape)
+    }
+}
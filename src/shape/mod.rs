@@ -1,30 +1,50 @@
 //! Shapes supported by parry.
 
+pub use self::approx_eq::approx_eq;
 pub use self::ball::Ball;
 pub use self::capsule::Capsule;
 #[doc(inline)]
 pub use self::composite_shape::{SimdCompositeShape, TypedSimdCompositeShape};
 pub use self::compound::Compound;
 pub use self::cuboid::Cuboid;
+pub use self::degeneracy::Degeneracy;
 pub use self::feature_id::FeatureId;
+pub use self::filtered_shape::FilteredShape;
 pub use self::half_space::HalfSpace;
+pub use self::minkowski_sum::MinkowskiSum;
 pub use self::polygonal_feature_map::PolygonalFeatureMap;
 pub use self::polyline::Polyline;
+pub use self::round_polyline::RoundPolyline;
 pub use self::round_shape::RoundShape;
+pub use self::round_trimesh::RoundTriMesh;
+pub use self::scaled_shape::ScaledShape;
+pub use self::sdf_shape::{SdfShape, SignedDistanceFn};
 pub use self::segment::{Segment, SegmentPointLocation};
 #[doc(inline)]
-pub use self::shape::{Shape, ShapeType};
+pub use self::shape::{CustomShape, Shape, ShapeType, TypedShape};
 pub use self::shared_shape::SharedShape;
+pub use self::slab::Slab;
 #[doc(inline)]
 pub use self::support_map::SupportMap;
+pub use self::tapered_capsule::TaperedCapsule;
+pub use self::transformed_shape::TransformedShape;
 pub use self::triangle::{Triangle, TrianglePointLocation};
+pub use self::voxels::Voxels;
 
+#[cfg(feature = "dim2")]
+pub use self::annulus::Annulus;
 #[cfg(feature = "dim2")]
 pub use self::convex_polygon::ConvexPolygon;
 #[cfg(feature = "dim2")]
 pub use self::heightfield2::HeightField;
 #[cfg(feature = "dim2")]
+pub use self::polygon::Polygon;
+#[cfg(feature = "dim2")]
 pub use self::polygonal_feature2d::PolygonalFeature;
+#[cfg(feature = "dim2")]
+pub use self::polyline::LoopOrientation;
+#[cfg(feature = "dim2")]
+pub use self::sector::Sector;
 
 #[cfg(feature = "dim3")]
 pub use self::cone::Cone;
@@ -33,12 +53,28 @@ pub use self::convex_polyhedron::ConvexPolyhedron;
 #[cfg(feature = "dim3")]
 pub use self::cylinder::Cylinder;
 #[cfg(feature = "dim3")]
+pub use self::ellipsoid::Ellipsoid;
+#[cfg(feature = "dim3")]
+pub use self::elliptic_cylinder::EllipticCylinder;
+#[cfg(feature = "dim3")]
 pub use self::heightfield3::{HeightField, HeightFieldCellStatus};
 #[cfg(feature = "dim3")]
+pub use self::plate::Plate;
+#[cfg(feature = "dim3")]
 pub use self::polygonal_feature3d::PolygonalFeature;
 #[cfg(feature = "dim3")]
+pub use self::prism::Prism;
+#[cfg(feature = "dim3")]
+pub use self::solid_of_revolution::SolidOfRevolution;
+#[cfg(feature = "dim3")]
+pub use self::spherical_sector::SphericalSector;
+#[cfg(feature = "dim3")]
 pub use self::tetrahedron::{Tetrahedron, TetrahedronPointLocation};
-pub use self::trimesh::TriMesh;
+#[cfg(feature = "dim3")]
+pub use self::torus::Torus;
+#[cfg(feature = "dim3")]
+pub use self::trimesh::TriMeshPseudoNormals;
+pub use self::trimesh::{TopoFace, TopoHalfEdge, TopoVertex, TriMesh, TriMeshTopology};
 
 /// A cylinder dilated by a sphere (so it has round corners).
 #[cfg(feature = "dim3")]
@@ -56,27 +92,51 @@ pub type RoundConvexPolyhedron = RoundShape<ConvexPolyhedron>;
 /// A convex polygon dilated by a sphere (so it has round corners).
 #[cfg(feature = "dim2")]
 pub type RoundConvexPolygon = RoundShape<ConvexPolygon>;
+/// A convex shape of any type, only known at runtime, dilated by a sphere (so it has round corners).
+///
+/// The base shape must be convex, i.e., `base_shape.as_support_map()` must not be `None`.
+pub type RoundSharedShape = RoundShape<SharedShape>;
 
+mod approx_eq;
 mod ball;
 mod capsule;
 #[doc(hidden)]
 pub mod composite_shape;
 mod compound;
 mod cuboid;
+mod degeneracy;
+mod filtered_shape;
 mod half_space;
+mod minkowski_sum;
+mod mirror;
 mod polyline;
+mod round_polyline;
 mod round_shape;
+mod round_trimesh;
+mod scale;
+mod scaled_shape;
+mod sdf_shape;
 mod segment;
 #[doc(hidden)]
 pub mod shape;
+mod slab;
 #[doc(hidden)]
 pub mod support_map;
+mod tapered_capsule;
+mod transformed_shape;
 mod triangle;
+mod voxels;
 
+#[cfg(feature = "dim2")]
+mod annulus;
 #[cfg(feature = "dim2")]
 mod convex_polygon;
 #[cfg(feature = "dim2")]
 mod heightfield2;
+#[cfg(feature = "dim2")]
+mod polygon;
+#[cfg(feature = "dim2")]
+mod sector;
 
 #[cfg(feature = "dim3")]
 mod cone;
@@ -85,12 +145,26 @@ mod convex_polyhedron;
 #[cfg(feature = "dim3")]
 mod cylinder;
 #[cfg(feature = "dim3")]
+mod ellipsoid;
+#[cfg(feature = "dim3")]
+mod elliptic_cylinder;
+#[cfg(feature = "dim3")]
 mod heightfield3;
 #[cfg(feature = "dim3")]
+mod plate;
+#[cfg(feature = "dim3")]
 mod polygonal_feature3d;
 mod polygonal_feature_map;
 #[cfg(feature = "dim3")]
+mod prism;
+#[cfg(feature = "dim3")]
+mod solid_of_revolution;
+#[cfg(feature = "dim3")]
+mod spherical_sector;
+#[cfg(feature = "dim3")]
 mod tetrahedron;
+#[cfg(feature = "dim3")]
+mod torus;
 mod trimesh;
 // TODO: move this elsewhere?
 mod feature_id;
@@ -0,0 +1,57 @@
+//! Support mapping based Plate shape.
+
+use crate::math::{Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::Vector2;
+
+/// A finite rectangular plate with zero thickness, lying on the `xz` plane.
+///
+/// The normal of the plate is aligned with the `y` axis.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Plate {
+    /// The half-extents of the plate along the `x` and `z` axes respectively.
+    pub half_extents: Vector2<Real>,
+}
+
+impl Plate {
+    /// Creates a new plate.
+    ///
+    /// # Arguments:
+    /// * `half_extents` - the half-extents of the plate along the `x` and `z` axes respectively.
+    pub fn new(half_extents: Vector2<Real>) -> Plate {
+        assert!(half_extents.x.is_sign_positive() && half_extents.y.is_sign_positive());
+
+        Plate { half_extents }
+    }
+
+    /// The half-extents of this plate along each of the three axes, with a zero `y` component.
+    #[inline]
+    pub fn half_extents3(&self) -> Vector<Real> {
+        Vector::new(self.half_extents.x, 0.0, self.half_extents.y)
+    }
+
+    /// The four corners of this plate, in counterclockwise order around the `y` axis.
+    #[inline]
+    pub fn vertices(&self) -> [Point<Real>; 4] {
+        let (hx, hz) = (self.half_extents.x, self.half_extents.y);
+
+        [
+            Point::new(hx, 0.0, hz),
+            Point::new(hx, 0.0, -hz),
+            Point::new(-hx, 0.0, -hz),
+            Point::new(-hx, 0.0, hz),
+        ]
+    }
+}
+
+impl SupportMap for Plate {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        Point::new(
+            self.half_extents.x.copysign(dir.x),
+            0.0,
+            self.half_extents.y.copysign(dir.z),
+        )
+    }
+}
@@ -1,78 +1,112 @@
-#![allow(dead_code)] // TODO: remove this once we support polygons.
+//! Definition of the simple polygon shape, possibly concave and with holes.
 
-use crate::math::{Isometry, Point, Real, Vector};
-use parry::bounding_volume::AABB;
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::{Polyline, TriMesh};
+use crate::transformation::triangulate_polygon;
 
 #[derive(Clone)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
-/// A convex planar polygon.
+/// A simple 2D polygon, possibly concave and with holes.
+///
+/// The polygon is triangulated when it is created. Both its boundary and its triangulation
+/// have their own BVH: point and ray queries run against the boundary, while containment
+/// tests and pairwise queries with other shapes rely on the triangulation, exposed as the
+/// composite shape of the polygon. The polygon is not convex so it does not implement
+/// `SupportMap`.
 pub struct Polygon {
-    pub(crate) vertices: Vec<Point<Real>>,
-    pub(crate) normals: Vec<Vector<Real>>,
+    pub(crate) boundary: Polyline,
+    pub(crate) triangulation: TriMesh,
 }
 
 impl Polygon {
-    /// Builds a new polygon from a set of vertices and normals.
+    /// Creates a new polygon from the vertices of its outer boundary and of its holes.
     ///
-    /// The vertices must be ordered in such a way that two consecutive
-    /// vertices determines an edge of the polygon. For example `vertices[0], vertices[1]`
-    /// is an edge, `vertices[1], vertices[2]` is the next edge, etc. The last edge will
-    /// be `vertices[vertices.len() - 1], vertices[0]`.
-    /// The vertices must be given in counter-clockwise order.
-    /// The vertices must form a convex polygon.
+    /// Each consecutive pair of vertices of a loop forms an edge, and the last vertex is linked
+    /// to the first one. The loops may have any orientation: the outer boundary is made
+    /// counterclockwise and the holes clockwise, so the interior of the polygon is on the left
+    /// of each edge of its boundary. The holes must be strictly inside of the outer boundary and
+    /// must not overlap each other.
     ///
-    /// One normal must be provided per edge and mut point towards the outside of the polygon.
-    pub fn new(vertices: Vec<Point<Real>>, normals: Vec<Vector<Real>>) -> Self {
-        Self { vertices, normals }
-    }
+    /// Returns `None` if a loop has less than three distinct vertices, or if the polygon can't
+    /// be triangulated, e.g., because its boundary self-intersects.
+    pub fn new(outer: Vec<Point<Real>>, holes: Vec<Vec<Point<Real>>>) -> Option<Self> {
+        let mut vertices = Vec::new();
+        let mut loops = Vec::with_capacity(holes.len() + 1);
 
-    /// Compute the axis-aligned bounding box of the polygon.
-    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
-        let p0 = pos * self.vertices[0];
-        let mut mins = p0;
-        let mut maxs = p0;
-
-        for pt in &self.vertices[1..] {
-            let pt = pos * pt;
-            mins = mins.inf(&pt);
-            maxs = maxs.sup(&pt);
+        for (i, mut pts) in std::iter::once(outer).chain(holes).enumerate() {
+            pts.dedup();
+            if pts.len() > 1 && pts.first() == pts.last() {
+                let _ = pts.pop();
+            }
+
+            let area2: Real = (0..pts.len())
+                .map(|k| pts[k].coords.perp(&pts[(k + 1) % pts.len()].coords))
+                .sum();
+
+            if pts.len() < 3 || area2 == 0.0 {
+                return None;
+            }
+
+            // The outer boundary must be counterclockwise, the holes clockwise.
+            if (i == 0) != (area2 > 0.0) {
+                pts.reverse();
+            }
+
+            let start = vertices.len() as u32;
+            loops.push((start..start + pts.len() as u32).collect::<Vec<_>>());
+            vertices.extend(pts);
         }
 
-        AABB::new(mins.into(), maxs.into())
+        let triangles = triangulate_polygon(&vertices, &loops[0], &loops[1..])?;
+        let indices = loops
+            .iter()
+            .flat_map(|lp| (0..lp.len()).map(move |k| [lp[k], lp[(k + 1) % lp.len()]]))
+            .collect();
+
+        Some(Polygon {
+            boundary: Polyline::new(vertices.clone(), Some(indices)),
+            triangulation: TriMesh::new(vertices, triangles),
+        })
     }
 
-    /// The vertices of this polygon.
-    pub fn vertices(&self) -> &[Point<Real>] {
-        &self.vertices
+    /// Compute the axis-aligned bounding box of this polygon.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        self.boundary.aabb(pos)
     }
 
-    pub(crate) fn support_point(&self, dir: &Vector<Real>) -> usize {
-        let mut best_dot = -Real::MAX;
-        let mut best_i = 0;
+    /// Gets the local axis-aligned bounding box of this polygon.
+    pub fn local_aabb(&self) -> &AABB {
+        self.boundary.local_aabb()
+    }
 
-        for (i, pt) in self.vertices.iter().enumerate() {
-            let dot = pt.coords.dot(&dir);
-            if dot > best_dot {
-                best_dot = dot;
-                best_i = i;
-            }
-        }
+    /// The vertices of this polygon: the vertices of its outer boundary, followed by the
+    /// vertices of each of its holes.
+    pub fn vertices(&self) -> &[Point<Real>] {
+        self.boundary.vertices()
+    }
 
-        best_i
+    /// The boundary of this polygon.
+    ///
+    /// The outer boundary is oriented counterclockwise and the holes clockwise.
+    pub fn boundary(&self) -> &Polyline {
+        &self.boundary
     }
 
-    pub(crate) fn support_face(&self, dir: &Vector<Real>) -> usize {
-        let mut max_dot = -Real::MAX;
-        let mut max_dot_i = 0;
+    /// The counterclockwise triangles covering this polygon.
+    ///
+    /// It has the same vertex buffer as the boundary of this polygon.
+    pub fn triangulation(&self) -> &TriMesh {
+        &self.triangulation
+    }
 
-        for (i, normal) in self.normals.iter().enumerate() {
-            let dot = normal.dot(dir);
-            if dot > max_dot {
-                max_dot = dot;
-                max_dot_i = i;
-            }
-        }
+    /// The area of this polygon.
+    pub fn area(&self) -> Real {
+        self.triangulation.triangles().map(|tri| tri.area()).sum()
+    }
 
-        max_dot_i
+    /// The number of holes of this polygon.
+    pub fn num_holes(&self) -> usize {
+        self.boundary.loops().len() - 1
     }
 }
@@ -5,7 +5,7 @@ use na::Unit;
 use {
     crate::{
         math::Point,
-        shape::{Cone, Cylinder},
+        shape::{Cone, Cylinder, Plate},
     },
     approx::AbsDiffEq,
 };
@@ -141,3 +141,45 @@ impl PolygonalFeatureMap for Cone {
         }
     }
 }
+
+#[cfg(feature = "dim3")]
+impl PolygonalFeatureMap for Plate {
+    fn local_support_feature(&self, dir: &Unit<Vector<Real>>, out_features: &mut PolygonalFeature) {
+        // About feature ids.
+        // - The vertices of the plate, as given by `Plate::vertices`, have the feature IDs 0,2,4,6.
+        // - The edge between the i-th and (i+1)-th vertices has the feature ID 2 * i + 1.
+        // - The plate seen from the positive `y` side has the face feature ID 8, and the plate
+        //   seen from the negative `y` side has the face feature ID 9.
+        let vertices = self.vertices();
+
+        if dir.y.abs() >= dir.x.abs() && dir.y.abs() >= dir.z.abs() {
+            // We return the whole plate.
+            out_features.vertices = vertices;
+            out_features.vids = [0, 2, 4, 6];
+            out_features.eids = [1, 3, 5, 7];
+            out_features.fid = if dir.y >= 0.0 { 8 } else { 9 };
+            out_features.num_vertices = 4;
+        } else {
+            // We return the edge with the outward normal closest to `dir`.
+            let i1 = if dir.x.abs() >= dir.z.abs() {
+                if dir.x >= 0.0 {
+                    0
+                } else {
+                    2
+                }
+            } else if dir.z >= 0.0 {
+                3
+            } else {
+                1
+            };
+            let i2 = (i1 + 1) % 4;
+
+            out_features.vertices[0] = vertices[i1];
+            out_features.vertices[1] = vertices[i2];
+            out_features.vids = [i1 as u32 * 2, i2 as u32 * 2, i2 as u32 * 2, i2 as u32 * 2];
+            out_features.eids = [i1 as u32 * 2 + 1; 4];
+            out_features.fid = i1 as u32 * 2 + 1;
+            out_features.num_vertices = 2;
+        }
+    }
+}
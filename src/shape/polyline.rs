@@ -3,6 +3,20 @@ use crate::math::{Isometry, Point, Real};
 use crate::partitioning::SimdQuadTree;
 use crate::shape::composite_shape::SimdCompositeShape;
 use crate::shape::{FeatureId, Segment, Shape, TypedSimdCompositeShape};
+use crate::utils::hashmap::HashMap;
+#[cfg(feature = "dim2")]
+use {crate::math::Vector, na::ComplexField, na::RealField};
+
+#[cfg(feature = "dim2")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// The winding order of a closed loop in the plane.
+pub enum LoopOrientation {
+    /// The loop turns counterclockwise, i.e., it encloses a positive signed area.
+    CounterClockwise,
+    /// The loop turns clockwise, i.e., it encloses a negative signed area.
+    Clockwise,
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -15,9 +29,12 @@ pub struct Polyline {
 
 impl Polyline {
     /// Creates a new polyline from a vertex buffer and an index buffer.
+    ///
+    /// If `indices` is `None`, the vertices are linked in order. The polyline may be
+    /// empty, in which case its AABB is invalid and queries against it never report any hit.
     pub fn new(vertices: Vec<Point<Real>>, indices: Option<Vec<[u32; 2]>>) -> Self {
         let indices =
-            indices.unwrap_or_else(|| (0..vertices.len() as u32 - 1).map(|i| [i, i + 1]).collect());
+            indices.unwrap_or_else(|| (1..vertices.len() as u32).map(|i| [i - 1, i]).collect());
         let data = indices.iter().enumerate().map(|(i, idx)| {
             let aabb =
                 Segment::new(vertices[idx[0] as usize], vertices[idx[1] as usize]).local_aabb();
@@ -36,8 +53,113 @@ impl Polyline {
         }
     }
 
+    /// Creates a new polyline from a vertex buffer and a 16-bits index buffer.
+    ///
+    /// The indices are widened to `u32` internally so this does not reduce the memory
+    /// used by the polyline itself. This is only a convenience for when the polyline
+    /// data is already stored with 16-bits indices.
+    pub fn from_u16_indices(vertices: Vec<Point<Real>>, indices: &[[u16; 2]]) -> Self {
+        let indices = indices
+            .iter()
+            .map(|idx| [idx[0] as u32, idx[1] as u32])
+            .collect();
+        Self::new(vertices, Some(indices))
+    }
+
+    /// Creates a closed polyline following the boundary of a rectangle centered at the origin.
+    #[cfg(feature = "dim2")]
+    pub fn rectangle(half_extents: Vector<Real>) -> Self {
+        let vertices = vec![
+            Point::new(-half_extents.x, -half_extents.y),
+            Point::new(half_extents.x, -half_extents.y),
+            Point::new(half_extents.x, half_extents.y),
+            Point::new(-half_extents.x, half_extents.y),
+        ];
+        let indices = vec![[0, 1], [1, 2], [2, 3], [3, 0]];
+        Self::new(vertices, Some(indices))
+    }
+
+    /// Creates a closed polyline approximating a circle centered at the origin.
+    ///
+    /// The circle is discretized into `nsubdiv` segments, with all the vertices lying on the circle.
+    #[cfg(feature = "dim2")]
+    pub fn circle(radius: Real, nsubdiv: u32) -> Self {
+        let dtheta = Real::two_pi() / (nsubdiv as Real);
+        let vertices = (0..nsubdiv)
+            .map(|i| {
+                let theta = dtheta * (i as Real);
+                Point::new(
+                    ComplexField::cos(theta) * radius,
+                    ComplexField::sin(theta) * radius,
+                )
+            })
+            .collect();
+        let indices = (0..nsubdiv).map(|i| [i, (i + 1) % nsubdiv]).collect();
+        Self::new(vertices, Some(indices))
+    }
+
+    /// Appends an arc of circle to the end of this polyline.
+    ///
+    /// The arc goes from the angle `start_angle` to the angle `end_angle` (in radians, going
+    /// counterclockwise if `end_angle > start_angle`), and is discretized into `nsubdiv` segments.
+    /// The last vertex of this polyline is connected to the start of the arc, unless they
+    /// are the same point or this polyline is empty.
+    ///
+    /// Panics if `nsubdiv` is zero.
+    #[cfg(feature = "dim2")]
+    pub fn append_arc(
+        &mut self,
+        center: Point<Real>,
+        radius: Real,
+        start_angle: Real,
+        end_angle: Real,
+        nsubdiv: u32,
+    ) {
+        assert!(nsubdiv > 0, "An arc must have at least one subdivision.");
+
+        let mut vertices = std::mem::take(&mut self.vertices);
+        let mut indices = std::mem::take(&mut self.indices);
+        let tail = indices
+            .last()
+            .map(|idx| idx[1])
+            .or_else(|| (vertices.len() as u32).checked_sub(1));
+        let dtheta = (end_angle - start_angle) / (nsubdiv as Real);
+        let eps = ComplexField::sqrt(crate::math::DEFAULT_EPSILON);
+        let mut prev = tail;
+
+        for i in 0..=nsubdiv {
+            let theta = start_angle + dtheta * (i as Real);
+            let pt =
+                center + Vector::new(ComplexField::cos(theta), ComplexField::sin(theta)) * radius;
+
+            if i == 0 {
+                if let Some(tail) = tail {
+                    if na::distance(&vertices[tail as usize], &pt) <= eps {
+                        continue;
+                    }
+                }
+            }
+
+            vertices.push(pt);
+            let id = vertices.len() as u32 - 1;
+
+            if let Some(prev) = prev {
+                indices.push([prev, id]);
+            }
+
+            prev = Some(id);
+        }
+
+        *self = Self::new(vertices, Some(indices));
+    }
+
     /// Compute the axis-aligned bounding box of this polyline.
     pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        if self.is_empty() {
+            // Transforming the invalid AABB would result in NaNs.
+            return AABB::new_invalid();
+        }
+
         self.quadtree.root_aabb().transform_by(pos)
     }
 
@@ -55,6 +177,11 @@ impl Polyline {
         self.indices.len()
     }
 
+    /// Returns `true` if this polyline does not contain any segment.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
     /// An iterator through all the segments of this mesh.
     pub fn segments(&self) -> impl Iterator<Item = Segment> + '_ {
         self.indices.iter().map(move |ids| {
@@ -74,6 +201,131 @@ impl Polyline {
         )
     }
 
+    /// Splits this polyline into its connected components.
+    ///
+    /// Two segments belong to the same component if they are linked by a chain of segments
+    /// sharing vertex indices. Each component only keeps the vertices used by its segments,
+    /// and the components are sorted by the index of their first segment in this polyline.
+    pub fn extract_connected_components(&self) -> Vec<Polyline> {
+        let mut parents: Vec<u32> = (0..self.vertices.len() as u32).collect();
+
+        for idx in &self.indices {
+            let root0 = find_root(&mut parents, idx[0]);
+            let root1 = find_root(&mut parents, idx[1]);
+            parents[root0 as usize] = root1;
+        }
+
+        let mut component_ids = HashMap::default();
+        let mut components = vec![];
+        // Each vertex belongs to a single component so it is given a single new index.
+        let mut new_vertex_ids = vec![u32::MAX; self.vertices.len()];
+
+        for idx in &self.indices {
+            let root = find_root(&mut parents, idx[0]);
+            let component_id = *component_ids.entry(root).or_insert_with(|| {
+                components.push((vec![], vec![]));
+                components.len() - 1
+            });
+            let (vertices, indices) = &mut components[component_id];
+            let mut new_idx = [0; 2];
+
+            for k in 0..2 {
+                let new_id = &mut new_vertex_ids[idx[k] as usize];
+
+                if *new_id == u32::MAX {
+                    vertices.push(self.vertices[idx[k] as usize]);
+                    *new_id = vertices.len() as u32 - 1;
+                }
+
+                new_idx[k] = *new_id;
+            }
+
+            indices.push(new_idx);
+        }
+
+        components
+            .into_iter()
+            .map(|(vertices, indices)| Polyline::new(vertices, Some(indices)))
+            .collect()
+    }
+
+    /// The closed loops formed by the segments of this polyline.
+    ///
+    /// Each loop is given by the indices of its segments, in the order they are traversed.
+    /// A connected component is a loop if each of its vertices is the first vertex of exactly
+    /// one segment, and the second vertex of exactly one segment. The connected components
+    /// that are not loops are ignored.
+    pub fn loops(&self) -> Vec<Vec<u32>> {
+        let mut outgoing = vec![u32::MAX; self.vertices.len()];
+        let mut num_outgoing = vec![0; self.vertices.len()];
+        let mut num_incoming = vec![0; self.vertices.len()];
+
+        for (i, idx) in self.indices.iter().enumerate() {
+            outgoing[idx[0] as usize] = i as u32;
+            num_outgoing[idx[0] as usize] += 1;
+            num_incoming[idx[1] as usize] += 1;
+        }
+
+        let mut visited = vec![false; self.indices.len()];
+        let mut loops = vec![];
+
+        for start in 0..self.indices.len() as u32 {
+            if visited[start as usize] {
+                continue;
+            }
+
+            let mut segments = vec![];
+            let mut segment = start;
+            let is_loop = loop {
+                visited[segment as usize] = true;
+                segments.push(segment);
+
+                let end = self.indices[segment as usize][1] as usize;
+
+                if num_outgoing[end] != 1 || num_incoming[end] != 1 {
+                    break false;
+                }
+
+                segment = outgoing[end];
+
+                if segment == start {
+                    break true;
+                } else if visited[segment as usize] {
+                    break false;
+                }
+            };
+
+            if is_loop {
+                loops.push(segments);
+            }
+        }
+
+        loops
+    }
+
+    /// The orientation of the closed loop formed by the given segments of this polyline.
+    ///
+    /// The segments are typically one of the loops returned by `self.loops()`. Returns `None`
+    /// if the loop encloses a zero area.
+    #[cfg(feature = "dim2")]
+    pub fn loop_orientation(&self, segments: &[u32]) -> Option<LoopOrientation> {
+        let twice_area: Real = segments
+            .iter()
+            .map(|i| {
+                let seg = self.segment(*i);
+                seg.a.coords.perp(&seg.b.coords)
+            })
+            .sum();
+
+        if twice_area > 0.0 {
+            Some(LoopOrientation::CounterClockwise)
+        } else if twice_area < 0.0 {
+            Some(LoopOrientation::Clockwise)
+        } else {
+            None
+        }
+    }
+
     /// Transforms  the feature-id of a segment to the feature-id of this polyline.
     pub fn segment_feature_to_polyline_feature(
         &self,
@@ -142,3 +394,12 @@ impl TypedSimdCompositeShape for Polyline {
         &self.quadtree
     }
 }
+
+fn find_root(parents: &mut [u32], mut i: u32) -> u32 {
+    while parents[i as usize] != i {
+        parents[i as usize] = parents[parents[i as usize] as usize];
+        i = parents[i as usize];
+    }
+
+    i
+}
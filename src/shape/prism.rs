@@ -0,0 +1,253 @@
+//! Support mapping based Prism shape.
+
+use crate::math::{Point, Real, Vector};
+use crate::shape::{FeatureId, PolygonalFeature, PolygonalFeatureMap, SupportMap};
+use na::{ComplexField, Point2, RealField, Unit, Vector2};
+
+/// A right prism obtained by extruding a 2D convex polygon along the `y` axis.
+///
+/// The cross-section of the prism lies on the `xz` plane: each of its 2D points
+/// `(u, v)` corresponds to the 3D points `(u, y, v)` for all `y` in `[-half_height, half_height]`.
+///
+/// # Feature IDs
+/// With `n` the number of vertices of the cross-section:
+/// - the vertex `i` of the bottom cap has the feature ID `i` and the vertex `i` of the top
+///   cap has the feature ID `n + i`;
+/// - the edge between the vertices `i` and `i + 1` of the bottom cap has the feature ID `i`,
+///   the same edge on the top cap has the feature ID `n + i`, and the vertical edge passing
+///   through the vertex `i` of the cross-section has the feature ID `2 * n + i`;
+/// - the side face extruded from the edge `i` of the cross-section has the feature ID `i`,
+///   the bottom cap has the feature ID `n` and the top cap has the feature ID `n + 1`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Prism {
+    points: Vec<Point2<Real>>,
+    normals: Vec<Unit<Vector2<Real>>>,
+    half_height: Real,
+}
+
+impl Prism {
+    /// Creates a new prism by extruding the convex hull of an arbitrary set of 2D points.
+    ///
+    /// Returns `None` if the convex hull computation failed.
+    pub fn from_convex_hull(points: &[Point2<Real>], half_height: Real) -> Option<Self> {
+        let mut vertices: Vec<_> = crate::transformation::convex_hull2_idx(points)
+            .into_iter()
+            .map(|i| points[i])
+            .collect();
+        vertices.reverse(); // The convex hull is given in clockwise order.
+
+        Self::from_convex_polyline(vertices, half_height)
+    }
+
+    /// Creates a new prism by extruding a set of 2D points assumed to describe a
+    /// counter-clockwise convex polyline.
+    ///
+    /// Convexity of the input polyline is not checked.
+    /// Returns `None` if all points form an almost flat line.
+    pub fn from_convex_polyline(mut points: Vec<Point2<Real>>, half_height: Real) -> Option<Self> {
+        assert!(half_height.is_sign_positive());
+
+        let eps = ComplexField::sqrt(crate::math::DEFAULT_EPSILON);
+        let mut normals = Vec::with_capacity(points.len());
+
+        // First, compute all normals.
+        for i1 in 0..points.len() {
+            let i2 = (i1 + 1) % points.len();
+            let ab = points[i2] - points[i1];
+            let normal = Vector2::new(ab.y, -ab.x);
+            normals.push(Unit::try_new(normal, crate::math::DEFAULT_EPSILON)?);
+        }
+
+        // Second, remove vertices between collinear edges.
+        let mut i = 0;
+        while i < points.len() && points.len() > 2 {
+            let prev = (i + points.len() - 1) % points.len();
+
+            if normals[prev].dot(&normals[i]) > 1.0 - eps {
+                let _ = points.remove(i);
+                let _ = normals.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        if points.len() > 2 {
+            Some(Prism {
+                points,
+                normals,
+                half_height,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The vertices of the cross-section of this prism, in counter-clockwise order.
+    #[inline]
+    pub fn cross_section(&self) -> &[Point2<Real>] {
+        &self.points
+    }
+
+    /// The outward normals of the edges of the cross-section of this prism.
+    #[inline]
+    pub fn cross_section_normals(&self) -> &[Unit<Vector2<Real>>] {
+        &self.normals
+    }
+
+    /// The half-height of this prism along the `y` axis.
+    #[inline]
+    pub fn half_height(&self) -> Real {
+        self.half_height
+    }
+
+    /// The 3D point of this prism at the height `y` above the `i`-th cross-section vertex.
+    #[inline]
+    pub fn vertex(&self, i: usize, y: Real) -> Point<Real> {
+        Point::new(self.points[i].x, y, self.points[i].y)
+    }
+
+    /// The outward normal of the face with the given id.
+    ///
+    /// See the type-level documentation for the face numbering.
+    pub fn face_normal(&self, face: u32) -> Vector<Real> {
+        let n = self.points.len() as u32;
+
+        if face < n {
+            let normal = self.normals[face as usize];
+            Vector::new(normal.x, 0.0, normal.y)
+        } else if face == n {
+            -Vector::y()
+        } else {
+            Vector::y()
+        }
+    }
+
+    /// The area of the cross-section of this prism, and its centroid.
+    pub fn cross_section_area_and_centroid(&self) -> (Real, Point2<Real>) {
+        let mut area = 0.0;
+        let mut centroid = Vector2::zeros();
+        let origin = self.points[0];
+
+        for i in 1..self.points.len() - 1 {
+            let a = self.points[i] - origin;
+            let b = self.points[i + 1] - origin;
+            let tri_area = a.perp(&b) / 2.0;
+            area += tri_area;
+            centroid += (a + b) * (tri_area / 3.0);
+        }
+
+        (area, origin + centroid / area)
+    }
+
+    /// Get the ID of the feature with a normal that maximizes the dot product with `local_dir`.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<Real>>) -> FeatureId {
+        let eps: Real = Real::pi() / 180.0;
+        let ceps = ComplexField::cos(eps);
+        let n = self.points.len() as u32;
+
+        if local_dir.y >= ceps {
+            return FeatureId::Face(n + 1);
+        } else if -local_dir.y >= ceps {
+            return FeatureId::Face(n);
+        }
+
+        let dir2 = Vector2::new(local_dir.x, local_dir.z);
+
+        for (i, normal) in self.normals.iter().enumerate() {
+            if normal.dot(&dir2) >= ceps {
+                return FeatureId::Face(i as u32);
+            }
+        }
+
+        let i = self.support_point_id(&dir2) as u32;
+
+        if local_dir.y >= 0.0 {
+            FeatureId::Vertex(n + i)
+        } else {
+            FeatureId::Vertex(i)
+        }
+    }
+
+    fn support_point_id(&self, dir: &Vector2<Real>) -> usize {
+        let mut best = 0;
+        let mut best_dot = self.points[0].coords.dot(dir);
+
+        for (i, pt) in self.points.iter().enumerate().skip(1) {
+            let dot = pt.coords.dot(dir);
+
+            if dot > best_dot {
+                best = i;
+                best_dot = dot;
+            }
+        }
+
+        best
+    }
+}
+
+impl SupportMap for Prism {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let i = self.support_point_id(&Vector2::new(dir.x, dir.z));
+        self.vertex(i, self.half_height.copysign(dir.y))
+    }
+}
+
+impl PolygonalFeatureMap for Prism {
+    fn local_support_feature(&self, dir: &Unit<Vector<Real>>, out_features: &mut PolygonalFeature) {
+        let n = self.points.len();
+        let dir2 = Vector2::new(dir.x, dir.z);
+        let mut best_side = 0;
+        let mut best_side_dot = self.normals[0].dot(&dir2);
+
+        for (i, normal) in self.normals.iter().enumerate().skip(1) {
+            let dot = normal.dot(&dir2);
+
+            if dot > best_side_dot {
+                best_side = i;
+                best_side_dot = dot;
+            }
+        }
+
+        if dir.y.abs() >= best_side_dot {
+            // We return the cap. If it has more than four vertices, it is
+            // approximated by four vertices spread evenly along its boundary.
+            let y = self.half_height.copysign(dir.y);
+            let shift = if dir.y >= 0.0 { n as u32 } else { 0 };
+            let num_vertices = n.min(4);
+
+            for k in 0..num_vertices {
+                let i = k * n / num_vertices;
+                out_features.vertices[k] = self.vertex(i, y);
+                out_features.vids[k] = i as u32 + shift;
+                out_features.eids[k] = i as u32 + shift;
+            }
+
+            out_features.fid = n as u32 + if dir.y >= 0.0 { 1 } else { 0 };
+            out_features.num_vertices = num_vertices;
+        } else {
+            // We return the side face.
+            let i1 = best_side;
+            let i2 = (best_side + 1) % n;
+            let h = self.half_height;
+            let n = n as u32;
+
+            out_features.vertices = [
+                self.vertex(i1, -h),
+                self.vertex(i2, -h),
+                self.vertex(i2, h),
+                self.vertex(i1, h),
+            ];
+            out_features.vids = [i1 as u32, i2 as u32, n + i2 as u32, n + i1 as u32];
+            out_features.eids = [
+                i1 as u32,
+                2 * n + i2 as u32,
+                n + i1 as u32,
+                2 * n + i1 as u32,
+            ];
+            out_features.fid = i1 as u32;
+            out_features.num_vertices = 4;
+        }
+    }
+}
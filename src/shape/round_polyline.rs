@@ -0,0 +1,108 @@
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Real};
+use crate::partitioning::SimdQuadTree;
+use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{Capsule, Polyline, Shape, TypedSimdCompositeShape};
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A polyline with rounded borders.
+///
+/// This is the set of points at a distance smaller than `border_radius` from the base
+/// polyline. Each segment is handled as a `Capsule` by the narrow-phase, which
+/// gives some thickness to polylines representing thin walls.
+pub struct RoundPolyline {
+    quadtree: SimdQuadTree<u32>,
+    polyline: Polyline,
+    border_radius: Real,
+}
+
+impl RoundPolyline {
+    /// Creates a new polyline with its segments dilated by `border_radius`.
+    pub fn new(polyline: Polyline, border_radius: Real) -> Self {
+        let data = (0..polyline.num_segments() as u32).map(|i| {
+            let aabb = polyline.segment(i).local_aabb().loosened(border_radius);
+            (i, aabb)
+        });
+
+        let mut quadtree = SimdQuadTree::new();
+        // NOTE: we apply no dilation factor because we won't
+        // update this tree dynamically.
+        quadtree.clear_and_rebuild(data, 0.0);
+
+        Self {
+            quadtree,
+            polyline,
+            border_radius,
+        }
+    }
+
+    /// The polyline being rounded.
+    pub fn polyline(&self) -> &Polyline {
+        &self.polyline
+    }
+
+    /// The radius of the rounded border.
+    pub fn border_radius(&self) -> Real {
+        self.border_radius
+    }
+
+    /// Compute the axis-aligned bounding box of this rounded polyline.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        self.polyline.aabb(pos).loosened(self.border_radius)
+    }
+
+    /// Gets the local axis-aligned bounding box of this rounded polyline.
+    pub fn local_aabb(&self) -> AABB {
+        self.polyline.local_aabb().loosened(self.border_radius)
+    }
+
+    /// The acceleration structure used by this rounded polyline.
+    pub fn quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+
+    /// Get the `i`-th segment of this polyline, with rounded borders.
+    pub fn segment(&self, i: u32) -> Capsule {
+        Capsule {
+            segment: self.polyline.segment(i),
+            radius: self.border_radius,
+        }
+    }
+}
+
+impl SimdCompositeShape for RoundPolyline {
+    fn map_part_at(&self, i: u32, f: &mut dyn FnMut(Option<&Isometry<Real>>, &dyn Shape)) {
+        let capsule = self.segment(i);
+        f(None, &capsule)
+    }
+
+    fn quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+}
+
+impl TypedSimdCompositeShape for RoundPolyline {
+    type PartShape = Capsule;
+    type PartId = u32;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(Option<&Isometry<Real>>, &Self::PartShape),
+    ) {
+        let capsule = self.segment(i);
+        f(None, &capsule)
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(&self, i: u32, mut f: impl FnMut(Option<&Isometry<Real>>, &dyn Shape)) {
+        let capsule = self.segment(i);
+        f(None, &capsule)
+    }
+
+    fn typed_quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+}
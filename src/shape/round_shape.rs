@@ -1,5 +1,5 @@
 use crate::math::{Point, Real, Vector};
-use crate::shape::SupportMap;
+use crate::shape::{SharedShape, SupportMap};
 use na::Unit;
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -21,3 +21,29 @@ impl<S: SupportMap> SupportMap for RoundShape<S> {
         self.base_shape.local_support_point_toward(dir) + **dir * self.border_radius
     }
 }
+
+impl SupportMap for RoundShape<SharedShape> {
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        self.local_support_point_toward(&Unit::new_normalize(*dir))
+    }
+
+    /// Panics if the base shape isn't convex, i.e., if `self.base_support_map()` is `None`.
+    fn local_support_point_toward(&self, dir: &Unit<Vector<Real>>) -> Point<Real> {
+        self.base_support_map()
+            .expect("The base shape of a rounded shape must be a support map.")
+            .local_support_point_toward(dir)
+            + **dir * self.border_radius
+    }
+}
+
+impl RoundShape<SharedShape> {
+    /// The support map of the base shape of this rounded shape.
+    ///
+    /// Returns `None` if the base shape isn't convex. `SharedShape::round` and deserialization
+    /// reject such base shapes, but they can still be set through the public fields. This
+    /// rounded shape is then neither convex nor a support map, and ray-casts on it never
+    /// report any hit.
+    pub fn base_support_map(&self) -> Option<&dyn SupportMap> {
+        self.base_shape.as_support_map()
+    }
+}
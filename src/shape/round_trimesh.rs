@@ -0,0 +1,108 @@
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Real};
+use crate::partitioning::SimdQuadTree;
+use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{RoundTriangle, Shape, TriMesh, TypedSimdCompositeShape};
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A triangle mesh with rounded borders.
+///
+/// This is the set of points at a distance smaller than `border_radius` from the base
+/// triangle mesh. Each triangle is handled as a `RoundTriangle` by the narrow-phase, which
+/// gives some thickness to meshes representing thin shells.
+pub struct RoundTriMesh {
+    quadtree: SimdQuadTree<u32>,
+    trimesh: TriMesh,
+    border_radius: Real,
+}
+
+impl RoundTriMesh {
+    /// Creates a new triangle mesh with its triangles dilated by `border_radius`.
+    pub fn new(trimesh: TriMesh, border_radius: Real) -> Self {
+        let data = (0..trimesh.num_triangles() as u32).map(|i| {
+            let aabb = trimesh.triangle(i).local_aabb().loosened(border_radius);
+            (i, aabb)
+        });
+
+        let mut quadtree = SimdQuadTree::new();
+        // NOTE: we apply no dilation factor because we won't
+        // update this tree dynamically.
+        quadtree.clear_and_rebuild(data, 0.0);
+
+        Self {
+            quadtree,
+            trimesh,
+            border_radius,
+        }
+    }
+
+    /// The triangle mesh being rounded.
+    pub fn trimesh(&self) -> &TriMesh {
+        &self.trimesh
+    }
+
+    /// The radius of the rounded border.
+    pub fn border_radius(&self) -> Real {
+        self.border_radius
+    }
+
+    /// Compute the axis-aligned bounding box of this rounded triangle mesh.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        self.trimesh.aabb(pos).loosened(self.border_radius)
+    }
+
+    /// Gets the local axis-aligned bounding box of this rounded triangle mesh.
+    pub fn local_aabb(&self) -> AABB {
+        self.trimesh.local_aabb().loosened(self.border_radius)
+    }
+
+    /// The acceleration structure used by this rounded triangle mesh.
+    pub fn quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+
+    /// Get the `i`-th triangle of this mesh, with rounded borders.
+    pub fn triangle(&self, i: u32) -> RoundTriangle {
+        RoundTriangle {
+            base_shape: self.trimesh.triangle(i),
+            border_radius: self.border_radius,
+        }
+    }
+}
+
+impl SimdCompositeShape for RoundTriMesh {
+    fn map_part_at(&self, i: u32, f: &mut dyn FnMut(Option<&Isometry<Real>>, &dyn Shape)) {
+        let tri = self.triangle(i);
+        f(None, &tri)
+    }
+
+    fn quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+}
+
+impl TypedSimdCompositeShape for RoundTriMesh {
+    type PartShape = RoundTriangle;
+    type PartId = u32;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(Option<&Isometry<Real>>, &Self::PartShape),
+    ) {
+        let tri = self.triangle(i);
+        f(None, &tri)
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(&self, i: u32, mut f: impl FnMut(Option<&Isometry<Real>>, &dyn Shape)) {
+        let tri = self.triangle(i);
+        f(None, &tri)
+    }
+
+    fn typed_quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+}
@@ -0,0 +1,504 @@
+use crate::math::{Point, Real, Vector};
+#[cfg(feature = "dim2")]
+use crate::shape::{Annulus, ConvexPolygon, Polygon, Sector};
+use crate::shape::{
+    Ball, Capsule, Cuboid, HalfSpace, HeightField, MinkowskiSum, Polyline, RoundPolyline,
+    RoundShape, RoundTriMesh, ScaledShape, SdfShape, Segment, Shape, SharedShape, Slab, SupportMap,
+    TaperedCapsule, TriMesh, Triangle, Voxels,
+};
+use na::Unit;
+#[cfg(feature = "dim3")]
+use {
+    crate::shape::{
+        Cone, ConvexPolyhedron, Cylinder, Ellipsoid, EllipticCylinder, Plate, Prism,
+        SolidOfRevolution, SphericalSector, Torus,
+    },
+    na::{Point2, Vector2},
+};
+
+/// Shapes that can be scaled along the coordinate axes of their local-space.
+pub(crate) trait Scale {
+    /// This shape scaled by the positive factors `scale`, discretized with `nsubdivs`
+    /// subdivisions if it can't be represented exactly.
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>>;
+}
+
+/// Scales `shape` by `scale`, or returns `None` if a scaling factor isn't positive.
+pub(crate) fn scaled_shape<S: Scale>(
+    shape: &S,
+    scale: &Vector<Real>,
+    nsubdivs: u32,
+) -> Option<Box<dyn Shape>> {
+    if scale.iter().all(|e| *e > 0.0) {
+        shape.scale(scale, nsubdivs)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn is_uniform(scale: &Vector<Real>) -> bool {
+    scale.iter().all(|e| *e == scale.x)
+}
+
+fn scale_point(pt: &Point<Real>, scale: &Vector<Real>) -> Point<Real> {
+    Point::from(pt.coords.component_mul(scale))
+}
+
+/// The convex hull of support points of `shape` scaled by `scale`.
+fn scaled_convex_hull<S: SupportMap>(
+    shape: &S,
+    scale: &Vector<Real>,
+    nsubdivs: u32,
+) -> Option<Box<dyn Shape>> {
+    #[cfg(feature = "dim2")]
+    let directions = Ball::new(1.0).to_polyline(nsubdivs);
+    #[cfg(feature = "dim3")]
+    let (directions, _) = Ball::new(1.0).to_trimesh(nsubdivs, (nsubdivs / 2).max(2));
+
+    // The support point of the scaled shape toward `dir` is the scaled support
+    // point of the original shape toward `dir.component_mul(scale)`.
+    let points: Vec<_> = directions
+        .iter()
+        .map(|dir| {
+            let pt = shape.local_support_point(&dir.coords.component_mul(scale));
+            scale_point(&pt, scale)
+        })
+        .collect();
+
+    #[cfg(feature = "dim2")]
+    let hull = ConvexPolygon::from_convex_hull(&points);
+    #[cfg(feature = "dim3")]
+    let hull = ConvexPolyhedron::from_convex_hull(&points);
+
+    hull.map(|hull| Box::new(hull) as Box<dyn Shape>)
+}
+
+impl Scale for Ball {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            return Some(Box::new(Ball::new(self.radius * scale.x)));
+        }
+
+        #[cfg(feature = "dim2")]
+        {
+            scaled_convex_hull(self, scale, nsubdivs)
+        }
+        #[cfg(feature = "dim3")]
+        {
+            let _ = nsubdivs;
+            Some(Box::new(Ellipsoid::new(scale * self.radius)))
+        }
+    }
+}
+
+impl Scale for Cuboid {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(Cuboid::new(
+            self.half_extents.component_mul(scale),
+        )))
+    }
+}
+
+impl Scale for Capsule {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            let a = scale_point(&self.segment.a, scale);
+            let b = scale_point(&self.segment.b, scale);
+            Some(Box::new(Capsule::new(a, b, self.radius * scale.x)))
+        } else {
+            scaled_convex_hull(self, scale, nsubdivs)
+        }
+    }
+}
+
+impl Scale for TaperedCapsule {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            Some(Box::new(TaperedCapsule::new(
+                scale_point(&self.segment.a, scale),
+                scale_point(&self.segment.b, scale),
+                self.radius_a * scale.x,
+                self.radius_b * scale.x,
+            )))
+        } else {
+            scaled_convex_hull(self, scale, nsubdivs)
+        }
+    }
+}
+
+impl Scale for Segment {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(Segment::new(
+            scale_point(&self.a, scale),
+            scale_point(&self.b, scale),
+        )))
+    }
+}
+
+impl Scale for Triangle {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(Triangle::new(
+            scale_point(&self.a, scale),
+            scale_point(&self.b, scale),
+            scale_point(&self.c, scale),
+        )))
+    }
+}
+
+impl Scale for Polyline {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        let vertices = self
+            .vertices()
+            .iter()
+            .map(|pt| scale_point(pt, scale))
+            .collect();
+        Some(Box::new(Polyline::new(
+            vertices,
+            Some(self.indices().to_vec()),
+        )))
+    }
+}
+
+impl Scale for TriMesh {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        let mut trimesh = self.clone();
+        TriMesh::scale(&mut trimesh, scale);
+        Some(Box::new(trimesh))
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Scale for Polygon {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        // Positive scaling factors preserve the orientation of the boundary and triangles.
+        let vertices: Vec<_> = self
+            .vertices()
+            .iter()
+            .map(|pt| scale_point(pt, scale))
+            .collect();
+        Some(Box::new(Polygon {
+            boundary: Polyline::new(vertices.clone(), Some(self.boundary.indices().to_vec())),
+            triangulation: TriMesh::new(vertices, self.triangulation.indices().to_vec()),
+        }))
+    }
+}
+
+impl Scale for RoundPolyline {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            let polyline = self.polyline();
+            let vertices = polyline
+                .vertices()
+                .iter()
+                .map(|pt| scale_point(pt, scale))
+                .collect();
+            let polyline = Polyline::new(vertices, Some(polyline.indices().to_vec()));
+            let border_radius = self.border_radius() * scale.x;
+            Some(Box::new(RoundPolyline::new(polyline, border_radius)))
+        } else {
+            None
+        }
+    }
+}
+
+impl Scale for RoundTriMesh {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            let mut trimesh = self.trimesh().clone();
+            TriMesh::scale(&mut trimesh, scale);
+            let border_radius = self.border_radius() * scale.x;
+            Some(Box::new(RoundTriMesh::new(trimesh, border_radius)))
+        } else {
+            None
+        }
+    }
+}
+
+impl Scale for HeightField {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        let heights = self.heights().clone();
+        let new_scale = self.scale().component_mul(scale);
+
+        #[cfg(feature = "dim2")]
+        let mut scaled = match self.x_coordinates() {
+            Some(xs) => HeightField::with_coordinates(heights, xs.to_vec(), new_scale),
+            None => HeightField::new(heights, new_scale),
+        };
+        #[cfg(feature = "dim3")]
+        let mut scaled = match (self.x_coordinates(), self.z_coordinates()) {
+            (Some(xs), Some(zs)) => {
+                HeightField::with_coordinates(heights, xs.to_vec(), zs.to_vec(), new_scale)
+            }
+            _ => HeightField::new(heights, new_scale),
+        };
+
+        // Keep the holes of this heightfield.
+        #[cfg(feature = "dim2")]
+        for i in 0..self.num_cells() {
+            if self.is_segment_removed(i) {
+                scaled.set_segment_removed(i, true);
+            }
+        }
+        #[cfg(feature = "dim3")]
+        {
+            *scaled.cells_statuses_mut() = self.cells_statuses().clone();
+        }
+
+        scaled.set_ccd_thickness(self.ccd_thickness * scale.min());
+        Some(Box::new(scaled))
+    }
+}
+
+impl Scale for HalfSpace {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        // Normals are transformed by the inverse transpose of the scaling.
+        let normal = Unit::new_normalize(self.normal.component_div(scale));
+        Some(Box::new(HalfSpace::new(normal)))
+    }
+}
+
+impl Scale for Slab {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        // Normals are transformed by the inverse transpose of the scaling, and the
+        // distance between the planes shrinks by the norm of the transformed normal.
+        let (normal, norm) = Unit::new_and_get(self.normal.component_div(scale));
+        Some(Box::new(Slab::new(normal, self.half_thickness / norm)))
+    }
+}
+
+impl Scale for Voxels {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(Voxels::new(
+            self.voxel_size().component_mul(scale),
+            self.keys(),
+        )))
+    }
+}
+
+impl Scale for SdfShape {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        // Multiplying by the smallest scaling factor ensures the scaled SDF
+        // never overestimates the distance to the scaled surface.
+        let base = self.clone();
+        let scale = *scale;
+        let min_scale = scale.min();
+        let mut sdf = SdfShape::new(
+            move |pt| {
+                let unscaled = Point::from(pt.coords.component_div(&scale));
+                base.signed_distance_to_local_point(&unscaled) * min_scale
+            },
+            self.aabb().scaled(&scale),
+        );
+        sdf.tolerance = self.tolerance;
+        sdf.max_iterations = self.max_iterations;
+        Some(Box::new(sdf))
+    }
+}
+
+impl<S: Shape + SupportMap + Clone> Scale for ScaledShape<S> {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(ScaledShape::new(
+            self.base_shape.clone(),
+            self.scale.component_mul(scale),
+        )))
+    }
+}
+
+impl<A: SupportMap, B: SupportMap> Scale for MinkowskiSum<A, B> {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scaled_convex_hull(self, scale, nsubdivs)
+    }
+}
+
+impl<S: SupportMap> Scale for RoundShape<S> {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scaled_convex_hull(self, scale, nsubdivs)
+    }
+}
+
+impl Scale for RoundShape<SharedShape> {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            let base_shape = SharedShape(self.base_shape.scaled(scale, nsubdivs)?.into());
+
+            if base_shape.as_support_map().is_some() {
+                return Some(Box::new(RoundShape {
+                    base_shape,
+                    border_radius: self.border_radius * scale.x,
+                }));
+            }
+        }
+
+        scaled_convex_hull(self, scale, nsubdivs)
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Scale for Annulus {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            Some(Box::new(Annulus::new(
+                self.inner_radius * scale.x,
+                self.outer_radius * scale.x,
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Scale for Sector {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            Some(Box::new(Sector::new(
+                self.radius * scale.x,
+                self.start_angle,
+                self.end_angle,
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Scale for ConvexPolygon {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        let points = self
+            .points()
+            .iter()
+            .map(|pt| scale_point(pt, scale))
+            .collect();
+        let polygon = ConvexPolygon::from_convex_polyline(points)?;
+        Some(Box::new(polygon))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for ConvexPolyhedron {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        let points: Vec<_> = self
+            .points()
+            .iter()
+            .map(|pt| scale_point(pt, scale))
+            .collect();
+        let polyhedron = ConvexPolyhedron::from_convex_hull(&points)?;
+        Some(Box::new(polyhedron))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for Cylinder {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        if scale.x == scale.z {
+            Some(Box::new(Cylinder::new(
+                self.half_height * scale.y,
+                self.radius * scale.x,
+            )))
+        } else {
+            scaled_convex_hull(self, scale, nsubdivs)
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for Cone {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        if scale.x == scale.z {
+            Some(Box::new(Cone::new(
+                self.half_height * scale.y,
+                self.radius * scale.x,
+            )))
+        } else {
+            scaled_convex_hull(self, scale, nsubdivs)
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for Ellipsoid {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(Ellipsoid::new(self.radii.component_mul(scale))))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for EllipticCylinder {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(EllipticCylinder::new(
+            self.half_height * scale.y,
+            self.radii.component_mul(&Vector2::new(scale.x, scale.z)),
+        )))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for Torus {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            Some(Box::new(Torus::new(
+                self.major_radius * scale.x,
+                self.minor_radius * scale.x,
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for SphericalSector {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        if is_uniform(scale) {
+            Some(Box::new(SphericalSector::new(
+                self.radius * scale.x,
+                self.half_angle,
+            )))
+        } else if self.is_convex() {
+            scaled_convex_hull(self, scale, nsubdivs)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for Plate {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        let scale_xz = Vector2::new(scale.x, scale.z);
+        Some(Box::new(Plate::new(
+            self.half_extents.component_mul(&scale_xz),
+        )))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for Prism {
+    fn scale(&self, scale: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+        let scale_xz = Vector2::new(scale.x, scale.z);
+        let points = self
+            .cross_section()
+            .iter()
+            .map(|pt| Point2::from(pt.coords.component_mul(&scale_xz)))
+            .collect();
+        let prism = Prism::from_convex_polyline(points, self.half_height() * scale.y)?;
+        Some(Box::new(prism))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Scale for SolidOfRevolution {
+    fn scale(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        if scale.x == scale.z {
+            let profile = self
+                .profile()
+                .iter()
+                .map(|pt| Point2::new(pt.x * scale.x, pt.y * scale.y))
+                .collect();
+            Some(Box::new(SolidOfRevolution::new(profile)))
+        } else {
+            scaled_convex_hull(self, scale, nsubdivs)
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::math::{Point, Real, Vector};
+use crate::shape::SupportMap;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug)]
+/// A shape scaled non-uniformly along the coordinate axes of its local-space.
+///
+/// Each point `p` of the base shape corresponds to the point `p.component_mul(&scale)` of the
+/// scaled shape. This avoids rebuilding the base shape (e.g. a convex hull) whenever its scale
+/// changes.
+pub struct ScaledShape<S> {
+    /// The shape being scaled.
+    pub base_shape: S,
+    /// The scaling factor along each coordinate axis.
+    pub scale: Vector<Real>,
+}
+
+impl<S> ScaledShape<S> {
+    /// Creates a new shape obtained by scaling `base_shape` by the positive factors `scale`.
+    pub fn new(base_shape: S, scale: Vector<Real>) -> Self {
+        assert!(
+            scale.iter().all(|e| *e > 0.0),
+            "The scaling factors must be positive."
+        );
+
+        ScaledShape { base_shape, scale }
+    }
+
+    /// Converts a point from the local-space of this shape to the local-space of its base shape.
+    #[inline]
+    pub fn unscale_point(&self, pt: &Point<Real>) -> Point<Real> {
+        Point::from(pt.coords.component_div(&self.scale))
+    }
+}
+
+impl<S: SupportMap> SupportMap for ScaledShape<S> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        // dir · (scale ⊙ p) = (scale ⊙ dir) · p
+        let pt = self
+            .base_shape
+            .local_support_point(&dir.component_mul(&self.scale));
+        Point::from(pt.coords.component_mul(&self.scale))
+    }
+}
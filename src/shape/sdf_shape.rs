@@ -0,0 +1,84 @@
+//! Definition of a shape described by a signed distance function.
+
+use crate::bounding_volume::AABB;
+use crate::math::{Point, Real, Vector, DEFAULT_EPSILON, DIM};
+use na::ComplexField;
+use std::fmt;
+use std::sync::Arc;
+
+/// The type of the signed distance functions wrapped by `SdfShape`.
+pub type SignedDistanceFn = dyn Fn(&Point<Real>) -> Real + Send + Sync;
+
+/// A shape described by a user-supplied signed distance function (SDF).
+///
+/// The SDF returns, for any point expressed in the local-space of the shape, its distance
+/// to the surface of the shape. This distance must be negative for points inside of the
+/// shape. The shape must be entirely contained in the AABB given at construction.
+///
+/// Point projections are computed by descending the gradient of the SDF, and ray-casts by
+/// sphere tracing. Both assume the SDF never overestimates the actual distance to the
+/// surface: an SDF that only bounds this distance from below still works, but requires more
+/// iterations to converge.
+#[derive(Clone)]
+pub struct SdfShape {
+    sdf: Arc<SignedDistanceFn>,
+    aabb: AABB,
+    /// The distance below which a point is considered to be on the surface of the shape.
+    pub tolerance: Real,
+    /// The maximum number of iterations of the point projection and ray marching algorithms.
+    pub max_iterations: usize,
+}
+
+impl SdfShape {
+    /// Creates a new shape from its signed distance function and a local AABB containing it.
+    pub fn new(sdf: impl Fn(&Point<Real>) -> Real + Send + Sync + 'static, aabb: AABB) -> Self {
+        SdfShape {
+            sdf: Arc::new(sdf),
+            aabb,
+            tolerance: 1.0e-4,
+            max_iterations: 128,
+        }
+    }
+
+    /// The local AABB containing this shape.
+    #[inline]
+    pub fn aabb(&self) -> &AABB {
+        &self.aabb
+    }
+
+    /// The signed distance between `pt` and the surface of this shape.
+    ///
+    /// This is negative if `pt` is inside of this shape.
+    #[inline]
+    pub fn signed_distance_to_local_point(&self, pt: &Point<Real>) -> Real {
+        (self.sdf)(pt)
+    }
+
+    /// The normalized gradient of the signed distance function at `pt`, estimated by central
+    /// differences.
+    ///
+    /// Returns `None` if the gradient is zero, e.g., on the medial axis of the shape.
+    pub fn gradient(&self, pt: &Point<Real>) -> Option<Vector<Real>> {
+        // This step balances the truncation and rounding errors of central differences.
+        let step = ComplexField::cbrt(DEFAULT_EPSILON);
+        let mut gradient = Vector::zeros();
+
+        for i in 0..DIM {
+            let mut shift = Vector::zeros();
+            shift[i] = step;
+            gradient[i] = ((self.sdf)(&(pt + shift)) - (self.sdf)(&(pt - shift))) / (2.0 * step);
+        }
+
+        gradient.try_normalize(0.0)
+    }
+}
+
+impl fmt::Debug for SdfShape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SdfShape")
+            .field("aabb", &self.aabb)
+            .field("tolerance", &self.tolerance)
+            .field("max_iterations", &self.max_iterations)
+            .finish()
+    }
+}
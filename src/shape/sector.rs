@@ -0,0 +1,85 @@
+//! Definition of the sector shape.
+
+use crate::math::{Point, Real, Vector};
+use na::{ComplexField, RealField};
+
+/// A circular sector, i.e., the wedge of a disk centered at the origin bounded by two rays.
+///
+/// The sector contains all the points of the disk with a polar angle between `start_angle`
+/// and `end_angle`, both measured counterclockwise from the `x` axis. The sector is not
+/// convex in general so it does not implement `SupportMap`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Sector {
+    /// The radius of the disk this sector is part of.
+    pub radius: Real,
+    /// The polar angle of the first straight edge of the sector.
+    pub start_angle: Real,
+    /// The polar angle of the second straight edge of the sector.
+    pub end_angle: Real,
+}
+
+impl Sector {
+    /// Creates a new sector.
+    ///
+    /// # Arguments:
+    /// * `radius` - the radius of the disk this sector is part of.
+    /// * `start_angle` - the polar angle of the first straight edge of the sector.
+    /// * `end_angle` - the polar angle of the second straight edge of the sector. The
+    ///   opening angle `end_angle - start_angle` must be in `(0, 2 * pi]`.
+    pub fn new(radius: Real, start_angle: Real, end_angle: Real) -> Sector {
+        assert!(radius.is_sign_positive());
+        assert!(
+            end_angle > start_angle && end_angle - start_angle <= Real::two_pi(),
+            "The opening angle of a sector must be positive and at most 2 * pi."
+        );
+
+        Sector {
+            radius,
+            start_angle,
+            end_angle,
+        }
+    }
+
+    /// The opening angle of this sector.
+    #[inline]
+    pub fn angle(&self) -> Real {
+        self.end_angle - self.start_angle
+    }
+
+    /// Checks if the given polar angle lies within the angular range of this sector.
+    #[inline]
+    pub fn contains_angle(&self, angle: Real) -> bool {
+        (angle - self.start_angle).rem_euclid(Real::two_pi()) <= self.angle()
+    }
+
+    /// The unit vector along the first straight edge of this sector.
+    #[inline]
+    pub fn start_dir(&self) -> Vector<Real> {
+        Vector::new(
+            ComplexField::cos(self.start_angle),
+            ComplexField::sin(self.start_angle),
+        )
+    }
+
+    /// The unit vector along the second straight edge of this sector.
+    #[inline]
+    pub fn end_dir(&self) -> Vector<Real> {
+        Vector::new(
+            ComplexField::cos(self.end_angle),
+            ComplexField::sin(self.end_angle),
+        )
+    }
+
+    /// The endpoint of the arc of this sector at `start_angle`.
+    #[inline]
+    pub fn start_point(&self) -> Point<Real> {
+        Point::from(self.start_dir() * self.radius)
+    }
+
+    /// The endpoint of the arc of this sector at `end_angle`.
+    #[inline]
+    pub fn end_point(&self) -> Point<Real> {
+        Point::from(self.end_dir() * self.radius)
+    }
+}
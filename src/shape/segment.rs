@@ -1,7 +1,7 @@
 //! Definition of the segment shape.
 
 use crate::math::{Isometry, Point, Real, Vector};
-use crate::shape::SupportMap;
+use crate::shape::{Degeneracy, SupportMap};
 
 use na::{self, Unit};
 use std::mem;
@@ -54,6 +54,16 @@ impl Segment {
     pub fn from_array(arr: &[Point<Real>; 2]) -> &Segment {
         unsafe { mem::transmute(arr) }
     }
+
+    /// The point this segment collapses to if its length is at most `eps`.
+    ///
+    /// Returns `None` if this segment isn't degenerate.
+    pub fn degeneracy(&self, eps: Real) -> Option<Degeneracy> {
+        match Degeneracy::of_points(&[self.a, self.b], eps)? {
+            Degeneracy::Point(pt) => Some(Degeneracy::Point(pt)),
+            Degeneracy::Segment(_) => None,
+        }
+    }
 }
 
 impl Segment {
@@ -1,34 +1,43 @@
-use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, AABB};
 use crate::mass_properties::MassProperties;
-use crate::math::{Isometry, Point, Real, Vector};
-use crate::query::{PointQuery, RayCast};
+use crate::math::{Isometry, Point, Real, Rotation, Vector, DIM};
+use crate::query::{PointQuery, QueryDispatcher, RayCast};
 use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{mirror, scale};
+#[cfg(feature = "dim2")]
+use crate::shape::{Annulus, ConvexPolygon, Polygon, RoundConvexPolygon, Sector};
 use crate::shape::{
-    Ball, Capsule, Compound, Cuboid, FeatureId, HalfSpace, HeightField, PolygonalFeatureMap,
-    Polyline, RoundCuboid, RoundShape, RoundTriangle, Segment, SupportMap, TriMesh, Triangle,
+    Ball, Capsule, Compound, Cuboid, FeatureId, HalfSpace, HeightField, MinkowskiSum,
+    PolygonalFeatureMap, Polyline, RoundCuboid, RoundPolyline, RoundShape, RoundSharedShape,
+    RoundTriMesh, RoundTriangle, ScaledShape, SdfShape, Segment, SharedShape, Slab, SupportMap,
+    TaperedCapsule, TransformedShape, TriMesh, Triangle, Voxels,
 };
 #[cfg(feature = "dim3")]
 use crate::shape::{
-    Cone, ConvexPolyhedron, Cylinder, RoundCone, RoundConvexPolyhedron, RoundCylinder,
+    Cone, ConvexPolyhedron, Cylinder, Ellipsoid, EllipticCylinder, Plate, Prism, RoundCone,
+    RoundConvexPolyhedron, RoundCylinder, SolidOfRevolution, SphericalSector, Torus,
 };
-#[cfg(feature = "dim2")]
-use crate::shape::{ConvexPolygon, RoundConvexPolygon};
 use downcast_rs::{impl_downcast, DowncastSync};
 #[cfg(feature = "serde-serialize")]
 use erased_serde::Serialize;
+#[cfg(feature = "dim2")]
+use na::ComplexField;
+use na::RealField;
 use na::Unit;
 use num::Zero;
-use num_derive::FromPrimitive;
+use std::collections::HashSet;
 
-#[derive(Copy, Clone, Debug, FromPrimitive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 /// Enum representing the type of a shape.
 pub enum ShapeType {
     /// A ball shape.
-    Ball = 0,
+    Ball,
     /// A cuboid shape.
     Cuboid,
     /// A capsule shape.
     Capsule,
+    /// A capsule shape with a different radius at each endpoint.
+    TaperedCapsule,
     /// A segment shape.
     Segment,
     /// A triangle shape.
@@ -39,12 +48,23 @@ pub enum ShapeType {
     Polyline,
     /// A shape representing a full half-space.
     HalfSpace,
+    /// The region between two parallel planes.
+    Slab,
     /// A heightfield shape.
     HeightField,
     /// A Compound shape.
     Compound,
     #[cfg(feature = "dim2")]
     ConvexPolygon,
+    #[cfg(feature = "dim2")]
+    /// A ring-shaped 2D shape.
+    Annulus,
+    #[cfg(feature = "dim2")]
+    /// A circular sector.
+    Sector,
+    #[cfg(feature = "dim2")]
+    /// A simple polygon, possibly concave and with holes.
+    Polygon,
     #[cfg(feature = "dim3")]
     /// A convex polyhedron.
     ConvexPolyhedron,
@@ -54,14 +74,47 @@ pub enum ShapeType {
     #[cfg(feature = "dim3")]
     /// A cylindrical shape.
     Cone,
-    // /// A custom shape type.
-    // Custom(u8),
+    #[cfg(feature = "dim3")]
+    /// A torus shape.
+    Torus,
+    #[cfg(feature = "dim3")]
+    /// An ellipsoid shape.
+    Ellipsoid,
+    #[cfg(feature = "dim3")]
+    /// A cylindrical shape with an elliptical cross-section.
+    EllipticCylinder,
+    #[cfg(feature = "dim3")]
+    /// A finite rectangular plate with zero thickness.
+    Plate,
+    #[cfg(feature = "dim3")]
+    /// A prism obtained by extruding a convex polygon.
+    Prism,
+    #[cfg(feature = "dim3")]
+    /// A convex solid obtained by rotating a profile around an axis.
+    SolidOfRevolution,
+    #[cfg(feature = "dim3")]
+    /// The part of a ball within a cone with its apex at the center of the ball.
+    SphericalSector,
+    /// A shape described by a signed distance function.
+    SdfShape,
+    /// A sparse set of voxels.
+    Voxels,
+    /// A shape scaled non-uniformly.
+    ScaledShape,
+    /// The Minkowski sum of two convex shapes.
+    MinkowskiSum,
+    /// A shape positioned by a rigid transformation.
+    TransformedShape,
+    /// A custom shape type, identified by a user-defined number.
+    Custom(u8),
     /// A cuboid with rounded corners.
     RoundCuboid,
     /// A triangle with rounded corners.
     RoundTriangle,
-    // /// A triangle-mesh with rounded corners.
-    // RoundedTriMesh,
+    /// A triangle-mesh with rounded corners.
+    RoundTriMesh,
+    /// A polyline with rounded corners.
+    RoundPolyline,
     // /// An heightfield with rounded corners.
     // RoundedHeightField,
     /// A cylinder with rounded corners.
@@ -76,6 +129,140 @@ pub enum ShapeType {
     /// A convex polygon with rounded corners.
     #[cfg(feature = "dim2")]
     RoundConvexPolygon,
+    /// A convex shape of any type with rounded corners.
+    RoundSharedShape,
+}
+
+#[derive(Copy, Clone)]
+/// A reference to a shape, with one variant per builtin shape type.
+///
+/// This is obtained with `<dyn Shape>::as_typed_shape` and allows exhaustive matching on
+/// the concrete type of a shape.
+pub enum TypedShape<'a> {
+    /// A ball shape.
+    Ball(&'a Ball),
+    /// A cuboid shape.
+    Cuboid(&'a Cuboid),
+    /// A capsule shape.
+    Capsule(&'a Capsule),
+    /// A capsule shape with a different radius at each endpoint.
+    TaperedCapsule(&'a TaperedCapsule),
+    /// A segment shape.
+    Segment(&'a Segment),
+    /// A triangle shape.
+    Triangle(&'a Triangle),
+    /// A triangle mesh shape.
+    TriMesh(&'a TriMesh),
+    /// A set of segments.
+    Polyline(&'a Polyline),
+    /// A shape representing a full half-space.
+    HalfSpace(&'a HalfSpace),
+    /// The region between two parallel planes.
+    Slab(&'a Slab),
+    /// A heightfield shape.
+    HeightField(&'a HeightField),
+    /// A Compound shape.
+    Compound(&'a Compound),
+    /// A convex polygon.
+    #[cfg(feature = "dim2")]
+    ConvexPolygon(&'a ConvexPolygon),
+    /// A ring-shaped 2D shape.
+    #[cfg(feature = "dim2")]
+    Annulus(&'a Annulus),
+    /// A circular sector.
+    #[cfg(feature = "dim2")]
+    Sector(&'a Sector),
+    /// A simple polygon, possibly concave and with holes.
+    #[cfg(feature = "dim2")]
+    Polygon(&'a Polygon),
+    /// A convex polyhedron.
+    #[cfg(feature = "dim3")]
+    ConvexPolyhedron(&'a ConvexPolyhedron),
+    /// A cylindrical shape.
+    #[cfg(feature = "dim3")]
+    Cylinder(&'a Cylinder),
+    /// A conical shape.
+    #[cfg(feature = "dim3")]
+    Cone(&'a Cone),
+    /// A torus shape.
+    #[cfg(feature = "dim3")]
+    Torus(&'a Torus),
+    /// An ellipsoid shape.
+    #[cfg(feature = "dim3")]
+    Ellipsoid(&'a Ellipsoid),
+    /// A cylindrical shape with an elliptical cross-section.
+    #[cfg(feature = "dim3")]
+    EllipticCylinder(&'a EllipticCylinder),
+    /// A finite rectangular plate with zero thickness.
+    #[cfg(feature = "dim3")]
+    Plate(&'a Plate),
+    /// A prism obtained by extruding a convex polygon.
+    #[cfg(feature = "dim3")]
+    Prism(&'a Prism),
+    /// A convex solid obtained by rotating a profile around an axis.
+    #[cfg(feature = "dim3")]
+    SolidOfRevolution(&'a SolidOfRevolution),
+    /// The part of a ball within a cone with its apex at the center of the ball.
+    #[cfg(feature = "dim3")]
+    SphericalSector(&'a SphericalSector),
+    /// A shape described by a signed distance function.
+    SdfShape(&'a SdfShape),
+    /// A sparse set of voxels.
+    Voxels(&'a Voxels),
+    /// A shape scaled non-uniformly.
+    ///
+    /// Use `<dyn Shape>::as_shape` to access the `ScaledShape` with its concrete base shape.
+    ScaledShape(&'a dyn Shape),
+    /// The Minkowski sum of two convex shapes.
+    ///
+    /// Use `<dyn Shape>::as_shape` to access the `MinkowskiSum` with its concrete operands.
+    MinkowskiSum(&'a dyn Shape),
+    /// A shape positioned by a rigid transformation.
+    ///
+    /// Use `<dyn Shape>::as_shape` to access the `TransformedShape` with its concrete inner
+    /// shape, or `Shape::as_transformed_shape` to access its inner shape as a `dyn Shape`.
+    TransformedShape(&'a dyn Shape),
+    /// A custom shape, or a shape with a builtin `ShapeType` that doesn't match its actual type.
+    Custom(&'a dyn Shape),
+    /// A cuboid with rounded corners.
+    RoundCuboid(&'a RoundCuboid),
+    /// A triangle with rounded corners.
+    RoundTriangle(&'a RoundTriangle),
+    /// A triangle-mesh with rounded corners.
+    RoundTriMesh(&'a RoundTriMesh),
+    /// A polyline with rounded corners.
+    RoundPolyline(&'a RoundPolyline),
+    /// A cylinder with rounded corners.
+    #[cfg(feature = "dim3")]
+    RoundCylinder(&'a RoundCylinder),
+    /// A cone with rounded corners.
+    #[cfg(feature = "dim3")]
+    RoundCone(&'a RoundCone),
+    /// A convex polyhedron with rounded corners.
+    #[cfg(feature = "dim3")]
+    RoundConvexPolyhedron(&'a RoundConvexPolyhedron),
+    /// A convex polygon with rounded corners.
+    #[cfg(feature = "dim2")]
+    RoundConvexPolygon(&'a RoundConvexPolygon),
+    /// A convex shape of any type with rounded corners.
+    RoundSharedShape(&'a RoundSharedShape),
+}
+
+/// Trait implemented by user-defined shapes with a `ShapeType::Custom` type.
+///
+/// The `DefaultQueryDispatcher` gives the query dispatcher of a custom shape the first
+/// chance to handle any query involving this shape. If both shapes of a query are custom
+/// shapes, the dispatcher of the first shape is tried before the dispatcher of the second
+/// one, so the pair can be handled by either of them. The numeric tag of
+/// `ShapeType::Custom` can be used by these dispatchers to identify the other shape.
+/// If the dispatchers return `Err(Unsupported)`, the builtin queries are used instead,
+/// e.g., the ones based on `Shape::as_support_map` if the custom shape is convex.
+pub trait CustomShape: Send + Sync {
+    /// The dispatcher handling the queries involving this shape.
+    ///
+    /// This dispatcher must not call the `DefaultQueryDispatcher` on the same pair of
+    /// shapes, or the query would loop indefinitely.
+    fn query_dispatcher(&self) -> &dyn QueryDispatcher;
 }
 
 /// Trait implemented by shapes usable by Rapier.
@@ -86,6 +273,9 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
         None
     }
 
+    /// Clones this shape into a boxed trait-object.
+    fn clone_dyn(&self) -> Box<dyn Shape>;
+
     /// Computes the AABB of this shape.
     fn compute_local_aabb(&self) -> AABB;
 
@@ -94,14 +284,102 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
         self.compute_local_aabb().transform_by(position)
     }
 
+    /// Computes the AABB of this shape moving from the position `start` to `end`, enlarged by `margin`.
+    ///
+    /// This is the union of the AABBs of this shape at both positions, loosened by `margin`.
+    /// The intermediate positions are not taken into account, so this may not bound the
+    /// shape if the motion includes a large rotation.
+    fn compute_aabb_for_motion(
+        &self,
+        start: &Isometry<Real>,
+        end: &Isometry<Real>,
+        margin: Real,
+    ) -> AABB {
+        self.compute_aabb(start)
+            .merged(&self.compute_aabb(end))
+            .loosened(margin)
+    }
+
+    /// The half-extents of the local AABB of this shape.
+    fn local_aabb_half_extents(&self) -> Vector<Real> {
+        self.compute_local_aabb().half_extents()
+    }
+
+    /// The center of the local AABB of this shape.
+    fn local_aabb_center(&self) -> Point<Real> {
+        self.compute_local_aabb().center()
+    }
+
+    /// Computes the bounding-sphere of this shape.
+    ///
+    /// By default, this is the bounding-sphere of the local AABB of this shape.
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.compute_local_aabb().bounding_sphere()
+    }
+
+    /// Computes the bounding-sphere of this shape with the given position.
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.compute_local_bounding_sphere().transform_by(position)
+    }
+
     /// Compute the mass-properties of this shape given its uniform density.
     fn mass_properties(&self, density: Real) -> MassProperties;
 
+    /// The volume of this shape, i.e., its area in 2D.
+    ///
+    /// Shapes without interior, e.g., segments, polylines, heightfields, and in 3D, triangles
+    /// and triangle meshes that aren't closed, have a zero volume. Unbounded shapes have a
+    /// volume equal to `Real::MAX`. The volume of a composite shape is the sum of the volumes
+    /// of its parts, so overlapping parts are counted more than once.
+    ///
+    /// By default, this is computed from the mass of this shape with a unit density.
+    fn volume(&self) -> Real {
+        let inv_mass = self.mass_properties(1.0).inv_mass;
+
+        if inv_mass == 0.0 {
+            0.0
+        } else {
+            1.0 / inv_mass
+        }
+    }
+
+    /// The area of the boundary of this shape, i.e., its perimeter in 2D.
+    ///
+    /// Shapes without interior count each of their faces once, e.g., the surface area of a
+    /// triangle mesh is the sum of the areas of its triangles. Unbounded shapes have a surface
+    /// area equal to `Real::MAX`. The surface area of a composite shape is the sum of the surface
+    /// areas of its parts.
+    ///
+    /// By default, this is approximated from the convex hull of some of the support points of
+    /// this shape, or is zero if this shape doesn't have a support map.
+    fn surface_area(&self) -> Real {
+        self.as_support_map()
+            .map(support_map_surface_area)
+            .unwrap_or(0.0)
+    }
+
     /// Gets the type tag of this shape.
     fn shape_type(&self) -> ShapeType;
 
+    /// The thickness of this shape, used by continuous collision detection.
+    ///
+    /// This is the smallest distance this shape can move by during a CCD substep without
+    /// risking to tunnel through a thin obstacle. Shapes without interior, e.g., 3D triangle
+    /// meshes and heightfields, have a zero thickness unless it is configured explicitly.
     fn ccd_thickness(&self) -> Real;
 
+    /// The angular thickness of this shape, used by continuous collision detection.
+    ///
+    /// This is the largest angle, in radians, this shape can rotate by during a CCD substep
+    /// while still relying on `self.ccd_thickness()` to detect tunneling. Rotations larger
+    /// than this must be substepped. This is `Real::MAX` for shapes that are invariant by
+    /// rotation around their center, e.g., balls.
+    ///
+    /// By default, this is `π / 4`.
+    fn ccd_angular_thickness(&self) -> Real {
+        Real::frac_pi_4()
+    }
+
     /// Is this shape known to be convex?
     ///
     /// If this returns `true` then `self` is known to be convex.
@@ -120,6 +398,17 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
         None
     }
 
+    /// Converts this shape into a custom shape, if it is one.
+    fn as_custom(&self) -> Option<&dyn CustomShape> {
+        None
+    }
+
+    /// Converts this shape into the position and the inner shape of a transformed shape, if it
+    /// is one.
+    fn as_transformed_shape(&self) -> Option<(&Isometry<Real>, &dyn Shape)> {
+        None
+    }
+
     /// Converts this shape to a polygonal feature-map, if it is one.
     fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
         None
@@ -129,6 +418,82 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
     //     None
     // }
 
+    /// Reflects this shape across the plane orthogonal to the coordinate axis `axis`.
+    ///
+    /// This negates the `axis`-th coordinate of all the geometry of this shape. Triangle
+    /// meshes and polylines get their winding flipped so their normals remain outward.
+    /// The result may not have the same type as `self`, e.g., a cone reflected along its
+    /// axis is represented by a compound shape containing a flipped cone.
+    ///
+    /// Returns `None` if this shape does not support reflection.
+    fn mirrored(&self, _axis: usize) -> Option<Box<dyn Shape>> {
+        None
+    }
+
+    /// Scales this shape by the factors `scale` along the coordinate axes of its local-space.
+    ///
+    /// The result is exact whenever the scaled shape can be represented by a builtin shape,
+    /// e.g., a scaled cuboid is a cuboid. Otherwise, the scaled shape is discretized with
+    /// `nsubdivs` subdivisions, e.g., a non-uniformly scaled 2D ball becomes a convex polygon.
+    ///
+    /// Returns `None` if this shape does not support this scaling, or if one of the scaling
+    /// factors is not positive.
+    fn scaled(&self, _scale: &Vector<Real>, _nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        None
+    }
+
+    /// Discretizes the boundary of this shape as a polygonal line, given by its vertices and the
+    /// indices of the endpoints of its segments.
+    ///
+    /// Curved parts of the boundary are approximated with `nsubdivs` subdivisions. The boundary
+    /// of a solid shape is oriented counterclockwise. The polylines of the parts of a composite
+    /// shape are merged, expressed in the local-space of the composite shape.
+    ///
+    /// By default, this is the convex hull of some of the support points of this shape.
+    /// Returns `None` if this shape is unbounded or can't be discretized.
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        let hull = support_map_hull(self.as_support_map()?, nsubdivs)?;
+        Some(closed_polyline(hull.points().to_vec()))
+    }
+
+    /// Discretizes the boundary of this shape as a triangle mesh, given by its vertices and
+    /// the indices of the vertices of its triangles.
+    ///
+    /// Curved parts of the boundary are approximated with `nsubdivs` subdivisions. The triangles
+    /// of the boundary of a solid shape are oriented counterclockwise around its outward normal.
+    /// The meshes of the parts of a composite shape are merged, expressed in the local-space of
+    /// the composite shape.
+    ///
+    /// By default, this is the convex hull of some of the support points of this shape.
+    /// Returns `None` if this shape is unbounded or doesn't have any face, e.g., a segment.
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        let hull = support_map_hull(self.as_support_map()?, nsubdivs)?;
+        Some(hull.to_trimesh())
+    }
+
+    /// The number of vertices, edges, and faces of this shape, in this order.
+    ///
+    /// In 2D, a closed polygonal shape is considered to have a single face. Curved shapes,
+    /// infinite shapes, and shapes with features that are not enumerated return `(0, 0, 0)`.
+    /// The features of a composite shape are the sum of the features of its parts.
+    fn num_features(&self) -> (usize, usize, usize) {
+        (0, 0, 0)
+    }
+
+    /// Calls `f` on each vertex, edge, and face of this shape, with its feature identifier.
+    ///
+    /// The points given to `f` are the vertices of the feature: the vertex itself, the two
+    /// endpoints of an edge, or the vertices of a face counterclockwise around its outward
+    /// normal. In 2D, the sides of a polygonal shape are identified as faces and given by their
+    /// endpoints in counterclockwise order along the boundary, while the faces of a 2D triangle
+    /// mesh are its triangles. The feature identifiers are the ones returned by the point
+    /// projections on this shape.
+    ///
+    /// Shapes without enumerable features, e.g., curved shapes or compound shapes, never call `f`.
+    fn for_each_feature(&self, _f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {}
+
     /// The shape's normal at the given point located on a specific feature.
     fn feature_normal_at_point(
         &self,
@@ -141,6 +506,12 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
 
 impl_downcast!(sync Shape);
 
+impl Clone for Box<dyn Shape> {
+    fn clone(&self) -> Self {
+        self.clone_dyn()
+    }
+}
+
 impl dyn Shape {
     /// Converts this abstract shape to the given shape, if it is one.
     pub fn as_shape<T: Shape>(&self) -> Option<&T> {
@@ -162,6 +533,11 @@ impl dyn Shape {
         self.downcast_ref()
     }
 
+    /// Converts this abstract shape to a tapered capsule, if it is one.
+    pub fn as_tapered_capsule(&self) -> Option<&TaperedCapsule> {
+        self.downcast_ref()
+    }
+
     /// Converts this abstract shape to a triangle, if it is one.
     pub fn as_triangle(&self) -> Option<&Triangle> {
         self.downcast_ref()
@@ -197,11 +573,49 @@ impl dyn Shape {
         self.downcast_ref()
     }
 
+    /// Converts this abstract shape to a round triangle mesh, if it is one.
+    pub fn as_round_trimesh(&self) -> Option<&RoundTriMesh> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a round polyline, if it is one.
+    pub fn as_round_polyline(&self) -> Option<&RoundPolyline> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a shape described by a signed distance function, if it is one.
+    pub fn as_sdf_shape(&self) -> Option<&SdfShape> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a set of voxels, if it is one.
+    pub fn as_voxels(&self) -> Option<&Voxels> {
+        self.downcast_ref()
+    }
+
     #[cfg(feature = "dim2")]
     pub fn as_convex_polygon(&self) -> Option<&ConvexPolygon> {
         self.downcast_ref()
     }
 
+    /// Converts this abstract shape to an annulus, if it is one.
+    #[cfg(feature = "dim2")]
+    pub fn as_annulus(&self) -> Option<&Annulus> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a sector, if it is one.
+    #[cfg(feature = "dim2")]
+    pub fn as_sector(&self) -> Option<&Sector> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a polygon, if it is one.
+    #[cfg(feature = "dim2")]
+    pub fn as_polygon(&self) -> Option<&Polygon> {
+        self.downcast_ref()
+    }
+
     /// Converts this abstract shape to a round convex polygon, if it is one.
     #[cfg(feature = "dim2")]
     pub fn as_round_convex_polygon(&self) -> Option<&RoundConvexPolygon> {
@@ -225,6 +639,48 @@ impl dyn Shape {
         self.downcast_ref()
     }
 
+    /// Converts this abstract shape to an ellipsoid, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_ellipsoid(&self) -> Option<&Ellipsoid> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to an elliptic cylinder, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_elliptic_cylinder(&self) -> Option<&EllipticCylinder> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a plate, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_plate(&self) -> Option<&Plate> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a prism, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_prism(&self) -> Option<&Prism> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a solid of revolution, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_solid_of_revolution(&self) -> Option<&SolidOfRevolution> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a spherical sector, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_spherical_sector(&self) -> Option<&SphericalSector> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a torus, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_torus(&self) -> Option<&Torus> {
+        self.downcast_ref()
+    }
+
     /// Converts this abstract shape to a round cylinder, if it is one.
     #[cfg(feature = "dim3")]
     pub fn as_round_cylinder(&self) -> Option<&RoundCylinder> {
@@ -242,6 +698,82 @@ impl dyn Shape {
     pub fn as_round_convex_polyhedron(&self) -> Option<&RoundConvexPolyhedron> {
         self.downcast_ref()
     }
+
+    /// Converts this abstract shape to a round shape with a base shape of any type, if it is one.
+    pub fn as_round_shared_shape(&self) -> Option<&RoundSharedShape> {
+        self.downcast_ref()
+    }
+
+    /// Converts this abstract shape to a reference to its concrete type.
+    pub fn as_typed_shape(&self) -> TypedShape<'_> {
+        let typed = match self.shape_type() {
+            ShapeType::Ball => self.downcast_ref().map(TypedShape::Ball),
+            ShapeType::Cuboid => self.downcast_ref().map(TypedShape::Cuboid),
+            ShapeType::Capsule => self.downcast_ref().map(TypedShape::Capsule),
+            ShapeType::TaperedCapsule => self.downcast_ref().map(TypedShape::TaperedCapsule),
+            ShapeType::Segment => self.downcast_ref().map(TypedShape::Segment),
+            ShapeType::Triangle => self.downcast_ref().map(TypedShape::Triangle),
+            ShapeType::TriMesh => self.downcast_ref().map(TypedShape::TriMesh),
+            ShapeType::Polyline => self.downcast_ref().map(TypedShape::Polyline),
+            ShapeType::HalfSpace => self.downcast_ref().map(TypedShape::HalfSpace),
+            ShapeType::Slab => self.downcast_ref().map(TypedShape::Slab),
+            ShapeType::HeightField => self.downcast_ref().map(TypedShape::HeightField),
+            ShapeType::Compound => self.downcast_ref().map(TypedShape::Compound),
+            #[cfg(feature = "dim2")]
+            ShapeType::ConvexPolygon => self.downcast_ref().map(TypedShape::ConvexPolygon),
+            #[cfg(feature = "dim2")]
+            ShapeType::Annulus => self.downcast_ref().map(TypedShape::Annulus),
+            #[cfg(feature = "dim2")]
+            ShapeType::Sector => self.downcast_ref().map(TypedShape::Sector),
+            #[cfg(feature = "dim2")]
+            ShapeType::Polygon => self.downcast_ref().map(TypedShape::Polygon),
+            #[cfg(feature = "dim3")]
+            ShapeType::ConvexPolyhedron => self.downcast_ref().map(TypedShape::ConvexPolyhedron),
+            #[cfg(feature = "dim3")]
+            ShapeType::Cylinder => self.downcast_ref().map(TypedShape::Cylinder),
+            #[cfg(feature = "dim3")]
+            ShapeType::Cone => self.downcast_ref().map(TypedShape::Cone),
+            #[cfg(feature = "dim3")]
+            ShapeType::Torus => self.downcast_ref().map(TypedShape::Torus),
+            #[cfg(feature = "dim3")]
+            ShapeType::Ellipsoid => self.downcast_ref().map(TypedShape::Ellipsoid),
+            #[cfg(feature = "dim3")]
+            ShapeType::EllipticCylinder => self.downcast_ref().map(TypedShape::EllipticCylinder),
+            #[cfg(feature = "dim3")]
+            ShapeType::Plate => self.downcast_ref().map(TypedShape::Plate),
+            #[cfg(feature = "dim3")]
+            ShapeType::Prism => self.downcast_ref().map(TypedShape::Prism),
+            #[cfg(feature = "dim3")]
+            ShapeType::SolidOfRevolution => self.downcast_ref().map(TypedShape::SolidOfRevolution),
+            #[cfg(feature = "dim3")]
+            ShapeType::SphericalSector => self.downcast_ref().map(TypedShape::SphericalSector),
+            ShapeType::SdfShape => self.downcast_ref().map(TypedShape::SdfShape),
+            ShapeType::Voxels => self.downcast_ref().map(TypedShape::Voxels),
+            ShapeType::ScaledShape => Some(TypedShape::ScaledShape(self)),
+            ShapeType::MinkowskiSum => Some(TypedShape::MinkowskiSum(self)),
+            ShapeType::TransformedShape => Some(TypedShape::TransformedShape(self)),
+            ShapeType::Custom(_) => None,
+            ShapeType::RoundCuboid => self.downcast_ref().map(TypedShape::RoundCuboid),
+            ShapeType::RoundTriangle => self.downcast_ref().map(TypedShape::RoundTriangle),
+            ShapeType::RoundTriMesh => self.downcast_ref().map(TypedShape::RoundTriMesh),
+            ShapeType::RoundPolyline => self.downcast_ref().map(TypedShape::RoundPolyline),
+            #[cfg(feature = "dim3")]
+            ShapeType::RoundCylinder => self.downcast_ref().map(TypedShape::RoundCylinder),
+            #[cfg(feature = "dim3")]
+            ShapeType::RoundCone => self.downcast_ref().map(TypedShape::RoundCone),
+            #[cfg(feature = "dim3")]
+            ShapeType::RoundConvexPolyhedron => {
+                self.downcast_ref().map(TypedShape::RoundConvexPolyhedron)
+            }
+            #[cfg(feature = "dim2")]
+            ShapeType::RoundConvexPolygon => {
+                self.downcast_ref().map(TypedShape::RoundConvexPolygon)
+            }
+            ShapeType::RoundSharedShape => self.downcast_ref().map(TypedShape::RoundSharedShape),
+        };
+
+        typed.unwrap_or(TypedShape::Custom(self))
+    }
 }
 
 impl Shape for Ball {
@@ -250,6 +782,10 @@ impl Shape for Ball {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -258,14 +794,41 @@ impl Shape for Ball {
         self.aabb(position)
     }
 
+    fn local_aabb_half_extents(&self) -> Vector<Real> {
+        Vector::repeat(self.radius)
+    }
+
+    fn local_aabb_center(&self) -> Point<Real> {
+        Point::origin()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_ball(density, self.radius)
     }
 
+    fn surface_area(&self) -> Real {
+        #[cfg(feature = "dim2")]
+        return Real::two_pi() * self.radius;
+        #[cfg(feature = "dim3")]
+        return Real::pi() * self.radius * self.radius * 4.0;
+    }
+
     fn ccd_thickness(&self) -> Real {
         self.radius
     }
 
+    fn ccd_angular_thickness(&self) -> Real {
+        Real::MAX
+    }
+
     fn is_convex(&self) -> bool {
         true
     }
@@ -277,26 +840,25 @@ impl Shape for Ball {
     fn as_support_map(&self) -> Option<&dyn SupportMap> {
         Some(self as &dyn SupportMap)
     }
-}
 
-// impl Shape for Polygon {
-//     #[cfg(feature = "serde-serialize")]
-//     fn as_serialize(&self) -> Option<&dyn Serialize> {
-//         Some(self as &dyn Serialize)
-//     }
-//
-//     fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
-//         self.aabb(position)
-//     }
-//
-//     fn mass_properties(&self, _density: Real) -> MassProperties {
-//         unimplemented!()
-//     }
-//
-//     fn shape_type(&self) -> ShapeType {
-//         ShapeType::Polygon
-//     }
-// }
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        Some(closed_polyline(self.to_polyline(nsubdivs)))
+    }
+
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        Some(self.to_trimesh(nsubdivs, (nsubdivs / 2).max(2)))
+    }
+}
 
 impl Shape for Cuboid {
     #[cfg(feature = "serde-serialize")]
@@ -304,6 +866,10 @@ impl Shape for Cuboid {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -312,10 +878,26 @@ impl Shape for Cuboid {
         self.aabb(position)
     }
 
+    fn local_aabb_half_extents(&self) -> Vector<Real> {
+        self.half_extents
+    }
+
+    fn local_aabb_center(&self) -> Point<Real> {
+        Point::origin()
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_cuboid(density, self.half_extents)
     }
 
+    fn surface_area(&self) -> Real {
+        let he = self.half_extents;
+        #[cfg(feature = "dim2")]
+        return (he.x + he.y) * 4.0;
+        #[cfg(feature = "dim3")]
+        return (he.x * he.y + he.y * he.z + he.z * he.x) * 8.0;
+    }
+
     fn is_convex(&self) -> bool {
         true
     }
@@ -328,6 +910,10 @@ impl Shape for Cuboid {
         self.half_extents.min()
     }
 
+    fn ccd_angular_thickness(&self) -> Real {
+        Real::frac_pi_2()
+    }
+
     fn as_support_map(&self) -> Option<&dyn SupportMap> {
         Some(self as &dyn SupportMap)
     }
@@ -335,6 +921,86 @@ impl Shape for Cuboid {
     fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
         Some((self as &dyn PolygonalFeatureMap, 0.0))
     }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        #[cfg(feature = "dim2")]
+        return (4, 4, 1);
+        #[cfg(feature = "dim3")]
+        return (8, 12, 6);
+    }
+
+    fn for_each_feature(&self, f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {
+        // The bit `i` of the id of a vertex is set if its `i`-th coordinate is negative.
+        let he = self.half_extents;
+        let vertex = |id: u32| {
+            Point::from(Vector::from_fn(|i, _| {
+                if id & (1 << i) == 0 {
+                    he[i]
+                } else {
+                    -he[i]
+                }
+            }))
+        };
+
+        for id in 0..1 << DIM {
+            f(FeatureId::Vertex(id), &[vertex(id)]);
+        }
+
+        #[cfg(feature = "dim2")]
+        {
+            // The sides with the outward normals `+x`, `+y`, `-x`, and `-y`.
+            let sides = [[0b10, 0b00], [0b00, 0b01], [0b01, 0b11], [0b11, 0b10]];
+
+            for (i, side) in sides.iter().enumerate() {
+                f(
+                    FeatureId::Face(i as u32),
+                    &[vertex(side[0]), vertex(side[1])],
+                );
+            }
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            for i in 0..3 {
+                let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+
+                // The edges parallel to the `i`-th axis.
+                for signs in 0..4 {
+                    let id = ((signs & 1) << j) | ((signs >> 1) << k);
+                    f(
+                        FeatureId::Edge((id << 2) | i as u32),
+                        &[vertex(id), vertex(id | (1 << i))],
+                    );
+                }
+
+                // The faces with the outward normals `+e_i` and `-e_i`, with their vertices
+                // counterclockwise in the `(e_j, e_k)` plane.
+                let ring = [0, 1 << j, (1 << j) | (1 << k), 1 << k];
+                let pos: Vec<_> = ring.iter().map(|id| vertex(*id)).collect();
+                let neg: Vec<_> = ring.iter().rev().map(|id| vertex(*id | (1 << i))).collect();
+                f(FeatureId::Face(i as u32), &pos);
+                f(FeatureId::Face(i as u32 + 3), &neg);
+            }
+        }
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some(closed_polyline(self.to_polyline()))
+    }
+
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some(self.to_trimesh())
+    }
 }
 
 impl Shape for Capsule {
@@ -343,6 +1009,10 @@ impl Shape for Capsule {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -351,10 +1021,25 @@ impl Shape for Capsule {
         self.aabb(position)
     }
 
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_capsule(density, self.segment.a, self.segment.b, self.radius)
     }
 
+    fn surface_area(&self) -> Real {
+        #[cfg(feature = "dim2")]
+        return (self.height() + Real::pi() * self.radius) * 2.0;
+        #[cfg(feature = "dim3")]
+        return Real::two_pi() * self.radius * (self.height() + self.radius * 2.0);
+    }
+
     fn is_convex(&self) -> bool {
         true
     }
@@ -374,7 +1059,86 @@ impl Shape for Capsule {
     fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
         Some((&self.segment as &dyn PolygonalFeatureMap, self.radius))
     }
-}
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        Some(closed_polyline(self.to_polyline((nsubdivs / 2).max(1))))
+    }
+
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        Some(self.to_trimesh(nsubdivs, (nsubdivs / 2).max(2)))
+    }
+}
+
+impl Shape for TaperedCapsule {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_tapered_capsule(
+            density,
+            self.segment.a,
+            self.segment.b,
+            self.radius_a,
+            self.radius_b,
+        )
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::TaperedCapsule
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radius_a.min(self.radius_b)
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+}
 
 impl Shape for Triangle {
     #[cfg(feature = "serde-serialize")]
@@ -382,6 +1146,10 @@ impl Shape for Triangle {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -397,6 +1165,13 @@ impl Shape for Triangle {
         return MassProperties::zero();
     }
 
+    fn surface_area(&self) -> Real {
+        #[cfg(feature = "dim2")]
+        return self.perimeter();
+        #[cfg(feature = "dim3")]
+        return self.area();
+    }
+
     fn is_convex(&self) -> bool {
         true
     }
@@ -406,7 +1181,21 @@ impl Shape for Triangle {
     }
 
     fn ccd_thickness(&self) -> Real {
-        // TODO: in 2D use the smallest height of the triangle.
+        // The smallest height of the triangle is the one orthogonal to its longest edge.
+        #[cfg(feature = "dim2")]
+        {
+            let longest_edge = na::distance(&self.a, &self.b)
+                .max(na::distance(&self.b, &self.c))
+                .max(na::distance(&self.c, &self.a));
+
+            if longest_edge == 0.0 {
+                0.0
+            } else {
+                self.area() * 2.0 / longest_edge
+            }
+        }
+        // A 3D triangle has no thickness.
+        #[cfg(feature = "dim3")]
         0.0
     }
 
@@ -417,6 +1206,66 @@ impl Shape for Triangle {
     fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
         Some((self as &dyn PolygonalFeatureMap, 0.0))
     }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        (3, 3, 1)
+    }
+
+    fn for_each_feature(&self, f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {
+        let vertices = self.vertices();
+
+        for (i, pt) in vertices.iter().enumerate() {
+            f(FeatureId::Vertex(i as u32), &[*pt]);
+        }
+
+        #[cfg(feature = "dim2")]
+        {
+            let ccw = (self.b - self.a).perp(&(self.c - self.a)) >= 0.0;
+
+            for i in 0..3 {
+                let (a, b) = (vertices[i], vertices[(i + 1) % 3]);
+                f(
+                    FeatureId::Face(i as u32),
+                    &if ccw { [a, b] } else { [b, a] },
+                );
+            }
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            for i in 0..3 {
+                f(
+                    FeatureId::Edge(i as u32),
+                    &[vertices[i], vertices[(i + 1) % 3]],
+                );
+            }
+
+            f(FeatureId::Face(0), &[self.a, self.b, self.c]);
+            f(FeatureId::Face(1), &[self.a, self.c, self.b]);
+        }
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, _nsubdivs: u32) -> Option<Discretization> {
+        if (self.b - self.a).perp(&(self.c - self.a)) < 0.0 {
+            Some(closed_polyline(vec![self.a, self.c, self.b]))
+        } else {
+            Some(closed_polyline(vec![self.a, self.b, self.c]))
+        }
+    }
+
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some((vec![self.a, self.b, self.c], vec![[0, 1, 2]]))
+    }
 }
 
 impl Shape for Segment {
@@ -425,6 +1274,10 @@ impl Shape for Segment {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -437,6 +1290,13 @@ impl Shape for Segment {
         MassProperties::zero()
     }
 
+    fn surface_area(&self) -> Real {
+        #[cfg(feature = "dim2")]
+        return self.length();
+        #[cfg(feature = "dim3")]
+        return 0.0;
+    }
+
     fn is_convex(&self) -> bool {
         true
     }
@@ -456,6 +1316,40 @@ impl Shape for Segment {
     fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
         Some((self as &dyn PolygonalFeatureMap, 0.0))
     }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        (2, 1, 0)
+    }
+
+    fn for_each_feature(&self, f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {
+        f(FeatureId::Vertex(0), &[self.a]);
+        f(FeatureId::Vertex(1), &[self.b]);
+        #[cfg(feature = "dim2")]
+        {
+            f(FeatureId::Face(0), &[self.a, self.b]);
+            f(FeatureId::Face(1), &[self.b, self.a]);
+        }
+        #[cfg(feature = "dim3")]
+        f(FeatureId::Edge(0), &[self.a, self.b]);
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some((vec![self.a, self.b], vec![[0, 1]]))
+    }
+
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, _nsubdivs: u32) -> Option<Discretization> {
+        None
+    }
 }
 
 impl Shape for Compound {
@@ -464,6 +1358,10 @@ impl Shape for Compound {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         *self.local_aabb()
     }
@@ -476,6 +1374,13 @@ impl Shape for Compound {
         MassProperties::from_compound(density, self.shapes())
     }
 
+    fn surface_area(&self) -> Real {
+        self.shapes()
+            .iter()
+            .map(|(_, part)| part.surface_area())
+            .sum()
+    }
+
     fn shape_type(&self) -> ShapeType {
         ShapeType::Compound
     }
@@ -486,9 +1391,82 @@ impl Shape for Compound {
             .fold(Real::MAX, |curr, (_, s)| curr.min(s.ccd_thickness()))
     }
 
+    fn ccd_angular_thickness(&self) -> Real {
+        self.shapes().iter().fold(Real::MAX, |curr, (_, s)| {
+            curr.min(s.ccd_angular_thickness())
+        })
+    }
+
     fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
         Some(self as &dyn SimdCompositeShape)
     }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        let shapes = self
+            .shapes()
+            .iter()
+            .map(|(pos, shape)| {
+                let mirrored = shape.mirrored(axis)?;
+                let pos = mirror::mirror_isometry(pos, axis);
+
+                // Shapes reflected with a change of pose are wrapped into a single-part
+                // compound, which can't be nested into this one: fold its pose instead.
+                match mirrored.as_compound().map(|c| c.shapes()) {
+                    Some([(part_pos, part)]) => Some((pos * part_pos, part.clone())),
+                    _ => Some((pos, SharedShape(mirrored.into()))),
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Box::new(Compound::new(shapes).with_attributes_of(self)))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        let uniform = scale::is_uniform(scale);
+        let shapes = self
+            .shapes()
+            .iter()
+            .map(|(pos, shape)| {
+                // A non-uniform scaling of a rotated sub-shape can't be
+                // expressed as a scaling of its own local-space.
+                if !uniform && pos.rotation != Rotation::identity() {
+                    return None;
+                }
+
+                let scaled = shape.scaled(scale, nsubdivs)?;
+                let translation = pos.translation.vector.component_mul(scale);
+                Some((
+                    Isometry::from_parts(translation.into(), pos.rotation),
+                    SharedShape(scaled.into()),
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Box::new(Compound::new(shapes).with_attributes_of(self)))
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        self.shapes().iter().fold((0, 0, 0), |acc, (_, shape)| {
+            let (nv, ne, nf) = shape.num_features();
+            (acc.0 + nv, acc.1 + ne, acc.2 + nf)
+        })
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        merge_discretizations(
+            self.shapes()
+                .iter()
+                .map(|(pos, part)| (*pos, discretize(&**part, nsubdivs))),
+        )
+    }
+
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        merge_discretizations(
+            self.shapes()
+                .iter()
+                .map(|(pos, part)| (*pos, discretize(&**part, nsubdivs))),
+        )
+    }
 }
 
 impl Shape for Polyline {
@@ -497,6 +1475,10 @@ impl Shape for Polyline {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         *self.local_aabb()
     }
@@ -509,6 +1491,13 @@ impl Shape for Polyline {
         MassProperties::zero()
     }
 
+    fn surface_area(&self) -> Real {
+        #[cfg(feature = "dim2")]
+        return self.segments().map(|seg| seg.length()).sum();
+        #[cfg(feature = "dim3")]
+        return 0.0;
+    }
+
     fn shape_type(&self) -> ShapeType {
         ShapeType::Polyline
     }
@@ -520,6 +1509,23 @@ impl Shape for Polyline {
     fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
         Some(self as &dyn SimdCompositeShape)
     }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        (self.vertices().len(), self.num_segments(), 0)
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some((self.vertices().to_vec(), self.indices().to_vec()))
+    }
 }
 
 impl Shape for TriMesh {
@@ -528,6 +1534,10 @@ impl Shape for TriMesh {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         *self.local_aabb()
     }
@@ -543,54 +1553,130 @@ impl Shape for TriMesh {
         return MassProperties::zero();
     }
 
+    fn volume(&self) -> Real {
+        #[cfg(feature = "dim2")]
+        return self.triangles().map(|tri| tri.area()).sum();
+        #[cfg(feature = "dim3")]
+        {
+            if !self.boundary_edges().is_empty() {
+                return 0.0;
+            }
+
+            // Divergence theorem: sum the signed volumes of the tetrahedra joining the origin
+            // to each triangle. The absolute value makes this independent from the winding.
+            let volume6: Real = self
+                .triangles()
+                .map(|tri| tri.a.coords.dot(&tri.b.coords.cross(&tri.c.coords)))
+                .sum();
+            volume6.abs() / 6.0
+        }
+    }
+
+    fn surface_area(&self) -> Real {
+        #[cfg(feature = "dim2")]
+        return self
+            .boundary_edges()
+            .iter()
+            .map(|e| {
+                na::distance(
+                    &self.vertices()[e[0] as usize],
+                    &self.vertices()[e[1] as usize],
+                )
+            })
+            .sum();
+        #[cfg(feature = "dim3")]
+        return self.triangles().map(|tri| tri.area()).sum();
+    }
+
     fn shape_type(&self) -> ShapeType {
         ShapeType::TriMesh
     }
 
     fn ccd_thickness(&self) -> Real {
-        // TODO: in 2D, return the smallest CCD thickness among triangles?
-        0.0
+        #[cfg(feature = "dim2")]
+        return self
+            .triangles()
+            .fold(Real::MAX, |curr, tri| curr.min(tri.ccd_thickness()));
+        #[cfg(feature = "dim3")]
+        return self.ccd_thickness;
     }
 
     fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
         Some(self as &dyn SimdCompositeShape)
     }
-}
 
-impl Shape for HeightField {
-    #[cfg(feature = "serde-serialize")]
-    fn as_serialize(&self) -> Option<&dyn Serialize> {
-        Some(self as &dyn Serialize)
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
     }
 
-    fn compute_local_aabb(&self) -> AABB {
-        self.local_aabb()
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
     }
 
-    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
-        self.aabb(position)
-    }
+    fn num_features(&self) -> (usize, usize, usize) {
+        // Edges shared by several triangles are counted only once.
+        let edges: HashSet<_> = self
+            .indices()
+            .iter()
+            .flat_map(|idx| {
+                (0..3).map(move |i| {
+                    let (a, b) = (idx[i], idx[(i + 1) % 3]);
+                    (a.min(b), a.max(b))
+                })
+            })
+            .collect();
+        (self.vertices().len(), edges.len(), self.num_triangles())
+    }
+
+    fn for_each_feature(&self, f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {
+        for (i, pt) in self.vertices().iter().enumerate() {
+            f(FeatureId::Vertex(i as u32), &[*pt]);
+        }
 
-    fn mass_properties(&self, _density: Real) -> MassProperties {
-        MassProperties::zero()
+        // Each edge is identified by `3 * i + k`, where `i` is the first triangle containing it
+        // and `k` its index in this triangle.
+        #[cfg(feature = "dim3")]
+        {
+            let mut edges = HashSet::new();
+
+            for (i, idx) in self.indices().iter().enumerate() {
+                for k in 0..3 {
+                    let (a, b) = (idx[k], idx[(k + 1) % 3]);
+
+                    if edges.insert((a.min(b), a.max(b))) {
+                        let vertices = [self.vertices()[a as usize], self.vertices()[b as usize]];
+                        f(FeatureId::Edge(3 * i as u32 + k as u32), &vertices);
+                    }
+                }
+            }
+        }
+
+        for (i, tri) in self.triangles().enumerate() {
+            f(FeatureId::Face(i as u32), &[tri.a, tri.b, tri.c]);
+        }
     }
 
-    fn shape_type(&self) -> ShapeType {
-        ShapeType::HeightField
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some((self.vertices().to_vec(), self.boundary_edges()))
     }
 
-    fn ccd_thickness(&self) -> Real {
-        0.0
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some((self.vertices().to_vec(), self.indices().to_vec()))
     }
 }
 
-#[cfg(feature = "dim2")]
-impl Shape for ConvexPolygon {
+impl Shape for RoundPolyline {
     #[cfg(feature = "serde-serialize")]
     fn as_serialize(&self) -> Option<&dyn Serialize> {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -600,38 +1686,44 @@ impl Shape for ConvexPolygon {
     }
 
     fn mass_properties(&self, density: Real) -> MassProperties {
-        MassProperties::from_convex_polygon(density, &self.points())
-    }
-
-    fn is_convex(&self) -> bool {
-        true
+        self.polyline().mass_properties(density)
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::ConvexPolygon
+        ShapeType::RoundPolyline
     }
 
     fn ccd_thickness(&self) -> Real {
-        // TODO: we should use the OBB instead.
-        self.compute_local_aabb().half_extents().min()
+        self.border_radius()
     }
 
-    fn as_support_map(&self) -> Option<&dyn SupportMap> {
-        Some(self as &dyn SupportMap)
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        Some(self as &dyn SimdCompositeShape)
     }
 
-    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
-        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        self.polyline().num_features()
     }
 }
 
-#[cfg(feature = "dim3")]
-impl Shape for ConvexPolyhedron {
+impl Shape for RoundTriMesh {
     #[cfg(feature = "serde-serialize")]
     fn as_serialize(&self) -> Option<&dyn Serialize> {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -641,39 +1733,44 @@ impl Shape for ConvexPolyhedron {
     }
 
     fn mass_properties(&self, density: Real) -> MassProperties {
-        let (vertices, indices) = self.to_trimesh();
-        MassProperties::from_convex_polyhedron(density, &vertices, &indices)
-    }
-
-    fn is_convex(&self) -> bool {
-        true
+        self.trimesh().mass_properties(density)
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::ConvexPolyhedron
+        ShapeType::RoundTriMesh
     }
 
     fn ccd_thickness(&self) -> Real {
-        // TODO: we should use the OBB instead.
-        self.compute_local_aabb().half_extents().min()
+        self.trimesh().ccd_thickness() + self.border_radius()
     }
 
-    fn as_support_map(&self) -> Option<&dyn SupportMap> {
-        Some(self as &dyn SupportMap)
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        Some(self as &dyn SimdCompositeShape)
     }
 
-    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
-        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        self.trimesh().num_features()
     }
 }
 
-#[cfg(feature = "dim3")]
-impl Shape for Cylinder {
+impl Shape for HeightField {
     #[cfg(feature = "serde-serialize")]
     fn as_serialize(&self) -> Option<&dyn Serialize> {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -682,38 +1779,1231 @@ impl Shape for Cylinder {
         self.aabb(position)
     }
 
-    fn mass_properties(&self, density: Real) -> MassProperties {
-        MassProperties::from_cylinder(density, self.half_height, self.radius)
+    fn mass_properties(&self, _density: Real) -> MassProperties {
+        MassProperties::zero()
     }
 
-    fn is_convex(&self) -> bool {
-        true
+    fn surface_area(&self) -> Real {
+        #[cfg(feature = "dim2")]
+        return self.segments().map(|seg| seg.length()).sum();
+        #[cfg(feature = "dim3")]
+        return self.triangles().map(|tri| tri.area()).sum();
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::Cylinder
+        ShapeType::HeightField
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
     }
 
     fn ccd_thickness(&self) -> Real {
-        self.radius
+        self.ccd_thickness
     }
 
-    fn as_support_map(&self) -> Option<&dyn SupportMap> {
-        Some(self as &dyn SupportMap)
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        merge_discretizations(
+            self.segments()
+                .map(|seg| (Isometry::identity(), seg.to_polyline(nsubdivs))),
+        )
     }
 
-    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
-        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some(self.to_trimesh())
     }
 }
 
-#[cfg(feature = "dim3")]
-impl Shape for Cone {
+impl Shape for SdfShape {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        *self.aabb()
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        // Split the AABB into roughly 64 cells along its largest dimension.
+        let cell_size = self.aabb().extents().max() / 64.0;
+        crate::transformation::mass_properties_by_voxelization(self, density, cell_size)
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::SdfShape
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.aabb().half_extents().min()
+    }
+}
+
+impl Shape for Voxels {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        *self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_voxels(density, *self.voxel_size(), self.keys())
+    }
+
+    fn surface_area(&self) -> Real {
+        let size = self.voxel_size();
+        let mut area = 0.0;
+
+        for i in 0..DIM {
+            // The area of a voxel face orthogonal to the `i`-th axis.
+            let face_area: Real = (0..DIM).filter(|j| *j != i).map(|j| size[j]).product();
+
+            for key in self.keys() {
+                for shift in [-1, 1].iter() {
+                    let mut neighbor = *key;
+                    neighbor[i] += *shift;
+
+                    if !self.contains_voxel(&neighbor) {
+                        area += face_area;
+                    }
+                }
+            }
+        }
+
+        area
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Voxels
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.voxel_size().min() / 2.0
+    }
+
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        Some(self as &dyn SimdCompositeShape)
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        let voxel = self.voxel_shape();
+        merge_discretizations(
+            (0..self.num_voxels() as u32)
+                .map(|i| (self.voxel_position(i), discretize(&voxel, nsubdivs))),
+        )
+    }
+
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        let voxel = self.voxel_shape();
+        merge_discretizations(
+            (0..self.num_voxels() as u32)
+                .map(|i| (self.voxel_position(i), discretize(&voxel, nsubdivs))),
+        )
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Shape for Annulus {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_annulus(density, self.inner_radius, self.outer_radius)
+    }
+
+    fn surface_area(&self) -> Real {
+        Real::two_pi() * (self.inner_radius + self.outer_radius)
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Annulus
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.outer_radius - self.inner_radius
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        // The inner circle is traversed clockwise so the interior stays on the left.
+        let (outer, outer_indices) =
+            closed_polyline(Ball::new(self.outer_radius).to_polyline(nsubdivs));
+        let mut inner = Ball::new(self.inner_radius).to_polyline(nsubdivs);
+        inner.reverse();
+        merge_discretizations(
+            vec![
+                (Isometry::identity(), Some((outer, outer_indices))),
+                (Isometry::identity(), Some(closed_polyline(inner))),
+            ]
+            .into_iter(),
+        )
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Shape for Sector {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_sector(density, self.radius, self.start_angle, self.end_angle)
+    }
+
+    fn surface_area(&self) -> Real {
+        let arc = self.radius * self.angle();
+
+        if self.angle() < Real::two_pi() {
+            arc + self.radius * 2.0
+        } else {
+            arc
+        }
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Sector
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        // The radius of the largest disk inscribed in the sector.
+        let half_angle = self.angle().min(Real::pi()) / 2.0;
+        let sin = ComplexField::sin(half_angle);
+        self.radius * sin / (1.0 + sin)
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        Some(closed_polyline(self.to_polyline(nsubdivs)))
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Shape for Polygon {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        *self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        let triangulation = self.triangulation();
+        MassProperties::from_trimesh(density, triangulation.vertices(), triangulation.indices())
+    }
+
+    fn volume(&self) -> Real {
+        self.area()
+    }
+
+    fn surface_area(&self) -> Real {
+        self.boundary().segments().map(|seg| seg.length()).sum()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Polygon
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        0.0
+    }
+
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        Some(self.triangulation() as &dyn SimdCompositeShape)
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        let boundary = self.boundary();
+        (boundary.vertices().len(), boundary.num_segments(), 1)
+    }
+
+    fn for_each_feature(&self, f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {
+        for (i, pt) in self.vertices().iter().enumerate() {
+            f(FeatureId::Vertex(i as u32), &[*pt]);
+        }
+
+        for (i, seg) in self.boundary().segments().enumerate() {
+            f(FeatureId::Face(i as u32), &[seg.a, seg.b]);
+        }
+    }
+
+    fn to_polyline(&self, _nsubdivs: u32) -> Option<Discretization> {
+        let boundary = self.boundary();
+        Some((boundary.vertices().to_vec(), boundary.indices().to_vec()))
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl Shape for ConvexPolygon {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_convex_polygon(density, &self.points())
+    }
+
+    fn surface_area(&self) -> Real {
+        let points = self.points();
+        (0..points.len())
+            .map(|i| na::distance(&points[i], &points[(i + 1) % points.len()]))
+            .sum()
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::ConvexPolygon
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        // TODO: we should use the OBB instead.
+        self.local_aabb_half_extents().min()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        (self.points().len(), self.points().len(), 1)
+    }
+
+    fn for_each_feature(&self, f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {
+        let points = self.points();
+
+        for (i, pt) in points.iter().enumerate() {
+            f(FeatureId::Vertex(i as u32), &[*pt]);
+        }
+
+        for i in 0..points.len() {
+            f(
+                FeatureId::Face(i as u32),
+                &[points[i], points[(i + 1) % points.len()]],
+            );
+        }
+    }
+
+    fn to_polyline(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some(closed_polyline(self.points().to_vec()))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for ConvexPolyhedron {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        let (vertices, indices) = self.to_trimesh();
+        MassProperties::from_convex_polyhedron(density, &vertices, &indices)
+    }
+
+    fn surface_area(&self) -> Real {
+        let (vertices, indices) = self.to_trimesh();
+        indices
+            .iter()
+            .map(|idx| {
+                Triangle::new(
+                    vertices[idx[0] as usize],
+                    vertices[idx[1] as usize],
+                    vertices[idx[2] as usize],
+                )
+                .area()
+            })
+            .sum()
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::ConvexPolyhedron
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        // TODO: we should use the OBB instead.
+        self.local_aabb_half_extents().min()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        // Edges between coplanar triangles merged into a single face are not
+        // features of the polyhedron, and each edge is adjacent to two faces.
+        let num_edges = self.edges_adj_to_face().len() / 2;
+        (self.points().len(), num_edges, self.faces().len())
+    }
+
+    fn for_each_feature(&self, f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {
+        let points = self.points();
+
+        for (i, pt) in points.iter().enumerate() {
+            f(FeatureId::Vertex(i as u32), &[*pt]);
+        }
+
+        // Edges between coplanar triangles merged into a single face are not features of the
+        // polyhedron, and don't appear on the boundary of any face.
+        let mut edge_ids = self.edges_adj_to_face().to_vec();
+        edge_ids.sort_unstable();
+        edge_ids.dedup();
+
+        for i in edge_ids {
+            let edge = &self.edges()[i as usize];
+            f(
+                FeatureId::Edge(i),
+                &[
+                    points[edge.vertices[0] as usize],
+                    points[edge.vertices[1] as usize],
+                ],
+            );
+        }
+
+        let mut vertices = Vec::new();
+
+        for (i, face) in self.faces().iter().enumerate() {
+            let first = face.first_vertex_or_edge as usize;
+            let last = first + face.num_vertices_or_edges as usize;
+            vertices.clear();
+            vertices.extend(
+                self.vertices_adj_to_face()[first..last]
+                    .iter()
+                    .map(|id| points[*id as usize]),
+            );
+            f(FeatureId::Face(i as u32), &vertices);
+        }
+    }
+
+    fn to_trimesh(&self, _nsubdivs: u32) -> Option<Discretization> {
+        Some(self.to_trimesh())
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for Cylinder {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_cylinder(density, self.half_height, self.radius)
+    }
+
+    fn surface_area(&self) -> Real {
+        Real::two_pi() * self.radius * (self.radius + self.half_height * 2.0)
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Cylinder
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radius
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        Some(self.to_trimesh(nsubdivs))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for EllipticCylinder {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn local_aabb_half_extents(&self) -> Vector<Real> {
+        Vector::new(self.radii.x, self.half_height, self.radii.y)
+    }
+
+    fn local_aabb_center(&self) -> Point<Real> {
+        Point::origin()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_elliptic_cylinder(density, self.half_height, &self.radii)
+    }
+
+    fn surface_area(&self) -> Real {
+        let (a, b) = (self.radii.x, self.radii.y);
+        // Ramanujan's approximation of the perimeter of an ellipse.
+        let h = (a - b) * (a - b) / ((a + b) * (a + b));
+        let perimeter = Real::pi() * (a + b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()));
+        Real::two_pi() * a * b + perimeter * self.half_height * 2.0
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::EllipticCylinder
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radii.min()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for Ellipsoid {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn local_aabb_half_extents(&self) -> Vector<Real> {
+        self.radii
+    }
+
+    fn local_aabb_center(&self) -> Point<Real> {
+        Point::origin()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_ellipsoid(density, &self.radii)
+    }
+
+    fn surface_area(&self) -> Real {
+        // Knud Thomsen's approximation, with a relative error of at most 1.061%. It is exact
+        // for spheres.
+        let p = 1.6075;
+        let [a, b, c] = [
+            self.radii.x.powf(p),
+            self.radii.y.powf(p),
+            self.radii.z.powf(p),
+        ];
+        Real::pi() * 4.0 * ((a * b + a * c + b * c) / 3.0).powf(1.0 / p)
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Ellipsoid
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radii.min()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for Plate {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn local_aabb_half_extents(&self) -> Vector<Real> {
+        self.half_extents3()
+    }
+
+    fn local_aabb_center(&self) -> Point<Real> {
+        Point::origin()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, _density: Real) -> MassProperties {
+        MassProperties::zero()
+    }
+
+    fn surface_area(&self) -> Real {
+        self.half_extents.x * self.half_extents.y * 4.0
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Plate
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        // The plate has no thickness, so it must always be considered thin.
+        0.0
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        (4, 4, 1)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for Prism {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_prism(density, self.cross_section(), self.half_height())
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Prism
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        // The distance between the centroid of the cross-section and its closest edge.
+        let (_, centroid) = self.cross_section_area_and_centroid();
+        let inradius = self
+            .cross_section()
+            .iter()
+            .zip(self.cross_section_normals().iter())
+            .map(|(pt, normal)| normal.dot(&(pt - centroid)))
+            .fold(Real::MAX, Real::min);
+
+        inradius.min(self.half_height())
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        let n = self.cross_section().len();
+        (2 * n, 3 * n, n + 2)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for SolidOfRevolution {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_solid_of_revolution(density, self.profile())
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::SolidOfRevolution
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        let half_height = (self.max_height() - self.min_height()) / 2.0;
+        half_height.min(self.max_radius())
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for SphericalSector {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_spherical_sector(density, self.radius, self.half_angle)
+    }
+
+    fn surface_area(&self) -> Real {
+        let (sin, cos) = self.half_angle.sin_cos();
+        // The spherical cap and the lateral surface of the cone.
+        Real::pi() * self.radius * self.radius * (2.0 * (1.0 - cos) + sin)
+    }
+
+    fn is_convex(&self) -> bool {
+        self.is_convex()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::SphericalSector
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radius * self.half_angle.sin().min(1.0) / 2.0
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        if self.is_convex() {
+            Some(self as &dyn SupportMap)
+        } else {
+            None
+        }
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        let nphi_subdiv = (nsubdivs as Real * self.half_angle / Real::two_pi()).ceil() as u32;
+        Some(self.to_trimesh(nsubdivs, nphi_subdiv.max(1)))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for Torus {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_bounding_sphere(&self, position: &Isometry<Real>) -> BoundingSphere {
+        self.bounding_sphere(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_torus(density, self.major_radius, self.minor_radius)
+    }
+
+    fn surface_area(&self) -> Real {
+        Real::two_pi() * Real::two_pi() * self.major_radius * self.minor_radius
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Torus
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.minor_radius
+    }
+
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        Some(self.to_trimesh(nsubdivs, (nsubdivs / 2).max(3)))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for Cone {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_cone(density, self.half_height, self.radius)
+    }
+
+    fn surface_area(&self) -> Real {
+        let slant = (self.radius * self.radius + self.half_height * self.half_height * 4.0).sqrt();
+        Real::pi() * self.radius * (self.radius + slant)
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Cone
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radius
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        Some(self.to_trimesh(nsubdivs))
+    }
+}
+
+impl Shape for HalfSpace {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.aabb(position)
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        f32::MAX as Real
+    }
+
+    fn mass_properties(&self, _: Real) -> MassProperties {
+        MassProperties::zero()
+    }
+
+    fn volume(&self) -> Real {
+        Real::MAX
+    }
+
+    fn surface_area(&self) -> Real {
+        Real::MAX
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::HalfSpace
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+}
+
+impl Shape for Slab {
     #[cfg(feature = "serde-serialize")]
     fn as_serialize(&self) -> Option<&dyn Serialize> {
         Some(self as &dyn Serialize)
     }
 
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
@@ -722,59 +3012,383 @@ impl Shape for Cone {
         self.aabb(position)
     }
 
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.half_thickness
+    }
+
+    fn mass_properties(&self, _: Real) -> MassProperties {
+        MassProperties::zero()
+    }
+
+    fn volume(&self) -> Real {
+        Real::MAX
+    }
+
+    fn surface_area(&self) -> Real {
+        Real::MAX
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Slab
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        Some(mirror::mirrored_shape(self, axis))
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+}
+
+impl<S: Shape + SupportMap + Clone> Shape for ScaledShape<S> {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.local_aabb()
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
-        MassProperties::from_cone(density, self.half_height, self.radius)
+        #[cfg(feature = "dim2")]
+        {
+            // The 2D angular inertia of the base shape doesn't carry enough information to be
+            // scaled non-uniformly.
+            support_map_mass_properties(self, density)
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            self.base_shape.mass_properties(density).scaled(&self.scale)
+        }
     }
 
     fn is_convex(&self) -> bool {
-        true
+        self.base_shape.is_convex()
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::Cone
+        ShapeType::ScaledShape
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
     }
 
     fn ccd_thickness(&self) -> Real {
-        self.radius
+        self.base_shape.ccd_thickness() * self.scale.min()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        self.base_shape.ccd_angular_thickness()
     }
 
     fn as_support_map(&self) -> Option<&dyn SupportMap> {
         Some(self as &dyn SupportMap)
     }
 
-    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
-        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    fn num_features(&self) -> (usize, usize, usize) {
+        self.base_shape.num_features()
     }
 }
 
-impl Shape for HalfSpace {
-    #[cfg(feature = "serde-serialize")]
-    fn as_serialize(&self) -> Option<&dyn Serialize> {
-        Some(self as &dyn Serialize)
+impl<A, B> Shape for MinkowskiSum<A, B>
+where
+    A: Shape + SupportMap + Clone,
+    B: Shape + SupportMap + Clone,
+{
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
     }
 
     fn compute_local_aabb(&self) -> AABB {
         self.local_aabb()
     }
 
-    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
-        self.aabb(position)
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        support_map_mass_properties(self, density)
     }
 
     fn is_convex(&self) -> bool {
         true
     }
 
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::MinkowskiSum
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+
     fn ccd_thickness(&self) -> Real {
-        f32::MAX as Real
+        // The width of the sum along any direction is the sum of the widths of its operands.
+        self.shape1.ccd_thickness() + self.shape2.ccd_thickness()
     }
 
-    fn mass_properties(&self, _: Real) -> MassProperties {
-        MassProperties::zero()
+    fn ccd_angular_thickness(&self) -> Real {
+        self.shape1
+            .ccd_angular_thickness()
+            .min(self.shape2.ccd_angular_thickness())
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+}
+
+impl<S: Shape + Clone> Shape for TransformedShape<S> {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.shape.compute_aabb(&self.position)
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.shape.compute_aabb(&(position * self.position))
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.shape.compute_bounding_sphere(&self.position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        self.shape
+            .mass_properties(density)
+            .transform_by(&self.position)
+    }
+
+    fn volume(&self) -> Real {
+        self.shape.volume()
+    }
+
+    fn surface_area(&self) -> Real {
+        self.shape.surface_area()
+    }
+
+    fn is_convex(&self) -> bool {
+        self.shape.is_convex()
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::HalfSpace
+        ShapeType::TransformedShape
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.shape.ccd_thickness()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        self.shape.ccd_angular_thickness()
+    }
+
+    fn as_transformed_shape(&self) -> Option<(&Isometry<Real>, &dyn Shape)> {
+        Some((&self.position, &self.shape as &dyn Shape))
+    }
+
+    #[cfg(feature = "dim2")]
+    fn to_polyline(&self, nsubdivs: u32) -> Option<Discretization> {
+        let (vertices, indices) = self.shape.to_polyline(nsubdivs)?;
+        Some((
+            vertices.iter().map(|pt| self.position * pt).collect(),
+            indices,
+        ))
+    }
+
+    #[cfg(feature = "dim3")]
+    fn to_trimesh(&self, nsubdivs: u32) -> Option<Discretization> {
+        let (vertices, indices) = self.shape.to_trimesh(nsubdivs)?;
+        Some((
+            vertices.iter().map(|pt| self.position * pt).collect(),
+            indices,
+        ))
+    }
+
+    fn num_features(&self) -> (usize, usize, usize) {
+        self.shape.num_features()
+    }
+
+    fn for_each_feature(&self, f: &mut dyn FnMut(FeatureId, &[Point<Real>])) {
+        self.shape.for_each_feature(&mut |feature, pts| {
+            let pts: Vec<_> = pts.iter().map(|pt| self.position * pt).collect();
+            f(feature, &pts)
+        })
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        feature: FeatureId,
+        point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        let local_point = self.position.inverse_transform_point(point);
+        let normal = self.shape.feature_normal_at_point(feature, &local_point)?;
+        Some(self.position * normal)
+    }
+}
+
+// The number of subdivisions of the directions of the support points used to approximate the
+// measures of a convex shape known only through its support map.
+#[cfg(feature = "dim2")]
+const SUPPORT_MAP_HULL_SUBDIVS: u32 = 128;
+#[cfg(feature = "dim3")]
+const SUPPORT_MAP_HULL_SUBDIVS: u32 = 32;
+
+// The convex hull of some of the support points of a convex shape known only through its
+// support map.
+#[cfg(feature = "dim2")]
+fn support_map_hull(shape: &dyn SupportMap, nsubdiv: u32) -> Option<ConvexPolygon> {
+    let points: Vec<_> = (0..nsubdiv)
+        .map(|i| {
+            let angle = Real::two_pi() * i as Real / nsubdiv as Real;
+            let dir = Vector::new(ComplexField::cos(angle), ComplexField::sin(angle));
+            shape.local_support_point(&dir)
+        })
+        .collect();
+
+    ConvexPolygon::from_convex_hull(&points)
+}
+
+// The convex hull of some of the support points of a convex shape known only through its
+// support map.
+#[cfg(feature = "dim3")]
+fn support_map_hull(shape: &dyn SupportMap, nsubdiv: u32) -> Option<ConvexPolyhedron> {
+    let (directions, _) = Ball::new(1.0).to_trimesh(nsubdiv, (nsubdiv / 2).max(2));
+    let points: Vec<_> = directions
+        .iter()
+        .map(|dir| shape.local_support_point(&dir.coords))
+        .collect();
+
+    ConvexPolyhedron::from_convex_hull(&points)
+}
+
+/// Approximates the mass properties of a convex shape known only through its support map,
+/// from the convex hull of some of its support points.
+fn support_map_mass_properties<S: Shape + SupportMap>(shape: &S, density: Real) -> MassProperties {
+    support_map_hull(shape, SUPPORT_MAP_HULL_SUBDIVS)
+        .map(|hull| hull.mass_properties(density))
+        .unwrap_or_else(MassProperties::zero)
+}
+
+/// Approximates the surface area of a convex shape known only through its support map, from
+/// the convex hull of some of its support points.
+fn support_map_surface_area(shape: &dyn SupportMap) -> Real {
+    support_map_hull(shape, SUPPORT_MAP_HULL_SUBDIVS)
+        .map(|hull| hull.surface_area())
+        .unwrap_or(0.0)
+}
+
+// The vertices and the indices of the segments in 2D, or triangles in 3D, discretizing the
+// boundary of a shape.
+type Discretization = (Vec<Point<Real>>, Vec<[u32; DIM]>);
+
+// The closed polygonal line joining the given vertices in order.
+#[cfg(feature = "dim2")]
+fn closed_polyline(vertices: Vec<Point<Real>>) -> Discretization {
+    let n = vertices.len() as u32;
+    let indices = (0..n).map(|i| [i, (i + 1) % n]).collect();
+    (vertices, indices)
+}
+
+// The polyline in 2D, or the triangle mesh in 3D, discretizing the boundary of `shape`.
+fn discretize(shape: &dyn Shape, nsubdivs: u32) -> Option<Discretization> {
+    #[cfg(feature = "dim2")]
+    return shape.to_polyline(nsubdivs);
+    #[cfg(feature = "dim3")]
+    return shape.to_trimesh(nsubdivs);
+}
+
+// Merges the discretizations of the given parts, each transformed by its position.
+fn merge_discretizations(
+    parts: impl Iterator<Item = (Isometry<Real>, Option<Discretization>)>,
+) -> Option<Discretization> {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for (pos, part) in parts {
+        let (part_vertices, part_indices) = part?;
+        let base = vertices.len() as u32;
+        vertices.extend(part_vertices.iter().map(|pt| pos * pt));
+        indices.extend(part_indices.iter().map(|idx| idx.map(|i| i + base)));
+    }
+
+    Some((vertices, indices))
+}
+
+// Measures of a convex shape appearing in the Steiner formulas, which give the volume and the
+// surface area of its Minkowski sum with a ball, i.e., of the round version of this shape.
+#[cfg(feature = "dim3")]
+trait SteinerMeasures: Shape {
+    // The area of the boundary of this shape, where flat shapes count both of their sides.
+    fn boundary_area(&self) -> Real {
+        self.surface_area()
+    }
+
+    // The integral of the mean curvature over the boundary of this shape, where each edge of
+    // length `l` and exterior dihedral angle `theta` contributes `l * theta / 2`.
+    fn mean_curvature_integral(&self) -> Real;
+}
+
+#[cfg(feature = "dim3")]
+impl SteinerMeasures for Cuboid {
+    fn mean_curvature_integral(&self) -> Real {
+        Real::two_pi() * self.half_extents.sum()
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl SteinerMeasures for Triangle {
+    fn boundary_area(&self) -> Real {
+        self.area() * 2.0
+    }
+
+    fn mean_curvature_integral(&self) -> Real {
+        Real::frac_pi_2() * self.perimeter()
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl SteinerMeasures for Cylinder {
+    fn mean_curvature_integral(&self) -> Real {
+        Real::pi() * (self.half_height * 2.0 + Real::pi() * self.radius)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl SteinerMeasures for Cone {
+    fn mean_curvature_integral(&self) -> Real {
+        let height = self.half_height * 2.0;
+        let slant = (self.radius * self.radius + height * height).sqrt();
+        // The exterior angle between the base and the lateral surface.
+        let rim_angle = (-self.radius / slant).acos();
+        Real::pi() * (height + self.radius * rim_angle)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl SteinerMeasures for ConvexPolyhedron {
+    fn mean_curvature_integral(&self) -> Real {
+        let points = self.points();
+        let faces = self.faces();
+
+        self.edges()
+            .iter()
+            .map(|edge| {
+                let length = na::distance(
+                    &points[edge.vertices[0] as usize],
+                    &points[edge.vertices[1] as usize],
+                );
+                let n1 = faces[edge.faces[0] as usize].normal;
+                let n2 = faces[edge.faces[1] as usize].normal;
+                length * n1.dot(&n2).clamp(-1.0, 1.0).acos() / 2.0
+            })
+            .sum()
     }
 }
 
@@ -786,6 +3400,10 @@ macro_rules! impl_shape_for_round_shape(
                 Some(self as &dyn Serialize)
             }
 
+            fn clone_dyn(&self) -> Box<dyn Shape> {
+                Box::new(self.clone())
+            }
+
             fn compute_local_aabb(&self) -> AABB {
                 self.base_shape.local_aabb().loosened(self.border_radius)
             }
@@ -798,6 +3416,29 @@ macro_rules! impl_shape_for_round_shape(
                 self.base_shape.mass_properties(density)
             }
 
+            fn volume(&self) -> Real {
+                let r = self.border_radius;
+                #[cfg(feature = "dim2")]
+                return self.base_shape.volume()
+                    + self.base_shape.surface_area() * r
+                    + Real::pi() * r * r;
+                #[cfg(feature = "dim3")]
+                return self.base_shape.volume()
+                    + self.base_shape.boundary_area() * r
+                    + self.base_shape.mean_curvature_integral() * r * r
+                    + Real::pi() * r * r * r * 4.0 / 3.0;
+            }
+
+            fn surface_area(&self) -> Real {
+                let r = self.border_radius;
+                #[cfg(feature = "dim2")]
+                return self.base_shape.surface_area() + Real::two_pi() * r;
+                #[cfg(feature = "dim3")]
+                return self.base_shape.boundary_area()
+                    + self.base_shape.mean_curvature_integral() * r * 2.0
+                    + Real::pi() * r * r * 4.0;
+            }
+
             fn is_convex(&self) -> bool {
                 self.base_shape.is_convex()
             }
@@ -810,6 +3451,10 @@ macro_rules! impl_shape_for_round_shape(
                 self.base_shape.ccd_thickness() + self.border_radius
             }
 
+            fn ccd_angular_thickness(&self) -> Real {
+                self.base_shape.ccd_angular_thickness()
+            }
+
             fn as_support_map(&self) -> Option<&dyn SupportMap> {
                 Some(self as &dyn SupportMap)
             }
@@ -817,6 +3462,14 @@ macro_rules! impl_shape_for_round_shape(
             fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
                 Some((&self.base_shape as &dyn PolygonalFeatureMap, self.border_radius))
             }
+
+            fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+                Some(mirror::mirrored_shape(self, axis))
+            }
+
+            fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+                scale::scaled_shape(self, scale, nsubdivs)
+            }
         }
     )*}
 );
@@ -833,3 +3486,89 @@ impl_shape_for_round_shape!(
     Cone, ShapeType::RoundCone;
     ConvexPolyhedron, ShapeType::RoundConvexPolyhedron
 );
+
+impl Shape for RoundShape<SharedShape> {
+    #[cfg(feature = "serde-serialize")]
+    fn as_serialize(&self) -> Option<&dyn Serialize> {
+        Some(self as &dyn Serialize)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> AABB {
+        self.base_shape
+            .compute_local_aabb()
+            .loosened(self.border_radius)
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> AABB {
+        self.base_shape
+            .compute_aabb(position)
+            .loosened(self.border_radius)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        self.base_shape.mass_properties(density)
+    }
+
+    fn volume(&self) -> Real {
+        if self.base_support_map().is_none() {
+            // Without a support map, only the volume of the base shape is known.
+            return self.base_shape.volume();
+        }
+
+        let inv_mass = support_map_mass_properties(self, 1.0).inv_mass;
+
+        if inv_mass == 0.0 {
+            0.0
+        } else {
+            1.0 / inv_mass
+        }
+    }
+
+    fn is_convex(&self) -> bool {
+        self.base_support_map().is_some()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::RoundSharedShape
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.base_shape.ccd_thickness() + self.border_radius
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        self.base_shape.ccd_angular_thickness()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        self.base_support_map().map(|_| self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        let (pfm, border_radius) = self.base_shape.as_polygonal_feature_map()?;
+        Some((pfm, border_radius + self.border_radius))
+    }
+
+    fn mirrored(&self, axis: usize) -> Option<Box<dyn Shape>> {
+        let base_shape = SharedShape(self.base_shape.mirrored(axis)?.into());
+
+        // Some convex shapes, e.g., a cone reflected along its axis, are
+        // not a support map anymore once reflected.
+        if base_shape.as_support_map().is_some() {
+            Some(Box::new(RoundShape {
+                base_shape,
+                border_radius: self.border_radius,
+            }))
+        } else {
+            None
+        }
+    }
+
+    fn scaled(&self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Box<dyn Shape>> {
+        scale::scaled_shape(self, scale, nsubdivs)
+    }
+}
@@ -1,15 +1,24 @@
+use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point, Real, Vector, DIM};
-#[cfg(feature = "dim2")]
-use crate::shape::ConvexPolygon;
 #[cfg(feature = "serde-serialize")]
 use crate::shape::{self, ShapeType};
+#[cfg(feature = "dim2")]
+use crate::shape::{Annulus, ConvexPolygon, Polygon, Sector};
 use crate::shape::{
-    Ball, Capsule, Compound, Cuboid, HeightField, Polyline, RoundShape, Segment, Shape, TriMesh,
-    Triangle,
+    Ball, Capsule, Compound, Cuboid, HalfSpace, HeightField, MinkowskiSum, Polyline, RoundPolyline,
+    RoundShape, RoundTriMesh, ScaledShape, SdfShape, Segment, Shape, Slab, SupportMap,
+    TaperedCapsule, TransformedShape, TriMesh, Triangle, Voxels,
 };
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexPolyhedron, Cylinder};
+use crate::shape::{
+    Cone, ConvexPolyhedron, Cylinder, Ellipsoid, EllipticCylinder, Plate, Prism, SolidOfRevolution,
+    SphericalSector, Torus,
+};
 use crate::transformation::vhacd::{VHACDParameters, VHACD};
+use na::Unit;
+#[cfg(feature = "dim3")]
+use na::{Point2, Vector2};
+use std::fmt;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -24,7 +33,38 @@ impl Deref for SharedShape {
     }
 }
 
+impl AsRef<dyn Shape> for SharedShape {
+    fn as_ref(&self) -> &dyn Shape {
+        &*self.0
+    }
+}
+
+impl fmt::Debug for SharedShape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SharedShape")
+            .field(&self.0.shape_type())
+            .finish()
+    }
+}
+
 impl SharedShape {
+    /// Wraps the given shape into a reference-counted shape.
+    pub fn new(shape: impl Shape) -> Self {
+        SharedShape(Arc::new(shape))
+    }
+
+    /// Gets a mutable reference to the underlying shape.
+    ///
+    /// If this shape is shared with other `SharedShape`s, it is cloned first so that the
+    /// modification doesn't affect them (copy-on-write).
+    pub fn make_mut(&mut self) -> &mut dyn Shape {
+        if Arc::get_mut(&mut self.0).is_none() {
+            self.0 = self.0.clone_dyn().into();
+        }
+
+        Arc::get_mut(&mut self.0).unwrap()
+    }
+
     /// Initialize a compound shape defined by its subshapes.
     pub fn compound(shapes: Vec<(Isometry<Real>, SharedShape)>) -> Self {
         let raw_shapes = shapes.into_iter().map(|s| (s.0, s.1)).collect();
@@ -37,6 +77,39 @@ impl SharedShape {
         SharedShape(Arc::new(Ball::new(radius)))
     }
 
+    /// Initialize a half-space shape defined by its outward normal.
+    pub fn halfspace(outward_normal: Unit<Vector<Real>>) -> Self {
+        SharedShape(Arc::new(HalfSpace::new(outward_normal)))
+    }
+
+    /// Initialize a slab, i.e., the region between two parallel planes, defined by the
+    /// outward normal of one of its planar boundaries and its half-thickness.
+    pub fn slab(outward_normal: Unit<Vector<Real>>, half_thickness: Real) -> Self {
+        SharedShape(Arc::new(Slab::new(outward_normal, half_thickness)))
+    }
+
+    /// Initialize an annulus shape defined by the radii of its inner and outer circles.
+    #[cfg(feature = "dim2")]
+    pub fn annulus(inner_radius: Real, outer_radius: Real) -> Self {
+        SharedShape(Arc::new(Annulus::new(inner_radius, outer_radius)))
+    }
+
+    /// Initialize a circular sector shape defined by its radius and the polar
+    /// angles of its two straight edges.
+    #[cfg(feature = "dim2")]
+    pub fn sector(radius: Real, start_angle: Real, end_angle: Real) -> Self {
+        SharedShape(Arc::new(Sector::new(radius, start_angle, end_angle)))
+    }
+
+    /// Initialize a simple polygon shape, possibly concave, defined by the vertices of its
+    /// outer boundary and of its holes.
+    ///
+    /// Returns `None` if the polygon can't be triangulated. See `Polygon::new` for details.
+    #[cfg(feature = "dim2")]
+    pub fn polygon(outer: Vec<Point<Real>>, holes: Vec<Vec<Point<Real>>>) -> Option<Self> {
+        Polygon::new(outer, holes).map(|polygon| SharedShape(Arc::new(polygon)))
+    }
+
     /// Initialize a cylindrical shape defined by its half-height
     /// (along along the y axis) and its radius.
     #[cfg(feature = "dim3")]
@@ -73,6 +146,58 @@ impl SharedShape {
         SharedShape(Arc::new(Cone::new(half_height, radius)))
     }
 
+    /// Initialize an ellipsoid shape defined by its radii along each coordinate axis.
+    #[cfg(feature = "dim3")]
+    pub fn ellipsoid(radii: Vector<Real>) -> Self {
+        SharedShape(Arc::new(Ellipsoid::new(radii)))
+    }
+
+    /// Initialize a cylindrical shape with an elliptical cross-section, defined by its
+    /// half-height (along the y axis) and the radii of its cross-section along the x and z axes.
+    #[cfg(feature = "dim3")]
+    pub fn elliptic_cylinder(half_height: Real, radii: Vector2<Real>) -> Self {
+        SharedShape(Arc::new(EllipticCylinder::new(half_height, radii)))
+    }
+
+    /// Initialize a plate shape lying on the `xz` plane, defined by its half-extents
+    /// along the `x` and `z` axes.
+    #[cfg(feature = "dim3")]
+    pub fn plate(half_extents: Vector2<Real>) -> Self {
+        SharedShape(Arc::new(Plate::new(half_extents)))
+    }
+
+    /// Initialize a prism shape obtained by extruding the 2D convex hull of the given
+    /// points along the `y` axis.
+    ///
+    /// Returns `None` if the convex hull computation failed.
+    #[cfg(feature = "dim3")]
+    pub fn prism(cross_section: &[Point2<Real>], half_height: Real) -> Option<Self> {
+        Prism::from_convex_hull(cross_section, half_height).map(|p| SharedShape(Arc::new(p)))
+    }
+
+    /// Initialize a convex solid of revolution obtained by rotating the given profile around
+    /// the `y` axis.
+    ///
+    /// The profile is a sequence of points `(r, y)` sorted by increasing heights `y`.
+    #[cfg(feature = "dim3")]
+    pub fn solid_of_revolution(profile: Vec<Point2<Real>>) -> Self {
+        SharedShape(Arc::new(SolidOfRevolution::new(profile)))
+    }
+
+    /// Initialize a spherical sector, i.e., the part of a ball of radius `radius` within the
+    /// cone around the `y` axis with the given half-angle and its apex at the center of the ball.
+    #[cfg(feature = "dim3")]
+    pub fn spherical_sector(radius: Real, half_angle: Real) -> Self {
+        SharedShape(Arc::new(SphericalSector::new(radius, half_angle)))
+    }
+
+    /// Initialize a torus shape defined by the radius of the circle at the center
+    /// of its tube (on the x-z plane) and the radius of its tube.
+    #[cfg(feature = "dim3")]
+    pub fn torus(major_radius: Real, minor_radius: Real) -> Self {
+        SharedShape(Arc::new(Torus::new(major_radius, minor_radius)))
+    }
+
     /// Initialize a cuboid shape defined by its half-extents.
     #[cfg(feature = "dim2")]
     pub fn cuboid(hx: Real, hy: Real) -> Self {
@@ -108,6 +233,11 @@ impl SharedShape {
         SharedShape(Arc::new(Capsule::new(a, b, radius)))
     }
 
+    /// Initialize a capsule shape from its endpoints and the radius at each endpoint.
+    pub fn tapered_capsule(a: Point<Real>, b: Point<Real>, radius_a: Real, radius_b: Real) -> Self {
+        SharedShape(Arc::new(TaperedCapsule::new(a, b, radius_a, radius_b)))
+    }
+
     /// Initialize a segment shape from its endpoints.
     pub fn segment(a: Point<Real>, b: Point<Real>) -> Self {
         SharedShape(Arc::new(Segment::new(a, b)))
@@ -142,6 +272,28 @@ impl SharedShape {
         SharedShape(Arc::new(TriMesh::new(vertices, indices)))
     }
 
+    /// Initializes a polyline shape with rounded corners, defined by its vertex and index buffers.
+    ///
+    /// If no index buffer is provided, the polyline is assumed to describe a line strip.
+    pub fn round_polyline(
+        vertices: Vec<Point<Real>>,
+        indices: Option<Vec<[u32; 2]>>,
+        border_radius: Real,
+    ) -> Self {
+        let polyline = Polyline::new(vertices, indices);
+        SharedShape(Arc::new(RoundPolyline::new(polyline, border_radius)))
+    }
+
+    /// Initializes a triangle mesh shape with rounded corners, defined by its vertex and index buffers.
+    pub fn round_trimesh(
+        vertices: Vec<Point<Real>>,
+        indices: Vec<[u32; 3]>,
+        border_radius: Real,
+    ) -> Self {
+        let trimesh = TriMesh::new(vertices, indices);
+        SharedShape(Arc::new(RoundTriMesh::new(trimesh, border_radius)))
+    }
+
     /// Initializes a compound shape obtained from the decomposition of the given trimesh (in 3D) or
     /// polyline (in 2D) into convex parts.
     pub fn convex_decomposition(vertices: &[Point<Real>], indices: &[[u32; DIM]]) -> Self {
@@ -274,6 +426,20 @@ impl SharedShape {
         })
     }
 
+    /// Initializes a shape with round corners from a convex shape of any type.
+    ///
+    /// Panics if `shape` isn't convex, i.e., if `shape.as_support_map()` is `None`.
+    pub fn round(shape: SharedShape, border_radius: Real) -> Self {
+        assert!(
+            shape.as_support_map().is_some(),
+            "The base shape of a rounded shape must be a support map."
+        );
+        SharedShape(Arc::new(RoundShape {
+            base_shape: shape,
+            border_radius,
+        }))
+    }
+
     /// Creates a new shared shape with round corners that is a convex polyhedron formed by the
     /// given set of points assumed to form a convex mesh (no convex-hull will be automatically
     /// computed).
@@ -304,6 +470,110 @@ impl SharedShape {
     pub fn heightfield(heights: na::DMatrix<Real>, scale: Vector<Real>) -> Self {
         SharedShape(Arc::new(HeightField::new(heights, scale)))
     }
+
+    /// Initializes a shape described by a signed distance function and a local AABB containing it.
+    ///
+    /// Shapes created this way can't be serialized.
+    pub fn sdf(sdf: impl Fn(&Point<Real>) -> Real + Send + Sync + 'static, aabb: AABB) -> Self {
+        SharedShape(Arc::new(SdfShape::new(sdf, aabb)))
+    }
+
+    /// Initializes a shape obtained by scaling the convex shape `base_shape` by the positive
+    /// factors `scale` along each coordinate axis.
+    pub fn scaled<S: Shape + SupportMap + Clone>(base_shape: S, scale: Vector<Real>) -> Self {
+        SharedShape(Arc::new(ScaledShape::new(base_shape, scale)))
+    }
+
+    /// Initializes the Minkowski sum of the convex shapes `shape1` and `shape2`.
+    pub fn minkowski_sum<A, B>(shape1: A, shape2: B) -> Self
+    where
+        A: Shape + SupportMap + Clone,
+        B: Shape + SupportMap + Clone,
+    {
+        SharedShape(Arc::new(MinkowskiSum::new(shape1, shape2)))
+    }
+
+    /// Initializes a shape obtained by transforming `shape` by `position`, relative to the
+    /// local-space of the new shape.
+    ///
+    /// This is cheaper than a compound shape with a single part. Shapes created this way can't
+    /// be serialized.
+    pub fn transformed<S: Shape + Clone>(position: Isometry<Real>, shape: S) -> Self {
+        SharedShape(Arc::new(TransformedShape::new(position, shape)))
+    }
+
+    /// Initializes a sparse set of voxels with the given size, from the keys of all its
+    /// occupied voxels.
+    pub fn voxels(voxel_size: Vector<Real>, keys: &[Point<i32>]) -> Self {
+        SharedShape(Arc::new(Voxels::new(voxel_size, keys)))
+    }
+}
+
+// The builtin shape types, indexed by their serialization tag.
+#[cfg(feature = "serde-serialize")]
+const BUILTIN_SHAPE_TYPES: &[ShapeType] = &[
+    ShapeType::Ball,
+    ShapeType::Cuboid,
+    ShapeType::Capsule,
+    ShapeType::Segment,
+    ShapeType::Triangle,
+    ShapeType::TriMesh,
+    ShapeType::Polyline,
+    ShapeType::HalfSpace,
+    ShapeType::HeightField,
+    ShapeType::Compound,
+    #[cfg(feature = "dim2")]
+    ShapeType::ConvexPolygon,
+    #[cfg(feature = "dim3")]
+    ShapeType::ConvexPolyhedron,
+    #[cfg(feature = "dim3")]
+    ShapeType::Cylinder,
+    #[cfg(feature = "dim3")]
+    ShapeType::Cone,
+    ShapeType::RoundCuboid,
+    ShapeType::RoundTriangle,
+    #[cfg(feature = "dim3")]
+    ShapeType::RoundCylinder,
+    #[cfg(feature = "dim3")]
+    ShapeType::RoundCone,
+    #[cfg(feature = "dim3")]
+    ShapeType::RoundConvexPolyhedron,
+    #[cfg(feature = "dim2")]
+    ShapeType::RoundConvexPolygon,
+    #[cfg(feature = "dim3")]
+    ShapeType::Torus,
+    #[cfg(feature = "dim3")]
+    ShapeType::Ellipsoid,
+    ShapeType::TaperedCapsule,
+    #[cfg(feature = "dim2")]
+    ShapeType::Annulus,
+    #[cfg(feature = "dim2")]
+    ShapeType::Sector,
+    #[cfg(feature = "dim3")]
+    ShapeType::Plate,
+    #[cfg(feature = "dim3")]
+    ShapeType::Prism,
+    #[cfg(feature = "dim3")]
+    ShapeType::SolidOfRevolution,
+    ShapeType::Voxels,
+    ShapeType::RoundTriMesh,
+    ShapeType::RoundPolyline,
+    #[cfg(feature = "dim3")]
+    ShapeType::EllipticCylinder,
+    ShapeType::Slab,
+    #[cfg(feature = "dim2")]
+    ShapeType::Polygon,
+    #[cfg(feature = "dim3")]
+    ShapeType::SphericalSector,
+    ShapeType::RoundSharedShape,
+];
+
+#[cfg(feature = "serde-serialize")]
+fn tag(typ: ShapeType) -> Option<i32> {
+    BUILTIN_SHAPE_TYPES
+        .iter()
+        .position(|t| *t == typ)
+        .map(|i| i as i32)
 }
 
 #[cfg(feature = "serde-serialize")]
@@ -314,10 +584,9 @@ impl serde::Serialize for SharedShape {
     {
         use crate::serde::ser::SerializeStruct;
 
-        if let Some(ser) = self.0.as_serialize() {
-            let typ = self.0.shape_type();
+        if let (Some(ser), Some(tag)) = (self.0.as_serialize(), tag(self.0.shape_type())) {
             let mut state = serializer.serialize_struct("SharedShape", 2)?;
-            state.serialize_field("tag", &(typ as i32))?;
+            state.serialize_field("tag", &tag)?;
             state.serialize_field("inner", ser)?;
             state.end()
         } else {
@@ -345,8 +614,6 @@ impl<'de> serde::Deserialize<'de> for SharedShape {
             where
                 A: serde::de::SeqAccess<'de>,
             {
-                use num::cast::FromPrimitive;
-
                 let tag: i32 = seq
                     .next_element()?
                     .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
@@ -363,10 +630,11 @@ impl<'de> serde::Deserialize<'de> for SharedShape {
                     Ok(Arc::new(shape) as Arc<dyn Shape>)
                 }
 
-                let shape = match ShapeType::from_i32(tag) {
+                let shape = match BUILTIN_SHAPE_TYPES.get(tag as usize).copied() {
                     Some(ShapeType::Ball) => deser::<A, Ball>(&mut seq)?,
                     Some(ShapeType::Cuboid) => deser::<A, Cuboid>(&mut seq)?,
                     Some(ShapeType::Capsule) => deser::<A, Capsule>(&mut seq)?,
+                    Some(ShapeType::TaperedCapsule) => deser::<A, TaperedCapsule>(&mut seq)?,
                     Some(ShapeType::Triangle) => deser::<A, Triangle>(&mut seq)?,
                     Some(ShapeType::Segment) => deser::<A, Segment>(&mut seq)?,
                     Some(ShapeType::TriMesh) => deser::<A, TriMesh>(&mut seq)?,
@@ -374,9 +642,16 @@ impl<'de> serde::Deserialize<'de> for SharedShape {
                     Some(ShapeType::HeightField) => deser::<A, HeightField>(&mut seq)?,
                     Some(ShapeType::Compound) => deser::<A, Compound>(&mut seq)?,
                     Some(ShapeType::HalfSpace) => deser::<A, shape::HalfSpace>(&mut seq)?,
+                    Some(ShapeType::Slab) => deser::<A, Slab>(&mut seq)?,
                     Some(ShapeType::RoundCuboid) => deser::<A, shape::RoundCuboid>(&mut seq)?,
                     Some(ShapeType::RoundTriangle) => deser::<A, shape::RoundTriangle>(&mut seq)?,
                     #[cfg(feature = "dim2")]
+                    Some(ShapeType::Annulus) => deser::<A, Annulus>(&mut seq)?,
+                    #[cfg(feature = "dim2")]
+                    Some(ShapeType::Sector) => deser::<A, Sector>(&mut seq)?,
+                    #[cfg(feature = "dim2")]
+                    Some(ShapeType::Polygon) => deser::<A, Polygon>(&mut seq)?,
+                    #[cfg(feature = "dim2")]
                     Some(ShapeType::ConvexPolygon) => deser::<A, ConvexPolygon>(&mut seq)?,
                     #[cfg(feature = "dim2")]
                     Some(ShapeType::RoundConvexPolygon) => {
@@ -389,6 +664,20 @@ impl<'de> serde::Deserialize<'de> for SharedShape {
                     #[cfg(feature = "dim3")]
                     Some(ShapeType::Cone) => deser::<A, Cone>(&mut seq)?,
                     #[cfg(feature = "dim3")]
+                    Some(ShapeType::Ellipsoid) => deser::<A, Ellipsoid>(&mut seq)?,
+                    #[cfg(feature = "dim3")]
+                    Some(ShapeType::EllipticCylinder) => deser::<A, EllipticCylinder>(&mut seq)?,
+                    #[cfg(feature = "dim3")]
+                    Some(ShapeType::Torus) => deser::<A, Torus>(&mut seq)?,
+                    #[cfg(feature = "dim3")]
+                    Some(ShapeType::Plate) => deser::<A, Plate>(&mut seq)?,
+                    #[cfg(feature = "dim3")]
+                    Some(ShapeType::Prism) => deser::<A, Prism>(&mut seq)?,
+                    #[cfg(feature = "dim3")]
+                    Some(ShapeType::SolidOfRevolution) => deser::<A, SolidOfRevolution>(&mut seq)?,
+                    #[cfg(feature = "dim3")]
+                    Some(ShapeType::SphericalSector) => deser::<A, SphericalSector>(&mut seq)?,
+                    #[cfg(feature = "dim3")]
                     Some(ShapeType::RoundCylinder) => deser::<A, shape::RoundCylinder>(&mut seq)?,
                     #[cfg(feature = "dim3")]
                     Some(ShapeType::RoundCone) => deser::<A, shape::RoundCone>(&mut seq)?,
@@ -396,7 +685,29 @@ impl<'de> serde::Deserialize<'de> for SharedShape {
                     Some(ShapeType::RoundConvexPolyhedron) => {
                         deser::<A, shape::RoundConvexPolyhedron>(&mut seq)?
                     }
-                    None => {
+                    Some(ShapeType::Voxels) => deser::<A, Voxels>(&mut seq)?,
+                    Some(ShapeType::RoundTriMesh) => deser::<A, RoundTriMesh>(&mut seq)?,
+                    Some(ShapeType::RoundPolyline) => deser::<A, RoundPolyline>(&mut seq)?,
+                    Some(ShapeType::RoundSharedShape) => {
+                        let shape = deser::<A, shape::RoundSharedShape>(&mut seq)?;
+                        let base_is_convex = shape
+                            .as_round_shared_shape()
+                            .map_or(false, |s| s.base_support_map().is_some());
+
+                        if !base_is_convex {
+                            return Err(serde::de::Error::custom(
+                                "The base shape of a rounded shape must be a support map.",
+                            ));
+                        }
+
+                        shape
+                    }
+                    Some(ShapeType::SdfShape)
+                    | Some(ShapeType::ScaledShape)
+                    | Some(ShapeType::MinkowskiSum)
+                    | Some(ShapeType::TransformedShape)
+                    | Some(ShapeType::Custom(_))
+                    | None => {
                         return Err(serde::de::Error::custom(
                             "found invalid shape type to deserialize",
                         ))
@@ -0,0 +1,61 @@
+//! A slab shape, i.e., the region between two parallel planes.
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::Unit;
+
+/// The region between two parallel infinite planes.
+///
+/// The slab is centered at the origin: it contains all the points `p` such that
+/// `|normal.dot(p)| <= half_thickness`.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Slab {
+    /// The outward normal of the first planar boundary of the slab.
+    ///
+    /// The outward normal of the second planar boundary is `-normal`.
+    pub normal: Unit<Vector<Real>>,
+    /// Half the distance between the two planar boundaries of the slab.
+    pub half_thickness: Real,
+}
+
+impl Slab {
+    /// Builds a new slab from the normal of its planar boundaries and its half-thickness.
+    #[inline]
+    pub fn new(normal: Unit<Vector<Real>>, half_thickness: Real) -> Slab {
+        assert!(
+            half_thickness >= 0.0,
+            "The half-thickness of a slab must be positive or null."
+        );
+
+        Slab {
+            normal,
+            half_thickness,
+        }
+    }
+
+    /// The planar boundary of this slab that is the closest to the support-mapped shape `other`
+    /// positioned at `pos12` relative to this slab.
+    ///
+    /// Returns the outward normal of this boundary, the point of `other` that is the deepest
+    /// along this normal, and the signed distance from this point to the boundary. All are
+    /// expressed in the local-space of the slab, and the distance is negative if `other`
+    /// penetrates the slab.
+    pub fn separating_side<G: ?Sized + SupportMap>(
+        &self,
+        pos12: &Isometry<Real>,
+        other: &G,
+    ) -> (Unit<Vector<Real>>, Point<Real>, Real) {
+        // The slab is the intersection of two half-spaces, and `other` is separated from
+        // it by at least the distance separating it from any of these half-spaces.
+        let lowest = other.support_point_toward(pos12, &-self.normal);
+        let highest = other.support_point_toward(pos12, &self.normal);
+        let dist_up = self.normal.dot(&lowest.coords) - self.half_thickness;
+        let dist_down = -self.normal.dot(&highest.coords) - self.half_thickness;
+
+        if dist_up >= dist_down {
+            (self.normal, lowest, dist_up)
+        } else {
+            (-self.normal, highest, dist_down)
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Support mapping based solid of revolution shape.
+
+use crate::math::{Point, Real, Vector};
+use crate::shape::{FeatureId, SupportMap};
+use na::{ComplexField, Point2, RealField, Unit, Vector2};
+
+/// A convex solid obtained by rotating a 2D profile around the `y` axis.
+///
+/// Each point `(r, y)` of the profile describes the circle of radius `r` at the height `y`.
+/// The solid is the convex hull of all these circles, e.g., a barrel, a vase, or any
+/// lathe-like shape, with flat caps at its lowest and highest profile points.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct SolidOfRevolution {
+    profile: Vec<Point2<Real>>,
+}
+
+impl SolidOfRevolution {
+    /// Creates a new solid of revolution from its profile.
+    ///
+    /// The profile is a sequence of points `(r, y)` sorted by increasing heights `y`, and with
+    /// non-negative radii `r`. Points of the profile that make it non-convex are discarded so
+    /// the resulting solid is the convex hull of the rotated profile.
+    pub fn new(profile: Vec<Point2<Real>>) -> SolidOfRevolution {
+        assert!(
+            profile.len() >= 2,
+            "The profile of a solid of revolution must have at least two points."
+        );
+        assert!(
+            profile.iter().all(|pt| pt.x.is_sign_positive()),
+            "The profile of a solid of revolution must have non-negative radii."
+        );
+        assert!(
+            profile.windows(2).all(|w| w[0].y < w[1].y),
+            "The profile of a solid of revolution must have strictly increasing heights."
+        );
+
+        // Keep only the points of the upper hull of the profile in the `(y, r)` plane.
+        let mut hull: Vec<Point2<Real>> = Vec::with_capacity(profile.len());
+
+        for pt in profile {
+            while hull.len() >= 2 {
+                let a = hull[hull.len() - 2];
+                let b = hull[hull.len() - 1];
+                let ab = Vector2::new(b.y - a.y, b.x - a.x);
+                let ap = Vector2::new(pt.y - a.y, pt.x - a.x);
+
+                if ab.perp(&ap) >= 0.0 {
+                    // `b` is below the segment `[a, pt]`.
+                    let _ = hull.pop();
+                } else {
+                    break;
+                }
+            }
+
+            hull.push(pt);
+        }
+
+        SolidOfRevolution { profile: hull }
+    }
+
+    /// The convex profile of this solid of revolution, sorted by increasing heights.
+    #[inline]
+    pub fn profile(&self) -> &[Point2<Real>] {
+        &self.profile
+    }
+
+    /// The lowest height of this solid of revolution.
+    #[inline]
+    pub fn min_height(&self) -> Real {
+        self.profile[0].y
+    }
+
+    /// The highest height of this solid of revolution.
+    #[inline]
+    pub fn max_height(&self) -> Real {
+        self.profile[self.profile.len() - 1].y
+    }
+
+    /// The largest radius of this solid of revolution.
+    #[inline]
+    pub fn max_radius(&self) -> Real {
+        self.profile.iter().fold(0.0, |r, pt| r.max(pt.x))
+    }
+
+    /// The radius of this solid of revolution at the height `y`.
+    ///
+    /// Returns `None` if `y` is outside of the height range of this solid.
+    pub fn radius_at(&self, y: Real) -> Option<Real> {
+        if y < self.min_height() || y > self.max_height() {
+            return None;
+        }
+
+        let i = self
+            .profile
+            .windows(2)
+            .position(|w| y <= w[1].y)
+            .unwrap_or(self.profile.len() - 2);
+        let (a, b) = (self.profile[i], self.profile[i + 1]);
+        let t = (y - a.y) / (b.y - a.y);
+
+        Some(a.x + (b.x - a.x) * t)
+    }
+
+    /// Get the ID of the feature with a normal that maximizes the dot product with `local_dir`.
+    ///
+    /// With `n` the number of points of the profile, the surface swept by the profile segment
+    /// between the points `i` and `i + 1` is identified by `FeatureId::Face(i)`, and the bottom
+    /// and top caps by `FeatureId::Face(n - 1)` and `FeatureId::Face(n)`. The circle swept by
+    /// the `i`-th profile point is identified by `FeatureId::Edge(i)`, or by `FeatureId::Vertex(i)`
+    /// if this point lies on the axis of revolution.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<Real>>) -> FeatureId {
+        let eps: Real = Real::pi() / 180.0;
+        let ceps = ComplexField::cos(eps);
+        let n = self.profile.len();
+
+        // The direction expressed in the `(r, y)` plane of the profile.
+        let dir = Vector2::new(
+            ComplexField::sqrt(local_dir.x * local_dir.x + local_dir.z * local_dir.z),
+            local_dir.y,
+        );
+
+        // Check faces.
+        if self.profile[0].x > 0.0 && -dir.y >= ceps {
+            return FeatureId::Face(n as u32 - 1);
+        }
+
+        if self.profile[n - 1].x > 0.0 && dir.y >= ceps {
+            return FeatureId::Face(n as u32);
+        }
+
+        for (i, w) in self.profile.windows(2).enumerate() {
+            let edge = w[1] - w[0];
+            let normal = Vector2::new(edge.y, -edge.x) / edge.norm();
+
+            if normal.dot(&dir) >= ceps {
+                return FeatureId::Face(i as u32);
+            }
+        }
+
+        // The profile point swept into the support circle, or vertex.
+        let mut best = 0;
+        let mut best_dot = self.profile[0].coords.dot(&dir);
+
+        for (i, pt) in self.profile.iter().enumerate().skip(1) {
+            let dot = pt.coords.dot(&dir);
+
+            if dot > best_dot {
+                best = i;
+                best_dot = dot;
+            }
+        }
+
+        if self.profile[best].x > 0.0 {
+            FeatureId::Edge(best as u32)
+        } else {
+            FeatureId::Vertex(best as u32)
+        }
+    }
+}
+
+impl SupportMap for SolidOfRevolution {
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let radial_norm = ComplexField::sqrt(dir.x * dir.x + dir.z * dir.z);
+        let radial = if radial_norm > 0.0 {
+            Vector2::new(dir.x, dir.z) / radial_norm
+        } else {
+            Vector2::x()
+        };
+
+        // Only the circles of the profile points can contain the support point.
+        let mut best = self.profile[0];
+        let mut best_dot = best.x * radial_norm + best.y * dir.y;
+
+        for pt in &self.profile[1..] {
+            let dot = pt.x * radial_norm + pt.y * dir.y;
+
+            if dot > best_dot {
+                best = *pt;
+                best_dot = dot;
+            }
+        }
+
+        Point::new(best.x * radial.x, best.y, best.x * radial.y)
+    }
+}
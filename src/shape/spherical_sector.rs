@@ -0,0 +1,93 @@
+//! Definition of the spherical sector shape.
+
+use crate::math::{Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::{ComplexField, RealField};
+
+/// A spherical sector, i.e., the part of a ball centered at the origin that lies within a
+/// cone with its apex at the origin.
+///
+/// The axis of the cone is aligned with the positive `y` axis. The sector contains all the
+/// points of the ball making an angle smaller than `half_angle` with this axis. It is bounded
+/// by a spherical cap and by the lateral surface of the cone. The sector is convex only if
+/// `half_angle` is at most `pi / 2`, so only convex sectors are exposed as support maps by
+/// `Shape::as_support_map`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct SphericalSector {
+    /// The radius of the ball this sector is part of.
+    pub radius: Real,
+    /// The angle between the axis of the sector and the lateral surface of its cone.
+    pub half_angle: Real,
+}
+
+impl SphericalSector {
+    /// Creates a new spherical sector.
+    ///
+    /// # Arguments:
+    /// * `radius` - the radius of the ball this sector is part of.
+    /// * `half_angle` - the angle between the `y` axis and the lateral surface of the cone
+    ///   bounding the sector. It must be in `(0, pi)`.
+    pub fn new(radius: Real, half_angle: Real) -> SphericalSector {
+        assert!(radius.is_sign_positive());
+        assert!(
+            half_angle > 0.0 && half_angle < Real::pi(),
+            "The half-angle of a spherical sector must be in (0, pi)."
+        );
+
+        SphericalSector { radius, half_angle }
+    }
+
+    /// Is this sector convex, i.e., is its half-angle at most `pi / 2`?
+    #[inline]
+    pub fn is_convex(&self) -> bool {
+        self.half_angle <= Real::frac_pi_2()
+    }
+
+    /// Checks if the direction `dir` lies within the cone bounding this sector.
+    ///
+    /// The null vector is considered to lie within this cone.
+    #[inline]
+    pub fn contains_direction(&self, dir: &Vector<Real>) -> bool {
+        dir.y >= ComplexField::cos(self.half_angle) * dir.norm()
+    }
+
+    /// The point of the circle at the rim of the spherical cap closest to the direction `dir`.
+    ///
+    /// If `dir` is parallel to the `y` axis, all the points of the rim are equally close,
+    /// and the one on the `xy` plane with a positive `x` coordinate is returned.
+    pub fn rim_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let radial = Vector::new(dir.x, 0.0, dir.z);
+        let radial_norm = radial.norm();
+        let radial_dir = if radial_norm > 0.0 {
+            radial / radial_norm
+        } else {
+            Vector::x()
+        };
+
+        let (sin, cos) = ComplexField::sin_cos(self.half_angle);
+        Point::from((radial_dir * sin + Vector::y() * cos) * self.radius)
+    }
+}
+
+impl SupportMap for SphericalSector {
+    /// The support point of the convex hull of this sector.
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let norm = dir.norm();
+
+        if norm == 0.0 {
+            Point::origin()
+        } else if self.contains_direction(dir) {
+            Point::from(dir * (self.radius / norm))
+        } else {
+            let rim = self.rim_point(dir);
+
+            if rim.coords.dot(dir) > 0.0 {
+                rim
+            } else {
+                Point::origin()
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::{Segment, SupportMap};
+use na::Unit;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A capsule with a different radius at each endpoint of its segment.
+///
+/// This is the convex hull of two balls, i.e., a sphere with a linearly varying radius
+/// swept along a segment. It is also known as a cone-sphere.
+pub struct TaperedCapsule {
+    /// The axis and endpoints of the tapered capsule.
+    pub segment: Segment,
+    /// The radius of the ball centered at `segment.a`.
+    pub radius_a: Real,
+    /// The radius of the ball centered at `segment.b`.
+    pub radius_b: Real,
+}
+
+impl TaperedCapsule {
+    /// Creates a new tapered capsule defined as the segment between `a` and `b`, with the
+    /// radius `radius_a` at `a` and the radius `radius_b` at `b`.
+    pub fn new(a: Point<Real>, b: Point<Real>, radius_a: Real, radius_b: Real) -> Self {
+        assert!(radius_a.is_sign_positive() && radius_b.is_sign_positive());
+
+        let segment = Segment::new(a, b);
+        Self {
+            segment,
+            radius_a,
+            radius_b,
+        }
+    }
+
+    /// The distance between the centers of the two balls of this tapered capsule.
+    pub fn height(&self) -> Real {
+        (self.segment.b - self.segment.a).norm()
+    }
+
+    /// Creates a new tapered capsule equal to `self` with all its endpoints transformed by `pos`.
+    pub fn transform_by(&self, pos: &Isometry<Real>) -> Self {
+        Self::new(
+            pos * self.segment.a,
+            pos * self.segment.b,
+            self.radius_a,
+            self.radius_b,
+        )
+    }
+}
+
+impl SupportMap for TaperedCapsule {
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let dir = Unit::try_new(*dir, 0.0).unwrap_or(Vector::y_axis());
+        self.local_support_point_toward(&dir)
+    }
+
+    fn local_support_point_toward(&self, dir: &Unit<Vector<Real>>) -> Point<Real> {
+        let support_a = self.segment.a + **dir * self.radius_a;
+        let support_b = self.segment.b + **dir * self.radius_b;
+
+        if dir.dot(&support_a.coords) > dir.dot(&support_b.coords) {
+            support_a
+        } else {
+            support_b
+        }
+    }
+}
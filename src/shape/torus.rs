@@ -0,0 +1,73 @@
+//! Definition of the torus shape.
+
+use crate::math::{Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::ComplexField;
+
+/// A torus shape, i.e., a ball of radius `minor_radius` swept along a circle of
+/// radius `major_radius` lying on the `xz` plane and centered at the origin.
+///
+/// The principal axis of the torus is aligned with the `y` axis. The torus is not convex
+/// so it is not exposed as a support map by `Shape::as_support_map`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Torus {
+    /// The radius of the circle at the center of the torus' tube.
+    pub major_radius: Real,
+    /// The radius of the torus' tube.
+    pub minor_radius: Real,
+}
+
+impl Torus {
+    /// Creates a new torus.
+    ///
+    /// # Arguments:
+    /// * `major_radius` - the distance between the center of the torus and the center of its tube.
+    /// * `minor_radius` - the radius of the torus' tube.
+    pub fn new(major_radius: Real, minor_radius: Real) -> Torus {
+        assert!(major_radius.is_sign_positive() && minor_radius.is_sign_positive());
+
+        Torus {
+            major_radius,
+            minor_radius,
+        }
+    }
+
+    /// The point of the circle at the center of the torus' tube closest to `pt`.
+    ///
+    /// If `pt` lies on the `y` axis, all the points of the circle are equally close,
+    /// and the one on the positive `x` axis is returned.
+    pub fn tube_center_point(&self, pt: &Point<Real>) -> Point<Real> {
+        let radial = Vector::new(pt.x, 0.0, pt.z);
+        let radial_norm = radial.norm();
+
+        if radial_norm > 0.0 {
+            Point::from(radial * (self.major_radius / radial_norm))
+        } else {
+            Point::new(self.major_radius, 0.0, 0.0)
+        }
+    }
+
+    /// The signed distance between `pt` and the surface of the torus.
+    ///
+    /// This is negative if `pt` is inside of the torus.
+    pub fn signed_distance_to_local_point(&self, pt: &Point<Real>) -> Real {
+        let radial = ComplexField::sqrt(pt.x * pt.x + pt.z * pt.z) - self.major_radius;
+        ComplexField::sqrt(radial * radial + pt.y * pt.y) - self.minor_radius
+    }
+}
+
+impl SupportMap for Torus {
+    /// The support point of the convex hull of this torus.
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let center = self.tube_center_point(&Point::from(*dir));
+        let norm = dir.norm();
+
+        if norm > 0.0 {
+            center + dir * (self.minor_radius / norm)
+        } else {
+            center
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::Unit;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug)]
+/// A shape positioned by a rigid transformation relative to the local-space of its parent.
+///
+/// Each point `p` of the inner shape corresponds to the point `position * p` of the transformed
+/// shape. This is a lightweight alternative to a `Compound` with a single part: there is no
+/// BVH to traverse and the queries involving this shape are forwarded to the inner shape.
+pub struct TransformedShape<S> {
+    /// The position of the inner shape relative to the local-space of this shape.
+    pub position: Isometry<Real>,
+    /// The shape being transformed.
+    pub shape: S,
+}
+
+impl<S> TransformedShape<S> {
+    /// Creates a new shape obtained by transforming `shape` by `position`.
+    pub fn new(position: Isometry<Real>, shape: S) -> Self {
+        TransformedShape { position, shape }
+    }
+}
+
+impl<S: SupportMap> SupportMap for TransformedShape<S> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        self.shape.support_point(&self.position, dir)
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<Real>>) -> Point<Real> {
+        self.shape.support_point_toward(&self.position, dir)
+    }
+}
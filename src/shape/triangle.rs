@@ -2,7 +2,7 @@
 
 use crate::math::{Isometry, Point, Real, Vector};
 use crate::shape::SupportMap;
-use crate::shape::{PolygonalFeature, Segment};
+use crate::shape::{Degeneracy, PolygonalFeature, Segment};
 use crate::utils;
 
 use na::{self, ComplexField, Unit};
@@ -123,6 +123,14 @@ impl Triangle {
         Unit::try_new(self.scaled_normal(), crate::math::DEFAULT_EPSILON)
     }
 
+    /// The point or segment this triangle collapses to if its vertices are all within the
+    /// distance `eps` of it, e.g., if two of its vertices coincide or if they are collinear.
+    ///
+    /// Returns `None` if this triangle isn't degenerate.
+    pub fn degeneracy(&self, eps: Real) -> Option<Degeneracy> {
+        Degeneracy::of_points(self.vertices(), eps)
+    }
+
     /// The three edges of this triangle: [AB, BC, CA].
     #[inline]
     pub fn edges(&self) -> [Segment; 3] {
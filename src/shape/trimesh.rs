@@ -1,10 +1,302 @@
 use crate::bounding_volume::AABB;
-use crate::math::{Isometry, Point, Real};
+use crate::math::{Isometry, Point, Real, Vector};
 use crate::partitioning::SimdQuadTree;
+#[cfg(feature = "dim3")]
+use crate::query::PointQueryWithLocation;
 use crate::shape::composite_shape::SimdCompositeShape;
 #[cfg(feature = "dim3")]
-use crate::shape::{Cuboid, HeightField};
-use crate::shape::{Shape, Triangle, TypedSimdCompositeShape};
+use crate::shape::HeightField;
+use crate::shape::{Cuboid, FeatureId, Shape, Triangle, TypedSimdCompositeShape};
+use crate::utils::hashmap::{Entry, HashMap};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A vertex of a triangle-mesh's half-edge topology.
+pub struct TopoVertex {
+    /// One of the half-edge with this vertex as endpoint.
+    pub half_edge: u32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A face of a triangle-mesh's half-edge topology.
+pub struct TopoFace {
+    /// The half-edge adjacent to this face, with a starting point equal
+    /// to the first point of this face.
+    pub half_edge: u32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A half-edge of a triangle-mesh's half-edge topology.
+pub struct TopoHalfEdge {
+    /// The next half-edge.
+    pub next: u32,
+    /// This half-edge's twin on the adjacent triangle.
+    ///
+    /// This is `u32::MAX` if there is no twin.
+    pub twin: u32,
+    /// The first vertex of this edge.
+    pub vertex: u32,
+    /// The face associated to this half-edge.
+    pub face: u32,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// The half-edge topology information of a triangle mesh.
+pub struct TriMeshTopology {
+    /// The vertices of this half-edge representation.
+    pub vertices: Vec<TopoVertex>,
+    /// The faces of this half-edge representation.
+    pub faces: Vec<TopoFace>,
+    /// The half-edges of this half-edge representation.
+    pub half_edges: Vec<TopoHalfEdge>,
+}
+
+impl TriMeshTopology {
+    /// Computes the half-edge topology of the given index buffer.
+    ///
+    /// The `i`-th face has the half-edges `3 * i`, `3 * i + 1`, and `3 * i + 2`.
+    /// Returns `None` if the index buffer is not an oriented manifold, i.e., if
+    /// a directed edge is shared by more than one triangle.
+    pub fn from_indices(num_vertices: usize, indices: &[[u32; 3]]) -> Option<Self> {
+        let mut vertices = vec![
+            TopoVertex {
+                half_edge: u32::MAX
+            };
+            num_vertices
+        ];
+        let mut faces = Vec::with_capacity(indices.len());
+        let mut half_edges = Vec::with_capacity(indices.len() * 3);
+        let mut half_edge_map = HashMap::default();
+
+        for (fid, idx) in indices.iter().enumerate() {
+            let fid = fid as u32;
+            faces.push(TopoFace { half_edge: fid * 3 });
+
+            for i in 0..3 {
+                let he_id = fid * 3 + i as u32;
+                let (a, b) = (idx[i], idx[(i + 1) % 3]);
+
+                match half_edge_map.entry((a, b)) {
+                    Entry::Occupied(_) => return None,
+                    Entry::Vacant(e) => {
+                        let _ = e.insert(he_id);
+                    }
+                }
+
+                vertices.get_mut(a as usize)?.half_edge = he_id;
+                half_edges.push(TopoHalfEdge {
+                    next: fid * 3 + (i as u32 + 1) % 3,
+                    twin: u32::MAX,
+                    vertex: a,
+                    face: fid,
+                });
+            }
+        }
+
+        for he in &mut half_edges {
+            let next_vertex = indices[he.face as usize][(he.next % 3) as usize];
+            if let Some(twin) = half_edge_map.get(&(next_vertex, he.vertex)) {
+                he.twin = *twin;
+            }
+        }
+
+        Some(Self {
+            vertices,
+            faces,
+            half_edges,
+        })
+    }
+
+    /// The three half-edges of the `face`-th face.
+    pub fn face_half_edges_ids(&self, face: u32) -> [u32; 3] {
+        let h0 = self.faces[face as usize].half_edge;
+        let h1 = self.half_edges[h0 as usize].next;
+        let h2 = self.half_edges[h1 as usize].next;
+        [h0, h1, h2]
+    }
+
+    /// The vertex at the end of the given half-edge.
+    pub fn half_edge_end(&self, half_edge: u32) -> u32 {
+        let next = self.half_edges[half_edge as usize].next;
+        self.half_edges[next as usize].vertex
+    }
+
+    /// The faces adjacent to the `face`-th face.
+    ///
+    /// The `i`-th element is the face sharing the `i`-th half-edge returned by
+    /// `self.face_half_edges_ids(face)`, i.e., the edge going from the `i`-th to the
+    /// `(i + 1) % 3`-th vertex of this face. It is `None` if this edge is on the boundary.
+    pub fn face_neighbors(&self, face: u32) -> [Option<u32>; 3] {
+        let mut neighbors = [None; 3];
+
+        for (neighbor, he) in neighbors
+            .iter_mut()
+            .zip(self.face_half_edges_ids(face).iter())
+        {
+            let twin = self.half_edges[*he as usize].twin;
+
+            if twin != u32::MAX {
+                *neighbor = Some(self.half_edges[twin as usize].face);
+            }
+        }
+
+        neighbors
+    }
+
+    /// The half-edges starting at the `vertex`-th vertex.
+    ///
+    /// These are found by turning around the vertex, so if several fans of faces only
+    /// touch at this vertex, only the half-edges of one of these fans are returned.
+    pub fn vertex_half_edges(&self, vertex: u32) -> Vec<u32> {
+        let mut result = Vec::new();
+        let start = match self.vertices.get(vertex as usize) {
+            Some(v) if v.half_edge != u32::MAX => v.half_edge,
+            _ => return result,
+        };
+
+        // Turn in one direction until we reach a boundary or come back to the start.
+        let mut he = start;
+        loop {
+            result.push(he);
+            let prev = self.half_edges[self.half_edges[he as usize].next as usize].next;
+            let twin = self.half_edges[prev as usize].twin;
+
+            if twin == start {
+                return result;
+            } else if twin == u32::MAX {
+                break;
+            }
+
+            he = twin;
+        }
+
+        // We reached a boundary so turn in the other direction to get the rest of the fan.
+        let mut he = start;
+        loop {
+            let twin = self.half_edges[he as usize].twin;
+
+            if twin == u32::MAX {
+                return result;
+            }
+
+            he = self.half_edges[twin as usize].next;
+            result.push(he);
+        }
+    }
+
+    /// The faces containing the `vertex`-th vertex.
+    ///
+    /// See `self.vertex_half_edges` for the limitations on non-manifold vertices.
+    pub fn vertex_faces(&self, vertex: u32) -> Vec<u32> {
+        self.vertex_half_edges(vertex)
+            .into_iter()
+            .map(|he| self.half_edges[he as usize].face)
+            .collect()
+    }
+
+    /// The half-edge going from the vertex `a` to the vertex `b`, if there is one.
+    pub fn half_edge_between(&self, a: u32, b: u32) -> Option<u32> {
+        self.vertex_half_edges(a)
+            .into_iter()
+            .find(|he| self.half_edge_end(*he) == b)
+    }
+
+    /// The faces on both sides of the edge between the vertices `a` and `b`.
+    ///
+    /// The first element is the face containing the half-edge from `a` to `b`, and the
+    /// second one is the face containing the half-edge from `b` to `a`.
+    pub fn edge_faces(&self, a: u32, b: u32) -> [Option<u32>; 2] {
+        let face = |he: u32| self.half_edges[he as usize].face;
+        [
+            self.half_edge_between(a, b).map(face),
+            self.half_edge_between(b, a).map(face),
+        ]
+    }
+}
+
+#[cfg(feature = "dim3")]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// The angle-weighted pseudo-normals of the vertices and edges of a triangle mesh.
+///
+/// The pseudo-normal of a vertex is the sum of the normals of the triangles containing
+/// it, weighted by the angle of these triangles at this vertex. The pseudo-normal of an
+/// edge is the sum of the normals of the triangles sharing this edge. If the mesh is
+/// closed and its triangles are oriented consistently, the point of the mesh closest to
+/// any point `p` outside of the mesh is a feature with a pseudo-normal pointing toward `p`.
+pub struct TriMeshPseudoNormals {
+    /// The normalized pseudo-normal of each vertex of the mesh.
+    pub vertices_pseudo_normal: Vec<Vector<Real>>,
+    /// The normalized pseudo-normals of the edges of each triangle of the mesh.
+    ///
+    /// The edges are in the same order as for `TrianglePointLocation::OnEdge`, i.e.,
+    /// the edges AB, BC, and AC.
+    pub edges_pseudo_normal: Vec<[Vector<Real>; 3]>,
+}
+
+#[cfg(feature = "dim3")]
+impl TriMeshPseudoNormals {
+    /// Computes the pseudo-normals of the mesh with the given vertex and index buffers.
+    ///
+    /// Degenerate triangles don't contribute to any pseudo-normal.
+    pub fn new(vertices: &[Point<Real>], indices: &[[u32; 3]]) -> Self {
+        let mut vertices_pseudo_normal = vec![Vector::zeros(); vertices.len()];
+        let mut edges_normal: HashMap<(u32, u32), Vector<Real>> = HashMap::default();
+        let edge_key = |a: u32, b: u32| (a.min(b), a.max(b));
+
+        for idx in indices {
+            let pts = [
+                vertices[idx[0] as usize],
+                vertices[idx[1] as usize],
+                vertices[idx[2] as usize],
+            ];
+
+            if let Some(normal) = Triangle::new(pts[0], pts[1], pts[2]).normal() {
+                for k in 0..3 {
+                    let angle = (pts[(k + 1) % 3] - pts[k]).angle(&(pts[(k + 2) % 3] - pts[k]));
+                    vertices_pseudo_normal[idx[k] as usize] += *normal * angle;
+                }
+
+                for k in 0..3 {
+                    *edges_normal
+                        .entry(edge_key(idx[k], idx[(k + 1) % 3]))
+                        .or_insert_with(Vector::zeros) += *normal;
+                }
+            }
+        }
+
+        let normalize = |v: Vector<Real>| v.try_normalize(0.0).unwrap_or(v);
+        let edge_normal = |a: u32, b: u32| {
+            edges_normal
+                .get(&edge_key(a, b))
+                .copied()
+                .map(normalize)
+                .unwrap_or_else(Vector::zeros)
+        };
+        let edges_pseudo_normal = indices
+            .iter()
+            .map(|idx| {
+                [
+                    edge_normal(idx[0], idx[1]),
+                    edge_normal(idx[1], idx[2]),
+                    edge_normal(idx[0], idx[2]),
+                ]
+            })
+            .collect();
+
+        for n in &mut vertices_pseudo_normal {
+            *n = normalize(*n);
+        }
+
+        Self {
+            vertices_pseudo_normal,
+            edges_pseudo_normal,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -13,16 +305,24 @@ pub struct TriMesh {
     quadtree: SimdQuadTree<u32>,
     vertices: Vec<Point<Real>>,
     indices: Vec<[u32; 3]>,
+    #[cfg_attr(feature = "serde-serialize", serde(default))]
+    triangle_data: Option<Vec<u32>>,
+    #[cfg_attr(feature = "serde-serialize", serde(default))]
+    topology: Option<TriMeshTopology>,
+    #[cfg(feature = "dim3")]
+    #[cfg_attr(feature = "serde-serialize", serde(default))]
+    pseudo_normals: Option<TriMeshPseudoNormals>,
+    #[cfg(feature = "dim3")]
+    #[cfg_attr(feature = "serde-serialize", serde(default))]
+    pub(crate) ccd_thickness: Real,
 }
 
 impl TriMesh {
     /// Creates a new triangle mesh from a vertex buffer and an index buffer.
+    ///
+    /// The mesh may be empty, in which case its AABB is invalid and queries
+    /// against it never report any hit.
     pub fn new(vertices: Vec<Point<Real>>, indices: Vec<[u32; 3]>) -> Self {
-        assert!(
-            indices.len() > 0,
-            "A triangle mesh must contain at least one triangle."
-        );
-
         let data = indices.iter().enumerate().map(|(i, idx)| {
             let aabb = Triangle::new(
                 vertices[idx[0] as usize],
@@ -42,11 +342,254 @@ impl TriMesh {
             quadtree,
             vertices,
             indices,
+            triangle_data: None,
+            topology: None,
+            #[cfg(feature = "dim3")]
+            pseudo_normals: None,
+            #[cfg(feature = "dim3")]
+            ccd_thickness: 0.0,
         }
     }
 
+    /// Creates a new triangle mesh from a vertex buffer and a 16-bits index buffer.
+    ///
+    /// The indices are widened to `u32` internally so this does not reduce the memory
+    /// used by the triangle mesh itself. This is only a convenience for when the mesh
+    /// data is already stored with 16-bits indices.
+    pub fn from_u16_indices(vertices: Vec<Point<Real>>, indices: &[[u16; 3]]) -> Self {
+        let indices = indices
+            .iter()
+            .map(|idx| [idx[0] as u32, idx[1] as u32, idx[2] as u32])
+            .collect();
+        Self::new(vertices, indices)
+    }
+
+    /// Attaches one user-defined value to each triangle of this mesh.
+    ///
+    /// The `i`-th element of `data` is associated to the `i`-th triangle. This can be used,
+    /// e.g., to identify the material of the surface hit by a query: the triangle index is
+    /// given by the `FeatureId::Face` returned by ray-casts and point projections, or by
+    /// the `subshape` index of the contact manifolds involving this mesh.
+    ///
+    /// Panics if `data` does not contain exactly one element per triangle.
+    pub fn with_triangle_data(mut self, data: Vec<u32>) -> Self {
+        assert_eq!(
+            data.len(),
+            self.indices.len(),
+            "There must be exactly one data per triangle."
+        );
+        self.triangle_data = Some(data);
+        self
+    }
+
+    /// Sets the thickness of this mesh used by continuous collision detection.
+    ///
+    /// A 3D triangle mesh has no interior so its thickness is zero by default, which forces
+    /// continuous collision detection to substep any motion toward it. A positive thickness
+    /// can be set if the mesh models, e.g., a wall or a floor of known thickness. It is
+    /// returned by `Shape::ccd_thickness`.
+    #[cfg(feature = "dim3")]
+    pub fn with_ccd_thickness(mut self, thickness: Real) -> Self {
+        self.ccd_thickness = thickness;
+        self
+    }
+
+    /// Sets the thickness of this mesh used by continuous collision detection.
+    ///
+    /// See `self.with_ccd_thickness` for details.
+    #[cfg(feature = "dim3")]
+    pub fn set_ccd_thickness(&mut self, thickness: Real) {
+        self.ccd_thickness = thickness;
+    }
+
+    /// Copies the triangle data, the topology, and the CCD thickness of `other`, which has
+    /// the same index buffer as this mesh.
+    ///
+    /// The pseudo-normals are recomputed if `other` has some, since they depend on the
+    /// positions of the vertices.
+    pub(crate) fn with_attributes_of(mut self, other: &TriMesh) -> Self {
+        self.triangle_data = other.triangle_data.clone();
+        self.topology = other.topology.clone();
+
+        #[cfg(feature = "dim3")]
+        {
+            self.ccd_thickness = other.ccd_thickness;
+        }
+
+        #[cfg(feature = "dim3")]
+        if other.pseudo_normals.is_some() {
+            self.compute_pseudo_normals();
+        }
+
+        self
+    }
+
+    /// The user-defined values attached to the triangles of this mesh, if any.
+    pub fn triangles_data(&self) -> Option<&[u32]> {
+        self.triangle_data.as_deref()
+    }
+
+    /// The user-defined value attached to the `i`-th triangle of this mesh, if any.
+    pub fn triangle_data(&self, i: u32) -> Option<u32> {
+        self.triangle_data.as_ref()?.get(i as usize).copied()
+    }
+
+    /// The user-defined value attached to the triangle identified by `feature`, if any.
+    ///
+    /// Only `FeatureId::Face` identifies a triangle, so this returns `None` for any other
+    /// feature.
+    pub fn feature_data(&self, feature: FeatureId) -> Option<u32> {
+        match feature {
+            FeatureId::Face(i) => self.triangle_data(i),
+            _ => None,
+        }
+    }
+
+    /// Computes and stores the half-edge topology of this mesh.
+    ///
+    /// Returns `false` and leaves this mesh without topology if its index buffer is not an
+    /// oriented manifold, i.e., if a directed edge is shared by more than one triangle.
+    pub fn compute_topology(&mut self) -> bool {
+        self.topology = TriMeshTopology::from_indices(self.vertices.len(), &self.indices);
+        self.topology.is_some()
+    }
+
+    /// The half-edge topology of this mesh, if it has been computed with `self.compute_topology()`.
+    pub fn topology(&self) -> Option<&TriMeshTopology> {
+        self.topology.as_ref()
+    }
+
+    /// The triangles adjacent to the `i`-th triangle of this mesh.
+    ///
+    /// The `k`-th element is the triangle sharing the edge between the `k`-th and the
+    /// `(k + 1) % 3`-th vertex of the `i`-th triangle, or `None` if this edge is on the
+    /// boundary. Returns `None` if the topology of this mesh has not been computed.
+    pub fn triangle_neighbors(&self, i: u32) -> Option<[Option<u32>; 3]> {
+        Some(self.topology.as_ref()?.face_neighbors(i))
+    }
+
+    /// Computes and stores the pseudo-normals of this mesh.
+    ///
+    /// Once computed, the pseudo-normals are used by point queries to determine if a point
+    /// is inside of this mesh, which is needed for `self.contains_local_point` and for
+    /// projections with `solid = true`. This only gives meaningful results if this mesh
+    /// is closed and its triangles are oriented consistently with outward normals.
+    #[cfg(feature = "dim3")]
+    pub fn compute_pseudo_normals(&mut self) {
+        self.pseudo_normals = Some(TriMeshPseudoNormals::new(&self.vertices, &self.indices));
+    }
+
+    /// The pseudo-normals of this mesh, if they have been computed with
+    /// `self.compute_pseudo_normals()`.
+    #[cfg(feature = "dim3")]
+    pub fn pseudo_normals(&self) -> Option<&TriMeshPseudoNormals> {
+        self.pseudo_normals.as_ref()
+    }
+
+    /// The signed distance between `pt` and the surface of this mesh.
+    ///
+    /// This is negative if `pt` is inside of this mesh. The sign is determined from the
+    /// pseudo-normals of the mesh feature closest to `pt`, so this returns `None` if they
+    /// haven't been computed with `self.compute_pseudo_normals()`.
+    #[cfg(feature = "dim3")]
+    pub fn signed_distance_to_local_point(&self, pt: &Point<Real>) -> Option<Real> {
+        let _ = self.pseudo_normals.as_ref()?;
+        let (proj, _) = self.project_local_point_and_get_location(pt, false);
+        let dist = na::distance(pt, &proj.point);
+        Some(if proj.is_inside { -dist } else { dist })
+    }
+
+    /// Transforms in-place all the vertices of this mesh by `transform`.
+    ///
+    /// The acceleration structure of this mesh is refitted to the new vertex positions instead
+    /// of being rebuilt from scratch. The triangle data, the topology, and the pseudo-normals
+    /// of this mesh are preserved.
+    pub fn transform_vertices(&mut self, transform: &Isometry<Real>) {
+        self.vertices
+            .iter_mut()
+            .for_each(|pt| *pt = transform * *pt);
+
+        #[cfg(feature = "dim3")]
+        if let Some(pseudo_normals) = &mut self.pseudo_normals {
+            let normals = pseudo_normals
+                .vertices_pseudo_normal
+                .iter_mut()
+                .chain(pseudo_normals.edges_pseudo_normal.iter_mut().flatten());
+
+            for n in normals {
+                *n = transform * *n;
+            }
+        }
+
+        self.refit();
+    }
+
+    /// Scales in-place all the vertices of this mesh by the componentwise factors `scale`.
+    ///
+    /// The acceleration structure of this mesh is refitted to the new vertex positions instead
+    /// of being rebuilt from scratch. The triangle data and the topology of this mesh are
+    /// preserved, and its pseudo-normals are recomputed if it has any. If an odd number of
+    /// scaling factors are negative, the scaling is a reflection: the winding of the triangles
+    /// is flipped so they keep their orientation, and the topology is recomputed. In 3D,
+    /// the CCD thickness is scaled by the smallest absolute scaling factor.
+    ///
+    /// Panics if any scaling factor is zero.
+    pub fn scale(&mut self, scale: &Vector<Real>) {
+        assert!(
+            scale.iter().all(|s| *s != 0.0),
+            "The scaling factors of a triangle mesh must not be zero."
+        );
+
+        self.vertices
+            .iter_mut()
+            .for_each(|pt| pt.coords.component_mul_assign(scale));
+
+        if scale.iter().product::<Real>() < 0.0 {
+            self.indices.iter_mut().for_each(|idx| idx.swap(1, 2));
+
+            if self.topology.is_some() {
+                // The flipped winding reverses all the half-edges.
+                let _ = self.compute_topology();
+            }
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            self.ccd_thickness *= scale.abs().min();
+
+            if self.pseudo_normals.is_some() {
+                self.compute_pseudo_normals();
+            }
+        }
+
+        self.refit();
+    }
+
+    /// Recomputes the AABBs of the acceleration structure after a change of the vertex positions.
+    fn refit(&mut self) {
+        let vertices = &self.vertices;
+        let indices = &self.indices;
+        self.quadtree.refit(
+            |i| {
+                let idx = indices[*i as usize];
+                Triangle::new(
+                    vertices[idx[0] as usize],
+                    vertices[idx[1] as usize],
+                    vertices[idx[2] as usize],
+                )
+                .local_aabb()
+            },
+            0.0,
+        );
+    }
+
     /// Compute the axis-aligned bounding box of this triangle mesh.
     pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        if self.is_empty() {
+            // Transforming the invalid AABB would result in NaNs.
+            return AABB::new_invalid();
+        }
+
         self.quadtree.root_aabb().transform_by(pos)
     }
 
@@ -55,6 +598,13 @@ impl TriMesh {
         self.quadtree.root_aabb()
     }
 
+    /// Computes an oriented bounding box enclosing the vertices of this triangle mesh.
+    ///
+    /// Returns the position of the box in the local-space of this mesh, and the box itself.
+    pub fn compute_obb(&self) -> (Isometry<Real>, Cuboid) {
+        crate::utils::obb(&self.vertices)
+    }
+
     /// The acceleration structure used by this triangle-mesh.
     pub fn quadtree(&self) -> &SimdQuadTree<u32> {
         &self.quadtree
@@ -65,6 +615,11 @@ impl TriMesh {
         self.indices.len()
     }
 
+    /// Returns `true` if this mesh does not contain any triangle.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
     /// An iterator through all the triangles of this mesh.
     pub fn triangles(&self) -> impl Iterator<Item = Triangle> + '_ {
         self.indices.iter().map(move |ids| {
@@ -86,6 +641,40 @@ impl TriMesh {
         )
     }
 
+    /// The edges of this mesh that belong to a single triangle, sorted lexicographically.
+    ///
+    /// Each edge is given by the indices of its endpoints, the smallest first. This is empty
+    /// if this mesh is closed.
+    pub fn boundary_edges(&self) -> Vec<[u32; 2]> {
+        let mut edges: Vec<_> = self
+            .indices
+            .iter()
+            .flat_map(|idx| (0..3).map(move |k| (idx[k], idx[(k + 1) % 3])))
+            .map(|(a, b)| [a.min(b), a.max(b)])
+            .collect();
+        // Sorting instead of hashing keeps the output deterministic.
+        edges.sort_unstable();
+
+        let mut boundary = Vec::new();
+        let mut i = 0;
+
+        while i < edges.len() {
+            let mut j = i + 1;
+
+            while j < edges.len() && edges[j] == edges[i] {
+                j += 1;
+            }
+
+            if j == i + 1 {
+                boundary.push(edges[i]);
+            }
+
+            i = j;
+        }
+
+        boundary
+    }
+
     /// The vertex buffer of this mesh.
     pub fn vertices(&self) -> &[Point<Real>] {
         &self.vertices[..]
@@ -157,7 +746,7 @@ impl RayCast for TriMesh {
 impl From<HeightField> for TriMesh {
     fn from(heightfield: HeightField) -> Self {
         let (vtx, idx) = heightfield.to_trimesh();
-        TriMesh::new(vtx, idx)
+        TriMesh::new(vtx, idx).with_ccd_thickness(heightfield.ccd_thickness)
     }
 }
 
@@ -0,0 +1,195 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::partitioning::SimdQuadTree;
+use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{Cuboid, Shape, TypedSimdCompositeShape};
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A sparse set of voxels, i.e., axis-aligned boxes of the same size laid out on a regular grid.
+///
+/// Each voxel is identified by its integer grid coordinates, called its key: the voxel with the
+/// key `k` occupies the box from `k * voxel_size` to `(k + 1) * voxel_size` (componentwise).
+/// Only the occupied voxels are stored, so large mostly-empty volumes remain cheap.
+pub struct Voxels {
+    quadtree: SimdQuadTree<u32>,
+    voxel_size: Vector<Real>,
+    // Sorted in lexicographic order, to speed-up lookups.
+    keys: Vec<Point<i32>>,
+}
+
+fn cmp_keys(a: &Point<i32>, b: &Point<i32>) -> Ordering {
+    a.coords.iter().cmp(b.coords.iter())
+}
+
+impl Voxels {
+    /// Creates a new set of voxels from the keys of all its occupied voxels.
+    ///
+    /// Duplicate keys are ignored. The set may be empty, in which case its AABB is invalid and
+    /// queries against it never report any hit.
+    pub fn new(voxel_size: Vector<Real>, keys: &[Point<i32>]) -> Self {
+        assert!(
+            voxel_size.iter().all(|e| *e > 0.0),
+            "The size of a voxel must be positive."
+        );
+
+        let mut keys = keys.to_vec();
+        keys.sort_unstable_by(cmp_keys);
+        keys.dedup();
+
+        let data = keys.iter().enumerate().map(|(i, key)| {
+            let mins = Point::from(key.coords.map(|e| e as Real).component_mul(&voxel_size));
+            (i as u32, AABB::new(mins, mins + voxel_size))
+        });
+
+        let mut quadtree = SimdQuadTree::new();
+        // NOTE: we apply no dilation factor because we won't
+        // update this tree dynamically.
+        quadtree.clear_and_rebuild(data, 0.0);
+
+        Self {
+            quadtree,
+            voxel_size,
+            keys,
+        }
+    }
+
+    /// Creates a new set of voxels containing all the voxels containing at least one of the
+    /// given points.
+    pub fn from_points(voxel_size: Vector<Real>, points: &[Point<Real>]) -> Self {
+        let keys: Vec<_> = points
+            .iter()
+            .map(|pt| Self::key_at(&voxel_size, pt))
+            .collect();
+        Self::new(voxel_size, &keys)
+    }
+
+    fn key_at(voxel_size: &Vector<Real>, pt: &Point<Real>) -> Point<i32> {
+        Point::from(
+            pt.coords
+                .component_div(voxel_size)
+                .map(|e| e.floor() as i32),
+        )
+    }
+
+    /// Compute the axis-aligned bounding box of this set of voxels.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> AABB {
+        if self.is_empty() {
+            // Transforming the invalid AABB would result in NaNs.
+            return AABB::new_invalid();
+        }
+
+        self.quadtree.root_aabb().transform_by(pos)
+    }
+
+    /// Gets the local axis-aligned bounding box of this set of voxels.
+    pub fn local_aabb(&self) -> &AABB {
+        self.quadtree.root_aabb()
+    }
+
+    pub(crate) fn quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+
+    /// The size of each voxel along each coordinate axis.
+    pub fn voxel_size(&self) -> &Vector<Real> {
+        &self.voxel_size
+    }
+
+    /// The keys of all the occupied voxels, in lexicographic order.
+    pub fn keys(&self) -> &[Point<i32>] {
+        &self.keys
+    }
+
+    /// The number of occupied voxels.
+    pub fn num_voxels(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if this set does not contain any voxel.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The key of the voxel containing the point `pt`, whether this voxel is occupied or not.
+    pub fn voxel_key_at(&self, pt: &Point<Real>) -> Point<i32> {
+        Self::key_at(&self.voxel_size, pt)
+    }
+
+    /// The index, in `self.keys()`, of the voxel with the given key, if it is occupied.
+    pub fn voxel_index(&self, key: &Point<i32>) -> Option<u32> {
+        self.keys
+            .binary_search_by(|k| cmp_keys(k, key))
+            .ok()
+            .map(|i| i as u32)
+    }
+
+    /// Returns `true` if the voxel with the given key is occupied.
+    pub fn contains_voxel(&self, key: &Point<i32>) -> bool {
+        self.voxel_index(key).is_some()
+    }
+
+    /// The AABB of the voxel with the given key, whether this voxel is occupied or not.
+    pub fn voxel_aabb(&self, key: &Point<i32>) -> AABB {
+        let mins = Point::from(
+            key.coords
+                .map(|e| e as Real)
+                .component_mul(&self.voxel_size),
+        );
+        AABB::new(mins, mins + self.voxel_size)
+    }
+
+    /// The center of the voxel with the given key, whether this voxel is occupied or not.
+    pub fn voxel_center(&self, key: &Point<i32>) -> Point<Real> {
+        Point::from(
+            (key.coords.map(|e| e as Real) + Vector::repeat(0.5)).component_mul(&self.voxel_size),
+        )
+    }
+
+    /// The cuboid shape of each voxel, centered at the origin.
+    pub fn voxel_shape(&self) -> Cuboid {
+        Cuboid::new(self.voxel_size / 2.0)
+    }
+
+    /// The position of the `i`-th occupied voxel's cuboid shape.
+    pub fn voxel_position(&self, i: u32) -> Isometry<Real> {
+        Isometry::new(self.voxel_center(&self.keys[i as usize]).coords, na::zero())
+    }
+}
+
+impl SimdCompositeShape for Voxels {
+    fn map_part_at(&self, i: u32, f: &mut dyn FnMut(Option<&Isometry<Real>>, &dyn Shape)) {
+        let pos = self.voxel_position(i);
+        f(Some(&pos), &self.voxel_shape())
+    }
+
+    fn quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+}
+
+impl TypedSimdCompositeShape for Voxels {
+    type PartShape = Cuboid;
+    type PartId = u32;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(Option<&Isometry<Real>>, &Self::PartShape),
+    ) {
+        let pos = self.voxel_position(i);
+        f(Some(&pos), &self.voxel_shape())
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(&self, i: u32, mut f: impl FnMut(Option<&Isometry<Real>>, &dyn Shape)) {
+        let pos = self.voxel_position(i);
+        f(Some(&pos), &self.voxel_shape())
+    }
+
+    fn typed_quadtree(&self) -> &SimdQuadTree<u32> {
+        &self.quadtree
+    }
+}
@@ -0,0 +1,339 @@
+use crate::math::Real;
+use crate::utils;
+use crate::utils::hashmap::HashMap;
+use na::{Point3, Unit, Vector3};
+use std::cmp::Ordering;
+
+// The half-edge of a re-triangulated face, identified by what determines its twin.
+#[derive(Copy, Clone)]
+enum HalfEdgeSource {
+    // The half-edge `3 * triangle_id + edge_id` of the original triangulation with the same
+    // endpoints. Its twin is the one of this original half-edge.
+    Original(u32),
+    // A diagonal of a fan, with the given twin in the new triangulation.
+    Diagonal(u32),
+}
+
+// The vertices, triangles, and half-edge twins of a convex hull.
+type Hull = (Vec<Point3<Real>>, Vec<[u32; 3]>, Option<Vec<[u32; 3]>>);
+
+/// Reorders the vertices and triangles of a convex hull into a canonical form.
+///
+/// The vertices are sorted lexicographically, the coplanar triangles forming a single
+/// polygonal face are re-triangulated as a fan starting at their smallest vertex index,
+/// each triangle is rotated so it starts with its smallest vertex index (preserving its
+/// winding), and the triangles are sorted lexicographically. This makes the output only
+/// depend on the hull itself, not on the order of the input points.
+///
+/// The `k`-th element of `twins[i]` is the twin of the half-edge going from the `k`-th to
+/// the `(k + 1) % 3`-th vertex of the `i`-th triangle. Half-edges are identified by
+/// `3 * triangle_id + k`, and `u32::MAX` indicates a half-edge without twin. The twins are
+/// updated along with the triangles.
+///
+/// If `twins` is `None`, the triangulation of the faces is kept unchanged. This is the case
+/// for the double-sided meshes of planar hulls, where both sides share all their vertices.
+pub(super) fn canonicalize_hull(
+    vertices: Vec<Point3<Real>>,
+    indices: Vec<[u32; 3]>,
+    twins: Option<Vec<[u32; 3]>>,
+) -> Hull {
+    let mut order: Vec<usize> = (0..vertices.len()).collect();
+    order.sort_by(|a, b| {
+        let (pa, pb) = (&vertices[*a], &vertices[*b]);
+        (0..3)
+            .map(|k| pa[k].partial_cmp(&pb[k]).unwrap_or(Ordering::Equal))
+            .find(|ord| *ord != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut remap = vec![0; vertices.len()];
+    for (new_id, old_id) in order.iter().enumerate() {
+        remap[*old_id] = new_id as u32;
+    }
+
+    let vertices: Vec<_> = order.iter().map(|i| vertices[*i]).collect();
+    let indices: Vec<_> = indices
+        .iter()
+        .map(|idx| {
+            [
+                remap[idx[0] as usize],
+                remap[idx[1] as usize],
+                remap[idx[2] as usize],
+            ]
+        })
+        .collect();
+
+    let (indices, twins) = match twins {
+        Some(twins) => {
+            let (indices, twins) = retriangulate_coplanar_faces(&vertices, &indices, &twins);
+            (indices, Some(twins))
+        }
+        None => (indices, None),
+    };
+
+    let (indices, twins) = rotate_and_sort_triangles(&indices, twins.as_deref());
+    (vertices, indices, twins)
+}
+
+// Rotates each triangle so it starts with its smallest vertex index, then sorts the triangles.
+fn rotate_and_sort_triangles(
+    indices: &[[u32; 3]],
+    twins: Option<&[[u32; 3]]>,
+) -> (Vec<[u32; 3]>, Option<Vec<[u32; 3]>>) {
+    let shifts: Vec<_> = indices
+        .iter()
+        .map(|idx| (0..3).min_by_key(|k| idx[*k]).unwrap())
+        .collect();
+    let rotated: Vec<_> = indices
+        .iter()
+        .zip(shifts.iter())
+        .map(|(idx, shift)| {
+            let mut idx = *idx;
+            idx.rotate_left(*shift);
+            idx
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..indices.len()).collect();
+    order.sort_unstable_by_key(|i| rotated[*i]);
+    let sorted = order.iter().map(|i| rotated[*i]).collect();
+
+    let twins = twins.map(|twins| {
+        // The new identifier of each half-edge.
+        let mut half_edge_map = vec![0; indices.len() * 3];
+        for (new_id, old_id) in order.iter().enumerate() {
+            for k in 0..3 {
+                let old_k = (k + shifts[*old_id]) % 3;
+                half_edge_map[old_id * 3 + old_k] = (new_id * 3 + k) as u32;
+            }
+        }
+
+        order
+            .iter()
+            .map(|old_id| {
+                let mut twin = [u32::MAX; 3];
+                for k in 0..3 {
+                    let old_twin = twins[*old_id][(k + shifts[*old_id]) % 3];
+                    if old_twin != u32::MAX {
+                        twin[k] = half_edge_map[old_twin as usize];
+                    }
+                }
+                twin
+            })
+            .collect()
+    });
+
+    (sorted, twins)
+}
+
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+
+    i
+}
+
+// The triangulation of a planar face depends on the order the hull points were
+// inserted, so we replace it by a fan triangulation of the face's boundary.
+fn retriangulate_coplanar_faces(
+    vertices: &[Point3<Real>],
+    indices: &[[u32; 3]],
+    twins: &[[u32; 3]],
+) -> (Vec<[u32; 3]>, Vec<[u32; 3]>) {
+    let eps = crate::math::DEFAULT_EPSILON * 100.0;
+    let normals: Vec<_> = indices
+        .iter()
+        .map(|idx| {
+            utils::ccw_face_normal([
+                &vertices[idx[0] as usize],
+                &vertices[idx[1] as usize],
+                &vertices[idx[2] as usize],
+            ])
+        })
+        .collect();
+
+    // Group adjacent coplanar triangles.
+    let mut parents: Vec<usize> = (0..indices.len()).collect();
+    for (i, twin) in twins.iter().enumerate() {
+        for he in twin.iter().filter(|he| **he != u32::MAX) {
+            let j = *he as usize / 3;
+            if let (Some(n1), Some(n2)) = (normals[i], normals[j]) {
+                if n1.dot(&n2) > 1.0 - eps {
+                    let (ri, rj) = (find_root(&mut parents, i), find_root(&mut parents, j));
+                    parents[ri] = rj;
+                }
+            }
+        }
+    }
+
+    let mut groups = vec![Vec::new(); indices.len()];
+    for i in 0..indices.len() {
+        groups[find_root(&mut parents, i)].push(i);
+    }
+
+    let mut result = Vec::with_capacity(indices.len());
+    let mut sources = Vec::with_capacity(indices.len());
+    let originals = |i: usize| {
+        let i = i as u32 * 3;
+        [
+            HalfEdgeSource::Original(i),
+            HalfEdgeSource::Original(i + 1),
+            HalfEdgeSource::Original(i + 2),
+        ]
+    };
+
+    for triangles in groups.iter().filter(|g| !g.is_empty()) {
+        if triangles.len() == 1 {
+            result.push(indices[triangles[0]]);
+            sources.push(originals(triangles[0]));
+            continue;
+        }
+
+        match face_boundary(indices, twins, &mut parents, triangles) {
+            // The fan only covers the face exactly if it has no interior
+            // vertex and if it is strictly convex.
+            Some((polygon, edges))
+                if polygon.len() == triangles.len() + 2
+                    && is_strictly_convex(vertices, &polygon, normals[triangles[0]], eps) =>
+            {
+                let n = polygon.len();
+
+                for i in 1..n - 1 {
+                    let first = (result.len() * 3) as u32;
+                    let diagonal_start = if i == 1 {
+                        HalfEdgeSource::Original(edges[0])
+                    } else {
+                        // The last half-edge of the previous triangle of the fan.
+                        HalfEdgeSource::Diagonal(first - 1)
+                    };
+                    let diagonal_end = if i == n - 2 {
+                        HalfEdgeSource::Original(edges[n - 1])
+                    } else {
+                        // The first half-edge of the next triangle of the fan.
+                        HalfEdgeSource::Diagonal(first + 3)
+                    };
+
+                    result.push([polygon[0], polygon[i], polygon[i + 1]]);
+                    sources.push([
+                        diagonal_start,
+                        HalfEdgeSource::Original(edges[i]),
+                        diagonal_end,
+                    ]);
+                }
+            }
+            _ => {
+                for i in triangles {
+                    result.push(indices[*i]);
+                    sources.push(originals(*i));
+                }
+            }
+        }
+    }
+
+    // The new identifier of each original half-edge that still exists.
+    let mut half_edge_map = vec![u32::MAX; indices.len() * 3];
+    for (i, source) in sources.iter().enumerate() {
+        for (k, he_source) in source.iter().enumerate() {
+            if let HalfEdgeSource::Original(he) = he_source {
+                half_edge_map[*he as usize] = (i * 3 + k) as u32;
+            }
+        }
+    }
+
+    let new_twins = sources
+        .iter()
+        .map(|source| {
+            let mut twin = [u32::MAX; 3];
+            for k in 0..3 {
+                twin[k] = match source[k] {
+                    HalfEdgeSource::Original(he) => {
+                        let old_twin = twins[he as usize / 3][he as usize % 3];
+                        if old_twin == u32::MAX {
+                            u32::MAX
+                        } else {
+                            half_edge_map[old_twin as usize]
+                        }
+                    }
+                    HalfEdgeSource::Diagonal(he) => he,
+                };
+            }
+            twin
+        })
+        .collect();
+
+    (result, new_twins)
+}
+
+// Extracts the boundary of a group of triangles, starting with its smallest vertex index.
+//
+// Returns the vertices of the boundary, and the original half-edge from each vertex to
+// the next one.
+fn face_boundary(
+    indices: &[[u32; 3]],
+    twins: &[[u32; 3]],
+    parents: &mut [usize],
+    triangles: &[usize],
+) -> Option<(Vec<u32>, Vec<u32>)> {
+    let mut next = HashMap::default();
+
+    for i in triangles {
+        let idx = indices[*i];
+
+        for k in 0..3 {
+            let (a, b) = (idx[k], idx[(k + 1) % 3]);
+            let twin = twins[*i][k];
+            let is_interior =
+                twin != u32::MAX && find_root(parents, twin as usize / 3) == find_root(parents, *i);
+
+            if !is_interior && next.insert(a, (b, (*i * 3 + k) as u32)).is_some() {
+                // The boundary is not a simple polygon.
+                return None;
+            }
+        }
+    }
+
+    let start = *next.keys().min()?;
+    let mut polygon = vec![start];
+    let (mut curr, edge) = *next.get(&start)?;
+    let mut edges = vec![edge];
+
+    while curr != start {
+        if polygon.len() == next.len() {
+            return None;
+        }
+
+        polygon.push(curr);
+        let (b, edge) = *next.get(&curr)?;
+        curr = b;
+        edges.push(edge);
+    }
+
+    if polygon.len() == next.len() {
+        Some((polygon, edges))
+    } else {
+        None
+    }
+}
+
+fn is_strictly_convex(
+    vertices: &[Point3<Real>],
+    polygon: &[u32],
+    normal: Option<Unit<Vector3<Real>>>,
+    eps: Real,
+) -> bool {
+    let normal = match normal {
+        Some(normal) => normal,
+        None => return false,
+    };
+
+    (0..polygon.len()).all(|i| {
+        let corner = utils::ccw_face_normal([
+            &vertices[polygon[i] as usize],
+            &vertices[polygon[(i + 1) % polygon.len()] as usize],
+            &vertices[polygon[(i + 2) % polygon.len()] as usize],
+        ]);
+        corner.map(|n| n.dot(&normal) > 1.0 - eps).unwrap_or(false)
+    })
+}
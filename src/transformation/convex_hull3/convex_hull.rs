@@ -1,17 +1,107 @@
 use super::InitialMesh;
 use super::TriangleFacet;
 use crate::math::Real;
+use crate::shape::{TopoFace, TopoHalfEdge, TopoVertex, TriMeshTopology};
 use crate::transformation::convex_hull_utils::indexed_support_point_nth;
 use crate::transformation::convex_hull_utils::{indexed_support_point_id, normalize};
 use crate::utils;
 use na::{self, Point3};
 
 /// Computes the convex hull of a set of 3d points.
+///
+/// The output is canonical: it only depends on the set of input points, not on their order.
+/// The hull vertices are sorted lexicographically, each planar face is triangulated as a fan
+/// starting at its smallest vertex index, each triangle starts with its smallest vertex index,
+/// and the triangles are sorted lexicographically.
 pub fn convex_hull(points: &[Point3<Real>]) -> (Vec<Point3<Real>>, Vec<[u32; 3]>) {
+    let (vertices, indices, _) = canonical_convex_hull(points);
+    (vertices, indices)
+}
+
+/// Computes the convex hull of a set of 3d points, as well as its half-edge topology.
+///
+/// The hull is the same as the one computed by `convex_hull`. The topology is extracted from
+/// the facet adjacency information maintained while computing the hull, and updated while
+/// making the hull canonical, so it comes at almost no extra cost. The `i`-th triangle of the
+/// returned index buffer is associated to the `i`-th face of the topology.
+///
+/// If the convex hull is degenerate (i.e. if it is a single point or a segment) the
+/// returned topology is empty.
+pub fn convex_hull_with_topology(
+    points: &[Point3<Real>],
+) -> (Vec<Point3<Real>>, Vec<[u32; 3]>, TriMeshTopology) {
+    let (vertices, indices, twins) = canonical_convex_hull(points);
+
+    let topology = match twins {
+        Some(twins) => topology_from_twins(vertices.len(), &indices, &twins),
+        // The hull is degenerate.
+        None if vertices.len() < 3 => TriMeshTopology::default(),
+        // The double-sided mesh of a planar hull.
+        None => TriMeshTopology::from_indices(vertices.len(), &indices)
+            .expect("Internal error: the convex hull is not an oriented manifold."),
+    };
+
+    (vertices, indices, topology)
+}
+
+// Computes the canonical convex hull, and the twin of each of its half-edges if they are
+// known from the hull computation. See `canonicalize_hull` for the layout of the twins.
+fn canonical_convex_hull(
+    points: &[Point3<Real>],
+) -> (Vec<Point3<Real>>, Vec<[u32; 3]>, Option<Vec<[u32; 3]>>) {
     if points.is_empty() {
-        return (Vec::new(), Vec::new());
+        return (Vec::new(), Vec::new(), None);
+    }
+
+    match compute_hull_facets(points) {
+        InitialMesh::Facets(triangles) => {
+            let (vertices, indices, twins) = extract_hull_mesh(points, &triangles);
+            super::canonicalize_hull(vertices, indices, Some(twins))
+        }
+        InitialMesh::ResultMesh(vertices, indices) => {
+            super::canonicalize_hull(vertices, indices, None)
+        }
     }
+}
 
+fn topology_from_twins(
+    num_vertices: usize,
+    indices: &[[u32; 3]],
+    twins: &[[u32; 3]],
+) -> TriMeshTopology {
+    let mut topology = TriMeshTopology {
+        vertices: vec![
+            TopoVertex {
+                half_edge: u32::MAX
+            };
+            num_vertices
+        ],
+        faces: Vec::with_capacity(indices.len()),
+        half_edges: Vec::with_capacity(indices.len() * 3),
+    };
+
+    for (face_id, (idx, twin)) in indices.iter().zip(twins.iter()).enumerate() {
+        let face_id = face_id as u32;
+        topology.faces.push(TopoFace {
+            half_edge: face_id * 3,
+        });
+
+        for i in 0..3 {
+            let he_id = face_id * 3 + i as u32;
+            topology.vertices[idx[i] as usize].half_edge = he_id;
+            topology.half_edges.push(TopoHalfEdge {
+                next: face_id * 3 + (i as u32 + 1) % 3,
+                twin: twin[i],
+                vertex: idx[i],
+                face: face_id,
+            });
+        }
+    }
+
+    topology
+}
+
+fn compute_hull_facets(points: &[Point3<Real>]) -> InitialMesh {
     // print_buildable_vec("input", points);
 
     let mut normalized_points = points.to_vec();
@@ -27,8 +117,8 @@ pub fn convex_hull(points: &[Point3<Real>]) -> (Vec<Point3<Real>>, Vec<[u32; 3]>
         InitialMesh::Facets(facets) => {
             triangles = facets;
         }
-        InitialMesh::ResultMesh(vertices, indices) => {
-            return (vertices, indices);
+        mesh @ InitialMesh::ResultMesh(..) => {
+            return mesh;
         }
     }
 
@@ -129,25 +219,55 @@ pub fn convex_hull(points: &[Point3<Real>]) -> (Vec<Point3<Real>>, Vec<[u32; 3]>
         i = i + 1;
     }
 
+    InitialMesh::Facets(triangles)
+}
+
+// Extracts the index buffer from the valid facets. Also returns, for each triangle, the
+// twin of each of its half-edges, identified by `3 * triangle_id + edge_id`.
+fn extract_hull_mesh(
+    points: &[Point3<Real>],
+    triangles: &[TriangleFacet],
+) -> (Vec<Point3<Real>>, Vec<[u32; 3]>, Vec<[u32; 3]>) {
     let mut idx = Vec::new();
+    let mut face_ids = Vec::with_capacity(triangles.len());
 
     for facet in triangles.iter() {
         if facet.valid {
+            face_ids.push(idx.len());
             idx.push([
                 facet.pts[0] as u32,
                 facet.pts[1] as u32,
                 facet.pts[2] as u32,
             ]);
+        } else {
+            face_ids.push(usize::MAX);
         }
     }
 
+    let twins = triangles
+        .iter()
+        .filter(|facet| facet.valid)
+        .map(|facet| {
+            let mut twin = [u32::MAX; 3];
+
+            for i in 0..3 {
+                let adj_face_id = face_ids[facet.adj[i]];
+                if adj_face_id != usize::MAX {
+                    twin[i] = adj_face_id as u32 * 3 + facet.indirect_adj_id[i] as u32;
+                }
+            }
+
+            twin
+        })
+        .collect();
+
     let mut points = points.to_vec();
     utils::remove_unused_points(&mut points, &mut idx[..]);
 
     assert!(points.len() != 0, "Internal error: empty output mesh.");
     // super::check_convex_hull(&points, &idx);
 
-    (points, idx)
+    (points, idx, twins)
 }
 
 fn compute_silhouette(
@@ -0,0 +1,168 @@
+use crate::math::Real;
+use na::Point2;
+use std::cmp::Ordering;
+
+/// Triangulates a simple polygon with holes by ear clipping.
+///
+/// The outer boundary `outer` and each hole of `holes` are loops of indices into `vertices`.
+/// The outer boundary must be counterclockwise and the holes clockwise, so the interior of
+/// the polygon is always on the left of its boundary. The holes must be strictly inside of the
+/// outer boundary and must not overlap each other.
+///
+/// Returns the indices of the counterclockwise triangles, or `None` if the polygon couldn't be
+/// triangulated, e.g., because its boundary self-intersects.
+pub fn triangulate_polygon(
+    vertices: &[Point2<Real>],
+    outer: &[u32],
+    holes: &[Vec<u32>],
+) -> Option<Vec<[u32; 3]>> {
+    if outer.len() < 3 || holes.iter().any(|hole| hole.len() < 3) {
+        return None;
+    }
+
+    let mut polygon = outer.to_vec();
+
+    for (i, hole) in holes.iter().enumerate() {
+        polygon = bridge_hole(vertices, &polygon, hole, &holes[i + 1..])?;
+    }
+
+    clip_ears(vertices, polygon)
+}
+
+fn orient(a: &Point2<Real>, b: &Point2<Real>, c: &Point2<Real>) -> Real {
+    (b - a).perp(&(c - a))
+}
+
+/// Is `p` strictly inside of the angle at `b` on the left of the path `a, b, c`?
+fn in_cone(a: &Point2<Real>, b: &Point2<Real>, c: &Point2<Real>, p: &Point2<Real>) -> bool {
+    if orient(a, b, c) >= 0.0 {
+        orient(a, b, p) > 0.0 && orient(b, c, p) > 0.0
+    } else {
+        orient(a, b, p) > 0.0 || orient(b, c, p) > 0.0
+    }
+}
+
+/// Does the edge `[q1, q2]` block the segment `[p1, p2]`?
+///
+/// Edges sharing an endpoint with the segment never block it.
+fn blocks(p1: &Point2<Real>, p2: &Point2<Real>, q1: &Point2<Real>, q2: &Point2<Real>) -> bool {
+    if q1 == p1 || q1 == p2 || q2 == p1 || q2 == p2 {
+        return false;
+    }
+
+    let on_segment = |q: &Point2<Real>| {
+        let dq = q - p1;
+        let dp = p2 - p1;
+        dq.dot(&dp) > 0.0 && dq.dot(&dp) < dp.norm_squared()
+    };
+
+    let o1 = orient(p1, p2, q1);
+    let o2 = orient(p1, p2, q2);
+
+    if (o1 == 0.0 && on_segment(q1)) || (o2 == 0.0 && on_segment(q2)) {
+        return true;
+    }
+
+    o1 * o2 < 0.0 && orient(q1, q2, p1) * orient(q1, q2, p2) < 0.0
+}
+
+/// Merges `hole` into `polygon` by linking them with a pair of opposite edges.
+///
+/// The bridge is the shortest segment between a vertex of the hole and a vertex of the
+/// polygon that doesn't cross the polygon, the hole, or the holes that remain to be merged.
+fn bridge_hole(
+    vertices: &[Point2<Real>],
+    polygon: &[u32],
+    hole: &[u32],
+    remaining_holes: &[Vec<u32>],
+) -> Option<Vec<u32>> {
+    let pt = |i: u32| &vertices[i as usize];
+    let prev = |lp: &[u32], k: usize| lp[(k + lp.len() - 1) % lp.len()];
+    let next = |lp: &[u32], k: usize| lp[(k + 1) % lp.len()];
+
+    let mut candidates: Vec<_> = (0..hole.len())
+        .flat_map(|h| (0..polygon.len()).map(move |k| (h, k)))
+        .collect();
+    let dist = |(h, k): (usize, usize)| na::distance_squared(pt(hole[h]), pt(polygon[k]));
+    candidates.sort_by(|a, b| dist(*a).partial_cmp(&dist(*b)).unwrap_or(Ordering::Equal));
+
+    let loops = || {
+        std::iter::once(polygon)
+            .chain(std::iter::once(hole))
+            .chain(remaining_holes.iter().map(|h| &h[..]))
+    };
+
+    for (h, k) in candidates {
+        let m = pt(hole[h]);
+        let v = pt(polygon[k]);
+
+        if m == v
+            || !in_cone(pt(prev(polygon, k)), v, pt(next(polygon, k)), m)
+            || !in_cone(pt(prev(hole, h)), m, pt(next(hole, h)), v)
+        {
+            continue;
+        }
+
+        let blocked = loops()
+            .any(|lp| (0..lp.len()).any(|i| blocks(m, v, pt(lp[i]), pt(lp[(i + 1) % lp.len()]))));
+
+        if !blocked {
+            let mut merged = Vec::with_capacity(polygon.len() + hole.len() + 2);
+            merged.extend_from_slice(&polygon[..=k]);
+            merged.extend_from_slice(&hole[h..]);
+            merged.extend_from_slice(&hole[..=h]);
+            merged.extend_from_slice(&polygon[k..]);
+            return Some(merged);
+        }
+    }
+
+    None
+}
+
+/// Triangulates a single counterclockwise loop, possibly with duplicate vertices.
+fn clip_ears(vertices: &[Point2<Real>], mut polygon: Vec<u32>) -> Option<Vec<[u32; 3]>> {
+    let pt = |i: u32| &vertices[i as usize];
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+    let mut i = 0;
+    let mut num_failures = 0;
+
+    while polygon.len() >= 3 {
+        let n = polygon.len();
+        let (ia, ib, ic) = (polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]);
+        let (a, b, c) = (pt(ia), pt(ib), pt(ic));
+        let orientation = orient(a, b, c);
+
+        // A vertex aligned with its neighbors bounds an empty region so it is removed as is.
+        let is_ear = orientation == 0.0
+            || (orientation > 0.0
+                && polygon.iter().all(|j| {
+                    let p = pt(*j);
+                    p == a
+                        || p == b
+                        || p == c
+                        || orient(a, b, p) < 0.0
+                        || orient(b, c, p) < 0.0
+                        || orient(c, a, p) < 0.0
+                }));
+
+        if is_ear {
+            if orientation > 0.0 {
+                triangles.push([ia, ib, ic]);
+            }
+
+            let _ = polygon.remove(i);
+            i = if i == 0 { 0 } else { i - 1 };
+            num_failures = 0;
+        } else {
+            num_failures += 1;
+
+            if num_failures > n {
+                return None;
+            }
+
+            i = (i + 1) % n;
+        }
+    }
+
+    Some(triangles)
+}
@@ -5,12 +5,22 @@ pub(crate) use self::convex_hull2::convex_hull2_idx;
 #[cfg(feature = "dim2")]
 pub use self::convex_hull2::{convex_hull2 as convex_hull, convex_hull2_idx as convex_hull_idx};
 #[cfg(feature = "dim3")]
-pub use self::convex_hull3::{check_convex_hull, convex_hull};
+pub use self::convex_hull3::{check_convex_hull, convex_hull, convex_hull_with_topology};
+#[cfg(feature = "dim2")]
+pub use self::ear_clipping::triangulate_polygon;
+pub use self::sampling::{sample_surface, sample_volume};
+pub use self::triangle_soup::trimesh_from_triangle_soup;
+pub use self::voxelized_mass_properties::mass_properties_by_voxelization;
 
 mod convex_hull2;
 #[cfg(feature = "dim3")]
 mod convex_hull3;
 pub(crate) mod convex_hull_utils;
+#[cfg(feature = "dim2")]
+mod ear_clipping;
+mod sampling;
+mod triangle_soup;
+mod voxelized_mass_properties;
 
 /// Approximate convex decomposition using the VHACD algorithm.
 pub mod vhacd;
@@ -0,0 +1,324 @@
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::query::Ray;
+#[cfg(feature = "dim3")]
+use crate::shape::{Ball, Triangle};
+use crate::shape::{Shape, TypedShape};
+use na::ComplexField;
+#[cfg(feature = "dim2")]
+use {crate::shape::Segment, na::RealField};
+
+// The number of support points used to discretize the boundary of convex shapes.
+#[cfg(feature = "dim2")]
+const NSUBDIVS: u32 = 128;
+#[cfg(feature = "dim3")]
+const NSUBDIVS: u32 = 32;
+
+// The maximum number of rejected points per accepted point before volume sampling gives up.
+const MAX_REJECTIONS: usize = 1000;
+
+#[cfg(feature = "dim2")]
+type Element = Segment;
+#[cfg(feature = "dim3")]
+type Element = Triangle;
+
+// A set of segments (in 2D) or triangles (in 3D) discretizing the boundary of a shape.
+struct Patch<'a> {
+    pos: Isometry<Real>,
+    // The shape the samples are projected on, if the elements only approximate its boundary,
+    // together with a point inside of the elements.
+    curved_shape: Option<(&'a dyn Shape, Point<Real>)>,
+    elements: Vec<Element>,
+}
+
+/// Samples `count` random points on the boundary of `shape`.
+///
+/// The random numbers are drawn from `rng`, which must return values uniformly distributed in
+/// `[0, 1)`. The points are expressed in the local-space of `shape`, and are uniformly
+/// distributed on the boundary of polyhedral shapes, triangle meshes and heightfields. Curved
+/// boundaries are discretized before being sampled, and the samples are then projected back
+/// onto the boundary, so their distribution is only approximately uniform. The boundary of a
+/// compound shape is the union of the boundaries of its parts.
+///
+/// Returns `None` if `shape`, or one of its parts, isn't supported. Supported shapes are the
+/// convex shapes with a support map, compound shapes, triangle meshes, heightfields, and in 2D,
+/// segments and polylines. Also returns `None` if the boundary of `shape` is empty or its
+/// measure is too large to be represented.
+pub fn sample_surface(
+    shape: &dyn Shape,
+    count: usize,
+    rng: &mut dyn FnMut() -> Real,
+) -> Option<Vec<Point<Real>>> {
+    let mut patches = Vec::new();
+    push_patches(shape, Isometry::identity(), &mut patches)?;
+
+    // The cumulated measures of all the elements, for picking them proportionally to
+    // their length (in 2D) or area (in 3D).
+    let mut cumulated = Vec::new();
+    let mut element_ids = Vec::new();
+    let mut total = 0.0;
+
+    for (i, patch) in patches.iter().enumerate() {
+        for (j, element) in patch.elements.iter().enumerate() {
+            #[cfg(feature = "dim2")]
+            let measure = element.length();
+            #[cfg(feature = "dim3")]
+            let measure = element.area();
+
+            if measure > 0.0 {
+                total += measure;
+                cumulated.push(total);
+                element_ids.push((i, j));
+            }
+        }
+    }
+
+    // An infinite total measure would make the sampled measures NaN.
+    if cumulated.is_empty() || !total.is_finite() {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        let target = rng() * total;
+        let k = match cumulated.binary_search_by(|c| c.partial_cmp(&target).unwrap()) {
+            Ok(k) | Err(k) => k.min(cumulated.len() - 1),
+        };
+        let (i, j) = element_ids[k];
+        let patch = &patches[i];
+        let element = &patch.elements[j];
+
+        #[cfg(feature = "dim2")]
+        let mut pt = element.a + element.scaled_direction() * rng();
+        #[cfg(feature = "dim3")]
+        let mut pt = {
+            // Uniform sampling of a triangle, see "Shape Distributions", Osada et al. 2002.
+            let r1 = ComplexField::sqrt(rng());
+            let r2 = rng();
+            Point::from(
+                element.a.coords * (1.0 - r1)
+                    + element.b.coords * (r1 * (1.0 - r2))
+                    + element.c.coords * (r1 * r2),
+            )
+        };
+
+        if let Some((curved_shape, center)) = patch.curved_shape {
+            pt = project_on_convex_boundary(curved_shape, &center, element, pt);
+        }
+
+        result.push(patch.pos * pt);
+    }
+
+    Some(result)
+}
+
+/// Samples `count` random points uniformly distributed inside of `shape`.
+///
+/// The random numbers are drawn from `rng`, which must return values uniformly distributed in
+/// `[0, 1)`. The points are expressed in the local-space of `shape`. They are obtained by
+/// rejection sampling: points are drawn uniformly in the local AABB of `shape`, and only the
+/// ones contained by `shape` are kept.
+///
+/// Returns `None` if `shape` is unbounded, or if too many points are rejected, e.g., because
+/// `shape` is flat or isn't solid. Note that triangle meshes are only solid if their
+/// pseudo-normals have been computed.
+pub fn sample_volume(
+    shape: &dyn Shape,
+    count: usize,
+    rng: &mut dyn FnMut() -> Real,
+) -> Option<Vec<Point<Real>>> {
+    let aabb = shape.compute_local_aabb();
+    let extents = aabb.extents();
+
+    if extents
+        .iter()
+        .any(|e| !e.is_finite() || *e >= Real::MAX / 2.0)
+    {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(count);
+    let mut rejected = 0;
+
+    while result.len() < count {
+        let unit = Vector::<Real>::from_fn(|_, _| rng());
+        let pt = aabb.mins + extents.component_mul(&unit);
+
+        if shape.contains_local_point(&pt) {
+            result.push(pt);
+        } else {
+            rejected += 1;
+
+            if rejected > MAX_REJECTIONS * (result.len() + 1) {
+                return None;
+            }
+        }
+    }
+
+    Some(result)
+}
+
+fn push_patches<'a>(
+    shape: &'a dyn Shape,
+    pos: Isometry<Real>,
+    out: &mut Vec<Patch<'a>>,
+) -> Option<()> {
+    match shape.as_typed_shape() {
+        TypedShape::Compound(compound) => {
+            for (part_pos, part) in compound.shapes() {
+                push_patches(&**part, pos * part_pos, out)?;
+            }
+        }
+        TypedShape::TriMesh(trimesh) => {
+            #[cfg(feature = "dim2")]
+            let elements = trimesh
+                .boundary_edges()
+                .iter()
+                .map(|e| {
+                    let vertices = trimesh.vertices();
+                    Segment::new(vertices[e[0] as usize], vertices[e[1] as usize])
+                })
+                .collect();
+            #[cfg(feature = "dim3")]
+            let elements = trimesh.triangles().collect();
+            out.push(Patch {
+                pos,
+                curved_shape: None,
+                elements,
+            });
+        }
+        TypedShape::HeightField(heightfield) => {
+            #[cfg(feature = "dim2")]
+            let elements = heightfield.segments().collect();
+            #[cfg(feature = "dim3")]
+            let elements = heightfield.triangles().collect();
+            out.push(Patch {
+                pos,
+                curved_shape: None,
+                elements,
+            });
+        }
+        #[cfg(feature = "dim2")]
+        TypedShape::Polyline(polyline) => out.push(Patch {
+            pos,
+            curved_shape: None,
+            elements: polyline.segments().collect(),
+        }),
+        #[cfg(feature = "dim2")]
+        TypedShape::Segment(segment) => out.push(Patch {
+            pos,
+            curved_shape: None,
+            elements: vec![*segment],
+        }),
+        #[cfg(feature = "dim3")]
+        TypedShape::Triangle(triangle) => out.push(Patch {
+            pos,
+            curved_shape: None,
+            elements: vec![*triangle],
+        }),
+        typed => {
+            let support_map = shape.as_support_map()?;
+            let (elements, center) = convex_boundary(|dir| support_map.local_support_point(dir));
+            let is_polyhedral = match typed {
+                TypedShape::Cuboid(_) => true,
+                #[cfg(feature = "dim2")]
+                TypedShape::ConvexPolygon(_) | TypedShape::Triangle(_) => true,
+                #[cfg(feature = "dim3")]
+                TypedShape::ConvexPolyhedron(_) => true,
+                _ => false,
+            };
+
+            out.push(Patch {
+                pos,
+                curved_shape: if is_polyhedral {
+                    None
+                } else {
+                    Some((shape, center))
+                },
+                elements,
+            });
+        }
+    }
+
+    Some(())
+}
+
+// Discretizes the boundary of a convex shape from its support function.
+#[cfg(feature = "dim2")]
+fn convex_boundary(support: impl Fn(&Vector<Real>) -> Point<Real>) -> (Vec<Segment>, Point<Real>) {
+    let points: Vec<_> = (0..NSUBDIVS)
+        .map(|i| {
+            let angle = Real::two_pi() * i as Real / NSUBDIVS as Real;
+            support(&Vector::new(
+                ComplexField::cos(angle),
+                ComplexField::sin(angle),
+            ))
+        })
+        .collect();
+
+    let center = crate::utils::center(&points);
+
+    if points.iter().all(|pt| *pt == points[0]) {
+        return (Vec::new(), center);
+    }
+
+    let hull = crate::transformation::convex_hull(&points);
+
+    let elements = (0..hull.len())
+        .map(|i| Segment::new(hull[i], hull[(i + 1) % hull.len()]))
+        .collect();
+    (elements, center)
+}
+
+// Discretizes the boundary of a convex shape from its support function.
+#[cfg(feature = "dim3")]
+fn convex_boundary(support: impl Fn(&Vector<Real>) -> Point<Real>) -> (Vec<Triangle>, Point<Real>) {
+    let (directions, _) = Ball::new(1.0).to_trimesh(NSUBDIVS, NSUBDIVS / 2);
+    let points: Vec<_> = directions.iter().map(|dir| support(&dir.coords)).collect();
+
+    let center = crate::utils::center(&points);
+
+    if points.iter().all(|pt| *pt == points[0]) {
+        // The convex hull computation doesn't support this degenerate case.
+        return (Vec::new(), center);
+    }
+
+    let (vertices, indices) = crate::transformation::convex_hull(&points);
+
+    let elements = indices
+        .iter()
+        .map(|idx| {
+            Triangle::new(
+                vertices[idx[0] as usize],
+                vertices[idx[1] as usize],
+                vertices[idx[2] as usize],
+            )
+        })
+        .collect();
+    (elements, center)
+}
+
+// Projects a point of an element inscribed in a convex shape onto the boundary of this shape,
+// along the normal of the element.
+//
+// This casts a ray from outside of the shape because the orthogonal projection of points
+// lying on, or very close to, the boundary of a support-mapped shape isn't reliable.
+fn project_on_convex_boundary(
+    shape: &dyn Shape,
+    center: &Point<Real>,
+    element: &Element,
+    pt: Point<Real>,
+) -> Point<Real> {
+    let normal = match element.normal() {
+        Some(normal) if normal.dot(&(pt - center)) < 0.0 => -normal,
+        Some(normal) => normal,
+        None => return pt,
+    };
+    let dist = shape.compute_local_bounding_sphere().radius() * 3.0;
+    let ray = Ray::new(pt + *normal * dist, -*normal);
+
+    shape
+        .cast_local_ray(&ray, dist, true)
+        .map(|toi| ray.point_at(toi))
+        .unwrap_or(pt)
+}
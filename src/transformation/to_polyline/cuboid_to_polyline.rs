@@ -10,12 +10,12 @@ impl Cuboid {
     }
 }
 
-/// The contour of a unit cuboid lying on the x-y plane.
+/// The counterclockwise contour of a unit cuboid lying on the x-y plane.
 fn unit_rectangle() -> Vec<Point2<Real>> {
     vec![
-        Point2::new(-0.5, 0.5),
-        Point2::new(0.5, 0.5),
         Point2::new(-0.5, -0.5),
         Point2::new(0.5, -0.5),
+        Point2::new(0.5, 0.5),
+        Point2::new(-0.5, 0.5),
     ]
 }
@@ -1,3 +1,4 @@
 mod ball_to_polyline;
 mod capsule_to_polyline;
 mod cuboid_to_polyline;
+mod sector_to_polyline;
@@ -0,0 +1,31 @@
+use crate::math::Real;
+use crate::shape::Sector;
+use na::{self, ComplexField, Point2, RealField};
+
+impl Sector {
+    /// Discretize the boundary of this sector as a polygonal line.
+    ///
+    /// The arc is discretized with `nsubdiv` segments, and is closed by the center of the
+    /// sector unless the sector is a full disk.
+    pub fn to_polyline(&self, nsubdiv: u32) -> Vec<Point2<Real>> {
+        let full_disk = self.angle() >= Real::two_pi();
+        let dtheta = self.angle() / (nsubdiv as Real);
+        let narc = if full_disk { nsubdiv } else { nsubdiv + 1 };
+
+        let mut points: Vec<_> = (0..narc)
+            .map(|i| {
+                let theta = self.start_angle + dtheta * (i as Real);
+                Point2::new(
+                    ComplexField::cos(theta) * self.radius,
+                    ComplexField::sin(theta) * self.radius,
+                )
+            })
+            .collect();
+
+        if !full_disk {
+            points.push(Point2::origin());
+        }
+
+        points
+    }
+}
@@ -5,3 +5,5 @@ mod convex_polyhedron_to_trimesh;
 mod cuboid_to_trimesh;
 mod cylinder_to_trimesh;
 mod heightfield_to_trimesh;
+mod spherical_sector_to_trimesh;
+mod torus_to_trimesh;
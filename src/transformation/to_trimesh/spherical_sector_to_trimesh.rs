@@ -0,0 +1,53 @@
+use crate::math::Real;
+use crate::shape::SphericalSector;
+use crate::transformation::utils;
+use na::{self, ComplexField, Point3, RealField};
+
+impl SphericalSector {
+    /// Discretize the boundary of this spherical sector as a triangle-mesh.
+    ///
+    /// The circles around the `y` axis are discretized with `ntheta_subdiv` points, and the
+    /// spherical cap is split into `nphi_subdiv` rings from its pole to its rim.
+    pub fn to_trimesh(
+        &self,
+        ntheta_subdiv: u32,
+        nphi_subdiv: u32,
+    ) -> (Vec<Point3<Real>>, Vec<[u32; 3]>) {
+        let dtheta = Real::two_pi() / (ntheta_subdiv as Real);
+        let dphi = self.half_angle / (nphi_subdiv as Real);
+        let mut coords = Vec::new();
+        let mut indices = Vec::new();
+
+        for i in 1..=nphi_subdiv {
+            let phi = dphi * (i as Real);
+            utils::push_circle(
+                ComplexField::sin(phi) * self.radius,
+                ntheta_subdiv,
+                dtheta,
+                ComplexField::cos(phi) * self.radius,
+                &mut coords,
+            );
+        }
+
+        let pole = coords.len() as u32;
+        coords.push(Point3::new(0.0, self.radius, 0.0));
+        let apex = coords.len() as u32;
+        coords.push(Point3::origin());
+
+        // The spherical cap.
+        utils::push_degenerate_top_ring_indices(0, pole, ntheta_subdiv, &mut indices);
+
+        for i in 0..nphi_subdiv - 1 {
+            let upper = i * ntheta_subdiv;
+            utils::push_ring_indices(upper + ntheta_subdiv, upper, ntheta_subdiv, &mut indices);
+        }
+
+        // The lateral surface of the cone, with its apex below the rim.
+        let rim = (nphi_subdiv - 1) * ntheta_subdiv;
+        let first_lateral = indices.len();
+        utils::push_degenerate_top_ring_indices(rim, apex, ntheta_subdiv, &mut indices);
+        utils::reverse_clockwising(&mut indices[first_lateral..]);
+
+        (coords, indices)
+    }
+}
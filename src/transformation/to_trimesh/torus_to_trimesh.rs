@@ -0,0 +1,49 @@
+use crate::math::Real;
+use crate::shape::Torus;
+use na::{self, ComplexField, Point3, RealField};
+
+impl Torus {
+    /// Discretize the boundary of this torus as a triangle-mesh.
+    ///
+    /// The circle at the center of the tube is discretized with `nmajor_subdiv` points, and
+    /// each section of the tube with `nminor_subdiv` points.
+    pub fn to_trimesh(
+        &self,
+        nmajor_subdiv: u32,
+        nminor_subdiv: u32,
+    ) -> (Vec<Point3<Real>>, Vec<[u32; 3]>) {
+        let dtheta = Real::two_pi() / (nmajor_subdiv as Real);
+        let dphi = Real::two_pi() / (nminor_subdiv as Real);
+        let mut coords = Vec::with_capacity((nmajor_subdiv * nminor_subdiv) as usize);
+        let mut indices = Vec::with_capacity((nmajor_subdiv * nminor_subdiv * 2) as usize);
+
+        for i in 0..nmajor_subdiv {
+            let theta = dtheta * (i as Real);
+
+            for j in 0..nminor_subdiv {
+                let phi = dphi * (j as Real);
+                let radial = self.major_radius + ComplexField::cos(phi) * self.minor_radius;
+                coords.push(Point3::new(
+                    ComplexField::cos(theta) * radial,
+                    ComplexField::sin(phi) * self.minor_radius,
+                    ComplexField::sin(theta) * radial,
+                ));
+            }
+        }
+
+        let id = |i: u32, j: u32| (i % nmajor_subdiv) * nminor_subdiv + j % nminor_subdiv;
+
+        for i in 0..nmajor_subdiv {
+            for j in 0..nminor_subdiv {
+                let a = id(i, j);
+                let b = id(i + 1, j);
+                let c = id(i + 1, j + 1);
+                let d = id(i, j + 1);
+                indices.push([a, d, c]);
+                indices.push([a, c, b]);
+            }
+        }
+
+        (coords, indices)
+    }
+}
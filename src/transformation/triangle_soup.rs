@@ -0,0 +1,73 @@
+use crate::math::{Point, Real, DIM};
+use crate::shape::TriMesh;
+use crate::utils::hashmap::HashMap;
+use na::ComplexField;
+
+/// Builds an indexed triangle mesh from a triangle soup.
+///
+/// Every vertex closer than `weld_eps` to a vertex already inserted into the mesh is
+/// merged with it, so triangles sharing an edge in the soup end up sharing vertex
+/// indices. The triangles are output in the same order as in `triangles`, even if
+/// some of them became degenerate after welding.
+///
+/// If `triangles` is empty, the resulting mesh is empty too.
+pub fn trimesh_from_triangle_soup(triangles: &[[Point<Real>; 3]], weld_eps: Real) -> TriMesh {
+    // Vertices within `weld_eps` of each other are always in the same
+    // or adjacent cells of a grid with a cell width equal to `weld_eps`.
+    let cell_width = if weld_eps > 0.0 { weld_eps } else { 1.0 };
+    let mut grid: HashMap<[i64; DIM], Vec<u32>> = HashMap::default();
+    let mut vertices = Vec::new();
+    let mut indices = Vec::with_capacity(triangles.len());
+
+    for tri in triangles {
+        let mut idx = [0; 3];
+
+        for (pt, id) in tri.iter().zip(idx.iter_mut()) {
+            let mut cell = [0; DIM];
+
+            for k in 0..DIM {
+                cell[k] = ComplexField::floor(pt[k] / cell_width) as i64;
+            }
+
+            *id = find_vertex(&grid, &vertices, pt, &cell, weld_eps).unwrap_or_else(|| {
+                let new_id = vertices.len() as u32;
+                vertices.push(*pt);
+                grid.entry(cell).or_default().push(new_id);
+                new_id
+            });
+        }
+
+        indices.push(idx);
+    }
+
+    TriMesh::new(vertices, indices)
+}
+
+fn find_vertex(
+    grid: &HashMap<[i64; DIM], Vec<u32>>,
+    vertices: &[Point<Real>],
+    pt: &Point<Real>,
+    cell: &[i64; DIM],
+    weld_eps: Real,
+) -> Option<u32> {
+    // Visit the 3^DIM cells surrounding `cell`.
+    for neighbor in 0..3usize.pow(DIM as u32) {
+        let mut key = *cell;
+        let mut rest = neighbor;
+
+        for coord in key.iter_mut() {
+            *coord += (rest % 3) as i64 - 1;
+            rest /= 3;
+        }
+
+        if let Some(ids) = grid.get(&key) {
+            for id in ids {
+                if na::distance(&vertices[*id as usize], pt) <= weld_eps {
+                    return Some(*id);
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,94 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real, Vector, DIM};
+use crate::shape::Shape;
+use na::ComplexField;
+#[cfg(feature = "dim3")]
+use na::Matrix3;
+use num::Zero;
+
+/// Numerically approximates the mass properties of any shape by voxelizing it.
+///
+/// The local AABB of `shape` is subdivided into cubic cells of width `cell_size`. Every
+/// cell with a center located inside of `shape` is treated as a solid cube of the given
+/// `density`. The result converges to the exact mass properties as `cell_size` decreases,
+/// at the cost of a number of point containment tests growing as `1 / cell_size^DIM`.
+///
+/// This is meant as a fallback for shapes without analytic mass properties. Shapes
+/// that don't contain any cell center (e.g. surfaces) result in zero mass properties.
+pub fn mass_properties_by_voxelization(
+    shape: &dyn Shape,
+    density: Real,
+    cell_size: Real,
+) -> MassProperties {
+    assert!(cell_size > 0.0, "The cell size must be positive.");
+
+    let aabb = shape.compute_local_aabb();
+    let extents = aabb.extents();
+    let mut num_cells = [0; DIM];
+
+    for k in 0..DIM {
+        num_cells[k] = (ComplexField::ceil(extents[k] / cell_size) as usize).max(1);
+    }
+
+    // Center the grid on the AABB.
+    let grid_extents = Vector::from_iterator(num_cells.iter().map(|n| *n as Real * cell_size));
+    let origin = aabb.center() - grid_extents / 2.0;
+    let total_cells: usize = num_cells.iter().product();
+
+    let mut centers = Vec::new();
+
+    for i in 0..total_cells {
+        let mut rem = i;
+        let mut center = origin;
+
+        for k in 0..DIM {
+            center[k] += (rem % num_cells[k]) as Real * cell_size + cell_size / 2.0;
+            rem /= num_cells[k];
+        }
+
+        if shape.contains_local_point(&center) {
+            centers.push(center);
+        }
+    }
+
+    if centers.is_empty() {
+        return MassProperties::zero();
+    }
+
+    let cell_mass = ComplexField::powi(cell_size, DIM as i32) * density;
+    let mass = cell_mass * centers.len() as Real;
+    let com = Point::from(
+        centers
+            .iter()
+            .fold(Vector::zeros(), |acc: Vector<Real>, pt| acc + pt.coords)
+            / centers.len() as Real,
+    );
+
+    // Each cell is a solid cube with a principal angular inertia of `m * s² / 6`
+    // shifted to the center of mass with the parallel axis theorem.
+    let cell_inertia = cell_mass * cell_size * cell_size / 6.0;
+
+    #[cfg(feature = "dim2")]
+    {
+        let inertia = centers
+            .iter()
+            .map(|pt| cell_inertia + (pt - com).norm_squared() * cell_mass)
+            .sum();
+        MassProperties::new(com, mass, inertia)
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        let mut inertia = Matrix3::zeros();
+
+        for pt in &centers {
+            let shift = pt - com;
+            inertia += (Matrix3::from_diagonal_element(shift.norm_squared())
+                - shift * shift.transpose())
+                * cell_mass;
+        }
+
+        inertia += Matrix3::from_diagonal_element(cell_inertia * centers.len() as Real);
+        MassProperties::with_inertia_matrix(com, mass, inertia)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::math::{Dim, Isometry, Matrix, Real, Rotation, Vector, DIM};
+use na::ComplexField;
+
+/// The number of elements of the homogeneous matrix representation of an isometry.
+pub const HOMOGENEOUS_MATRIX_LEN: usize = (DIM + 1) * (DIM + 1);
+
+/// Converts an isometry to its homogeneous matrix representation, as a flat array.
+///
+/// The matrix is stored in column-major order, i.e., it is a 4x4 matrix in 3D, and
+/// a 3x3 matrix in 2D, with the translation stored in the last column.
+pub fn isometry_to_matrix_array(iso: &Isometry<Real>) -> [Real; HOMOGENEOUS_MATRIX_LEN] {
+    let mut result = [0.0; HOMOGENEOUS_MATRIX_LEN];
+    result.copy_from_slice(iso.to_homogeneous().as_slice());
+    result
+}
+
+/// Converts a flat homogeneous matrix, stored in column-major order, to an isometry.
+///
+/// This is the inverse of `isometry_to_matrix_array`. Returns `None` if `m` does not
+/// contain exactly `HOMOGENEOUS_MATRIX_LEN` elements, or if it does not represent a
+/// rigid transformation (e.g. if it contains a scaling, a shear, a reflection, or a
+/// projective part).
+pub fn isometry_from_matrix_array(m: &[Real]) -> Option<Isometry<Real>> {
+    const N: usize = DIM + 1;
+    let eps = ComplexField::sqrt(crate::math::DEFAULT_EPSILON);
+
+    if m.len() != HOMOGENEOUS_MATRIX_LEN {
+        return None;
+    }
+
+    // The last row must be (0, ..., 0, 1).
+    for j in 0..DIM {
+        if ComplexField::abs(m[j * N + DIM]) > eps {
+            return None;
+        }
+    }
+
+    if ComplexField::abs(m[N * N - 1] - 1.0) > eps {
+        return None;
+    }
+
+    let rot = Matrix::from_fn(|i, j| m[j * N + i]);
+    let tra = Vector::from_fn(|i, _| m[DIM * N + i]);
+
+    if !relative_eq!(rot.transpose() * rot, Matrix::identity(), epsilon = eps)
+        || rot.determinant() < 0.0
+    {
+        return None;
+    }
+
+    let rot = na::Rotation::<Real, Dim>::from_matrix_unchecked(rot);
+    Some(Isometry::from_parts(
+        tra.into(),
+        Rotation::from_rotation_matrix(&rot),
+    ))
+}
@@ -7,9 +7,13 @@ pub use self::deterministic_state::DeterministicState;
 #[cfg(feature = "dim3")]
 pub use self::cleanup::remove_unused_points;
 pub(crate) use self::inv::inv;
+pub use self::isometry_array::{
+    isometry_from_matrix_array, isometry_to_matrix_array, HOMOGENEOUS_MATRIX_LEN,
+};
 pub use self::isometry_ops::{IsometryOps, IsometryOpt};
 pub use self::maybe_serializable_data::MaybeSerializableData;
 pub use self::median::median;
+pub use self::obb::obb;
 pub use self::point_cloud_support_point::{
     point_cloud_support_point, point_cloud_support_point_id,
 };
@@ -36,9 +40,11 @@ mod deterministic_state;
 mod hashable_partial_eq;
 pub mod hashmap;
 mod inv;
+mod isometry_array;
 mod isometry_ops;
 mod maybe_serializable_data;
 mod median;
+mod obb;
 mod point_cloud_support_point;
 mod point_in_poly2d;
 mod ref_with_cost;
@@ -0,0 +1,36 @@
+use crate::math::{Dim, Isometry, Point, Real, Rotation, Vector, DIM};
+use crate::shape::Cuboid;
+
+/// Computes an oriented bounding box for the given set of points.
+///
+/// The axes of the box are the principal axes of the point cloud, i.e., the
+/// eigenvectors of its covariance matrix. This is not the smallest enclosing
+/// box in general, but it is tight for elongated point clouds.
+///
+/// Returns the position of the box center and orientation, and the box itself.
+/// Panics if `pts` is empty.
+pub fn obb(pts: &[Point<Real>]) -> (Isometry<Real>, Cuboid) {
+    let cov = crate::utils::cov(pts);
+    let mut axes = cov.symmetric_eigen().eigenvectors;
+
+    // Make sure the basis is right-handed so it is a proper rotation.
+    if axes.determinant() < 0.0 {
+        let last = -axes.column(DIM - 1);
+        axes.set_column(DIM - 1, &last);
+    }
+
+    let mut mins = Vector::repeat(Real::MAX);
+    let mut maxs = Vector::repeat(-Real::MAX);
+
+    for pt in pts {
+        let local = axes.tr_mul(&pt.coords);
+        mins = mins.inf(&local);
+        maxs = maxs.sup(&local);
+    }
+
+    let center = axes * (mins + maxs) / 2.0;
+    let rot = na::Rotation::<Real, Dim>::from_matrix_unchecked(axes);
+    let pos = Isometry::from_parts(center.into(), Rotation::from_rotation_matrix(&rot));
+
+    (pos, Cuboid::new((maxs - mins) / 2.0))
+}